@@ -0,0 +1,159 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use bytes::BytesMut;
+use criterion::{
+    criterion_group,
+    measurement::{Measurement, ValueFormatter},
+    BatchSize, BenchmarkGroup, Criterion, SamplingMode, Throughput,
+};
+use serde_json::json;
+use tokio_util::codec::Encoder;
+use vector::event::{Event, LogEvent, Value};
+use vector_lib::{codecs::LogfmtSerializerConfig, encode_logfmt};
+
+/// Counts the allocations made by this benchmark binary.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Measures the number of allocations made, rather than the time taken.
+struct Allocations;
+
+impl Measurement for Allocations {
+    type Intermediate = u64;
+    type Value = u64;
+
+    fn start(&self) -> Self::Intermediate {
+        ALLOCATIONS.load(Ordering::Relaxed)
+    }
+
+    fn end(&self, start: Self::Intermediate) -> Self::Value {
+        ALLOCATIONS.load(Ordering::Relaxed) - start
+    }
+
+    fn add(&self, left: &Self::Value, right: &Self::Value) -> Self::Value {
+        left + right
+    }
+
+    fn zero(&self) -> Self::Value {
+        0
+    }
+
+    fn to_f64(&self, value: &Self::Value) -> f64 {
+        *value as f64
+    }
+
+    fn formatter(&self) -> &dyn ValueFormatter {
+        &AllocationsFormatter
+    }
+}
+
+struct AllocationsFormatter;
+
+impl ValueFormatter for AllocationsFormatter {
+    fn scale_values(&self, _typical_value: f64, _values: &mut [f64]) -> &'static str {
+        "allocations"
+    }
+
+    fn scale_throughputs(
+        &self,
+        _typical_value: f64,
+        throughput: &Throughput,
+        values: &mut [f64],
+    ) -> &'static str {
+        match throughput {
+            Throughput::Elements(elements) => {
+                for value in values {
+                    *value /= *elements as f64;
+                }
+                "allocations/event"
+            }
+            _ => "allocations",
+        }
+    }
+
+    fn scale_for_machines(&self, _values: &mut [f64]) -> &'static str {
+        "allocations"
+    }
+}
+
+fn logfmt(c: &mut Criterion<Allocations>) {
+    let mut group: BenchmarkGroup<Allocations> = c.benchmark_group("logfmt");
+    group.sampling_mode(SamplingMode::Flat);
+
+    // Events from the same source share the same keys, including nested ones.
+    let input = Event::Log(LogEvent::from(Value::from(json!({
+        "message": "GET /index.html 200",
+        "host": "web-1",
+        "kubernetes": {
+            "container_name": "nginx",
+            "pod_labels": {
+                "app": "web",
+                "app.kubernetes.io/component": "frontend",
+                "pod-template-hash": "5d4f8c7b9",
+            },
+            "pod_namespace": "default",
+        },
+        "status": 200,
+    }))));
+
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("encode_logfmt::encode_value", |b| {
+        b.iter_batched(
+            || (input.clone(), BytesMut::with_capacity(1024)),
+            |(event, mut bytes)| {
+                let string = encode_logfmt::encode_value(event.as_log().value()).unwrap();
+                bytes.extend_from_slice(string.as_bytes());
+                bytes
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    let mut serializer = LogfmtSerializerConfig.build();
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("codecs::LogfmtSerializer::encode", |b| {
+        b.iter_batched(
+            || (input.clone(), BytesMut::with_capacity(1024)),
+            |(event, mut bytes)| {
+                serializer.encode(event, &mut bytes).unwrap();
+                bytes
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(
+    name = benches;
+    // Allocation counts are deterministic, so there is no noise to sample away.
+    config = Criterion::default()
+        .with_measurement(Allocations)
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(5))
+        .sample_size(10);
+    targets = logfmt
+);
@@ -7,6 +7,7 @@ mod http;
 mod lua;
 mod metrics_snapshot;
 mod template;
+mod udp;
 
 criterion_main!(
     batch::benches,
@@ -16,4 +17,5 @@ criterion_main!(
     lua::benches,
     metrics_snapshot::benches,
     template::benches,
+    udp::benches,
 );
@@ -49,6 +49,7 @@ fn benchmark_http(c: &mut Criterion) {
                             &["in"],
                             sinks::http::config::HttpSinkConfig {
                                 uri: out_addr.to_string().parse::<http::Uri>().unwrap().into(),
+                                unix_socket: None,
                                 compression: *compression,
                                 method: Default::default(),
                                 auth: Default::default(),
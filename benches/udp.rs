@@ -0,0 +1,135 @@
+use std::{
+    net::{SocketAddr, UdpSocket},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+use criterion::{criterion_group, BatchSize, BenchmarkId, Criterion, SamplingMode, Throughput};
+use indoc::indoc;
+use tokio::time::sleep;
+use vector::{
+    config,
+    test_util::{next_addr, runtime, start_topology, CountReceiver},
+};
+
+/// Sends small datagrams, from several senders at once, to the UDP mode of the `socket` source,
+/// with a growing number of listeners.
+///
+/// As senders don't wait for the source to keep up, datagrams are dropped by the kernel when the
+/// receive buffers of the listeners overflow. The share of dropped datagrams is reported once
+/// each benchmark is done.
+fn benchmark_udp_listeners(c: &mut Criterion) {
+    let num_senders: usize = 4;
+    let datagrams_per_sender: usize = 25_000;
+    let num_datagrams = num_senders * datagrams_per_sender;
+
+    let mut group = c.benchmark_group("udp");
+    group.throughput(Throughput::Elements(num_datagrams as u64));
+    group.sampling_mode(SamplingMode::Flat);
+
+    for listeners in [1, 2, 4] {
+        let dropped = AtomicUsize::new(0);
+        let sent = AtomicUsize::new(0);
+
+        group.bench_with_input(
+            BenchmarkId::new("listeners", listeners),
+            &listeners,
+            |b, &listeners| {
+                b.iter_batched(
+                    || {
+                        let in_addr = next_addr();
+                        let out_addr = next_addr();
+                        let config = format!(
+                            indoc! {r#"
+                                [sources.in]
+                                  type = "socket"
+                                  mode = "udp"
+                                  address = "{}"
+                                  listeners = {}
+
+                                [sinks.out]
+                                  inputs = ["in"]
+                                  type = "socket"
+                                  mode = "tcp"
+                                  encoding.codec = "text"
+                                  address = "{}"
+                            "#},
+                            in_addr, listeners, out_addr
+                        );
+                        let config = config::load_from_str(&config, config::Format::Toml)
+                            .expect("invalid TOML configuration");
+
+                        let rt = runtime();
+                        let (output_lines, topology) = rt.block_on(async move {
+                            let output_lines = CountReceiver::receive_lines(out_addr);
+                            let (topology, _crash) = start_topology(config, false).await;
+                            // Wait for the source to listen.
+                            sleep(Duration::from_millis(100)).await;
+                            (output_lines, topology)
+                        });
+                        (rt, in_addr, topology, output_lines)
+                    },
+                    |(rt, in_addr, topology, output_lines)| {
+                        send_datagrams(in_addr, num_senders, datagrams_per_sender);
+
+                        let received = rt.block_on(async move {
+                            // The datagrams still buffered are received before stopping.
+                            let mut last_count = None;
+                            while last_count != Some(output_lines.count()) {
+                                last_count = Some(output_lines.count());
+                                sleep(Duration::from_millis(100)).await;
+                            }
+                            topology.stop().await;
+                            output_lines.await.len()
+                        });
+                        sent.fetch_add(num_datagrams, Ordering::Relaxed);
+                        dropped.fetch_add(num_datagrams - received, Ordering::Relaxed);
+                    },
+                    BatchSize::PerIteration,
+                )
+            },
+        );
+
+        report_drops(
+            listeners,
+            dropped.load(Ordering::Relaxed),
+            sent.load(Ordering::Relaxed),
+        );
+    }
+
+    group.finish();
+}
+
+/// Sends datagrams from several threads, each from its own port, for the kernel to spread them
+/// between the listeners of the source.
+fn send_datagrams(addr: SocketAddr, num_senders: usize, datagrams_per_sender: usize) {
+    let senders = (0..num_senders)
+        .map(|_| {
+            thread::spawn(move || {
+                let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+                let datagram = [b'x'; 64];
+                for _ in 0..datagrams_per_sender {
+                    // Datagrams are dropped rather than failing to be sent once buffers are full.
+                    _ = socket.send_to(&datagram, addr);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for sender in senders {
+        sender.join().unwrap();
+    }
+}
+
+#[allow(clippy::print_stderr)]
+fn report_drops(listeners: usize, dropped: usize, sent: usize) {
+    if sent > 0 {
+        eprintln!(
+            "udp/listeners/{listeners}: dropped {dropped} of {sent} datagrams ({:.2}%)",
+            dropped as f64 * 100.0 / sent as f64
+        );
+    }
+}
+
+criterion_group!(benches, benchmark_udp_listeners);
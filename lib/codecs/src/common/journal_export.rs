@@ -0,0 +1,252 @@
+//! Support for systemd's [Journal Export Format][export].
+//!
+//! An entry is a sequence of fields terminated by an empty line. Text fields are written as
+//! `NAME=value\n`, while fields whose value may contain newlines or other binary data are written
+//! as `NAME\n`, followed by the value length as a little-endian 64-bit integer, the raw value and
+//! a final `\n`.
+//!
+//! [export]: https://systemd.io/JOURNAL_EXPORT_FORMATS/#journal-export-format
+
+use std::ops::Range;
+
+use bytes::BufMut;
+use memchr::memchr;
+
+/// The size of the little-endian length prefix of a binary field value.
+pub const BINARY_LENGTH_SIZE: usize = 8;
+
+/// The maximum length of a field name accepted by journald.
+pub const MAX_FIELD_NAME_LENGTH: usize = 64;
+
+/// A single field parsed out of an entry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Field {
+    /// The range of the field name.
+    pub name: Range<usize>,
+    /// The range of the field value.
+    pub value: Range<usize>,
+    /// The position just past the end of the field, including its trailing newline.
+    pub end: usize,
+}
+
+/// The result of parsing the next field of an entry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParsedField {
+    /// A complete field.
+    Field(Field),
+    /// The empty line terminating the entry.
+    EndOfEntry,
+    /// A field whose length is not known yet, because its first line or the length of its binary
+    /// value is incomplete.
+    Incomplete,
+    /// A binary field whose header was parsed, but whose value is not fully available yet.
+    IncompleteBinary {
+        /// The range of the field name.
+        name: Range<usize>,
+        /// The position of the first byte of the value.
+        value_start: usize,
+        /// The length of the value, excluding its trailing newline.
+        length: usize,
+    },
+    /// A binary field whose value is not followed by a newline.
+    InvalidBinary {
+        /// The range of the field name.
+        name: Range<usize>,
+    },
+}
+
+/// Parses the field starting at `start` in `buf`.
+pub fn parse_field(buf: &[u8], start: usize) -> ParsedField {
+    let rest = &buf[start..];
+    let Some(newline) = memchr(b'\n', rest) else {
+        return ParsedField::Incomplete;
+    };
+    if newline == 0 {
+        return ParsedField::EndOfEntry;
+    }
+
+    let line = start..start + newline;
+    if let Some(equals) = memchr(b'=', &buf[line.clone()]) {
+        return ParsedField::Field(Field {
+            name: start..start + equals,
+            value: start + equals + 1..line.end,
+            end: line.end + 1,
+        });
+    }
+
+    let name = line.clone();
+    let value_start = line.end + 1 + BINARY_LENGTH_SIZE;
+    let Some(header) = buf.get(line.end + 1..value_start) else {
+        return ParsedField::Incomplete;
+    };
+    let length = u64::from_le_bytes(header.try_into().expect("header has a fixed size"));
+    let length = usize::try_from(length).unwrap_or(usize::MAX);
+    let value_end = value_start.saturating_add(length);
+
+    match buf.get(value_end) {
+        None => ParsedField::IncompleteBinary {
+            name,
+            value_start,
+            length,
+        },
+        Some(b'\n') => ParsedField::Field(Field {
+            name,
+            value: value_start..value_end,
+            end: value_end + 1,
+        }),
+        Some(_) => ParsedField::InvalidBinary { name },
+    }
+}
+
+/// Writes `name` to `output`, mangled into a field name accepted by journald:
+///
+/// - An empty name is replaced with `EMPTY`.
+/// - ASCII letters are uppercased, and any character other than `A`-`Z`, `0`-`9` and `_` is
+///   replaced with an underscore.
+/// - A name starting with a digit is prefixed with `ESC_`. Unless `allow_protected` is set, a
+///   name starting with an underscore, which journald reserves for trusted fields, is prefixed
+///   with `ESC_` as well.
+/// - Only the first 64 characters of the result are used.
+///
+/// # Reference
+///
+/// + [Upstream Validations](https://github.com/systemd/systemd/blob/cf8fd7148cd8fbdb79381202ce8686eed1de09d2/src/libsystemd/sd-journal/journal-file.c#L1703-L1739)
+pub fn write_field_name<B: BufMut>(name: &str, allow_protected: bool, output: &mut B) {
+    let name = name.as_bytes();
+    if name.is_empty() {
+        output.put_slice(b"EMPTY");
+        return;
+    }
+
+    let mut wrote = 0;
+    if !(name[0].is_ascii_alphabetic() || (allow_protected && name[0] == b'_')) {
+        output.put_slice(b"ESC_");
+        wrote += 4;
+    }
+
+    for byte in name.iter().take(MAX_FIELD_NAME_LENGTH) {
+        if byte.is_ascii_alphanumeric() {
+            output.put_u8(byte.to_ascii_uppercase());
+        } else {
+            output.put_u8(b'_');
+        }
+        wrote += 1;
+
+        if wrote >= MAX_FIELD_NAME_LENGTH {
+            break;
+        }
+    }
+}
+
+/// Writes the value of a field whose name was just written to `output`.
+///
+/// Values containing newlines or bytes that are not valid UTF-8 are written in the binary form,
+/// all others as text.
+pub fn write_field_value<B: BufMut>(value: &[u8], output: &mut B) {
+    if value.contains(&b'\n') || std::str::from_utf8(value).is_err() {
+        output.put_u8(b'\n');
+        output.put_u64_le(value.len() as u64);
+    } else {
+        output.put_u8(b'=');
+    }
+    output.put_slice(value);
+    output.put_u8(b'\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_text_field() {
+        let buf = b"MESSAGE=hello=world\n";
+        assert_eq!(
+            parse_field(buf, 0),
+            ParsedField::Field(Field {
+                name: 0..7,
+                value: 8..19,
+                end: 20,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_binary_field() {
+        let buf = b"MESSAGE\n\x03\x00\x00\x00\x00\x00\x00\x00a\nb\n";
+        assert_eq!(
+            parse_field(buf, 0),
+            ParsedField::Field(Field {
+                name: 0..7,
+                value: 16..19,
+                end: 20,
+            })
+        );
+    }
+
+    #[test]
+    fn detects_incomplete_fields() {
+        assert_eq!(parse_field(b"MESSAGE=hel", 0), ParsedField::Incomplete);
+        assert_eq!(
+            parse_field(b"MESSAGE\n\x03\x00\x00", 0),
+            ParsedField::Incomplete
+        );
+        assert_eq!(
+            parse_field(b"MESSAGE\n\x03\x00\x00\x00\x00\x00\x00\x00a\nb", 0),
+            ParsedField::IncompleteBinary {
+                name: 0..7,
+                value_start: 16,
+                length: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn detects_invalid_binary_field() {
+        assert_eq!(
+            parse_field(b"MESSAGE\n\x01\x00\x00\x00\x00\x00\x00\x00ab\n", 0),
+            ParsedField::InvalidBinary { name: 0..7 }
+        );
+    }
+
+    #[test]
+    fn detects_end_of_entry() {
+        assert_eq!(parse_field(b"A=b\n\n", 4), ParsedField::EndOfEntry);
+    }
+
+    fn field_name(name: &str, allow_protected: bool) -> String {
+        let mut output = Vec::new();
+        write_field_name(name, allow_protected, &mut output);
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn mangles_field_names() {
+        assert_eq!(field_name("", false), "EMPTY");
+        assert_eq!(field_name("message", false), "MESSAGE");
+        assert_eq!(field_name("foo.bar[0]", false), "FOO_BAR_0_");
+        assert_eq!(field_name("1st", false), "ESC_1ST");
+        assert_eq!(field_name("_PID", false), "ESC__PID");
+        assert_eq!(field_name("_PID", true), "_PID");
+        assert_eq!(
+            field_name("__REALTIME_TIMESTAMP", true),
+            "__REALTIME_TIMESTAMP"
+        );
+        assert_eq!(field_name("1st", true), "ESC_1ST");
+        assert_eq!(field_name(&"a".repeat(100), false), "A".repeat(64));
+        assert_eq!(field_name(&"1".repeat(100), false).len(), 64);
+    }
+
+    #[test]
+    fn writes_field_values() {
+        let mut output = Vec::new();
+        write_field_value(b"hello", &mut output);
+        write_field_value(b"a\nb", &mut output);
+        write_field_value(b"\xff", &mut output);
+        assert_eq!(
+            output,
+            b"=hello\n\
+              \n\x03\x00\x00\x00\x00\x00\x00\x00a\nb\n\
+              \n\x01\x00\x00\x00\x00\x00\x00\x00\xff\n"
+        );
+    }
+}
@@ -1,3 +1,4 @@
 //! A collection of common utility features used by both encoding and decoding logic.
 
+pub mod journal_export;
 pub mod length_delimited;
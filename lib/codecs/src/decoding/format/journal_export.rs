@@ -0,0 +1,291 @@
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use lookup::event_path;
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+use vector_core::config::LogNamespace;
+use vector_core::schema::meaning;
+use vector_core::{
+    config::{log_schema, DataType},
+    event::Event,
+    schema,
+};
+use vrl::value::kind::Collection;
+use vrl::value::{Kind, ObjectMap, Value};
+
+use super::Deserializer;
+use crate::common::journal_export::{parse_field, ParsedField};
+
+const MESSAGE: &str = "MESSAGE";
+const SOURCE_TIMESTAMP: &str = "_SOURCE_REALTIME_TIMESTAMP";
+const RECEIVED_TIMESTAMP: &str = "__REALTIME_TIMESTAMP";
+
+/// Config used to build a `JournalExportDeserializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct JournalExportDeserializerConfig;
+
+impl JournalExportDeserializerConfig {
+    /// Build the `JournalExportDeserializer` from this configuration.
+    pub fn build(&self) -> JournalExportDeserializer {
+        JournalExportDeserializer
+    }
+
+    /// Return the type of event build by this deserializer.
+    pub fn output_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema produced by the deserializer.
+    pub fn schema_definition(&self, log_namespace: LogNamespace) -> schema::Definition {
+        let field = Kind::bytes().or_array(Collection::empty().with_unknown(Kind::bytes()));
+        let definition = schema::Definition::new_with_default_metadata(
+            Kind::object(Collection::empty().with_unknown(field)),
+            [log_namespace],
+        );
+        match log_namespace {
+            LogNamespace::Legacy => {
+                let definition = match log_schema().timestamp_key() {
+                    Some(timestamp_key) => {
+                        definition.optional_field(timestamp_key, Kind::timestamp(), None)
+                    }
+                    None => definition,
+                };
+                match log_schema().message_key() {
+                    Some(message_key) => definition.optional_field(
+                        message_key,
+                        Kind::bytes(),
+                        Some(meaning::MESSAGE),
+                    ),
+                    None => definition,
+                }
+            }
+            LogNamespace::Vector => definition.optional_field(
+                &lookup::owned_value_path!(MESSAGE),
+                Kind::bytes(),
+                Some(meaning::MESSAGE),
+            ),
+        }
+    }
+}
+
+/// Deserializer that builds `Event`s from entries in systemd's [Journal Export Format][export].
+///
+/// Each entry becomes a log event holding the entry's fields, with binary-safe values. Fields
+/// that appear more than once in an entry are collected into an array. When using the legacy
+/// namespace, the `MESSAGE` field is moved to the global message key, and the entry's timestamp
+/// is stored in the global timestamp key, mirroring the `journald` source.
+///
+/// A frame may contain several entries separated by empty lines.
+///
+/// [export]: https://systemd.io/JOURNAL_EXPORT_FORMATS/#journal-export-format
+#[derive(Debug, Clone, Default)]
+pub struct JournalExportDeserializer;
+
+impl Deserializer for JournalExportDeserializer {
+    fn parse(
+        &self,
+        bytes: Bytes,
+        log_namespace: LogNamespace,
+    ) -> vector_common::Result<SmallVec<[Event; 1]>> {
+        let mut events = SmallVec::new();
+        let mut fields = ObjectMap::new();
+        let mut position = 0;
+
+        while position < bytes.len() {
+            match parse_field(&bytes, position) {
+                ParsedField::Field(field) => {
+                    insert_field(
+                        &mut fields,
+                        &bytes[field.name.clone()],
+                        bytes.slice(field.value),
+                    )?;
+                    position = field.end;
+                }
+                ParsedField::EndOfEntry => {
+                    if !fields.is_empty() {
+                        events.push(to_event(std::mem::take(&mut fields), log_namespace));
+                    }
+                    position += 1;
+                }
+                // The last text field of a frame does not need to be terminated by a newline.
+                ParsedField::Incomplete => match memchr::memchr(b'=', &bytes[position..]) {
+                    Some(equals) => {
+                        let equals = position + equals;
+                        insert_field(
+                            &mut fields,
+                            &bytes[position..equals],
+                            bytes.slice(equals + 1..),
+                        )?;
+                        position = bytes.len();
+                    }
+                    None => return Err("Journal export field is truncated.".into()),
+                },
+                ParsedField::IncompleteBinary { name, .. } => {
+                    return Err(format!(
+                        "Journal export binary field {:?} is truncated.",
+                        String::from_utf8_lossy(&bytes[name])
+                    )
+                    .into())
+                }
+                ParsedField::InvalidBinary { name } => {
+                    return Err(format!(
+                        "Journal export binary field {:?} is not terminated by a newline.",
+                        String::from_utf8_lossy(&bytes[name])
+                    )
+                    .into())
+                }
+            }
+        }
+
+        if !fields.is_empty() {
+            events.push(to_event(fields, log_namespace));
+        }
+
+        Ok(events)
+    }
+}
+
+fn insert_field(fields: &mut ObjectMap, name: &[u8], value: Bytes) -> vector_common::Result<()> {
+    if name.is_empty() {
+        return Err("Journal export field name is empty.".into());
+    }
+    let name = String::from_utf8_lossy(name);
+    match fields.get_mut(name.as_ref()) {
+        None => {
+            fields.insert(name.into_owned().into(), Value::Bytes(value));
+        }
+        Some(Value::Array(values)) => values.push(Value::Bytes(value)),
+        Some(existing) => {
+            let first = std::mem::replace(existing, Value::Null);
+            *existing = Value::Array(vec![first, Value::Bytes(value)]);
+        }
+    }
+    Ok(())
+}
+
+fn to_event(fields: ObjectMap, log_namespace: LogNamespace) -> Event {
+    let mut log = log_namespace.new_log_from_data(fields);
+
+    if log_namespace == LogNamespace::Legacy {
+        if let Some(message) = log.remove(event_path!(MESSAGE)) {
+            log.maybe_insert(log_schema().message_key_target_path(), message);
+        }
+
+        let timestamp = log
+            .get(event_path!(SOURCE_TIMESTAMP))
+            .or_else(|| log.get(event_path!(RECEIVED_TIMESTAMP)))
+            .and_then(parse_timestamp);
+        if let Some(timestamp) = timestamp {
+            log.maybe_insert(log_schema().timestamp_key_target_path(), timestamp);
+        }
+    }
+
+    log.into()
+}
+
+/// Parses a journal timestamp, given in microseconds since the Unix epoch.
+fn parse_timestamp(value: &Value) -> Option<DateTime<Utc>> {
+    let micros = std::str::from_utf8(value.as_bytes()?).ok()?.parse().ok()?;
+    DateTime::from_timestamp_micros(micros)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENTRIES: &[u8] = b"__REALTIME_TIMESTAMP=1791944996386616\n\
+        MESSAGE\n\x0c\x00\x00\x00\x00\x00\x00\x00hello\n\nworld\n\
+        REPEATED=one\nREPEATED=two\nREPEATED=three\n\
+        \n\
+        MESSAGE=second\n_SOURCE_REALTIME_TIMESTAMP=1791944996385029\n\
+        __REALTIME_TIMESTAMP=1791944996386616";
+
+    fn parse(bytes: &'static [u8], log_namespace: LogNamespace) -> SmallVec<[Event; 1]> {
+        JournalExportDeserializer
+            .parse(Bytes::from_static(bytes), log_namespace)
+            .unwrap()
+    }
+
+    #[test]
+    fn deserialize_legacy_namespace() {
+        let events = parse(ENTRIES, LogNamespace::Legacy);
+        assert_eq!(events.len(), 2);
+
+        let log = events[0].as_log();
+        assert_eq!(*log.get_message().unwrap(), "hello\n\nworld".into());
+        assert_eq!(log.get(event_path!(MESSAGE)), None);
+        assert_eq!(
+            log.get(event_path!("REPEATED")),
+            Some(&Value::Array(vec![
+                "one".into(),
+                "two".into(),
+                "three".into()
+            ]))
+        );
+        assert_eq!(
+            log.get_timestamp(),
+            Some(&Value::from(
+                DateTime::from_timestamp_micros(1791944996386616).unwrap()
+            ))
+        );
+
+        // The source timestamp takes precedence over the reception timestamp.
+        let log = events[1].as_log();
+        assert_eq!(*log.get_message().unwrap(), "second".into());
+        assert_eq!(
+            log.get_timestamp(),
+            Some(&Value::from(
+                DateTime::from_timestamp_micros(1791944996385029).unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn deserialize_vector_namespace() {
+        let events = parse(ENTRIES, LogNamespace::Vector);
+        assert_eq!(events.len(), 2);
+
+        let log = events[0].as_log();
+        assert_eq!(
+            log.get(event_path!(MESSAGE)),
+            Some(&Value::from("hello\n\nworld"))
+        );
+        assert_eq!(
+            log.get(event_path!(RECEIVED_TIMESTAMP)),
+            Some(&Value::from("1791944996386616"))
+        );
+        assert_eq!(log.get(event_path!("timestamp")), None);
+    }
+
+    #[test]
+    fn deserialize_binary_values() {
+        let events = parse(
+            b"DATA\n\x05\x00\x00\x00\x00\x00\x00\x00\x00\x01\x02\xff\n\n",
+            LogNamespace::Vector,
+        );
+        assert_eq!(
+            events[0].as_log().get(event_path!("DATA")),
+            Some(&Value::Bytes(Bytes::from_static(b"\x00\x01\x02\xff\n")))
+        );
+    }
+
+    #[test]
+    fn deserialize_empty_frame() {
+        assert!(parse(b"", LogNamespace::Legacy).is_empty());
+        assert!(parse(b"\n\n", LogNamespace::Legacy).is_empty());
+    }
+
+    #[test]
+    fn deserialize_invalid_entries() {
+        for input in [
+            &b"MESSAGE\n\x05\x00\x00\x00\x00\x00\x00\x00abc"[..],
+            &b"MESSAGE\n\x01\x00\x00\x00\x00\x00\x00\x00abc\n"[..],
+            &b"MESSAGE=hello\nTRUNCATED"[..],
+            &b"=value\n"[..],
+        ] {
+            assert!(JournalExportDeserializer
+                .parse(Bytes::from_static(input), LogNamespace::Legacy)
+                .is_err());
+        }
+    }
+}
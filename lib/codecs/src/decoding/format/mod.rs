@@ -7,6 +7,7 @@ mod avro;
 mod bytes;
 mod gelf;
 mod influxdb;
+mod journal_export;
 mod json;
 mod native;
 mod native_json;
@@ -20,6 +21,7 @@ pub use avro::{AvroDeserializer, AvroDeserializerConfig, AvroDeserializerOptions
 use dyn_clone::DynClone;
 pub use gelf::{GelfDeserializer, GelfDeserializerConfig, GelfDeserializerOptions};
 pub use influxdb::{InfluxdbDeserializer, InfluxdbDeserializerConfig};
+pub use journal_export::{JournalExportDeserializer, JournalExportDeserializerConfig};
 pub use json::{JsonDeserializer, JsonDeserializerConfig, JsonDeserializerOptions};
 pub use native::{NativeDeserializer, NativeDeserializerConfig};
 pub use native_json::{
@@ -0,0 +1,412 @@
+use std::any::Any;
+
+use bytes::{Buf, Bytes, BytesMut};
+use memchr::memchr;
+use snafu::Snafu;
+use tokio_util::codec::Decoder;
+use tracing::warn;
+use vector_config::configurable_component;
+
+use super::{BoxedFramingError, FramingError};
+use crate::common::journal_export::{parse_field, ParsedField, MAX_FIELD_NAME_LENGTH};
+use crate::StreamDecodingError;
+
+/// Config used to build a `JournalExportDecoder`.
+#[configurable_component]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JournalExportDecoderConfig {
+    /// Options for the journal export decoder.
+    #[serde(default, skip_serializing_if = "vector_core::serde::is_default")]
+    pub journal_export: JournalExportDecoderOptions,
+}
+
+/// Options for building a `JournalExportDecoder`.
+#[configurable_component]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct JournalExportDecoderOptions {
+    /// The maximum length of an entry, in bytes.
+    ///
+    /// This length does *not* include the empty line terminating the entry.
+    ///
+    /// By default, there is no maximum length enforced. Binary field values carry their own length,
+    /// so malformed input can lead to large amounts of data being buffered in memory. Entries
+    /// larger than the maximum length are discarded.
+    #[serde(skip_serializing_if = "vector_core::serde::is_default")]
+    pub max_length: Option<usize>,
+}
+
+impl JournalExportDecoderConfig {
+    /// Creates a new `JournalExportDecoderConfig`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Build the `JournalExportDecoder` from this configuration.
+    pub const fn build(&self) -> JournalExportDecoder {
+        JournalExportDecoder::new(self.journal_export.max_length)
+    }
+}
+
+/// An error that occurred while framing entries in the Journal Export Format.
+#[derive(Debug, Snafu, PartialEq, Eq)]
+pub enum JournalExportDecoderError {
+    /// A binary field value is not followed by a newline, so its length cannot be trusted.
+    #[snafu(display(
+        "Binary field {name:?} is not terminated by a newline, discarding the entry"
+    ))]
+    InvalidBinaryField {
+        /// The name of the field.
+        name: String,
+    },
+    /// The stream ended before the last entry was complete.
+    #[snafu(display("Stream ended in the middle of an entry, discarding {length} bytes"))]
+    TruncatedEntry {
+        /// The number of buffered bytes of the entry.
+        length: usize,
+    },
+}
+
+impl StreamDecodingError for JournalExportDecoderError {
+    fn can_continue(&self) -> bool {
+        true
+    }
+}
+
+impl FramingError for JournalExportDecoderError {
+    fn as_any(&self) -> &dyn Any {
+        self as &dyn Any
+    }
+}
+
+/// A codec for handling entries in systemd's [Journal Export Format][export].
+///
+/// Each frame holds the fields of a single entry, without the empty line terminating it.
+///
+/// [export]: https://systemd.io/JOURNAL_EXPORT_FORMATS/#journal-export-format
+#[derive(Debug, Clone)]
+pub struct JournalExportDecoder {
+    max_length: Option<usize>,
+    /// The position up to which the fields of the current entry have been parsed.
+    offset: usize,
+    /// Whether the current entry is being discarded.
+    ///
+    /// While discarding, complete fields are dropped from the buffer as soon as they are parsed,
+    /// so `offset` stays at zero.
+    discarding: bool,
+    /// The number of bytes of a discarded binary value that still have to be skipped.
+    skip: usize,
+    /// Whether the remainder of a discarded text line still has to be skipped.
+    skip_line: bool,
+}
+
+impl JournalExportDecoder {
+    /// Creates a new `JournalExportDecoder`, with an optional maximum entry length.
+    pub const fn new(max_length: Option<usize>) -> Self {
+        Self {
+            max_length,
+            offset: 0,
+            discarding: false,
+            skip: 0,
+            skip_line: false,
+        }
+    }
+
+    fn exceeds_max_length(&self, length: usize) -> bool {
+        self.max_length
+            .is_some_and(|max_length| length > max_length)
+    }
+
+    fn start_discarding(&mut self, buf: &mut BytesMut) {
+        warn!(
+            message = "Discarding entry larger than max_length.",
+            max_length = self.max_length,
+            internal_log_rate_limit = true
+        );
+        buf.advance(self.offset);
+        self.offset = 0;
+        self.discarding = true;
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new(self.max_length);
+    }
+}
+
+impl Default for JournalExportDecoder {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl Decoder for JournalExportDecoder {
+    type Item = Bytes;
+    type Error = BoxedFramingError;
+
+    fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        loop {
+            if self.skip > 0 {
+                let length = self.skip.min(buf.len());
+                buf.advance(length);
+                self.skip -= length;
+                if self.skip > 0 {
+                    return Ok(None);
+                }
+            }
+
+            if self.skip_line {
+                match memchr(b'\n', buf) {
+                    Some(newline) => {
+                        buf.advance(newline + 1);
+                        self.skip_line = false;
+                    }
+                    None => {
+                        buf.clear();
+                        return Ok(None);
+                    }
+                }
+            }
+
+            match parse_field(buf, self.offset) {
+                ParsedField::EndOfEntry => {
+                    let entry = buf.split_to(self.offset).freeze();
+                    buf.advance(1);
+                    self.offset = 0;
+                    // Consecutive empty lines do not delimit empty entries.
+                    if !std::mem::take(&mut self.discarding) && !entry.is_empty() {
+                        return Ok(Some(entry));
+                    }
+                }
+                ParsedField::Field(field) => {
+                    if self.discarding {
+                        buf.advance(field.end);
+                    } else if self.exceeds_max_length(field.end) {
+                        self.start_discarding(buf);
+                    } else {
+                        self.offset = field.end;
+                    }
+                }
+                ParsedField::Incomplete => {
+                    if self.discarding {
+                        // A line without `=` could still be the name of a binary field, whose
+                        // value must be skipped according to its length rather than up to the
+                        // next newline. Field names are short, so wait for the rest of the line.
+                        let line = &buf[..];
+                        if memchr(b'\n', line).is_none()
+                            && (memchr(b'=', line).is_some() || line.len() > MAX_FIELD_NAME_LENGTH)
+                        {
+                            buf.clear();
+                            self.skip_line = true;
+                        }
+                        return Ok(None);
+                    } else if self.exceeds_max_length(buf.len()) {
+                        self.start_discarding(buf);
+                    } else {
+                        return Ok(None);
+                    }
+                }
+                ParsedField::IncompleteBinary {
+                    value_start,
+                    length,
+                    ..
+                } => {
+                    if self.discarding {
+                        buf.advance(value_start);
+                        self.skip = length.saturating_add(1);
+                    } else if self
+                        .exceeds_max_length(value_start.saturating_add(length).saturating_add(1))
+                    {
+                        self.start_discarding(buf);
+                    } else {
+                        return Ok(None);
+                    }
+                }
+                ParsedField::InvalidBinary { name } => {
+                    let was_discarding = std::mem::replace(&mut self.discarding, true);
+                    let error = JournalExportDecoderError::InvalidBinaryField {
+                        name: String::from_utf8_lossy(&buf[name.clone()]).into_owned(),
+                    };
+                    // The length of the value cannot be trusted, so skip up to the end of the
+                    // line following the field name and treat whatever follows as part of the
+                    // discarded entry.
+                    buf.advance(name.end + 1);
+                    self.offset = 0;
+                    self.skip_line = true;
+                    if !was_discarding {
+                        return Err(error.into());
+                    }
+                }
+            }
+        }
+    }
+
+    fn decode_eof(&mut self, buf: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        if let Some(entry) = self.decode(buf)? {
+            return Ok(Some(entry));
+        }
+        if buf.is_empty() {
+            self.reset();
+            return Ok(None);
+        }
+        if self.discarding || self.skip > 0 || self.skip_line {
+            buf.clear();
+            self.reset();
+            return Ok(None);
+        }
+
+        // The last entry of a stream does not need to be terminated by an empty line, and its
+        // last text field does not need to be terminated by a newline.
+        let rest = &buf[self.offset..];
+        let complete =
+            rest.is_empty() || (memchr(b'\n', rest).is_none() && memchr(b'=', rest).is_some());
+        let length = buf.len();
+        self.reset();
+        if complete && !self.exceeds_max_length(length) {
+            Ok(Some(buf.split().freeze()))
+        } else {
+            buf.clear();
+            Err(JournalExportDecoderError::TruncatedEntry { length }.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BINARY_ENTRY: &[u8] =
+        b"__CURSOR=s=1\nMESSAGE\n\x0c\x00\x00\x00\x00\x00\x00\x00hello\n\nworld\n_PID=42\n";
+
+    fn downcast(error: &BoxedFramingError) -> &JournalExportDecoderError {
+        error
+            .as_any()
+            .downcast_ref::<JournalExportDecoderError>()
+            .expect("Expected JournalExportDecoderError to be downcasted")
+    }
+
+    #[test]
+    fn decode_entries() {
+        let mut input = BytesMut::from("A=1\nB=2\n\nA=3\n\n");
+        let mut decoder = JournalExportDecoder::default();
+
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "A=1\nB=2\n");
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "A=3\n");
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn decode_binary_entry() {
+        let mut input = BytesMut::from(BINARY_ENTRY);
+        input.extend_from_slice(b"\nA=1\n\n");
+        let mut decoder = JournalExportDecoder::default();
+
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), BINARY_ENTRY);
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "A=1\n");
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+    }
+
+    #[test]
+    fn decode_entry_in_pieces() {
+        let mut decoder = JournalExportDecoder::default();
+        let mut input = BytesMut::new();
+
+        for byte in BINARY_ENTRY {
+            input.extend_from_slice(&[*byte]);
+            assert_eq!(decoder.decode(&mut input).unwrap(), None);
+        }
+        input.extend_from_slice(b"\n");
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), BINARY_ENTRY);
+    }
+
+    #[test]
+    fn skip_empty_lines() {
+        let mut input = BytesMut::from("\n\nA=1\n\n\n");
+        let mut decoder = JournalExportDecoder::default();
+
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "A=1\n");
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn discard_entries_exceeding_max_length() {
+        let mut input = BytesMut::from(BINARY_ENTRY);
+        input.extend_from_slice(b"\nA=1\n\nB=0123456789\nC=1\n\nD=1\n\n");
+        let mut decoder = JournalExportDecoder::new(Some(16));
+
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "A=1\n");
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "D=1\n");
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+    }
+
+    #[test]
+    fn discard_large_binary_value_without_buffering() {
+        let mut decoder = JournalExportDecoder::new(Some(16));
+        let mut input = BytesMut::from(&b"MESSAGE\n\x00\x00\x10\x00\x00\x00\x00\x00"[..]);
+
+        assert_eq!(decoder.decode(&mut input).unwrap(), None);
+        for _ in 0..16 {
+            input.extend_from_slice(&[b'x'; 0x10000]);
+            assert_eq!(decoder.decode(&mut input).unwrap(), None);
+            assert!(input.is_empty());
+        }
+        input.extend_from_slice(b"\n\nA=1\n\n");
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "A=1\n");
+    }
+
+    #[test]
+    fn discard_large_text_line_without_buffering() {
+        let mut decoder = JournalExportDecoder::new(Some(16));
+        let mut input = BytesMut::from("MESSAGE=");
+
+        for _ in 0..16 {
+            input.extend_from_slice(&[b'x'; 1024]);
+            assert_eq!(decoder.decode(&mut input).unwrap(), None);
+            assert!(input.len() <= 1024 + 8);
+        }
+        input.extend_from_slice(b"\n_PID=1\n\nA=1\n\n");
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "A=1\n");
+    }
+
+    #[test]
+    fn discard_invalid_binary_field() {
+        let mut input = BytesMut::from(
+            &b"A=1\nMESSAGE\n\x01\x00\x00\x00\x00\x00\x00\x00ab\nB=1\n\nC=1\n\n"[..],
+        );
+        let mut decoder = JournalExportDecoder::default();
+
+        let error = decoder.decode(&mut input).unwrap_err();
+        assert_eq!(
+            downcast(&error),
+            &JournalExportDecoderError::InvalidBinaryField {
+                name: "MESSAGE".into()
+            }
+        );
+        assert!(error.can_continue());
+        assert_eq!(decoder.decode(&mut input).unwrap().unwrap(), "C=1\n");
+    }
+
+    #[test]
+    fn decode_eof_unterminated_entry() {
+        let mut input = BytesMut::from("A=1\n\nB=2\nC=3");
+        let mut decoder = JournalExportDecoder::default();
+
+        assert_eq!(decoder.decode_eof(&mut input).unwrap().unwrap(), "A=1\n");
+        assert_eq!(decoder.decode_eof(&mut input).unwrap().unwrap(), "B=2\nC=3");
+        assert_eq!(decoder.decode_eof(&mut input).unwrap(), None);
+    }
+
+    #[test]
+    fn decode_eof_truncated_binary_field() {
+        let mut input = BytesMut::from(&b"A=1\nMESSAGE\n\x05\x00\x00\x00\x00\x00\x00\x00ab"[..]);
+        let mut decoder = JournalExportDecoder::default();
+
+        let error = decoder.decode_eof(&mut input).unwrap_err();
+        assert_eq!(
+            downcast(&error),
+            &JournalExportDecoderError::TruncatedEntry { length: 22 }
+        );
+        assert!(input.is_empty());
+        assert_eq!(decoder.decode_eof(&mut input).unwrap(), None);
+    }
+}
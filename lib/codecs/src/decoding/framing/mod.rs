@@ -6,6 +6,7 @@
 mod bytes;
 mod character_delimited;
 mod chunked_gelf;
+mod journal_export;
 mod length_delimited;
 mod newline_delimited;
 mod octet_counting;
@@ -18,6 +19,9 @@ pub use character_delimited::{
 };
 pub use chunked_gelf::{ChunkedGelfDecoder, ChunkedGelfDecoderConfig, ChunkedGelfDecoderOptions};
 use dyn_clone::DynClone;
+pub use journal_export::{
+    JournalExportDecoder, JournalExportDecoderConfig, JournalExportDecoderOptions,
+};
 pub use length_delimited::{LengthDelimitedDecoder, LengthDelimitedDecoderConfig};
 pub use newline_delimited::{
     NewlineDelimitedDecoder, NewlineDelimitedDecoderConfig, NewlineDelimitedDecoderOptions,
@@ -11,17 +11,19 @@ pub use error::StreamDecodingError;
 pub use format::{
     BoxedDeserializer, BytesDeserializer, BytesDeserializerConfig, GelfDeserializer,
     GelfDeserializerConfig, GelfDeserializerOptions, InfluxdbDeserializer,
-    InfluxdbDeserializerConfig, JsonDeserializer, JsonDeserializerConfig, JsonDeserializerOptions,
-    NativeDeserializer, NativeDeserializerConfig, NativeJsonDeserializer,
-    NativeJsonDeserializerConfig, NativeJsonDeserializerOptions, ProtobufDeserializer,
-    ProtobufDeserializerConfig, ProtobufDeserializerOptions,
+    InfluxdbDeserializerConfig, JournalExportDeserializer, JournalExportDeserializerConfig,
+    JsonDeserializer, JsonDeserializerConfig, JsonDeserializerOptions, NativeDeserializer,
+    NativeDeserializerConfig, NativeJsonDeserializer, NativeJsonDeserializerConfig,
+    NativeJsonDeserializerOptions, ProtobufDeserializer, ProtobufDeserializerConfig,
+    ProtobufDeserializerOptions,
 };
 #[cfg(feature = "syslog")]
 pub use format::{SyslogDeserializer, SyslogDeserializerConfig, SyslogDeserializerOptions};
 pub use framing::{
     BoxedFramer, BoxedFramingError, BytesDecoder, BytesDecoderConfig, CharacterDelimitedDecoder,
     CharacterDelimitedDecoderConfig, CharacterDelimitedDecoderOptions, ChunkedGelfDecoder,
-    ChunkedGelfDecoderConfig, ChunkedGelfDecoderOptions, FramingError, LengthDelimitedDecoder,
+    ChunkedGelfDecoderConfig, ChunkedGelfDecoderOptions, FramingError, JournalExportDecoder,
+    JournalExportDecoderConfig, JournalExportDecoderOptions, LengthDelimitedDecoder,
     LengthDelimitedDecoderConfig, NewlineDelimitedDecoder, NewlineDelimitedDecoderConfig,
     NewlineDelimitedDecoderOptions, OctetCountingDecoder, OctetCountingDecoderConfig,
     OctetCountingDecoderOptions,
@@ -105,6 +107,11 @@ pub enum FramingConfig {
     ///
     /// [chunked_gelf]: https://go2docs.graylog.org/current/getting_in_log_data/gelf.html
     ChunkedGelf(ChunkedGelfDecoderConfig),
+
+    /// Byte frames which are entries in systemd's [Journal Export Format][export].
+    ///
+    /// [export]: https://systemd.io/JOURNAL_EXPORT_FORMATS/#journal-export-format
+    JournalExport(JournalExportDecoderConfig),
 }
 
 impl From<BytesDecoderConfig> for FramingConfig {
@@ -143,6 +150,12 @@ impl From<ChunkedGelfDecoderConfig> for FramingConfig {
     }
 }
 
+impl From<JournalExportDecoderConfig> for FramingConfig {
+    fn from(config: JournalExportDecoderConfig) -> Self {
+        Self::JournalExport(config)
+    }
+}
+
 impl FramingConfig {
     /// Build the `Framer` from this configuration.
     pub fn build(&self) -> Framer {
@@ -153,6 +166,7 @@ impl FramingConfig {
             FramingConfig::NewlineDelimited(config) => Framer::NewlineDelimited(config.build()),
             FramingConfig::OctetCounting(config) => Framer::OctetCounting(config.build()),
             FramingConfig::ChunkedGelf(config) => Framer::ChunkedGelf(config.build()),
+            FramingConfig::JournalExport(config) => Framer::JournalExport(config.build()),
         }
     }
 }
@@ -174,6 +188,8 @@ pub enum Framer {
     Boxed(BoxedFramer),
     /// Uses a `ChunkedGelfDecoder` for framing.
     ChunkedGelf(ChunkedGelfDecoder),
+    /// Uses a `JournalExportDecoder` for framing.
+    JournalExport(JournalExportDecoder),
 }
 
 impl tokio_util::codec::Decoder for Framer {
@@ -189,6 +205,7 @@ impl tokio_util::codec::Decoder for Framer {
             Framer::OctetCounting(framer) => framer.decode(src),
             Framer::Boxed(framer) => framer.decode(src),
             Framer::ChunkedGelf(framer) => framer.decode(src),
+            Framer::JournalExport(framer) => framer.decode(src),
         }
     }
 
@@ -201,6 +218,7 @@ impl tokio_util::codec::Decoder for Framer {
             Framer::OctetCounting(framer) => framer.decode_eof(src),
             Framer::Boxed(framer) => framer.decode_eof(src),
             Framer::ChunkedGelf(framer) => framer.decode_eof(src),
+            Framer::JournalExport(framer) => framer.decode_eof(src),
         }
     }
 }
@@ -287,6 +305,14 @@ pub enum DeserializerConfig {
     ///
     /// [vrl]: https://vector.dev/docs/reference/vrl
     Vrl(VrlDeserializerConfig),
+
+    /// Decodes the raw bytes as entries in systemd's [Journal Export Format][export].
+    ///
+    /// Each entry produces one event. Values are kept as raw bytes, so binary field values are
+    /// preserved. Fields that appear more than once in an entry are collected into an array.
+    ///
+    /// [export]: https://systemd.io/JOURNAL_EXPORT_FORMATS/#journal-export-format
+    JournalExport,
 }
 
 impl From<BytesDeserializerConfig> for DeserializerConfig {
@@ -332,6 +358,12 @@ impl From<InfluxdbDeserializerConfig> for DeserializerConfig {
     }
 }
 
+impl From<JournalExportDeserializerConfig> for DeserializerConfig {
+    fn from(_: JournalExportDeserializerConfig) -> Self {
+        Self::JournalExport
+    }
+}
+
 impl DeserializerConfig {
     /// Build the `Deserializer` from this configuration.
     pub fn build(&self) -> vector_common::Result<Deserializer> {
@@ -354,6 +386,9 @@ impl DeserializerConfig {
             DeserializerConfig::Gelf(config) => Ok(Deserializer::Gelf(config.build())),
             DeserializerConfig::Influxdb(config) => Ok(Deserializer::Influxdb(config.build())),
             DeserializerConfig::Vrl(config) => Ok(Deserializer::Vrl(config.build()?)),
+            DeserializerConfig::JournalExport => Ok(Deserializer::JournalExport(
+                JournalExportDeserializerConfig.build(),
+            )),
         }
     }
 
@@ -375,6 +410,7 @@ impl DeserializerConfig {
             DeserializerConfig::Gelf(_) => {
                 FramingConfig::CharacterDelimited(CharacterDelimitedDecoderConfig::new(0))
             }
+            DeserializerConfig::JournalExport => FramingConfig::JournalExport(Default::default()),
         }
     }
 
@@ -403,6 +439,7 @@ impl DeserializerConfig {
             DeserializerConfig::Gelf(config) => config.output_type(),
             DeserializerConfig::Vrl(config) => config.output_type(),
             DeserializerConfig::Influxdb(config) => config.output_type(),
+            DeserializerConfig::JournalExport => JournalExportDeserializerConfig.output_type(),
         }
     }
 
@@ -423,6 +460,9 @@ impl DeserializerConfig {
             DeserializerConfig::Gelf(config) => config.schema_definition(log_namespace),
             DeserializerConfig::Influxdb(config) => config.schema_definition(log_namespace),
             DeserializerConfig::Vrl(config) => config.schema_definition(log_namespace),
+            DeserializerConfig::JournalExport => {
+                JournalExportDeserializerConfig.schema_definition(log_namespace)
+            }
         }
     }
 
@@ -449,6 +489,7 @@ impl DeserializerConfig {
                 "application/octet-stream"
             }
             (DeserializerConfig::Protobuf(_), _) => "application/octet-stream",
+            (DeserializerConfig::JournalExport, _) => "application/vnd.fdo.journal",
             (
                 DeserializerConfig::Json(_)
                 | DeserializerConfig::NativeJson(_)
@@ -490,6 +531,8 @@ pub enum Deserializer {
     Influxdb(InfluxdbDeserializer),
     /// Uses a `VrlDeserializer` for deserialization.
     Vrl(VrlDeserializer),
+    /// Uses a `JournalExportDeserializer` for deserialization.
+    JournalExport(JournalExportDeserializer),
 }
 
 impl format::Deserializer for Deserializer {
@@ -511,6 +554,7 @@ impl format::Deserializer for Deserializer {
             Deserializer::Gelf(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Influxdb(deserializer) => deserializer.parse(bytes, log_namespace),
             Deserializer::Vrl(deserializer) => deserializer.parse(bytes, log_namespace),
+            Deserializer::JournalExport(deserializer) => deserializer.parse(bytes, log_namespace),
         }
     }
 }
@@ -0,0 +1,210 @@
+use bytes::{BufMut, BytesMut};
+use lookup::event_path;
+use serde::{Deserialize, Serialize};
+use tokio_util::codec::Encoder;
+use vector_core::{
+    config::{log_schema, DataType, LogNamespace},
+    event::{Event, LogEvent, Value},
+    schema,
+};
+
+use crate::common::journal_export::{write_field_name, write_field_value};
+
+const MESSAGE: &str = "MESSAGE";
+const REALTIME_TIMESTAMP: &str = "__REALTIME_TIMESTAMP";
+
+/// Config used to build a `JournalExportSerializer`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct JournalExportSerializerConfig;
+
+impl JournalExportSerializerConfig {
+    /// Creates a new `JournalExportSerializerConfig`.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Build the `JournalExportSerializer` from this configuration.
+    pub const fn build(&self) -> JournalExportSerializer {
+        JournalExportSerializer
+    }
+
+    /// The data type of events that are accepted by `JournalExportSerializer`.
+    pub fn input_type(&self) -> DataType {
+        DataType::Log
+    }
+
+    /// The schema required by the serializer.
+    pub fn schema_requirement(&self) -> schema::Requirement {
+        schema::Requirement::empty()
+    }
+}
+
+/// Serializer that converts an `Event` to an entry in systemd's [Journal Export Format][export].
+///
+/// Field names are mangled the same way as in the `journald` sink, except that fields starting
+/// with an underscore are kept as is, so that entries read from the journal round-trip. Arrays
+/// are written as repeated fields, and nested fields are flattened. When the event has a
+/// timestamp and no `__REALTIME_TIMESTAMP` field, the timestamp is written to that field.
+///
+/// Each entry is terminated by an empty line, so entries can be concatenated without framing.
+///
+/// [export]: https://systemd.io/JOURNAL_EXPORT_FORMATS/#journal-export-format
+#[derive(Debug, Clone)]
+pub struct JournalExportSerializer;
+
+impl Encoder<Event> for JournalExportSerializer {
+    type Error = vector_common::Error;
+
+    fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
+        let log = event.into_log();
+
+        // With the legacy namespace, the timestamp lives among the fields and is written as
+        // `__REALTIME_TIMESTAMP` instead.
+        let timestamp_key = match log.namespace() {
+            LogNamespace::Legacy => log_schema().timestamp_key().map(ToString::to_string),
+            LogNamespace::Vector => None,
+        };
+
+        match log.value() {
+            Value::Object(fields) => {
+                for (name, value) in fields {
+                    if timestamp_key.as_deref() == Some(name.as_str())
+                        && matches!(value, Value::Timestamp(_))
+                    {
+                        continue;
+                    }
+                    write_field(name.as_str(), value, buffer);
+                }
+            }
+            value => write_field(MESSAGE, value, buffer),
+        }
+
+        if let Some(timestamp) = realtime_timestamp(&log) {
+            write_field_name(REALTIME_TIMESTAMP, true, buffer);
+            write_field_value(timestamp.to_string().as_bytes(), buffer);
+        }
+
+        buffer.put_u8(b'\n');
+        Ok(())
+    }
+}
+
+/// The event timestamp in microseconds, unless the event already has a `__REALTIME_TIMESTAMP`.
+fn realtime_timestamp(log: &LogEvent) -> Option<i64> {
+    if log.contains(event_path!(REALTIME_TIMESTAMP)) {
+        return None;
+    }
+    match log.get_timestamp()? {
+        Value::Timestamp(timestamp) => Some(timestamp.timestamp_micros()),
+        _ => None,
+    }
+}
+
+fn write_field(name: &str, value: &Value, buffer: &mut BytesMut) {
+    match value {
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                write_field(&format!("{name}_{key}"), value, buffer);
+            }
+        }
+        Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                match value {
+                    Value::Object(_) | Value::Array(_) => {
+                        write_field(&format!("{name}_{index}"), value, buffer)
+                    }
+                    value => write_field(name, value, buffer),
+                }
+            }
+        }
+        value => {
+            write_field_name(name, true, buffer);
+            match value {
+                Value::Bytes(bytes) => write_field_value(bytes, buffer),
+                Value::Regex(regex) => write_field_value(regex.as_bytes_slice(), buffer),
+                Value::Null => write_field_value(b"<NULL>", buffer),
+                value => write_field_value(value.to_string_lossy().as_bytes(), buffer),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+    use chrono::{TimeZone, Utc};
+    use vector_core::event::{LogEvent, Value};
+    use vrl::btreemap;
+
+    use super::*;
+
+    fn serialize(event: Event) -> BytesMut {
+        let mut buffer = BytesMut::new();
+        JournalExportSerializer.encode(event, &mut buffer).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn serialize_fields() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "MESSAGE" => "hello",
+            "count" => 3,
+            "_PID" => "42",
+            "tags" => Value::Array(vec!["a".into(), "b".into()]),
+            "nested" => btreemap! { "key" => "value" },
+        }));
+
+        assert_eq!(
+            serialize(event),
+            &b"MESSAGE=hello\n\
+               _PID=42\n\
+               COUNT=3\n\
+               NESTED_KEY=value\n\
+               TAGS=a\n\
+               TAGS=b\n\
+               \n"[..]
+        );
+    }
+
+    #[test]
+    fn serialize_binary_values() {
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "MESSAGE" => "a\nb",
+            "DATA" => Value::Bytes(bytes::Bytes::from_static(b"\x00\xff")),
+        }));
+
+        assert_eq!(
+            serialize(event),
+            &b"DATA\n\x02\x00\x00\x00\x00\x00\x00\x00\x00\xff\n\
+               MESSAGE\n\x03\x00\x00\x00\x00\x00\x00\x00a\nb\n\
+               \n"[..]
+        );
+    }
+
+    #[test]
+    fn serialize_timestamp() {
+        let timestamp = Utc.timestamp_opt(1_700_000_000, 123_456_000).unwrap();
+        let mut log = LogEvent::from("hello");
+        log.insert(log_schema().timestamp_key_target_path().unwrap(), timestamp);
+
+        assert_eq!(
+            serialize(Event::Log(log)),
+            &b"MESSAGE=hello\n__REALTIME_TIMESTAMP=1700000000123456\n\n"[..]
+        );
+    }
+
+    #[test]
+    fn serialize_keeps_existing_realtime_timestamp() {
+        let timestamp = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let mut log = LogEvent::from(btreemap! {
+            "MESSAGE" => "hello",
+            "__REALTIME_TIMESTAMP" => "1",
+        });
+        log.insert(log_schema().timestamp_key_target_path().unwrap(), timestamp);
+
+        assert_eq!(
+            serialize(Event::Log(log)),
+            &b"MESSAGE=hello\n__REALTIME_TIMESTAMP=1\n\n"[..]
+        );
+    }
+}
@@ -1,8 +1,28 @@
-use bytes::BytesMut;
+use bytes::{BufMut, BytesMut};
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 use tokio_util::codec::Encoder;
-use vector_common::encode_logfmt;
-use vector_core::{config::DataType, event::Event, schema};
+use vector_common::{
+    encode_logfmt,
+    internal_event::{
+        register, InternalEventHandle as _, KeyInternerLookups, KeyLookups, Registered,
+    },
+};
+use vector_core::{
+    config::DataType,
+    event::{
+        util::log::{ArrayIndices, FlattenOptions, FlattenedFields, KeyInterner},
+        Event, Value,
+    },
+    schema,
+};
+
+/// How the paths of nested fields are rendered, as in `a.b.0`.
+const FLATTEN_OPTIONS: FlattenOptions = FlattenOptions {
+    separator: '.',
+    array_indices: ArrayIndices::Segments,
+    max_depth: None,
+};
 
 /// Config used to build a `LogfmtSerializer`.
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
@@ -15,8 +35,8 @@ impl LogfmtSerializerConfig {
     }
 
     /// Build the `LogfmtSerializer` from this configuration.
-    pub const fn build(&self) -> LogfmtSerializer {
-        LogfmtSerializer
+    pub fn build(&self) -> LogfmtSerializer {
+        LogfmtSerializer::new()
     }
 
     /// The data type of events that are accepted by `LogfmtSerializer`.
@@ -33,25 +53,106 @@ impl LogfmtSerializerConfig {
 }
 
 /// Serializer that converts an `Event` to bytes using the logfmt format.
-#[derive(Debug, Clone)]
-pub struct LogfmtSerializer;
+///
+/// Nested fields are flattened, and the encoded paths of fields are kept in a bounded cache, so
+/// that the same paths aren't escaped and allocated again for every event.
+#[derive(Clone, Derivative)]
+#[derivative(Debug)]
+pub struct LogfmtSerializer {
+    keys: KeyInterner,
+    #[derivative(Debug = "ignore")]
+    lookups: Registered<KeyInternerLookups>,
+}
+
+impl LogfmtSerializer {
+    /// Creates a new `LogfmtSerializer`.
+    pub fn new() -> Self {
+        Self {
+            keys: KeyInterner::default(),
+            lookups: register(KeyInternerLookups),
+        }
+    }
+}
+
+impl Default for LogfmtSerializer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Encoder<Event> for LogfmtSerializer {
     type Error = vector_common::Error;
 
     fn encode(&mut self, event: Event, buffer: &mut BytesMut) -> Result<(), Self::Error> {
         let log = event.as_log();
-        let string = encode_logfmt::encode_value(log.value())?;
-        buffer.extend_from_slice(string.as_bytes());
+        if !log.value().is_object() {
+            let string = encode_logfmt::encode_value(log.value())?;
+            buffer.extend_from_slice(string.as_bytes());
+            return Ok(());
+        }
+
+        let mut first = true;
+        let mut fields = FlattenedFields::new(log.value(), FLATTEN_OPTIONS);
+        while let Some((path, value)) = fields.next_field() {
+            // Booleans are written as a standalone key when true, and left out when false.
+            if matches!(value, Value::Boolean(false)) {
+                continue;
+            }
+            if !first {
+                buffer.put_u8(b' ');
+            }
+            first = false;
 
+            let key = self.keys.get_or_insert_with(path, |path| {
+                let mut key = Vec::new();
+                write_string(path, &mut key);
+                String::from_utf8(key)
+                    .expect("escaped keys are UTF-8")
+                    .into()
+            });
+            buffer.extend_from_slice(key.as_bytes());
+            if !matches!(value, Value::Boolean(true)) {
+                buffer.put_u8(b'=');
+                write_string(&value.to_string_lossy(), buffer);
+            }
+        }
+
+        let (hits, misses) = self.keys.take_stats();
+        self.lookups.emit(KeyLookups(hits, misses));
         Ok(())
     }
 }
 
+/// Writes a key or a value, quoted if it contains whitespace, a quote or an equal sign.
+fn write_string<B: BufMut>(string: &str, output: &mut B) {
+    let needs_quoting = string
+        .chars()
+        .any(|c| c.is_whitespace() || c == '"' || c == '=');
+    if needs_quoting {
+        output.put_u8(b'"');
+    }
+    let mut rest = string;
+    while let Some(index) = rest.find(['\\', '"', '\n']) {
+        output.put_slice(&rest.as_bytes()[..index]);
+        let escaped: &[u8] = match rest.as_bytes()[index] {
+            b'\\' => br"\\",
+            b'"' => br#"\""#,
+            _ => br"\\n",
+        };
+        output.put_slice(escaped);
+        rest = &rest[index + 1..];
+    }
+    output.put_slice(rest.as_bytes());
+    if needs_quoting {
+        output.put_u8(b'"');
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use bytes::BytesMut;
+    use serde_json::json;
     use vector_core::event::{LogEvent, Value};
     use vrl::btreemap;
 
@@ -60,11 +161,78 @@ mod tests {
         let event = Event::Log(LogEvent::from(btreemap! {
             "foo" => Value::from("bar")
         }));
-        let mut serializer = LogfmtSerializer;
+        let mut serializer = LogfmtSerializer::new();
         let mut bytes = BytesMut::new();
 
         serializer.encode(event, &mut bytes).unwrap();
 
         assert_eq!(bytes.freeze(), "foo=bar");
     }
+
+    #[test]
+    fn serializes_like_encode_logfmt() {
+        let events = [
+            json!({
+                "message": "hello world",
+                "level": "info",
+                "count": 42,
+                "ratio": 0.5,
+                "ok": true,
+                "failed": false,
+                "missing": null,
+            }),
+            json!({
+                "kubernetes": {
+                    "pod_labels": {
+                        "app": "vector",
+                        "app.kubernetes.io/name": "agent",
+                    },
+                },
+                "kubernetes-namespace": "default",
+                "tags": ["a", "b c"],
+                "empty_map": {},
+                "empty_array": [],
+            }),
+            json!({
+                "quote": r#"say "hi""#,
+                "equals": "a=b",
+                "backslash": r"C:\path",
+                "newline": "first\nsecond",
+                "empty": "",
+                "with space": "value",
+            }),
+            json!({}),
+        ];
+
+        let mut serializer = LogfmtSerializer::new();
+        for value in events.map(Value::from) {
+            let expected = encode_logfmt::encode_value(&value).unwrap();
+            let mut bytes = BytesMut::new();
+            serializer
+                .encode(Event::Log(LogEvent::from(value)), &mut bytes)
+                .unwrap();
+            assert_eq!(bytes.freeze(), expected);
+        }
+    }
+
+    #[test]
+    fn reuses_encoded_keys() {
+        let event = Event::Log(LogEvent::from(Value::from(json!({
+            "kubernetes": { "pod_labels": { "app": "vector" } },
+            "with space": "value",
+        }))));
+        let mut serializer = LogfmtSerializer::new();
+
+        let mut first = BytesMut::new();
+        serializer.encode(event.clone(), &mut first).unwrap();
+        let mut second = BytesMut::new();
+        serializer.encode(event, &mut second).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(
+            first.freeze(),
+            r#"kubernetes.pod_labels.app=vector "with space"=value"#
+        );
+        assert_eq!(serializer.keys.len(), 2);
+    }
 }
@@ -8,6 +8,7 @@ mod cef;
 mod common;
 mod csv;
 mod gelf;
+mod journal_export;
 mod json;
 mod logfmt;
 mod native;
@@ -23,6 +24,7 @@ pub use avro::{AvroSerializer, AvroSerializerConfig, AvroSerializerOptions};
 pub use cef::{CefSerializer, CefSerializerConfig};
 use dyn_clone::DynClone;
 pub use gelf::{GelfSerializer, GelfSerializerConfig};
+pub use journal_export::{JournalExportSerializer, JournalExportSerializerConfig};
 pub use json::{JsonSerializer, JsonSerializerConfig, JsonSerializerOptions};
 pub use logfmt::{LogfmtSerializer, LogfmtSerializerConfig};
 pub use native::{NativeSerializer, NativeSerializerConfig};
@@ -10,11 +10,11 @@ use bytes::BytesMut;
 pub use format::{
     AvroSerializer, AvroSerializerConfig, AvroSerializerOptions, CefSerializer,
     CefSerializerConfig, CsvSerializer, CsvSerializerConfig, GelfSerializer, GelfSerializerConfig,
-    JsonSerializer, JsonSerializerConfig, JsonSerializerOptions, LogfmtSerializer,
-    LogfmtSerializerConfig, NativeJsonSerializer, NativeJsonSerializerConfig, NativeSerializer,
-    NativeSerializerConfig, ProtobufSerializer, ProtobufSerializerConfig,
-    ProtobufSerializerOptions, RawMessageSerializer, RawMessageSerializerConfig, TextSerializer,
-    TextSerializerConfig,
+    JournalExportSerializer, JournalExportSerializerConfig, JsonSerializer, JsonSerializerConfig,
+    JsonSerializerOptions, LogfmtSerializer, LogfmtSerializerConfig, NativeJsonSerializer,
+    NativeJsonSerializerConfig, NativeSerializer, NativeSerializerConfig, ProtobufSerializer,
+    ProtobufSerializerConfig, ProtobufSerializerOptions, RawMessageSerializer,
+    RawMessageSerializerConfig, TextSerializer, TextSerializerConfig,
 };
 pub use framing::{
     BoxedFramer, BoxedFramingError, BytesEncoder, BytesEncoderConfig, CharacterDelimitedEncoder,
@@ -216,6 +216,13 @@ pub enum SerializerConfig {
     /// [implementation]: https://github.com/Graylog2/go-gelf/blob/v2/gelf/reader.go
     Gelf,
 
+    /// Encodes an event as an entry in systemd's [Journal Export Format][journal_export].
+    ///
+    /// The output can be imported with `systemd-journal-remote`.
+    ///
+    /// [journal_export]: https://systemd.io/JOURNAL_EXPORT_FORMATS/#journal-export-format
+    JournalExport,
+
     /// Encodes an event as [JSON][json].
     ///
     /// [json]: https://www.json.org/
@@ -291,6 +298,12 @@ impl From<GelfSerializerConfig> for SerializerConfig {
     }
 }
 
+impl From<JournalExportSerializerConfig> for SerializerConfig {
+    fn from(_: JournalExportSerializerConfig) -> Self {
+        Self::JournalExport
+    }
+}
+
 impl From<JsonSerializerConfig> for SerializerConfig {
     fn from(config: JsonSerializerConfig) -> Self {
         Self::Json(config)
@@ -343,6 +356,9 @@ impl SerializerConfig {
             SerializerConfig::Cef(config) => Ok(Serializer::Cef(config.build()?)),
             SerializerConfig::Csv(config) => Ok(Serializer::Csv(config.build()?)),
             SerializerConfig::Gelf => Ok(Serializer::Gelf(GelfSerializerConfig::new().build())),
+            SerializerConfig::JournalExport => Ok(Serializer::JournalExport(
+                JournalExportSerializerConfig.build(),
+            )),
             SerializerConfig::Json(config) => Ok(Serializer::Json(config.build())),
             SerializerConfig::Logfmt => Ok(Serializer::Logfmt(LogfmtSerializerConfig.build())),
             SerializerConfig::Native => Ok(Serializer::Native(NativeSerializerConfig.build())),
@@ -386,6 +402,8 @@ impl SerializerConfig {
             SerializerConfig::Gelf => {
                 FramingConfig::CharacterDelimited(CharacterDelimitedEncoderConfig::new(0))
             }
+            // Entries are terminated by an empty line already.
+            SerializerConfig::JournalExport => FramingConfig::Bytes,
         }
     }
 
@@ -398,6 +416,7 @@ impl SerializerConfig {
             SerializerConfig::Cef(config) => config.input_type(),
             SerializerConfig::Csv(config) => config.input_type(),
             SerializerConfig::Gelf { .. } => GelfSerializerConfig::input_type(),
+            SerializerConfig::JournalExport => JournalExportSerializerConfig.input_type(),
             SerializerConfig::Json(config) => config.input_type(),
             SerializerConfig::Logfmt => LogfmtSerializerConfig.input_type(),
             SerializerConfig::Native => NativeSerializerConfig.input_type(),
@@ -417,6 +436,7 @@ impl SerializerConfig {
             SerializerConfig::Cef(config) => config.schema_requirement(),
             SerializerConfig::Csv(config) => config.schema_requirement(),
             SerializerConfig::Gelf { .. } => GelfSerializerConfig::schema_requirement(),
+            SerializerConfig::JournalExport => JournalExportSerializerConfig.schema_requirement(),
             SerializerConfig::Json(config) => config.schema_requirement(),
             SerializerConfig::Logfmt => LogfmtSerializerConfig.schema_requirement(),
             SerializerConfig::Native => NativeSerializerConfig.schema_requirement(),
@@ -439,6 +459,8 @@ pub enum Serializer {
     Csv(CsvSerializer),
     /// Uses a `GelfSerializer` for serialization.
     Gelf(GelfSerializer),
+    /// Uses a `JournalExportSerializer` for serialization.
+    JournalExport(JournalExportSerializer),
     /// Uses a `JsonSerializer` for serialization.
     Json(JsonSerializer),
     /// Uses a `LogfmtSerializer` for serialization.
@@ -463,6 +485,7 @@ impl Serializer {
             Serializer::Avro(_)
             | Serializer::Cef(_)
             | Serializer::Csv(_)
+            | Serializer::JournalExport(_)
             | Serializer::Logfmt(_)
             | Serializer::Text(_)
             | Serializer::Native(_)
@@ -485,6 +508,7 @@ impl Serializer {
             Serializer::Avro(_)
             | Serializer::Cef(_)
             | Serializer::Csv(_)
+            | Serializer::JournalExport(_)
             | Serializer::Logfmt(_)
             | Serializer::Text(_)
             | Serializer::Native(_)
@@ -520,6 +544,12 @@ impl From<GelfSerializer> for Serializer {
     }
 }
 
+impl From<JournalExportSerializer> for Serializer {
+    fn from(serializer: JournalExportSerializer) -> Self {
+        Self::JournalExport(serializer)
+    }
+}
+
 impl From<JsonSerializer> for Serializer {
     fn from(serializer: JsonSerializer) -> Self {
         Self::Json(serializer)
@@ -571,6 +601,7 @@ impl tokio_util::codec::Encoder<Event> for Serializer {
             Serializer::Cef(serializer) => serializer.encode(event, buffer),
             Serializer::Csv(serializer) => serializer.encode(event, buffer),
             Serializer::Gelf(serializer) => serializer.encode(event, buffer),
+            Serializer::JournalExport(serializer) => serializer.encode(event, buffer),
             Serializer::Json(serializer) => serializer.encode(event, buffer),
             Serializer::Logfmt(serializer) => serializer.encode(event, buffer),
             Serializer::Native(serializer) => serializer.encode(event, buffer),
@@ -9,6 +9,7 @@ pub mod decoding;
 pub mod encoding;
 pub mod gelf;
 
+pub use common::journal_export;
 pub use decoding::{
     BytesDecoder, BytesDecoderConfig, BytesDeserializer, BytesDeserializerConfig,
     CharacterDelimitedDecoder, CharacterDelimitedDecoderConfig, GelfDeserializer,
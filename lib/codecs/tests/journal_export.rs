@@ -0,0 +1,168 @@
+//! Tests for the behaviour of the Journal Export Format framer, deserializer and serializer
+//! (together).
+
+use bytes::{Bytes, BytesMut};
+use std::path::PathBuf;
+use tokio_util::codec::{Decoder, Encoder};
+use vector_core::config::LogNamespace;
+use vector_core::event::Event;
+use vrl::value::Value;
+
+use codecs::decoding::format::Deserializer;
+use codecs::decoding::{JournalExportDecoder, JournalExportDeserializer};
+use codecs::encoding::JournalExportSerializer;
+
+fn read_entries() -> BytesMut {
+    let path = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap())
+        .join("tests/data/journal_export/entries.export");
+    BytesMut::from(&std::fs::read(path).unwrap()[..])
+}
+
+fn decode(mut input: BytesMut, log_namespace: LogNamespace) -> Vec<Event> {
+    let mut framer = JournalExportDecoder::default();
+    let mut events = Vec::new();
+    while let Some(frame) = framer.decode_eof(&mut input).unwrap() {
+        events.extend(
+            JournalExportDeserializer
+                .parse(frame, log_namespace)
+                .unwrap(),
+        );
+    }
+    events
+}
+
+fn field<'a>(event: &'a Event, name: &str) -> Option<&'a Value> {
+    event.as_log().get(lookup::event_path!(name))
+}
+
+#[test]
+fn decodes_journalctl_export() {
+    let events = decode(read_entries(), LogNamespace::Vector);
+    assert_eq!(events.len(), 3);
+
+    assert_eq!(
+        field(&events[0], "MESSAGE"),
+        Some(&Value::from("hello from vector"))
+    );
+    assert_eq!(
+        field(&events[0], "_TRANSPORT"),
+        Some(&Value::from("stdout"))
+    );
+
+    assert_eq!(
+        field(&events[1], "CUSTOM_FIELD"),
+        Some(&Value::from("value=with=equals"))
+    );
+
+    assert_eq!(
+        field(&events[2], "MESSAGE"),
+        Some(&Value::from("first line\nsecond line\n\nafter empty line"))
+    );
+    assert_eq!(
+        field(&events[2], "BINARY_DATA"),
+        Some(&Value::Bytes(Bytes::from_static(b"\x00\x01\x02\xff\n")))
+    );
+    assert_eq!(
+        field(&events[2], "REPEATED"),
+        Some(&Value::Array(vec!["one".into(), "two".into()]))
+    );
+    assert_eq!(
+        field(&events[2], "_SOURCE_REALTIME_TIMESTAMP"),
+        Some(&Value::from("1791944996385029"))
+    );
+}
+
+#[test]
+fn decodes_journalctl_export_in_chunks() {
+    let entries = read_entries();
+    let expected = decode(entries.clone(), LogNamespace::Legacy);
+
+    for chunk_size in [1, 7, 64] {
+        let mut framer = JournalExportDecoder::default();
+        let mut input = BytesMut::new();
+        let mut events = Vec::new();
+        for chunk in entries.chunks(chunk_size) {
+            input.extend_from_slice(chunk);
+            while let Some(frame) = framer.decode(&mut input).unwrap() {
+                events.extend(
+                    JournalExportDeserializer
+                        .parse(frame, LogNamespace::Legacy)
+                        .unwrap(),
+                );
+            }
+        }
+        assert!(framer.decode_eof(&mut input).unwrap().is_none());
+
+        assert_eq!(events.len(), expected.len());
+        for (event, expected) in events.iter().zip(&expected) {
+            assert_eq!(event.as_log().value(), expected.as_log().value());
+        }
+    }
+}
+
+fn encode(events: Vec<Event>) -> BytesMut {
+    let mut output = BytesMut::new();
+    for event in events {
+        JournalExportSerializer.encode(event, &mut output).unwrap();
+    }
+    output
+}
+
+#[test]
+fn roundtrips_journalctl_export() {
+    for log_namespace in [LogNamespace::Legacy, LogNamespace::Vector] {
+        let expected = decode(read_entries(), log_namespace);
+        let events = decode(encode(expected.clone()), log_namespace);
+
+        assert_eq!(events.len(), expected.len());
+        for (event, expected) in events.iter().zip(&expected) {
+            assert_eq!(event.as_log().value(), expected.as_log().value());
+        }
+    }
+}
+
+/// Imports the serialized entries into a journal file with `systemd-journal-remote`, and reads
+/// them back with `journalctl`.
+#[ignore]
+#[test]
+fn imports_with_systemd_journal_remote() {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let directory = std::env::temp_dir().join(format!("vector-journal-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let journal = directory.join("imported.journal");
+
+    let expected = decode(read_entries(), LogNamespace::Vector);
+    let mut remote = Command::new("systemd-journal-remote")
+        .arg("-o")
+        .arg(&journal)
+        .arg("-")
+        .stdin(Stdio::piped())
+        .spawn()
+        .expect("systemd-journal-remote must be installed");
+    remote
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(&encode(expected.clone()))
+        .unwrap();
+    assert!(remote.wait().unwrap().success());
+
+    let output = Command::new("journalctl")
+        .arg("--file")
+        .arg(&journal)
+        .args(["--output", "export"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    std::fs::remove_dir_all(&directory).unwrap();
+
+    let events = decode(BytesMut::from(&output.stdout[..]), LogNamespace::Vector);
+    assert_eq!(events.len(), expected.len());
+    for (event, expected) in events.iter().zip(&expected) {
+        for name in ["MESSAGE", "CUSTOM_FIELD", "BINARY_DATA", "REPEATED"] {
+            assert_eq!(field(event, name), field(expected, name));
+        }
+    }
+}
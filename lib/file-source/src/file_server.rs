@@ -21,6 +21,7 @@ use crate::{
     checkpointer::{Checkpointer, CheckpointsView},
     file_watcher::FileWatcher,
     fingerprinter::{FileFingerprint, Fingerprinter},
+    metadata_ext::is_fifo,
     paths_provider::PathsProvider,
     FileSourceInternalEvents, ReadFrom,
 };
@@ -124,12 +125,16 @@ where
         let checkpoints = checkpointer.view();
 
         for (path, file_id) in existing_files {
-            checkpointer.maybe_upgrade(
-                &path,
-                file_id,
-                &self.fingerprinter,
-                &mut fingerprint_buffer,
-            );
+            // Named pipes have no legacy checkpoints to upgrade, and opening them to compute their
+            // legacy fingerprints would block until they have a writer.
+            if !fs::metadata(&path).is_ok_and(|metadata| is_fifo(&metadata)) {
+                checkpointer.maybe_upgrade(
+                    &path,
+                    file_id,
+                    &self.fingerprinter,
+                    &mut fingerprint_buffer,
+                );
+            }
 
             self.watch_new_file(path, file_id, &mut fp_map, &checkpoints, true);
         }
@@ -1,7 +1,7 @@
 use std::{
     fs::{self, File},
     io::{self, BufRead, Seek},
-    path::PathBuf,
+    path::{Path, PathBuf},
     time::{Duration, Instant},
 };
 
@@ -11,7 +11,9 @@ use flate2::bufread::MultiGzDecoder;
 use tracing::debug;
 
 use crate::{
-    buffer::read_until_with_max_size, metadata_ext::PortableFileExt, FilePosition, ReadFrom,
+    buffer::read_until_with_max_size,
+    metadata_ext::{is_fifo, PortableFileExt},
+    FilePosition, ReadFrom,
 };
 #[cfg(test)]
 mod tests;
@@ -38,6 +40,7 @@ pub struct FileWatcher {
     pub path: PathBuf,
     findable: bool,
     reader: Box<dyn BufRead>,
+    fifo: bool,
     file_position: FilePosition,
     devno: u64,
     inode: u64,
@@ -57,6 +60,9 @@ impl FileWatcher {
     /// The input path will be used by `FileWatcher` to prime its state
     /// machine. A `FileWatcher` tracks _only one_ file. This function returns
     /// None if the path does not exist or is not readable by the current process.
+    ///
+    /// Named pipes are read from whatever is written to them once they are opened, regardless of
+    /// `read_from`, and are reopened each time their writers close them.
     pub fn new(
         path: PathBuf,
         read_from: ReadFrom,
@@ -64,6 +70,10 @@ impl FileWatcher {
         max_line_bytes: usize,
         line_delimiter: Bytes,
     ) -> Result<FileWatcher, io::Error> {
+        if is_fifo(&fs::metadata(&path)?) {
+            return Self::new_fifo(path, max_line_bytes, line_delimiter);
+        }
+
         let f = fs::File::open(&path)?;
         let (devno, ino) = (f.portable_dev()?, f.portable_ino()?);
         let metadata = f.metadata()?;
@@ -141,6 +151,7 @@ impl FileWatcher {
             path,
             findable: true,
             reader,
+            fifo: false,
             file_position,
             devno,
             inode: ino,
@@ -155,7 +166,41 @@ impl FileWatcher {
         })
     }
 
+    fn new_fifo(
+        path: PathBuf,
+        max_line_bytes: usize,
+        line_delimiter: Bytes,
+    ) -> Result<FileWatcher, io::Error> {
+        let f = open_fifo(&path)?;
+        let (devno, ino) = (f.portable_dev()?, f.portable_ino()?);
+        let now = Instant::now();
+
+        Ok(FileWatcher {
+            path,
+            findable: true,
+            reader: Box::new(io::BufReader::new(f)),
+            fifo: true,
+            file_position: 0,
+            devno,
+            inode: ino,
+            is_dead: false,
+            reached_eof: false,
+            last_read_attempt: now,
+            last_read_success: now,
+            last_seen: now,
+            max_line_bytes,
+            line_delimiter,
+            buf: BytesMut::new(),
+        })
+    }
+
     pub fn update_path(&mut self, path: PathBuf) -> io::Result<()> {
+        if self.fifo {
+            // Named pipes are reopened from their path once their writers close them.
+            self.path = path;
+            return Ok(());
+        }
+
         let file_handle = File::open(&path)?;
         if (file_handle.portable_dev()?, file_handle.portable_ino()?) != (self.devno, self.inode) {
             let mut reader = io::BufReader::new(fs::File::open(&path)?);
@@ -226,6 +271,25 @@ impl FileWatcher {
                     bytes: self.buf.split().freeze(),
                 }))
             }
+            Ok(None) if self.fifo && self.file_findable() => {
+                // All the writers closed the pipe, which has to be reopened for the next ones. The
+                // new reader is opened before the current one is closed, so that writers never
+                // find the pipe without a reader.
+                self.reader = Box::new(io::BufReader::new(open_fifo(&self.path)?));
+                self.reached_eof = true;
+
+                // Each writer ends its writes with the pipe, so what is left in the buffer is
+                // its last line.
+                let buf = self.buf.split().freeze();
+                if buf.is_empty() {
+                    Ok(None)
+                } else {
+                    Ok(Some(RawLine {
+                        offset: initial_position,
+                        bytes: buf,
+                    }))
+                }
+            }
             Ok(None) => {
                 if !self.file_findable() {
                     self.set_dead();
@@ -248,6 +312,11 @@ impl FileWatcher {
                     Ok(None)
                 }
             }
+            // The pipe has writers, which have nothing more to write for now.
+            Err(e) if self.fifo && e.kind() == io::ErrorKind::WouldBlock => {
+                self.reached_eof = true;
+                Ok(None)
+            }
             Err(e) => {
                 if let io::ErrorKind::NotFound = e.kind() {
                     self.set_dead();
@@ -296,6 +365,23 @@ fn is_gzipped(r: &mut io::BufReader<fs::File>) -> io::Result<bool> {
     Ok(header_bytes.starts_with(&[0x1f, 0x8b]))
 }
 
+/// Opens a named pipe without waiting for it to have a writer. Reads then fail with `WouldBlock`
+/// while its writers have nothing to write, and return nothing once they have all closed it.
+#[cfg(unix)]
+fn open_fifo(path: &Path) -> io::Result<File> {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    fs::OpenOptions::new()
+        .read(true)
+        .custom_flags(libc::O_NONBLOCK)
+        .open(path)
+}
+
+#[cfg(not(unix))]
+fn open_fifo(_path: &Path) -> io::Result<File> {
+    unreachable!("named pipes are only detected on Unix")
+}
+
 fn null_reader() -> impl BufRead {
     io::Cursor::new(Vec::new())
 }
@@ -8,7 +8,10 @@ use std::{
 use crc::Crc;
 use serde::{Deserialize, Serialize};
 
-use crate::{metadata_ext::PortableFileExt, FileSourceInternalEvents};
+use crate::{
+    metadata_ext::{is_fifo, PortableFileExt},
+    FileSourceInternalEvents,
+};
 
 const FINGERPRINT_CRC: Crc<u64> = Crc::<u64>::new(&crc::CRC_64_ECMA_182);
 const LEGACY_FINGERPRINT_CRC: Crc<u64> = Crc::<u64>::new(&crc::CRC_64_XZ);
@@ -114,6 +117,10 @@ impl Fingerprinter {
             .and_then(|metadata| {
                 if metadata.is_dir() {
                     Ok(None)
+                } else if is_fifo(&metadata) {
+                    // Named pipes are identified by their device and inode, whatever the strategy,
+                    // as reading their content would consume it.
+                    Ok(Some(fifo_fingerprint(&metadata)))
                 } else {
                     self.get_fingerprint_of_file(path, buffer).map(Some)
                 }
@@ -216,6 +223,17 @@ impl Fingerprinter {
     }
 }
 
+#[cfg(unix)]
+fn fifo_fingerprint(metadata: &fs::Metadata) -> FileFingerprint {
+    use std::os::unix::fs::MetadataExt;
+    FileFingerprint::DevInode(metadata.dev(), metadata.ino())
+}
+
+#[cfg(not(unix))]
+fn fifo_fingerprint(_metadata: &fs::Metadata) -> FileFingerprint {
+    unreachable!("named pipes are only detected on Unix")
+}
+
 /// Saved for backwards compatibility.
 fn fingerprinter_read_until_and_zerofill_buf(
     mut r: impl Read,
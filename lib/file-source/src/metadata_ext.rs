@@ -3,9 +3,9 @@
 //!
 //! In stdlib imported code, warnings are allowed.
 
-use std::fs::File;
+use std::fs::{File, Metadata};
 #[cfg(unix)]
-use std::os::unix::fs::MetadataExt;
+use std::os::unix::fs::{FileTypeExt, MetadataExt};
 #[cfg(windows)]
 use std::{mem::zeroed, ptr};
 
@@ -69,6 +69,18 @@ pub trait PortableFileExt: std::os::windows::io::AsRawHandle {
     }
 }
 
+/// Whether the file is a named pipe, which is read as a stream of lines rather than a file that
+/// can be fingerprinted and checkpointed.
+#[cfg(unix)]
+pub fn is_fifo(metadata: &Metadata) -> bool {
+    metadata.file_type().is_fifo()
+}
+
+#[cfg(not(unix))]
+pub fn is_fifo(_metadata: &Metadata) -> bool {
+    false
+}
+
 #[cfg(unix)]
 impl PortableFileExt for File {
     fn portable_dev(&self) -> std::io::Result<u64> {
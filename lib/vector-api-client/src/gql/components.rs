@@ -31,6 +31,15 @@ pub struct ComponentAddedSubscription;
 )]
 pub struct ComponentRemovedSubscription;
 
+/// Components subscription for the healthcheck status of each sink, sampled every `interval`
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_health_statuses.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentHealthStatusesSubscription;
+
 pub trait ComponentsQueryExt {
     async fn components_query(&self, first: i64) -> crate::QueryResult<ComponentsQuery>;
 }
@@ -45,6 +54,10 @@ impl ComponentsQueryExt for crate::Client {
 pub trait ComponentsSubscriptionExt {
     fn component_added(&self) -> crate::BoxedSubscription<ComponentAddedSubscription>;
     fn component_removed(&self) -> crate::BoxedSubscription<ComponentRemovedSubscription>;
+    fn component_health_statuses(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<ComponentHealthStatusesSubscription>;
 }
 
 impl ComponentsSubscriptionExt for crate::SubscriptionClient {
@@ -63,6 +76,18 @@ impl ComponentsSubscriptionExt for crate::SubscriptionClient {
 
         self.start::<ComponentRemovedSubscription>(&request_body)
     }
+
+    /// Subscription for the healthcheck status of each sink
+    fn component_health_statuses(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentHealthStatusesSubscription> {
+        let request_body = ComponentHealthStatusesSubscription::build_query(
+            component_health_statuses_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentHealthStatusesSubscription>(&request_body)
+    }
 }
 
 impl components_query::ComponentsQueryComponentsEdgesNodeOn {
@@ -80,6 +105,28 @@ impl components_query::ComponentsQueryComponentsEdgesNodeOn {
         }
     }
 
+    /// Returns the estimated size of the events received, in bytes, or `None` if the
+    /// component doesn't report it.
+    pub fn received_event_bytes_total(&self) -> Option<i64> {
+        match self {
+            components_query::ComponentsQueryComponentsEdgesNodeOn::Source(s) => s
+                .metrics
+                .received_event_bytes_total
+                .as_ref()
+                .map(|p| p.received_event_bytes_total as i64),
+            components_query::ComponentsQueryComponentsEdgesNodeOn::Transform(t) => t
+                .metrics
+                .received_event_bytes_total
+                .as_ref()
+                .map(|p| p.received_event_bytes_total as i64),
+            components_query::ComponentsQueryComponentsEdgesNodeOn::Sink(s) => s
+                .metrics
+                .received_event_bytes_total
+                .as_ref()
+                .map(|p| p.received_event_bytes_total as i64),
+        }
+    }
+
     pub fn received_events_total(&self) -> i64 {
         match self {
             components_query::ComponentsQueryComponentsEdgesNodeOn::Source(s) => s
@@ -117,6 +164,28 @@ impl components_query::ComponentsQueryComponentsEdgesNodeOn {
         }
     }
 
+    /// Returns the estimated size of the events sent, in bytes, or `None` if the
+    /// component doesn't report it.
+    pub fn sent_event_bytes_total(&self) -> Option<i64> {
+        match self {
+            components_query::ComponentsQueryComponentsEdgesNodeOn::Source(s) => s
+                .metrics
+                .sent_event_bytes_total
+                .as_ref()
+                .map(|p| p.sent_event_bytes_total as i64),
+            components_query::ComponentsQueryComponentsEdgesNodeOn::Transform(t) => t
+                .metrics
+                .sent_event_bytes_total
+                .as_ref()
+                .map(|p| p.sent_event_bytes_total as i64),
+            components_query::ComponentsQueryComponentsEdgesNodeOn::Sink(s) => s
+                .metrics
+                .sent_event_bytes_total
+                .as_ref()
+                .map(|p| p.sent_event_bytes_total as i64),
+        }
+    }
+
     pub fn sent_events_total(&self) -> i64 {
         match self {
             components_query::ComponentsQueryComponentsEdgesNodeOn::Source(s) => s
@@ -171,6 +240,18 @@ impl components_query::ComponentsQueryComponentsEdgesNodeOn {
             components_query::ComponentsQueryComponentsEdgesNodeOn::Sink(_) => vec![],
         }
     }
+
+    /// Returns the status of the latest healthcheck of a sink, if it ran. Only sinks have
+    /// healthchecks.
+    pub fn health_status(&self) -> Option<&components_query::SinkHealthStatus> {
+        match self {
+            components_query::ComponentsQueryComponentsEdgesNodeOn::Source(_) => None,
+            components_query::ComponentsQueryComponentsEdgesNodeOn::Transform(_) => None,
+            components_query::ComponentsQueryComponentsEdgesNodeOn::Sink(s) => {
+                s.health.as_ref().map(|h| &h.status)
+            }
+        }
+    }
 }
 
 impl fmt::Display for components_query::ComponentsQueryComponentsEdgesNodeOn {
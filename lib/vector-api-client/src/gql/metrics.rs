@@ -44,6 +44,26 @@ pub struct ComponentReceivedBytesThroughputsSubscription;
 )]
 pub struct ComponentReceivedBytesTotalsSubscription;
 
+/// ComponentReceivedEventBytesThroughputsSubscription contains metrics on the estimated size of
+/// the events that have been received between `interval` samples, against specific components.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_received_event_bytes_throughputs.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentReceivedEventBytesThroughputsSubscription;
+
+/// ComponentReceivedEventBytesTotalsSubscription contains metrics on the estimated size of the
+/// events that have been received by a Vector instance, against specific components.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_received_event_bytes_totals.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentReceivedEventBytesTotalsSubscription;
+
 /// ComponentReceivedEventsThroughputsSubscription contains metrics on the number of events
 /// that have been accepted for processing between `interval` samples, against specific components.
 #[derive(GraphQLQuery, Debug, Copy, Clone)]
@@ -84,6 +104,26 @@ pub struct ComponentSentBytesThroughputsSubscription;
 )]
 pub struct ComponentSentBytesTotalsSubscription;
 
+/// ComponentSentEventBytesThroughputsSubscription contains metrics on the estimated size of
+/// the events that have been sent between `interval` samples, against specific components.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_sent_event_bytes_throughputs.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentSentEventBytesThroughputsSubscription;
+
+/// ComponentSentEventBytesTotalsSubscription contains metrics on the estimated size of the
+/// events that have been sent by a Vector instance, against specific components.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_sent_event_bytes_totals.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentSentEventBytesTotalsSubscription;
+
 /// ComponentSentEventsThroughputsSubscription contains metrics on the number of events
 /// that have been emitted between `interval` samples, against specific components.
 #[derive(GraphQLQuery, Debug, Copy, Clone)]
@@ -147,6 +187,26 @@ impl component_sent_events_throughputs_subscription::ComponentSentEventsThroughp
 )]
 pub struct ComponentErrorsTotalsSubscription;
 
+/// ComponentSendInFlightsSubscription contains the number of payloads being sent by sinks,
+/// against specific components.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_send_in_flights.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentSendInFlightsSubscription;
+
+/// ComponentSendUtilizationsSubscription contains the share of time during which sinks are
+/// sending payloads, against specific components.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_send_utilizations.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentSendUtilizationsSubscription;
+
 /// Extension methods for metrics subscriptions
 pub trait MetricsSubscriptionExt {
     /// Executes an uptime metrics subscription.
@@ -170,6 +230,18 @@ pub trait MetricsSubscriptionExt {
         interval: i64,
     ) -> crate::BoxedSubscription<ComponentReceivedBytesThroughputsSubscription>;
 
+    /// Executes a component received event bytes totals subscription.
+    fn component_received_event_bytes_totals_subscription(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<ComponentReceivedEventBytesTotalsSubscription>;
+
+    /// Executes a component received event bytes throughputs subscription.
+    fn component_received_event_bytes_throughputs_subscription(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<ComponentReceivedEventBytesThroughputsSubscription>;
+
     /// Executes a component received events totals subscription.
     fn component_received_events_totals_subscription(
         &self,
@@ -194,6 +266,18 @@ pub trait MetricsSubscriptionExt {
         interval: i64,
     ) -> crate::BoxedSubscription<ComponentSentBytesThroughputsSubscription>;
 
+    /// Executes a component sent event bytes totals subscription.
+    fn component_sent_event_bytes_totals_subscription(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<ComponentSentEventBytesTotalsSubscription>;
+
+    /// Executes a component sent event bytes throughputs subscription.
+    fn component_sent_event_bytes_throughputs_subscription(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<ComponentSentEventBytesThroughputsSubscription>;
+
     /// Executes a component events totals subscription.
     fn component_sent_events_totals_subscription(
         &self,
@@ -210,6 +294,18 @@ pub trait MetricsSubscriptionExt {
         &self,
         interval: i64,
     ) -> crate::BoxedSubscription<ComponentErrorsTotalsSubscription>;
+
+    /// Executes a component send in flights subscription.
+    fn component_send_in_flights_subscription(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<ComponentSendInFlightsSubscription>;
+
+    /// Executes a component send utilizations subscription.
+    fn component_send_utilizations_subscription(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<ComponentSendUtilizationsSubscription>;
 }
 
 impl MetricsSubscriptionExt for crate::SubscriptionClient {
@@ -256,6 +352,30 @@ impl MetricsSubscriptionExt for crate::SubscriptionClient {
         self.start::<ComponentReceivedBytesThroughputsSubscription>(&request_body)
     }
 
+    /// Executes a component received event bytes totals subscription.
+    fn component_received_event_bytes_totals_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentReceivedEventBytesTotalsSubscription> {
+        let request_body = ComponentReceivedEventBytesTotalsSubscription::build_query(
+            component_received_event_bytes_totals_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentReceivedEventBytesTotalsSubscription>(&request_body)
+    }
+
+    /// Executes a component received event bytes throughputs subscription.
+    fn component_received_event_bytes_throughputs_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentReceivedEventBytesThroughputsSubscription> {
+        let request_body = ComponentReceivedEventBytesThroughputsSubscription::build_query(
+            component_received_event_bytes_throughputs_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentReceivedEventBytesThroughputsSubscription>(&request_body)
+    }
+
     /// Executes an all component received events totals subscription.
     fn component_received_events_totals_subscription(
         &self,
@@ -304,6 +424,30 @@ impl MetricsSubscriptionExt for crate::SubscriptionClient {
         self.start::<ComponentSentBytesThroughputsSubscription>(&request_body)
     }
 
+    /// Executes a component sent event bytes totals subscription.
+    fn component_sent_event_bytes_totals_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentSentEventBytesTotalsSubscription> {
+        let request_body = ComponentSentEventBytesTotalsSubscription::build_query(
+            component_sent_event_bytes_totals_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentSentEventBytesTotalsSubscription>(&request_body)
+    }
+
+    /// Executes a component sent event bytes throughputs subscription.
+    fn component_sent_event_bytes_throughputs_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentSentEventBytesThroughputsSubscription> {
+        let request_body = ComponentSentEventBytesThroughputsSubscription::build_query(
+            component_sent_event_bytes_throughputs_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentSentEventBytesThroughputsSubscription>(&request_body)
+    }
+
     /// Executes a component sent events totals subscription.
     fn component_sent_events_totals_subscription(
         &self,
@@ -338,4 +482,28 @@ impl MetricsSubscriptionExt for crate::SubscriptionClient {
 
         self.start::<ComponentErrorsTotalsSubscription>(&request_body)
     }
+
+    /// Executes a component send in flights subscription.
+    fn component_send_in_flights_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentSendInFlightsSubscription> {
+        let request_body = ComponentSendInFlightsSubscription::build_query(
+            component_send_in_flights_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentSendInFlightsSubscription>(&request_body)
+    }
+
+    /// Executes a component send utilizations subscription.
+    fn component_send_utilizations_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentSendUtilizationsSubscription> {
+        let request_body = ComponentSendUtilizationsSubscription::build_query(
+            component_send_utilizations_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentSendUtilizationsSubscription>(&request_body)
+    }
 }
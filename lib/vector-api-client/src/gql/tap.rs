@@ -17,6 +17,16 @@ type DateTime = chrono::DateTime<chrono::Utc>;
 )]
 pub struct OutputEventsByComponentIdPatternsSubscription;
 
+/// EncodedEventsByComponentIdSubscription allows observability into the payloads that a sink
+/// encodes for the events it receives.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/encoded_events_by_component_id.graphql",
+    response_derives = "Debug"
+)]
+pub struct EncodedEventsByComponentIdSubscription;
+
 /// Tap encoding format type that is more convenient to use for public clients than the
 /// generated `output_events_by_component_id_patterns_subscription::EventEncodingType`.
 #[derive(clap::ValueEnum, Debug, Clone, Copy)]
@@ -24,6 +34,9 @@ pub enum TapEncodingFormat {
     Json,
     Yaml,
     Logfmt,
+    /// The payloads a sink encodes for the events it receives, which is requested with
+    /// [`TapSubscriptionExt::encoded_events_by_component_id_subscription`].
+    Encoded,
 }
 
 /// String -> TapEncodingFormat, typically for parsing user input.
@@ -35,6 +48,7 @@ impl std::str::FromStr for TapEncodingFormat {
             "json" => Ok(Self::Json),
             "yaml" => Ok(Self::Yaml),
             "logfmt" => Ok(Self::Logfmt),
+            "encoded" => Ok(Self::Encoded),
             _ => Err("Invalid encoding format".to_string()),
         }
     }
@@ -49,6 +63,9 @@ impl From<TapEncodingFormat>
             TapEncodingFormat::Json => Self::JSON,
             TapEncodingFormat::Yaml => Self::YAML,
             TapEncodingFormat::Logfmt => Self::LOGFMT,
+            // Encoded payloads aren't requested through this subscription, which only reports the
+            // events themselves.
+            TapEncodingFormat::Encoded => Self::JSON,
         }
     }
 }
@@ -63,6 +80,15 @@ pub trait TapSubscriptionExt {
         limit: i64,
         interval: i64,
     ) -> crate::BoxedSubscription<OutputEventsByComponentIdPatternsSubscription>;
+
+    /// Executes an encoded events subscription.
+    fn encoded_events_by_component_id_subscription(
+        &self,
+        component_id: String,
+        limit: i64,
+        interval: i64,
+        max_payload_bytes: i64,
+    ) -> crate::BoxedSubscription<EncodedEventsByComponentIdSubscription>;
 }
 
 impl TapSubscriptionExt for crate::SubscriptionClient {
@@ -87,4 +113,24 @@ impl TapSubscriptionExt for crate::SubscriptionClient {
 
         self.start::<OutputEventsByComponentIdPatternsSubscription>(&request_body)
     }
+
+    /// Executes an encoded events subscription.
+    fn encoded_events_by_component_id_subscription(
+        &self,
+        component_id: String,
+        limit: i64,
+        interval: i64,
+        max_payload_bytes: i64,
+    ) -> BoxedSubscription<EncodedEventsByComponentIdSubscription> {
+        let request_body = EncodedEventsByComponentIdSubscription::build_query(
+            encoded_events_by_component_id_subscription::Variables {
+                component_id,
+                limit,
+                interval,
+                max_payload_bytes,
+            },
+        );
+
+        self.start::<EncodedEventsByComponentIdSubscription>(&request_body)
+    }
 }
@@ -169,6 +169,20 @@ pub async fn wtr_measurement<const N: usize>(
     while receiver.next().await.is_some() {}
 }
 
+pub async fn wftr_measurement<const N: usize>(
+    mut sender: BufferSender<Message<N>>,
+    mut receiver: BufferReceiver<Message<N>>,
+    messages: Vec<Message<N>>,
+) {
+    for msg in messages.into_iter() {
+        sender.send(msg, None).await.unwrap();
+        sender.flush().await.unwrap();
+    }
+    drop(sender);
+
+    while receiver.next().await.is_some() {}
+}
+
 pub async fn war_measurement<const N: usize>(
     mut sender: BufferSender<Message<N>>,
     mut receiver: BufferReceiver<Message<N>>,
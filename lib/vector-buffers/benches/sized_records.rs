@@ -10,9 +10,9 @@ use criterion::{
     Criterion, SamplingMode, Throughput,
 };
 use tokio::runtime::{Handle, Runtime};
-use vector_buffers::{BufferType, WhenFull};
+use vector_buffers::{BufferType, DiskBufferDurability, DiskBufferEncryption, WhenFull};
 
-use crate::common::{init_instrumentation, war_measurement, wtr_measurement};
+use crate::common::{init_instrumentation, war_measurement, wftr_measurement, wtr_measurement};
 
 mod common;
 
@@ -75,13 +75,63 @@ impl Drop for PathGuard {
 fn create_disk_v2_variant(_max_events: usize, max_size: u64) -> BufferType {
     BufferType::DiskV2 {
         max_size: NonZeroU64::new(max_size).unwrap(),
+        max_age_secs: None,
+        expired_status: Default::default(),
+        durability: Default::default(),
+        encryption: None,
         when_full: WhenFull::DropNewest,
     }
 }
 
+fn create_encrypted_disk_v2_variant(_max_events: usize, max_size: u64) -> BufferType {
+    BufferType::DiskV2 {
+        max_size: NonZeroU64::new(max_size).unwrap(),
+        max_age_secs: None,
+        expired_status: Default::default(),
+        durability: Default::default(),
+        encryption: Some(DiskBufferEncryption {
+            key_file: None,
+            key_env: None,
+            // 32 zero bytes, encoded in base64.
+            key: Some(
+                "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA="
+                    .to_owned()
+                    .into(),
+            ),
+        }),
+        when_full: WhenFull::DropNewest,
+    }
+}
+
+fn create_durable_disk_v2_variant(max_size: u64, durability: DiskBufferDurability) -> BufferType {
+    BufferType::DiskV2 {
+        max_size: NonZeroU64::new(max_size).unwrap(),
+        max_age_secs: None,
+        expired_status: Default::default(),
+        durability,
+        encryption: None,
+        when_full: WhenFull::DropNewest,
+    }
+}
+
+fn create_interval_disk_v2_variant(_max_events: usize, max_size: u64) -> BufferType {
+    create_durable_disk_v2_variant(
+        max_size,
+        DiskBufferDurability::Interval {
+            interval_ms: NonZeroU64::new(500).unwrap(),
+            max_bytes: None,
+        },
+    )
+}
+
+fn create_always_disk_v2_variant(_max_events: usize, max_size: u64) -> BufferType {
+    create_durable_disk_v2_variant(max_size, DiskBufferDurability::Always)
+}
+
 fn create_in_memory_variant(max_events: usize, _max_size: u64) -> BufferType {
     BufferType::Memory {
-        max_events: NonZeroUsize::new(max_events).unwrap(),
+        max_events: NonZeroUsize::new(max_events),
+        max_size_bytes: None,
         when_full: WhenFull::DropNewest,
     }
 }
@@ -146,6 +196,15 @@ fn write_then_read(c: &mut Criterion) {
         create_disk_v2_variant
     );
 
+    experiment!(
+        c,
+        [32, 64, 128, 256, 512, 1024],
+        "buffer-disk-v2-encrypted",
+        "write-then-read",
+        wtr_measurement,
+        create_encrypted_disk_v2_variant
+    );
+
     experiment!(
         c,
         [32, 64, 128, 256, 512, 1024],
@@ -167,6 +226,15 @@ fn write_and_read(c: &mut Criterion) {
         create_disk_v2_variant
     );
 
+    experiment!(
+        c,
+        [32, 64, 128, 256, 512, 1024],
+        "buffer-disk-v2-encrypted",
+        "write-and-read",
+        war_measurement,
+        create_encrypted_disk_v2_variant
+    );
+
     experiment!(
         c,
         [32, 64, 128, 256, 512, 1024],
@@ -177,6 +245,39 @@ fn write_and_read(c: &mut Criterion) {
     );
 }
 
+/// Writes and flushes each message, as topologies do, and then reads them all out.
+///
+/// This compares the durability modes of disk buffers, since flushes are what synchronize the
+/// buffer to disk.
+fn write_flush_then_read(c: &mut Criterion) {
+    experiment!(
+        c,
+        [32, 256, 1024],
+        "buffer-disk-v2-durability-none",
+        "write-flush-then-read",
+        wftr_measurement,
+        create_disk_v2_variant
+    );
+
+    experiment!(
+        c,
+        [32, 256, 1024],
+        "buffer-disk-v2-durability-interval",
+        "write-flush-then-read",
+        wftr_measurement,
+        create_interval_disk_v2_variant
+    );
+
+    experiment!(
+        c,
+        [32, 256, 1024],
+        "buffer-disk-v2-durability-always",
+        "write-flush-then-read",
+        wftr_measurement,
+        create_always_disk_v2_variant
+    );
+}
+
 criterion_group!(
     name = sized_records;
     config = Criterion::default()
@@ -184,6 +285,6 @@ criterion_group!(
         .confidence_level(0.99)
         .nresamples(500_000)
         .sample_size(100);
-    targets = write_then_read, write_and_read
+    targets = write_then_read, write_and_read, write_flush_then_read
 );
 criterion_main!(sized_records);
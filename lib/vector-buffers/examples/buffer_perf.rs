@@ -261,7 +261,8 @@ where
                 max_size_events
             );
             BufferType::Memory {
-                max_events: max_size_events,
+                max_events: Some(max_size_events),
+                max_size_bytes: None,
                 when_full,
             }
         }
@@ -272,6 +273,10 @@ where
             );
             BufferType::DiskV2 {
                 max_size: max_size_bytes,
+                max_age_secs: None,
+                expired_status: Default::default(),
+                durability: Default::default(),
+                encryption: None,
                 when_full,
             }
         }
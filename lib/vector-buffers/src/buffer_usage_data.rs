@@ -1,17 +1,21 @@
 use std::{
+    collections::VecDeque,
     sync::{
         atomic::{AtomicU64, Ordering},
-        Arc,
+        Arc, Mutex,
     },
-    time::Duration,
+    time::{Duration, SystemTime},
 };
 
-use tokio::time::interval;
+use tokio::time::{interval, Instant};
 use tracing::{Instrument, Span};
 use vector_common::internal_event::emit;
 
 use crate::{
-    internal_events::{BufferCreated, BufferEventsDropped, BufferEventsReceived, BufferEventsSent},
+    internal_events::{
+        BufferCreated, BufferEventsDropped, BufferEventsExpired, BufferEventsReceived,
+        BufferEventsSent, BufferOldestEventAge,
+    },
     spawn_named,
 };
 
@@ -79,6 +83,86 @@ impl CategoryMetrics {
     }
 }
 
+/// The times at which the events in a buffer stage were enqueued.
+///
+/// Events leave a buffer stage in the order they entered it, so the events are tracked as batches
+/// of events enqueued at the same time, oldest first, and the events leaving the stage are taken
+/// from the oldest batches.
+///
+/// The events already in a disk buffer when it is opened are tracked as a single restored batch,
+/// whose enqueue time is only known once the buffer reads their records.
+#[derive(Debug, Default)]
+struct EnqueueTimes {
+    batches: Mutex<VecDeque<EnqueuedBatch>>,
+}
+
+#[derive(Debug)]
+struct EnqueuedBatch {
+    count: u64,
+    enqueued_at: Instant,
+    restored: bool,
+}
+
+impl EnqueueTimes {
+    /// Tracks `count` events being enqueued now.
+    fn push(&self, count: u64) {
+        self.push_batch(count, false);
+    }
+
+    /// Tracks `count` events restored from a previous run, whose enqueue time is not known yet.
+    fn restore(&self, count: u64) {
+        self.push_batch(count, true);
+    }
+
+    fn push_batch(&self, count: u64, restored: bool) {
+        if count > 0 {
+            self.batches
+                .lock()
+                .expect("poisoned lock")
+                .push_back(EnqueuedBatch {
+                    count,
+                    enqueued_at: Instant::now(),
+                    restored,
+                });
+        }
+    }
+
+    /// Sets the enqueue time of the oldest restored events, if they are the oldest events in the
+    /// buffer stage.
+    fn restore_enqueued_at(&self, enqueued_at: Instant) {
+        let mut batches = self.batches.lock().expect("poisoned lock");
+        if let Some(batch) = batches.front_mut().filter(|batch| batch.restored) {
+            batch.enqueued_at = enqueued_at;
+        }
+    }
+
+    /// Tracks the `count` oldest events leaving the buffer stage.
+    fn pop(&self, mut count: u64) {
+        let mut batches = self.batches.lock().expect("poisoned lock");
+        while count > 0 {
+            let Some(batch) = batches.front_mut() else {
+                break;
+            };
+            if batch.count > count {
+                batch.count -= count;
+                break;
+            }
+            count -= batch.count;
+            batches.pop_front();
+        }
+    }
+
+    /// Gets the age of the oldest event in the buffer stage, or zero if it is empty.
+    fn oldest_age(&self) -> Duration {
+        self.batches
+            .lock()
+            .expect("poisoned lock")
+            .front()
+            .map(|batch| batch.enqueued_at.elapsed())
+            .unwrap_or_default()
+    }
+}
+
 /// Handle to buffer usage metrics for a specific buffer stage.
 #[derive(Clone, Debug)]
 pub struct BufferUsageHandle {
@@ -118,6 +202,32 @@ impl BufferUsageHandle {
     /// This represents the events being sent into the buffer.
     pub fn increment_received_event_count_and_byte_size(&self, count: u64, byte_size: u64) {
         self.state.received.increment(count, byte_size);
+        self.state.enqueue_times.push(count);
+    }
+
+    /// Increments the number of events (and their total size) already in this buffer component
+    /// when it was opened.
+    ///
+    /// Their age is only tracked once the time at which the oldest of them was enqueued is set
+    /// through [`BufferUsageHandle::set_restored_enqueued_at`], and they are considered enqueued
+    /// now until then.
+    pub fn increment_restored_event_count_and_byte_size(&self, count: u64, byte_size: u64) {
+        self.state.received.increment(count, byte_size);
+        self.state.enqueue_times.restore(count);
+    }
+
+    /// Sets the time at which the oldest events still in this buffer component since it was opened
+    /// were enqueued.
+    ///
+    /// This has no effect once all of the events restored when opening the buffer have left it.
+    pub fn set_restored_enqueued_at(&self, enqueued_at: SystemTime) {
+        let age = SystemTime::now()
+            .duration_since(enqueued_at)
+            .unwrap_or_default();
+        let now = Instant::now();
+        self.state
+            .enqueue_times
+            .restore_enqueued_at(now.checked_sub(age).unwrap_or(now));
     }
 
     /// Increments the number of events (and their total size) sent by this buffer component.
@@ -125,9 +235,13 @@ impl BufferUsageHandle {
     /// This represents the events being read out of the buffer.
     pub fn increment_sent_event_count_and_byte_size(&self, count: u64, byte_size: u64) {
         self.state.sent.increment(count, byte_size);
+        self.state.enqueue_times.pop(count);
     }
 
     /// Increment the number of dropped events (and their total size) for this buffer component.
+    ///
+    /// Events dropped intentionally are dropped before entering the buffer, while the other ones
+    /// were lost from within the buffer.
     pub fn increment_dropped_event_count_and_byte_size(
         &self,
         count: u64,
@@ -138,6 +252,25 @@ impl BufferUsageHandle {
             self.state.dropped_intentional.increment(count, byte_size);
         } else {
             self.state.dropped.increment(count, byte_size);
+            self.state.enqueue_times.pop(count);
+        }
+    }
+
+    /// Increments the number of events (and their total size) discarded by this buffer component
+    /// for being older than the maximum age of its records.
+    ///
+    /// Events expired as rejected are counted apart, as they are reported as discarded
+    /// unintentionally.
+    pub fn increment_expired_event_count_and_byte_size(
+        &self,
+        count: u64,
+        byte_size: u64,
+        rejected: bool,
+    ) {
+        if rejected {
+            self.state.expired_rejected.increment(count, byte_size);
+        } else {
+            self.state.expired.increment(count, byte_size);
         }
     }
 }
@@ -149,7 +282,10 @@ struct BufferUsageData {
     sent: CategoryMetrics,
     dropped: CategoryMetrics,
     dropped_intentional: CategoryMetrics,
+    expired: CategoryMetrics,
+    expired_rejected: CategoryMetrics,
     max_size: CategoryMetrics,
+    enqueue_times: EnqueueTimes,
 }
 
 impl BufferUsageData {
@@ -165,6 +301,8 @@ impl BufferUsageData {
         let sent = self.sent.get();
         let dropped = self.dropped.get();
         let dropped_intentional = self.dropped_intentional.get();
+        let expired = self.expired.get();
+        let expired_rejected = self.expired_rejected.get();
         let max_size = self.max_size.get();
 
         BufferUsageSnapshot {
@@ -176,6 +314,11 @@ impl BufferUsageData {
             dropped_event_byte_size: dropped.event_byte_size,
             dropped_event_count_intentional: dropped_intentional.event_count,
             dropped_event_byte_size_intentional: dropped_intentional.event_byte_size,
+            expired_event_count: expired.event_count,
+            expired_event_byte_size: expired.event_byte_size,
+            expired_event_count_rejected: expired_rejected.event_count,
+            expired_event_byte_size_rejected: expired_rejected.event_byte_size,
+            oldest_event_age: self.enqueue_times.oldest_age(),
             max_size_bytes: max_size.event_byte_size,
             max_size_events: max_size
                 .event_count
@@ -196,6 +339,11 @@ pub struct BufferUsageSnapshot {
     pub dropped_event_byte_size: u64,
     pub dropped_event_count_intentional: u64,
     pub dropped_event_byte_size_intentional: u64,
+    pub expired_event_count: u64,
+    pub expired_event_byte_size: u64,
+    pub expired_event_count_rejected: u64,
+    pub expired_event_byte_size_rejected: u64,
+    pub oldest_event_age: Duration,
     pub max_size_bytes: u64,
     pub max_size_events: usize,
 }
@@ -261,6 +409,11 @@ impl BufferUsage {
                             .expect("should never be bigger than `usize`"),
                     });
 
+                    emit(BufferOldestEventAge {
+                        idx: stage.idx,
+                        age: stage.enqueue_times.oldest_age(),
+                    });
+
                     let received = stage.received.consume();
                     if received.has_updates() {
                         emit(BufferEventsReceived {
@@ -300,6 +453,26 @@ impl BufferUsage {
                             byte_size: dropped_intentional.event_byte_size,
                         });
                     }
+
+                    let expired = stage.expired.consume();
+                    if expired.has_updates() {
+                        emit(BufferEventsExpired {
+                            idx: stage.idx,
+                            intentional: true,
+                            count: expired.event_count,
+                            byte_size: expired.event_byte_size,
+                        });
+                    }
+
+                    let expired_rejected = stage.expired_rejected.consume();
+                    if expired_rejected.has_updates() {
+                        emit(BufferEventsExpired {
+                            idx: stage.idx,
+                            intentional: false,
+                            count: expired_rejected.event_count,
+                            byte_size: expired_rejected.event_byte_size,
+                        });
+                    }
                 }
             }
         };
@@ -308,3 +481,39 @@ impl BufferUsage {
         spawn_named(task.instrument(span.or_current()), task_name.as_str());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oldest_event_age(handle: &BufferUsageHandle) -> Duration {
+        handle.snapshot().oldest_event_age
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tracks_oldest_event_age() {
+        let handle = BufferUsageHandle::noop();
+        assert_eq!(oldest_event_age(&handle), Duration::ZERO);
+
+        // Backlogged: the age grows for as long as the oldest events stay in the buffer.
+        handle.increment_received_event_count_and_byte_size(2, 100);
+        tokio::time::advance(Duration::from_secs(5)).await;
+        handle.increment_received_event_count_and_byte_size(3, 150);
+        tokio::time::advance(Duration::from_secs(5)).await;
+        assert_eq!(oldest_event_age(&handle), Duration::from_secs(10));
+
+        // Draining: once the oldest batch is partially read, its remaining events are the oldest.
+        handle.increment_sent_event_count_and_byte_size(1, 50);
+        assert_eq!(oldest_event_age(&handle), Duration::from_secs(10));
+        handle.increment_sent_event_count_and_byte_size(2, 100);
+        assert_eq!(oldest_event_age(&handle), Duration::from_secs(5));
+
+        // Events dropped before entering the buffer do not leave it.
+        handle.increment_dropped_event_count_and_byte_size(1, 50, true);
+        assert_eq!(oldest_event_age(&handle), Duration::from_secs(5));
+
+        // Empty: events lost from within the buffer leave it as well.
+        handle.increment_dropped_event_count_and_byte_size(2, 100, false);
+        assert_eq!(oldest_event_age(&handle), Duration::ZERO);
+    }
+}
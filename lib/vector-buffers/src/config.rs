@@ -3,12 +3,18 @@ use std::{
     num::{NonZeroU64, NonZeroUsize},
     path::{Path, PathBuf},
     slice,
+    time::Duration,
 };
 
+use base64::prelude::{Engine as _, BASE64_STANDARD};
 use serde::{de, Deserialize, Deserializer, Serialize};
 use snafu::{ResultExt, Snafu};
 use tracing::Span;
-use vector_common::{config::ComponentKey, finalization::Finalizable};
+use vector_common::{
+    config::ComponentKey,
+    finalization::{EventStatus, Finalizable},
+    sensitive_string::SensitiveString,
+};
 use vector_config::configurable_component;
 
 use crate::{
@@ -16,7 +22,10 @@ use crate::{
         builder::{TopologyBuilder, TopologyError},
         channel::{BufferReceiver, BufferSender},
     },
-    variants::{DiskV2Buffer, MemoryBuffer},
+    variants::{
+        disk_v2::{Durability, ENCRYPTION_KEY_LEN},
+        DiskV2Buffer, MemoryBuffer,
+    },
     Bufferable, WhenFull,
 };
 
@@ -28,6 +37,10 @@ pub enum BufferBuildError {
     FailedToBuildTopology { source: TopologyError },
     #[snafu(display("`max_events` must be greater than zero"))]
     InvalidMaxEvents,
+    #[snafu(display("failed to load the disk buffer encryption key: {}", reason))]
+    InvalidEncryptionKey { reason: String },
+    #[snafu(display("a buffer can only have a single disk stage"))]
+    MultipleDiskStages,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -38,7 +51,26 @@ enum BufferTypeKind {
     DiskV2,
 }
 
-const ALL_FIELDS: [&str; 4] = ["type", "max_events", "max_size", "when_full"];
+/// The minimum size of a disk buffer, which must be able to hold two data files along with its
+/// ledger.
+///
+/// This matches the `range` validation on `max_size`, which `BufferType` has to enforce itself as
+/// it is deserialized by hand.
+const DISK_BUFFER_MIN_SIZE: u64 = 268_435_488;
+
+const ALL_FIELDS: [&str; 9] = [
+    "type",
+    "max_events",
+    "max_size_bytes",
+    "max_size",
+    "max_age_secs",
+    "expired_status",
+    "durability",
+    "encryption",
+    "when_full",
+];
+
+const MEMORY_FIELDS: &[&str] = &["type", "max_events", "max_size_bytes", "when_full"];
 
 struct BufferTypeVisitor;
 
@@ -49,7 +81,12 @@ impl BufferTypeVisitor {
     {
         let mut kind: Option<BufferTypeKind> = None;
         let mut max_events: Option<NonZeroUsize> = None;
+        let mut max_size_bytes: Option<NonZeroUsize> = None;
         let mut max_size: Option<NonZeroU64> = None;
+        let mut max_age_secs: Option<NonZeroU64> = None;
+        let mut expired_status: Option<DiskBufferExpiredStatus> = None;
+        let mut durability: Option<DiskBufferDurability> = None;
+        let mut encryption: Option<DiskBufferEncryption> = None;
         let mut when_full: Option<WhenFull> = None;
         while let Some(key) = map.next_key::<String>()? {
             match key.as_str() {
@@ -65,12 +102,42 @@ impl BufferTypeVisitor {
                     }
                     max_events = Some(map.next_value()?);
                 }
+                "max_size_bytes" => {
+                    if max_size_bytes.is_some() {
+                        return Err(de::Error::duplicate_field("max_size_bytes"));
+                    }
+                    max_size_bytes = Some(map.next_value()?);
+                }
                 "max_size" => {
                     if max_size.is_some() {
                         return Err(de::Error::duplicate_field("max_size"));
                     }
                     max_size = Some(map.next_value()?);
                 }
+                "max_age_secs" => {
+                    if max_age_secs.is_some() {
+                        return Err(de::Error::duplicate_field("max_age_secs"));
+                    }
+                    max_age_secs = Some(map.next_value()?);
+                }
+                "expired_status" => {
+                    if expired_status.is_some() {
+                        return Err(de::Error::duplicate_field("expired_status"));
+                    }
+                    expired_status = Some(map.next_value()?);
+                }
+                "durability" => {
+                    if durability.is_some() {
+                        return Err(de::Error::duplicate_field("durability"));
+                    }
+                    durability = Some(map.next_value()?);
+                }
+                "encryption" => {
+                    if encryption.is_some() {
+                        return Err(de::Error::duplicate_field("encryption"));
+                    }
+                    encryption = Some(map.next_value()?);
+                }
                 "when_full" => {
                     if when_full.is_some() {
                         return Err(de::Error::duplicate_field("when_full"));
@@ -87,25 +154,71 @@ impl BufferTypeVisitor {
         match kind {
             BufferTypeKind::Memory => {
                 if max_size.is_some() {
-                    return Err(de::Error::unknown_field(
-                        "max_size",
-                        &["type", "max_events", "when_full"],
-                    ));
+                    return Err(de::Error::unknown_field("max_size", MEMORY_FIELDS));
+                }
+                if max_age_secs.is_some() {
+                    return Err(de::Error::unknown_field("max_age_secs", MEMORY_FIELDS));
+                }
+                if expired_status.is_some() {
+                    return Err(de::Error::unknown_field("expired_status", MEMORY_FIELDS));
+                }
+                if durability.is_some() {
+                    return Err(de::Error::unknown_field("durability", MEMORY_FIELDS));
+                }
+                if encryption.is_some() {
+                    return Err(de::Error::unknown_field("encryption", MEMORY_FIELDS));
+                }
+                // Without any limit, the buffer is limited to the default number of events.
+                if max_events.is_none() && max_size_bytes.is_none() {
+                    max_events = Some(memory_buffer_default_max_events());
+                }
+                if let (Some(max_events), Some(max_size_bytes)) = (max_events, max_size_bytes) {
+                    // Each event takes at least one byte, so the byte limit would always be
+                    // reached first.
+                    if max_size_bytes < max_events {
+                        return Err(de::Error::custom(format!(
+                            "invalid value for `max_size_bytes`: must be at least `max_events` ({max_events}), but is {max_size_bytes}"
+                        )));
+                    }
                 }
                 Ok(BufferType::Memory {
-                    max_events: max_events.unwrap_or_else(memory_buffer_default_max_events),
+                    max_events,
+                    max_size_bytes,
                     when_full,
                 })
             }
             BufferTypeKind::DiskV2 => {
-                if max_events.is_some() {
+                if max_events.is_some() || max_size_bytes.is_some() {
+                    let field = if max_events.is_some() {
+                        "max_events"
+                    } else {
+                        "max_size_bytes"
+                    };
                     return Err(de::Error::unknown_field(
-                        "max_events",
-                        &["type", "max_size", "when_full"],
+                        field,
+                        &[
+                            "type",
+                            "max_size",
+                            "max_age_secs",
+                            "expired_status",
+                            "durability",
+                            "encryption",
+                            "when_full",
+                        ],
                     ));
                 }
+                let max_size = max_size.ok_or_else(|| de::Error::missing_field("max_size"))?;
+                if max_size.get() < DISK_BUFFER_MIN_SIZE {
+                    return Err(de::Error::custom(format!(
+                        "invalid value for `max_size`: must be at least {DISK_BUFFER_MIN_SIZE}, but is {max_size}"
+                    )));
+                }
                 Ok(BufferType::DiskV2 {
-                    max_size: max_size.ok_or_else(|| de::Error::missing_field("max_size"))?,
+                    max_size,
+                    max_age_secs,
+                    expired_status: expired_status.unwrap_or_default(),
+                    durability: durability.unwrap_or_default(),
+                    encryption,
                     when_full,
                 })
             }
@@ -175,9 +288,147 @@ impl DiskUsage {
     }
 }
 
+/// Encryption of the events in a disk buffer.
+///
+/// Exactly one source of the key must be set. The key is 32 bytes encoded in base64, such as one
+/// generated by `openssl rand -base64 32`, and is never written to disk.
+#[configurable_component]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct DiskBufferEncryption {
+    /// The path of a file containing the key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[configurable(metadata(docs::examples = "/etc/vector/buffer.key"))]
+    pub key_file: Option<PathBuf>,
+
+    /// The name of an environment variable containing the key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[configurable(metadata(docs::examples = "VECTOR_BUFFER_KEY"))]
+    pub key_env: Option<String>,
+
+    /// The key itself.
+    ///
+    /// This is meant to be fetched from a secrets backend, such as `SECRET[backend.buffer_key]`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[configurable(metadata(docs::examples = "SECRET[backend.buffer_key]"))]
+    pub key: Option<SensitiveString>,
+}
+
+impl DiskBufferEncryption {
+    /// Loads the key from its configured source.
+    ///
+    /// # Errors
+    ///
+    /// If not exactly one source is set, the source cannot be read, or it does not hold a valid
+    /// key, an error describing the issue is returned.
+    pub fn load_key(&self) -> Result<[u8; ENCRYPTION_KEY_LEN], String> {
+        let encoded = match (&self.key_file, &self.key_env, &self.key) {
+            (Some(path), None, None) => std::fs::read_to_string(path)
+                .map_err(|error| format!("failed to read {}: {error}", path.display()))?,
+            (None, Some(name), None) => std::env::var(name)
+                .map_err(|error| format!("failed to read environment variable {name}: {error}"))?,
+            (None, None, Some(key)) => key.inner().to_owned(),
+            _ => return Err("exactly one of `key_file`, `key_env` or `key` must be set".to_owned()),
+        };
+
+        let key = BASE64_STANDARD
+            .decode(encoded.trim())
+            .map_err(|error| format!("the key is not valid base64: {error}"))?;
+        key.try_into().map_err(|key: Vec<u8>| {
+            format!(
+                "the key must be {ENCRYPTION_KEY_LEN} bytes long, but is {} bytes long",
+                key.len()
+            )
+        })
+    }
+}
+
+/// How the events discarded for being older than the maximum age of a disk buffer are reported.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DiskBufferExpiredStatus {
+    /// Expired events are finalized as delivered, and counted as discarded intentionally.
+    #[default]
+    Delivered,
+
+    /// Expired events are finalized as rejected, counted as discarded unintentionally, and logged
+    /// as an error.
+    Rejected,
+}
+
+impl From<DiskBufferExpiredStatus> for EventStatus {
+    fn from(status: DiskBufferExpiredStatus) -> Self {
+        match status {
+            DiskBufferExpiredStatus::Delivered => Self::Delivered,
+            DiskBufferExpiredStatus::Rejected => Self::Rejected,
+        }
+    }
+}
+
+const fn default_durability_interval_ms() -> NonZeroU64 {
+    unsafe { NonZeroU64::new_unchecked(500) }
+}
+
+/// When the events in a disk buffer are synchronized to disk, and acknowledged.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(tag = "mode", rename_all = "snake_case", deny_unknown_fields)]
+#[configurable(metadata(
+    docs::enum_tag_description = "When events are synchronized to disk, and acknowledged."
+))]
+pub enum DiskBufferDurability {
+    /// Events are acknowledged once written to the buffer, which only puts them in the page cache
+    /// of the operating system.
+    ///
+    /// The buffer is still synchronized to disk every 500 milliseconds while events are written to
+    /// it, but acknowledged events written since the last synchronization are lost if the host
+    /// crashes or loses power.
+    #[default]
+    None,
+
+    /// Events are synchronized to disk periodically, and acknowledged once synchronized.
+    ///
+    /// Synchronizations are batched, so events are acknowledged up to about `interval_ms` after
+    /// being written.
+    Interval {
+        /// The interval between synchronizations.
+        #[serde(default = "default_durability_interval_ms")]
+        #[configurable(metadata(docs::type_unit = "milliseconds"))]
+        interval_ms: NonZeroU64,
+
+        /// The amount of data written, past which the buffer is synchronized before the interval
+        /// elapses.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[configurable(metadata(docs::type_unit = "bytes"))]
+        max_bytes: Option<NonZeroU64>,
+    },
+
+    /// Events are synchronized to disk before being acknowledged, every time they are written.
+    ///
+    /// This is the most durable, and slowest, mode.
+    Always,
+}
+
+impl From<DiskBufferDurability> for Durability {
+    fn from(durability: DiskBufferDurability) -> Self {
+        match durability {
+            DiskBufferDurability::None => Self::None,
+            DiskBufferDurability::Interval {
+                interval_ms,
+                max_bytes,
+            } => Self::Interval {
+                interval: Duration::from_millis(interval_ms.get()),
+                max_bytes: max_bytes.map(NonZeroU64::get),
+            },
+            DiskBufferDurability::Always => Self::Always,
+        }
+    }
+}
+
 /// A specific type of buffer stage.
 #[configurable_component(no_deser)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[serde(rename_all = "snake_case", tag = "type")]
 #[configurable(metadata(docs::enum_tag_description = "The type of buffer to use."))]
 pub enum BufferType {
@@ -189,8 +440,21 @@ pub enum BufferType {
     #[serde(rename = "memory")]
     Memory {
         /// The maximum number of events allowed in the buffer.
-        #[serde(default = "memory_buffer_default_max_events")]
-        max_events: NonZeroUsize,
+        ///
+        /// If neither this nor `max_size_bytes` is set, the buffer is limited to 500 events.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_events: Option<NonZeroUsize>,
+
+        /// The maximum estimated size of the events allowed in the buffer.
+        ///
+        /// This can be set instead of, or along with, `max_events`. When both are set, the buffer
+        /// is full once either limit is reached.
+        ///
+        /// Sizes are estimated from the in-memory representation of events, which is larger than
+        /// their encoded size.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[configurable(metadata(docs::type_unit = "bytes"))]
+        max_size_bytes: Option<NonZeroUsize>,
 
         #[configurable(derived)]
         #[serde(default)]
@@ -202,7 +466,7 @@ pub enum BufferType {
     /// This is less performant, but more durable. Data that has been synchronized to disk will not
     /// be lost if Vector is restarted forcefully or crashes.
     ///
-    /// Data is synchronized to disk every 500ms.
+    /// By default, data is synchronized to disk every 500ms. See `durability` to change this.
     #[configurable(title = "Events are buffered on disk.")]
     #[serde(rename = "disk")]
     DiskV2 {
@@ -215,6 +479,42 @@ pub enum BufferType {
         )]
         max_size: NonZeroU64,
 
+        /// The maximum age of the events in the buffer.
+        ///
+        /// Events older than this, based on the time they were written to the buffer, are discarded
+        /// instead of being sent to the sink, such as after the sink was unavailable for a long time.
+        /// Events are acknowledged once stored in a disk buffer, so discarding them does not affect
+        /// their sources.
+        ///
+        /// Only the events written while this option is set can expire.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[configurable(metadata(docs::type_unit = "seconds"))]
+        max_age_secs: Option<NonZeroU64>,
+
+        /// The status expired events are finalized with.
+        ///
+        /// As events are acknowledged to their sources once stored in the buffer, this only changes
+        /// how expired events are reported.
+        #[configurable(derived)]
+        #[serde(default)]
+        expired_status: DiskBufferExpiredStatus,
+
+        #[configurable(derived)]
+        #[serde(default)]
+        durability: DiskBufferDurability,
+
+        /// Encrypts the events in the buffer.
+        ///
+        /// Events are encrypted with XChaCha20-Poly1305 as they are written to the buffer. Events
+        /// already in the buffer when encryption is enabled are read as they are, and the buffer
+        /// only refuses to open without its key while it holds encrypted events. Likewise, it
+        /// refuses to open with a key other than the one it was encrypted with while it holds
+        /// events, and switches to the new key once it is empty.
+        ///
+        /// Encryption adds 40 bytes to each record, and the cost of encrypting and decrypting it.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        encryption: Option<DiskBufferEncryption>,
+
         #[configurable(derived)]
         #[serde(default)]
         when_full: WhenFull,
@@ -276,19 +576,40 @@ impl BufferType {
     where
         T: Bufferable + Clone + Finalizable,
     {
-        match *self {
+        match self {
             BufferType::Memory {
                 when_full,
                 max_events,
+                max_size_bytes,
             } => {
-                builder.stage(MemoryBuffer::new(max_events), when_full);
+                builder.stage(
+                    MemoryBuffer::with_limits(*max_events, *max_size_bytes),
+                    *when_full,
+                );
             }
             BufferType::DiskV2 {
                 when_full,
                 max_size,
+                max_age_secs,
+                expired_status,
+                durability,
+                encryption,
             } => {
                 let data_dir = data_dir.ok_or(BufferBuildError::RequiresDataDir)?;
-                builder.stage(DiskV2Buffer::new(id, data_dir, max_size), when_full);
+                let max_age = max_age_secs.map(|secs| Duration::from_secs(secs.get()));
+                let encryption_key = encryption
+                    .as_ref()
+                    .map(DiskBufferEncryption::load_key)
+                    .transpose()
+                    .map_err(|reason| BufferBuildError::InvalidEncryptionKey { reason })?;
+                builder.stage(
+                    DiskV2Buffer::new(id, data_dir, *max_size)
+                        .with_max_age(max_age)
+                        .with_expired_status((*expired_status).into())
+                        .with_durability((*durability).into())
+                        .with_encryption_key(encryption_key),
+                    *when_full,
+                );
             }
         };
 
@@ -310,10 +631,8 @@ impl BufferType {
 /// functionality to allow chaining buffers together, you'll see "buffer topology" used in internal
 /// documentation to correctly reflect the internal structure.
 ///
-// TODO: We need to limit chained buffers to only allowing a single copy of each buffer type to be
-// defined, otherwise, for example, two instances of the same disk buffer type in a single chained
-// buffer topology would try to both open the same buffer files on disk, which wouldn't work or
-// would go horribly wrong.
+/// A chained buffer can only have a single disk stage, as its stages share the data directory of
+/// the sink, and two disk stages would try to open the same buffer files.
 #[configurable_component]
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[serde(untagged)]
@@ -329,13 +648,17 @@ pub enum BufferConfig {
     Single(BufferType),
 
     /// A chained buffer topology.
+    ///
+    /// Each stage but the last must be set to overflow into the next one when full, such as a
+    /// memory stage overflowing into a disk stage.
     Chained(Vec<BufferType>),
 }
 
 impl Default for BufferConfig {
     fn default() -> Self {
         Self::Single(BufferType::Memory {
-            max_events: memory_buffer_default_max_events(),
+            max_events: Some(memory_buffer_default_max_events()),
+            max_size_bytes: None,
             when_full: WhenFull::default(),
         })
     }
@@ -357,8 +680,8 @@ impl BufferConfig {
     ///
     /// # Errors
     ///
-    /// If the buffer is configured with anything other than a single stage, an error variant will
-    /// be thrown.
+    /// If the buffer is configured with more than one disk stage, or with stages which don't
+    /// overflow into the next one, an error variant will be thrown.
     ///
     /// If a disk buffer stage is configured and the data directory provided is `None`, an error
     /// variant will be thrown.
@@ -372,6 +695,15 @@ impl BufferConfig {
     where
         T: Bufferable + Clone + Finalizable,
     {
+        let disk_stages = self
+            .stages()
+            .iter()
+            .filter(|stage| matches!(stage, BufferType::DiskV2 { .. }))
+            .count();
+        if disk_stages > 1 {
+            return Err(BufferBuildError::MultipleDiskStages);
+        }
+
         let mut builder = TopologyBuilder::default();
 
         for stage in self.stages() {
@@ -389,7 +721,14 @@ impl BufferConfig {
 mod test {
     use std::num::{NonZeroU64, NonZeroUsize};
 
-    use crate::{BufferConfig, BufferType, WhenFull};
+    use base64::prelude::{Engine as _, BASE64_STANDARD};
+
+    use tracing::Span;
+
+    use super::{
+        BufferBuildError, DiskBufferDurability, DiskBufferEncryption, DiskBufferExpiredStatus,
+    };
+    use crate::{test::SizedRecord, BufferConfig, BufferType, WhenFull};
 
     fn check_single_stage(source: &str, expected: BufferType) {
         let config: BufferConfig = serde_yaml::from_str(source).unwrap();
@@ -439,7 +778,8 @@ max_events: 42
           max_events: 100
           ",
             BufferType::Memory {
-                max_events: NonZeroUsize::new(100).unwrap(),
+                max_events: NonZeroUsize::new(100),
+                max_size_bytes: None,
                 when_full: WhenFull::Block,
             },
         );
@@ -455,17 +795,61 @@ max_events: 42
           ",
             &[
                 BufferType::Memory {
-                    max_events: NonZeroUsize::new(42).unwrap(),
+                    max_events: NonZeroUsize::new(42),
+                    max_size_bytes: None,
                     when_full: WhenFull::Block,
                 },
                 BufferType::Memory {
-                    max_events: NonZeroUsize::new(100).unwrap(),
+                    max_events: NonZeroUsize::new(100),
+                    max_size_bytes: None,
                     when_full: WhenFull::DropNewest,
                 },
             ],
         );
     }
 
+    #[tokio::test]
+    async fn parse_memory_overflowing_to_disk() {
+        check_multiple_stages(
+            r"
+          - type: memory
+            max_events: 1000
+            when_full: overflow
+          - type: disk
+            max_size: 268435488
+          ",
+            &[
+                BufferType::Memory {
+                    max_events: NonZeroUsize::new(1000),
+                    max_size_bytes: None,
+                    when_full: WhenFull::Overflow,
+                },
+                BufferType::DiskV2 {
+                    max_size: NonZeroU64::new(268_435_488).unwrap(),
+                    max_age_secs: None,
+                    expired_status: DiskBufferExpiredStatus::Delivered,
+                    durability: DiskBufferDurability::None,
+                    encryption: None,
+                    when_full: WhenFull::Block,
+                },
+            ],
+        );
+
+        let source = r"
+          - type: disk
+            max_size: 268435488
+            when_full: overflow
+          - type: disk
+            max_size: 268435488
+          ";
+        let config: BufferConfig = serde_yaml::from_str(source).unwrap();
+        let error = config
+            .build::<SizedRecord>(None, String::from("test"), Span::none())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, BufferBuildError::MultipleDiskStages));
+    }
+
     #[test]
     fn ensure_field_defaults_for_all_types() {
         check_single_stage(
@@ -473,7 +857,8 @@ max_events: 42
           type: memory
           ",
             BufferType::Memory {
-                max_events: NonZeroUsize::new(500).unwrap(),
+                max_events: NonZeroUsize::new(500),
+                max_size_bytes: None,
                 when_full: WhenFull::Block,
             },
         );
@@ -484,7 +869,8 @@ max_events: 42
           max_events: 100
           ",
             BufferType::Memory {
-                max_events: NonZeroUsize::new(100).unwrap(),
+                max_events: NonZeroUsize::new(100),
+                max_size_bytes: None,
                 when_full: WhenFull::Block,
             },
         );
@@ -495,7 +881,8 @@ max_events: 42
           when_full: drop_newest
           ",
             BufferType::Memory {
-                max_events: NonZeroUsize::new(500).unwrap(),
+                max_events: NonZeroUsize::new(500),
+                max_size_bytes: None,
                 when_full: WhenFull::DropNewest,
             },
         );
@@ -506,7 +893,8 @@ max_events: 42
           when_full: overflow
           ",
             BufferType::Memory {
-                max_events: NonZeroUsize::new(500).unwrap(),
+                max_events: NonZeroUsize::new(500),
+                max_size_bytes: None,
                 when_full: WhenFull::Overflow,
             },
         );
@@ -514,12 +902,239 @@ max_events: 42
         check_single_stage(
             r"
           type: disk
+          max_size: 268435488
+          ",
+            BufferType::DiskV2 {
+                max_size: NonZeroU64::new(268_435_488).unwrap(),
+                max_age_secs: None,
+                expired_status: DiskBufferExpiredStatus::Delivered,
+                durability: DiskBufferDurability::None,
+                encryption: None,
+                when_full: WhenFull::Block,
+            },
+        );
+    }
+
+    #[test]
+    fn parse_disk_max_size_too_small() {
+        let source = r"
+          type: disk
           max_size: 1024
+          ";
+        let error = serde_yaml::from_str::<BufferType>(source).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("invalid value for `max_size`: must be at least 268435488, but is 1024"));
+    }
+
+    #[test]
+    fn parse_disk_max_age() {
+        check_single_stage(
+            r"
+          type: disk
+          max_size: 268435488
+          max_age_secs: 3600
+          ",
+            BufferType::DiskV2 {
+                max_size: NonZeroU64::new(268_435_488).unwrap(),
+                max_age_secs: NonZeroU64::new(3600),
+                expired_status: DiskBufferExpiredStatus::Delivered,
+                durability: DiskBufferDurability::None,
+                encryption: None,
+                when_full: WhenFull::Block,
+            },
+        );
+
+        let source = r"
+          type: memory
+          max_age_secs: 3600
+          ";
+        let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
+        assert_eq!(error.to_string(), BUFFER_CONFIG_NO_MATCH_ERR);
+    }
+
+    #[test]
+    fn parse_disk_expired_status() {
+        for (status, expected) in [
+            ("delivered", DiskBufferExpiredStatus::Delivered),
+            ("rejected", DiskBufferExpiredStatus::Rejected),
+        ] {
+            check_single_stage(
+                &format!(
+                    r"
+          type: disk
+          max_size: 268435488
+          max_age_secs: 3600
+          expired_status: {status}
+          "
+                ),
+                BufferType::DiskV2 {
+                    max_size: NonZeroU64::new(268_435_488).unwrap(),
+                    max_age_secs: NonZeroU64::new(3600),
+                    expired_status: expected,
+                    durability: DiskBufferDurability::None,
+                    encryption: None,
+                    when_full: WhenFull::Block,
+                },
+            );
+        }
+
+        let source = r"
+          type: memory
+          expired_status: rejected
+          ";
+        let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
+        assert_eq!(error.to_string(), BUFFER_CONFIG_NO_MATCH_ERR);
+    }
+
+    #[test]
+    fn parse_memory_max_size_bytes() {
+        check_single_stage(
+            r"
+          type: memory
+          max_size_bytes: 1048576
+          ",
+            BufferType::Memory {
+                max_events: None,
+                max_size_bytes: NonZeroUsize::new(1_048_576),
+                when_full: WhenFull::Block,
+            },
+        );
+
+        check_single_stage(
+            r"
+          type: memory
+          max_events: 100
+          max_size_bytes: 1048576
+          when_full: drop_newest
+          ",
+            BufferType::Memory {
+                max_events: NonZeroUsize::new(100),
+                max_size_bytes: NonZeroUsize::new(1_048_576),
+                when_full: WhenFull::DropNewest,
+            },
+        );
+
+        let source = r"
+          type: memory
+          max_events: 100
+          max_size_bytes: 10
+          ";
+        let error = serde_yaml::from_str::<BufferType>(source).unwrap_err();
+        assert!(error.to_string().starts_with(
+            "invalid value for `max_size_bytes`: must be at least `max_events` (100), but is 10"
+        ));
+
+        let source = r"
+          type: disk
+          max_size: 268435488
+          max_size_bytes: 1048576
+          ";
+        let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
+        assert_eq!(error.to_string(), BUFFER_CONFIG_NO_MATCH_ERR);
+    }
+
+    #[test]
+    fn parse_disk_encryption() {
+        check_single_stage(
+            r"
+          type: disk
+          max_size: 268435488
+          encryption:
+            key_env: VECTOR_BUFFER_KEY
           ",
             BufferType::DiskV2 {
-                max_size: NonZeroU64::new(1024).unwrap(),
+                max_size: NonZeroU64::new(268_435_488).unwrap(),
+                max_age_secs: None,
+                expired_status: DiskBufferExpiredStatus::Delivered,
+                durability: DiskBufferDurability::None,
+                encryption: Some(DiskBufferEncryption {
+                    key_file: None,
+                    key_env: Some("VECTOR_BUFFER_KEY".to_owned()),
+                    key: None,
+                }),
                 when_full: WhenFull::Block,
             },
         );
+
+        let source = r"
+          type: memory
+          encryption:
+            key_env: VECTOR_BUFFER_KEY
+          ";
+        let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
+        assert_eq!(error.to_string(), BUFFER_CONFIG_NO_MATCH_ERR);
+    }
+
+    #[test]
+    fn parse_disk_durability() {
+        check_single_stage(
+            r"
+          type: disk
+          max_size: 268435488
+          durability:
+            mode: interval
+            max_bytes: 1048576
+          ",
+            BufferType::DiskV2 {
+                max_size: NonZeroU64::new(268_435_488).unwrap(),
+                max_age_secs: None,
+                expired_status: DiskBufferExpiredStatus::Delivered,
+                durability: DiskBufferDurability::Interval {
+                    interval_ms: NonZeroU64::new(500).unwrap(),
+                    max_bytes: NonZeroU64::new(1_048_576),
+                },
+                encryption: None,
+                when_full: WhenFull::Block,
+            },
+        );
+
+        check_single_stage(
+            r"
+          type: disk
+          max_size: 268435488
+          durability:
+            mode: always
+          ",
+            BufferType::DiskV2 {
+                max_size: NonZeroU64::new(268_435_488).unwrap(),
+                max_age_secs: None,
+                expired_status: DiskBufferExpiredStatus::Delivered,
+                durability: DiskBufferDurability::Always,
+                encryption: None,
+                when_full: WhenFull::Block,
+            },
+        );
+
+        let source = r"
+          type: memory
+          durability:
+            mode: always
+          ";
+        let error = serde_yaml::from_str::<BufferConfig>(source).unwrap_err();
+        assert_eq!(error.to_string(), BUFFER_CONFIG_NO_MATCH_ERR);
+    }
+
+    #[test]
+    fn load_encryption_key() {
+        let encryption = |key: &str| DiskBufferEncryption {
+            key_file: None,
+            key_env: None,
+            key: Some(key.to_owned().into()),
+        };
+
+        let key = [7; 32];
+        let encoded = format!(" {}\n", BASE64_STANDARD.encode(key));
+        assert_eq!(encryption(&encoded).load_key().unwrap(), key);
+        assert!(encryption("not base64").load_key().is_err());
+        assert!(encryption(&BASE64_STANDARD.encode([7; 16]))
+            .load_key()
+            .is_err());
+
+        let ambiguous = DiskBufferEncryption {
+            key_env: Some("VECTOR_BUFFER_KEY".to_owned()),
+            ..encryption(&encoded)
+        };
+        assert!(ambiguous.load_key().is_err());
     }
 }
@@ -94,6 +94,7 @@ impl InternalEvent for BufferEventsDropped {
         }
         counter!(
             "buffer_discarded_events_total", "intentional" => intentional_str,
+            "reason" => self.reason,
         )
         .increment(self.count);
         gauge!("buffer_events", "stage" => self.idx.to_string()).decrement(self.count as f64);
@@ -102,6 +103,57 @@ impl InternalEvent for BufferEventsDropped {
     }
 }
 
+/// Events discarded for being older than the maximum age of the records of a buffer.
+///
+/// Expired events are removed from the buffer as they are acknowledged, which already updates the
+/// buffer size gauges. They are discarded unintentionally when the buffer expires them as rejected.
+pub struct BufferEventsExpired {
+    pub idx: usize,
+    pub intentional: bool,
+    pub count: u64,
+    pub byte_size: u64,
+}
+
+impl InternalEvent for BufferEventsExpired {
+    fn emit(self) {
+        let intentional_str = if self.intentional { "true" } else { "false" };
+        if self.intentional {
+            debug!(
+                message = "Events expired.",
+                count = %self.count,
+                byte_size = %self.byte_size,
+                intentional = %intentional_str,
+                stage = %self.idx,
+            );
+        } else {
+            error!(
+                message = "Events expired.",
+                count = %self.count,
+                byte_size = %self.byte_size,
+                intentional = %intentional_str,
+                stage = %self.idx,
+            );
+        }
+        counter!(
+            "buffer_discarded_events_total", "intentional" => intentional_str,
+            "reason" => "expired",
+        )
+        .increment(self.count);
+    }
+}
+
+pub struct BufferOldestEventAge {
+    pub idx: usize,
+    pub age: Duration,
+}
+
+impl InternalEvent for BufferOldestEventAge {
+    fn emit(self) {
+        gauge!("buffer_oldest_event_age_seconds", "stage" => self.idx.to_string())
+            .set(self.age.as_secs_f64());
+    }
+}
+
 pub struct BufferReadError {
     pub error_code: &'static str,
     pub error: String,
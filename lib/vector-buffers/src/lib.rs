@@ -17,7 +17,9 @@ extern crate tracing;
 mod buffer_usage_data;
 
 pub mod config;
-pub use config::{BufferConfig, BufferType};
+pub use config::{
+    BufferConfig, BufferType, DiskBufferDurability, DiskBufferEncryption, DiskBufferExpiredStatus,
+};
 use encoding::Encodable;
 use vector_config::configurable_component;
 
@@ -66,8 +68,10 @@ pub enum WhenFull {
     /// buffer topology must use one of the other handling behaviors. This means that next stage may
     /// potentially be able to buffer the event, but it may also block or drop the event.
     ///
-    /// This mode can only be used when two or more buffer stages are configured.
-    #[configurable(metadata(docs::hidden))]
+    /// This mode can only be used when two or more buffer stages are configured, such as a memory
+    /// stage overflowing to a disk stage. Events stay in memory while it has room, and only spill to
+    /// disk when it is full. Events are only approximately ordered while spilling and draining, as
+    /// the events spilled to disk are read alongside the newer events in memory.
     Overflow,
 }
 
@@ -257,15 +257,24 @@ impl<T: Bufferable> Default for TopologyBuilder<T> {
 
 #[cfg(test)]
 mod tests {
-    use std::num::NonZeroUsize;
+    use std::{
+        num::{NonZeroU64, NonZeroUsize},
+        path::Path,
+    };
 
+    use tokio::sync::oneshot::error::TryRecvError;
     use tracing::Span;
+    use vector_common::finalization::{AddBatchNotifier, BatchNotifier, BatchStatus};
 
     use super::TopologyBuilder;
     use crate::{
+        test::{with_temp_dir, SizedRecord},
         topology::builder::TopologyError,
-        topology::test_util::{assert_current_send_capacity, Sample},
-        variants::MemoryBuffer,
+        topology::{
+            channel::{BufferReceiver, BufferSender},
+            test_util::{assert_current_send_capacity, Sample},
+        },
+        variants::{DiskV2Buffer, MemoryBuffer},
         WhenFull,
     };
 
@@ -365,4 +374,80 @@ mod tests {
         let (mut sender, _) = result.unwrap();
         assert_current_send_capacity(&mut sender, Some(1), Some(1));
     }
+
+    async fn memory_overflowing_to_disk(
+        data_dir: &Path,
+    ) -> (BufferSender<SizedRecord>, BufferReceiver<SizedRecord>) {
+        let mut builder = TopologyBuilder::<SizedRecord>::default();
+        builder.stage(
+            MemoryBuffer::new(NonZeroUsize::new(2).unwrap()),
+            WhenFull::Overflow,
+        );
+        builder.stage(
+            DiskV2Buffer::new(
+                String::from("test"),
+                data_dir.to_path_buf(),
+                NonZeroU64::new(268_435_488).unwrap(),
+            ),
+            WhenFull::Block,
+        );
+        builder
+            .build(String::from("test"), Span::none())
+            .await
+            .expect("building the buffer should not fail")
+    }
+
+    #[tokio::test]
+    async fn overflow_to_disk_survives_restart() {
+        with_temp_dir(|dir| {
+            let data_dir = dir.to_path_buf();
+
+            async move {
+                let (mut sender, receiver) = memory_overflowing_to_disk(&data_dir).await;
+
+                let mut statuses = Vec::new();
+                for size in 1..=5 {
+                    let (batch, status) = BatchNotifier::new_with_receiver();
+                    let mut record = SizedRecord::new(size);
+                    record.add_batch_notifier(batch);
+                    sender
+                        .send(record, None)
+                        .await
+                        .expect("send should not fail");
+                    statuses.push(status);
+                }
+                sender.flush().await.expect("flush should not fail");
+
+                // The first two records fill the memory stage, and are only acknowledged once
+                // sent, while the others spill to the disk stage, which acknowledges them as
+                // they are written.
+                for status in &mut statuses[..2] {
+                    assert_eq!(status.try_recv(), Err(TryRecvError::Empty));
+                }
+                for status in &mut statuses[2..] {
+                    assert_eq!(status.try_recv(), Ok(BatchStatus::Delivered));
+                }
+
+                // Simulate a crash, which loses the records in memory.
+                drop(sender);
+                drop(receiver);
+
+                let (_sender, mut receiver) = memory_overflowing_to_disk(&data_dir).await;
+                let mut records = Vec::new();
+                for _ in 0..3 {
+                    let record = await_timeout!(receiver.next(), 2);
+                    records.push(record.expect("spilled record should be read"));
+                }
+                assert_eq!(
+                    records,
+                    [
+                        SizedRecord::new(3),
+                        SizedRecord::new(4),
+                        SizedRecord::new(5)
+                    ]
+                );
+            }
+        })
+        .await;
+    }
 }
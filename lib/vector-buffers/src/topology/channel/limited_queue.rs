@@ -8,7 +8,7 @@ use std::{
 };
 
 use async_stream::stream;
-use crossbeam_queue::ArrayQueue;
+use crossbeam_queue::{ArrayQueue, SegQueue};
 use futures::Stream;
 use tokio::sync::{Notify, OwnedSemaphorePermit, Semaphore, TryAcquireError};
 
@@ -54,11 +54,56 @@ impl<T> fmt::Display for TrySendError<T> {
 
 impl<T: fmt::Debug> std::error::Error for TrySendError<T> {}
 
+/// The permits held by an item while it is in the channel.
+#[derive(Debug)]
+struct Permits {
+    _events: OwnedSemaphorePermit,
+    _bytes: Option<OwnedSemaphorePermit>,
+}
+
+/// The items in a channel.
+///
+/// A channel limited by the number of events never holds more items than that limit, as each item
+/// takes at least one permit, so they fit in a queue allocated upfront. Channels limited by bytes
+/// alone have no such bound.
+#[derive(Debug)]
+enum Queue<T> {
+    Bounded(ArrayQueue<T>),
+    Unbounded(SegQueue<T>),
+}
+
+impl<T> Queue<T> {
+    fn new(max_events: Option<usize>) -> Self {
+        match max_events {
+            Some(limit) => Self::Bounded(ArrayQueue::new(limit)),
+            None => Self::Unbounded(SegQueue::new()),
+        }
+    }
+
+    fn push(&self, item: T) {
+        match self {
+            Self::Bounded(queue) => queue.push(item).unwrap_or_else(|_| {
+                unreachable!("acquired permits but channel reported being full")
+            }),
+            Self::Unbounded(queue) => queue.push(item),
+        }
+    }
+
+    fn pop(&self) -> Option<T> {
+        match self {
+            Self::Bounded(queue) => queue.pop(),
+            Self::Unbounded(queue) => queue.pop(),
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Inner<T> {
-    data: Arc<ArrayQueue<(OwnedSemaphorePermit, T)>>,
+    data: Arc<Queue<(Permits, T)>>,
     limit: usize,
     limiter: Arc<Semaphore>,
+    byte_limit: Option<usize>,
+    byte_limiter: Option<Arc<Semaphore>>,
     read_waker: Arc<Notify>,
 }
 
@@ -68,11 +113,22 @@ impl<T> Clone for Inner<T> {
             data: self.data.clone(),
             limit: self.limit,
             limiter: self.limiter.clone(),
+            byte_limit: self.byte_limit,
+            byte_limiter: self.byte_limiter.clone(),
             read_waker: self.read_waker.clone(),
         }
     }
 }
 
+impl<T> Inner<T> {
+    fn close(&self) {
+        self.limiter.close();
+        if let Some(byte_limiter) = &self.byte_limiter {
+            byte_limiter.close();
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct LimitedSender<T> {
     inner: Inner<T>,
@@ -88,6 +144,15 @@ impl<T: InMemoryBufferable> LimitedSender<T> {
         cmp::min(self.inner.limit, item.event_count()) as u32
     }
 
+    #[allow(clippy::cast_possible_truncation)]
+    fn get_required_byte_permits_for_item(&self, item: &T) -> Option<u32> {
+        // Like events, items bigger than the byte limit are let in when the queue is empty. A
+        // single request for permits is also limited to `u32::MAX` of them.
+        self.inner
+            .byte_limit
+            .map(|limit| cmp::min(cmp::min(limit, item.size_of()), u32::MAX as usize) as u32)
+    }
+
     /// Gets the number of items that this channel could accept.
     pub fn available_capacity(&self) -> usize {
         self.inner.limiter.available_permits()
@@ -102,7 +167,7 @@ impl<T: InMemoryBufferable> LimitedSender<T> {
     pub async fn send(&mut self, item: T) -> Result<(), SendError<T>> {
         // Calculate how many permits we need, and wait until we can acquire all of them.
         let permits_required = self.get_required_permits_for_item(&item);
-        let Ok(permits) = self
+        let Ok(events) = self
             .inner
             .limiter
             .clone()
@@ -111,11 +176,28 @@ impl<T: InMemoryBufferable> LimitedSender<T> {
         else {
             return Err(SendError(item));
         };
+        let bytes = match (
+            &self.inner.byte_limiter,
+            self.get_required_byte_permits_for_item(&item),
+        ) {
+            (Some(byte_limiter), Some(byte_permits_required)) => {
+                let Ok(bytes) = byte_limiter
+                    .clone()
+                    .acquire_many_owned(byte_permits_required)
+                    .await
+                else {
+                    return Err(SendError(item));
+                };
+                Some(bytes)
+            }
+            _ => None,
+        };
 
-        self.inner
-            .data
-            .push((permits, item))
-            .unwrap_or_else(|_| unreachable!("acquired permits but channel reported being full"));
+        let permits = Permits {
+            _events: events,
+            _bytes: bytes,
+        };
+        self.inner.data.push((permits, item));
         self.inner.read_waker.notify_one();
 
         trace!("Sent item.");
@@ -138,25 +220,36 @@ impl<T: InMemoryBufferable> LimitedSender<T> {
     pub fn try_send(&mut self, item: T) -> Result<(), TrySendError<T>> {
         // Calculate how many permits we need, and try to acquire them all without waiting.
         let permits_required = self.get_required_permits_for_item(&item);
-        let permits = match self
+        let events = match self
             .inner
             .limiter
             .clone()
             .try_acquire_many_owned(permits_required)
         {
             Ok(permits) => permits,
-            Err(ae) => {
-                return match ae {
-                    TryAcquireError::NoPermits => Err(TrySendError::InsufficientCapacity(item)),
-                    TryAcquireError::Closed => Err(TrySendError::Disconnected(item)),
+            Err(ae) => return Err(try_send_error(ae, item)),
+        };
+        let bytes = match (
+            &self.inner.byte_limiter,
+            self.get_required_byte_permits_for_item(&item),
+        ) {
+            (Some(byte_limiter), Some(byte_permits_required)) => {
+                match byte_limiter
+                    .clone()
+                    .try_acquire_many_owned(byte_permits_required)
+                {
+                    Ok(permits) => Some(permits),
+                    Err(ae) => return Err(try_send_error(ae, item)),
                 }
             }
+            _ => None,
         };
 
-        self.inner
-            .data
-            .push((permits, item))
-            .unwrap_or_else(|_| unreachable!("acquired permits but channel reported being full"));
+        let permits = Permits {
+            _events: events,
+            _bytes: bytes,
+        };
+        self.inner.data.push((permits, item));
         self.inner.read_waker.notify_one();
 
         trace!("Attempt to send item succeeded.");
@@ -165,6 +258,13 @@ impl<T: InMemoryBufferable> LimitedSender<T> {
     }
 }
 
+fn try_send_error<T>(error: TryAcquireError, item: T) -> TrySendError<T> {
+    match error {
+        TryAcquireError::NoPermits => TrySendError::InsufficientCapacity(item),
+        TryAcquireError::Closed => TrySendError::Disconnected(item),
+    }
+}
+
 impl<T> Clone for LimitedSender<T> {
     fn clone(&self) -> Self {
         self.sender_count.fetch_add(1, Ordering::SeqCst);
@@ -180,7 +280,7 @@ impl<T> Drop for LimitedSender<T> {
     fn drop(&mut self) {
         // If we're the last sender to drop, close the semaphore on our way out the door.
         if self.sender_count.fetch_sub(1, Ordering::SeqCst) == 1 {
-            self.inner.limiter.close();
+            self.inner.close();
             self.inner.read_waker.notify_one();
         }
     }
@@ -231,15 +331,31 @@ impl<T> Drop for LimitedReceiver<T> {
         // Notify senders that the channel is now closed by closing the semaphore.  Any pending
         // acquisitions will be awoken and notified that the semaphore is closed, and further new
         // sends will immediately see the semaphore is closed.
-        self.inner.limiter.close();
+        self.inner.close();
     }
 }
 
 pub fn limited<T>(limit: usize) -> (LimitedSender<T>, LimitedReceiver<T>) {
+    limited_by_size(Some(limit), None)
+}
+
+/// Creates a channel limited by the number of events in it, their estimated size in bytes, or
+/// both.
+///
+/// Items are let in once there is room for them under both limits. Without any limit, the channel
+/// is unbounded.
+pub fn limited_by_size<T>(
+    max_events: Option<usize>,
+    max_bytes: Option<usize>,
+) -> (LimitedSender<T>, LimitedReceiver<T>) {
+    let limit = max_events.unwrap_or(Semaphore::MAX_PERMITS);
+    let byte_limit = max_bytes.map(|limit| cmp::min(limit, Semaphore::MAX_PERMITS));
     let inner = Inner {
-        data: Arc::new(ArrayQueue::new(limit)),
+        data: Arc::new(Queue::new(max_events)),
         limit,
         limiter: Arc::new(Semaphore::new(limit)),
+        byte_limit,
+        byte_limiter: byte_limit.map(|limit| Arc::new(Semaphore::new(limit))),
         read_waker: Arc::new(Notify::new()),
     };
 
@@ -256,7 +372,7 @@ pub fn limited<T>(limit: usize) -> (LimitedSender<T>, LimitedReceiver<T>) {
 mod tests {
     use tokio_test::{assert_pending, assert_ready, task::spawn};
 
-    use super::limited;
+    use super::{limited, limited_by_size, Queue, TrySendError};
     use crate::{
         test::MultiEventRecord, topology::channel::limited_queue::SendError,
         topology::test_util::Sample,
@@ -595,4 +711,56 @@ mod tests {
 
         assert_eq!(2, tx.available_capacity());
     }
+
+    #[test]
+    fn sender_waits_for_byte_capacity() {
+        // Each sample is 8 bytes, so there is only room for two of them.
+        let (mut tx, mut rx) = limited_by_size(Some(10), Some(16));
+
+        assert_eq!(Ok(()), tx.try_send(Sample(1)));
+        assert_eq!(Ok(()), tx.try_send(Sample(2)));
+        assert_eq!(
+            Err(TrySendError::InsufficientCapacity(Sample(3))),
+            tx.try_send(Sample(3))
+        );
+        assert_eq!(8, tx.available_capacity());
+
+        let mut send = spawn(async { tx.send(Sample(3)).await });
+        assert_pending!(send.poll());
+
+        // Receiving an item frees up its bytes, which lets the pending send through.
+        let mut recv = spawn(async { rx.next().await });
+        assert_eq!(Some(Sample(1)), assert_ready!(recv.poll()));
+        drop(recv);
+
+        assert!(send.is_woken());
+        assert_eq!(Ok(()), assert_ready!(send.poll()));
+    }
+
+    #[test]
+    fn byte_limit_without_event_limit() {
+        let (mut tx, mut rx) = limited_by_size(None, Some(4));
+
+        // Items bigger than the byte limit are still let in when the channel is empty.
+        assert_eq!(Ok(()), tx.try_send(Sample(1)));
+        assert_eq!(
+            Err(TrySendError::InsufficientCapacity(Sample(2))),
+            tx.try_send(Sample(2))
+        );
+
+        let mut recv = spawn(async { rx.next().await });
+        assert_eq!(Some(Sample(1)), assert_ready!(recv.poll()));
+        drop(recv);
+
+        assert_eq!(Ok(()), tx.try_send(Sample(2)));
+    }
+
+    #[test]
+    fn event_limit_bounds_the_queue() {
+        let (tx, _rx) = limited_by_size::<Sample>(Some(10), Some(16));
+        assert!(matches!(&*tx.inner.data, Queue::Bounded(queue) if queue.capacity() == 10));
+
+        let (tx, _rx) = limited_by_size::<Sample>(None, Some(16));
+        assert!(matches!(&*tx.inner.data, Queue::Unbounded(_)));
+    }
 }
@@ -1,4 +1,5 @@
 use std::{
+    future::poll_fn,
     mem,
     pin::Pin,
     task::{ready, Context, Poll},
@@ -69,16 +70,29 @@ where
 /// The receiver handles retrieving events from the buffer, regardless of the overall buffer configuration.
 ///
 /// If a buffer was configured to operate in "overflow" mode, then the receiver will be responsible
-/// for querying the overflow buffer as well.  The ordering of events when operating in "overflow"
-/// is undefined, as the receiver will try to manage polling both its own buffer, as well as the
-/// overflow buffer, in order to fairly balance throughput.
+/// for querying the overflow buffer as well.  Events are only approximately ordered when operating
+/// in "overflow" mode, as the receiver will try to manage polling both its own buffer, as well as
+/// the overflow buffer, in order to fairly balance throughput: events spilled to the overflow
+/// buffer are drained alongside the events entering its own buffer once it has capacity again.
 #[derive(Debug)]
 pub struct BufferReceiver<T: Bufferable> {
     base: ReceiverAdapter<T>,
-    overflow: Option<Box<BufferReceiver<T>>>,
+    overflow: Option<OverflowRead<T>>,
     instrumentation: Option<BufferUsageHandle>,
 }
 
+/// A read from the overflow receiver, kept across calls to [`BufferReceiver::next`].
+///
+/// Reading from a disk buffer is not cancel safe, so a read that loses the race against the base
+/// receiver is resumed on the next call instead of being dropped midway.
+type OverflowRead<T> = ReusableBoxFuture<'static, (Option<T>, BufferReceiver<T>)>;
+
+/// The next item read by an overflowing receiver.
+enum OverflowNext<T: Bufferable> {
+    Base(T),
+    Overflow(Option<T>, BufferReceiver<T>),
+}
+
 impl<T: Bufferable> BufferReceiver<T> {
     /// Creates a new [`BufferReceiver`] wrapping the given channel receiver.
     pub fn new(base: ReceiverAdapter<T>) -> Self {
@@ -93,7 +107,7 @@ impl<T: Bufferable> BufferReceiver<T> {
     pub fn with_overflow(base: ReceiverAdapter<T>, overflow: BufferReceiver<T>) -> Self {
         Self {
             base,
-            overflow: Some(Box::new(overflow)),
+            overflow: Some(ReusableBoxFuture::new(make_recv_future(Some(overflow)))),
             instrumentation: None,
         }
     }
@@ -104,7 +118,7 @@ impl<T: Bufferable> BufferReceiver<T> {
     /// when initially constructing `BufferSender<T>`.
     #[cfg(test)]
     pub fn switch_to_overflow(&mut self, overflow: BufferReceiver<T>) {
-        self.overflow = Some(Box::new(overflow));
+        self.overflow = Some(ReusableBoxFuture::new(make_recv_future(Some(overflow))));
     }
 
     /// Configures this receiver to instrument the items passing through it.
@@ -122,19 +136,25 @@ impl<T: Bufferable> BufferReceiver<T> {
         // occurred, and is over, and items are flowing through the base receiver.  If we waited to
         // entirely drain the overflow receiver, we might cause another small stall of the pipeline
         // attached to the base receiver.
-        let overflow = self.overflow.as_mut().map(Pin::new);
-
-        let (item, from_base) = match overflow {
-            None => match self.base.next().await {
-                Some(item) => (item, true),
-                None => return None,
-            },
-            Some(mut overflow) => {
-                select! {
-                    Some(item) = overflow.next() => (item, false),
-                    Some(item) = self.base.next() => (item, true),
-                    else => return None,
+        let (item, from_base) = loop {
+            let Some(overflow) = self.overflow.as_mut() else {
+                break (self.base.next().await?, true);
+            };
+
+            let next = select! {
+                (item, receiver) = poll_fn(|cx| overflow.poll(cx)) => {
+                    OverflowNext::Overflow(item, receiver)
+                }
+                Some(item) = self.base.next() => OverflowNext::Base(item),
+            };
+            match next {
+                OverflowNext::Base(item) => break (item, true),
+                OverflowNext::Overflow(Some(item), receiver) => {
+                    overflow.set(make_recv_future(Some(receiver)));
+                    break (item, false);
                 }
+                // Once the overflow receiver is done, only the base receiver is left to read from.
+                OverflowNext::Overflow(None, _) => self.overflow = None,
             }
         };
 
@@ -5,8 +5,10 @@ use std::{
 
 use crc32fast::Hasher;
 use snafu::Snafu;
+use vector_common::finalization::EventStatus;
 
 use super::{
+    encryption::{RecordCipher, KEY_LEN},
     io::{Filesystem, ProductionFilesystem},
     ledger::LEDGER_LEN,
     record::RECORD_HEADER_LEN,
@@ -52,6 +54,36 @@ pub(crate) fn create_crc32c_hasher() -> Hasher {
     crc32fast::Hasher::new()
 }
 
+/// When data files are synchronized to disk, and records acknowledged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Durability {
+    /// Records are acknowledged as soon as they are written to the data file, which only puts them
+    /// in the OS page cache.
+    ///
+    /// Data files are still synchronized every `flush_interval` as records are flushed, but
+    /// acknowledged records written since the last synchronization are lost if the host crashes.
+    #[default]
+    None,
+
+    /// Data files are synchronized every `interval`, or once `max_bytes` were written since the
+    /// last synchronization, and records are acknowledged after the synchronization covering them.
+    Interval {
+        interval: Duration,
+        max_bytes: Option<u64>,
+    },
+
+    /// Data files are synchronized every time the writer is flushed, and records are acknowledged
+    /// after that.
+    Always,
+}
+
+impl Durability {
+    /// Whether records are only acknowledged once synchronized to disk.
+    pub const fn holds_acknowledgements(self) -> bool {
+        !matches!(self, Self::None)
+    }
+}
+
 /// Aligns the given amount to 16.
 ///
 /// This is required due to the overalignment used in record serialization, such that we can correctly determine minimum
@@ -153,6 +185,29 @@ pub struct DiskBufferConfig<FS> {
     /// amount of data written since the last flush would be lost.
     pub(crate) flush_interval: Duration,
 
+    /// Maximum age of a record.
+    ///
+    /// When set, records carry the time they were written at, and records older than this are
+    /// discarded by the reader instead of being read.
+    pub(crate) max_record_age: Option<Duration>,
+
+    /// Status the events of expired records are finalized with.
+    ///
+    /// Events expired as [`EventStatus::Rejected`] are reported as discarded unintentionally.
+    pub(crate) expired_status: EventStatus,
+
+    /// When data files are synchronized to disk, and records acknowledged.
+    ///
+    /// Unless this is [`Durability::None`], the acknowledgements of records written to the buffer
+    /// are held back until they are synchronized to disk.
+    pub(crate) durability: Durability,
+
+    /// Cipher encrypting the payload of records.
+    ///
+    /// When set, records are encrypted as they are written, and the buffer refuses to open if it
+    /// was encrypted with another key.
+    pub(crate) cipher: Option<RecordCipher>,
+
     /// Filesystem implementation for opening data files.
     ///
     /// We allow parameterizing the filesystem implementation for ease of testing.  The "filesystem"
@@ -173,6 +228,10 @@ where
     pub(crate) max_record_size: Option<usize>,
     pub(crate) write_buffer_size: Option<usize>,
     pub(crate) flush_interval: Option<Duration>,
+    pub(crate) max_record_age: Option<Duration>,
+    pub(crate) expired_status: EventStatus,
+    pub(crate) durability: Durability,
+    pub(crate) cipher: Option<RecordCipher>,
     pub(crate) filesystem: FS,
 }
 
@@ -188,6 +247,10 @@ impl DiskBufferConfigBuilder {
             max_record_size: None,
             write_buffer_size: None,
             flush_interval: None,
+            max_record_age: None,
+            expired_status: EventStatus::Delivered,
+            durability: Durability::None,
+            cipher: None,
             filesystem: ProductionFilesystem,
         }
     }
@@ -268,6 +331,51 @@ where
         self
     }
 
+    /// Sets the maximum age of a record.
+    ///
+    /// When set, records carry the time they were written at, and records older than this are
+    /// discarded by the reader instead of being read.  Records written while no maximum age was set
+    /// never expire.
+    ///
+    /// Defaults to no maximum age.
+    #[allow(dead_code)]
+    pub fn max_record_age(mut self, max_age: Option<Duration>) -> Self {
+        self.max_record_age = max_age;
+        self
+    }
+
+    /// Sets the status the events of expired records are finalized with.
+    ///
+    /// Defaults to [`EventStatus::Delivered`].
+    #[allow(dead_code)]
+    pub fn expired_status(mut self, status: EventStatus) -> Self {
+        self.expired_status = status;
+        self
+    }
+
+    /// Sets when data files are synchronized to disk, and records acknowledged.
+    ///
+    /// With [`Durability::Interval`], the interval replaces the flush interval.
+    ///
+    /// Defaults to [`Durability::None`].
+    #[allow(dead_code)]
+    pub fn durability(mut self, durability: Durability) -> Self {
+        self.durability = durability;
+        self
+    }
+
+    /// Key encrypting the payload of records.
+    ///
+    /// When set, records are encrypted as they are written.  Records written while no key was set
+    /// are still read as they are, so that enabling encryption drains the plaintext records first.
+    ///
+    /// Defaults to no encryption.
+    #[allow(dead_code)]
+    pub fn encryption_key(mut self, key: Option<[u8; KEY_LEN]>) -> Self {
+        self.cipher = key.as_ref().map(RecordCipher::new);
+        self
+    }
+
     /// Filesystem implementation for opening data files.
     ///
     /// We allow parameterizing the filesystem implementation for ease of testing.  The "filesystem"
@@ -287,6 +395,10 @@ where
             max_record_size: self.max_record_size,
             write_buffer_size: self.write_buffer_size,
             flush_interval: self.flush_interval,
+            max_record_age: self.max_record_age,
+            expired_status: self.expired_status,
+            durability: self.durability,
+            cipher: self.cipher,
             filesystem,
         }
     }
@@ -300,7 +412,12 @@ where
         });
         let max_record_size = self.max_record_size.unwrap_or(DEFAULT_MAX_RECORD_SIZE);
         let write_buffer_size = self.write_buffer_size.unwrap_or(DEFAULT_WRITE_BUFFER_SIZE);
-        let flush_interval = self.flush_interval.unwrap_or(DEFAULT_FLUSH_INTERVAL);
+        let flush_interval = match self.durability {
+            Durability::Interval { interval, .. } => interval,
+            Durability::None | Durability::Always => {
+                self.flush_interval.unwrap_or(DEFAULT_FLUSH_INTERVAL)
+            }
+        };
         let filesystem = self.filesystem;
 
         // Validate the input parameters.
@@ -358,6 +475,26 @@ where
             });
         }
 
+        if let Durability::Interval {
+            interval,
+            max_bytes,
+        } = self.durability
+        {
+            if interval.is_zero() {
+                return Err(BuildError::InvalidParameter {
+                    param_name: "durability",
+                    reason: "interval cannot be zero".to_string(),
+                });
+            }
+
+            if max_bytes == Some(0) {
+                return Err(BuildError::InvalidParameter {
+                    param_name: "durability",
+                    reason: "maximum bytes cannot be zero".to_string(),
+                });
+            }
+        }
+
         if write_buffer_size == 0 {
             return Err(BuildError::InvalidParameter {
                 param_name: "write_buffer_size",
@@ -412,6 +549,10 @@ where
             max_record_size,
             write_buffer_size,
             flush_interval,
+            max_record_age: self.max_record_age,
+            expired_status: self.expired_status,
+            durability: self.durability,
+            cipher: self.cipher,
             filesystem,
         })
     }
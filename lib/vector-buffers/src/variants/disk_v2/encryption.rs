@@ -0,0 +1,222 @@
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    aead::{AeadCore, AeadInPlace, KeyInit, OsRng},
+    Key, Tag, XChaCha20Poly1305, XNonce,
+};
+use snafu::{ResultExt, Snafu};
+
+/// Length of the keys encrypting records.
+pub const KEY_LEN: usize = 32;
+
+/// Length of the random nonce prefixed to the payload of encrypted records.
+const NONCE_LEN: usize = 24;
+
+/// Length of the authentication tag suffixed to the payload of encrypted records.
+const TAG_LEN: usize = 16;
+
+/// Number of bytes an encrypted record payload takes on top of its plaintext.
+pub const ENCRYPTION_OVERHEAD: usize = NONCE_LEN + TAG_LEN;
+
+/// Name of the file, within the buffer directory, used to check that the configured key is the one
+/// the buffer was encrypted with.
+const KEY_CHECK_FILE_NAME: &str = "encryption.check";
+
+/// Plaintext sealed into the key check file.
+const KEY_CHECK_PLAINTEXT: &[u8] = b"vector disk buffer v2";
+
+/// Error that occurred while checking the encryption key of a buffer.
+#[derive(Debug, Snafu)]
+pub enum EncryptionError {
+    /// The key check file could not be read or written.
+    #[snafu(display("failed to access encryption key check file {}: {}", path.display(), source))]
+    KeyCheckIo { path: PathBuf, source: io::Error },
+
+    /// The buffer was encrypted with a different key than the configured one.
+    #[snafu(display(
+        "the configured encryption key does not match the one the buffer was encrypted with"
+    ))]
+    KeyMismatch,
+
+    /// The buffer holds encrypted records, but no key is configured to read them.
+    #[snafu(display(
+        "the buffer holds encrypted records, but no encryption key is configured; configure the \
+         key the buffer was encrypted with, or delete the buffer directory to discard them"
+    ))]
+    MissingKey,
+}
+
+/// Failed to decrypt a record, because it was encrypted with another key or was tampered with.
+#[derive(Debug)]
+pub struct DecryptionError;
+
+impl fmt::Display for DecryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("failed to authenticate encrypted record")
+    }
+}
+
+/// Encrypts and decrypts the payload of records.
+///
+/// Payloads are encrypted with XChaCha20-Poly1305, with a random nonce per record, and the record
+/// ID as associated data so that a record cannot be passed off as another one.  An encrypted
+/// payload is laid out as `nonce || ciphertext || tag`.
+#[derive(Clone)]
+pub struct RecordCipher {
+    cipher: XChaCha20Poly1305,
+}
+
+impl RecordCipher {
+    pub fn new(key: &[u8; KEY_LEN]) -> Self {
+        Self {
+            cipher: XChaCha20Poly1305::new(Key::from_slice(key)),
+        }
+    }
+
+    /// Encrypts `plaintext` as the payload of the record `id`, appending it to `output`.
+    pub fn seal(&self, id: u64, plaintext: &[u8], output: &mut Vec<u8>) {
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        output.extend_from_slice(&nonce);
+        let start = output.len();
+        output.extend_from_slice(plaintext);
+        let tag = self
+            .cipher
+            .encrypt_in_place_detached(&nonce, &id.to_be_bytes(), &mut output[start..])
+            .expect("record payloads are always small enough to be encrypted");
+        output.extend_from_slice(&tag);
+    }
+
+    /// Decrypts the payload of the record `id`.
+    pub fn open(&self, id: u64, payload: &[u8]) -> Result<Vec<u8>, DecryptionError> {
+        if payload.len() < ENCRYPTION_OVERHEAD {
+            return Err(DecryptionError);
+        }
+
+        let (nonce, rest) = payload.split_at(NONCE_LEN);
+        let (ciphertext, tag) = rest.split_at(rest.len() - TAG_LEN);
+        let mut plaintext = ciphertext.to_vec();
+        self.cipher
+            .decrypt_in_place_detached(
+                XNonce::from_slice(nonce),
+                &id.to_be_bytes(),
+                &mut plaintext,
+                Tag::from_slice(tag),
+            )
+            .map_err(|_| DecryptionError)?;
+        Ok(plaintext)
+    }
+}
+
+impl fmt::Debug for RecordCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordCipher").finish_non_exhaustive()
+    }
+}
+
+/// Checks that `cipher` can read the buffer in `data_dir`, before anything is read from it.
+///
+/// The key is never written to disk.  Instead, the first time a buffer is opened with a key, a
+/// known plaintext sealed with it is written next to the data files, and later opening the buffer
+/// with a key that cannot open it fails, rather than the reader failing on every record.
+///
+/// Records are flagged as encrypted individually, so enabling encryption on a buffer that holds
+/// plaintext records is fine: those are still read as they are, while new records are encrypted.
+/// Disabling encryption, or changing the key, is only possible once the encrypted records have
+/// been read, since `is_empty` has to be true for the key check file to be removed or rewritten.
+pub async fn check_key(
+    data_dir: &Path,
+    cipher: Option<&RecordCipher>,
+    is_empty: bool,
+) -> Result<(), EncryptionError> {
+    let path = data_dir.join(KEY_CHECK_FILE_NAME);
+    let existing = match tokio::fs::read(&path).await {
+        Ok(contents) => Some(contents),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+        Err(source) => return Err(EncryptionError::KeyCheckIo { path, source }),
+    };
+
+    match (cipher, existing) {
+        (Some(cipher), Some(existing)) => match cipher.open(0, &existing) {
+            Ok(plaintext) if plaintext == KEY_CHECK_PLAINTEXT => Ok(()),
+            _ if is_empty => {
+                info!("Disk buffer holds no records, switching to the configured encryption key.");
+                write_key_check(&path, cipher).await
+            }
+            _ => Err(EncryptionError::KeyMismatch),
+        },
+        (Some(cipher), None) => write_key_check(&path, cipher).await,
+        (None, Some(_)) if is_empty => tokio::fs::remove_file(&path)
+            .await
+            .context(KeyCheckIoSnafu { path }),
+        (None, Some(_)) => Err(EncryptionError::MissingKey),
+        (None, None) => Ok(()),
+    }
+}
+
+/// Writes the key check file of `cipher` to `path`, replacing any previous one.
+async fn write_key_check(path: &Path, cipher: &RecordCipher) -> Result<(), EncryptionError> {
+    let mut contents = Vec::new();
+    cipher.seal(0, KEY_CHECK_PLAINTEXT, &mut contents);
+    tokio::fs::write(path, contents)
+        .await
+        .context(KeyCheckIoSnafu { path })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_payloads() {
+        let cipher = RecordCipher::new(&[7; KEY_LEN]);
+        let mut payload = Vec::new();
+        cipher.seal(42, b"hello", &mut payload);
+        assert_eq!(payload.len(), b"hello".len() + ENCRYPTION_OVERHEAD);
+        assert_eq!(cipher.open(42, &payload).unwrap(), b"hello");
+
+        // The record ID is authenticated along with the payload.
+        assert!(cipher.open(43, &payload).is_err());
+        assert!(RecordCipher::new(&[8; KEY_LEN]).open(42, &payload).is_err());
+    }
+
+    #[tokio::test]
+    async fn checks_buffer_key() {
+        let data_dir = temp_dir::TempDir::new().unwrap();
+        let cipher = RecordCipher::new(&[7; KEY_LEN]);
+        let other = RecordCipher::new(&[8; KEY_LEN]);
+
+        check_key(data_dir.path(), Some(&cipher), true)
+            .await
+            .unwrap();
+        check_key(data_dir.path(), Some(&cipher), false)
+            .await
+            .unwrap();
+        assert!(matches!(
+            check_key(data_dir.path(), Some(&other), false).await,
+            Err(EncryptionError::KeyMismatch)
+        ));
+        assert!(matches!(
+            check_key(data_dir.path(), None, false).await,
+            Err(EncryptionError::MissingKey)
+        ));
+
+        // Once drained, the buffer can switch to another key, and back to plaintext.
+        check_key(data_dir.path(), Some(&other), true)
+            .await
+            .unwrap();
+        check_key(data_dir.path(), Some(&other), false)
+            .await
+            .unwrap();
+        assert!(matches!(
+            check_key(data_dir.path(), Some(&cipher), false).await,
+            Err(EncryptionError::KeyMismatch)
+        ));
+        check_key(data_dir.path(), None, true).await.unwrap();
+        check_key(data_dir.path(), Some(&cipher), true)
+            .await
+            .unwrap();
+    }
+}
@@ -3,7 +3,7 @@ use std::{
     path::PathBuf,
     sync::atomic::{AtomicBool, AtomicU16, AtomicU64, Ordering},
     sync::Arc,
-    time::Instant,
+    time::{Instant, SystemTime},
 };
 
 use bytecheck::CheckBytes;
@@ -14,7 +14,7 @@ use futures::StreamExt;
 use rkyv::{with::Atomic, Archive, Serialize};
 use snafu::{ResultExt, Snafu};
 use tokio::{fs, io::AsyncWriteExt, sync::Notify};
-use vector_common::finalizer::OrderedFinalizer;
+use vector_common::{finalization::EventStatus, finalizer::OrderedFinalizer};
 
 use super::{
     backed_archive::BackedArchive,
@@ -520,12 +520,28 @@ where
         let initial_buffer_events = self.get_total_records();
         let initial_buffer_size = self.get_total_buffer_size();
         self.usage_handle
-            .increment_received_event_count_and_byte_size(
+            .increment_restored_event_count_and_byte_size(
                 initial_buffer_events,
                 initial_buffer_size,
             );
     }
 
+    /// Tracks the time at which the oldest record left in the buffer when it was opened was
+    /// written, once the reader gets to it.
+    pub fn track_restored_enqueued_at(&self, enqueued_at: SystemTime) {
+        self.usage_handle.set_restored_enqueued_at(enqueued_at);
+    }
+
+    /// Tracks events discarded for being older than the maximum record age.
+    ///
+    /// Expired records are acknowledged like any other record, so their removal from the buffer is
+    /// tracked as they are acknowledged.
+    pub fn track_expired_events(&self, count: u64, byte_size: u64) {
+        let rejected = self.config.expired_status == EventStatus::Rejected;
+        self.usage_handle
+            .increment_expired_event_count_and_byte_size(count, byte_size, rejected);
+    }
+
     pub fn track_dropped_events(&self, count: u64) {
         // We don't know how many bytes are represented by dropped events because we never actually had a chance to read
         // them, so we have to use a byte size of 0 here.
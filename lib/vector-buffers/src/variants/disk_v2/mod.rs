@@ -52,6 +52,21 @@
 //! potentially changed based on the payload that a record contains. The only safe way to access the
 //! records in a disk buffer should be through the reader/writer interface in this module.
 //!
+//! When the buffer has a maximum record age, the payload of each record is prefixed with the time
+//! it was written at, as the number of milliseconds since the Unix epoch in big endian, and the
+//! highest bit of the record metadata is set to flag it. The reader strips the timestamp, and
+//! discards the records that are older than the maximum age. When the buffer is reopened, the age
+//! of the oldest buffered event is seeded from the timestamps of the records left in the buffer,
+//! if they have one. Records are not timestamped otherwise, so that buffers without a maximum
+//! record age can still be read by versions of Vector that predate timestamps.
+//!
+//! When the buffer has an encryption key, the payload of each record, after its timestamp if any,
+//! is encrypted with XChaCha20-Poly1305, and the second highest bit of the record metadata is set to
+//! flag it.  The encrypted payload is made of a random 24-byte nonce, the ciphertext, and a 16-byte
+//! authentication tag, with the record ID as associated data.  The key itself is never written to
+//! disk: a known plaintext encrypted with it is written to `encryption.check` next to the data
+//! files, so that the buffer refuses to open with another key.
+//!
 //! ### Data files
 //!
 //! Data files contain the buffered records and nothing else. Records are written
@@ -175,15 +190,18 @@ use std::{
     marker::PhantomData,
     num::NonZeroU64,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{Arc, Weak},
+    time::Duration,
 };
 
 use async_trait::async_trait;
 use snafu::{ResultExt, Snafu};
-use vector_common::finalization::Finalizable;
+use tokio::{sync::Mutex, time::MissedTickBehavior};
+use vector_common::finalization::{EventStatus, Finalizable};
 
 mod backed_archive;
 mod common;
+mod encryption;
 mod io;
 mod ledger;
 mod reader;
@@ -196,7 +214,8 @@ mod tests;
 
 use self::ledger::Ledger;
 pub use self::{
-    common::{DiskBufferConfig, DiskBufferConfigBuilder},
+    common::{DiskBufferConfig, DiskBufferConfigBuilder, Durability},
+    encryption::{EncryptionError, KEY_LEN as ENCRYPTION_KEY_LEN},
     io::{Filesystem, ProductionFilesystem},
     ledger::LedgerLoadCreateError,
     reader::{BufferReader, ReaderError},
@@ -221,6 +240,10 @@ where
     #[snafu(display("failed to load/create ledger: {}", source))]
     LedgerError { source: LedgerLoadCreateError },
 
+    /// The configured encryption key cannot read the buffer.
+    #[snafu(display("failed to check encryption key: {}", source))]
+    Encryption { source: EncryptionError },
+
     /// Failed to initialize/catch the reader up to where it left off.
     #[snafu(display("failed to seek to position where reader left off: {}", source))]
     ReaderSeekFailed { source: ReaderError<T> },
@@ -253,6 +276,14 @@ where
             .context(LedgerSnafu)?;
         let ledger = Arc::new(ledger);
 
+        encryption::check_key(
+            &ledger.config().data_dir,
+            ledger.config().cipher.as_ref(),
+            ledger.get_total_records() == 0,
+        )
+        .await
+        .context(EncryptionSnafu)?;
+
         let mut writer = BufferWriter::new(Arc::clone(&ledger));
         writer
             .validate_last_write()
@@ -301,6 +332,10 @@ pub struct DiskV2Buffer {
     id: String,
     data_dir: PathBuf,
     max_size: NonZeroU64,
+    max_age: Option<Duration>,
+    expired_status: EventStatus,
+    durability: Durability,
+    encryption_key: Option<[u8; ENCRYPTION_KEY_LEN]>,
 }
 
 impl DiskV2Buffer {
@@ -309,8 +344,40 @@ impl DiskV2Buffer {
             id,
             data_dir,
             max_size,
+            max_age: None,
+            expired_status: EventStatus::Delivered,
+            durability: Durability::None,
+            encryption_key: None,
         }
     }
+
+    /// Sets the maximum age of the records in the buffer, past which they are discarded.
+    #[must_use]
+    pub fn with_max_age(mut self, max_age: Option<Duration>) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Sets the status the events of expired records are finalized with.
+    #[must_use]
+    pub fn with_expired_status(mut self, status: EventStatus) -> Self {
+        self.expired_status = status;
+        self
+    }
+
+    /// Sets when the buffer is synchronized to disk, and the records written to it acknowledged.
+    #[must_use]
+    pub fn with_durability(mut self, durability: Durability) -> Self {
+        self.durability = durability;
+        self
+    }
+
+    /// Sets the key encrypting the records in the buffer.
+    #[must_use]
+    pub fn with_encryption_key(mut self, key: Option<[u8; ENCRYPTION_KEY_LEN]>) -> Self {
+        self.encryption_key = key;
+        self
+    }
 }
 
 #[async_trait]
@@ -326,23 +393,53 @@ where
         self: Box<Self>,
         usage_handle: BufferUsageHandle,
     ) -> Result<(SenderAdapter<T>, ReceiverAdapter<T>), Box<dyn Error + Send + Sync>> {
-        let (writer, reader) = build_disk_v2_buffer(
-            usage_handle,
-            &self.data_dir,
-            self.id.as_str(),
-            self.max_size,
-        )
-        .await?;
+        let (mut writer, reader) = build_disk_v2_buffer(usage_handle, &self).await?;
+        writer.hold_acknowledgements(T::take_finalizers);
+
+        let writer = Arc::new(Mutex::new(writer));
+        if let Durability::Interval { interval, .. } = self.durability {
+            tokio::spawn(sync_periodically(Arc::downgrade(&writer), interval));
+        }
+
+        Ok((SenderAdapter::DiskV2(writer), reader.into()))
+    }
+}
 
-        Ok((writer.into(), reader.into()))
+/// Flushes the writer periodically, for as long as it is in use.
+///
+/// Records whose acknowledgements are held back are otherwise only synchronized as later records
+/// are flushed, so this ensures they are acknowledged within about the interval even once no more
+/// records are written.
+async fn sync_periodically<T>(
+    writer: Weak<Mutex<BufferWriter<T, ProductionFilesystem>>>,
+    interval: Duration,
+) where
+    T: Bufferable,
+{
+    // Ticking at half the interval ensures the interval has elapsed since the last
+    // synchronization by the time of the second tick after it.
+    let mut ticker = tokio::time::interval(interval / 2);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+        let Some(writer) = writer.upgrade() else {
+            break;
+        };
+
+        let mut writer = writer.lock().await;
+        if writer.has_unsynced_writes() {
+            if let Err(error) = writer.flush().await {
+                error!(message = "Failed to synchronize disk buffer.", %error);
+                break;
+            }
+        }
     }
 }
 
 async fn build_disk_v2_buffer<T>(
     usage_handle: BufferUsageHandle,
-    data_dir: &Path,
-    id: &str,
-    max_size: NonZeroU64,
+    buffer: &DiskV2Buffer,
 ) -> Result<
     (
         BufferWriter<T, ProductionFilesystem>,
@@ -353,11 +450,15 @@ async fn build_disk_v2_buffer<T>(
 where
     T: Bufferable + Clone,
 {
-    usage_handle.set_buffer_limits(Some(max_size.get()), None);
+    usage_handle.set_buffer_limits(Some(buffer.max_size.get()), None);
 
-    let buffer_path = get_disk_v2_data_dir_path(data_dir, id);
+    let buffer_path = get_disk_v2_data_dir_path(&buffer.data_dir, buffer.id.as_str());
     let config = DiskBufferConfigBuilder::from_path(buffer_path)
-        .max_buffer_size(max_size.get())
+        .max_buffer_size(buffer.max_size.get())
+        .max_record_age(buffer.max_age)
+        .expired_status(buffer.expired_status)
+        .durability(buffer.durability)
+        .encryption_key(buffer.encryption_key)
         .build()?;
     Buffer::from_config(config, usage_handle)
         .await
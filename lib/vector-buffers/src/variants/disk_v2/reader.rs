@@ -5,16 +5,21 @@ use std::{
     num::NonZeroU64,
     path::PathBuf,
     sync::Arc,
+    time::SystemTime,
 };
 
 use crc32fast::Hasher;
 use rkyv::{archived_root, AlignedVec};
 use snafu::{ResultExt, Snafu};
 use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
-use vector_common::{finalization::BatchNotifier, finalizer::OrderedFinalizer};
+use vector_common::{
+    finalization::{BatchNotifier, EventFinalizer},
+    finalizer::OrderedFinalizer,
+};
 
 use super::{
     common::create_crc32c_hasher,
+    encryption::RecordCipher,
     ledger::Ledger,
     record::{validate_record_archive, ArchivedRecord, Record, RecordStatus},
     Filesystem,
@@ -108,6 +113,14 @@ where
     #[snafu(display("record version not compatible: {}", reason))]
     Incompatible { reason: String },
 
+    /// The reader failed to decrypt an encrypted record.
+    ///
+    /// This happens when the key the record was encrypted with is not configured anymore.  The
+    /// buffer refuses to open with a key different from the one it was encrypted with, so this
+    /// otherwise indicates that the record was tampered with.
+    #[snafu(display("failed to decrypt record: {}", reason))]
+    Decryption { reason: String },
+
     /// The reader detected that a data file contains a partially-written record.
     ///
     /// Records should never be partially written to a data file (we don't split records across data
@@ -200,6 +213,7 @@ pub(super) struct RecordReader<R, T> {
     aligned_buf: AlignedVec,
     checksummer: Hasher,
     current_record_id: u64,
+    cipher: Option<RecordCipher>,
     _t: PhantomData<T>,
 }
 
@@ -218,10 +232,18 @@ where
             aligned_buf: AlignedVec::new(),
             checksummer: create_crc32c_hasher(),
             current_record_id: 0,
+            cipher: None,
             _t: PhantomData,
         }
     }
 
+    /// Sets the cipher decrypting encrypted records.
+    #[must_use]
+    pub fn decrypt_with(mut self, cipher: Option<RecordCipher>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
     #[cfg_attr(test, instrument(skip(self), level = "trace"))]
     async fn read_length_delimiter(
         &mut self,
@@ -374,7 +396,25 @@ where
         // - `try_next_record` does all the archive checks, checksum validation, etc
         let record = unsafe { archived_root::<Record<'_>>(&self.aligned_buf) };
 
-        decode_record_payload(record)
+        decode_record_payload(record, self.cipher.as_ref())
+    }
+
+    /// Gets the time at which the record for the given token was written, if it was timestamped.
+    ///
+    /// # Panics
+    ///
+    /// If the `ReadToken` is not the one handed back by the latest call to `try_next_record`, this
+    /// method will panic due to an out-of-order read.
+    pub fn enqueued_at(&self, token: &ReadToken) -> Option<SystemTime> {
+        assert_eq!(
+            self.current_record_id,
+            token.record_id(),
+            "using expired read token; this is a serious bug"
+        );
+
+        // SAFETY: See `read_record`.
+        let record = unsafe { archived_root::<Record<'_>>(&self.aligned_buf) };
+        record.enqueued_at()
     }
 }
 
@@ -785,7 +825,9 @@ where
                 "Opened data file for reading."
             );
 
-            self.reader = Some(RecordReader::new(data_file));
+            self.reader = Some(
+                RecordReader::new(data_file).decrypt_with(self.ledger.config().cipher.clone()),
+            );
             return Ok(());
         }
     }
@@ -851,7 +893,8 @@ where
                     let record = try_as_record_archive(data_file_mmap.as_ref())
                         .expect("record was already validated");
 
-                    let Ok(item) = decode_record_payload::<T>(record) else {
+                    let cipher = self.ledger.config().cipher.as_ref();
+                    let Ok(item) = decode_record_payload::<T>(record, cipher) else {
                         // If there's an error decoding the item, just fall back to the slow path,
                         // because this file might actually be where we left off, so we don't want
                         // to incorrectly skip ahead or anything.
@@ -955,6 +998,35 @@ where
     /// the error.
     #[cfg_attr(test, instrument(skip(self), level = "trace"))]
     pub async fn next(&mut self) -> Result<Option<T>, ReaderError<T>> {
+        loop {
+            match self.next_record().await? {
+                Some(ReadRecord::Valid(record)) => return Ok(Some(record)),
+                Some(ReadRecord::Expired { events, bytes }) => {
+                    self.ledger.track_expired_events(events, bytes);
+                }
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// Whether a record written at `enqueued_at` is older than the maximum record age.
+    ///
+    /// Records are only expired once the reader is caught up, as the records read while seeking
+    /// were already acknowledged.
+    fn is_expired(&self, enqueued_at: Option<SystemTime>) -> bool {
+        let (Some(max_age), Some(enqueued_at)) = (self.ledger.config().max_record_age, enqueued_at)
+        else {
+            return false;
+        };
+
+        self.ready_to_read
+            && SystemTime::now()
+                .duration_since(enqueued_at)
+                .is_ok_and(|age| age > max_age)
+    }
+
+    /// Reads the next record, which may have expired.
+    async fn next_record(&mut self) -> Result<Option<ReadRecord<T>>, ReaderError<T>> {
         let mut force_check_pending_data_files = false;
 
         let token = loop {
@@ -1096,6 +1168,13 @@ where
         let record_id = token.record_id();
         let record_bytes = token.record_bytes() as u64;
 
+        let reader = self
+            .reader
+            .as_ref()
+            .expect("reader should exist after `ensure_ready_for_read`");
+        let enqueued_at = reader.enqueued_at(&token);
+        let expired = self.is_expired(enqueued_at);
+
         let reader = self
             .reader
             .as_mut()
@@ -1109,12 +1188,39 @@ where
         let record_events = record_events
             .try_into()
             .map_err(|_| ReaderError::EmptyRecord)?;
+
+        // When every record read so far has been acknowledged, this record is the oldest one in
+        // the buffer, which gives the age of the records left in the buffer when it was opened.
+        if self.ready_to_read
+            && self.last_reader_record_id == self.ledger.state().get_last_reader_record_id()
+        {
+            if let Some(enqueued_at) = enqueued_at {
+                self.ledger.track_restored_enqueued_at(enqueued_at);
+            }
+        }
         self.track_read(record_id, record_bytes, record_events);
 
         let (batch, receiver) = BatchNotifier::new_with_receiver();
-        record.add_batch_notifier(batch);
         self.finalizer.add(record_events.get(), receiver);
 
+        // Expired records are finalized with the configured status as soon as they are read,
+        // which removes them from the buffer like any other acknowledged record.
+        if expired {
+            trace!(
+                record_id,
+                record_events,
+                record_bytes,
+                "Discarding expired record."
+            );
+            EventFinalizer::new(batch).update_status(self.ledger.config().expired_status);
+            drop(record);
+            return Ok(Some(ReadRecord::Expired {
+                events: record_events.get(),
+                bytes: record_bytes,
+            }));
+        }
+        record.add_batch_notifier(batch);
+
         if self.ready_to_read {
             trace!(
                 record_id,
@@ -1125,12 +1231,23 @@ where
             );
         }
 
-        Ok(Some(record))
+        Ok(Some(ReadRecord::Valid(record)))
     }
 }
 
+/// A record read from the buffer.
+enum ReadRecord<T> {
+    Valid(T),
+    /// The record was older than the maximum record age, and was discarded.
+    Expired {
+        events: u64,
+        bytes: u64,
+    },
+}
+
 pub(crate) fn decode_record_payload<T: Bufferable>(
     record: &ArchivedRecord<'_>,
+    cipher: Option<&RecordCipher>,
 ) -> Result<T, ReaderError<T>> {
     // Try and convert the raw record metadata into the true metadata type used by `T`, and then
     // also verify that `T` is able to decode records with the metadata used for this record in particular.
@@ -1147,6 +1264,20 @@ pub(crate) fn decode_record_payload<T: Bufferable>(
         });
     }
 
+    // Encrypted records are only decrypted once they have been otherwise validated.
+    if record.is_encrypted() {
+        let cipher = cipher.ok_or(ReaderError::Decryption {
+            reason: "no encryption key is configured".to_string(),
+        })?;
+        let payload =
+            cipher
+                .open(record.id(), record.payload())
+                .map_err(|e| ReaderError::Decryption {
+                    reason: e.to_string(),
+                })?;
+        return T::decode(metadata, &payload[..]).context(DecodeSnafu);
+    }
+
     // Now we can finally try decoding.
     T::decode(metadata, record.payload()).context(DecodeSnafu)
 }
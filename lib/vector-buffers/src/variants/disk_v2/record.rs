@@ -1,4 +1,8 @@
-use std::{mem, ptr::addr_of};
+use std::{
+    mem,
+    ptr::addr_of,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use bytecheck::{CheckBytes, ErrorBox, StructCheckError};
 use crc32fast::Hasher;
@@ -15,6 +19,32 @@ use super::{
 
 pub const RECORD_HEADER_LEN: usize = align16(mem::size_of::<ArchivedRecord<'_>>() + 8);
 
+/// Set in the metadata of records whose payload is prefixed with the time they were written at.
+///
+/// Records are only timestamped when the buffer has a maximum record age. The flag is never part
+/// of the metadata of `T` itself, and is cleared before the metadata is handed back to `T`.
+pub const ENQUEUED_AT_FLAG: u32 = 1 << 31;
+
+/// Set in the metadata of records whose payload is encrypted.
+///
+/// Records are only encrypted when the buffer has an encryption key. Like `ENQUEUED_AT_FLAG`, the
+/// flag is cleared before the metadata is handed back to `T`.
+pub const ENCRYPTED_FLAG: u32 = 1 << 30;
+
+/// Length of the timestamp prefixed to the payload of timestamped records: the number of
+/// milliseconds since the Unix epoch, as a big endian integer.
+pub const ENQUEUED_AT_LEN: usize = mem::size_of::<u64>();
+
+/// Encodes the current time as the prefix of the payload of a timestamped record.
+pub fn enqueued_at_now() -> [u8; ENQUEUED_AT_LEN] {
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    u64::try_from(since_epoch.as_millis())
+        .unwrap_or(u64::MAX)
+        .to_be_bytes()
+}
+
 /// Result of checking if a buffer contained a valid record.
 pub enum RecordStatus {
     /// The record was able to be read from the buffer, and the checksum is valid.
@@ -130,12 +160,40 @@ impl<'a> Record<'a> {
 impl<'a> ArchivedRecord<'a> {
     /// Gets the metadata of this record.
     pub fn metadata(&self) -> u32 {
-        self.metadata
+        self.metadata & !(ENQUEUED_AT_FLAG | ENCRYPTED_FLAG)
+    }
+
+    /// Gets the ID of this record.
+    pub fn id(&self) -> u64 {
+        self.id
     }
 
-    /// Gets the payload of this record.
+    /// Gets the payload of this record, which is still encrypted if the record is.
     pub fn payload(&self) -> &[u8] {
-        &self.payload
+        if self.is_timestamped() {
+            self.payload.get(ENQUEUED_AT_LEN..).unwrap_or_default()
+        } else {
+            &self.payload
+        }
+    }
+
+    /// Gets the time at which this record was written, if it was timestamped.
+    pub fn enqueued_at(&self) -> Option<SystemTime> {
+        if !self.is_timestamped() {
+            return None;
+        }
+
+        let millis = self.payload.get(..ENQUEUED_AT_LEN)?.try_into().ok()?;
+        Some(UNIX_EPOCH + Duration::from_millis(u64::from_be_bytes(millis)))
+    }
+
+    fn is_timestamped(&self) -> bool {
+        self.metadata & ENQUEUED_AT_FLAG != 0
+    }
+
+    /// Whether the payload of this record is encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.metadata & ENCRYPTED_FLAG != 0
     }
 
     /// Verifies if the stored checksum of this record matches the record itself.
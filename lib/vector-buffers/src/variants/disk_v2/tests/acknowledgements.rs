@@ -1,12 +1,19 @@
-use std::sync::Arc;
+use std::{fs, mem, path::Path, sync::Arc, time::Duration};
 
 use tokio_test::{assert_pending, assert_ready, task::spawn};
-use vector_common::finalization::{BatchNotifier, EventFinalizer, EventStatus};
+use vector_common::finalization::{
+    AddBatchNotifier, BatchNotifier, BatchStatus, BatchStatusReceiver, EventFinalizer, EventStatus,
+    Finalizable,
+};
 
+use super::read_next;
 use crate::{
     buffer_usage_data::BufferUsageHandle,
-    test::with_temp_dir,
-    variants::disk_v2::{ledger::Ledger, DiskBufferConfigBuilder},
+    test::{with_temp_dir, SizedRecord},
+    variants::disk_v2::{
+        ledger::Ledger, Buffer, BufferReader, BufferWriter, DiskBufferConfigBuilder, Durability,
+        ProductionFilesystem,
+    },
 };
 
 pub(crate) async fn acknowledge(batch: BatchNotifier) {
@@ -83,3 +90,276 @@ async fn ack_wakes_reader() {
     })
     .await;
 }
+
+async fn create_durable_buffer(
+    data_dir: &Path,
+    durability: Durability,
+) -> (
+    BufferWriter<SizedRecord, ProductionFilesystem>,
+    BufferReader<SizedRecord, ProductionFilesystem>,
+    Arc<Ledger<ProductionFilesystem>>,
+) {
+    let config = DiskBufferConfigBuilder::from_path(data_dir)
+        .durability(durability)
+        .build()
+        .expect("creating buffer should not fail");
+    let (mut writer, reader, ledger) = Buffer::from_config_inner(config, BufferUsageHandle::noop())
+        .await
+        .expect("should not fail to create buffer");
+    writer.hold_acknowledgements(SizedRecord::take_finalizers);
+    (writer, reader, ledger)
+}
+
+fn record_with_receiver(size: u32) -> (SizedRecord, BatchStatusReceiver) {
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let mut record = SizedRecord::new(size);
+    record.add_batch_notifier(batch);
+    (record, receiver)
+}
+
+#[tokio::test]
+async fn always_acknowledges_writes_once_synced() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let (mut writer, _reader, _ledger) =
+                create_durable_buffer(&data_dir, Durability::Always).await;
+
+            // The record is only acknowledged once the flush synchronizes it to disk.
+            let (record, receiver) = record_with_receiver(32);
+            let mut receiver = spawn(receiver);
+            writer
+                .write_record(record)
+                .await
+                .expect("write should not fail");
+            assert_pending!(receiver.poll());
+
+            writer.flush().await.expect("writer flush should not fail");
+            assert_eq!(assert_ready!(receiver.poll()), BatchStatus::Delivered);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn interval_acknowledges_writes_once_synced() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let durability = Durability::Interval {
+                interval: Duration::from_secs(3600),
+                max_bytes: Some(4096),
+            };
+            let (mut writer, _reader, _ledger) = create_durable_buffer(&data_dir, durability).await;
+
+            // Flushing does not synchronize the buffer until the interval elapses, or enough data
+            // was written, and records are held back until then.
+            let (first, first_receiver) = record_with_receiver(32);
+            let mut first_receiver = spawn(first_receiver);
+            writer
+                .write_record(first)
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("writer flush should not fail");
+            assert_pending!(first_receiver.poll());
+
+            let (second, second_receiver) = record_with_receiver(8192);
+            let mut second_receiver = spawn(second_receiver);
+            writer
+                .write_record(second)
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("writer flush should not fail");
+            assert_eq!(assert_ready!(first_receiver.poll()), BatchStatus::Delivered);
+            assert_eq!(
+                assert_ready!(second_receiver.poll()),
+                BatchStatus::Delivered
+            );
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn crash_before_sync_does_not_acknowledge_writes() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let (mut writer, reader, ledger) =
+                create_durable_buffer(&data_dir, Durability::Always).await;
+
+            let (synced, synced_receiver) = record_with_receiver(32);
+            let mut synced_receiver = spawn(synced_receiver);
+            writer
+                .write_record(synced)
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("writer flush should not fail");
+            assert_eq!(
+                assert_ready!(synced_receiver.poll()),
+                BatchStatus::Delivered
+            );
+
+            // Simulate a crash between writing a record and synchronizing it, by dropping the
+            // buffer before the record ever leaves the write buffer.
+            let (unsynced, unsynced_receiver) = record_with_receiver(33);
+            let mut unsynced_receiver = spawn(unsynced_receiver);
+            writer
+                .write_record(unsynced)
+                .await
+                .expect("write should not fail");
+            assert_pending!(unsynced_receiver.poll());
+            drop(writer);
+            drop(reader);
+            drop(ledger);
+            assert_eq!(
+                assert_ready!(unsynced_receiver.poll()),
+                BatchStatus::Errored
+            );
+
+            // Only the acknowledged record survives.
+            let (mut writer, mut reader, _ledger) =
+                create_durable_buffer(&data_dir, Durability::Always).await;
+            writer.close();
+            let mut items = Vec::new();
+            while let Some(record) = read_next(&mut reader).await {
+                items.push(record);
+            }
+            assert_eq!(items, [SizedRecord::new(32)]);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn killed_writer_does_not_acknowledge_unsynced_writes() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.join("original");
+        let crashed_dir = dir.join("crashed");
+
+        async move {
+            let (mut writer, reader, ledger) =
+                create_durable_buffer(&data_dir, Durability::Always).await;
+
+            let (synced, synced_receiver) = record_with_receiver(32);
+            let mut synced_receiver = spawn(synced_receiver);
+            writer
+                .write_record(synced)
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("writer flush should not fail");
+            assert_eq!(
+                assert_ready!(synced_receiver.poll()),
+                BatchStatus::Delivered
+            );
+
+            // Simulate the process being killed between writing a record and synchronizing it:
+            // the writer is abandoned without running any of its drop logic, and only the files
+            // as they are on disk at that point are left behind.
+            let (unsynced, unsynced_receiver) = record_with_receiver(33);
+            let mut unsynced_receiver = spawn(unsynced_receiver);
+            writer
+                .write_record(unsynced)
+                .await
+                .expect("write should not fail");
+            mem::forget(writer);
+            drop(reader);
+            drop(ledger);
+            copy_buffer_files(&data_dir, &crashed_dir);
+            assert_pending!(unsynced_receiver.poll());
+
+            // Only the acknowledged record survives.
+            let (mut writer, mut reader, _ledger) =
+                create_durable_buffer(&crashed_dir, Durability::Always).await;
+            writer.close();
+            let mut items = Vec::new();
+            while let Some(record) = read_next(&mut reader).await {
+                items.push(record);
+            }
+            assert_eq!(items, [SizedRecord::new(32)]);
+        }
+    })
+    .await;
+}
+
+/// Writes records with the given durability until they are acknowledged, and checks that they are
+/// all read back once the writer is killed.
+async fn acknowledged_writes_survive_killed_writer(durability: Durability) {
+    with_temp_dir(|dir| {
+        let data_dir = dir.join("original");
+        let crashed_dir = dir.join("crashed");
+
+        async move {
+            let (mut writer, reader, ledger) = create_durable_buffer(&data_dir, durability).await;
+
+            let mut receivers = Vec::new();
+            for size in [32, 33, 34] {
+                let (record, receiver) = record_with_receiver(size);
+                writer
+                    .write_record(record)
+                    .await
+                    .expect("write should not fail");
+                writer.flush().await.expect("writer flush should not fail");
+                receivers.push(spawn(receiver));
+            }
+            for receiver in &mut receivers {
+                assert_eq!(assert_ready!(receiver.poll()), BatchStatus::Delivered);
+            }
+
+            // Kill the writer right after the records were acknowledged, without running any of
+            // its drop logic.
+            mem::forget(writer);
+            drop(reader);
+            drop(ledger);
+            copy_buffer_files(&data_dir, &crashed_dir);
+
+            let (mut writer, mut reader, _ledger) =
+                create_durable_buffer(&crashed_dir, durability).await;
+            writer.close();
+            let mut items = Vec::new();
+            while let Some(record) = read_next(&mut reader).await {
+                items.push(record);
+            }
+            assert_eq!(
+                items,
+                [
+                    SizedRecord::new(32),
+                    SizedRecord::new(33),
+                    SizedRecord::new(34)
+                ]
+            );
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn always_acknowledged_writes_survive_killed_writer() {
+    acknowledged_writes_survive_killed_writer(Durability::Always).await;
+}
+
+#[tokio::test]
+async fn interval_acknowledged_writes_survive_killed_writer() {
+    // Every flush synchronizes the buffer, as more than `max_bytes` are written between them.
+    acknowledged_writes_survive_killed_writer(Durability::Interval {
+        interval: Duration::from_secs(3600),
+        max_bytes: Some(1),
+    })
+    .await;
+}
+
+/// Copies the files of a buffer, leaving out its lock file, which is still held by the abandoned
+/// buffer.
+fn copy_buffer_files(from: &Path, to: &Path) {
+    fs::create_dir_all(to).expect("creating directory should not fail");
+    for entry in fs::read_dir(from).expect("reading directory should not fail") {
+        let path = entry.expect("reading directory should not fail").path();
+        if path.file_name().is_some_and(|name| name != "buffer.lock") {
+            fs::copy(&path, to.join(path.file_name().unwrap()))
+                .expect("copying file should not fail");
+        }
+    }
+}
@@ -1,15 +1,19 @@
-use std::io::Cursor;
+use std::{io::Cursor, time::Duration};
 
 use futures::{stream, StreamExt};
 use tokio_test::{assert_pending, assert_ready, task::spawn};
 use tracing::Instrument;
-use vector_common::finalization::Finalizable;
+use vector_common::finalization::{EventStatus, Finalizable};
 
 use super::{create_default_buffer_v2, read_next, read_next_some};
 use crate::{
     assert_buffer_is_empty, assert_buffer_records,
+    buffer_usage_data::BufferUsageHandle,
     test::{acknowledge, install_tracing_helpers, with_temp_dir, MultiEventRecord, SizedRecord},
-    variants::disk_v2::{tests::create_default_buffer_v2_with_usage, writer::RecordWriter},
+    variants::disk_v2::{
+        tests::create_default_buffer_v2_with_usage, writer::RecordWriter, Buffer, BufferError,
+        DiskBufferConfigBuilder, EncryptionError,
+    },
     EventCount,
 };
 
@@ -66,6 +70,215 @@ async fn basic_read_write_loop() {
     .await;
 }
 
+/// Checks that expired records are discarded, and counted according to `expired_status`.
+async fn reader_discards_expired_records(expired_status: EventStatus) {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Create a buffer whose records expire quickly.
+            let config = DiskBufferConfigBuilder::from_path(data_dir)
+                .max_record_age(Some(Duration::from_millis(500)))
+                .expired_status(expired_status)
+                .build()
+                .expect("creating buffer should not fail");
+            let usage_handle = BufferUsageHandle::noop();
+            let (mut writer, mut reader, ledger) =
+                Buffer::<SizedRecord>::from_config_inner(config, usage_handle.clone())
+                    .await
+                    .expect("should not fail to create buffer");
+
+            // Write a first batch of records, and let them age past the maximum age before
+            // writing a second batch.
+            for size in [32, 33] {
+                writer
+                    .write_record(SizedRecord::new(size))
+                    .await
+                    .expect("write should not fail");
+            }
+            writer.flush().await.expect("writer flush should not fail");
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            for size in [34, 35] {
+                writer
+                    .write_record(SizedRecord::new(size))
+                    .await
+                    .expect("write should not fail");
+            }
+            writer.flush().await.expect("writer flush should not fail");
+            writer.close();
+            assert_buffer_records!(ledger, 4);
+
+            // Only the second batch should be read, and the expired records should still be
+            // removed from the buffer.
+            let mut items = Vec::new();
+            while let Some(mut record) = read_next(&mut reader).await {
+                acknowledge(record.take_finalizers()).await;
+                items.push(record);
+            }
+            assert_eq!(items, [SizedRecord::new(34), SizedRecord::new(35)]);
+            assert_buffer_is_empty!(ledger);
+
+            let snapshot = usage_handle.snapshot();
+            let (delivered, rejected) = if expired_status == EventStatus::Rejected {
+                (0, 2)
+            } else {
+                (2, 0)
+            };
+            assert_eq!(snapshot.expired_event_count, delivered);
+            assert_eq!(snapshot.expired_event_count_rejected, rejected);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn reader_discards_expired_records_as_delivered() {
+    reader_discards_expired_records(EventStatus::Delivered).await;
+}
+
+#[tokio::test]
+async fn reader_discards_expired_records_as_rejected() {
+    reader_discards_expired_records(EventStatus::Rejected).await;
+}
+
+#[tokio::test]
+async fn reopened_buffer_reports_age_of_oldest_record() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            // Records are only timestamped when the buffer has a maximum record age.
+            let open = |data_dir| async move {
+                let config = DiskBufferConfigBuilder::from_path(data_dir)
+                    .max_record_age(Some(Duration::from_secs(3600)))
+                    .build()
+                    .expect("creating buffer should not fail");
+                let usage_handle = BufferUsageHandle::noop();
+                let (writer, reader, ledger) =
+                    Buffer::<SizedRecord>::from_config_inner(config, usage_handle.clone())
+                        .await
+                        .expect("should not fail to create buffer");
+                (writer, reader, ledger, usage_handle)
+            };
+
+            // Write a first record, and let it age before writing a second one and closing the
+            // buffer with both records left in it.
+            let (mut writer, reader, ledger, _) = open(data_dir.clone()).await;
+            writer
+                .write_record(SizedRecord::new(32))
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("writer flush should not fail");
+            tokio::time::sleep(Duration::from_secs(2)).await;
+            writer
+                .write_record(SizedRecord::new(33))
+                .await
+                .expect("write should not fail");
+            writer.flush().await.expect("writer flush should not fail");
+            writer.close();
+            drop(reader);
+            drop(writer);
+            drop(ledger);
+            tokio::time::sleep(Duration::from_secs(1)).await;
+
+            // Once reopened, the age of the records left in the buffer comes from the time they
+            // were written at, rather than from the time the buffer was opened.
+            let (writer, mut reader, ledger, usage_handle) = open(data_dir).await;
+            drop(writer);
+            assert_buffer_records!(ledger, 2);
+            let oldest_event_age = || usage_handle.snapshot().oldest_event_age;
+
+            let mut first = read_next_some(&mut reader).await;
+            assert_eq!(first, SizedRecord::new(32));
+            assert!(oldest_event_age() >= Duration::from_secs(3));
+
+            // The age of the remaining records is still the age of the oldest one, until they are
+            // read, and it goes back to zero once the buffer is empty.
+            acknowledge(first.take_finalizers()).await;
+            let mut second = read_next_some(&mut reader).await;
+            assert_eq!(second, SizedRecord::new(33));
+            let age = oldest_event_age();
+            assert!(age >= Duration::from_secs(1) && age < Duration::from_secs(3));
+
+            acknowledge(second.take_finalizers()).await;
+            assert_eq!(read_next(&mut reader).await, None);
+            assert_buffer_is_empty!(ledger);
+            assert_eq!(oldest_event_age(), Duration::ZERO);
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn encrypted_buffer_requires_its_key() {
+    with_temp_dir(|dir| {
+        let data_dir = dir.to_path_buf();
+
+        async move {
+            let open = |key: Option<[u8; 32]>| {
+                let config = DiskBufferConfigBuilder::from_path(data_dir.clone())
+                    .encryption_key(key)
+                    .build()
+                    .expect("creating buffer should not fail");
+                Buffer::<SizedRecord>::from_config_inner(config, BufferUsageHandle::noop())
+            };
+
+            // Write a few records with a key, and close the buffer before reading them.
+            let (mut writer, reader, ledger) = open(Some([7; 32]))
+                .await
+                .expect("should not fail to create buffer");
+            for size in [32, 33] {
+                writer
+                    .write_record(SizedRecord::new(size))
+                    .await
+                    .expect("write should not fail");
+            }
+            writer.flush().await.expect("writer flush should not fail");
+            writer.close();
+            drop(reader);
+            drop(writer);
+            drop(ledger);
+
+            // The buffer cannot be opened with another key, or without one, while it holds
+            // encrypted records.
+            assert!(matches!(
+                open(Some([8; 32])).await,
+                Err(BufferError::Encryption {
+                    source: EncryptionError::KeyMismatch
+                })
+            ));
+            assert!(matches!(
+                open(None).await,
+                Err(BufferError::Encryption {
+                    source: EncryptionError::MissingKey
+                })
+            ));
+
+            // With the right key, the records are read back as they were written.
+            let (mut writer, mut reader, ledger) = open(Some([7; 32]))
+                .await
+                .expect("should not fail to open buffer");
+            writer.close();
+            let mut items = Vec::new();
+            while let Some(mut record) = read_next(&mut reader).await {
+                acknowledge(record.take_finalizers()).await;
+                items.push(record);
+            }
+            assert_eq!(items, [SizedRecord::new(32), SizedRecord::new(33)]);
+            assert_buffer_is_empty!(ledger);
+            drop(reader);
+            drop(writer);
+            drop(ledger);
+
+            // Once empty, the buffer can be opened with a new key.
+            open(Some([8; 32]))
+                .await
+                .expect("should not fail to open empty buffer with a new key");
+        }
+    })
+    .await;
+}
+
 #[tokio::test]
 async fn reader_exits_cleanly_when_writer_done_and_in_flight_acks() {
     let assertion_registry = install_tracing_helpers();
@@ -169,8 +382,14 @@ async fn initial_size_correct_with_multievents() {
             // are identical:
             let expected_bytes = stream::iter(input_items.iter().cloned())
                 .filter_map(|record| async move {
-                    let mut record_writer =
-                        RecordWriter::new(Cursor::new(Vec::new()), 0, 16_384, u64::MAX, usize::MAX);
+                    let mut record_writer = RecordWriter::new(
+                        Cursor::new(Vec::new()),
+                        0,
+                        16_384,
+                        u64::MAX,
+                        usize::MAX,
+                        false,
+                    );
                     let (bytes_written, flush_result) = record_writer
                         .write_record(0, record)
                         .await
@@ -4,6 +4,7 @@ use std::{
     fmt,
     io::{self, ErrorKind},
     marker::PhantomData,
+    mem,
     num::NonZeroUsize,
     sync::Arc,
 };
@@ -22,9 +23,11 @@ use rkyv::{
 };
 use snafu::{ResultExt, Snafu};
 use tokio::io::{AsyncWrite, AsyncWriteExt};
+use vector_common::finalization::{EventFinalizers, EventStatus};
 
 use super::{
-    common::{create_crc32c_hasher, DiskBufferConfig},
+    common::{create_crc32c_hasher, DiskBufferConfig, Durability},
+    encryption::{RecordCipher, ENCRYPTION_OVERHEAD},
     io::Filesystem,
     ledger::Ledger,
     record::{validate_record_archive, Record, RecordStatus},
@@ -34,7 +37,10 @@ use crate::{
     variants::disk_v2::{
         io::AsyncFile,
         reader::decode_record_payload,
-        record::{try_as_record_archive, RECORD_HEADER_LEN},
+        record::{
+            enqueued_at_now, try_as_record_archive, ENCRYPTED_FLAG, ENQUEUED_AT_FLAG,
+            ENQUEUED_AT_LEN, RECORD_HEADER_LEN,
+        },
     },
     Bufferable,
 };
@@ -360,12 +366,15 @@ impl<W: fmt::Debug> fmt::Debug for TrackingBufWriter<W> {
 pub(super) struct RecordWriter<W, T> {
     writer: TrackingBufWriter<W>,
     encode_buf: Vec<u8>,
+    encrypt_buf: Vec<u8>,
     ser_buf: AlignedVec,
     ser_scratch: AlignedVec,
     checksummer: Hasher,
     max_record_size: usize,
     current_data_file_size: u64,
     max_data_file_size: u64,
+    timestamp_records: bool,
+    cipher: Option<RecordCipher>,
     _t: PhantomData<T>,
 }
 
@@ -378,12 +387,16 @@ where
     ///
     /// Internally, the writer is wrapped in a [`BufWriter`], so callers should not pass in an
     /// already buffered writer.
+    ///
+    /// When `timestamp_records` is true, each record carries the time it was written at, so that
+    /// the reader can discard records that are too old.
     pub fn new(
         writer: W,
         current_data_file_size: u64,
         write_buffer_size: usize,
         max_data_file_size: u64,
         max_record_size: usize,
+        timestamp_records: bool,
     ) -> Self {
         // These should also be getting checked at a higher level, but we're double-checking them here to be absolutely sure.
         let max_record_size_converted = u64::try_from(max_record_size)
@@ -410,16 +423,26 @@ where
         Self {
             writer: TrackingBufWriter::with_capacity(write_buffer_size, writer),
             encode_buf: Vec::with_capacity(16_384),
+            encrypt_buf: Vec::new(),
             ser_buf: AlignedVec::with_capacity(16_384),
             ser_scratch: AlignedVec::with_capacity(16_384),
             checksummer: create_crc32c_hasher(),
             max_record_size,
             current_data_file_size,
             max_data_file_size,
+            timestamp_records,
+            cipher: None,
             _t: PhantomData,
         }
     }
 
+    /// Sets the cipher encrypting the payload of each record.
+    #[must_use]
+    pub fn encrypt_with(mut self, cipher: Option<RecordCipher>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
     /// Gets a reference to the underlying writer.
     #[cfg(test)]
     pub fn get_ref(&self) -> &W {
@@ -467,12 +490,28 @@ where
         // the actual encoded size and then check it against the limit.
         //
         // C'est la vie.
+        //
+        // Timestamped records have the time they were written at prefixed to their payload.
+        let enqueued_at_len = if self.timestamp_records {
+            self.encode_buf.extend_from_slice(&enqueued_at_now());
+            ENQUEUED_AT_LEN
+        } else {
+            0
+        };
+        let encryption_overhead = if self.cipher.is_some() {
+            ENCRYPTION_OVERHEAD
+        } else {
+            0
+        };
         let encode_result = {
-            let mut encode_buf = (&mut self.encode_buf).limit(self.max_record_size);
+            let mut encode_buf = (&mut self.encode_buf).limit(
+                self.max_record_size
+                    .saturating_sub(enqueued_at_len + encryption_overhead),
+            );
             record.encode(&mut encode_buf)
         };
         let encoded_len = encode_result
-            .map(|()| self.encode_buf.len())
+            .map(|()| self.encode_buf.len() + encryption_overhead)
             .context(FailedToEncodeSnafu)?;
         if encoded_len > self.max_record_size {
             return Err(WriterError::RecordTooLarge {
@@ -480,9 +519,34 @@ where
             });
         }
 
-        let metadata = T::get_metadata().into_u32();
-        let wrapped_record =
-            Record::with_checksum(id, metadata, &self.encode_buf, &self.checksummer);
+        let mut metadata = T::get_metadata().into_u32();
+        debug_assert_eq!(
+            metadata & (ENQUEUED_AT_FLAG | ENCRYPTED_FLAG),
+            0,
+            "record metadata must not use the bits reserved for timestamped and encrypted records"
+        );
+        if self.timestamp_records {
+            metadata |= ENQUEUED_AT_FLAG;
+        }
+
+        // Encrypted records keep their timestamp in the clear, so that expired records can be
+        // discarded without being decrypted.  The encoded record is kept as is, in case it has to
+        // be decoded back below.
+        let payload = if let Some(cipher) = &self.cipher {
+            metadata |= ENCRYPTED_FLAG;
+            self.encrypt_buf.clear();
+            self.encrypt_buf
+                .extend_from_slice(&self.encode_buf[..enqueued_at_len]);
+            cipher.seal(
+                id,
+                &self.encode_buf[enqueued_at_len..],
+                &mut self.encrypt_buf,
+            );
+            &self.encrypt_buf
+        } else {
+            &self.encode_buf
+        };
+        let wrapped_record = Record::with_checksum(id, metadata, payload, &self.checksummer);
 
         // Push 8 dummy bytes where our length delimiter will sit.  We'll fix this up after
         // serialization.  Notably, `AlignedSerializer` will report the serializer position as
@@ -535,7 +599,8 @@ where
             // We have to decode the record back out to actually be able to give it back.  If we
             // can't decode it for some reason, this is entirely an unrecoverable error, since an
             // encoded record should always be decodable within the same process that encoded it.
-            let record = T::decode(T::get_metadata(), &self.encode_buf[..]).map_err(|_| {
+            let payload = &self.encode_buf[enqueued_at_len..];
+            let record = T::decode(T::get_metadata(), payload).map_err(|_| {
                 WriterError::InconsistentState {
                     reason: "failed to decode record immediately after encoding it".to_string(),
                 }
@@ -724,6 +789,9 @@ where
     data_file_full: bool,
     skip_to_next: bool,
     ready_to_write: bool,
+    take_finalizers: Option<fn(&mut T) -> EventFinalizers>,
+    unsynced_finalizers: EventFinalizers,
+    unsynced_bytes: u64,
     _t: PhantomData<T>,
 }
 
@@ -748,10 +816,30 @@ where
             ready_to_write: false,
             next_record_id,
             unflushed_events: 0,
+            take_finalizers: None,
+            unsynced_finalizers: EventFinalizers::default(),
+            unsynced_bytes: 0,
             _t: PhantomData,
         }
     }
 
+    /// Holds back the acknowledgements of records until they are synchronized to disk.
+    ///
+    /// `take_finalizers` takes the finalizers of a record before it is written, and they are
+    /// released once the data file is synchronized.  This only has an effect if the configured
+    /// durability holds back acknowledgements, since otherwise records are acknowledged as soon as
+    /// they are encoded.
+    pub(crate) fn hold_acknowledgements(&mut self, take_finalizers: fn(&mut T) -> EventFinalizers) {
+        if self.config.durability.holds_acknowledgements() {
+            self.take_finalizers = Some(take_finalizers);
+        }
+    }
+
+    /// Whether records were written since the data file was last synchronized to disk.
+    pub(crate) fn has_unsynced_writes(&self) -> bool {
+        self.unsynced_bytes > 0
+    }
+
     fn get_next_record_id(&mut self) -> u64 {
         self.next_record_id.wrapping_add(self.unflushed_events)
     }
@@ -882,7 +970,8 @@ where
                 // next writer record ID should be.
                 let record = try_as_record_archive(data_file_mmap.as_ref())
                     .expect("record was already validated");
-                let item = decode_record_payload::<T>(record).map_err(|e| {
+                let cipher = self.ledger.config().cipher.as_ref();
+                let item = decode_record_payload::<T>(record, cipher).map_err(|e| {
                     WriterError::FailedToValidate {
                         reason: e.to_string(),
                     }
@@ -1120,13 +1209,17 @@ where
                 // Make sure the file is flushed to disk, especially if we just created it.
                 data_file.sync_all().await?;
 
-                self.writer = Some(RecordWriter::new(
-                    data_file,
-                    data_file_size,
-                    self.config.write_buffer_size,
-                    self.config.max_data_file_size,
-                    self.config.max_record_size,
-                ));
+                self.writer = Some(
+                    RecordWriter::new(
+                        data_file,
+                        data_file_size,
+                        self.config.write_buffer_size,
+                        self.config.max_data_file_size,
+                        self.config.max_record_size,
+                        self.config.max_record_age.is_some(),
+                    )
+                    .encrypt_with(self.config.cipher.clone()),
+                );
                 self.data_file_size = data_file_size;
 
                 // If we opened the "next" data file, we need to increment the current writer
@@ -1184,6 +1277,11 @@ where
             .try_into()
             .map_err(|_| WriterError::EmptyRecord)?;
 
+        // Hold on to the finalizers of the record until it is synchronized to disk, if need be.
+        // Records recovered below, after failing to fit in the buffer, are decoded anew, so their
+        // finalizers are simply dropped, as they would be when encoding the record.
+        let finalizers = self.take_finalizers.map(|take| take(&mut record));
+
         // Grab the next record ID and attempt to write the record.
         let record_id = self.get_next_record_id();
 
@@ -1257,6 +1355,10 @@ where
         // setting the ledger state to a record ID that we may never have actually written, which
         // could lead to record ID gaps.
         self.track_write(record_events.get(), bytes_written as u64);
+        self.unsynced_bytes += bytes_written as u64;
+        if let Some(finalizers) = finalizers {
+            self.unsynced_finalizers.merge(finalizers);
+        }
 
         // If we did flush some buffered writes during this write, however, we now compensate for
         // that after updating our internal state.  We'll also notify the reader, too, since the
@@ -1314,15 +1416,31 @@ where
             self.ledger.notify_writer_waiters();
         }
 
-        if self.ledger.should_flush() || force_full_flush {
+        let should_sync = force_full_flush
+            || match self.config.durability {
+                Durability::None => self.ledger.should_flush(),
+                Durability::Interval { max_bytes, .. } => {
+                    self.ledger.should_flush()
+                        || max_bytes.is_some_and(|max_bytes| self.unsynced_bytes >= max_bytes)
+                }
+                Durability::Always => self.has_unsynced_writes() || self.ledger.should_flush(),
+            };
+
+        if should_sync {
             if let Some(writer) = self.writer.as_mut() {
                 writer.sync_all().await?;
             }
 
-            self.ledger.flush()
-        } else {
-            Ok(())
+            self.ledger.flush()?;
+
+            // Everything written so far is now on disk, so the records held back can be
+            // acknowledged.
+            self.unsynced_bytes = 0;
+            let finalizers = mem::take(&mut self.unsynced_finalizers);
+            finalizers.update_status(EventStatus::Delivered);
         }
+
+        Ok(())
     }
 
     /// Flushes the writer.
@@ -1331,7 +1449,8 @@ where
     ///
     /// This does not ensure that the data is fully synchronized (i.e. `fsync`) to disk, however it
     /// may sometimes perform a full synchronization if the time since the last full synchronization
-    /// occurred has exceeded a configured limit.
+    /// occurred has exceeded a configured limit, or as the configured durability requires.  Records
+    /// whose acknowledgements are held back are acknowledged once synchronized.
     ///
     /// # Errors
     ///
@@ -1374,6 +1493,9 @@ where
     FS::File: Unpin,
 {
     fn drop(&mut self) {
+        // Records held back waiting to be synchronized to disk may not have made it there, so
+        // they are not acknowledged as delivered.
+        self.unsynced_finalizers.update_status(EventStatus::Errored);
         self.close();
     }
 }
@@ -6,18 +6,28 @@ use crate::{
     buffer_usage_data::BufferUsageHandle,
     topology::{
         builder::IntoBuffer,
-        channel::{limited, ReceiverAdapter, SenderAdapter},
+        channel::{limited_by_size, ReceiverAdapter, SenderAdapter},
     },
     Bufferable,
 };
 
 pub struct MemoryBuffer {
-    capacity: NonZeroUsize,
+    max_events: Option<NonZeroUsize>,
+    max_size: Option<NonZeroUsize>,
 }
 
 impl MemoryBuffer {
     pub fn new(capacity: NonZeroUsize) -> Self {
-        MemoryBuffer { capacity }
+        Self::with_limits(Some(capacity), None)
+    }
+
+    /// Creates a memory buffer limited by the number of events in it, their estimated size in
+    /// bytes, or both.
+    pub fn with_limits(max_events: Option<NonZeroUsize>, max_size: Option<NonZeroUsize>) -> Self {
+        MemoryBuffer {
+            max_events,
+            max_size,
+        }
     }
 }
 
@@ -30,9 +40,11 @@ where
         self: Box<Self>,
         usage_handle: BufferUsageHandle,
     ) -> Result<(SenderAdapter<T>, ReceiverAdapter<T>), Box<dyn Error + Send + Sync>> {
-        usage_handle.set_buffer_limits(None, Some(self.capacity.get()));
+        let max_events = self.max_events.map(NonZeroUsize::get);
+        let max_size = self.max_size.map(NonZeroUsize::get);
+        usage_handle.set_buffer_limits(max_size.map(|size| size as u64), max_events);
 
-        let (tx, rx) = limited(self.capacity.get());
+        let (tx, rx) = limited_by_size(max_events, max_size);
         Ok((tx.into(), rx.into()))
     }
 }
@@ -3,7 +3,7 @@
 //! as it flows through transforms, being duplicated and merged, and
 //! then report its status when the last copy is delivered or dropped.
 
-use std::{cmp, future::Future, mem, pin::Pin, sync::Arc, task::Poll};
+use std::{cmp, fmt, future::Future, mem, pin::Pin, sync::Arc, task::Poll, time::Instant};
 
 use crossbeam_utils::atomic::AtomicCell;
 use futures::future::FutureExt;
@@ -11,6 +11,9 @@ use tokio::sync::oneshot;
 
 #[cfg(feature = "byte_size_of")]
 use crate::byte_size_of::ByteSizeOf;
+use crate::internal_event::{
+    InternalEventHandle as _, Registered, SourceUnacknowledgedEvents, UnacknowledgedEventsChange,
+};
 
 /// A collection of event finalizers.
 #[derive(Clone, Debug, Default)]
@@ -129,6 +132,11 @@ impl EventFinalizer {
     #[must_use]
     pub fn new(batch: BatchNotifier) -> Self {
         let status = AtomicCell::new(EventStatus::Dropped);
+        if let Some(unacknowledged) = &batch.0.unacknowledged {
+            unacknowledged
+                .events
+                .emit(UnacknowledgedEventsChange::Added);
+        }
         Self { status, batch }
     }
 
@@ -154,6 +162,11 @@ impl EventFinalizer {
 impl Drop for EventFinalizer {
     fn drop(&mut self) {
         self.update_batch();
+        if let Some(unacknowledged) = &self.batch.0.unacknowledged {
+            unacknowledged
+                .events
+                .emit(UnacknowledgedEventsChange::Finalized);
+        }
     }
 }
 
@@ -198,10 +211,29 @@ impl BatchNotifier {
     /// Creates a new `BatchNotifier` along with the receiver used to await its finalization status.
     #[must_use]
     pub fn new_with_receiver() -> (Self, BatchStatusReceiver) {
+        Self::new_with_tracking(None)
+    }
+
+    /// Creates a new `BatchNotifier` along with the receiver used to await its finalization status.
+    ///
+    /// The events the batch is applied to are reported through `unacknowledged`, registered by the
+    /// source sending them, until they are finalized.
+    #[must_use]
+    pub fn new_tracked_with_receiver(
+        unacknowledged: &Registered<SourceUnacknowledgedEvents>,
+    ) -> (Self, BatchStatusReceiver) {
+        Self::new_with_tracking(Some(Unacknowledged {
+            events: unacknowledged.clone(),
+            created: Instant::now(),
+        }))
+    }
+
+    fn new_with_tracking(unacknowledged: Option<Unacknowledged>) -> (Self, BatchStatusReceiver) {
         let (sender, receiver) = oneshot::channel();
         let notifier = OwnedBatchNotifier {
             status: AtomicCell::new(BatchStatus::Delivered),
             notifier: Some(sender),
+            unacknowledged,
         };
         (Self(Arc::new(notifier)), BatchStatusReceiver(receiver))
     }
@@ -217,11 +249,43 @@ impl BatchNotifier {
         }
     }
 
+    /// Optionally creates a new `BatchNotifier` along with the receiver used to await its finalization status,
+    /// reporting the events it is applied to through `unacknowledged` until they are finalized.
+    #[must_use]
+    pub fn maybe_new_tracked_with_receiver(
+        enabled: bool,
+        unacknowledged: &Registered<SourceUnacknowledgedEvents>,
+    ) -> (Option<Self>, Option<BatchStatusReceiver>) {
+        if enabled {
+            let (batch, receiver) = Self::new_tracked_with_receiver(unacknowledged);
+            (Some(batch), Some(receiver))
+        } else {
+            (None, None)
+        }
+    }
+
     /// Creates a new `BatchNotifier` and attaches it to a group of events.
     ///
     /// The receiver used to await the finalization status of the batch is returned.
     pub fn apply_to<T: AddBatchNotifier>(items: &mut [T]) -> BatchStatusReceiver {
-        let (batch, receiver) = Self::new_with_receiver();
+        Self::attach(Self::new_with_receiver(), items)
+    }
+
+    /// Creates a new `BatchNotifier` and attaches it to a group of events, which are reported
+    /// through `unacknowledged` until they are finalized.
+    ///
+    /// The receiver used to await the finalization status of the batch is returned.
+    pub fn apply_tracked_to<T: AddBatchNotifier>(
+        items: &mut [T],
+        unacknowledged: &Registered<SourceUnacknowledgedEvents>,
+    ) -> BatchStatusReceiver {
+        Self::attach(Self::new_tracked_with_receiver(unacknowledged), items)
+    }
+
+    fn attach<T: AddBatchNotifier>(
+        (batch, receiver): (Self, BatchStatusReceiver),
+        items: &mut [T],
+    ) -> BatchStatusReceiver {
         for item in items {
             item.add_batch_notifier(batch.clone());
         }
@@ -239,6 +303,19 @@ impl BatchNotifier {
         enabled.then(|| Self::apply_to(items))
     }
 
+    /// Optionally creates a new `BatchNotifier` and attaches it to a group of events, which are
+    /// reported through `unacknowledged` until they are finalized.
+    ///
+    /// If `enabled`, the receiver used to await the finalization status of the batch is
+    /// returned. Otherwise, `None` is returned.
+    pub fn maybe_apply_tracked_to<T: AddBatchNotifier>(
+        enabled: bool,
+        items: &mut [T],
+        unacknowledged: &Registered<SourceUnacknowledgedEvents>,
+    ) -> Option<BatchStatusReceiver> {
+        enabled.then(|| Self::apply_tracked_to(items, unacknowledged))
+    }
+
     /// Updates the status of the notifier.
     fn update_status(&self, status: EventStatus) {
         // The status starts as Delivered and can only change if the new
@@ -257,12 +334,37 @@ impl BatchNotifier {
 pub struct OwnedBatchNotifier {
     status: AtomicCell<BatchStatus>,
     notifier: Option<oneshot::Sender<BatchStatus>>,
+    unacknowledged: Option<Unacknowledged>,
+}
+
+/// Tracks the events of a batch until they are finalized.
+///
+/// The handle is registered by the source creating the batch, so it is labelled with that source,
+/// even though its events are finalized by other components.
+struct Unacknowledged {
+    events: Registered<SourceUnacknowledgedEvents>,
+    created: Instant,
+}
+
+impl fmt::Debug for Unacknowledged {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Unacknowledged")
+            .field("created", &self.created)
+            .finish_non_exhaustive()
+    }
 }
 
 impl OwnedBatchNotifier {
     /// Sends the status of the notifier back to the source.
     fn send_status(&mut self) {
         if let Some(notifier) = self.notifier.take() {
+            if let Some(unacknowledged) = &self.unacknowledged {
+                unacknowledged
+                    .events
+                    .emit(UnacknowledgedEventsChange::BatchFinalized(
+                        unacknowledged.created.elapsed(),
+                    ));
+            }
             let status = self.status.load();
             // Ignore the error case, as it will happen during normal
             // source shutdown and we can't detect that here.
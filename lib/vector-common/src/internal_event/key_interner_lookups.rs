@@ -0,0 +1,19 @@
+use metrics::{counter, Counter};
+use tracing::trace;
+
+use super::KeyLookups;
+
+crate::registered_event!(
+    KeyInternerLookups => {
+        hits: Counter = counter!("key_interner_hits_total"),
+        misses: Counter = counter!("key_interner_misses_total"),
+    }
+
+    fn emit(&self, data: KeyLookups) {
+        let KeyLookups(hits, misses) = data;
+
+        trace!(message = "Interned keys.", hits = %hits, misses = %misses);
+        self.hits.increment(hits);
+        self.misses.increment(misses);
+    }
+);
@@ -4,11 +4,16 @@ pub mod cached_event;
 pub mod component_events_dropped;
 mod events_received;
 mod events_sent;
+mod key_interner_lookups;
 mod optional_tag;
 mod prelude;
 pub mod service;
+mod source_unacknowledged_events;
 
-use std::ops::{Add, AddAssign};
+use std::{
+    ops::{Add, AddAssign},
+    time::Duration,
+};
 
 pub use metrics::SharedString;
 
@@ -19,9 +24,11 @@ pub use cached_event::{RegisterTaggedInternalEvent, RegisteredEventCache};
 pub use component_events_dropped::{ComponentEventsDropped, INTENTIONAL, UNINTENTIONAL};
 pub use events_received::EventsReceived;
 pub use events_sent::{EventsSent, TaggedEventsSent, DEFAULT_OUTPUT};
+pub use key_interner_lookups::KeyInternerLookups;
 pub use optional_tag::OptionalTag;
 pub use prelude::{error_stage, error_type};
 pub use service::{CallError, PollReadyError};
+pub use source_unacknowledged_events::SourceUnacknowledgedEvents;
 
 use crate::json_size::JsonSize;
 
@@ -134,6 +141,21 @@ impl Add<CountByteSize> for CountByteSize {
     }
 }
 
+/// Holds the tuple `(hits, misses)` of the lookups made in a key interner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyLookups(pub u64, pub u64);
+
+/// A change in the events sent by a source which are awaiting acknowledgement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnacknowledgedEventsChange {
+    /// An event was added to a batch awaiting acknowledgement.
+    Added,
+    /// An event of a batch awaiting acknowledgement was finalized.
+    Finalized,
+    /// All the events of a batch were finalized, this long after the batch was created.
+    BatchFinalized(Duration),
+}
+
 // Wrapper types used to hold parameters for registering events
 
 pub struct Output(pub Option<SharedString>);
@@ -0,0 +1,18 @@
+use metrics::{gauge, histogram, Gauge, Histogram};
+
+use super::UnacknowledgedEventsChange;
+
+crate::registered_event!(
+    SourceUnacknowledgedEvents => {
+        events: Gauge = gauge!("source_unacknowledged_events"),
+        duration: Histogram = histogram!("source_acknowledgement_duration_seconds"),
+    }
+
+    fn emit(&self, data: UnacknowledgedEventsChange) {
+        match data {
+            UnacknowledgedEventsChange::Added => self.events.increment(1.0),
+            UnacknowledgedEventsChange::Finalized => self.events.decrement(1.0),
+            UnacknowledgedEventsChange::BatchFinalized(duration) => self.duration.record(duration),
+        }
+    }
+);
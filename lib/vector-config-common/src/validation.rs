@@ -2,9 +2,12 @@
 // https://github.com/TedDriggs/darling/issues/293
 #![allow(clippy::manual_unwrap_or_default)]
 
+use std::path::Path;
+
 use darling::FromMeta;
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use serde_json::Value;
 use syn::{Expr, Lit, Meta};
 
 use crate::{
@@ -162,7 +165,47 @@ impl ToTokens for Format {
     }
 }
 
+/// Constraints on file paths.
+#[derive(Clone, Debug, FromMeta)]
+pub enum PathValidation {
+    /// The path must be absolute.
+    ///
+    /// On Unix, this means starting at the root (`/`). On Windows, this means starting with a drive letter (`C:\`).
+    Absolute,
+}
+
+impl PathValidation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PathValidation::Absolute => "absolute",
+        }
+    }
+
+    fn pattern(&self) -> &'static str {
+        match self {
+            PathValidation::Absolute => r"^(/|[a-zA-Z]:[\\/])",
+        }
+    }
+}
+
+impl ToTokens for PathValidation {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let vector_config = configurable_package_name_hack();
+        let path_tokens = match self {
+            PathValidation::Absolute => {
+                quote! { #vector_config::validation::PathValidation::Absolute }
+            }
+        };
+
+        tokens.extend(path_tokens);
+    }
+}
+
 /// A validation definition.
+///
+/// Besides being emitted into the schema, validations on the fields of a `#[configurable_component]` type are
+/// enforced when the type is deserialized, with the exception of formats and patterns, which only apply to the
+/// schema.
 #[derive(Clone, Debug, FromMeta)]
 #[darling(and_then = "Self::ensure_conformance")]
 pub enum Validation {
@@ -199,6 +242,16 @@ pub enum Validation {
     ///
     /// Can only be used for strings.
     Pattern(String),
+
+    /// A non-empty value.
+    ///
+    /// Shorthand for a minimum length of one, and so can be used for strings, arrays, and objects.
+    NotEmpty,
+
+    /// A constraint on a file path.
+    ///
+    /// Can only be used for strings.
+    Path(PathValidation),
 }
 
 impl Validation {
@@ -292,6 +345,88 @@ impl Validation {
                     schema.string().pattern = Some(pattern.clone());
                 }
             }
+            Validation::NotEmpty => Validation::Length {
+                minimum: Some(1),
+                maximum: None,
+            }
+            .apply(schema),
+            Validation::Path(path) => {
+                if contains_instance_type(schema, InstanceType::String) {
+                    schema.string().pattern = Some(path.pattern().to_string());
+                }
+            }
+        }
+    }
+
+    /// Checks that the given value, in its serialized form, satisfies this validation.
+    ///
+    /// Values the validation does not apply to, such as the length of a number, or a missing optional value, are
+    /// accepted. Formats and patterns are not checked, as they only serve to document the expected value in the
+    /// schema.
+    ///
+    /// # Errors
+    ///
+    /// If the value does not satisfy the validation, an error describing why is returned.
+    pub fn check(&self, value: &Value) -> Result<(), String> {
+        match self {
+            Validation::KnownFormat(_) | Validation::Pattern(_) => Ok(()),
+            Validation::Length { minimum, maximum } => {
+                let (length, unit) = match value {
+                    Value::String(s) => (s.chars().count(), "characters"),
+                    Value::Array(a) => (a.len(), "items"),
+                    Value::Object(o) => (o.len(), "properties"),
+                    _ => return Ok(()),
+                };
+
+                if let Some(minimum) = minimum {
+                    if length < *minimum as usize {
+                        return Err(format!(
+                            "must have at least {minimum} {unit}, but has {length}"
+                        ));
+                    }
+                }
+
+                if let Some(maximum) = maximum {
+                    if length > *maximum as usize {
+                        return Err(format!(
+                            "must have at most {maximum} {unit}, but has {length}"
+                        ));
+                    }
+                }
+
+                Ok(())
+            }
+            Validation::Range { minimum, maximum } => {
+                let Some(number) = value.as_f64() else {
+                    return Ok(());
+                };
+
+                if let Some(minimum) = minimum {
+                    if number < *minimum {
+                        return Err(format!("must be at least {minimum}, but is {value}"));
+                    }
+                }
+
+                if let Some(maximum) = maximum {
+                    if number > *maximum {
+                        return Err(format!("must be at most {maximum}, but is {value}"));
+                    }
+                }
+
+                Ok(())
+            }
+            Validation::NotEmpty => match value {
+                Value::String(s) if s.is_empty() => Err("must not be empty".to_string()),
+                Value::Array(a) if a.is_empty() => Err("must not be empty".to_string()),
+                Value::Object(o) if o.is_empty() => Err("must not be empty".to_string()),
+                _ => Ok(()),
+            },
+            Validation::Path(PathValidation::Absolute) => match value {
+                Value::String(s) if !is_absolute_path(s) => {
+                    Err(format!("must be an absolute path, but is \"{s}\""))
+                }
+                _ => Ok(()),
+            },
         }
     }
 }
@@ -318,6 +453,10 @@ impl ToTokens for Validation {
             Validation::Pattern(pattern) => {
                 quote! { #vector_config::validation::Validation::Pattern(#pattern.to_string()) }
             }
+            Validation::NotEmpty => quote! { #vector_config::validation::Validation::NotEmpty },
+            Validation::Path(path) => {
+                quote! { #vector_config::validation::Validation::Path(#path) }
+            }
         };
 
         tokens.extend(validation_tokens);
@@ -331,6 +470,15 @@ fn option_as_token<T: ToTokens>(optional: Option<T>) -> proc_macro2::TokenStream
     }
 }
 
+fn is_absolute_path(path: &str) -> bool {
+    // `Path::is_absolute` only knows about the conventions of the platform we're running on, but configurations
+    // written for Windows should still be understood as such when checked elsewhere, such as when generating
+    // documentation.
+    Path::new(path).is_absolute()
+        || path.starts_with('/')
+        || matches!(path.as_bytes(), [drive, b':', b'\\' | b'/', ..] if drive.is_ascii_alphabetic())
+}
+
 fn contains_instance_type(schema: &SchemaObject, instance_type: InstanceType) -> bool {
     schema
         .instance_type
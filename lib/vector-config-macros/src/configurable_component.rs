@@ -1,14 +1,15 @@
 use darling::{ast::NestedMeta, Error, FromMeta};
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
-use quote::{quote, quote_spanned};
+use quote::{format_ident, quote, quote_spanned};
 use syn::{
-    parse_macro_input, parse_quote, parse_quote_spanned, punctuated::Punctuated, spanned::Spanned,
-    token::Comma, DeriveInput, Lit, LitStr, Meta, MetaList, Path,
+    ext::IdentExt, parse_macro_input, parse_quote, parse_quote_spanned, punctuated::Punctuated,
+    spanned::Spanned, token::Comma, Attribute, Data, DeriveInput, Expr, ExprLit, ExprPath, Lit,
+    LitStr, Meta, MetaList, Path,
 };
 use vector_config_common::{
     configurable_package_name_hack, constants::ComponentType,
-    human_friendly::generate_human_friendly_string,
+    human_friendly::generate_human_friendly_string, validation::Validation,
 };
 
 use crate::attrs;
@@ -265,7 +266,7 @@ pub fn configurable_component_impl(args: TokenStream, item: TokenStream) -> Toke
         parse_macro_input!(args with Punctuated::<NestedMeta, Comma>::parse_terminated)
             .into_iter()
             .collect();
-    let input = parse_macro_input!(item as DeriveInput);
+    let mut input = parse_macro_input!(item as DeriveInput);
 
     let options = match Options::from_list(&args) {
         Ok(v) => v,
@@ -274,6 +275,17 @@ pub fn configurable_component_impl(args: TokenStream, item: TokenStream) -> Toke
         }
     };
 
+    // Validations are only enforced when we derive `serde::Deserialize` ourselves, as a manual
+    // implementation has to enforce them on its own.
+    let field_validators = if options.skip_derive_deser() {
+        None
+    } else {
+        match generate_field_validators(&mut input) {
+            Ok(field_validators) => field_validators,
+            Err(e) => return TokenStream::from(e.write_errors()),
+        }
+    };
+
     // If the component is typed (see `TypedComponent`/`ComponentType`), we do a few additional
     // things:
     // - we add a metadata attribute to indicate the component type
@@ -333,11 +345,276 @@ pub fn configurable_component_impl(args: TokenStream, item: TokenStream) -> Toke
         #maybe_component_name
         #input
         #maybe_component_desc
+        #field_validators
     };
 
     derived.into()
 }
 
+/// Generates the code enforcing the validations of fields during deserialization.
+///
+/// Validations declared with `#[configurable(validation(...))]` end up in the schema via the
+/// `Configurable` derive, but `serde` knows nothing about them. For each named field with
+/// validations that can be checked at runtime, we generate a function that deserializes the field
+/// as it otherwise would have been, and then checks the validations against the serialized form of
+/// the value, and point `serde` at it with `#[serde(deserialize_with = "...")]`.
+///
+/// If no field needs to be validated, `None` is returned.
+fn generate_field_validators(
+    input: &mut DeriveInput,
+) -> darling::Result<Option<proc_macro2::TokenStream>> {
+    let container_items = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .flat_map(serde_metas)
+        .collect::<Vec<_>>();
+
+    // Containers deserialized via another type never deserialize their fields directly.
+    if container_items
+        .iter()
+        .any(|meta| meta.path().is_ident("from") || meta.path().is_ident("try_from"))
+    {
+        return Ok(None);
+    }
+    let container_has_default = container_items
+        .iter()
+        .any(|meta| meta.path().is_ident("default"));
+
+    let vector_config = configurable_package_name_hack();
+    let container = input.ident.clone();
+    let is_generic = input.generics.type_params().next().is_some();
+    let mut errors = Error::accumulator();
+    let mut validators = Vec::new();
+
+    let fields = match &mut input.data {
+        Data::Struct(data) => data
+            .fields
+            .iter_mut()
+            .map(|f| (None, f))
+            .collect::<Vec<_>>(),
+        Data::Enum(data) => data
+            .variants
+            .iter_mut()
+            .flat_map(|variant| {
+                let variant_ident = variant.ident.clone();
+                variant
+                    .fields
+                    .iter_mut()
+                    .map(move |f| (Some(variant_ident.clone()), f))
+            })
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+
+    for (variant_ident, field) in fields {
+        let Some(field_ident) = field.ident.clone() else {
+            continue;
+        };
+
+        // Formats and patterns only serve to document the field, so there's nothing to enforce if
+        // those are all the field has.
+        let validations = field_validations(&field.attrs)
+            .into_iter()
+            .filter(|validation| {
+                !matches!(
+                    validation,
+                    Validation::KnownFormat(_) | Validation::Pattern(_)
+                )
+            })
+            .collect::<Vec<_>>();
+        if validations.is_empty() {
+            continue;
+        }
+
+        let serde_items = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("serde"))
+            .flat_map(serde_metas)
+            .collect::<Vec<_>>();
+        let has_serde_flag = |name: &str| serde_items.iter().any(|meta| meta.path().is_ident(name));
+        let serde_value = |name: &str| {
+            serde_items.iter().find_map(|meta| match meta {
+                Meta::NameValue(nv) if nv.path.is_ident(name) => match &nv.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(s), ..
+                    }) => Some(s.clone()),
+                    _ => None,
+                },
+                _ => None,
+            })
+        };
+
+        if has_serde_flag("skip")
+            || has_serde_flag("skip_deserializing")
+            || has_serde_flag("flatten")
+        {
+            continue;
+        }
+
+        // `serde_with` relies on `with`, which is also what the `Configurable` derive looks at to
+        // figure out the delegated type of the field, so we can't take it over.
+        if has_serde_flag("with")
+            || field
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("serde_as"))
+        {
+            errors.push(
+                Error::custom("validations cannot be enforced on fields using `#[serde(with = \"...\")]` or `#[serde_as(...)]`")
+                    .with_span(&field_ident),
+            );
+            continue;
+        }
+
+        let deserialize_with = match serde_value("deserialize_with").map(|s| s.parse::<ExprPath>())
+        {
+            Some(Ok(path)) => Some(path),
+            Some(Err(e)) => {
+                errors.push(e.into());
+                continue;
+            }
+            None => None,
+        };
+        let serialize_with = match serde_value("serialize_with").map(|s| s.parse::<ExprPath>()) {
+            Some(Ok(path)) => Some(path),
+            Some(Err(e)) => {
+                errors.push(e.into());
+                continue;
+            }
+            None => None,
+        };
+
+        let field_name = serde_value("rename")
+            .map(|s| s.value())
+            .unwrap_or_else(|| field_ident.unraw().to_string());
+        let field_ty = &field.ty;
+
+        let mut bounds = Vec::new();
+        let deserialize = match &deserialize_with {
+            Some(path) => quote! { #path(deserializer) },
+            None => {
+                bounds.push(quote! { #field_ty: ::serde::Deserialize<'de> });
+                quote! { <#field_ty as ::serde::Deserialize<'de>>::deserialize(deserializer) }
+            }
+        };
+        let serialize = match &serialize_with {
+            Some(path) => quote! { #path(&value, #vector_config::serde_json::value::Serializer) },
+            None => {
+                bounds.push(quote! { #field_ty: ::serde::Serialize });
+                quote! { #vector_config::serde_json::to_value(&value) }
+            }
+        };
+        // Bounds on concrete types are fine as long as they hold, but there's no point in
+        // emitting them.
+        if !is_generic {
+            bounds.clear();
+        }
+
+        let fn_ident = match variant_ident {
+            Some(variant_ident) => format_ident!(
+                "__vector_config_deserialize_{}_{}",
+                variant_ident.unraw(),
+                field_ident.unraw()
+            ),
+            None => format_ident!("__vector_config_deserialize_{}", field_ident.unraw()),
+        };
+
+        validators.push(quote! {
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            fn #fn_ident<'de, __D>(deserializer: __D) -> ::std::result::Result<#field_ty, __D::Error>
+            where
+                __D: ::serde::Deserializer<'de>,
+                #(#bounds,)*
+            {
+                let value = #deserialize?;
+                #vector_config::__check_field_validations(#field_name, #serialize, &[#(#validations),*])?;
+                ::std::result::Result::Ok(value)
+            }
+        });
+
+        // Point `serde` at the generated function instead of any `deserialize_with` the field had,
+        // as the generated function calls it in turn.
+        field.attrs.retain_mut(|attr| {
+            if !attr.path().is_ident("serde") {
+                return true;
+            }
+
+            let metas = serde_metas(attr)
+                .into_iter()
+                .filter(|meta| !meta.path().is_ident("deserialize_with"))
+                .collect::<Punctuated<Meta, Comma>>();
+            if metas.is_empty() {
+                return false;
+            }
+
+            attr.meta = parse_quote! { serde(#metas) };
+            true
+        });
+        let deserialize_with = format!("{}::{}", container, fn_ident);
+        field
+            .attrs
+            .push(parse_quote! { #[serde(deserialize_with = #deserialize_with)] });
+
+        // `serde` only treats missing `Option<T>` fields as `None` when they have no
+        // `deserialize_with`, so we have to say so explicitly to keep them optional.
+        if is_option(&field.ty) && !has_serde_flag("default") && !container_has_default {
+            field.attrs.push(parse_quote! { #[serde(default)] });
+        }
+    }
+
+    errors.finish()?;
+
+    if validators.is_empty() {
+        return Ok(None);
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(Some(quote! {
+        impl #impl_generics #container #ty_generics #where_clause {
+            #(#validators)*
+        }
+    }))
+}
+
+/// Gets the validations declared on a field.
+///
+/// Validations that fail to parse are ignored, as the `Configurable` derive reports those errors.
+fn field_validations(attrs: &[Attribute]) -> Vec<Validation> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("configurable"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .filter(|meta| meta.path().is_ident("validation"))
+        .filter_map(|meta| Validation::from_meta(&meta).ok())
+        .collect()
+}
+
+/// Whether or not the given type is an `Option<T>`.
+fn is_option(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(tp) => tp
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+/// Gets the items of a `#[serde(...)]` attribute.
+fn serde_metas(attr: &Attribute) -> Vec<Meta> {
+    attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated)
+        .map(|metas| metas.into_iter().collect())
+        .unwrap_or_default()
+}
+
 /// Gets the ident of the component type-specific helper attribute for the `NamedComponent` derive.
 ///
 /// When we emit code for a configurable item that has been marked as a typed component, we
@@ -79,6 +79,36 @@ mod configurable_component;
 ///   // having them automatically derived via `#[configurable_component]`.
 /// }
 /// ```
+///
+/// ## Validation
+///
+/// Validations declared on named fields with `#[configurable(validation(...))]` are emitted into the generated schema,
+/// and, unless `no_deser` is used, enforced when the type is deserialized: a value that does not satisfy them fails to
+/// deserialize with an error naming the field. Formats (`format = "..."`) and patterns (`pattern = "..."`) are the
+/// exception, as they are only emitted into the schema.
+///
+/// ```no_run
+/// use vector_config::configurable_component;
+///
+/// /// Configuration for a socket.
+/// #[configurable_component]
+/// pub struct SocketConfig {
+///   /// The address to connect to.
+///   #[configurable(validation(not_empty))]
+///   address: String,
+///
+///   /// The path of the socket.
+///   #[configurable(validation(path = "absolute"))]
+///   path: std::path::PathBuf,
+///
+///   /// The size of the socket's send buffer.
+///   #[configurable(validation(range(min = 1024)))]
+///   send_buffer_bytes: Option<usize>,
+/// }
+/// ```
+///
+/// Fields (de)serialized with `#[serde(with = "...")]`, including through `serde_with`, cannot have their validations
+/// enforced, and using them together fails to compile.
 #[proc_macro_attribute]
 pub fn configurable_component(attrs: TokenStream, item: TokenStream) -> TokenStream {
     configurable_component::configurable_component_impl(attrs, item)
@@ -140,6 +140,33 @@ pub use vector_config_macros::*;
 // `Configurable` from `vector_config` itself, and not leak out the crate arrangement as an impl detail.
 pub use vector_config_common::{attributes, validation};
 
+#[doc(hidden)]
+pub fn __check_field_validations<E>(
+    field: &str,
+    value: Result<serde_json::Value, serde_json::Error>,
+    validations: &[validation::Validation],
+) -> Result<(), E>
+where
+    E: serde::de::Error,
+{
+    // Fields with validations attached to them in a `#[configurable_component]` type are deserialized with a generated
+    // function that calls this one, which checks the validations against the serialized form of the deserialized value:
+    // that form is what the validations describe in the schema, and so what the user actually wrote.
+    //
+    // Values that can't be serialized to JSON are left alone, as there is nothing we could check them against.
+    let Ok(value) = value else {
+        return Ok(());
+    };
+
+    for validation in validations {
+        validation
+            .check(&value)
+            .map_err(|reason| E::custom(format!("invalid value for `{field}`: {reason}")))?;
+    }
+
+    Ok(())
+}
+
 #[doc(hidden)]
 pub fn __ensure_numeric_validation_bounds<N>(metadata: &Metadata) -> Result<(), GenerateError>
 where
@@ -1,3 +1,4 @@
 mod configurable_string;
 mod named;
 mod smoke;
+mod validation;
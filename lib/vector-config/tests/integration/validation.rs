@@ -0,0 +1,145 @@
+#![allow(dead_code)]
+
+use std::{collections::HashMap, num::NonZeroU64, path::PathBuf};
+
+use serde::Deserializer;
+use serde_json::json;
+use vector_config::{configurable_component, schema::generate_root_schema};
+
+fn deserialize_doubled<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    <u64 as serde::Deserialize>::deserialize(deserializer).map(|n| n * 2)
+}
+
+/// A configuration with validated fields.
+#[configurable_component]
+#[derive(Clone, Debug)]
+struct Validated {
+    /// A name.
+    #[configurable(validation(not_empty))]
+    name: String,
+
+    /// A socket path.
+    #[configurable(validation(path = "absolute"))]
+    #[serde(default = "default_path")]
+    path: PathBuf,
+
+    /// A ratio.
+    #[configurable(validation(range(min = 0.0, max = 1.0)))]
+    #[serde(default)]
+    ratio: f64,
+
+    /// A limit.
+    #[configurable(validation(range(max = 100000)))]
+    limit: Option<NonZeroU64>,
+
+    /// Some tags.
+    #[configurable(validation(length(max = 2)))]
+    #[serde(default)]
+    tags: HashMap<String, String>,
+
+    /// A doubled value, which is checked after being deserialized.
+    #[configurable(validation(range(max = 10)))]
+    #[serde(default, rename = "size", deserialize_with = "deserialize_doubled")]
+    doubled: u64,
+}
+
+fn default_path() -> PathBuf {
+    PathBuf::from("/run/socket")
+}
+
+/// A configured mode.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+enum Mode {
+    /// Connects to an address.
+    Connect {
+        /// The address.
+        #[configurable(validation(not_empty))]
+        address: String,
+    },
+}
+
+fn error(value: serde_json::Value) -> String {
+    serde_json::from_value::<Validated>(value)
+        .unwrap_err()
+        .to_string()
+}
+
+#[test]
+fn accepts_valid_values() {
+    let config = serde_json::from_value::<Validated>(json!({
+        "name": "foo",
+        "path": "/var/run/socket",
+        "ratio": 0.5,
+        "limit": 1000,
+        "tags": { "a": "b" },
+        "size": 5,
+    }))
+    .unwrap();
+    assert_eq!(config.doubled, 10);
+
+    // Fields left out are not validated, and `Option<T>` fields are still optional.
+    let config = serde_json::from_value::<Validated>(json!({ "name": "foo" })).unwrap();
+    assert_eq!(config.path, default_path());
+    assert!(config.limit.is_none());
+}
+
+#[test]
+fn rejects_invalid_values() {
+    assert_eq!(
+        error(json!({ "name": "" })),
+        "invalid value for `name`: must not be empty"
+    );
+    assert_eq!(
+        error(json!({ "name": "foo", "path": "relative/socket" })),
+        "invalid value for `path`: must be an absolute path, but is \"relative/socket\""
+    );
+    assert_eq!(
+        error(json!({ "name": "foo", "ratio": 1.5 })),
+        "invalid value for `ratio`: must be at most 1, but is 1.5"
+    );
+    assert_eq!(
+        error(json!({ "name": "foo", "limit": 100001 })),
+        "invalid value for `limit`: must be at most 100000, but is 100001"
+    );
+    assert_eq!(
+        error(json!({ "name": "foo", "tags": { "a": "1", "b": "2", "c": "3" } })),
+        "invalid value for `tags`: must have at most 2 properties, but has 3"
+    );
+    assert_eq!(
+        error(json!({ "name": "foo", "size": 6 })),
+        "invalid value for `size`: must be at most 10, but is 12"
+    );
+}
+
+#[test]
+fn rejects_invalid_variant_fields() {
+    assert!(
+        serde_json::from_value::<Mode>(json!({ "mode": "connect", "address": "host:80" })).is_ok()
+    );
+
+    let error = serde_json::from_value::<Mode>(json!({ "mode": "connect", "address": "" }))
+        .unwrap_err()
+        .to_string();
+    assert_eq!(error, "invalid value for `address`: must not be empty");
+}
+
+#[test]
+fn emits_validations_into_schema() {
+    let schema = serde_json::to_value(generate_root_schema::<Validated>().unwrap()).unwrap();
+    let properties = schema["definitions"]
+        .as_object()
+        .unwrap()
+        .values()
+        .map(|definition| &definition["properties"])
+        .find(|properties| properties["name"].is_object())
+        .unwrap();
+
+    assert_eq!(properties["name"]["minLength"], json!(1));
+    assert_eq!(properties["ratio"]["maximum"], json!(1.0));
+    assert_eq!(properties["tags"]["maxProperties"], json!(2));
+}
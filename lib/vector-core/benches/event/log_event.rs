@@ -1,10 +1,14 @@
 use std::time::Duration;
 
 use criterion::{
-    criterion_group, measurement::WallTime, BatchSize, BenchmarkGroup, Criterion, SamplingMode,
+    black_box, criterion_group, measurement::WallTime, BatchSize, BenchmarkGroup, Criterion,
+    SamplingMode,
 };
 use lookup::event_path;
-use vector_core::event::LogEvent;
+use vector_core::event::{
+    util::log::{FlattenOptions, KeyInterner},
+    LogEvent,
+};
 
 fn default_log_event() -> LogEvent {
     let mut log_event = LogEvent::default();
@@ -40,6 +44,55 @@ fn rename_key_flat(c: &mut Criterion) {
     });
 }
 
+/// A log event with 30 fields, nested in objects and arrays.
+fn nested_log_event() -> LogEvent {
+    let mut log_event = LogEvent::default();
+    for i in 0..10 {
+        log_event.insert(format!("field{i}").as_str(), i);
+        log_event.insert(format!("nested.field{i}.value").as_str(), "value");
+        log_event.insert(format!("array.items[{i}]").as_str(), i % 2 == 0);
+    }
+    log_event
+}
+
+fn flatten_fields(c: &mut Criterion) {
+    let mut group: BenchmarkGroup<WallTime> =
+        c.benchmark_group("vector_core::event::log_event::LogEvent::flatten_fields");
+    group.sampling_mode(SamplingMode::Auto);
+
+    let log_event = nested_log_event();
+
+    group.bench_function("convert_to_fields", |b| {
+        b.iter(|| {
+            for (path, value) in log_event.convert_to_fields() {
+                black_box((path, value));
+            }
+        })
+    });
+
+    group.bench_function("flattened_fields", |b| {
+        b.iter(|| {
+            log_event
+                .flattened_fields(FlattenOptions::default())
+                .for_each(|path, value| {
+                    black_box((path, value));
+                })
+        })
+    });
+
+    // Owning the paths, as encoders batching fields do, without allocating them for each event.
+    let mut interner = KeyInterner::default();
+    group.bench_function("flattened_fields (interned)", |b| {
+        b.iter(|| {
+            log_event
+                .flattened_fields(FlattenOptions::default())
+                .for_each(|path, value| {
+                    black_box((interner.intern(path), value));
+                })
+        })
+    });
+}
+
 criterion_group!(
     name = benches;
     config = Criterion::default()
@@ -55,5 +108,5 @@ criterion_group!(
         .nresamples(100_000)
         // total samples to collect within the set measurement time
         .sample_size(150);
-    targets = rename_key_flat
+    targets = rename_key_flat, flatten_fields
 );
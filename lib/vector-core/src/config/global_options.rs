@@ -5,8 +5,10 @@ use vector_common::TimeZone;
 use vector_config::configurable_component;
 
 use super::super::default_data_dir;
+use super::HostMetadataConfig;
 use super::Telemetry;
 use super::{proxy::ProxyConfig, AcknowledgementsConfig, LogSchema};
+use super::{TimestampFormat, TimestampPrecision, TimestampSettings};
 use crate::serde::bool_or_struct;
 
 #[derive(Debug, Snafu)]
@@ -60,6 +62,22 @@ pub struct GlobalOptions {
     #[serde(default, skip_serializing_if = "crate::serde::is_default")]
     pub telemetry: Telemetry,
 
+    #[configurable(derived)]
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub host_metadata: HostMetadataConfig,
+
+    /// Whether or not to attach the origin of events to their metadata when they are ingested.
+    ///
+    /// When enabled, the ID and the type of the source that ingested an event, along with the time
+    /// at which it did, are inserted into the event metadata under `vector.source_id`,
+    /// `vector.source_type`, and `vector.ingest_timestamp`. These can be read in VRL with
+    /// `%vector.source_id`, and so on.
+    ///
+    /// This is disabled by default, as it adds to the metadata of every event, which is carried
+    /// through buffers and to other Vector instances.
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub origin_metadata: Option<bool>,
+
     /// The name of the time zone to apply to timestamp conversions that do not contain an explicit time zone.
     ///
     /// The time zone name may be any name in the [TZ database][tzdb] or `local` to indicate system
@@ -71,6 +89,22 @@ pub struct GlobalOptions {
     #[serde(default, skip_serializing_if = "crate::serde::is_default")]
     pub timezone: Option<TimeZone>,
 
+    /// The format in which timestamps are represented when events are encoded by sinks, and when
+    /// timestamp fields are rendered in templates.
+    ///
+    /// This can be overridden for a sink with its `encoding.timestamp_format` option. By default,
+    /// timestamps are represented as RFC 3339 timestamps.
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub timestamp_format: Option<TimestampFormat>,
+
+    /// The precision with which timestamps represented as RFC 3339 timestamps are encoded by
+    /// sinks, and rendered in templates.
+    ///
+    /// This can be overridden for a sink with its `encoding.timestamp_precision` option. By
+    /// default, as many fractional digits as needed are used, in groups of three.
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub timestamp_precision: Option<TimestampPrecision>,
+
     #[configurable(derived)]
     #[serde(default, skip_serializing_if = "crate::serde::is_default")]
     pub proxy: ProxyConfig,
@@ -181,6 +215,14 @@ impl GlobalOptions {
             errors.push("conflicting values for 'timezone' found".to_owned());
         }
 
+        if conflicts(&self.timestamp_format, &with.timestamp_format) {
+            errors.push("conflicting values for 'timestamp_format' found".to_owned());
+        }
+
+        if conflicts(&self.timestamp_precision, &with.timestamp_precision) {
+            errors.push("conflicting values for 'timestamp_precision' found".to_owned());
+        }
+
         if conflicts(
             &self.acknowledgements.enabled,
             &with.acknowledgements.enabled,
@@ -188,6 +230,17 @@ impl GlobalOptions {
             errors.push("conflicting values for 'acknowledgements' found".to_owned());
         }
 
+        if conflicts(
+            &self.acknowledgements.timeout_secs,
+            &with.acknowledgements.timeout_secs,
+        ) {
+            errors.push("conflicting values for 'acknowledgements.timeout_secs' found".to_owned());
+        }
+
+        if conflicts(&self.origin_metadata, &with.origin_metadata) {
+            errors.push("conflicting values for 'origin_metadata' found".to_owned());
+        }
+
         if conflicts(&self.expire_metrics, &with.expire_metrics) {
             errors.push("conflicting values for 'expire_metrics' found".to_owned());
         }
@@ -217,13 +270,29 @@ impl GlobalOptions {
         let mut telemetry = self.telemetry.clone();
         telemetry.merge(&with.telemetry);
 
+        let default_host_metadata = HostMetadataConfig::default();
+        let host_metadata = if self.host_metadata == default_host_metadata {
+            with.host_metadata
+        } else if with.host_metadata == default_host_metadata
+            || with.host_metadata == self.host_metadata
+        {
+            self.host_metadata.clone()
+        } else {
+            errors.push("conflicting values for 'host_metadata' found".to_owned());
+            default_host_metadata
+        };
+
         if errors.is_empty() {
             Ok(Self {
                 data_dir,
                 log_schema,
                 telemetry,
+                host_metadata,
+                origin_metadata: self.origin_metadata.or(with.origin_metadata),
                 acknowledgements: self.acknowledgements.merge_default(&with.acknowledgements),
                 timezone: self.timezone.or(with.timezone),
+                timestamp_format: self.timestamp_format.or(with.timestamp_format),
+                timestamp_precision: self.timestamp_precision.or(with.timestamp_precision),
                 proxy: self.proxy.merge(&with.proxy),
                 expire_metrics: self.expire_metrics.or(with.expire_metrics),
                 expire_metrics_secs: self.expire_metrics_secs.or(with.expire_metrics_secs),
@@ -233,6 +302,19 @@ impl GlobalOptions {
         }
     }
 
+    /// Whether or not the origin of events is attached to their metadata at ingest.
+    pub fn origin_metadata(&self) -> bool {
+        self.origin_metadata.unwrap_or(false)
+    }
+
+    /// Get the configured representation of timestamps.
+    pub fn timestamp_settings(&self) -> TimestampSettings {
+        TimestampSettings {
+            format: self.timestamp_format,
+            precision: self.timestamp_precision,
+        }
+    }
+
     /// Get the configured time zone, using "local" time if none is set.
     pub fn timezone(&self) -> TimeZone {
         self.timezone.unwrap_or(TimeZone::Local)
@@ -355,6 +437,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn merges_origin_metadata() {
+        let merge = |a, b| merge("origin_metadata", a, b, |result| result.origin_metadata());
+
+        assert_eq!(merge(None, None), Ok(false));
+        assert_eq!(merge(Some(true), None), Ok(true));
+        assert_eq!(merge(None, Some(true)), Ok(true));
+        assert_eq!(merge(Some(false), Some(false)), Ok(false));
+        assert_eq!(
+            merge(Some(true), Some(false)),
+            Err(vec!["conflicting values for 'origin_metadata' found".into()])
+        );
+    }
+
+    #[test]
+    fn merges_timestamp_settings() {
+        let merge_format = |a, b| merge("timestamp_format", a, b, |result| result.timestamp_format);
+        let merge_precision = |a, b| {
+            merge("timestamp_precision", a, b, |result| {
+                result.timestamp_precision
+            })
+        };
+
+        assert_eq!(merge_format(None, None), Ok(None));
+        assert_eq!(
+            merge_format(Some("unix_ms"), None),
+            Ok(Some(TimestampFormat::UnixMs))
+        );
+        assert_eq!(
+            merge_precision(None, Some("micros")),
+            Ok(Some(TimestampPrecision::Micros))
+        );
+        assert_eq!(
+            merge_format(Some("unix"), Some("rfc3339")),
+            Err(vec![
+                "conflicting values for 'timestamp_format' found".into()
+            ])
+        );
+        assert_eq!(
+            merge_precision(Some("millis"), Some("nanos")),
+            Err(vec![
+                "conflicting values for 'timestamp_precision' found".into()
+            ])
+        );
+    }
+
+    #[test]
+    fn merges_host_metadata() {
+        let merge_enabled = |a, b| {
+            merge("host_metadata.enabled", a, b, |result| {
+                result.host_metadata.enabled
+            })
+        };
+
+        assert_eq!(merge_enabled(None, None), Ok(false));
+        assert_eq!(merge_enabled(Some(true), None), Ok(true));
+        assert_eq!(merge_enabled(None, Some(true)), Ok(true));
+        assert_eq!(merge_enabled(Some(true), Some(true)), Ok(true));
+
+        let merge_path = |a, b| {
+            merge("host_metadata.machine_id_path", a, b, |result| {
+                result.host_metadata.machine_id_path
+            })
+        };
+        assert_eq!(
+            merge_path(Some("/test1"), Some("/test1")),
+            Ok(PathBuf::from("/test1"))
+        );
+        assert_eq!(
+            merge_path(Some("/test1"), Some("/test2")),
+            Err(vec!["conflicting values for 'host_metadata' found".into()])
+        );
+    }
+
     fn merge<P: Debug, T>(
         name: &str,
         dd1: Option<P>,
@@ -0,0 +1,229 @@
+use std::path::{Path, PathBuf};
+
+use bytes::Bytes;
+use lookup::lookup_v2::OptionalTargetPath;
+use lookup::{owned_value_path, OwnedTargetPath, PathPrefix};
+use vector_config::configurable_component;
+use vrl::value::Value;
+
+use crate::event::{EventMutRef, LogEvent};
+
+/// Host metadata enrichment options.
+///
+/// When enabled, every log and trace event produced by a source is enriched with the host's
+/// machine ID and current boot ID. These are the same identifiers that journald attaches to its
+/// entries as `_MACHINE_ID` and `_BOOT_ID`, which allows correlating events with journal entries
+/// across a fleet.
+#[configurable_component]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct HostMetadataConfig {
+    /// Whether or not to enrich events with host metadata.
+    pub enabled: bool,
+
+    /// The path of the file containing the machine ID.
+    #[configurable(metadata(docs::examples = "/etc/machine-id"))]
+    pub machine_id_path: PathBuf,
+
+    /// The path of the file containing the current boot ID.
+    #[configurable(metadata(docs::examples = "/proc/sys/kernel/random/boot_id"))]
+    pub boot_id_path: PathBuf,
+
+    /// The path at which the machine ID is attached to each event.
+    ///
+    /// By default it is attached to the event metadata, where it can be read in VRL with
+    /// `%host.machine_id`. Set to `""` to not attach the machine ID.
+    #[configurable(metadata(docs::examples = ".host.machine_id"))]
+    pub machine_id_key: OptionalTargetPath,
+
+    /// The path at which the boot ID is attached to each event.
+    ///
+    /// By default it is attached to the event metadata, where it can be read in VRL with
+    /// `%host.boot_id`. Set to `""` to not attach the boot ID.
+    #[configurable(metadata(docs::examples = ".host.boot_id"))]
+    pub boot_id_key: OptionalTargetPath,
+}
+
+impl Default for HostMetadataConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            machine_id_path: PathBuf::from("/etc/machine-id"),
+            boot_id_path: PathBuf::from("/proc/sys/kernel/random/boot_id"),
+            machine_id_key: default_metadata_key("machine_id"),
+            boot_id_key: default_metadata_key("boot_id"),
+        }
+    }
+}
+
+fn default_metadata_key(name: &str) -> OptionalTargetPath {
+    OptionalTargetPath::from(PathPrefix::Metadata, Some(owned_value_path!("host", name)))
+}
+
+impl HostMetadataConfig {
+    /// Reads the configured identifiers from disk.
+    ///
+    /// Identifiers whose file cannot be read are skipped with a warning, so that a missing
+    /// `/etc/machine-id` inside a container does not prevent Vector from starting. Returns `None`
+    /// if enrichment is disabled or nothing could be loaded.
+    pub fn load(&self) -> Option<HostMetadata> {
+        if !self.enabled {
+            return None;
+        }
+
+        let mut fields = Vec::with_capacity(2);
+        for (key, path) in [
+            (&self.machine_id_key, &self.machine_id_path),
+            (&self.boot_id_key, &self.boot_id_path),
+        ] {
+            if let Some(key) = key.as_ref() {
+                if let Some(id) = read_id(path) {
+                    fields.push((key.clone(), Value::Bytes(id)));
+                }
+            }
+        }
+
+        (!fields.is_empty()).then_some(HostMetadata { fields })
+    }
+}
+
+fn read_id(path: &Path) -> Option<Bytes> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => {
+            let id = normalize_id(&contents);
+            if id.is_none() {
+                warn!(message = "Host metadata file is empty.", path = ?path);
+            }
+            id
+        }
+        Err(error) => {
+            warn!(message = "Could not read host metadata file.", path = ?path, %error);
+            None
+        }
+    }
+}
+
+/// Normalizes an identifier to the 32 hexadecimal character form used by journald, which strips
+/// the dashes from the UUID format of the kernel boot ID.
+fn normalize_id(contents: &str) -> Option<Bytes> {
+    let id = contents.trim().replace('-', "");
+    (!id.is_empty()).then(|| id.into())
+}
+
+/// Host identifiers loaded from a [`HostMetadataConfig`], ready to be attached to events.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HostMetadata {
+    fields: Vec<(OwnedTargetPath, Value)>,
+}
+
+impl HostMetadata {
+    /// Attaches the host identifiers to the given event.
+    ///
+    /// Metric events are left untouched, as they have no place to carry arbitrary values.
+    pub fn enrich(&self, event: EventMutRef<'_>) {
+        match event {
+            EventMutRef::Log(log) => self.enrich_log(log),
+            EventMutRef::Trace(trace) => {
+                for (key, value) in &self.fields {
+                    trace.insert(key, value.clone());
+                }
+            }
+            EventMutRef::Metric(_) => {}
+        }
+    }
+
+    fn enrich_log(&self, log: &mut LogEvent) {
+        for (key, value) in &self.fields {
+            log.insert(key, value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lookup::{event_path, metadata_path};
+
+    use super::*;
+    use crate::event::{Event, LogEvent};
+
+    const MACHINE_ID: &str = "0123456789abcdef0123456789abcdef";
+    const BOOT_ID: &str = "8c0b2a4e6a7f4b3d9d2e5f1a3c7b9e01";
+
+    fn loaded(config: &HostMetadataConfig) -> HostMetadata {
+        let mut fields = Vec::new();
+        if let Some(key) = config.machine_id_key.as_ref() {
+            fields.push((key.clone(), Value::from(MACHINE_ID)));
+        }
+        if let Some(key) = config.boot_id_key.as_ref() {
+            fields.push((key.clone(), Value::from(BOOT_ID)));
+        }
+        HostMetadata { fields }
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        assert_eq!(HostMetadataConfig::default().load(), None);
+    }
+
+    #[test]
+    fn normalizes_ids() {
+        assert_eq!(
+            normalize_id("8c0b2a4e-6a7f-4b3d-9d2e-5f1a3c7b9e01\n"),
+            Some(Bytes::from(BOOT_ID))
+        );
+        assert_eq!(
+            normalize_id("0123456789abcdef0123456789abcdef\n"),
+            Some(Bytes::from(MACHINE_ID))
+        );
+        assert_eq!(normalize_id(" \n"), None);
+    }
+
+    #[test]
+    fn skips_missing_files() {
+        let config = HostMetadataConfig {
+            enabled: true,
+            machine_id_path: "/nonexistent/machine-id".into(),
+            boot_id_path: "/nonexistent/boot_id".into(),
+            ..Default::default()
+        };
+        assert_eq!(config.load(), None);
+    }
+
+    #[test]
+    fn enriches_event_metadata() {
+        let metadata = loaded(&HostMetadataConfig::default());
+
+        let mut event = Event::Log(LogEvent::from("message"));
+        metadata.enrich((&mut event).into());
+
+        let log = event.as_log();
+        assert_eq!(
+            log.get(metadata_path!("host", "machine_id")),
+            Some(&Value::from(MACHINE_ID))
+        );
+        assert_eq!(
+            log.get(metadata_path!("host", "boot_id")),
+            Some(&Value::from(BOOT_ID))
+        );
+        assert_eq!(log.get(event_path!("host")), None);
+    }
+
+    #[test]
+    fn enriches_custom_keys() {
+        let metadata = loaded(&HostMetadataConfig {
+            machine_id_key: OptionalTargetPath::event("machine_id"),
+            boot_id_key: OptionalTargetPath::none(),
+            ..Default::default()
+        });
+
+        let mut event = Event::Log(LogEvent::from("message"));
+        metadata.enrich((&mut event).into());
+
+        let log = event.as_log();
+        assert_eq!(
+            log.get(event_path!("machine_id")),
+            Some(&Value::from(MACHINE_ID))
+        );
+        assert_eq!(log.get(metadata_path!("host", "boot_id")), None);
+    }
+}
@@ -1,23 +1,31 @@
 use std::sync::Arc;
-use std::{collections::HashMap, fmt, num::NonZeroUsize};
+use std::{collections::HashMap, fmt, num::NonZeroUsize, time::Duration};
 
 use bitmask_enum::bitmask;
 use bytes::Bytes;
 use chrono::{DateTime, Utc};
 
 mod global_options;
+mod host_metadata;
 mod log_schema;
 pub mod output_id;
 pub mod proxy;
 mod telemetry;
+mod timestamps;
 
 use crate::event::LogEvent;
 pub use global_options::GlobalOptions;
+pub use host_metadata::{HostMetadata, HostMetadataConfig};
 pub use log_schema::{init_log_schema, log_schema, LogSchema};
 use lookup::{lookup_v2::ValuePath, path, PathPrefix};
 pub use output_id::OutputId;
 use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
 pub use telemetry::{init_telemetry, telemetry, Tags, Telemetry};
+pub use timestamps::{
+    init_timestamp_settings, timestamp_settings, TimestampFormat, TimestampPrecision,
+    TimestampSettings,
+};
 pub use vector_common::config::ComponentKey;
 use vector_config::configurable_component;
 use vrl::value::Value;
@@ -341,11 +349,13 @@ impl From<SourceAcknowledgementsConfig> for AcknowledgementsConfig {
     fn from(config: SourceAcknowledgementsConfig) -> Self {
         Self {
             enabled: config.enabled,
+            timeout_secs: None,
         }
     }
 }
 
 /// End-to-end acknowledgements configuration.
+#[serde_as]
 #[configurable_component]
 #[configurable(title = "Controls how acknowledgements are handled for this sink.")]
 #[configurable(
@@ -366,25 +376,53 @@ pub struct AcknowledgementsConfig {
     ///
     /// [global_acks]: https://vector.dev/docs/reference/configuration/global-options/#acknowledgements
     enabled: Option<bool>,
+
+    /// The maximum amount of time a sink may take to acknowledge events.
+    ///
+    /// Events that the sink has not acknowledged when the timeout elapses are reported to their
+    /// sources as errored, so that a stuck sink cannot hold back source checkpoints forever. The
+    /// sink may still deliver those events afterwards. By default, there is no timeout.
+    ///
+    /// Setting the timeout at the sink level takes precedence over any global
+    /// [`acknowledgements`][global_acks] configuration.
+    ///
+    /// [global_acks]: https://vector.dev/docs/reference/configuration/global-options/#acknowledgements
+    #[serde_as(as = "Option<serde_with::DurationSecondsWithFrac<f64>>")]
+    #[configurable(metadata(docs::human_name = "Timeout"))]
+    timeout_secs: Option<Duration>,
 }
 
 impl AcknowledgementsConfig {
-    pub const DEFAULT: Self = Self { enabled: None };
+    pub const DEFAULT: Self = Self {
+        enabled: None,
+        timeout_secs: None,
+    };
 
     #[must_use]
     pub fn merge_default(&self, other: &Self) -> Self {
         let enabled = self.enabled.or(other.enabled);
-        Self { enabled }
+        let timeout_secs = self.timeout_secs.or(other.timeout_secs);
+        Self {
+            enabled,
+            timeout_secs,
+        }
     }
 
     pub fn enabled(&self) -> bool {
         self.enabled.unwrap_or(false)
     }
+
+    pub const fn timeout(&self) -> Option<Duration> {
+        self.timeout_secs
+    }
 }
 
 impl From<Option<bool>> for AcknowledgementsConfig {
     fn from(enabled: Option<bool>) -> Self {
-        Self { enabled }
+        Self {
+            enabled,
+            timeout_secs: None,
+        }
     }
 }
 
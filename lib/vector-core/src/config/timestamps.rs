@@ -0,0 +1,199 @@
+use std::sync::OnceLock;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use ordered_float::NotNan;
+use vector_config::configurable_component;
+use vrl::value::Value;
+
+static TIMESTAMP_SETTINGS: OnceLock<TimestampSettings> = OnceLock::new();
+
+/// Sets the global timestamp settings, used to represent timestamps unless configured otherwise
+/// for a sink.
+///
+/// # Panics
+///
+/// If deny is set, will panic if the timestamp settings have already been set.
+pub fn init_timestamp_settings(settings: TimestampSettings, deny_if_set: bool) {
+    assert!(
+        !(TIMESTAMP_SETTINGS.set(settings).is_err() && deny_if_set),
+        "Couldn't set timestamp settings"
+    );
+}
+
+/// Components should use the global `TimestampSettings` returned by this function when encoding
+/// or rendering timestamps.
+pub fn timestamp_settings() -> TimestampSettings {
+    TIMESTAMP_SETTINGS.get().copied().unwrap_or_default()
+}
+
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+/// The format in which a timestamp should be represented.
+pub enum TimestampFormat {
+    /// Represent the timestamp as a Unix timestamp.
+    Unix,
+
+    /// Represent the timestamp as a RFC 3339 timestamp.
+    Rfc3339,
+
+    /// Represent the timestamp as a Unix timestamp in milliseconds.
+    UnixMs,
+
+    /// Represent the timestamp as a Unix timestamp in microseconds
+    UnixUs,
+
+    /// Represent the timestamp as a Unix timestamp in nanoseconds.
+    UnixNs,
+
+    /// Represent the timestamp as a Unix timestamp in floating point.
+    UnixFloat,
+}
+
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+/// The precision of the fractional seconds of a timestamp represented as a RFC 3339 timestamp.
+///
+/// The timestamp is always represented in UTC.
+pub enum TimestampPrecision {
+    /// Represent the timestamp with whole seconds.
+    Seconds,
+
+    /// Represent the timestamp with milliseconds.
+    Millis,
+
+    /// Represent the timestamp with microseconds.
+    Micros,
+
+    /// Represent the timestamp with nanoseconds.
+    Nanos,
+}
+
+impl From<TimestampPrecision> for SecondsFormat {
+    fn from(precision: TimestampPrecision) -> Self {
+        match precision {
+            TimestampPrecision::Seconds => SecondsFormat::Secs,
+            TimestampPrecision::Millis => SecondsFormat::Millis,
+            TimestampPrecision::Micros => SecondsFormat::Micros,
+            TimestampPrecision::Nanos => SecondsFormat::Nanos,
+        }
+    }
+}
+
+/// How timestamps are represented when encoding events or rendering templates.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TimestampSettings {
+    /// The format of timestamps. Defaults to RFC 3339.
+    pub format: Option<TimestampFormat>,
+
+    /// The precision of RFC 3339 timestamps. By default, as many fractional digits as needed are
+    /// used, in groups of three.
+    pub precision: Option<TimestampPrecision>,
+}
+
+impl TimestampSettings {
+    /// Returns these settings, with the ones that are not set taken from `defaults`.
+    #[must_use]
+    pub fn or(self, defaults: Self) -> Self {
+        Self {
+            format: self.format.or(defaults.format),
+            precision: self.precision.or(defaults.precision),
+        }
+    }
+
+    /// Returns the representation of a timestamp, or `None` if it is to be left as a timestamp,
+    /// which is represented as a RFC 3339 timestamp by default.
+    ///
+    /// The precision only applies to RFC 3339 timestamps, the other formats having their own.
+    pub fn format(&self, timestamp: &DateTime<Utc>) -> Option<Value> {
+        match self.format.unwrap_or(TimestampFormat::Rfc3339) {
+            TimestampFormat::Unix => Some(timestamp.timestamp().into()),
+            TimestampFormat::UnixMs => Some(timestamp.timestamp_millis().into()),
+            TimestampFormat::UnixUs => Some(timestamp.timestamp_micros().into()),
+            TimestampFormat::UnixNs => Some(
+                timestamp
+                    .timestamp_nanos_opt()
+                    .expect("Timestamp out of range")
+                    .into(),
+            ),
+            TimestampFormat::UnixFloat => Some(
+                NotNan::new(timestamp.timestamp_micros() as f64 / 1e6)
+                    .unwrap()
+                    .into(),
+            ),
+            TimestampFormat::Rfc3339 => self
+                .precision
+                .map(|precision| timestamp.to_rfc3339_opts(precision.into(), true).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Timelike};
+
+    use super::*;
+
+    fn timestamp() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5)
+            .single()
+            .unwrap()
+            .with_nanosecond(123_456_789)
+            .unwrap()
+    }
+
+    #[test]
+    fn formats_rfc3339_with_precision() {
+        let format = |precision| {
+            TimestampSettings {
+                format: Some(TimestampFormat::Rfc3339),
+                precision,
+            }
+            .format(&timestamp())
+        };
+
+        assert_eq!(format(None), None);
+        assert_eq!(
+            format(Some(TimestampPrecision::Seconds)),
+            Some("2024-01-02T03:04:05Z".into())
+        );
+        assert_eq!(
+            format(Some(TimestampPrecision::Micros)),
+            Some("2024-01-02T03:04:05.123456Z".into())
+        );
+    }
+
+    #[test]
+    fn formats_unix_ignoring_precision() {
+        let settings = TimestampSettings {
+            format: Some(TimestampFormat::UnixMs),
+            precision: Some(TimestampPrecision::Seconds),
+        };
+
+        assert_eq!(
+            settings.format(&timestamp()),
+            Some(Value::from(1_704_164_645_123_i64))
+        );
+    }
+
+    #[test]
+    fn overrides_defaults() {
+        let defaults = TimestampSettings {
+            format: Some(TimestampFormat::Unix),
+            precision: Some(TimestampPrecision::Millis),
+        };
+        let settings = TimestampSettings {
+            format: Some(TimestampFormat::Rfc3339),
+            precision: None,
+        };
+
+        assert_eq!(
+            settings.or(defaults),
+            TimestampSettings {
+                format: Some(TimestampFormat::Rfc3339),
+                precision: Some(TimestampPrecision::Millis),
+            }
+        );
+    }
+}
@@ -478,6 +478,16 @@ impl LogEvent {
         }
     }
 
+    /// Returns a depth-first traversal of all fields, borrowing their paths and values instead of
+    /// cloning them, with paths rendered according to `options`. Fields are visited in the same
+    /// order as [`LogEvent::convert_to_fields_unquoted`], and field names are not quoted.
+    pub fn flattened_fields(
+        &self,
+        options: util::log::FlattenOptions,
+    ) -> util::log::FlattenedFields<'_> {
+        util::log::FlattenedFields::new(self.value(), options)
+    }
+
     pub fn is_empty_object(&self) -> bool {
         if let Some(map) = self.as_map() {
             map.is_empty()
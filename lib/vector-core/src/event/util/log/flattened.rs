@@ -0,0 +1,261 @@
+use std::{collections::btree_map, fmt::Write as _, iter, slice};
+
+use crate::event::{KeyString, Value};
+
+/// How array elements are rendered in flattened paths.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ArrayIndices {
+    /// Indices are rendered in brackets, as in `a.b[0]`.
+    #[default]
+    Brackets,
+
+    /// Indices are rendered as path segments, joined with the separator, as in `a.b.0`.
+    Segments,
+
+    /// Arrays are not traversed, and are yielded as a single value.
+    Skip,
+}
+
+/// How flattened paths are rendered.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FlattenOptions {
+    /// The separator joining the keys of nested fields.
+    pub separator: char,
+
+    /// How array elements are rendered.
+    pub array_indices: ArrayIndices,
+
+    /// The maximum number of levels of nesting that are traversed. Objects and arrays nested
+    /// deeper are yielded as a single value. All levels are traversed if not set.
+    pub max_depth: Option<usize>,
+}
+
+impl Default for FlattenOptions {
+    fn default() -> Self {
+        Self {
+            separator: '.',
+            array_indices: ArrayIndices::Brackets,
+            max_depth: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Level<'a> {
+    Root(Option<&'a Value>),
+    Map(btree_map::Iter<'a, KeyString, Value>),
+    Array(iter::Enumerate<slice::Iter<'a, Value>>),
+}
+
+/// Performs a depth-first traversal of a value, yielding the path and value of each of its fields.
+///
+/// Fields are visited in the alphabetical order of their keys, and array elements in order. A key
+/// mapping to an empty collection is yielded along with the empty collection. A value other than
+/// an object is yielded as a single `message` field.
+///
+/// Unlike [`super::all_fields`], this borrows both the paths and the values instead of cloning
+/// them: paths are rendered into a buffer reused for every field, so this is a lending iterator
+/// rather than an [`Iterator`]. Field names are not quoted.
+#[derive(Debug)]
+pub struct FlattenedFields<'a> {
+    options: FlattenOptions,
+    /// Stack of iterators used for the depth-first traversal.
+    stack: Vec<Level<'a>>,
+    /// Length of the path of the parent of the fields of each level of the stack.
+    lengths: Vec<usize>,
+    /// Path of the last field yielded.
+    path: String,
+}
+
+impl<'a> FlattenedFields<'a> {
+    pub fn new(value: &'a Value, options: FlattenOptions) -> Self {
+        let level = match value {
+            Value::Object(map) => Level::Map(map.iter()),
+            value => Level::Root(Some(value)),
+        };
+        Self {
+            options,
+            stack: vec![level],
+            lengths: vec![0],
+            path: String::new(),
+        }
+    }
+
+    /// Returns the path and value of the next field, if any.
+    pub fn next_field(&mut self) -> Option<(&str, &'a Value)> {
+        loop {
+            let (key, index, value) = match self.stack.last_mut()? {
+                Level::Root(value) => {
+                    let value = value.take()?;
+                    self.path.push_str("message");
+                    return Some((self.path.as_str(), value));
+                }
+                Level::Map(map_iter) => match map_iter.next() {
+                    Some((key, value)) => (Some(key), 0, value),
+                    None => {
+                        self.pop();
+                        continue;
+                    }
+                },
+                Level::Array(array_iter) => match array_iter.next() {
+                    Some((index, value)) => (None, index, value),
+                    None => {
+                        self.pop();
+                        continue;
+                    }
+                },
+            };
+
+            let parent = *self.lengths.last().expect("a length for each level");
+            self.path.truncate(parent);
+            match (key, self.options.array_indices) {
+                (Some(key), _) => {
+                    if parent > 0 {
+                        self.path.push(self.options.separator);
+                    }
+                    self.path.push_str(key);
+                }
+                (None, ArrayIndices::Segments) => {
+                    if parent > 0 {
+                        self.path.push(self.options.separator);
+                    }
+                    write!(self.path, "{index}").expect("write to String never fails");
+                }
+                (None, _) => {
+                    write!(self.path, "[{index}]").expect("write to String never fails");
+                }
+            }
+
+            if self
+                .options
+                .max_depth
+                .is_some_and(|max_depth| self.stack.len() >= max_depth)
+            {
+                return Some((self.path.as_str(), value));
+            }
+            match value {
+                Value::Object(map) if !map.is_empty() => {
+                    self.stack.push(Level::Map(map.iter()));
+                    self.lengths.push(self.path.len());
+                }
+                Value::Array(array)
+                    if !array.is_empty() && self.options.array_indices != ArrayIndices::Skip =>
+                {
+                    self.stack.push(Level::Array(array.iter().enumerate()));
+                    self.lengths.push(self.path.len());
+                }
+                value => return Some((self.path.as_str(), value)),
+            }
+        }
+    }
+
+    /// Calls `f` with the path and value of each remaining field.
+    pub fn for_each(mut self, mut f: impl FnMut(&str, &'a Value)) {
+        while let Some((path, value)) = self.next_field() {
+            f(path, value);
+        }
+    }
+
+    fn pop(&mut self) {
+        self.stack.pop();
+        self.lengths.pop();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{super::test::fields_from_json, *};
+
+    fn flattened(value: &Value, options: FlattenOptions) -> Vec<(String, Value)> {
+        let mut fields = Vec::new();
+        FlattenedFields::new(value, options)
+            .for_each(|path, value| fields.push((path.to_owned(), value.clone())));
+        fields
+    }
+
+    fn nested() -> Value {
+        Value::Object(fields_from_json(json!({
+            "b": {
+                "y": [1, {"z": true}, []],
+                "x": "foo",
+            },
+            "a": 1,
+            "c": {},
+            "d.e": "bar",
+        })))
+    }
+
+    #[test]
+    fn visits_fields_in_order() {
+        assert_eq!(
+            flattened(&nested(), FlattenOptions::default()),
+            vec![
+                ("a".to_owned(), Value::from(1)),
+                ("b.x".to_owned(), Value::from("foo")),
+                ("b.y[0]".to_owned(), Value::from(1)),
+                ("b.y[1].z".to_owned(), Value::from(true)),
+                ("b.y[2]".to_owned(), Value::Array(Vec::new())),
+                ("c".to_owned(), Value::Object(Default::default())),
+                ("d.e".to_owned(), Value::from("bar")),
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_separators_and_array_segments() {
+        let options = FlattenOptions {
+            separator: '_',
+            array_indices: ArrayIndices::Segments,
+            ..Default::default()
+        };
+        let paths = flattened(&nested(), options)
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["a", "b_x", "b_y_0", "b_y_1_z", "b_y_2", "c", "d.e"]);
+    }
+
+    #[test]
+    fn skips_array_elements() {
+        let options = FlattenOptions {
+            array_indices: ArrayIndices::Skip,
+            ..Default::default()
+        };
+        let fields = flattened(&nested(), options);
+        assert_eq!(fields[2].0, "b.y");
+        assert!(fields[2].1.is_array());
+        assert_eq!(fields.len(), 5);
+    }
+
+    #[test]
+    fn stops_at_max_depth() {
+        let options = FlattenOptions {
+            max_depth: Some(2),
+            ..Default::default()
+        };
+        let paths = flattened(&nested(), options)
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["a", "b.x", "b.y", "c", "d.e"]);
+    }
+
+    #[test]
+    fn matches_all_fields_unquoted() {
+        let value = nested();
+        let expected = super::super::all_fields_unquoted(value.as_object().unwrap())
+            .map(|(path, value)| (path.to_string(), value.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(flattened(&value, FlattenOptions::default()), expected);
+    }
+
+    #[test]
+    fn yields_non_object_root_as_message() {
+        assert_eq!(
+            flattened(&Value::from("foo"), FlattenOptions::default()),
+            vec![("message".to_owned(), Value::from("foo"))]
+        );
+    }
+}
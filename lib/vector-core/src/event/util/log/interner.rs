@@ -0,0 +1,143 @@
+use std::{collections::HashMap, mem, sync::Arc};
+
+/// The default number of keys an interner holds.
+pub const DEFAULT_KEY_INTERNER_CAPACITY: usize = 4096;
+
+/// A bounded cache of the strings derived from keys, such as flattened field paths or the field
+/// names an encoder renders them to.
+///
+/// Events from the same source overwhelmingly share the same keys, so looking them up saves
+/// allocating the same string again for each event, while the returned [`Arc<str>`] is cheap to
+/// clone. Keys are kept in two generations of at most half the capacity each: once the current
+/// generation is full, the previous one is dropped and replaced by it. Keys looked up since then
+/// are moved to the new generation, so frequently used keys survive while the memory used stays
+/// bounded whatever the cardinality of the keys.
+#[derive(Clone, Debug)]
+pub struct KeyInterner {
+    generation_capacity: usize,
+    current: HashMap<Arc<str>, Arc<str>>,
+    previous: HashMap<Arc<str>, Arc<str>>,
+    hits: u64,
+    misses: u64,
+}
+
+impl KeyInterner {
+    /// Creates an interner holding at most `capacity` keys.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            generation_capacity: (capacity / 2).max(1),
+            current: HashMap::new(),
+            previous: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Returns the shared string for the given key.
+    pub fn intern(&mut self, key: &str) -> Arc<str> {
+        self.get_or_insert_with(key, Arc::clone)
+    }
+
+    /// Returns the string derived from the given key, deriving it with `derive` if it isn't held.
+    pub fn get_or_insert_with(
+        &mut self,
+        key: &str,
+        derive: impl FnOnce(&Arc<str>) -> Arc<str>,
+    ) -> Arc<str> {
+        if let Some(value) = self.current.get(key) {
+            self.hits += 1;
+            return Arc::clone(value);
+        }
+
+        let (key, value) = match self.previous.remove_entry(key) {
+            Some(entry) => {
+                self.hits += 1;
+                entry
+            }
+            None => {
+                self.misses += 1;
+                let key = Arc::<str>::from(key);
+                let value = derive(&key);
+                (key, value)
+            }
+        };
+        if self.current.len() >= self.generation_capacity {
+            self.previous = mem::take(&mut self.current);
+        }
+        self.current.insert(key, Arc::clone(&value));
+        value
+    }
+
+    /// Returns the number of keys held.
+    pub fn len(&self) -> usize {
+        self.current.len() + self.previous.len()
+    }
+
+    /// Returns whether no keys are held.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of lookups that found their key held, and that of those that didn't,
+    /// since last called.
+    pub fn take_stats(&mut self) -> (u64, u64) {
+        (mem::take(&mut self.hits), mem::take(&mut self.misses))
+    }
+}
+
+impl Default for KeyInterner {
+    fn default() -> Self {
+        Self::new(DEFAULT_KEY_INTERNER_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shares_interned_keys() {
+        let mut interner = KeyInterner::default();
+        let first = interner.intern("kubernetes.pod_labels.app");
+        let second = interner.intern("kubernetes.pod_labels.app");
+
+        assert_eq!(&*first, "kubernetes.pod_labels.app");
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(interner.take_stats(), (1, 1));
+        assert_eq!(interner.take_stats(), (0, 0));
+    }
+
+    #[test]
+    fn derives_values_once() {
+        let mut interner = KeyInterner::default();
+        let mut derived = 0;
+        for _ in 0..3 {
+            let value = interner.get_or_insert_with("a.b", |key| {
+                derived += 1;
+                key.to_uppercase().into()
+            });
+            assert_eq!(&*value, "A.B");
+        }
+        assert_eq!(derived, 1);
+    }
+
+    #[test]
+    fn evicts_keys_beyond_capacity() {
+        let mut interner = KeyInterner::new(4);
+        for i in 0..100 {
+            interner.intern(&format!("key{i}"));
+            assert!(interner.len() <= 4);
+        }
+        assert_eq!(interner.take_stats(), (0, 100));
+    }
+
+    #[test]
+    fn keeps_recently_used_keys() {
+        let mut interner = KeyInterner::new(4);
+        let hot = interner.intern("hot");
+        for i in 0..100 {
+            interner.intern(&format!("key{i}"));
+            assert!(Arc::ptr_eq(&hot, &interner.intern("hot")));
+        }
+    }
+}
@@ -1,10 +1,14 @@
 mod all_fields;
+mod flattened;
+mod interner;
 mod keys;
 
 pub use all_fields::{
     all_fields, all_fields_non_object_root, all_fields_skip_array_elements, all_fields_unquoted,
     all_metadata_fields,
 };
+pub use flattened::{ArrayIndices, FlattenOptions, FlattenedFields};
+pub use interner::{KeyInterner, DEFAULT_KEY_INTERNER_CAPACITY};
 pub use keys::keys;
 
 #[cfg(test)]
@@ -3,4 +3,4 @@ pub mod meaning;
 mod requirement;
 
 pub use definition::Definition;
-pub use requirement::Requirement;
+pub use requirement::{Requirement, ValidationError, ValidationErrors};
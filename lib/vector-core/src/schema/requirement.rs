@@ -23,12 +23,25 @@ struct SemanticMeaning {
     /// The type required by this semantic meaning.
     kind: Kind,
 
-    /// Whether the meaning is optional.
+    /// Whether the meaning must be defined.
     ///
-    /// If a meaning is optional, the sink must not error when the meaning is not defined in the
-    /// provided `Definition`, but it *must* error if it is defined, but its type does not meet the
-    /// requirement.
-    optional: bool,
+    /// If a meaning is not required, the sink must not error when the meaning is not defined in
+    /// the provided `Definition`, but it *must* error if it is defined, but its type does not meet
+    /// the requirement.
+    presence: Presence,
+}
+
+/// Whether a semantic meaning must be defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Presence {
+    /// The meaning must be defined.
+    Required,
+
+    /// The meaning should be defined, and its absence is reported as a warning.
+    Recommended,
+
+    /// The meaning may be defined.
+    Optional,
 }
 
 impl Requirement {
@@ -53,7 +66,18 @@ impl Requirement {
     /// Add a restriction to the schema.
     #[must_use]
     pub fn required_meaning(mut self, meaning: impl Into<String>, kind: Kind) -> Self {
-        self.insert_meaning(meaning, kind, false);
+        self.insert_meaning(meaning, kind, Presence::Required);
+        self
+    }
+
+    /// Add a recommended restriction to the schema.
+    ///
+    /// This behaves like `optional_meaning` when validating, but the absence of the meaning is
+    /// reported by `check_recommendations`, for sinks that work without the meaning but produce
+    /// poor output.
+    #[must_use]
+    pub fn recommended_meaning(mut self, meaning: impl Into<String>, kind: Kind) -> Self {
+        self.insert_meaning(meaning, kind, Presence::Recommended);
         self
     }
 
@@ -64,12 +88,12 @@ impl Requirement {
     /// matching the configured expectation.
     #[must_use]
     pub fn optional_meaning(mut self, meaning: impl Into<String>, kind: Kind) -> Self {
-        self.insert_meaning(meaning, kind, true);
+        self.insert_meaning(meaning, kind, Presence::Optional);
         self
     }
 
-    fn insert_meaning(&mut self, identifier: impl Into<String>, kind: Kind, optional: bool) {
-        let meaning = SemanticMeaning { kind, optional };
+    fn insert_meaning(&mut self, identifier: impl Into<String>, kind: Kind, presence: Presence) {
+        let meaning = SemanticMeaning { kind, presence };
         self.meaning.insert(identifier.into(), meaning);
     }
 
@@ -127,7 +151,7 @@ impl Requirement {
                         });
                     }
                 }
-                None if !req_meaning.optional => {
+                None if req_meaning.presence == Presence::Required => {
                     errors.push(ValidationError::MeaningMissing {
                         identifier: identifier.clone(),
                     });
@@ -142,6 +166,39 @@ impl Requirement {
             Err(ValidationErrors(errors))
         }
     }
+
+    /// Check that the provided [`Definition`] defines the recommended meanings of the current
+    /// requirement.
+    ///
+    /// Like `validate`, this only checks definitions if there is at least one connected component
+    /// that uses the Vector namespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns the missing recommended meanings.
+    pub fn check_recommendations(&self, definition: &Definition) -> Result<(), ValidationErrors> {
+        if !definition.log_namespaces().contains(&LogNamespace::Vector) {
+            return Ok(());
+        }
+
+        let errors = self
+            .meaning
+            .iter()
+            .filter(|(identifier, meaning)| {
+                meaning.presence == Presence::Recommended
+                    && !definition.meanings().any(|(id, _)| id == *identifier)
+            })
+            .map(|(identifier, _)| ValidationError::MeaningMissing {
+                identifier: identifier.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors(errors))
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -348,6 +405,27 @@ mod tests {
                     }],
                 },
             ),
+            (
+                "missing recommended meaning",
+                TestCase {
+                    requirement: Requirement::empty().recommended_meaning("foo", Kind::any()),
+                    definition: Definition::default_for_namespace(&[LogNamespace::Vector].into()),
+                    errors: vec![],
+                },
+            ),
+            (
+                "invalid recommended meaning kind",
+                TestCase {
+                    requirement: Requirement::empty().recommended_meaning("foo", Kind::boolean()),
+                    definition: Definition::default_for_namespace(&[LogNamespace::Vector].into())
+                        .with_event_field(&owned_value_path!("foo"), Kind::integer(), Some("foo")),
+                    errors: vec![ValidationError::MeaningKind {
+                        identifier: "foo".into(),
+                        want: Kind::boolean(),
+                        got: Kind::integer(),
+                    }],
+                },
+            ),
             (
                 "invalid required meaning kind",
                 TestCase {
@@ -408,4 +486,29 @@ mod tests {
             assert_eq!(got, want, "{title}");
         }
     }
+
+    #[test]
+    fn test_check_recommendations() {
+        let requirement = Requirement::empty()
+            .recommended_meaning("foo", Kind::bytes())
+            .recommended_meaning("bar", Kind::bytes())
+            .required_meaning("baz", Kind::bytes());
+
+        let definition = Definition::default_for_namespace(&[LogNamespace::Vector].into())
+            .with_event_field(&owned_value_path!("foo"), Kind::bytes(), Some("foo"));
+        assert_eq!(
+            Err(ValidationErrors(vec![ValidationError::MeaningMissing {
+                identifier: "bar".into(),
+            }])),
+            requirement.check_recommendations(&definition)
+        );
+
+        let definition =
+            definition.with_event_field(&owned_value_path!("bar"), Kind::bytes(), Some("bar"));
+        assert_eq!(Ok(()), requirement.check_recommendations(&definition));
+
+        // Recommendations aren't checked with just the Legacy namespace.
+        let definition = Definition::default_for_namespace(&[LogNamespace::Legacy].into());
+        assert_eq!(Ok(()), requirement.check_recommendations(&definition));
+    }
 }
@@ -1,3 +1,5 @@
+use std::{io, time::Duration};
+
 use socket2::SockRef;
 use tokio::net::TcpStream;
 use vector_config::configurable_component;
@@ -11,15 +13,118 @@ pub struct TcpKeepaliveConfig {
     /// The time to wait before starting to send TCP keepalive probes on an idle connection.
     #[configurable(metadata(docs::type_unit = "seconds"))]
     pub time_secs: Option<u64>,
+
+    /// The time to wait between TCP keepalive probes.
+    ///
+    /// The default of the operating system is used if not set. This is not supported on all
+    /// platforms.
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    pub interval_secs: Option<u64>,
+
+    /// The number of unanswered TCP keepalive probes after which the connection is dropped.
+    ///
+    /// The default of the operating system is used if not set. This is not supported on all
+    /// platforms, including Windows.
+    pub retries: Option<u32>,
+}
+
+impl TcpKeepaliveConfig {
+    /// The keepalive parameters to set on sockets, if any is configured.
+    fn params(&self) -> io::Result<Option<socket2::TcpKeepalive>> {
+        if self.time_secs.is_none() && self.interval_secs.is_none() && self.retries.is_none() {
+            return Ok(None);
+        }
+
+        let mut params = socket2::TcpKeepalive::new();
+        if let Some(time_secs) = self.time_secs {
+            params = params.with_time(Duration::from_secs(time_secs));
+        }
+        if let Some(interval_secs) = self.interval_secs {
+            params = with_interval(params, Duration::from_secs(interval_secs))?;
+        }
+        if let Some(retries) = self.retries {
+            params = with_retries(params, retries)?;
+        }
+        Ok(Some(params))
+    }
+}
+
+#[cfg(not(any(
+    target_os = "haiku",
+    target_os = "openbsd",
+    target_os = "redox",
+    target_os = "solaris",
+    target_os = "nto",
+    target_os = "espidf",
+    target_os = "vita",
+)))]
+fn with_interval(
+    params: socket2::TcpKeepalive,
+    interval: Duration,
+) -> io::Result<socket2::TcpKeepalive> {
+    Ok(params.with_interval(interval))
+}
+
+#[cfg(any(
+    target_os = "haiku",
+    target_os = "openbsd",
+    target_os = "redox",
+    target_os = "solaris",
+    target_os = "nto",
+    target_os = "espidf",
+    target_os = "vita",
+))]
+fn with_interval(
+    _params: socket2::TcpKeepalive,
+    _interval: Duration,
+) -> io::Result<socket2::TcpKeepalive> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "The TCP keepalive interval is not supported on this platform.",
+    ))
+}
+
+#[cfg(not(any(
+    windows,
+    target_os = "haiku",
+    target_os = "openbsd",
+    target_os = "redox",
+    target_os = "solaris",
+    target_os = "nto",
+    target_os = "espidf",
+    target_os = "vita",
+)))]
+fn with_retries(params: socket2::TcpKeepalive, retries: u32) -> io::Result<socket2::TcpKeepalive> {
+    Ok(params.with_retries(retries))
+}
+
+#[cfg(any(
+    windows,
+    target_os = "haiku",
+    target_os = "openbsd",
+    target_os = "redox",
+    target_os = "solaris",
+    target_os = "nto",
+    target_os = "espidf",
+    target_os = "vita",
+))]
+fn with_retries(
+    _params: socket2::TcpKeepalive,
+    _retries: u32,
+) -> io::Result<socket2::TcpKeepalive> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "The number of TCP keepalive retries is not supported on this platform.",
+    ))
 }
 
 // This function will be obsolete after tokio/mio internally use `socket2` and expose the methods to
 // apply options to a socket.
-pub(crate) fn set_keepalive(
-    socket: &TcpStream,
-    params: &socket2::TcpKeepalive,
-) -> std::io::Result<()> {
-    SockRef::from(socket).set_tcp_keepalive(params)
+pub(crate) fn set_keepalive(socket: &TcpStream, keepalive: &TcpKeepaliveConfig) -> io::Result<()> {
+    match keepalive.params()? {
+        Some(params) => SockRef::from(socket).set_tcp_keepalive(&params),
+        None => Ok(()),
+    }
 }
 
 // This function will be obsolete after tokio/mio internally use `socket2` and expose the methods to
@@ -33,3 +138,47 @@ pub(crate) fn set_receive_buffer_size(socket: &TcpStream, size: usize) -> std::i
 pub(crate) fn set_send_buffer_size(socket: &TcpStream, size: usize) -> std::io::Result<()> {
     SockRef::from(socket).set_send_buffer_size(size)
 }
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_keepalive_params() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let stream = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+
+        let keepalive: TcpKeepaliveConfig = toml::from_str(
+            r"
+            time_secs = 30
+            interval_secs = 5
+            retries = 3
+            ",
+        )
+        .unwrap();
+        set_keepalive(&stream, &keepalive).unwrap();
+
+        let socket = SockRef::from(&stream);
+        assert!(socket.keepalive().unwrap());
+        assert_eq!(socket.keepalive_time().unwrap(), Duration::from_secs(30));
+        assert_eq!(socket.keepalive_interval().unwrap(), Duration::from_secs(5));
+        assert_eq!(socket.keepalive_retries().unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn leaves_keepalive_unset_by_default() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let stream = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+
+        let keepalive: TcpKeepaliveConfig = toml::from_str("").unwrap();
+        set_keepalive(&stream, &keepalive).unwrap();
+
+        assert!(!SockRef::from(&stream).keepalive().unwrap());
+    }
+}
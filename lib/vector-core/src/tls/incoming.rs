@@ -286,14 +286,7 @@ impl MaybeTlsIncomingStream<TcpStream> {
             )
         })?;
 
-        if let Some(time_secs) = keepalive.time_secs {
-            let config =
-                socket2::TcpKeepalive::new().with_time(std::time::Duration::from_secs(time_secs));
-
-            tcp::set_keepalive(stream, &config)?;
-        }
-
-        Ok(())
+        tcp::set_keepalive(stream, &keepalive)
     }
 
     pub fn set_receive_buffer_bytes(&mut self, bytes: usize) -> std::io::Result<()> {
@@ -1,6 +1,6 @@
 #![allow(clippy::missing_errors_doc)]
 
-use std::{fmt::Debug, net::SocketAddr, path::PathBuf, time::Duration};
+use std::{fmt::Debug, net::SocketAddr, path::PathBuf};
 
 use openssl::{
     error::ErrorStack,
@@ -148,13 +148,7 @@ impl MaybeTlsStream<TcpStream> {
             Self::Tls(tls) => tls.get_ref(),
         };
 
-        if let Some(time_secs) = keepalive.time_secs {
-            let config = socket2::TcpKeepalive::new().with_time(Duration::from_secs(time_secs));
-
-            tcp::set_keepalive(stream, &config)?;
-        }
-
-        Ok(())
+        tcp::set_keepalive(stream, &keepalive)
     }
 
     pub fn set_send_buffer_bytes(&mut self, bytes: usize) -> std::io::Result<()> {
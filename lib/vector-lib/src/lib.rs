@@ -32,9 +32,9 @@ pub mod config {
     pub use vector_common::config::ComponentKey;
     pub use vector_core::config::{
         clone_input_definitions, init_log_schema, init_telemetry, log_schema, proxy, telemetry,
-        AcknowledgementsConfig, DataType, GlobalOptions, Input, LegacyKey, LogNamespace, LogSchema,
-        OutputId, SourceAcknowledgementsConfig, SourceOutput, Tags, Telemetry, TransformOutput,
-        MEMORY_BUFFER_DEFAULT_MAX_EVENTS,
+        AcknowledgementsConfig, DataType, GlobalOptions, HostMetadata, HostMetadataConfig, Input,
+        LegacyKey, LogNamespace, LogSchema, OutputId, SourceAcknowledgementsConfig, SourceOutput,
+        Tags, Telemetry, TransformOutput, MEMORY_BUFFER_DEFAULT_MAX_EVENTS,
     };
 }
 
@@ -19,6 +19,7 @@ use url::Url;
 use vector_api_client::{
     connect_subscription_client,
     gql::{
+        encoded_events_by_component_id_subscription::EncodedEventsByComponentIdSubscriptionEncodedEventsByComponentId as GraphQLTapEncodedEvent,
         output_events_by_component_id_patterns_subscription::OutputEventsByComponentIdPatternsSubscriptionOutputEventsByComponentIdPatterns as GraphQLTapOutputEvent,
         TapEncodingFormat, TapSubscriptionExt,
     },
@@ -93,6 +94,19 @@ impl EventFormatter {
                     event
                 )
                 .into(),
+                // Encoded payloads are free-form, and may span multiple lines, so they are printed
+                // on their own.
+                TapEncodingFormat::Encoded => format!(
+                    "{}={} {}={} {}={}\n{}",
+                    self.component_id_label,
+                    component_id.green(),
+                    self.component_kind_label,
+                    component_kind.green(),
+                    self.component_type_label,
+                    component_type.green(),
+                    event
+                )
+                .into(),
             }
         } else {
             event.into()
@@ -111,6 +125,9 @@ pub enum OutputChannel {
 pub enum TapExecutorError {
     ConnectionFailure(tokio_tungstenite::tungstenite::Error),
     GraphQLError,
+    /// Encoded payloads were requested without a sink to encode them with, or to be sent to an
+    /// async channel, which only supports events.
+    UnsupportedEncoding,
 }
 
 #[derive(Debug)]
@@ -120,6 +137,7 @@ pub struct TapRunner<'a> {
     output_patterns: Vec<String>,
     output_channel: &'a OutputChannel,
     format: TapEncodingFormat,
+    encoding_sink: Option<(String, i64)>,
 }
 
 impl<'a> TapRunner<'a> {
@@ -136,9 +154,17 @@ impl<'a> TapRunner<'a> {
             output_patterns,
             output_channel,
             format,
+            encoding_sink: None,
         }
     }
 
+    /// Sets the sink whose encoding is previewed in the `encoded` format, keeping at most
+    /// `max_payload_bytes` of each payload.
+    pub fn with_encoding_sink(mut self, component_id: String, max_payload_bytes: i64) -> Self {
+        self.encoding_sink = Some((component_id, max_payload_bytes));
+        self
+    }
+
     pub async fn run_tap(
         &self,
         interval: i64,
@@ -146,6 +172,12 @@ impl<'a> TapRunner<'a> {
         duration_ms: Option<u64>,
         quiet: bool,
     ) -> Result<(), TapExecutorError> {
+        if let TapEncodingFormat::Encoded = self.format {
+            return self
+                .run_encoded_tap(interval, limit, duration_ms, quiet)
+                .await;
+        }
+
         let subscription_client = connect_subscription_client((*self.url).clone())
             .await
             .map_err(TapExecutorError::ConnectionFailure)?;
@@ -210,6 +242,109 @@ impl<'a> TapRunner<'a> {
         }
     }
 
+    async fn run_encoded_tap(
+        &self,
+        interval: i64,
+        limit: i64,
+        duration_ms: Option<u64>,
+        quiet: bool,
+    ) -> Result<(), TapExecutorError> {
+        let (Some((component_id, max_payload_bytes)), OutputChannel::Stdout(formatter)) =
+            (&self.encoding_sink, self.output_channel)
+        else {
+            return Err(TapExecutorError::UnsupportedEncoding);
+        };
+
+        let subscription_client = connect_subscription_client((*self.url).clone())
+            .await
+            .map_err(TapExecutorError::ConnectionFailure)?;
+
+        tokio::pin! {
+            let stream = subscription_client.encoded_events_by_component_id_subscription(
+                component_id.clone(),
+                limit,
+                interval,
+                *max_payload_bytes,
+            );
+        }
+
+        let start_time = Instant::now();
+        let stream_duration = duration_ms
+            .map(Duration::from_millis)
+            .unwrap_or(Duration::MAX);
+
+        // Loop over the returned results, printing out encoded payloads.
+        loop {
+            let time_elapsed = start_time.elapsed();
+            if time_elapsed >= stream_duration {
+                return Ok(());
+            }
+
+            let message = timeout(stream_duration - time_elapsed, stream.next()).await;
+            match message {
+                Ok(Some(Some(res))) => {
+                    if let Some(d) = res.data {
+                        self.output_encoded_event_stdout(
+                            &d.encoded_events_by_component_id,
+                            formatter,
+                            *max_payload_bytes,
+                            quiet,
+                        );
+                    }
+                }
+                // If the stream times out, that indicates the duration specified by the user
+                // has elapsed. We should exit gracefully.
+                Err(_) => return Ok(()),
+                Ok(_) => return Err(TapExecutorError::GraphQLError),
+            }
+        }
+    }
+
+    #[allow(clippy::print_stdout, clippy::print_stderr)]
+    fn output_encoded_event_stdout(
+        &self,
+        encoded_events: &[GraphQLTapEncodedEvent],
+        formatter: &EventFormatter,
+        max_payload_bytes: i64,
+        quiet: bool,
+    ) {
+        for tap_event in encoded_events.iter() {
+            match tap_event {
+                GraphQLTapEncodedEvent::EncodedEvent(ev) => {
+                    if let Some(error) = &ev.error {
+                        eprintln!(
+                            "[tap] Failed to encode event from component \"{}\": {}",
+                            ev.input_component_id, error
+                        );
+                        continue;
+                    }
+                    // Most payloads end with a line feed, which is printed anyway.
+                    let payload = ev.payload.strip_suffix('\n').unwrap_or(&ev.payload);
+                    println!(
+                        "{}",
+                        formatter.format(
+                            ev.component_id.as_ref(),
+                            ev.component_kind.as_ref(),
+                            ev.component_type.as_ref(),
+                            payload
+                        )
+                    );
+                    if ev.truncated && !quiet {
+                        eprintln!(
+                            "[tap] Payload of {} bytes truncated to its first {} bytes.",
+                            ev.payload_bytes, max_payload_bytes
+                        );
+                    }
+                }
+                GraphQLTapEncodedEvent::EventNotification(ev) => {
+                    if !quiet {
+                        eprintln!("{}", ev.message);
+                    }
+                }
+            }
+        }
+    }
+
     #[allow(clippy::print_stdout)]
     fn output_event_stdout(
         &self,
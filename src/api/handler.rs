@@ -6,14 +6,26 @@ use std::sync::{
 use serde_json::json;
 use warp::{reply::json, Rejection, Reply};
 
+use crate::topology::health;
+
 // Health handler, responds with '{ ok: true }' when running and '{ ok: false}'
-// when shutting down
+// when shutting down. Sinks that are required to be healthy and failed their
+// latest healthcheck are listed under `unhealthy`, and also fail the check.
 pub(super) async fn health(running: Arc<AtomicBool>) -> Result<impl Reply, Rejection> {
     if running.load(atomic::Ordering::Relaxed) {
-        Ok(warp::reply::with_status(
-            json(&json!({"ok": true})),
-            warp::http::StatusCode::OK,
-        ))
+        let unhealthy = health::unhealthy_required();
+        if unhealthy.is_empty() {
+            Ok(warp::reply::with_status(
+                json(&json!({"ok": true})),
+                warp::http::StatusCode::OK,
+            ))
+        } else {
+            let unhealthy = unhealthy.iter().map(|key| key.id()).collect::<Vec<_>>();
+            Ok(warp::reply::with_status(
+                json(&json!({"ok": false, "unhealthy": unhealthy})),
+                warp::http::StatusCode::SERVICE_UNAVAILABLE,
+            ))
+        }
     } else {
         Ok(warp::reply::with_status(
             json(&json!({"ok": false})),
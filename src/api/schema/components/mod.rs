@@ -7,10 +7,14 @@ use std::{
     cmp,
     collections::{HashMap, HashSet},
     sync::LazyLock,
+    time::Duration,
 };
 
 use async_graphql::{Enum, InputObject, Interface, Object, Subscription};
-use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
+use tokio_stream::{
+    wrappers::{BroadcastStream, IntervalStream},
+    Stream, StreamExt,
+};
 use vector_lib::internal_event::DEFAULT_OUTPUT;
 
 use crate::{
@@ -249,6 +253,21 @@ impl ComponentsSubscription {
             _ => None,
         })
     }
+
+    /// Subscribes to the healthcheck status of all sinks, sampled every `interval` milliseconds
+    async fn component_health_statuses(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<sink::ComponentHealthStatus>> {
+        IntervalStream::new(tokio::time::interval(Duration::from_millis(
+            interval as u64,
+        )))
+        .map(|_| {
+            let mut sinks = state::get_sinks();
+            sinks.sort_by(|a, b| a.get_component_key().cmp(b.get_component_key()));
+            sinks.iter().map(sink::ComponentHealthStatus::new).collect()
+        })
+    }
 }
 
 /// Update the 'global' configuration that will be consumed by component queries
@@ -1,6 +1,7 @@
 use std::cmp;
 
-use async_graphql::{Enum, InputObject, Object};
+use async_graphql::{Enum, InputObject, Object, SimpleObject};
+use chrono::{DateTime, Utc};
 
 use super::{source, state, transform, Component};
 use crate::{
@@ -11,6 +12,7 @@ use crate::{
     },
     config::{ComponentKey, Inputs, OutputId},
     filter_check,
+    topology::health::{self, HealthStatus},
 };
 
 #[derive(Debug, Clone)]
@@ -77,6 +79,72 @@ impl sort::SortableByField<SinksSortFieldName> for Sink {
     }
 }
 
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum SinkHealthStatus {
+    Healthy,
+    Degraded,
+    Unhealthy,
+}
+
+impl From<HealthStatus> for SinkHealthStatus {
+    fn from(status: HealthStatus) -> Self {
+        match status {
+            HealthStatus::Healthy => Self::Healthy,
+            HealthStatus::Degraded => Self::Degraded,
+            HealthStatus::Unhealthy => Self::Unhealthy,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct SinkHealth {
+    /// Whether the sink passed its latest healthcheck
+    status: SinkHealthStatus,
+
+    /// The error of the latest healthcheck if it failed, or the reason the sink is degraded
+    last_error: Option<String>,
+
+    /// The time of the latest healthcheck
+    last_check: DateTime<Utc>,
+
+    /// Whether the sink is required to be healthy for Vector to be healthy
+    required: bool,
+
+    /// Whether the sink passed its latest healthcheck, degraded sinks passing unless
+    /// `healthchecks.allow_degraded` is disabled
+    passing: bool,
+}
+
+#[derive(SimpleObject)]
+pub struct ComponentHealthStatus {
+    /// Sink component_id
+    component_id: String,
+
+    /// Whether the sink passed its latest healthcheck, if it ran
+    status: Option<SinkHealthStatus>,
+}
+
+impl ComponentHealthStatus {
+    pub fn new(sink: &Sink) -> Self {
+        Self {
+            component_id: sink.get_component_key().id().to_string(),
+            status: health::get(sink.get_component_key()).map(|health| health.status.into()),
+        }
+    }
+}
+
+impl From<health::SinkHealth> for SinkHealth {
+    fn from(health: health::SinkHealth) -> Self {
+        Self {
+            passing: health.is_passing(),
+            status: health.status.into(),
+            last_error: health.last_error,
+            last_check: health.last_check,
+            required: health.required,
+        }
+    }
+}
+
 #[Object]
 impl Sink {
     /// Sink component_id
@@ -118,6 +186,11 @@ impl Sink {
         metrics::by_component_key(self.get_component_key())
             .into_sink_metrics(self.get_component_type())
     }
+
+    /// Result of the latest sink healthcheck, if it ran
+    pub async fn health(&self) -> Option<SinkHealth> {
+        health::get(self.get_component_key()).map(Into::into)
+    }
 }
 
 #[cfg(test)]
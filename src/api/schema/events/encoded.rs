@@ -0,0 +1,308 @@
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    sync::{Arc, LazyLock, RwLock},
+};
+
+use async_graphql::{Object, Union};
+use vector_lib::tap::topology::TapOutput;
+
+use super::output::{EventNotification, OutputEventsPayload};
+use crate::{
+    config::{ComponentKey, Config, PayloadEncoder},
+    event::Event,
+};
+
+const INVARIANT: &str = "Couldn't acquire lock on Vector sink encoders. Please report this.";
+
+/// The encoders of the sinks of the running configuration, by component key.
+static SINK_ENCODERS: LazyLock<RwLock<HashMap<ComponentKey, Result<SinkEncoder, String>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// The encoder of a sink, used to preview the payloads it sends for tapped events.
+#[derive(Clone)]
+pub struct SinkEncoder {
+    component_key: ComponentKey,
+    component_type: String,
+    encoder: Arc<PayloadEncoder>,
+}
+
+impl SinkEncoder {
+    pub fn new(
+        component_key: ComponentKey,
+        component_type: String,
+        encoder: PayloadEncoder,
+    ) -> Self {
+        Self {
+            component_key,
+            component_type,
+            encoder: Arc::new(encoder),
+        }
+    }
+
+    pub const fn component_key(&self) -> &ComponentKey {
+        &self.component_key
+    }
+
+    /// Returns the encoder of the given sink of the running configuration.
+    pub fn get(component_key: &ComponentKey) -> async_graphql::Result<Self> {
+        SINK_ENCODERS
+            .read()
+            .expect(INVARIANT)
+            .get(component_key)
+            .cloned()
+            .unwrap_or_else(|| Err(format!("There is no sink with ID \"{component_key}\".")))
+            .map_err(Into::into)
+    }
+
+    /// Encodes a tapped event into the payload the sink would send for it, keeping at most
+    /// `max_payload_bytes` of it.
+    fn encode(&self, output: TapOutput, event: Event, max_payload_bytes: usize) -> EncodedEvent {
+        let mut payload = Vec::new();
+        let error = match self.encoder.encode_input(event, &mut payload) {
+            Ok(_) => None,
+            Err(error) => {
+                payload.clear();
+                Some(error.to_string())
+            }
+        };
+
+        let payload_bytes = payload.len();
+        let (payload, binary) = render_payload(&payload[..payload_bytes.min(max_payload_bytes)]);
+        EncodedEvent {
+            sink_key: self.component_key.clone(),
+            sink_type: self.component_type.clone(),
+            output,
+            payload,
+            binary,
+            payload_bytes,
+            truncated: payload_bytes > max_payload_bytes,
+            error,
+        }
+    }
+}
+
+/// Builds the encoders of the sinks of the given configuration, replacing the previous ones.
+pub fn update_config(config: &Config) {
+    let encoders = config
+        .sinks()
+        .map(|(component_key, sink)| {
+            let component_type = sink.inner.get_component_name();
+            let encoder = match sink.inner.as_encodable() {
+                Some(encodable) => encodable
+                    .build_payload_encoder()
+                    .map(|encoder| {
+                        SinkEncoder::new(component_key.clone(), component_type.to_owned(), encoder)
+                    })
+                    .map_err(|error| {
+                        format!("Failed to build the encoder of sink \"{component_key}\": {error}.")
+                    }),
+                None => Err(format!(
+                    "Sink \"{component_key}\" of type \"{component_type}\" does not support \
+                     encoding payloads."
+                )),
+            };
+            (component_key.clone(), encoder)
+        })
+        .collect();
+
+    *SINK_ENCODERS.write().expect(INVARIANT) = encoders;
+}
+
+/// Renders a payload as text when it is valid UTF-8, with control characters other than line
+/// feeds and tabs escaped, and as hexadecimal otherwise. Returns whether it is binary.
+fn render_payload(payload: &[u8]) -> (String, bool) {
+    let text = match std::str::from_utf8(payload) {
+        Ok(text) => text,
+        // The payload may have been truncated in the middle of a character.
+        Err(error) if error.error_len().is_none() => {
+            std::str::from_utf8(&payload[..error.valid_up_to()]).expect("valid up to here")
+        }
+        Err(_) => {
+            let mut hex = String::with_capacity(payload.len() * 2);
+            for byte in payload {
+                _ = write!(hex, "{byte:02x}");
+            }
+            return (hex, true);
+        }
+    };
+
+    let mut rendered = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_control() && c != '\n' && c != '\t' {
+            rendered.extend(c.escape_default());
+        } else {
+            rendered.push(c);
+        }
+    }
+    (rendered, false)
+}
+
+/// A payload encoded by a sink for a tapped event.
+#[derive(Debug, Clone)]
+pub struct EncodedEvent {
+    sink_key: ComponentKey,
+    sink_type: String,
+    output: TapOutput,
+    payload: String,
+    binary: bool,
+    payload_bytes: usize,
+    truncated: bool,
+    error: Option<String>,
+}
+
+#[Object]
+/// Payload a sink would send for an event it received
+impl EncodedEvent {
+    /// Id of the sink that encoded the event
+    async fn component_id(&self) -> &str {
+        self.sink_key.id()
+    }
+
+    /// Type of the sink that encoded the event
+    async fn component_type(&self) -> &str {
+        self.sink_type.as_ref()
+    }
+
+    /// Kind of the component that encoded the event
+    async fn component_kind(&self) -> &str {
+        "sink"
+    }
+
+    /// Id of the component the sink received the event from
+    async fn input_component_id(&self) -> &str {
+        self.output.output_id.component.id()
+    }
+
+    /// Encoded payload, as text with control characters escaped, or as hexadecimal if it is binary
+    async fn payload(&self) -> &str {
+        self.payload.as_ref()
+    }
+
+    /// Whether the payload is binary, and so encoded as hexadecimal
+    async fn binary(&self) -> bool {
+        self.binary
+    }
+
+    /// Size of the whole payload, in bytes
+    async fn payload_bytes(&self) -> i64 {
+        self.payload_bytes as i64
+    }
+
+    /// Whether the payload was truncated to the requested maximum size
+    async fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Error encountered encoding the event, in which case the payload is empty
+    async fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+#[derive(Union, Debug, Clone)]
+/// A payload encoded by a sink or a notification
+pub enum EncodedEventsPayload {
+    /// Encoded event
+    EncodedEvent(EncodedEvent),
+
+    // Notification
+    Notification(EventNotification),
+}
+
+/// Encodes the tapped events of a payload with the given sink encoder.
+pub(crate) fn encode_output_events(
+    payload: OutputEventsPayload,
+    encoder: &SinkEncoder,
+    max_payload_bytes: usize,
+) -> EncodedEventsPayload {
+    match payload.into_event() {
+        Ok((output, event)) => {
+            EncodedEventsPayload::EncodedEvent(encoder.encode(output, event, max_payload_bytes))
+        }
+        Err(notification) => EncodedEventsPayload::Notification(notification),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::codecs::TextSerializerConfig;
+    use vector_lib::tap::notification::{Matched, Notification};
+
+    use super::*;
+    use crate::{
+        api::schema::events::log::Log,
+        codecs::{Encoder, Transformer},
+        config::OutputId,
+        event::LogEvent,
+    };
+
+    fn text_encoder() -> SinkEncoder {
+        SinkEncoder::new(
+            ComponentKey::from("out"),
+            "console".to_owned(),
+            Box::new((
+                Transformer::default(),
+                Encoder::<()>::new(TextSerializerConfig::default().build().into()),
+            )),
+        )
+    }
+
+    #[test]
+    fn encodes_tapped_events() {
+        let output = TapOutput {
+            output_id: OutputId::from("in"),
+            component_kind: "source",
+            component_type: "demo_logs".to_owned(),
+        };
+        let log = Log::new(output, LogEvent::from("hello world"));
+
+        let EncodedEventsPayload::EncodedEvent(event) =
+            encode_output_events(OutputEventsPayload::Log(log), &text_encoder(), 5)
+        else {
+            panic!("Expected payload to be an EncodedEvent");
+        };
+        assert_eq!(event.sink_key, ComponentKey::from("out"));
+        assert_eq!(event.output.output_id, OutputId::from("in"));
+        assert_eq!(event.payload, "hello");
+        assert!(!event.binary);
+        assert_eq!(event.payload_bytes, 11);
+        assert!(event.truncated);
+        assert_eq!(event.error, None);
+    }
+
+    #[test]
+    fn passes_notifications_through() {
+        let notification = Notification::Matched(Matched::new("out".to_owned()));
+        let payload = OutputEventsPayload::Notification(EventNotification {
+            notification: notification.clone(),
+        });
+
+        assert!(matches!(
+            encode_output_events(payload, &text_encoder(), 5),
+            EncodedEventsPayload::Notification(event_notification)
+                if event_notification.notification == notification
+        ));
+    }
+
+    #[test]
+    fn renders_text_payloads() {
+        assert_eq!(
+            render_payload(b"MESSAGE=hello\n\tworld\x1b"),
+            ("MESSAGE=hello\n\tworld\\u{1b}".to_owned(), false)
+        );
+        // Truncated in the middle of `é`.
+        assert_eq!(
+            render_payload(&"café".as_bytes()[..4]),
+            ("caf".to_owned(), false)
+        );
+    }
+
+    #[test]
+    fn renders_binary_payloads() {
+        assert_eq!(
+            render_payload(&[0x4d, 0xff, 0x00, 0x0a]),
+            ("4dff000a".to_owned(), true)
+        );
+    }
+}
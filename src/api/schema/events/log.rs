@@ -20,6 +20,10 @@ impl Log {
         Self { output, event }
     }
 
+    pub fn into_parts(self) -> (TapOutput, event::LogEvent) {
+        (self.output, self.event)
+    }
+
     pub fn get_message(&self) -> Option<Cow<'_, str>> {
         Some(self.event.get(event_path!("message"))?.to_string_lossy())
     }
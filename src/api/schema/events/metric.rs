@@ -17,6 +17,10 @@ impl Metric {
     pub const fn new(output: TapOutput, event: event::Metric) -> Self {
         Self { output, event }
     }
+
+    pub fn into_parts(self) -> (TapOutput, event::Metric) {
+        (self.output, self.event)
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Enum)]
@@ -1,10 +1,14 @@
+pub mod encoded;
 pub mod encoding;
 pub mod log;
 pub mod metric;
 pub mod output;
 pub mod trace;
 
+use std::collections::HashSet;
+
 use async_graphql::{Context, Subscription};
+use encoded::{encode_output_events, EncodedEventsPayload, SinkEncoder};
 use encoding::EventEncodingType;
 use futures::{stream, Stream, StreamExt};
 use output::{from_tap_payload_to_output_events, OutputEventsPayload};
@@ -16,6 +20,8 @@ use vector_lib::tap::{
     topology::WatchRx,
 };
 
+use crate::config::ComponentKey;
+
 #[derive(Debug, Default)]
 pub struct EventsSubscription;
 
@@ -39,6 +45,55 @@ impl EventsSubscription {
         // Client input is confined to `u32` to provide sensible bounds.
         create_events_stream(watch_rx, patterns, interval as u64, limit as usize)
     }
+
+    /// A stream of the payloads a sink would send for the events it receives, encoded as
+    /// configured for the sink
+    pub async fn encoded_events_by_component_id<'a>(
+        &'a self,
+        ctx: &'a Context<'a>,
+        component_id: String,
+        #[graphql(default = 500)] interval: u32,
+        #[graphql(default = 100, validator(minimum = 1, maximum = 10_000))] limit: u32,
+        #[graphql(default = 4096, validator(minimum = 1, maximum = 1_048_576))]
+        max_payload_bytes: u32,
+    ) -> async_graphql::Result<impl Stream<Item = Vec<EncodedEventsPayload>> + 'a> {
+        let watch_rx = ctx.data_unchecked::<WatchRx>().clone();
+        let encoder = SinkEncoder::get(&ComponentKey::from(component_id))?;
+
+        // Client input is confined to `u32` to provide sensible bounds.
+        Ok(create_encoded_events_stream(
+            watch_rx,
+            encoder,
+            interval as u64,
+            limit as usize,
+            max_payload_bytes as usize,
+        ))
+    }
+}
+
+/// Creates a stream of the payloads the given sink encoder produces for the events reaching its
+/// sink, sampled as in [`create_events_stream`]. Payloads are truncated to `max_payload_bytes`, so
+/// as to bound the size of the results streamed back to the client.
+pub(crate) fn create_encoded_events_stream(
+    watch_rx: WatchRx,
+    encoder: SinkEncoder,
+    interval: u64,
+    limit: usize,
+    max_payload_bytes: usize,
+) -> impl Stream<Item = Vec<EncodedEventsPayload>> {
+    // The inputs of the sink are tapped, rather than any component whose ID would match it as a
+    // pattern.
+    let patterns = TapPatterns::new(
+        HashSet::new(),
+        HashSet::from([glob::Pattern::escape(encoder.component_key().id())]),
+    );
+
+    create_events_stream(watch_rx, patterns, interval, limit).map(move |payloads| {
+        payloads
+            .into_iter()
+            .map(|payload| encode_output_events(payload, &encoder, max_payload_bytes))
+            .collect()
+    })
 }
 
 /// Creates an events stream based on component ids, and a provided interval. Will emit
@@ -3,8 +3,10 @@ use async_graphql::{Object, Union};
 use crate::api::schema::events::log::Log;
 use crate::api::schema::events::metric::Metric;
 use crate::api::schema::events::trace::Trace;
+use crate::event::Event;
 use vector_lib::tap::controller::TapPayload;
 use vector_lib::tap::notification::Notification;
+use vector_lib::tap::topology::TapOutput;
 
 /// This wrapper struct hoists `message` up from [`Notification`] for a more
 /// natural querying experience. While ideally [`Notification`] would be a
@@ -49,6 +51,27 @@ pub enum OutputEventsPayload {
     Trace(Trace),
 }
 
+impl OutputEventsPayload {
+    /// Returns the tapped event along with the output it was tapped from, or the notification.
+    pub(crate) fn into_event(self) -> Result<(TapOutput, Event), EventNotification> {
+        match self {
+            Self::Log(log) => {
+                let (output, event) = log.into_parts();
+                Ok((output, event.into()))
+            }
+            Self::Metric(metric) => {
+                let (output, event) = metric.into_parts();
+                Ok((output, event.into()))
+            }
+            Self::Notification(notification) => Err(notification),
+            Self::Trace(trace) => {
+                let (output, event) = trace.into_parts();
+                Ok((output, event.into()))
+            }
+        }
+    }
+}
+
 /// Convert an `api::TapPayload` to the equivalent GraphQL type.
 pub(crate) fn from_tap_payload_to_output_events(t: TapPayload) -> Vec<OutputEventsPayload> {
     match t {
@@ -16,6 +16,10 @@ impl Trace {
     pub const fn new(output: TapOutput, event: event::TraceEvent) -> Self {
         Self { output, event }
     }
+
+    pub fn into_parts(self) -> (TapOutput, event::TraceEvent) {
+        (self.output, self.event)
+    }
 }
 
 #[Object]
@@ -5,8 +5,9 @@ use tokio::time::Duration;
 use tokio_stream::{Stream, StreamExt};
 
 use super::{
-    filter_output_metric, OutputThroughput, ReceivedBytesTotal, ReceivedEventsTotal,
-    SentBytesTotal, SentEventsTotal,
+    filter_output_metric, OutputThroughput, ReceivedBytesTotal, ReceivedEventBytesTotal,
+    ReceivedEventsTotal, SentBytesTotal, SentEventBytesTotal, SentEventsTotal,
+    UnacknowledgedEvents,
 };
 use crate::{
     config::ComponentKey,
@@ -47,9 +48,12 @@ fn sum_metrics_owned<I: IntoIterator<Item = Metric>>(metrics: I) -> Option<Metri
 
 pub trait MetricsFilter<'a> {
     fn received_bytes_total(&self) -> Option<ReceivedBytesTotal>;
+    fn received_event_bytes_total(&self) -> Option<ReceivedEventBytesTotal>;
     fn received_events_total(&self) -> Option<ReceivedEventsTotal>;
     fn sent_bytes_total(&self) -> Option<SentBytesTotal>;
+    fn sent_event_bytes_total(&self) -> Option<SentEventBytesTotal>;
     fn sent_events_total(&self) -> Option<SentEventsTotal>;
+    fn unacknowledged_events(&self) -> Option<UnacknowledgedEvents>;
 }
 
 impl<'a> MetricsFilter<'a> for Vec<Metric> {
@@ -62,6 +66,15 @@ impl<'a> MetricsFilter<'a> for Vec<Metric> {
         Some(ReceivedBytesTotal::new(sum))
     }
 
+    fn received_event_bytes_total(&self) -> Option<ReceivedEventBytesTotal> {
+        let sum = sum_metrics(
+            self.iter()
+                .filter(|m| m.name() == "component_received_event_bytes_total"),
+        )?;
+
+        Some(ReceivedEventBytesTotal::new(sum))
+    }
+
     fn received_events_total(&self) -> Option<ReceivedEventsTotal> {
         let sum = sum_metrics(
             self.iter()
@@ -80,6 +93,15 @@ impl<'a> MetricsFilter<'a> for Vec<Metric> {
         Some(SentBytesTotal::new(sum))
     }
 
+    fn sent_event_bytes_total(&self) -> Option<SentEventBytesTotal> {
+        let sum = sum_metrics(
+            self.iter()
+                .filter(|m| m.name() == "component_sent_event_bytes_total"),
+        )?;
+
+        Some(SentEventBytesTotal::new(sum))
+    }
+
     fn sent_events_total(&self) -> Option<SentEventsTotal> {
         let sum = sum_metrics(
             self.iter()
@@ -88,6 +110,15 @@ impl<'a> MetricsFilter<'a> for Vec<Metric> {
 
         Some(SentEventsTotal::new(sum))
     }
+
+    fn unacknowledged_events(&self) -> Option<UnacknowledgedEvents> {
+        let sum = sum_metrics(
+            self.iter()
+                .filter(|m| m.name() == "source_unacknowledged_events"),
+        )?;
+
+        Some(UnacknowledgedEvents::new(sum))
+    }
 }
 
 impl<'a> MetricsFilter<'a> for Vec<&'a Metric> {
@@ -101,6 +132,16 @@ impl<'a> MetricsFilter<'a> for Vec<&'a Metric> {
         Some(ReceivedBytesTotal::new(sum))
     }
 
+    fn received_event_bytes_total(&self) -> Option<ReceivedEventBytesTotal> {
+        let sum = sum_metrics(
+            self.iter()
+                .filter(|m| m.name() == "component_received_event_bytes_total")
+                .copied(),
+        )?;
+
+        Some(ReceivedEventBytesTotal::new(sum))
+    }
+
     fn received_events_total(&self) -> Option<ReceivedEventsTotal> {
         let sum = sum_metrics(
             self.iter()
@@ -121,6 +162,16 @@ impl<'a> MetricsFilter<'a> for Vec<&'a Metric> {
         Some(SentBytesTotal::new(sum))
     }
 
+    fn sent_event_bytes_total(&self) -> Option<SentEventBytesTotal> {
+        let sum = sum_metrics(
+            self.iter()
+                .filter(|m| m.name() == "component_sent_event_bytes_total")
+                .copied(),
+        )?;
+
+        Some(SentEventBytesTotal::new(sum))
+    }
+
     fn sent_events_total(&self) -> Option<SentEventsTotal> {
         let sum = sum_metrics(
             self.iter()
@@ -130,6 +181,16 @@ impl<'a> MetricsFilter<'a> for Vec<&'a Metric> {
 
         Some(SentEventsTotal::new(sum))
     }
+
+    fn unacknowledged_events(&self) -> Option<UnacknowledgedEvents> {
+        let sum = sum_metrics(
+            self.iter()
+                .filter(|m| m.name() == "source_unacknowledged_events")
+                .copied(),
+        )?;
+
+        Some(UnacknowledgedEvents::new(sum))
+    }
 }
 
 /// Returns a stream of `Metric`s, collected at the provided millisecond interval.
@@ -229,6 +290,31 @@ pub fn component_gauge_metrics(
     })
 }
 
+/// Returns a stream of `Vec<Metric>`, where `metric_name` matches the name of the metric
+/// (e.g. "sink_send_utilization"), and the value is derived from `MetricValue::Gauge`. Unlike
+/// `component_gauge_metrics`, results are returned whenever the value of a current iteration
+/// differs from the previous, for gauges that decrease as well as increase.
+pub fn component_gauge_changes(
+    interval: i32,
+    filter_fn: &'static MetricFilterFn,
+) -> impl Stream<Item = Vec<Metric>> {
+    let mut cache = BTreeMap::new();
+
+    component_to_filtered_metrics(interval, filter_fn).map(move |map| {
+        map.into_iter()
+            .filter_map(|(id, metrics)| {
+                let m = sum_metrics_owned(metrics)?;
+                match m.value() {
+                    MetricValue::Gauge { value } if cache.insert(id, *value) != Some(*value) => {
+                        Some(m)
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    })
+}
+
 /// Returns the throughput of a 'counter' metric, sampled over `interval` milliseconds
 /// and filtered by the provided `filter_fn`.
 pub fn counter_throughput(
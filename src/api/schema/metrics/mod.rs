@@ -3,12 +3,17 @@ mod errors;
 pub mod filter;
 mod output;
 mod received_bytes;
+mod received_event_bytes;
 mod received_events;
+mod send_in_flight;
+mod send_utilization;
 mod sent_bytes;
+mod sent_event_bytes;
 mod sent_events;
 mod sink;
 pub mod source;
 mod transform;
+mod unacknowledged_events;
 mod uptime;
 
 #[cfg(feature = "sources-host_metrics")]
@@ -23,15 +28,25 @@ pub use output::*;
 pub use received_bytes::{
     ComponentReceivedBytesThroughput, ComponentReceivedBytesTotal, ReceivedBytesTotal,
 };
+pub use received_event_bytes::{
+    ComponentReceivedEventBytesThroughput, ComponentReceivedEventBytesTotal,
+    ReceivedEventBytesTotal,
+};
 pub use received_events::{
     ComponentReceivedEventsThroughput, ComponentReceivedEventsTotal, ReceivedEventsTotal,
 };
+pub use send_in_flight::{ComponentSendInFlight, SendInFlight};
+pub use send_utilization::{ComponentSendUtilization, SendUtilization};
 pub use sent_bytes::{ComponentSentBytesThroughput, ComponentSentBytesTotal, SentBytesTotal};
+pub use sent_event_bytes::{
+    ComponentSentEventBytesThroughput, ComponentSentEventBytesTotal, SentEventBytesTotal,
+};
 pub use sent_events::{ComponentSentEventsThroughput, ComponentSentEventsTotal, SentEventsTotal};
 pub use sink::{IntoSinkMetrics, SinkMetrics};
 pub use source::{IntoSourceMetrics, SourceMetrics};
 use tokio_stream::{Stream, StreamExt};
 pub use transform::{IntoTransformMetrics, TransformMetrics};
+pub use unacknowledged_events::UnacknowledgedEvents;
 pub use uptime::Uptime;
 
 use crate::config::ComponentKey;
@@ -232,6 +247,76 @@ impl MetricsSubscription {
         )
     }
 
+    /// Component received event bytes metrics over `interval`.
+    async fn component_received_event_bytes_totals(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentReceivedEventBytesTotal>> {
+        component_counter_metrics(interval, &|m| {
+            m.name() == "component_received_event_bytes_total"
+        })
+        .map(|m| {
+            m.into_iter()
+                .map(ComponentReceivedEventBytesTotal::new)
+                .collect()
+        })
+    }
+
+    /// Component received event bytes throughput over `interval`
+    async fn component_received_event_bytes_throughputs(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentReceivedEventBytesThroughput>> {
+        component_counter_throughputs(interval, &|m| {
+            m.name() == "component_received_event_bytes_total"
+        })
+        .map(|m| {
+            m.into_iter()
+                .map(|(m, throughput)| {
+                    ComponentReceivedEventBytesThroughput::new(
+                        ComponentKey::from(m.tag_value("component_id").unwrap()),
+                        throughput as i64,
+                    )
+                })
+                .collect()
+        })
+    }
+
+    /// Component sent event bytes metrics over `interval`.
+    async fn component_sent_event_bytes_totals(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentSentEventBytesTotal>> {
+        component_counter_metrics(interval, &|m| {
+            m.name() == "component_sent_event_bytes_total"
+        })
+        .map(|m| {
+            m.into_iter()
+                .map(ComponentSentEventBytesTotal::new)
+                .collect()
+        })
+    }
+
+    /// Component sent event bytes throughput over `interval`
+    async fn component_sent_event_bytes_throughputs(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentSentEventBytesThroughput>> {
+        component_counter_throughputs(interval, &|m| {
+            m.name() == "component_sent_event_bytes_total"
+        })
+        .map(|m| {
+            m.into_iter()
+                .map(|(m, throughput)| {
+                    ComponentSentEventBytesThroughput::new(
+                        ComponentKey::from(m.tag_value("component_id").unwrap()),
+                        throughput as i64,
+                    )
+                })
+                .collect()
+        })
+    }
+
     /// Total error metrics.
     async fn errors_total(
         &self,
@@ -270,6 +355,24 @@ impl MetricsSubscription {
             .map(|m| m.into_iter().map(ComponentErrorsTotal::new).collect())
     }
 
+    /// Payloads being sent by the sinks sending each payload on its own
+    async fn component_send_in_flights(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentSendInFlight>> {
+        component_gauge_changes(interval, &|m| m.name() == "sink_send_in_flight")
+            .map(|m| m.into_iter().map(ComponentSendInFlight::new).collect())
+    }
+
+    /// Send utilization metrics of the sinks sending each payload on its own
+    async fn component_send_utilizations(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentSendUtilization>> {
+        component_gauge_changes(interval, &|m| m.name() == "sink_send_utilization")
+            .map(|m| m.into_iter().map(ComponentSendUtilization::new).collect())
+    }
+
     /// All metrics.
     async fn metrics(
         &self,
@@ -0,0 +1,111 @@
+use async_graphql::Object;
+use chrono::{DateTime, Utc};
+
+use crate::{
+    config::ComponentKey,
+    event::{Metric, MetricValue},
+};
+
+pub struct ReceivedEventBytesTotal(Metric);
+
+impl ReceivedEventBytesTotal {
+    pub const fn new(m: Metric) -> Self {
+        Self(m)
+    }
+
+    pub fn get_timestamp(&self) -> Option<DateTime<Utc>> {
+        self.0.timestamp()
+    }
+
+    pub fn get_received_event_bytes_total(&self) -> f64 {
+        match self.0.value() {
+            MetricValue::Counter { value } => *value,
+            _ => 0.00,
+        }
+    }
+}
+
+#[Object]
+impl ReceivedEventBytesTotal {
+    /// Metric timestamp.
+    pub async fn timestamp(&self) -> Option<DateTime<Utc>> {
+        self.get_timestamp()
+    }
+
+    /// Total estimated size of the events received, in bytes.
+    ///
+    /// Unlike the bytes received on the wire, this is reported by every kind of component, from
+    /// the JSON-encoded size of the events.
+    pub async fn received_event_bytes_total(&self) -> f64 {
+        self.get_received_event_bytes_total()
+    }
+}
+
+impl From<Metric> for ReceivedEventBytesTotal {
+    fn from(m: Metric) -> Self {
+        Self(m)
+    }
+}
+
+pub struct ComponentReceivedEventBytesTotal {
+    component_key: ComponentKey,
+    metric: Metric,
+}
+
+impl ComponentReceivedEventBytesTotal {
+    /// Returns a new `ComponentReceivedEventBytesTotal`.
+    ///
+    /// Expects that the metric contains a tag for the component ID the metric is referenced to.
+    pub fn new(metric: Metric) -> Self {
+        let component_key = metric.tag_value("component_id").expect(
+            "Returned a metric without a `component_id`, which shouldn't happen. Please report.",
+        );
+        let component_key = ComponentKey::from(component_key);
+
+        Self {
+            component_key,
+            metric,
+        }
+    }
+}
+
+#[Object]
+impl ComponentReceivedEventBytesTotal {
+    /// Component ID.
+    async fn component_id(&self) -> &str {
+        self.component_key.id()
+    }
+
+    /// Metric for the total estimated size of the events received.
+    async fn metric(&self) -> ReceivedEventBytesTotal {
+        ReceivedEventBytesTotal::new(self.metric.clone())
+    }
+}
+
+pub struct ComponentReceivedEventBytesThroughput {
+    component_key: ComponentKey,
+    throughput: i64,
+}
+
+impl ComponentReceivedEventBytesThroughput {
+    /// Returns a new `ComponentReceivedEventBytesThroughput` for the given component.
+    pub const fn new(component_key: ComponentKey, throughput: i64) -> Self {
+        Self {
+            component_key,
+            throughput,
+        }
+    }
+}
+
+#[Object]
+impl ComponentReceivedEventBytesThroughput {
+    /// Component ID.
+    async fn component_id(&self) -> &str {
+        self.component_key.id()
+    }
+
+    /// Throughput of the estimated size of the events received, in bytes.
+    async fn throughput(&self) -> i64 {
+        self.throughput
+    }
+}
@@ -0,0 +1,71 @@
+use async_graphql::Object;
+use chrono::{DateTime, Utc};
+
+use crate::{
+    config::ComponentKey,
+    event::{Metric, MetricValue},
+};
+
+pub struct SendInFlight(Metric);
+
+impl SendInFlight {
+    pub const fn new(m: Metric) -> Self {
+        Self(m)
+    }
+}
+
+#[Object]
+impl SendInFlight {
+    /// Metric timestamp
+    pub async fn timestamp(&self) -> Option<DateTime<Utc>> {
+        self.0.timestamp()
+    }
+
+    /// Number of payloads being sent
+    pub async fn send_in_flight(&self) -> f64 {
+        match self.0.value() {
+            MetricValue::Gauge { value } => *value,
+            _ => 0.00,
+        }
+    }
+}
+
+impl From<Metric> for SendInFlight {
+    fn from(m: Metric) -> Self {
+        Self(m)
+    }
+}
+
+pub struct ComponentSendInFlight {
+    component_key: ComponentKey,
+    metric: Metric,
+}
+
+impl ComponentSendInFlight {
+    /// Returns a new `ComponentSendInFlight` struct, which is a GraphQL type. The
+    /// component id is hoisted for clear field resolution in the resulting payload
+    pub fn new(metric: Metric) -> Self {
+        let component_key = metric.tag_value("component_id").expect(
+            "Returned a metric without a `component_id`, which shouldn't happen. Please report.",
+        );
+        let component_key = ComponentKey::from(component_key);
+
+        Self {
+            component_key,
+            metric,
+        }
+    }
+}
+
+#[Object]
+impl ComponentSendInFlight {
+    /// Component id
+    async fn component_id(&self) -> &str {
+        self.component_key.id()
+    }
+
+    /// Payloads in flight metric
+    async fn metric(&self) -> SendInFlight {
+        SendInFlight::new(self.metric.clone())
+    }
+}
@@ -0,0 +1,71 @@
+use async_graphql::Object;
+use chrono::{DateTime, Utc};
+
+use crate::{
+    config::ComponentKey,
+    event::{Metric, MetricValue},
+};
+
+pub struct SendUtilization(Metric);
+
+impl SendUtilization {
+    pub const fn new(m: Metric) -> Self {
+        Self(m)
+    }
+}
+
+#[Object]
+impl SendUtilization {
+    /// Metric timestamp
+    pub async fn timestamp(&self) -> Option<DateTime<Utc>> {
+        self.0.timestamp()
+    }
+
+    /// Share of time during which the sink was sending at least one payload, between 0 and 1
+    pub async fn send_utilization(&self) -> f64 {
+        match self.0.value() {
+            MetricValue::Gauge { value } => *value,
+            _ => 0.00,
+        }
+    }
+}
+
+impl From<Metric> for SendUtilization {
+    fn from(m: Metric) -> Self {
+        Self(m)
+    }
+}
+
+pub struct ComponentSendUtilization {
+    component_key: ComponentKey,
+    metric: Metric,
+}
+
+impl ComponentSendUtilization {
+    /// Returns a new `ComponentSendUtilization` struct, which is a GraphQL type. The
+    /// component id is hoisted for clear field resolution in the resulting payload
+    pub fn new(metric: Metric) -> Self {
+        let component_key = metric.tag_value("component_id").expect(
+            "Returned a metric without a `component_id`, which shouldn't happen. Please report.",
+        );
+        let component_key = ComponentKey::from(component_key);
+
+        Self {
+            component_key,
+            metric,
+        }
+    }
+}
+
+#[Object]
+impl ComponentSendUtilization {
+    /// Component id
+    async fn component_id(&self) -> &str {
+        self.component_key.id()
+    }
+
+    /// Send utilization metric
+    async fn metric(&self) -> SendUtilization {
+        SendUtilization::new(self.metric.clone())
+    }
+}
@@ -16,6 +16,11 @@ impl GenericSinkMetrics {
 
 #[Object]
 impl GenericSinkMetrics {
+    /// Total estimated size of the events received by the current sink, in bytes
+    pub async fn received_event_bytes_total(&self) -> Option<metrics::ReceivedEventBytesTotal> {
+        self.0.received_event_bytes_total()
+    }
+
     /// Total received events for the current sink
     pub async fn received_events_total(&self) -> Option<metrics::ReceivedEventsTotal> {
         self.0.received_events_total()
@@ -26,6 +31,11 @@ impl GenericSinkMetrics {
         self.0.sent_bytes_total()
     }
 
+    /// Total estimated size of the events sent by the current sink, in bytes
+    pub async fn sent_event_bytes_total(&self) -> Option<metrics::SentEventBytesTotal> {
+        self.0.sent_event_bytes_total()
+    }
+
     /// Total sent events for the current sink
     pub async fn sent_events_total(&self) -> Option<metrics::SentEventsTotal> {
         self.0.sent_events_total()
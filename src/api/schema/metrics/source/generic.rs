@@ -21,13 +21,28 @@ impl GenericSourceMetrics {
         self.0.received_bytes_total()
     }
 
+    /// Total estimated size of the events received by the current source, in bytes
+    pub async fn received_event_bytes_total(&self) -> Option<metrics::ReceivedEventBytesTotal> {
+        self.0.received_event_bytes_total()
+    }
+
     /// Total received events for the current source
     pub async fn received_events_total(&self) -> Option<metrics::ReceivedEventsTotal> {
         self.0.received_events_total()
     }
 
+    /// Total estimated size of the events sent by the current source, in bytes
+    pub async fn sent_event_bytes_total(&self) -> Option<metrics::SentEventBytesTotal> {
+        self.0.sent_event_bytes_total()
+    }
+
     /// Total sent events for the current source
     pub async fn sent_events_total(&self) -> Option<metrics::SentEventsTotal> {
         self.0.sent_events_total()
     }
+
+    /// Events sent by the current source which are still awaiting acknowledgement
+    pub async fn unacknowledged_events(&self) -> Option<metrics::UnacknowledgedEvents> {
+        self.0.unacknowledged_events()
+    }
 }
@@ -3,14 +3,23 @@ mod generic;
 
 use async_graphql::Interface;
 
-use super::{ReceivedBytesTotal, ReceivedEventsTotal, SentEventsTotal};
+use super::{
+    ReceivedBytesTotal, ReceivedEventBytesTotal, ReceivedEventsTotal, SentEventBytesTotal,
+    SentEventsTotal, UnacknowledgedEvents,
+};
 use crate::event::Metric;
 
 #[derive(Debug, Clone, Interface)]
 #[graphql(
     field(name = "received_bytes_total", ty = "Option<ReceivedBytesTotal>"),
+    field(
+        name = "received_event_bytes_total",
+        ty = "Option<ReceivedEventBytesTotal>"
+    ),
     field(name = "received_events_total", ty = "Option<ReceivedEventsTotal>"),
-    field(name = "sent_events_total", ty = "Option<SentEventsTotal>")
+    field(name = "sent_event_bytes_total", ty = "Option<SentEventBytesTotal>"),
+    field(name = "sent_events_total", ty = "Option<SentEventsTotal>"),
+    field(name = "unacknowledged_events", ty = "Option<UnacknowledgedEvents>")
 )]
 pub enum SourceMetrics {
     GenericSourceMetrics(generic::GenericSourceMetrics),
@@ -16,11 +16,21 @@ impl GenericTransformMetrics {
 
 #[Object]
 impl GenericTransformMetrics {
+    /// Total estimated size of the events received by the current transform, in bytes
+    pub async fn received_event_bytes_total(&self) -> Option<metrics::ReceivedEventBytesTotal> {
+        self.0.received_event_bytes_total()
+    }
+
     /// Total received events for the current transform
     pub async fn received_events_total(&self) -> Option<metrics::ReceivedEventsTotal> {
         self.0.received_events_total()
     }
 
+    /// Total estimated size of the events sent by the current transform, in bytes
+    pub async fn sent_event_bytes_total(&self) -> Option<metrics::SentEventBytesTotal> {
+        self.0.sent_event_bytes_total()
+    }
+
     /// Total sent events for the current transform
     pub async fn sent_events_total(&self) -> Option<metrics::SentEventsTotal> {
         self.0.sent_events_total()
@@ -2,12 +2,17 @@ mod generic;
 
 use async_graphql::Interface;
 
-use super::{ReceivedEventsTotal, SentEventsTotal};
+use super::{ReceivedEventBytesTotal, ReceivedEventsTotal, SentEventBytesTotal, SentEventsTotal};
 use crate::event::Metric;
 
 #[derive(Debug, Clone, Interface)]
 #[graphql(
+    field(
+        name = "received_event_bytes_total",
+        ty = "Option<ReceivedEventBytesTotal>"
+    ),
     field(name = "received_events_total", ty = "Option<ReceivedEventsTotal>"),
+    field(name = "sent_event_bytes_total", ty = "Option<SentEventBytesTotal>"),
     field(name = "sent_events_total", ty = "Option<SentEventsTotal>")
 )]
 pub enum TransformMetrics {
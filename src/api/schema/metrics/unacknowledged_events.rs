@@ -0,0 +1,38 @@
+use async_graphql::Object;
+use chrono::{DateTime, Utc};
+
+use crate::event::{Metric, MetricValue};
+
+pub struct UnacknowledgedEvents(Metric);
+
+impl UnacknowledgedEvents {
+    pub const fn new(m: Metric) -> Self {
+        Self(m)
+    }
+
+    pub fn get_unacknowledged_events(&self) -> f64 {
+        match self.0.value() {
+            MetricValue::Gauge { value } => *value,
+            _ => 0.00,
+        }
+    }
+}
+
+#[Object]
+impl UnacknowledgedEvents {
+    /// Metric timestamp
+    pub async fn timestamp(&self) -> Option<DateTime<Utc>> {
+        self.0.timestamp()
+    }
+
+    /// Events sent by the source which are still awaiting acknowledgement
+    pub async fn unacknowledged_events(&self) -> f64 {
+        self.get_unacknowledged_events()
+    }
+}
+
+impl From<Metric> for UnacknowledgedEvents {
+    fn from(m: Metric) -> Self {
+        Self(m)
+    }
+}
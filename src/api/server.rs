@@ -72,8 +72,9 @@ impl Server {
                 })
         };
 
-        // Update component schema with the config before starting the server.
+        // Update component schema and sink encoders with the config before starting the server.
         schema::components::update_config(config);
+        schema::events::encoded::update_config(config);
 
         // Spawn the server in the background.
         handle.spawn(server);
@@ -90,7 +91,8 @@ impl Server {
     /// directly involve `self`, it provides a neater API to expose an internal implementation
     /// detail than exposing the function of the sub-mod directly.
     pub fn update_config(&self, config: &config::Config) {
-        schema::components::update_config(config)
+        schema::components::update_config(config);
+        schema::events::encoded::update_config(config);
     }
 }
 
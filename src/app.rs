@@ -353,6 +353,7 @@ async fn handle_signal(
                 &topology_controller.config_paths,
                 signal_handler,
                 allow_empty_config,
+                Some(&topology_controller.topology.config),
             )
             .await;
 
@@ -514,12 +515,14 @@ pub async fn load_configs(
         &config_paths,
         signal_handler,
         allow_empty_config,
+        None,
     )
     .await
     .map_err(handle_config_errors)?;
 
     config::init_log_schema(config.global.log_schema.clone(), true);
     config::init_telemetry(config.global.telemetry.clone(), true);
+    config::init_timestamp_settings(config.global.timestamp_settings(), true);
 
     if !config.healthchecks.enabled {
         info!("Health checks are disabled.");
@@ -538,6 +541,7 @@ pub fn init_logging(color: bool, format: LogFormat, log_level: &str, rate: u64)
     };
 
     trace::init(color, json, &level, rate);
+    trace::init_panic_hook();
     debug!(
         message = "Internal log rate limit configured.",
         internal_log_rate_secs = rate,
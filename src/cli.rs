@@ -317,7 +317,7 @@ pub enum SubCommand {
     Service(service::Opts),
 
     /// Vector Remap Language CLI
-    Vrl(vrl::cli::Opts),
+    Vrl(crate::vrl_cli::Opts),
 }
 
 impl SubCommand {
@@ -344,7 +344,7 @@ impl SubCommand {
             Self::Vrl(s) => {
                 let mut functions = vrl::stdlib::all();
                 functions.extend(vector_vrl_functions::all());
-                vrl::cli::cmd::cmd(s, functions)
+                crate::vrl_cli::cmd(s, functions)
             }
         }
     }
@@ -1,7 +1,7 @@
 use crate::codecs::Transformer;
 use vector_lib::codecs::{
     encoding::{Framer, FramingConfig, Serializer, SerializerConfig},
-    CharacterDelimitedEncoder, LengthDelimitedEncoder, NewlineDelimitedEncoder,
+    BytesEncoder, CharacterDelimitedEncoder, LengthDelimitedEncoder, NewlineDelimitedEncoder,
 };
 use vector_lib::configurable::configurable_component;
 
@@ -112,6 +112,7 @@ impl EncodingConfigWithFraming {
                 // https://github.com/Graylog2/graylog2-server/issues/1240
                 CharacterDelimitedEncoder::new(0).into()
             }
+            (None, Serializer::JournalExport(_)) => BytesEncoder.into(),
             (None, Serializer::Protobuf(_)) => {
                 // Protobuf uses length-delimited messages, see:
                 // https://developers.google.com/protocol-buffers/docs/techniques#streaming
@@ -114,6 +114,7 @@ impl Encoder<Framer> {
                 Framer::CharacterDelimited(CharacterDelimitedEncoder { delimiter: b',' }),
             ) => "application/json",
             (Serializer::Native(_), _) | (Serializer::Protobuf(_), _) => "application/octet-stream",
+            (Serializer::JournalExport(_), _) => "application/vnd.fdo.journal",
             (
                 Serializer::Avro(_)
                 | Serializer::Cef(_)
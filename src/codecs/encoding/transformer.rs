@@ -1,11 +1,10 @@
 #![deny(missing_docs)]
 
-use chrono::{DateTime, Utc};
 use core::fmt::Debug;
 use std::collections::BTreeMap;
 
-use ordered_float::NotNan;
 use serde::{Deserialize, Deserializer};
+use vector_lib::config::{timestamp_settings, TimestampSettings};
 use vector_lib::configurable::configurable_component;
 use vector_lib::event::{LogEvent, MaybeAsLogMut};
 use vector_lib::lookup::lookup_v2::ConfigValuePath;
@@ -16,6 +15,8 @@ use vrl::value::Value;
 
 use crate::{event::Event, serde::is_default};
 
+pub use vector_lib::config::{TimestampFormat, TimestampPrecision};
+
 /// Transformations to prepare an event for serialization.
 #[configurable_component(no_deser)]
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -29,8 +30,16 @@ pub struct Transformer {
     except_fields: Option<Vec<ConfigValuePath>>,
 
     /// Format used for timestamp fields.
+    ///
+    /// Defaults to the global `timestamp_format` option.
     #[serde(default, skip_serializing_if = "is_default")]
     timestamp_format: Option<TimestampFormat>,
+
+    /// Precision used for timestamp fields represented as RFC 3339 timestamps.
+    ///
+    /// Defaults to the global `timestamp_precision` option.
+    #[serde(default, skip_serializing_if = "is_default")]
+    timestamp_precision: Option<TimestampPrecision>,
 }
 
 impl<'de> Deserialize<'de> for Transformer {
@@ -47,6 +56,8 @@ impl<'de> Deserialize<'de> for Transformer {
             except_fields: Option<Vec<OwnedValuePath>>,
             #[serde(default)]
             timestamp_format: Option<TimestampFormat>,
+            #[serde(default)]
+            timestamp_precision: Option<TimestampPrecision>,
         }
 
         let inner: TransformerInner = Deserialize::deserialize(deserializer)?;
@@ -59,6 +70,7 @@ impl<'de> Deserialize<'de> for Transformer {
                 .map(|v| v.iter().map(|p| ConfigValuePath(p.clone())).collect()),
             inner.timestamp_format,
         )
+        .map(|transformer| transformer.with_timestamp_precision(inner.timestamp_precision))
         .map_err(serde::de::Error::custom)
     }
 }
@@ -79,9 +91,19 @@ impl Transformer {
             only_fields,
             except_fields,
             timestamp_format,
+            timestamp_precision: None,
         })
     }
 
+    /// Sets the precision of timestamp fields represented as RFC 3339 timestamps.
+    pub const fn with_timestamp_precision(
+        mut self,
+        timestamp_precision: Option<TimestampPrecision>,
+    ) -> Self {
+        self.timestamp_precision = timestamp_precision;
+        self
+    }
+
     /// Get the `Transformer`'s `only_fields`.
     #[cfg(test)]
     pub const fn only_fields(&self) -> &Option<Vec<ConfigValuePath>> {
@@ -120,12 +142,18 @@ impl Transformer {
 
     /// Prepare an event for serialization by the given transformation rules.
     pub fn transform(&self, event: &mut Event) {
+        self.transform_with_timestamp_defaults(event, timestamp_settings());
+    }
+
+    /// Same as `transform`, with the timestamp settings not set for this transformer taken from
+    /// `defaults` rather than from the global ones.
+    fn transform_with_timestamp_defaults(&self, event: &mut Event, defaults: TimestampSettings) {
         // Rules are currently applied to logs only.
         if let Some(log) = event.maybe_as_log_mut() {
             // Ordering in here should not matter.
             self.apply_except_fields(log);
             self.apply_only_fields(log);
-            self.apply_timestamp_format(log);
+            self.apply_timestamp_format(log, defaults);
         }
     }
 
@@ -178,48 +206,38 @@ impl Transformer {
         }
     }
 
-    fn format_timestamps<F, T>(&self, log: &mut LogEvent, extract: F)
-    where
-        F: Fn(&DateTime<Utc>) -> T,
-        T: Into<Value>,
-    {
+    fn apply_timestamp_format(&self, log: &mut LogEvent, defaults: TimestampSettings) {
+        let settings = TimestampSettings {
+            format: self.timestamp_format,
+            precision: self.timestamp_precision,
+        }
+        .or(defaults);
+        // Timestamps are serialized as RFC 3339 timestamps by default.
+        if settings == TimestampSettings::default() {
+            return;
+        }
+
         if log.value().is_object() {
-            let mut unix_timestamps = Vec::new();
+            let mut formatted_timestamps = Vec::new();
             for (k, v) in log.all_event_fields().expect("must be an object") {
                 if let Value::Timestamp(ts) = v {
-                    unix_timestamps.push((k.clone(), extract(ts).into()));
+                    if let Some(formatted) = settings.format(ts) {
+                        formatted_timestamps.push((k.clone(), formatted));
+                    }
                 }
             }
-            for (k, v) in unix_timestamps {
+            for (k, v) in formatted_timestamps {
                 log.parse_path_and_insert(k, v).unwrap();
             }
         } else {
             // root is not an object
             let timestamp = if let Value::Timestamp(ts) = log.value() {
-                Some(extract(ts))
+                settings.format(ts)
             } else {
                 None
             };
             if let Some(ts) = timestamp {
-                log.insert(event_path!(), ts.into());
-            }
-        }
-    }
-
-    fn apply_timestamp_format(&self, log: &mut LogEvent) {
-        if let Some(timestamp_format) = self.timestamp_format.as_ref() {
-            match timestamp_format {
-                TimestampFormat::Unix => self.format_timestamps(log, |ts| ts.timestamp()),
-                TimestampFormat::UnixMs => self.format_timestamps(log, |ts| ts.timestamp_millis()),
-                TimestampFormat::UnixUs => self.format_timestamps(log, |ts| ts.timestamp_micros()),
-                TimestampFormat::UnixNs => self.format_timestamps(log, |ts| {
-                    ts.timestamp_nanos_opt().expect("Timestamp out of range")
-                }),
-                TimestampFormat::UnixFloat => self.format_timestamps(log, |ts| {
-                    NotNan::new(ts.timestamp_micros() as f64 / 1e6).unwrap()
-                }),
-                // RFC3339 is the default serialization of a timestamp.
-                TimestampFormat::Rfc3339 => (),
+                log.insert(event_path!(), ts);
             }
         }
     }
@@ -239,34 +257,14 @@ impl Transformer {
     }
 }
 
-#[configurable_component]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-#[serde(rename_all = "snake_case")]
-/// The format in which a timestamp should be represented.
-pub enum TimestampFormat {
-    /// Represent the timestamp as a Unix timestamp.
-    Unix,
-
-    /// Represent the timestamp as a RFC 3339 timestamp.
-    Rfc3339,
-
-    /// Represent the timestamp as a Unix timestamp in milliseconds.
-    UnixMs,
-
-    /// Represent the timestamp as a Unix timestamp in microseconds
-    UnixUs,
-
-    /// Represent the timestamp as a Unix timestamp in nanoseconds.
-    UnixNs,
-
-    /// Represent the timestamp as a Unix timestamp in floating point.
-    UnixFloat,
-}
-
 #[cfg(test)]
 mod tests {
+    use bytes::BytesMut;
+    use chrono::{TimeZone, Timelike, Utc};
     use indoc::indoc;
+    use tokio_util::codec::Encoder as _;
     use vector_lib::btreemap;
+    use vector_lib::codecs::{JsonSerializerConfig, LogfmtSerializerConfig, TextSerializerConfig};
     use vector_lib::config::{log_schema, LogNamespace};
     use vector_lib::lookup::path::parse_target_path;
     use vrl::value::Kind;
@@ -417,6 +415,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn encodes_timestamps_with_defaults() {
+        let timestamp = Utc
+            .with_ymd_and_hms(2024, 1, 2, 3, 4, 5)
+            .single()
+            .and_then(|timestamp| timestamp.with_nanosecond(123_456_789))
+            .unwrap();
+        let event = Event::Log(LogEvent::from(btreemap! {
+            "message" => Value::Timestamp(timestamp),
+        }));
+        let defaults = TimestampSettings {
+            format: Some(TimestampFormat::Rfc3339),
+            precision: Some(TimestampPrecision::Micros),
+        };
+        let encode = |config: &str| {
+            let transformer: Transformer = toml::from_str(config).unwrap();
+            let mut event = event.clone();
+            transformer.transform_with_timestamp_defaults(&mut event, defaults);
+
+            let mut json = BytesMut::new();
+            JsonSerializerConfig::default()
+                .build()
+                .encode(event.clone(), &mut json)
+                .unwrap();
+            let mut logfmt = BytesMut::new();
+            LogfmtSerializerConfig::new()
+                .build()
+                .encode(event.clone(), &mut logfmt)
+                .unwrap();
+            let mut text = BytesMut::new();
+            TextSerializerConfig::default()
+                .build()
+                .encode(event, &mut text)
+                .unwrap();
+            (json.freeze(), logfmt.freeze(), text.freeze())
+        };
+
+        assert_eq!(
+            encode(""),
+            (
+                r#"{"message":"2024-01-02T03:04:05.123456Z"}"#.into(),
+                "message=2024-01-02T03:04:05.123456Z".into(),
+                "2024-01-02T03:04:05.123456Z".into(),
+            )
+        );
+        assert_eq!(
+            encode(r#"timestamp_precision = "seconds""#),
+            (
+                r#"{"message":"2024-01-02T03:04:05Z"}"#.into(),
+                "message=2024-01-02T03:04:05Z".into(),
+                "2024-01-02T03:04:05Z".into(),
+            )
+        );
+        assert_eq!(
+            encode(r#"timestamp_format = "unix_ms""#),
+            (
+                r#"{"message":1704164645123}"#.into(),
+                "message=1704164645123".into(),
+                "1704164645123".into(),
+            )
+        );
+    }
+
     #[test]
     fn exclusivity_violation() {
         let config: std::result::Result<Transformer, _> = toml::from_str(indoc! {r#"
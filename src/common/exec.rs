@@ -0,0 +1,34 @@
+//! Handling of the commands run by the `exec` source and sink.
+
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt};
+
+/// Reads the lines of the stderr of a command until it is closed, so that the command never
+/// writes to a closed pipe. Lines longer than `max_line_bytes` are split, and invalid UTF-8 is
+/// replaced.
+pub(crate) async fn read_stderr_lines<R: AsyncBufRead + Unpin>(
+    mut reader: R,
+    max_line_bytes: usize,
+    mut log_line: impl FnMut(&str),
+) {
+    let mut line = Vec::new();
+    loop {
+        let mut limited = (&mut reader).take(max_line_bytes as u64);
+        match limited.read_until(b'\n', &mut line).await {
+            Ok(0) => break,
+            Ok(_) => {
+                if line.ends_with(b"\n") {
+                    line.pop();
+                    if line.ends_with(b"\r") {
+                        line.pop();
+                    }
+                }
+                log_line(&String::from_utf8_lossy(&line));
+                line.clear();
+            }
+            Err(error) => {
+                debug!(message = "Failed to read command stderr.", %error);
+                break;
+            }
+        }
+    }
+}
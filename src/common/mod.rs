@@ -8,6 +8,9 @@
 ))]
 pub mod datadog;
 
+#[cfg(any(feature = "sources-exec", feature = "sinks-exec"))]
+pub(crate) mod exec;
+
 #[cfg(any(
     feature = "sources-aws_sqs",
     feature = "sinks-aws_sqs",
@@ -17,3 +20,10 @@ pub(crate) mod sqs;
 
 #[cfg(any(feature = "sources-aws_s3", feature = "sinks-aws_s3"))]
 pub(crate) mod s3;
+
+#[cfg(any(
+    feature = "transforms-normalize_severity",
+    all(unix, feature = "sinks-journald"),
+    feature = "sinks-papertrail"
+))]
+pub(crate) mod severity;
@@ -0,0 +1,240 @@
+//! Syslog severities, as normalized by the `normalize_severity` transform and written by the
+//! `journald` and `papertrail` sinks.
+
+use vector_lib::configurable::configurable_component;
+use vrl::value::Value;
+
+/// A syslog severity, as defined in [RFC 5424][rfc5424].
+///
+/// [rfc5424]: https://datatracker.ietf.org/doc/html/rfc5424#section-6.2.1
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// System is unusable.
+    Emergency,
+
+    /// Action must be taken immediately.
+    Alert,
+
+    /// Critical conditions.
+    Critical,
+
+    /// Error conditions.
+    Error,
+
+    /// Warning conditions.
+    Warning,
+
+    /// Normal but significant conditions.
+    Notice,
+
+    /// Informational messages.
+    Info,
+
+    /// Debug-level messages.
+    Debug,
+}
+
+/// How the numbers held by a field are mapped to severities.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SeverityNumbering {
+    /// Numbers are syslog severity codes, from 0 for `emerg` to 7 for `debug`.
+    #[default]
+    Syslog,
+
+    /// Numbers are syslog priority values, combining a facility and a severity, as in
+    /// `<PRI>` header of a syslog message.
+    SyslogPriority,
+
+    /// Numbers are OpenTelemetry severity numbers, from 1 for `TRACE` to 24 for `FATAL4`.
+    Opentelemetry,
+}
+
+/// The short names of the ranges of OpenTelemetry severity numbers, which can be suffixed with
+/// the number of a severity within the range, as in `INFO2`.
+const OTEL_SEVERITY_TEXTS: [&str; 6] = ["trace", "debug", "info", "warn", "error", "fatal"];
+
+impl Severity {
+    /// Finds the severity with the given code.
+    pub const fn from_code(code: i64) -> Option<Self> {
+        Some(match code {
+            0 => Self::Emergency,
+            1 => Self::Alert,
+            2 => Self::Critical,
+            3 => Self::Error,
+            4 => Self::Warning,
+            5 => Self::Notice,
+            6 => Self::Info,
+            7 => Self::Debug,
+            _ => return None,
+        })
+    }
+
+    /// Finds the severity of a syslog priority value.
+    pub const fn from_priority(priority: i64) -> Option<Self> {
+        match priority {
+            0..=191 => Self::from_code(priority % 8),
+            _ => None,
+        }
+    }
+
+    /// Finds the severity of an OpenTelemetry severity number, as mapped from syslog severities in
+    /// the OpenTelemetry log data model.
+    pub const fn from_opentelemetry(number: i64) -> Option<Self> {
+        Some(match number {
+            1..=8 => Self::Debug,
+            9 => Self::Info,
+            10..=12 => Self::Notice,
+            13..=16 => Self::Warning,
+            17 => Self::Error,
+            18 => Self::Critical,
+            19 | 20 => Self::Alert,
+            21..=24 => Self::Emergency,
+            _ => return None,
+        })
+    }
+
+    /// Finds the severity of a keyword, ignoring case.
+    ///
+    /// Besides the syslog keywords, this recognizes their full names, common aliases such as
+    /// `warn` or `panic`, and OpenTelemetry severity texts such as `INFO2`. A severity text
+    /// without a number, such as `FATAL`, is the first one of its range.
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        let keyword = keyword.trim().to_ascii_lowercase();
+        let severity = match keyword.as_str() {
+            "emerg" | "emergency" | "panic" => Self::Emergency,
+            "alert" => Self::Alert,
+            "crit" | "critical" => Self::Critical,
+            "err" | "error" => Self::Error,
+            "warning" | "warn" => Self::Warning,
+            "notice" => Self::Notice,
+            "info" | "informational" | "information" => Self::Info,
+            "debug" | "trace" => Self::Debug,
+            keyword => {
+                let (text, index) = match keyword.chars().last()?.to_digit(10) {
+                    Some(index) => (&keyword[..keyword.len() - 1], index),
+                    None => (keyword, 1),
+                };
+                let range = OTEL_SEVERITY_TEXTS.iter().position(|t| *t == text)?;
+                if !(1..=4).contains(&index) {
+                    return None;
+                }
+                return Self::from_opentelemetry((range * 4) as i64 + i64::from(index));
+            }
+        };
+        Some(severity)
+    }
+
+    /// Finds the severity held by a value, either as a keyword, or as a number, a string of
+    /// digits included, mapped according to the given numbering.
+    pub fn from_value(value: &Value, numbering: SeverityNumbering) -> Option<Self> {
+        let number = match value {
+            Value::Bytes(bytes) => {
+                let text = std::str::from_utf8(bytes).ok()?;
+                if let Some(severity) = Self::from_keyword(text) {
+                    return Some(severity);
+                }
+                text.trim().parse::<i64>().ok()?
+            }
+            Value::Integer(number) => *number,
+            Value::Float(number) if number.fract() == 0.0 => number.into_inner() as i64,
+            _ => return None,
+        };
+        match numbering {
+            SeverityNumbering::Syslog => Self::from_code(number),
+            SeverityNumbering::SyslogPriority => Self::from_priority(number),
+            SeverityNumbering::Opentelemetry => Self::from_opentelemetry(number),
+        }
+    }
+
+    /// Returns the syslog severity code, from 0 for `emerg` to 7 for `debug`.
+    pub const fn code(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the syslog keyword, such as `err` or `warning`.
+    pub const fn keyword(self) -> &'static str {
+        match self {
+            Self::Emergency => "emerg",
+            Self::Alert => "alert",
+            Self::Critical => "crit",
+            Self::Error => "err",
+            Self::Warning => "warning",
+            Self::Notice => "notice",
+            Self::Info => "info",
+            Self::Debug => "debug",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ordered_float::NotNan;
+
+    use super::*;
+
+    #[test]
+    fn parses_keywords() {
+        for (keyword, severity) in [
+            ("emerg", Severity::Emergency),
+            ("CRITICAL", Severity::Critical),
+            ("err", Severity::Error),
+            (" Warn ", Severity::Warning),
+            ("informational", Severity::Info),
+            ("trace", Severity::Debug),
+            ("INFO2", Severity::Notice),
+            ("error3", Severity::Alert),
+            ("FATAL", Severity::Emergency),
+            ("fatal1", Severity::Emergency),
+            ("FATAL4", Severity::Emergency),
+        ] {
+            assert_eq!(Severity::from_keyword(keyword), Some(severity), "{keyword}");
+        }
+        for keyword in ["", "verbose", "info5", "info0", "2", "débug"] {
+            assert_eq!(Severity::from_keyword(keyword), None, "{keyword}");
+        }
+    }
+
+    #[test]
+    fn parses_numbers() {
+        let parse = |value: Value, numbering| Severity::from_value(&value, numbering);
+
+        assert_eq!(
+            parse(3.into(), SeverityNumbering::Syslog),
+            Some(Severity::Error)
+        );
+        assert_eq!(
+            parse("4".into(), SeverityNumbering::Syslog),
+            Some(Severity::Warning)
+        );
+        assert_eq!(parse(8.into(), SeverityNumbering::Syslog), None);
+        assert_eq!(
+            parse(34.into(), SeverityNumbering::SyslogPriority),
+            Some(Severity::Critical)
+        );
+        assert_eq!(parse(192.into(), SeverityNumbering::SyslogPriority), None);
+        assert_eq!(
+            parse(9.into(), SeverityNumbering::Opentelemetry),
+            Some(Severity::Info)
+        );
+        assert_eq!(
+            parse(
+                Value::Float(NotNan::new(17.0).unwrap()),
+                SeverityNumbering::Opentelemetry
+            ),
+            Some(Severity::Error)
+        );
+        assert_eq!(parse(0.into(), SeverityNumbering::Opentelemetry), None);
+        assert_eq!(parse(true.into(), SeverityNumbering::Syslog), None);
+    }
+
+    #[test]
+    fn renders_severities() {
+        assert_eq!(Severity::Error.keyword(), "err");
+        assert_eq!(Severity::Error.code(), 3);
+        assert_eq!(Severity::from_code(5), Some(Severity::Notice));
+    }
+}
@@ -175,7 +175,7 @@ pub fn encode_test_event(
             let mut alt_encoder = if encoder.serializer().supports_json() {
                 Encoder::<encoding::Framer>::new(
                     LengthDelimitedEncoder::default().into(),
-                    LogfmtSerializer.into(),
+                    LogfmtSerializer::new().into(),
                 )
             } else {
                 Encoder::<encoding::Framer>::new(
@@ -172,6 +172,8 @@ fn deserializer_config_to_serializer(config: &DeserializerConfig) -> encoding::S
         // TODO: Influxdb has no serializer yet
         DeserializerConfig::Influxdb { .. } => todo!(),
         DeserializerConfig::Vrl { .. } => unimplemented!(),
+        // TODO: Journal export has no serializer yet
+        DeserializerConfig::JournalExport => SerializerConfig::JournalExport,
     };
 
     serializer_config
@@ -200,6 +202,8 @@ fn decoder_framing_to_encoding_framer(framing: &decoding::FramingConfig) -> enco
         decoding::FramingConfig::OctetCounting(_) => todo!(),
         // TODO: chunked gelf is not supported yet in encoding
         decoding::FramingConfig::ChunkedGelf(_) => todo!(),
+        // Journal export entries are terminated by the serializer itself.
+        decoding::FramingConfig::JournalExport(_) => encoding::FramingConfig::Bytes,
     };
 
     framing_config.build()
@@ -213,6 +217,7 @@ fn serializer_config_to_deserializer(
         SerializerConfig::Cef { .. } => todo!(),
         SerializerConfig::Csv { .. } => todo!(),
         SerializerConfig::Gelf => DeserializerConfig::Gelf(Default::default()),
+        SerializerConfig::JournalExport => DeserializerConfig::JournalExport,
         SerializerConfig::Json(_) => DeserializerConfig::Json(Default::default()),
         SerializerConfig::Logfmt => todo!(),
         SerializerConfig::Native => DeserializerConfig::Native,
@@ -14,6 +14,9 @@ use crate::{
 };
 
 /// A condition that uses the [Vector Remap Language](https://vector.dev/docs/reference/vrl) (VRL) [boolean expression](https://vector.dev/docs/reference/vrl#boolean-expressions) against an event.
+///
+/// The expression can read the metadata of the event with `%` paths, such as `%vector.source_id`,
+/// but not its secrets.
 #[configurable_component]
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct VrlConfig {
@@ -172,6 +175,7 @@ impl Conditional for Vrl {
 #[cfg(test)]
 mod test {
     use vector_lib::metric_tags;
+    use vrl::path;
 
     use super::*;
     use crate::{
@@ -186,6 +190,12 @@ mod test {
 
     #[test]
     fn check_vrl() {
+        let mut with_metadata = log_event!["message" => "hello"];
+        with_metadata
+            .metadata_mut()
+            .value_mut()
+            .insert(path!("vector", "source_id"), "journald_in");
+
         let checks = vec![
             (
                 log_event![],   // event
@@ -238,6 +248,24 @@ mod test {
                 Ok(()),
                 Ok(()),
             ),
+            (
+                with_metadata.clone(),
+                r#"%vector.source_id == "journald_in" && .message == "hello""#,
+                Ok(()),
+                Ok(()),
+            ),
+            (
+                with_metadata,
+                r#"%vector.source_id == "file_in""#,
+                Ok(()),
+                Err("source execution resolved to false"),
+            ),
+            (
+                log_event!["message" => "hello"],
+                r#"%vector.source_id == "journald_in""#,
+                Ok(()),
+                Err("source execution resolved to false"),
+            ),
             (
                 log_event![],
                 r#""i_return_a_string""#,
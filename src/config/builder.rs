@@ -93,6 +93,7 @@ impl From<Config> for ConfigBuilder {
             transforms,
             tests,
             secret,
+            secrets: _,
             graceful_shutdown_duration,
         } = config;
 
@@ -3,8 +3,10 @@ use super::{
     OutputId,
 };
 
+use std::collections::HashSet;
+
 use indexmap::IndexSet;
-use vector_lib::id::Inputs;
+use vector_lib::{config::ComponentKey, id::Inputs};
 
 pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<String>> {
     let mut errors = Vec::new();
@@ -24,6 +26,8 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
 
     expand_globs(&mut builder);
 
+    let disabled_warnings = remove_disabled_components(&mut builder);
+
     if let Err(type_errors) = validation::check_shape(&builder) {
         errors.extend(type_errors);
     }
@@ -103,12 +107,14 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
             transforms,
             tests,
             secret,
+            secrets: Default::default(),
             graceful_shutdown_duration,
         };
 
         config.propagate_acknowledgements()?;
 
-        let warnings = validation::warnings(&config);
+        let mut warnings = disabled_warnings;
+        warnings.extend(validation::warnings(&config));
 
         Ok((config, warnings))
     } else {
@@ -116,6 +122,85 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
     }
 }
 
+/// Removes disabled components, along with the references to them in the inputs of other
+/// components.
+///
+/// Globs have to be expanded beforehand, so that they don't leave a dangling input once the
+/// components they matched are removed. Transforms and sinks whose inputs all referred to disabled
+/// components would never receive any events, so they are disabled as well, with a warning.
+fn remove_disabled_components(builder: &mut ConfigBuilder) -> Vec<String> {
+    let mut disabled = builder
+        .sources
+        .iter()
+        .filter(|(_, source)| !source.enabled)
+        .map(|(key, _)| key.clone())
+        .chain(
+            builder
+                .transforms
+                .iter()
+                .filter(|(_, transform)| !transform.enabled)
+                .map(|(key, _)| key.clone()),
+        )
+        .chain(
+            builder
+                .sinks
+                .iter()
+                .filter(|(_, sink)| !sink.enabled)
+                .map(|(key, _)| key.clone()),
+        )
+        .collect::<HashSet<_>>();
+    let mut warnings = Vec::new();
+
+    while !disabled.is_empty() {
+        builder.sources.retain(|key, _| !disabled.contains(key));
+        builder.transforms.retain(|key, _| !disabled.contains(key));
+        builder.sinks.retain(|key, _| !disabled.contains(key));
+
+        let mut newly_disabled = HashSet::new();
+        for (key, transform) in builder.transforms.iter_mut() {
+            if remove_disabled_inputs(&mut transform.inputs, &disabled) {
+                warnings.push(format!(
+                    "Transform \"{key}\" is disabled, as all of its inputs are disabled."
+                ));
+                newly_disabled.insert(key.clone());
+            }
+        }
+        for (key, sink) in builder.sinks.iter_mut() {
+            if remove_disabled_inputs(&mut sink.inputs, &disabled) {
+                warnings.push(format!(
+                    "Sink \"{key}\" is disabled, as all of its inputs are disabled."
+                ));
+                newly_disabled.insert(key.clone());
+            }
+        }
+
+        disabled = newly_disabled;
+    }
+
+    warnings
+}
+
+/// Removes the inputs referring to the outputs of disabled components, returning whether or not
+/// all of them did.
+fn remove_disabled_inputs(inputs: &mut Inputs<String>, disabled: &HashSet<ComponentKey>) -> bool {
+    let is_disabled = |input: &String| {
+        disabled.iter().any(|key| {
+            input
+                .strip_prefix(key.id())
+                .is_some_and(|port| port.is_empty() || port.starts_with('.'))
+        })
+    };
+    if !inputs.iter().any(is_disabled) {
+        return false;
+    }
+
+    *inputs = std::mem::take(inputs)
+        .into_iter()
+        .filter(|input| !is_disabled(input))
+        .collect();
+    inputs.is_empty()
+}
+
 /// Expand globs in input lists
 pub(crate) fn expand_globs(config: &mut ConfigBuilder) {
     let candidates = config
@@ -247,6 +332,58 @@ mod test {
         );
     }
 
+    #[test]
+    fn disabled_components() {
+        let mut builder = ConfigBuilder::default();
+        builder.add_source("in1", basic_source().1);
+        builder.add_source("in2", basic_source().1);
+        builder.add_transform("t1", &["in1"], basic_transform("", 1.0));
+        builder.add_transform("t2", &["in*"], basic_transform("", 1.0));
+        builder.add_sink("out1", &["t1"], basic_sink(1).1);
+        builder.add_sink("out2", &["t1", "t2"], basic_sink(1).1);
+        builder.add_sink("out3", &["in2"], basic_sink(1).1);
+        builder
+            .sources
+            .get_mut(&ComponentKey::from("in1"))
+            .unwrap()
+            .enabled = false;
+        builder
+            .sinks
+            .get_mut(&ComponentKey::from("out3"))
+            .unwrap()
+            .enabled = false;
+
+        let (config, warnings) = compile(builder).expect("build should succeed");
+
+        assert!(!config.sources.contains_key(&ComponentKey::from("in1")));
+        assert!(!config.transforms.contains_key(&ComponentKey::from("t1")));
+        assert!(!config.sinks.contains_key(&ComponentKey::from("out1")));
+        assert!(!config.sinks.contains_key(&ComponentKey::from("out3")));
+        assert_eq!(
+            config
+                .transforms
+                .get(&ComponentKey::from("t2"))
+                .map(|item| without_ports(item.inputs.clone()))
+                .unwrap(),
+            vec![ComponentKey::from("in2")]
+        );
+        assert_eq!(
+            config
+                .sinks
+                .get(&ComponentKey::from("out2"))
+                .map(|item| without_ports(item.inputs.clone()))
+                .unwrap(),
+            vec![ComponentKey::from("t2")]
+        );
+        assert_eq!(
+            warnings[..2],
+            [
+                "Transform \"t1\" is disabled, as all of its inputs are disabled.",
+                "Sink \"out1\" is disabled, as all of its inputs are disabled.",
+            ]
+        );
+    }
+
     fn without_ports(outputs: Inputs<OutputId>) -> Vec<ComponentKey> {
         outputs
             .into_iter()
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use indexmap::IndexMap;
 
-use super::{ComponentKey, Config};
+use super::{ComponentKey, Config, OutputId, SinkOuter};
 
 #[derive(Debug)]
 pub struct ConfigDiff {
@@ -21,7 +21,8 @@ impl ConfigDiff {
         ConfigDiff {
             sources: Difference::new(&old.sources, &new.sources),
             transforms: Difference::new(&old.transforms, &new.transforms),
-            sinks: Difference::new(&old.sinks, &new.sinks),
+            sinks: Difference::new(&old.sinks, &new.sinks)
+                .with_sink_updates(&old.sinks, &new.sinks),
             enrichment_tables: Difference::new(&old.enrichment_tables, &new.enrichment_tables),
         }
     }
@@ -59,6 +60,9 @@ pub struct Difference {
     pub to_remove: HashSet<ComponentKey>,
     pub to_change: HashSet<ComponentKey>,
     pub to_add: HashSet<ComponentKey>,
+    /// Changed components that apply their new configuration while running, instead of being
+    /// rebuilt. Only sinks can be updated this way.
+    pub to_update: HashSet<ComponentKey>,
 }
 
 impl Difference {
@@ -92,9 +96,35 @@ impl Difference {
             to_remove,
             to_change,
             to_add,
+            to_update: HashSet::new(),
         }
     }
 
+    /// Moves the changed sinks whose changes are all to fields they can reload in place from
+    /// `to_change` to `to_update`.
+    fn with_sink_updates(
+        mut self,
+        old: &IndexMap<ComponentKey, SinkOuter<OutputId>>,
+        new: &IndexMap<ComponentKey, SinkOuter<OutputId>>,
+    ) -> Self {
+        let to_update = self
+            .to_change
+            .iter()
+            .filter(|&key| {
+                let reloadable = old[key].inner.reloadable_fields();
+                !reloadable.is_empty()
+                    && changed_fields(&old[key], &new[key])
+                        .iter()
+                        .all(|field| reloadable.contains(&field.as_str()))
+            })
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        self.to_change.retain(|key| !to_update.contains(key));
+        self.to_update = to_update;
+        self
+    }
+
     /// Checks whether or not any components are being changed or added.
     pub fn any_changed_or_added(&self) -> bool {
         !(self.to_change.is_empty() && self.to_add.is_empty())
@@ -142,3 +172,65 @@ impl Difference {
         self.to_change.iter().chain(self.to_remove.iter())
     }
 }
+
+/// Gets the top-level fields that differ between two configurations of a sink.
+///
+/// The fields of the sink itself are flattened into those of its outer configuration, so a change
+/// to its inputs, buffer or type is reported as well.
+fn changed_fields(old: &SinkOuter<OutputId>, new: &SinkOuter<OutputId>) -> Vec<String> {
+    let serde_json::Value::Object(old) = serde_json::to_value(old).unwrap() else {
+        unreachable!("sink configurations are maps")
+    };
+    let serde_json::Value::Object(new) = serde_json::to_value(new).unwrap() else {
+        unreachable!("sink configurations are maps")
+    };
+
+    old.keys()
+        .chain(new.keys().filter(|&field| !old.contains_key(field)))
+        .filter(|&field| old.get(field) != new.get(field))
+        .cloned()
+        .collect()
+}
+
+#[cfg(all(test, unix, feature = "sinks-journald"))]
+mod tests {
+    use super::*;
+    use crate::{
+        sinks::journald::{JournaldPriority, JournaldSinkConfig},
+        test_util::mock::basic_source,
+    };
+
+    fn config(sink: JournaldSinkConfig) -> Config {
+        let mut config = Config::builder();
+        config.add_source("in", basic_source().1);
+        config.add_sink("out", &["in"], sink);
+        config.build().unwrap()
+    }
+
+    #[test]
+    fn updates_sinks_with_reloadable_changes() {
+        let old = config(JournaldSinkConfig::default());
+        let new = config(JournaldSinkConfig {
+            default_priority: Some(JournaldPriority::Warning),
+            ..Default::default()
+        });
+
+        let diff = ConfigDiff::new(&old, &new);
+        assert!(diff.sinks.to_change.is_empty());
+        assert_eq!(diff.sinks.to_update, HashSet::from(["out".into()]));
+    }
+
+    #[test]
+    fn rebuilds_sinks_with_other_changes() {
+        let old = config(JournaldSinkConfig::default());
+        let new = config(JournaldSinkConfig {
+            default_priority: Some(JournaldPriority::Warning),
+            path: "/run/other/socket".into(),
+            ..Default::default()
+        });
+
+        let diff = ConfigDiff::new(&old, &new);
+        assert!(diff.sinks.to_update.is_empty());
+        assert_eq!(diff.sinks.to_change, HashSet::from(["out".into()]));
+    }
+}
@@ -2,6 +2,7 @@ use super::{
     schema, ComponentKey, DataType, OutputId, SinkOuter, SourceOuter, SourceOutput, TransformOuter,
     TransformOutput,
 };
+use crate::sinks::util::dropped::DROPPED;
 use indexmap::{set::IndexSet, IndexMap};
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
@@ -17,6 +18,8 @@ pub enum Node {
     },
     Sink {
         ty: DataType,
+        /// Whether the sink reroutes the events it fails to deliver to its `dropped` output.
+        reroute_dropped: bool,
     },
 }
 
@@ -40,8 +43,15 @@ impl fmt::Display for Node {
                 }
                 Ok(())
             }
-            Node::Sink { ty } => {
-                write!(f, "component_kind: sink\n  types: {ty}")
+            Node::Sink {
+                ty,
+                reroute_dropped,
+            } => {
+                write!(f, "component_kind: sink\n  types: {ty}")?;
+                if *reroute_dropped {
+                    write!(f, "\n  outputs:\n    {DROPPED}: {ty}")?;
+                }
+                Ok(())
             }
         }
     }
@@ -117,6 +127,7 @@ impl Graph {
                 id.clone(),
                 Node::Sink {
                     ty: config.inner.input().data_type(),
+                    reroute_dropped: config.reroute_dropped(),
                 },
             );
         }
@@ -190,7 +201,7 @@ impl Graph {
         match self.nodes[key] {
             Node::Source { .. } => panic!("no inputs on sources"),
             Node::Transform { in_ty, .. } => in_ty,
-            Node::Sink { ty } => ty,
+            Node::Sink { ty, .. } => ty,
         }
     }
 
@@ -198,8 +209,8 @@ impl Graph {
     ///
     /// # Panics
     ///
-    /// Will panic if the given id is not present in the graph or identifies a sink, which can't
-    /// have inputs.
+    /// Will panic if the given id is not present in the graph or identifies an output that
+    /// doesn't exist.
     fn get_output_type(&self, id: &OutputId) -> DataType {
         match &self.nodes[&id.component] {
             Node::Source { outputs } => outputs
@@ -212,7 +223,11 @@ impl Graph {
                 .find(|output| output.port == id.port)
                 .map(|output| output.ty)
                 .expect("output didn't exist"),
-            Node::Sink { .. } => panic!("no outputs on sinks"),
+            // The `dropped` output of a sink carries the events it received.
+            Node::Sink { ty, .. } => {
+                assert_eq!(id.port.as_deref(), Some(DROPPED), "output didn't exist");
+                *ty
+            }
         }
     }
 
@@ -297,7 +312,15 @@ impl Graph {
         self.nodes
             .iter()
             .flat_map(|(key, node)| match node {
-                Node::Sink { .. } => vec![],
+                Node::Sink {
+                    reroute_dropped, ..
+                } => {
+                    if *reroute_dropped {
+                        vec![OutputId::from((key, DROPPED.to_owned()))]
+                    } else {
+                        vec![]
+                    }
+                }
                 Node::Source { outputs } => outputs
                     .iter()
                     .map(|output| OutputId {
@@ -347,6 +370,10 @@ impl Graph {
         }
     }
 
+    pub fn is_sink(&self, key: &ComponentKey) -> bool {
+        matches!(self.nodes.get(key), Some(Node::Sink { .. }))
+    }
+
     pub fn inputs_for(&self, node: &ComponentKey) -> Vec<OutputId> {
         self.edges
             .iter()
@@ -389,7 +416,7 @@ impl Graph {
             .into_iter()
             .filter(|path| {
                 if let Some(key) = path.last() {
-                    matches!(self.nodes.get(key), Some(Node::Sink { ty: _ }))
+                    matches!(self.nodes.get(key), Some(Node::Sink { .. }))
                 } else {
                     false
                 }
@@ -463,7 +490,13 @@ mod test {
         fn add_sink(&mut self, id: &str, ty: DataType, inputs: Vec<&str>) {
             let id = ComponentKey::from(id);
             let inputs = clean_inputs(inputs);
-            self.nodes.insert(id.clone(), Node::Sink { ty });
+            self.nodes.insert(
+                id.clone(),
+                Node::Sink {
+                    ty,
+                    reroute_dropped: false,
+                },
+            );
             for from in inputs {
                 self.edges.push(Edge {
                     from,
@@ -472,6 +505,15 @@ mod test {
             }
         }
 
+        fn reroute_dropped(&mut self, id: &str) {
+            match self.nodes.get_mut(&id.into()) {
+                Some(Node::Sink {
+                    reroute_dropped, ..
+                }) => *reroute_dropped = true,
+                _ => panic!("invalid sink"),
+            }
+        }
+
         fn test_add_input(&mut self, node: &str, input: &str) -> Result<(), String> {
             let available_inputs = self.input_map().unwrap();
             self.add_input(input, &node.into(), &available_inputs)
@@ -666,6 +708,55 @@ mod test {
         );
     }
 
+    #[test]
+    fn allows_sink_dropped_outputs() {
+        let mut graph = Graph::default();
+        graph.add_source("log_source", DataType::Log);
+        graph.add_sink("log_sink", DataType::Log, vec!["log_source"]);
+        graph.add_sink("rejects_sink", DataType::Log, vec![]);
+        graph.add_sink("metric_sink", DataType::Metric, vec![]);
+
+        let expected =
+            "Input \"log_sink.dropped\" for sink \"rejects_sink\" doesn't match any components."
+                .to_string();
+        assert_eq!(
+            Err(expected),
+            graph.test_add_input("rejects_sink", "log_sink.dropped")
+        );
+
+        graph.reroute_dropped("log_sink");
+        assert_eq!(
+            Ok(()),
+            graph.test_add_input("rejects_sink", "log_sink.dropped")
+        );
+        assert_eq!(
+            Ok(()),
+            graph.test_add_input("metric_sink", "log_sink.dropped")
+        );
+        assert_eq!(
+            Err(vec![
+                "Data type mismatch between log_sink.dropped ([\"Log\"]) and metric_sink ([\"Metric\"])"
+                    .into()
+            ]),
+            graph.typecheck()
+        );
+    }
+
+    #[test]
+    fn detects_cycles_through_sink_dropped_outputs() {
+        let mut graph = Graph::default();
+        graph.add_source("in", DataType::Log);
+        graph.add_transform("one", DataType::Log, DataType::Log, vec!["in"]);
+        graph.add_sink("out", DataType::Log, vec!["one"]);
+        graph.reroute_dropped("out");
+        graph.test_add_input("one", "out.dropped").unwrap();
+
+        assert_eq!(
+            Err("Cyclic dependency detected in the chain [ out -> one ]".into()),
+            graph.check_for_cycles()
+        );
+    }
+
     #[test]
     fn disallows_ambiguous_inputs() {
         let mut graph = Graph::default();
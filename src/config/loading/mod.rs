@@ -133,24 +133,37 @@ pub fn load_from_paths(config_paths: &[ConfigPath]) -> Result<Config, Vec<String
 /// Loads a configuration from paths. Handle secret replacement and if a provider is present
 /// in the builder, the config is used as bootstrapping for a remote source. Otherwise,
 /// provider instantiation is skipped.
+///
+/// When reloading, the secrets of the `previous` configuration are reused for the backends
+/// reporting them unchanged, and kept for those failing to retrieve them again.
 pub async fn load_from_paths_with_provider_and_secrets(
     config_paths: &[ConfigPath],
     signal_handler: &mut signal::SignalHandler,
     allow_empty: bool,
+    previous: Option<&Config>,
 ) -> Result<Config, Vec<String>> {
     // Load secret backends first
     let mut secrets_backends_loader = load_secret_backends_from_paths(config_paths)?;
     // And then, if needed, retrieve secrets from configured backends
-    let mut builder = if secrets_backends_loader.has_secrets_to_retrieve() {
+    let (mut builder, resolved_secrets) = if secrets_backends_loader.has_secrets_to_retrieve() {
         debug!(message = "Secret placeholders found, retrieving secrets from configured backends.");
+        let default_secrets = ResolvedSecrets::default();
+        let previous_secrets = previous.map_or(&default_secrets, |config| &config.secrets);
         let resolved_secrets = secrets_backends_loader
-            .retrieve(&mut signal_handler.subscribe())
+            .retrieve(&mut signal_handler.subscribe(), previous_secrets)
             .await
             .map_err(|e| vec![e])?;
-        load_builder_from_paths_with_secrets(config_paths, resolved_secrets)?
+        let builder = load_builder_from_paths_with_secrets(
+            config_paths,
+            resolved_secrets.to_interpolation_map(),
+        )?;
+        (builder, resolved_secrets)
     } else {
         debug!(message = "No secret placeholder found, skipping secret resolution.");
-        load_builder_from_paths(config_paths)?
+        (
+            load_builder_from_paths(config_paths)?,
+            ResolvedSecrets::default(),
+        )
     };
 
     builder.allow_empty = allow_empty;
@@ -164,7 +177,8 @@ pub async fn load_from_paths_with_provider_and_secrets(
         debug!(message = "Provider configured.", provider = ?provider.get_component_name());
     }
 
-    let (new_config, build_warnings) = builder.build_with_warnings()?;
+    let (mut new_config, build_warnings) = builder.build_with_warnings()?;
+    new_config.secrets = resolved_secrets;
 
     validation::check_buffer_preconditions(&new_config).await?;
 
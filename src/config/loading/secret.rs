@@ -1,10 +1,10 @@
 use std::{
     collections::{HashMap, HashSet},
+    fmt,
     io::Read,
     sync::LazyLock,
 };
 
-use futures::TryFutureExt;
 use indexmap::IndexMap;
 use regex::{Captures, Regex};
 use serde::{Deserialize, Serialize};
@@ -37,6 +37,54 @@ pub(crate) struct SecretBackendOuter {
     pub(crate) secret: IndexMap<ComponentKey, SecretBackends>,
 }
 
+/// Secrets retrieved from the secret backends.
+///
+/// These are kept along with the configuration they were interpolated in, so that reloading the
+/// configuration can fall back to them when they can't be retrieved again.
+#[derive(Clone, Default)]
+pub struct ResolvedSecrets {
+    backends: HashMap<String, ResolvedBackend>,
+}
+
+#[derive(Clone)]
+struct ResolvedBackend {
+    secrets: HashMap<String, String>,
+}
+
+impl ResolvedSecrets {
+    /// Gets the secrets by their `<backend>.<key>` name, as they are interpolated.
+    pub(crate) fn to_interpolation_map(&self) -> HashMap<String, String> {
+        self.backends
+            .iter()
+            .flat_map(|(backend_name, backend)| {
+                backend
+                    .secrets
+                    .iter()
+                    .map(move |(k, v)| (format!("{backend_name}.{k}"), v.clone()))
+            })
+            .collect()
+    }
+
+    fn get(&self, backend_name: &str, keys: &HashSet<String>) -> Option<&ResolvedBackend> {
+        self.backends
+            .get(backend_name)
+            .filter(|backend| keys.iter().all(|k| backend.secrets.contains_key(k)))
+    }
+}
+
+// Secret values are never logged.
+impl fmt::Debug for ResolvedSecrets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.backends
+                    .iter()
+                    .map(|(name, backend)| (name, backend.secrets.keys().collect::<Vec<_>>())),
+            )
+            .finish()
+    }
+}
+
 /// Loader for secrets backends.
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct SecretBackendLoader {
@@ -52,11 +100,16 @@ impl SecretBackendLoader {
         }
     }
 
+    /// Retrieves the secrets referenced by the configuration.
+    ///
+    /// The `previous` secrets, retrieved when the configuration was last loaded, are kept, with an
+    /// error logged, when a backend fails to retrieve them again.
     pub(crate) async fn retrieve(
         &mut self,
         signal_rx: &mut signal::SignalRx,
-    ) -> Result<HashMap<String, String>, String> {
-        let mut secrets: HashMap<String, String> = HashMap::new();
+        previous: &ResolvedSecrets,
+    ) -> Result<ResolvedSecrets, String> {
+        let mut resolved = ResolvedSecrets::default();
 
         for (backend_name, keys) in &self.secret_keys {
             let backend = self.backends
@@ -64,22 +117,47 @@ impl SecretBackendLoader {
                 .ok_or_else(|| {
                     format!("Backend \"{backend_name}\" is required for secret retrieval but was not found in config.")
                 })?;
+            let previous = previous.get(backend_name, keys);
 
             debug!(message = "Retrieving secrets from a backend.", backend = ?backend_name, keys = ?keys);
-            let backend_secrets = backend
-                .retrieve(keys.clone(), signal_rx)
-                .map_err(|e| {
-                    format!("Error while retrieving secret from backend \"{backend_name}\": {e}.",)
-                })
-                .await?;
+            let backend_secrets = match backend.retrieve(keys.clone(), signal_rx).await {
+                Ok(backend_secrets) => backend_secrets,
+                Err(error) => {
+                    let Some(previous) = previous else {
+                        return Err(format!(
+                            "Error while retrieving secret from backend \"{backend_name}\": {error}."
+                        ));
+                    };
+                    error!(
+                        message = "Error while retrieving secrets, keeping their previous values.",
+                        backend = ?backend_name,
+                        %error,
+                    );
+                    resolved
+                        .backends
+                        .insert(backend_name.clone(), previous.clone());
+                    continue;
+                }
+            };
 
-            for (k, v) in backend_secrets {
+            for k in backend_secrets.keys() {
                 trace!(message = "Successfully retrieved a secret.", backend = ?backend_name, key = ?k);
-                secrets.insert(format!("{backend_name}.{k}"), v);
+                if previous
+                    .and_then(|previous| previous.secrets.get(k))
+                    .is_some_and(|value| value != &backend_secrets[k])
+                {
+                    info!(message = "Secret changed, components referencing it will be reloaded.", backend = ?backend_name, key = ?k);
+                }
             }
+            resolved.backends.insert(
+                backend_name.clone(),
+                ResolvedBackend {
+                    secrets: backend_secrets,
+                },
+            );
         }
 
-        Ok(secrets)
+        Ok(resolved)
     }
 
     pub(crate) fn has_secrets_to_retrieve(&self) -> bool {
@@ -151,11 +229,46 @@ pub fn interpolate(input: &str, secrets: &HashMap<String, String>) -> Result<Str
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::{
+        collections::{HashMap, HashSet},
+        fs,
+    };
 
+    use indexmap::IndexMap;
+    #[cfg(all(unix, feature = "sources-demo_logs", feature = "sinks-blackhole"))]
+    use indoc::formatdoc;
     use indoc::indoc;
+    use vector_lib::config::ComponentKey;
+
+    use super::{collect_secret_keys, interpolate, ResolvedSecrets, SecretBackendLoader};
+    use crate::secrets::SecretBackends;
+    #[cfg(all(unix, feature = "sources-demo_logs", feature = "sinks-blackhole"))]
+    use crate::{
+        config::{load_from_paths_with_provider_and_secrets, Config, ConfigDiff, ConfigPath},
+        signal::SignalHandler,
+    };
+
+    fn loader(backend: &str) -> SecretBackendLoader {
+        SecretBackendLoader {
+            backends: IndexMap::from([(
+                ComponentKey::from("backend"),
+                toml::from_str::<SecretBackends>(backend).unwrap(),
+            )]),
+            secret_keys: HashMap::from([("backend".to_owned(), HashSet::from(["key".to_owned()]))]),
+        }
+    }
+
+    async fn retrieve(
+        backend: &str,
+        previous: &ResolvedSecrets,
+    ) -> Result<ResolvedSecrets, String> {
+        let (_tx, mut signal_rx) = tokio::sync::broadcast::channel(1);
+        loader(backend).retrieve(&mut signal_rx, previous).await
+    }
 
-    use super::{collect_secret_keys, interpolate};
+    fn secret(secrets: &ResolvedSecrets) -> String {
+        secrets.to_interpolation_map()["backend.key"].clone()
+    }
 
     #[test]
     fn replacement() {
@@ -243,4 +356,108 @@ mod tests {
         assert_eq!(first_backend_keys.len(), 1);
         assert!(first_backend_keys.contains("secret_key"));
     }
+
+    // The exec backend blocks on its command, which needs another worker to drive it.
+    #[cfg(unix)]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn reload_retrieves_changed_secrets() {
+        let dir = tempfile::tempdir().unwrap();
+        let response = dir.path().join("response.json");
+        let backend = format!(
+            r#"
+            type = "exec"
+            command = ["sh", "-c", "cat {}"]
+            "#,
+            response.display()
+        );
+        let write_response = |value: &str| {
+            fs::write(&response, format!(r#"{{"key": {{"value": "{value}"}}}}"#)).unwrap();
+        };
+
+        write_response("first");
+        let first = retrieve(&backend, &ResolvedSecrets::default())
+            .await
+            .unwrap();
+        assert_eq!(secret(&first), "first");
+
+        write_response("second");
+        let second = retrieve(&backend, &first).await.unwrap();
+        assert_eq!(secret(&second), "second");
+
+        // Failures to retrieve secrets again keep their previous values.
+        fs::remove_file(&response).unwrap();
+        let third = retrieve(&backend, &second).await.unwrap();
+        assert_eq!(secret(&third), "second");
+
+        // Without previous values, failures are still errors.
+        assert!(retrieve(&backend, &ResolvedSecrets::default())
+            .await
+            .is_err());
+    }
+
+    // Components are rebuilt on reload when their configuration differs, which changed secrets
+    // make it do.
+    #[cfg(all(unix, feature = "sources-demo_logs", feature = "sinks-blackhole"))]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn reload_changes_components_referencing_changed_secrets() {
+        let dir = tempfile::tempdir().unwrap();
+        let response = dir.path().join("response.json");
+        let write_response = |value: &str| {
+            fs::write(&response, format!(r#"{{"key": {{"value": "{value}"}}}}"#)).unwrap();
+        };
+        let config = dir.path().join("vector.toml");
+        fs::write(
+            &config,
+            formatdoc! {r#"
+                [secret.backend]
+                type = "exec"
+                command = ["sh", "-c", "cat {}"]
+
+                [sources.with_secret]
+                type = "demo_logs"
+                format = "shuffle"
+                lines = ["SECRET[backend.key]"]
+
+                [sources.without_secret]
+                type = "demo_logs"
+                format = "shuffle"
+                lines = ["static"]
+
+                [sinks.out]
+                type = "blackhole"
+                inputs = ["with_secret", "without_secret"]
+            "#, response.display()},
+        )
+        .unwrap();
+        let config_paths = [ConfigPath::File(config, None)];
+        async fn load(config_paths: &[ConfigPath], previous: Option<&Config>) -> Config {
+            let (mut signal_handler, _signal_rx) = SignalHandler::new();
+            load_from_paths_with_provider_and_secrets(
+                config_paths,
+                &mut signal_handler,
+                false,
+                previous,
+            )
+            .await
+            .unwrap()
+        }
+
+        write_response("first");
+        let first = load(&config_paths, None).await;
+
+        write_response("second");
+        let second = load(&config_paths, Some(&first)).await;
+        let diff = ConfigDiff::new(&first, &second);
+        assert_eq!(
+            diff.sources.to_change,
+            HashSet::from([ComponentKey::from("with_secret")])
+        );
+        assert!(diff.sinks.to_change.is_empty());
+
+        // Unchanged secrets don't change any component.
+        let third = load(&config_paths, Some(&second)).await;
+        let diff = ConfigDiff::new(&second, &third);
+        assert!(diff.sources.to_change.is_empty());
+        assert!(diff.sinks.to_change.is_empty());
+    }
 }
@@ -49,12 +49,15 @@ pub use enrichment_table::{EnrichmentTableConfig, EnrichmentTableOuter};
 pub use format::{Format, FormatHint};
 pub use loading::{
     load, load_builder_from_paths, load_from_paths, load_from_paths_with_provider_and_secrets,
-    load_from_str, load_source_from_paths, merge_path_lists, process_paths, COLLECTOR,
-    CONFIG_PATHS,
+    load_from_str, load_source_from_paths, merge_path_lists, process_paths, ResolvedSecrets,
+    COLLECTOR, CONFIG_PATHS,
 };
 pub use provider::ProviderConfig;
 pub use secret::SecretBackend;
-pub use sink::{BoxedSink, SinkConfig, SinkContext, SinkHealthcheckOptions, SinkOuter};
+pub use sink::{
+    BoxedSink, EncodableSink, PayloadEncoder, SinkConfig, SinkContext, SinkHealthcheckOptions,
+    SinkOuter, SinkUpdater, SinkUpdates,
+};
 pub use source::{BoxedSource, SourceConfig, SourceContext, SourceOuter};
 pub use transform::{
     get_transform_output_ids, BoxedTransform, TransformConfig, TransformContext, TransformOuter,
@@ -64,8 +67,8 @@ pub use validation::warnings;
 pub use vars::{interpolate, ENVIRONMENT_VARIABLE_INTERPOLATION_REGEX};
 pub use vector_lib::{
     config::{
-        init_log_schema, init_telemetry, log_schema, proxy::ProxyConfig, telemetry, ComponentKey,
-        LogSchema, OutputId,
+        init_log_schema, init_telemetry, init_timestamp_settings, log_schema, proxy::ProxyConfig,
+        telemetry, ComponentKey, LogSchema, OutputId,
     },
     id::Inputs,
 };
@@ -107,6 +110,9 @@ pub struct Config {
     pub enrichment_tables: IndexMap<ComponentKey, EnrichmentTableOuter>,
     tests: Vec<TestDefinition>,
     secret: IndexMap<ComponentKey, SecretBackends>,
+    /// The secrets interpolated in this configuration, kept to reload it.
+    #[serde(skip)]
+    pub(crate) secrets: ResolvedSecrets,
     pub graceful_shutdown_duration: Option<Duration>,
 }
 
@@ -190,12 +196,22 @@ impl Config {
                     .map(|input| (sink.clone(), input.clone()))
                     .collect();
                 self.propagate_acks_rec(inputs);
+            } else if let Some(upstream) = self.sinks.get(component) {
+                // Events rerouted to the `dropped` output of a sink are acknowledged by the sinks
+                // consuming it.
+                let inputs = upstream
+                    .inputs
+                    .iter()
+                    .map(|input| (sink.clone(), input.clone()))
+                    .collect();
+                self.propagate_acks_rec(inputs);
             }
         }
     }
 }
 
 /// Healthcheck options.
+#[serde_with::serde_as]
 #[configurable_component]
 #[derive(Clone, Copy, Debug)]
 #[serde(default)]
@@ -211,6 +227,21 @@ pub struct HealthcheckOptions {
     ///
     /// Can be alternatively set, and overridden by, the `--require-healthy` command-line flag.
     pub require_healthy: bool,
+
+    /// Whether or not a sink reporting as degraded passes its healthcheck.
+    ///
+    /// A degraded sink is functional but not in its nominal state, such as when it can only reach
+    /// some of its endpoints. When disabled, degraded sinks are treated as unhealthy.
+    pub allow_degraded: bool,
+
+    /// The maximum amount of time to wait for the healthchecks of all sinks, on startup and reload.
+    ///
+    /// Healthchecks run concurrently, and the ones that have not completed by then are treated as
+    /// failed. The timeout of each sink's healthcheck still applies.
+    #[serde_as(as = "Option<serde_with::DurationSecondsWithFrac<f64>>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[configurable(metadata(docs::human_name = "Deadline"))]
+    pub deadline_secs: Option<Duration>,
 }
 
 impl HealthcheckOptions {
@@ -223,6 +254,12 @@ impl HealthcheckOptions {
     fn merge(&mut self, other: Self) {
         self.enabled &= other.enabled;
         self.require_healthy |= other.require_healthy;
+        self.allow_degraded &= other.allow_degraded;
+        self.deadline_secs = self
+            .deadline_secs
+            .into_iter()
+            .chain(other.deadline_secs)
+            .min();
     }
 }
 
@@ -231,6 +268,8 @@ impl Default for HealthcheckOptions {
         Self {
             enabled: true,
             require_healthy: false,
+            allow_degraded: true,
+            deadline_secs: None,
         }
     }
 }
@@ -370,15 +409,42 @@ impl TestDefinition<String> {
                 let TestOutput {
                     extract_from,
                     conditions,
+                    ..
                 } = old;
 
                 (extract_from.to_vec(), conditions)
             })
             .filter_map(|(extract_from, conditions)| {
+                // Extracting from a sink checks the payloads it would send for the events
+                // reaching it, so the events are extracted from its inputs.
+                if let [from] = extract_from.as_slice() {
+                    let key = ComponentKey::from(from.as_str());
+                    if graph.is_sink(&key) {
+                        let outputs = graph.inputs_for(&key);
+                        if outputs.is_empty() {
+                            errors.push(format!(
+                                r#"Invalid extract_from target in test '{}': sink '{}' has no inputs"#,
+                                name, from
+                            ));
+                            return None;
+                        }
+                        return Some(TestOutput {
+                            extract_from: outputs.into(),
+                            conditions,
+                            encoded_by: Some(key),
+                        });
+                    }
+                }
+
                 let mut outputs = Vec::new();
                 for from in extract_from {
                     if let Some(output_id) = output_map.get(&from) {
                         outputs.push(output_id.clone());
+                    } else if graph.is_sink(&ComponentKey::from(from.as_str())) {
+                        errors.push(format!(
+                            r#"Invalid extract_from target in test '{}': sink '{}' must be the only target"#,
+                            name, from
+                        ));
                     } else {
                         errors.push(format!(
                             r#"Invalid extract_from target in test '{}': '{}' does not exist"#,
@@ -392,6 +458,7 @@ impl TestDefinition<String> {
                     Some(TestOutput {
                         extract_from: outputs.into(),
                         conditions,
+                        encoded_by: None,
                     })
                 }
             })
@@ -439,14 +506,18 @@ impl TestDefinition<OutputId> {
         let outputs = outputs
             .into_iter()
             .map(|old| TestOutput {
-                extract_from: old
-                    .extract_from
-                    .to_vec()
-                    .into_iter()
-                    .map(|item| item.to_string())
-                    .collect::<Vec<_>>()
-                    .into(),
+                extract_from: match &old.encoded_by {
+                    Some(sink) => vec![sink.to_string()].into(),
+                    None => old
+                        .extract_from
+                        .to_vec()
+                        .into_iter()
+                        .map(|item| item.to_string())
+                        .collect::<Vec<_>>()
+                        .into(),
+                },
                 conditions: old.conditions,
+                encoded_by: old.encoded_by,
             })
             .collect();
 
@@ -532,10 +603,19 @@ fn default_test_input_type() -> String {
 #[serde(deny_unknown_fields)]
 pub struct TestOutput<T: 'static = OutputId> {
     /// The transform outputs to extract events from.
+    ///
+    /// A sink can also be extracted from, on its own, to check the payloads it would send for the
+    /// events reaching it. Each payload is then exposed to the conditions as the `message` field
+    /// of a log event. Only sinks whose encoding can be built apart from their transport support
+    /// this.
     pub extract_from: OneOrMany<T>,
 
     /// The conditions to run against the output to validate that they were transformed as expected.
     pub conditions: Option<Vec<conditions::AnyCondition>>,
+
+    /// The sink whose payloads are checked, when extracting from a sink.
+    #[serde(skip)]
+    pub encoded_by: Option<ComponentKey>,
 }
 
 #[cfg(all(test, feature = "sources-file", feature = "sinks-console"))]
@@ -756,6 +836,29 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn unsupported_reroute_dropped() {
+        let errors = load(
+            r#"
+            [sources.in]
+            type = "test_basic"
+
+            [sinks.out]
+            type = "test_basic"
+            inputs = ["in"]
+            reroute_dropped = true
+            "#,
+            Format::Toml,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec!["Sink out does not support `reroute_dropped`: it has no `dropped` output"]
+        )
+    }
+
     #[tokio::test]
     async fn cycle() {
         let errors = load(
@@ -1,8 +1,10 @@
-use std::cell::RefCell;
+use std::{cell::RefCell, sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use dyn_clone::DynClone;
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_with::serde_as;
+use tokio::sync::watch;
 use vector_lib::buffers::{BufferConfig, BufferType};
 use vector_lib::configurable::attributes::CustomAttribute;
 use vector_lib::configurable::schema::{SchemaGenerator, SchemaObject};
@@ -16,11 +18,18 @@ use vector_lib::{
 };
 
 use super::{dot_graph::GraphConfig, schema, ComponentKey, ProxyConfig, Resource};
+use crate::event::Event;
 use crate::extra_context::ExtraContext;
-use crate::sinks::{util::UriSerde, Healthcheck};
+use crate::sinks::{
+    util::{encoding::Encoder, DroppedOutput, UriSerde},
+    Healthcheck, HealthcheckBuilder,
+};
 
 pub type BoxedSink = Box<dyn SinkConfig>;
 
+/// Encodes each event into the payload a sink would send for it.
+pub type PayloadEncoder = Box<dyn Encoder<Event> + Send + Sync>;
+
 impl Configurable for BoxedSink {
     fn referenceable_name() -> Option<&'static str> {
         Some("vector::sinks::Sinks")
@@ -46,6 +55,7 @@ impl<T: SinkConfig + 'static> From<T> for BoxedSink {
 }
 
 /// Fully resolved sink component.
+#[serde_as]
 #[configurable_component]
 #[configurable(metadata(docs::component_base_type = "sink"))]
 #[derive(Clone, Debug)]
@@ -53,6 +63,17 @@ pub struct SinkOuter<T>
 where
     T: Configurable + Serialize + 'static,
 {
+    /// Whether or not the component is enabled.
+    ///
+    /// Disabled components are left out of the topology, as if they were not configured, and
+    /// references to them in the `inputs` of other components are ignored.
+    #[serde(
+        default = "crate::serde::default_true",
+        skip_serializing_if = "crate::serde::is_true"
+    )]
+    #[configurable(metadata(docs::advanced))]
+    pub enabled: bool,
+
     #[configurable(derived)]
     #[serde(default, skip_serializing_if = "vector_lib::serde::is_default")]
     pub graph: GraphConfig,
@@ -79,6 +100,30 @@ where
     #[serde(default, skip_serializing_if = "vector_lib::serde::is_default")]
     proxy: ProxyConfig,
 
+    /// The maximum amount of time to wait for the sink to finish once Vector begins shutting down.
+    ///
+    /// When exceeded, the sink is killed, and the events it has not acknowledged yet are reported
+    /// to their sources as errored. By default, the sink is given as long as the global graceful
+    /// shutdown limit.
+    ///
+    /// This can only shorten the time the sink is given: once the global graceful shutdown limit
+    /// is exceeded, the sink is killed along with the rest of the topology, even if its own timeout
+    /// is longer.
+    #[serde_as(as = "Option<serde_with::DurationSecondsWithFrac<f64>>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[configurable(metadata(docs::advanced, docs::human_name = "Graceful Shutdown Timeout"))]
+    graceful_shutdown_timeout_secs: Option<Duration>,
+
+    /// Reroutes the events the sink fails to deliver to the `dropped` output, instead of
+    /// discarding them.
+    ///
+    /// Rerouted events are annotated with the reason they failed and the sink that rerouted them,
+    /// and are acknowledged once the components consuming the `dropped` output deliver them. Only
+    /// supported by some sinks.
+    #[serde(default, skip_serializing_if = "vector_lib::serde::is_default")]
+    #[configurable(metadata(docs::advanced))]
+    reroute_dropped: bool,
+
     #[serde(flatten)]
     #[configurable(metadata(docs::hidden))]
     pub inner: BoxedSink,
@@ -94,12 +139,15 @@ where
         IS: Into<BoxedSink>,
     {
         SinkOuter {
+            enabled: true,
             inputs: Inputs::from_iter(inputs),
             buffer: Default::default(),
             healthcheck: SinkHealthcheckOptions::default(),
             healthcheck_uri: None,
             inner: inner.into(),
             proxy: Default::default(),
+            graceful_shutdown_timeout_secs: None,
+            reroute_dropped: false,
             graph: Default::default(),
         }
     }
@@ -137,6 +185,21 @@ where
         &self.proxy
     }
 
+    pub const fn graceful_shutdown_timeout(&self) -> Option<Duration> {
+        self.graceful_shutdown_timeout_secs
+    }
+
+    pub const fn reroute_dropped(&self) -> bool {
+        self.reroute_dropped
+    }
+
+    /// Sets the healthcheck options of the sink.
+    #[must_use]
+    pub fn with_healthcheck(mut self, healthcheck: impl Into<SinkHealthcheckOptions>) -> Self {
+        self.healthcheck = healthcheck.into();
+        self
+    }
+
     pub(super) fn map_inputs<U>(self, f: impl Fn(&T) -> U) -> SinkOuter<U>
     where
         U: Configurable + Serialize,
@@ -151,18 +214,22 @@ where
         U: Configurable + Serialize,
     {
         SinkOuter {
+            enabled: self.enabled,
             inputs: Inputs::from_iter(inputs),
             inner: self.inner,
             buffer: self.buffer,
             healthcheck: self.healthcheck,
             healthcheck_uri: self.healthcheck_uri,
             proxy: self.proxy,
+            graceful_shutdown_timeout_secs: self.graceful_shutdown_timeout_secs,
+            reroute_dropped: self.reroute_dropped,
             graph: self.graph,
         }
     }
 }
 
 /// Healthcheck configuration.
+#[serde_as]
 #[configurable_component]
 #[derive(Clone, Debug)]
 #[serde(default)]
@@ -176,6 +243,41 @@ pub struct SinkHealthcheckOptions {
     /// components -- port, path, etc -- are allowed as well.
     #[configurable(validation(format = "uri"))]
     pub uri: Option<UriSerde>,
+
+    /// The amount of time to wait for each healthcheck attempt to complete.
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Timeout"))]
+    pub timeout_secs: Duration,
+
+    /// The number of times to retry a failed or timed out healthcheck.
+    pub retries: u32,
+
+    /// The amount of time to wait between healthcheck retries.
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Retry Backoff"))]
+    pub retry_backoff_secs: Duration,
+
+    /// The amount of time to wait after the sink is built before running its first healthcheck.
+    ///
+    /// This is useful for sinks whose endpoint only becomes reachable shortly after Vector starts,
+    /// such as a sidecar. The delay counts towards the global `healthchecks.deadline_secs` option.
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Initial Delay"))]
+    pub initial_delay_secs: Duration,
+
+    /// The interval at which to re-run the healthcheck once the sink has started.
+    ///
+    /// By default, the healthcheck only runs when the sink starts. When set, the latest result is
+    /// reported through the API, and a change in health emits an internal event.
+    #[serde_as(as = "Option<serde_with::DurationSecondsWithFrac<f64>>")]
+    #[configurable(metadata(docs::human_name = "Interval"))]
+    pub interval_secs: Option<Duration>,
+
+    /// Whether or not to require the sink to report as being healthy during startup.
+    ///
+    /// Overrides the global `healthchecks.require_healthy` option, and the `--require-healthy`
+    /// command-line flag, for this sink. This applies to configuration reloads as well.
+    pub require_healthy: Option<bool>,
 }
 
 impl Default for SinkHealthcheckOptions {
@@ -183,21 +285,30 @@ impl Default for SinkHealthcheckOptions {
         Self {
             enabled: true,
             uri: None,
+            timeout_secs: Duration::from_secs(10),
+            retries: 0,
+            retry_backoff_secs: Duration::from_secs(1),
+            initial_delay_secs: Duration::ZERO,
+            interval_secs: None,
+            require_healthy: None,
         }
     }
 }
 
 impl From<bool> for SinkHealthcheckOptions {
     fn from(enabled: bool) -> Self {
-        Self { enabled, uri: None }
+        Self {
+            enabled,
+            ..Default::default()
+        }
     }
 }
 
 impl From<UriSerde> for SinkHealthcheckOptions {
     fn from(uri: UriSerde) -> Self {
         Self {
-            enabled: true,
             uri: Some(uri),
+            ..Default::default()
         }
     }
 }
@@ -205,7 +316,7 @@ impl From<UriSerde> for SinkHealthcheckOptions {
 /// Generalized interface for describing and building sink components.
 #[async_trait]
 #[typetag::serde(tag = "type")]
-pub trait SinkConfig: DynClone + NamedComponent + core::fmt::Debug + Send + Sync {
+pub trait SinkConfig: DynClone + NamedComponent + core::fmt::Debug + Send + Sync + 'static {
     /// Builds the sink with the given context.
     ///
     /// If the sink is built successfully, `Ok(...)` is returned containing the sink and the sink's
@@ -217,6 +328,40 @@ pub trait SinkConfig: DynClone + NamedComponent + core::fmt::Debug + Send + Sync
     /// returned.
     async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)>;
 
+    /// Builds a builder of the healthcheck of this sink, if it can re-run its healthcheck.
+    ///
+    /// The builder is what retries and periodic runs of the healthcheck use. Without it, only the
+    /// healthcheck returned by `build` runs, once.
+    ///
+    /// By default, each healthcheck builds the sink again and only keeps its healthcheck. Sinks
+    /// that can run their healthcheck without being built again should override this.
+    ///
+    /// # Errors
+    ///
+    /// If an error occurs while building the healthcheck builder, an error variant explaining the
+    /// issue is returned.
+    async fn healthcheck_builder(
+        &self,
+        cx: &SinkContext,
+    ) -> crate::Result<Option<HealthcheckBuilder>> {
+        let config = Arc::new(dyn_clone::clone_box(self));
+        // The sink built for its healthcheck never runs, so it doesn't need the context the
+        // running sink is given.
+        let cx = SinkContext {
+            dropped_output: None,
+            updates: None,
+            ..cx.clone()
+        };
+        Ok(Some(Arc::new(move || -> Healthcheck {
+            let config = Arc::clone(&config);
+            let cx = cx.clone();
+            Box::pin(async move {
+                let (_sink, healthcheck) = config.build(cx).await?;
+                healthcheck.await
+            })
+        })))
+    }
+
     /// Gets the input configuration for this sink.
     fn input(&self) -> Input;
 
@@ -233,10 +378,45 @@ pub trait SinkConfig: DynClone + NamedComponent + core::fmt::Debug + Send + Sync
 
     /// Gets the acknowledgements configuration for this sink.
     fn acknowledgements(&self) -> &AcknowledgementsConfig;
+
+    /// Whether this sink can reroute the events it fails to deliver to a `dropped` output.
+    ///
+    /// Sinks that do must send those events to the `dropped_output` of their context, when set.
+    fn supports_dropped_output(&self) -> bool {
+        false
+    }
+
+    /// Gets the fields of this configuration that the running sink can update in place.
+    ///
+    /// When only these fields change on reload, the sink is not rebuilt. Instead, its new
+    /// configuration is sent to the `updates` of the context it was built with, which is only set
+    /// for sinks with reloadable fields.
+    fn reloadable_fields(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Gets this sink as an encodable sink, if its encoding can be built apart from its transport.
+    fn as_encodable(&self) -> Option<&dyn EncodableSink> {
+        None
+    }
 }
 
 dyn_clone::clone_trait_object!(SinkConfig);
 
+/// A sink whose encoding can be built apart from its transport.
+///
+/// This is what lets unit tests assert on the payloads a sink would send for the events reaching
+/// it, without a live endpoint to send them to.
+pub trait EncodableSink {
+    /// Builds the encoder of the sink.
+    ///
+    /// # Errors
+    ///
+    /// If the encoding of the sink is misconfigured, an error variant explaining the issue is
+    /// returned.
+    fn build_payload_encoder(&self) -> crate::Result<PayloadEncoder>;
+}
+
 #[derive(Clone)]
 pub struct SinkContext {
     pub healthcheck: SinkHealthcheckOptions,
@@ -246,6 +426,13 @@ pub struct SinkContext {
     pub app_name: String,
     pub app_name_slug: String,
 
+    /// The output events the sink fails to deliver are rerouted to, when `reroute_dropped` is
+    /// enabled.
+    pub dropped_output: Option<DroppedOutput>,
+
+    /// The new configurations of the sink, sent on reload when only its reloadable fields change.
+    pub updates: Option<SinkUpdates>,
+
     /// Extra context data provided by the running app and shared across all components. This can be
     /// used to pass shared settings or other data from outside the components.
     pub extra_context: ExtraContext,
@@ -260,6 +447,8 @@ impl Default for SinkContext {
             schema: Default::default(),
             app_name: crate::get_app_name().to_string(),
             app_name_slug: crate::get_slugified_app_name(),
+            dropped_output: None,
+            updates: None,
             extra_context: Default::default(),
         }
     }
@@ -274,3 +463,148 @@ impl SinkContext {
         &self.proxy
     }
 }
+
+/// Sends the new configurations of a running sink to it.
+#[derive(Debug)]
+pub struct SinkUpdater(watch::Sender<serde_json::Value>);
+
+impl SinkUpdater {
+    /// Creates the updater of a sink built from `config`, along with the updates it sends.
+    pub fn new(config: &dyn SinkConfig) -> (Self, SinkUpdates) {
+        let (tx, rx) = watch::channel(to_update(config));
+        (Self(tx), SinkUpdates(rx))
+    }
+
+    /// Sends a new configuration to the sink.
+    pub fn update(&self, config: &dyn SinkConfig) {
+        self.0.send_replace(to_update(config));
+    }
+}
+
+fn to_update(config: &dyn SinkConfig) -> serde_json::Value {
+    // Like when diffing configurations, trait objects go through their serialized form, without
+    // the tag naming the type of the sink.
+    let mut value = serde_json::to_value(config).expect("sink configurations are serializable");
+    if let Some(fields) = value.as_object_mut() {
+        fields.remove("type");
+    }
+    value
+}
+
+/// The new configurations of a running sink.
+#[derive(Clone, Debug)]
+pub struct SinkUpdates(watch::Receiver<serde_json::Value>);
+
+impl SinkUpdates {
+    /// Waits for the next configuration of the sink.
+    ///
+    /// Returns `None` once the sink is no longer updated, such as when it is removed or rebuilt.
+    pub async fn next<T: DeserializeOwned>(&mut self) -> Option<T> {
+        loop {
+            self.0.changed().await.ok()?;
+            let value = self.0.borrow_and_update().clone();
+            match serde_json::from_value(value) {
+                Ok(config) => return Some(config),
+                Err(error) => error!(message = "Invalid sink configuration update.", %error),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Outer {
+        #[serde(deserialize_with = "crate::serde::bool_or_struct")]
+        healthcheck: SinkHealthcheckOptions,
+    }
+
+    fn parse(config: &str) -> SinkHealthcheckOptions {
+        toml::from_str::<Outer>(config).unwrap().healthcheck
+    }
+
+    #[test]
+    fn parse_healthcheck_bool() {
+        let options = parse("healthcheck = false");
+        assert!(!options.enabled);
+        assert_eq!(options.timeout_secs, Duration::from_secs(10));
+        assert_eq!(options.retries, 0);
+    }
+
+    #[test]
+    fn parse_healthcheck_options() {
+        let options = parse(
+            r#"
+            [healthcheck]
+            timeout_secs = 2.5
+            retries = 3
+            retry_backoff_secs = 0.5
+            "#,
+        );
+        assert!(options.enabled);
+        assert_eq!(options.timeout_secs, Duration::from_millis(2500));
+        assert_eq!(options.retries, 3);
+        assert_eq!(options.retry_backoff_secs, Duration::from_millis(500));
+        assert_eq!(options.initial_delay_secs, Duration::ZERO);
+        assert_eq!(options.interval_secs, None);
+    }
+
+    #[test]
+    fn parse_healthcheck_initial_delay() {
+        let options = parse(
+            r#"
+            [healthcheck]
+            initial_delay_secs = 1.5
+            "#,
+        );
+        assert_eq!(options.initial_delay_secs, Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn parse_healthcheck_interval() {
+        let options = parse(
+            r#"
+            [healthcheck]
+            interval_secs = 30
+            "#,
+        );
+        assert_eq!(options.interval_secs, Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn parse_healthcheck_require_healthy() {
+        assert_eq!(parse("healthcheck = true").require_healthy, None);
+        let options = parse(
+            r#"
+            [healthcheck]
+            require_healthy = false
+            "#,
+        );
+        assert_eq!(options.require_healthy, Some(false));
+    }
+
+    #[tokio::test]
+    async fn default_healthcheck_builder_builds_the_sink_again() {
+        use crate::{
+            sinks::HealthcheckStatus,
+            test_util::mock::{basic_sink_failing_healthcheck, error_sink},
+        };
+
+        let cx = SinkContext::default();
+        let (_rx, failing) = basic_sink_failing_healthcheck(1);
+        let build = failing.healthcheck_builder(&cx).await.unwrap().unwrap();
+        assert!(build().await.is_err());
+        assert!(build().await.is_err());
+
+        let build = error_sink()
+            .healthcheck_builder(&cx)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(build().await.unwrap(), HealthcheckStatus::Healthy);
+    }
+}
@@ -50,6 +50,17 @@ impl<T: SourceConfig + 'static> From<T> for BoxedSource {
 #[configurable(metadata(docs::component_base_type = "source"))]
 #[derive(Clone, Debug)]
 pub struct SourceOuter {
+    /// Whether or not the component is enabled.
+    ///
+    /// Disabled components are left out of the topology, as if they were not configured, and
+    /// references to them in the `inputs` of other components are ignored.
+    #[serde(
+        default = "crate::serde::default_true",
+        skip_serializing_if = "crate::serde::is_true"
+    )]
+    #[configurable(metadata(docs::advanced))]
+    pub enabled: bool,
+
     #[configurable(derived)]
     #[serde(default, skip_serializing_if = "vector_lib::serde::is_default")]
     pub proxy: ProxyConfig,
@@ -69,6 +80,7 @@ pub struct SourceOuter {
 impl SourceOuter {
     pub(crate) fn new<I: Into<BoxedSource>>(inner: I) -> Self {
         Self {
+            enabled: true,
             proxy: Default::default(),
             graph: Default::default(),
             sink_acknowledgements: false,
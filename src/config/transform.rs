@@ -57,6 +57,17 @@ pub struct TransformOuter<T>
 where
     T: Configurable + Serialize + 'static,
 {
+    /// Whether or not the component is enabled.
+    ///
+    /// Disabled components are left out of the topology, as if they were not configured, and
+    /// references to them in the `inputs` of other components are ignored.
+    #[serde(
+        default = "crate::serde::default_true",
+        skip_serializing_if = "crate::serde::is_true"
+    )]
+    #[configurable(metadata(docs::advanced))]
+    pub enabled: bool,
+
     #[configurable(derived)]
     #[serde(default, skip_serializing_if = "vector_lib::serde::is_default")]
     pub graph: GraphConfig,
@@ -81,6 +92,7 @@ where
         let inputs = Inputs::from_iter(inputs);
         let inner = inner.into();
         TransformOuter {
+            enabled: true,
             inputs,
             inner,
             graph: Default::default(),
@@ -101,6 +113,7 @@ where
         U: Configurable + Serialize,
     {
         TransformOuter {
+            enabled: self.enabled,
             inputs: Inputs::from_iter(inputs),
             inner: self.inner,
             graph: self.graph,
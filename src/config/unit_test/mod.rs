@@ -31,8 +31,8 @@ use vrl::{
 };
 
 pub use self::unit_test_components::{
-    UnitTestSinkCheck, UnitTestSinkConfig, UnitTestSinkResult, UnitTestSourceConfig,
-    UnitTestStreamSinkConfig, UnitTestStreamSourceConfig,
+    UnitTestSinkCheck, UnitTestSinkConfig, UnitTestSinkEncoder, UnitTestSinkResult,
+    UnitTestSourceConfig, UnitTestStreamSinkConfig, UnitTestStreamSourceConfig,
 };
 use super::{compiler::expand_globs, graph::Graph, transform::get_transform_output_ids, OutputId};
 use crate::{
@@ -83,7 +83,8 @@ impl UnitTest {
     }
 }
 
-/// Loads Log Schema from configurations and sets global schema.
+/// Loads Log Schema from configurations and sets global schema, along with
+/// the global timestamp settings, so that outputs are encoded as they would be.
 /// Once this is done, configurations can be correctly loaded using
 /// configured log schema defaults.
 /// If deny is set, will panic if schema has already been set.
@@ -92,6 +93,7 @@ fn init_log_schema_from_paths(
     deny_if_set: bool,
 ) -> Result<(), Vec<String>> {
     let builder = config::loading::load_builder_from_paths(config_paths)?;
+    vector_lib::config::init_timestamp_settings(builder.global.timestamp_settings(), deny_if_set);
     vector_lib::config::init_log_schema(builder.global.log_schema, deny_if_set);
     Ok(())
 }
@@ -120,12 +122,12 @@ pub async fn build_unit_tests(
 ) -> Result<Vec<UnitTest>, Vec<String>> {
     // Sanitize config by removing existing sources and sinks
     config_builder.sources = Default::default();
-    config_builder.sinks = Default::default();
+    let sinks = std::mem::take(&mut config_builder.sinks);
 
     let test_definitions = std::mem::take(&mut config_builder.tests);
     let mut tests = Vec::new();
     let mut build_errors = Vec::new();
-    let metadata = UnitTestBuildMetadata::initialize(&mut config_builder)?;
+    let metadata = UnitTestBuildMetadata::initialize(&mut config_builder, sinks)?;
 
     for mut test_definition in test_definitions {
         let test_name = test_definition.name.clone();
@@ -163,10 +165,15 @@ pub struct UnitTestBuildMetadata {
     template_sources: IndexMap<ComponentKey, UnitTestSourceConfig>,
     // A mapping from transform name to unit test sink name.
     sink_ids: HashMap<OutputId, String>,
+    // The sinks of the original configuration, whose encoded output tests can check.
+    sinks: IndexMap<ComponentKey, SinkOuter<String>>,
 }
 
 impl UnitTestBuildMetadata {
-    pub fn initialize(config_builder: &mut ConfigBuilder) -> Result<Self, Vec<String>> {
+    pub fn initialize(
+        config_builder: &mut ConfigBuilder,
+        sinks: IndexMap<ComponentKey, SinkOuter<String>>,
+    ) -> Result<Self, Vec<String>> {
         // A unique id used to name test sources and sinks to avoid name clashes
         let random_id = Uuid::new_v4().to_string();
 
@@ -226,6 +233,7 @@ impl UnitTestBuildMetadata {
             source_ids,
             template_sources,
             sink_ids,
+            sinks,
         })
     }
 
@@ -285,20 +293,38 @@ impl UnitTestBuildMetadata {
 
         let mut template_sinks = IndexMap::new();
         let mut test_result_rxs = Vec::new();
+        let mut errors = Vec::new();
         // Add sinks with checks
-        for (ids, checks) in outputs {
+        for ((ids, encoded_by), checks) in outputs {
+            let encoder = match encoded_by {
+                Some(sink) => match self.build_sink_encoder(sink) {
+                    Ok(encoder) => Some(encoder),
+                    Err(error) => {
+                        errors.push(error);
+                        continue;
+                    }
+                },
+                None => None,
+            };
             let (tx, rx) = oneshot::channel();
-            let sink_ids = ids.clone();
+            let sink_ids = (
+                ids.clone(),
+                encoder.as_ref().map(|encoder| encoder.sink.clone()),
+            );
             let sink_config = UnitTestSinkConfig {
                 test_name: test_name.to_string(),
                 transform_ids: ids.iter().map(|id| id.to_string()).collect(),
                 result_tx: Arc::new(Mutex::new(Some(tx))),
                 check: UnitTestSinkCheck::Checks(checks),
+                encoder,
             };
 
             test_result_rxs.push(rx);
             template_sinks.insert(sink_ids, sink_config);
         }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
 
         // Add sinks with no outputs check
         for id in no_outputs_from {
@@ -308,15 +334,16 @@ impl UnitTestBuildMetadata {
                 transform_ids: vec![id.to_string()],
                 result_tx: Arc::new(Mutex::new(Some(tx))),
                 check: UnitTestSinkCheck::NoOutputs,
+                encoder: None,
             };
 
             test_result_rxs.push(rx);
-            template_sinks.insert(vec![id.clone()], sink_config);
+            template_sinks.insert((vec![id.clone()], None), sink_config);
         }
 
         let sinks = template_sinks
             .into_iter()
-            .map(|(transform_ids, sink_config)| {
+            .map(|((transform_ids, encoded_by), sink_config)| {
                 let transform_ids_str = transform_ids
                     .iter()
                     .map(|s| s.to_string())
@@ -330,7 +357,10 @@ impl UnitTestBuildMetadata {
                             .as_str()
                     })
                     .collect::<Vec<_>>();
-                let sink_id = sink_ids.join(",");
+                let mut sink_id = sink_ids.join(",");
+                if let Some(encoded_by) = encoded_by {
+                    sink_id = format!("{}-encoded-by-{}", sink_id, encoded_by);
+                }
                 (
                     ComponentKey::from(sink_id),
                     SinkOuter::new(transform_ids_str, sink_config),
@@ -340,6 +370,29 @@ impl UnitTestBuildMetadata {
 
         Ok((test_result_rxs, sinks))
     }
+
+    /// Builds the encoder of a sink of the original configuration, to check its encoded output.
+    fn build_sink_encoder(&self, key: ComponentKey) -> Result<UnitTestSinkEncoder, String> {
+        let sink = self
+            .sinks
+            .get(&key)
+            .ok_or_else(|| format!("unable to locate sink '{}'", key))?;
+        let encodable = sink.inner.as_encodable().ok_or_else(|| {
+            format!(
+                "sink '{}' of type '{}' does not support checking its encoded output",
+                key,
+                sink.inner.get_component_name()
+            )
+        })?;
+        let encoder = encodable
+            .build_payload_encoder()
+            .map_err(|error| format!("failed to build the encoder of sink '{}': {}", key, error))?;
+
+        Ok(UnitTestSinkEncoder {
+            sink: key,
+            encoder: encoder.into(),
+        })
+    }
 }
 
 // Find all components that participate in the test
@@ -380,11 +433,12 @@ async fn build_unit_test(
     test: TestDefinition<String>,
     mut config_builder: ConfigBuilder,
 ) -> Result<UnitTest, Vec<String>> {
+    // The original sinks are kept in this graph so that tests can extract from them.
     let transform_only_config = config_builder.clone();
     let transform_only_graph = Graph::new_unchecked(
         &transform_only_config.sources,
         &transform_only_config.transforms,
-        &transform_only_config.sinks,
+        &metadata.sinks,
         transform_only_config.schema,
     );
     let test = test.resolve_outputs(&transform_only_graph)?;
@@ -501,6 +555,7 @@ fn get_loose_end_outputs_sink(config: &ConfigBuilder) -> Option<SinkOuter<String
             transform_ids: vec![],
             result_tx: Arc::new(Mutex::new(None)),
             check: UnitTestSinkCheck::NoOp,
+            encoder: None,
         };
         Some(SinkOuter::new(loose_end_outputs, noop_sink))
     }
@@ -545,10 +600,13 @@ fn build_and_validate_inputs(
     }
 }
 
+/// The outputs a test extracts events from, along with the sink encoding them, if any.
+type ExtractTarget = (Vec<OutputId>, Option<ComponentKey>);
+
 fn build_outputs(
     test_outputs: &[TestOutput],
-) -> Result<IndexMap<Vec<OutputId>, Vec<Vec<Condition>>>, Vec<String>> {
-    let mut outputs: IndexMap<Vec<OutputId>, Vec<Vec<Condition>>> = IndexMap::new();
+) -> Result<IndexMap<ExtractTarget, Vec<Vec<Condition>>>, Vec<String>> {
+    let mut outputs: IndexMap<ExtractTarget, Vec<Vec<Condition>>> = IndexMap::new();
     let mut errors = Vec::new();
 
     for output in test_outputs {
@@ -570,7 +628,10 @@ fn build_outputs(
         }
 
         outputs
-            .entry(output.extract_from.clone().to_vec())
+            .entry((
+                output.extract_from.clone().to_vec(),
+                output.encoded_by.clone(),
+            ))
             .and_modify(|existing_conditions| existing_conditions.push(conditions.clone()))
             .or_insert(vec![conditions.clone()]);
     }
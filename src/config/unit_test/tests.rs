@@ -1277,3 +1277,115 @@ async fn test_glob_input() {
     let mut tests = build_unit_tests(config).await.unwrap();
     assert!(tests.remove(0).run().await.errors.is_empty());
 }
+
+#[tokio::test]
+async fn test_sink_encoded_output() {
+    crate::test_util::trace_init();
+
+    let config: ConfigBuilder = toml::from_str(indoc! {r#"
+        [sources.in]
+          type = "demo_logs"
+          format = "shuffle"
+          lines = ["ignored"]
+
+        [transforms.foo]
+          type = "remap"
+          inputs = ["in"]
+          source = '.level = "error"'
+
+        [sinks.out]
+          type = "console"
+          inputs = ["foo"]
+          encoding.codec = "json"
+          encoding.only_fields = ["level", "message"]
+
+        [[tests]]
+          name = "encoded output"
+
+          [[tests.inputs]]
+            type = "log"
+            insert_at = "foo"
+
+            [tests.inputs.log_fields]
+              message = "test1"
+
+          [[tests.outputs]]
+            extract_from = "out"
+
+            [[tests.outputs.conditions]]
+              type = "vrl"
+              source = """
+                assert_eq!(.message, s'{"level":"error","message":"test1"}' + "\\n")
+              """
+
+          [[tests.outputs]]
+            extract_from = "foo"
+
+            [[tests.outputs.conditions]]
+              type = "vrl"
+              source = """
+                assert_eq!(.message, "test1")
+              """
+
+        [[tests]]
+          name = "failing encoded output"
+
+          [[tests.inputs]]
+            type = "log"
+            insert_at = "foo"
+
+            [tests.inputs.log_fields]
+              message = "test1"
+
+          [[tests.outputs]]
+            extract_from = "out"
+
+            [[tests.outputs.conditions]]
+              type = "vrl"
+              source = """
+                assert_eq!(.level, "error")
+              """
+    "#})
+    .unwrap();
+
+    let mut tests = build_unit_tests(config).await.unwrap();
+    assert_eq!(tests.remove(0).run().await.errors, Vec::<String>::new());
+    assert!(!tests.remove(0).run().await.errors.is_empty());
+}
+
+#[tokio::test]
+async fn test_sink_extract_from_other_targets() {
+    crate::test_util::trace_init();
+
+    let config: ConfigBuilder = toml::from_str(indoc! {r#"
+        [transforms.foo]
+          type = "remap"
+          inputs = ["in"]
+          source = '.level = "error"'
+
+        [sinks.out]
+          type = "console"
+          inputs = ["foo"]
+          encoding.codec = "json"
+
+        [[tests]]
+          name = "broken test"
+
+          [[tests.inputs]]
+            insert_at = "foo"
+            value = "test1"
+
+          [[tests.outputs]]
+            extract_from = ["foo", "out"]
+    "#})
+    .unwrap();
+
+    let errs = build_unit_tests(config).await.err().unwrap();
+    assert_eq!(
+        errs,
+        vec![indoc! {r"
+            Failed to build test 'broken test':
+              Invalid extract_from target in test 'broken test': sink 'out' must be the only target"}
+        .to_owned(),]
+    );
+}
@@ -1,12 +1,13 @@
 use std::sync::Arc;
 
+use bytes::Bytes;
 use futures::{stream, Sink, Stream};
-use futures_util::{future, stream::BoxStream, FutureExt, StreamExt};
+use futures_util::{stream::BoxStream, StreamExt};
 use tokio::sync::{oneshot, Mutex};
 use vector_lib::configurable::configurable_component;
 use vector_lib::{
     config::{DataType, Input, LogNamespace},
-    event::Event,
+    event::{Event, LogEvent},
     schema,
     sink::{StreamSink, VectorSink},
 };
@@ -14,9 +15,10 @@ use vector_lib::{
 use crate::{
     conditions::Condition,
     config::{
-        AcknowledgementsConfig, SinkConfig, SinkContext, SourceConfig, SourceContext, SourceOutput,
+        AcknowledgementsConfig, ComponentKey, SinkConfig, SinkContext, SourceConfig, SourceContext,
+        SourceOutput,
     },
-    sinks::Healthcheck,
+    sinks::{healthy, util::encoding::Encoder, Healthcheck},
     sources,
 };
 
@@ -127,6 +129,33 @@ pub enum UnitTestSinkCheck {
     NoOp,
 }
 
+/// The encoder of a sink whose encoded output is checked, instead of the events reaching it.
+#[derive(Clone)]
+pub struct UnitTestSinkEncoder {
+    /// Name of the sink.
+    pub sink: ComponentKey,
+
+    /// Encoder of the sink.
+    pub encoder: Arc<dyn Encoder<Event> + Send + Sync>,
+}
+
+impl UnitTestSinkEncoder {
+    /// Encodes an event into a log event holding, as its message, the payload the sink would
+    /// send for it.
+    fn encode(&self, event: Event) -> Result<Event, String> {
+        let mut payload = Vec::new();
+        self.encoder
+            .encode_input(event, &mut payload)
+            .map_err(|error| {
+                format!(
+                    "failed to encode event with sink '{}': {}",
+                    self.sink, error
+                )
+            })?;
+        Ok(LogEvent::from(Bytes::from(payload)).into())
+    }
+}
+
 #[derive(Debug)]
 pub struct UnitTestSinkResult {
     pub test_name: String,
@@ -152,6 +181,11 @@ pub struct UnitTestSinkConfig {
     #[serde(skip)]
     #[derivative(Debug = "ignore")]
     pub check: UnitTestSinkCheck,
+
+    /// Encoder applied to each event that reaches the sink, before checking it.
+    #[serde(skip)]
+    #[derivative(Debug = "ignore")]
+    pub encoder: Option<UnitTestSinkEncoder>,
 }
 
 impl_generate_config_from_default!(UnitTestSinkConfig);
@@ -166,8 +200,9 @@ impl SinkConfig for UnitTestSinkConfig {
             transform_ids: self.transform_ids.clone(),
             result_tx: tx,
             check: self.check.clone(),
+            encoder: self.encoder.clone(),
         };
-        let healthcheck = future::ok(()).boxed();
+        let healthcheck = healthy();
 
         Ok((VectorSink::from_event_streamsink(sink), healthcheck))
     }
@@ -187,6 +222,7 @@ pub struct UnitTestSink {
     // None for NoOp test sinks
     pub result_tx: Option<oneshot::Sender<UnitTestSinkResult>>,
     pub check: UnitTestSinkCheck,
+    pub encoder: Option<UnitTestSinkEncoder>,
 }
 
 #[async_trait::async_trait]
@@ -199,7 +235,13 @@ impl StreamSink<Event> for UnitTestSink {
         };
 
         while let Some(event) = input.next().await {
-            output_events.push(event);
+            match &self.encoder {
+                Some(encoder) => match encoder.encode(event) {
+                    Ok(payload) => output_events.push(payload),
+                    Err(error) => result.test_errors.push(error),
+                },
+                None => output_events.push(event),
+            }
         }
 
         match self.check {
@@ -301,7 +343,7 @@ impl std::fmt::Debug for UnitTestStreamSinkConfig {
 impl SinkConfig for UnitTestStreamSinkConfig {
     async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
         let sink = self.sink.lock().await.take().unwrap();
-        let healthcheck = future::ok(()).boxed();
+        let healthcheck = healthy();
 
         #[allow(deprecated)]
         Ok((VectorSink::from_event_sink(sink), healthcheck))
@@ -1,4 +1,5 @@
 use crate::config::schema;
+use crate::sinks::util::dropped::DROPPED;
 use futures_util::{stream, FutureExt, StreamExt, TryFutureExt, TryStreamExt};
 use heim::{disk::Partition, units::information::byte};
 use indexmap::IndexMap;
@@ -187,6 +188,14 @@ pub fn check_outputs(config: &ConfigBuilder) -> Result<(), Vec<String>> {
         }
     }
 
+    for (key, sink) in config.sinks.iter() {
+        if sink.reroute_dropped() && !sink.inner.supports_dropped_output() {
+            errors.push(format!(
+                "Sink {key} does not support `reroute_dropped`: it has no `dropped` output"
+            ));
+        }
+    }
+
     if errors.is_empty() {
         Ok(())
     } else {
@@ -349,7 +358,13 @@ pub fn warnings(config: &Config) -> Vec<String> {
         .collect::<Vec<_>>()
     });
 
-    for (input_type, id) in transform_ids.chain(source_ids) {
+    let sink_ids = config
+        .sinks
+        .iter()
+        .filter(|(_, sink)| sink.reroute_dropped())
+        .map(|(key, _)| ("sink", OutputId::from((key, DROPPED.to_owned()))));
+
+    for (input_type, id) in transform_ids.chain(source_ids).chain(sink_ids) {
         if !config
             .transforms
             .iter()
@@ -367,6 +382,8 @@ pub fn warnings(config: &Config) -> Vec<String> {
         }
     }
 
+    warnings.extend(crate::topology::schema::sink_expectation_warnings(config));
+
     warnings
 }
 
@@ -0,0 +1,520 @@
+//! Handles enrichment tables for `type = cgroup`.
+//!
+//! Resolves process IDs to the cgroup, systemd unit and container that own them, by reading
+//! `/proc/<pid>/cgroup`.
+use std::{
+    num::NonZeroUsize,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use lru::LruCache;
+use serde_with::serde_as;
+use vector_lib::configurable::configurable_component;
+use vector_lib::enrichment::{Case, Condition, IndexHandle, Table};
+use vrl::value::{ObjectMap, Value};
+
+use crate::config::EnrichmentTableConfig;
+
+/// The suffixes of systemd unit types that can own processes.
+const UNIT_SUFFIXES: [&str; 5] = [".service", ".scope", ".socket", ".mount", ".swap"];
+
+/// Prefixes that container runtimes put in front of the container ID in scope unit names.
+const CONTAINER_PREFIXES: [&str; 4] = ["docker-", "cri-containerd-", "crio-", "libpod-"];
+
+/// Configuration for the `cgroup` enrichment table.
+#[serde_as]
+#[configurable_component(enrichment_table("cgroup"))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct CgroupConfig {
+    /// The root of the procfs filesystem to read process information from.
+    ///
+    /// This is useful when Vector runs in a container with the host's procfs mounted elsewhere.
+    #[serde(default = "default_procfs_root")]
+    #[configurable(metadata(docs::examples = "/host/proc"))]
+    pub procfs_root: PathBuf,
+
+    /// The amount of time, in seconds, that a lookup result is cached.
+    ///
+    /// Process IDs are recycled by the kernel, so this should be kept short.
+    #[serde(default = "default_cache_ttl_secs")]
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    #[configurable(metadata(docs::human_name = "Cache TTL"))]
+    pub cache_ttl_secs: Duration,
+
+    /// The maximum number of lookup results kept in the cache.
+    #[serde(default = "default_cache_size")]
+    pub cache_size: NonZeroUsize,
+}
+
+fn default_procfs_root() -> PathBuf {
+    PathBuf::from("/proc")
+}
+
+const fn default_cache_ttl_secs() -> Duration {
+    Duration::from_secs(5)
+}
+
+fn default_cache_size() -> NonZeroUsize {
+    NonZeroUsize::new(10_000).expect("static non-zero number")
+}
+
+impl Default for CgroupConfig {
+    fn default() -> Self {
+        Self {
+            procfs_root: default_procfs_root(),
+            cache_ttl_secs: default_cache_ttl_secs(),
+            cache_size: default_cache_size(),
+        }
+    }
+}
+
+impl_generate_config_from_default!(CgroupConfig);
+
+impl EnrichmentTableConfig for CgroupConfig {
+    async fn build(
+        &self,
+        _: &crate::config::GlobalOptions,
+    ) -> crate::Result<Box<dyn Table + Send + Sync>> {
+        if !cfg!(target_os = "linux") {
+            return Err("The cgroup enrichment table is only available on Linux systems".into());
+        }
+        Ok(Box::new(Cgroup::new(self.clone())))
+    }
+}
+
+#[derive(Clone)]
+struct CacheEntry {
+    inserted: Instant,
+    row: ObjectMap,
+}
+
+/// A struct that implements [vector_lib::enrichment::Table] to resolve process IDs to their
+/// cgroup and systemd unit.
+#[derive(Clone)]
+pub struct Cgroup {
+    config: CgroupConfig,
+    cache: Arc<Mutex<LruCache<u32, CacheEntry>>>,
+}
+
+impl Cgroup {
+    /// Creates a new Cgroup struct from the provided config.
+    pub fn new(config: CgroupConfig) -> Self {
+        let cache = Arc::new(Mutex::new(LruCache::new(config.cache_size)));
+        Self { config, cache }
+    }
+
+    fn lookup(&self, pid: u32) -> ObjectMap {
+        let now = Instant::now();
+        let mut cache = self.cache.lock().expect("cgroup cache lock poisoned");
+        if let Some(entry) = cache.get(&pid) {
+            if now.duration_since(entry.inserted) < self.config.cache_ttl_secs {
+                return entry.row.clone();
+            }
+        }
+
+        // A process that has exited, or that we are not allowed to inspect, resolves to a row of
+        // nulls rather than an error, so that enrichment never fails an otherwise valid event.
+        let path = self.config.procfs_root.join(pid.to_string()).join("cgroup");
+        let cgroup = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| parse_cgroup_file(&contents));
+        let row = CgroupInfo::from_path(cgroup.as_deref()).into_row(pid);
+
+        cache.put(
+            pid,
+            CacheEntry {
+                inserted: now,
+                row: row.clone(),
+            },
+        );
+        row
+    }
+}
+
+impl Table for Cgroup {
+    /// Search the enrichment table data with the given condition.
+    /// All conditions must match (AND).
+    ///
+    /// # Errors
+    /// Errors if the condition is not a single `pid` equality.
+    fn find_table_row<'a>(
+        &self,
+        case: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&[String]>,
+        index: Option<IndexHandle>,
+    ) -> Result<ObjectMap, String> {
+        let mut rows = self.find_table_rows(case, condition, select, index)?;
+        rows.pop().ok_or_else(|| "PID not found".to_string())
+    }
+
+    /// Search the enrichment table data with the given condition.
+    /// All conditions must match (AND).
+    /// Can return multiple matched records
+    fn find_table_rows<'a>(
+        &self,
+        _: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&[String]>,
+        _: Option<IndexHandle>,
+    ) -> Result<Vec<ObjectMap>, String> {
+        match condition.first() {
+            Some(_) if condition.len() > 1 => Err("Only one condition is allowed".to_string()),
+            Some(Condition::Equals { field, value }) if *field == "pid" => {
+                let pid = parse_pid(value).ok_or_else(|| "Invalid PID".to_string())?;
+                let mut row = self.lookup(pid);
+                if let Some(fields) = select {
+                    row.retain(|key, _| fields.iter().any(|field| field == key.as_str()));
+                }
+                Ok(vec![row])
+            }
+            Some(Condition::Equals { .. }) => {
+                Err("Only the `pid` field can be searched".to_string())
+            }
+            Some(_) => Err("Only equality condition is allowed".to_string()),
+            None => Err("PID condition must be specified".to_string()),
+        }
+    }
+
+    /// Hints to the enrichment table what data is going to be searched to allow it to index the
+    /// data in advance.
+    ///
+    /// # Errors
+    /// Errors if the fields are not in the table.
+    fn add_index(&mut self, _: Case, fields: &[&str]) -> Result<IndexHandle, String> {
+        match fields {
+            ["pid"] => Ok(IndexHandle(0)),
+            [] => Err("PID field is required".to_string()),
+            _ => Err("Only the `pid` field can be indexed".to_string()),
+        }
+    }
+
+    /// Returns a list of the field names that are in each index
+    fn index_fields(&self) -> Vec<(Case, Vec<String>)> {
+        Vec::new()
+    }
+
+    /// Returns true if the underlying data has changed and the table needs reloading.
+    fn needs_reload(&self) -> bool {
+        false
+    }
+}
+
+impl std::fmt::Debug for Cgroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cgroup table for {:?}", self.config.procfs_root)
+    }
+}
+
+fn parse_pid(value: &Value) -> Option<u32> {
+    match value {
+        Value::Integer(pid) => u32::try_from(*pid).ok(),
+        Value::Bytes(_) => value.to_string_lossy().trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// Finds the most relevant cgroup path in the contents of a `/proc/<pid>/cgroup` file.
+///
+/// The unified (v2) hierarchy is preferred, followed by the `name=systemd` v1 hierarchy that
+/// systemd uses for process tracking on legacy systems, and finally the first listed hierarchy.
+fn parse_cgroup_file(contents: &str) -> Option<String> {
+    let mut systemd = None;
+    let mut first = None;
+    for line in contents.lines() {
+        let mut parts = line.splitn(3, ':');
+        let (Some(id), Some(controllers), Some(path)) = (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        if id == "0" && controllers.is_empty() {
+            return Some(path.to_string());
+        }
+        if controllers == "name=systemd" {
+            systemd.get_or_insert(path);
+        }
+        first.get_or_insert(path);
+    }
+    systemd.or(first).map(Into::into)
+}
+
+#[derive(Debug, Default, Eq, PartialEq)]
+struct CgroupInfo<'a> {
+    cgroup: Option<&'a str>,
+    slice: Option<&'a str>,
+    unit: Option<&'a str>,
+    user_unit: Option<&'a str>,
+    container_id: Option<&'a str>,
+}
+
+impl<'a> CgroupInfo<'a> {
+    /// Resolves the systemd slice, unit and container ID from a cgroup path.
+    ///
+    /// The unit is the first non-slice unit in the path, matching how systemd itself attributes
+    /// processes. Processes running under a user manager (`user@.service`) additionally get the
+    /// user unit that owns them.
+    fn from_path(cgroup: Option<&'a str>) -> Self {
+        let mut info = Self {
+            cgroup,
+            ..Default::default()
+        };
+        let Some(path) = cgroup else {
+            return info;
+        };
+
+        for component in path.split('/').filter(|component| !component.is_empty()) {
+            if component.ends_with(".slice") {
+                if info.unit.is_none() {
+                    info.slice = Some(component);
+                }
+            } else if is_unit(component) {
+                if info.unit.is_none() {
+                    info.unit = Some(component);
+                } else if info.user_unit.is_none() {
+                    info.user_unit = Some(component);
+                }
+            }
+        }
+        info.container_id = path
+            .rsplit('/')
+            .find(|component| !component.is_empty())
+            .and_then(container_id);
+
+        info
+    }
+
+    fn into_row(self, pid: u32) -> ObjectMap {
+        let value = |field: Option<&str>| field.map(Value::from).unwrap_or(Value::Null);
+        ObjectMap::from([
+            ("pid".into(), Value::from(pid)),
+            ("cgroup".into(), value(self.cgroup)),
+            ("slice".into(), value(self.slice)),
+            ("unit".into(), value(self.unit)),
+            ("user_unit".into(), value(self.user_unit)),
+            ("container_id".into(), value(self.container_id)),
+        ])
+    }
+}
+
+fn is_unit(component: &str) -> bool {
+    UNIT_SUFFIXES
+        .iter()
+        .any(|suffix| component.len() > suffix.len() && component.ends_with(suffix))
+}
+
+/// Extracts a container ID from the last component of a cgroup path, such as
+/// `docker-<id>.scope` (systemd cgroup driver) or a bare `<id>` (cgroupfs driver).
+fn container_id(component: &str) -> Option<&str> {
+    let id = component.strip_suffix(".scope").unwrap_or(component);
+    let id = CONTAINER_PREFIXES
+        .iter()
+        .find_map(|prefix| id.strip_prefix(prefix))
+        .unwrap_or(id);
+    (id.len() == 64 && id.bytes().all(|byte| byte.is_ascii_hexdigit())).then_some(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    const CONTAINER: &str = "4b825dc642cb6eb9a060e54bf8d69288fbee4904a8f4b6a2e6f4c1b3a9d0e7f1";
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<CgroupConfig>();
+    }
+
+    #[test]
+    fn parses_unified_cgroup_file() {
+        assert_eq!(
+            parse_cgroup_file("0::/system.slice/nginx.service\n"),
+            Some("/system.slice/nginx.service".into())
+        );
+    }
+
+    #[test]
+    fn parses_legacy_cgroup_file() {
+        let contents = "12:memory:/system.slice/nginx.service\n\
+            1:name=systemd:/system.slice/sshd.service\n";
+        assert_eq!(
+            parse_cgroup_file(contents),
+            Some("/system.slice/sshd.service".into())
+        );
+        assert_eq!(
+            parse_cgroup_file("4:cpu,cpuacct:/user.slice\n"),
+            Some("/user.slice".into())
+        );
+    }
+
+    #[test]
+    fn resolves_system_unit() {
+        let info = CgroupInfo::from_path(Some("/system.slice/nginx.service"));
+        assert_eq!(info.slice, Some("system.slice"));
+        assert_eq!(info.unit, Some("nginx.service"));
+        assert_eq!(info.user_unit, None);
+        assert_eq!(info.container_id, None);
+    }
+
+    #[test]
+    fn resolves_user_unit() {
+        let info = CgroupInfo::from_path(Some(
+            "/user.slice/user-1000.slice/user@1000.service/app.slice/app-firefox.scope",
+        ));
+        assert_eq!(info.slice, Some("user-1000.slice"));
+        assert_eq!(info.unit, Some("user@1000.service"));
+        assert_eq!(info.user_unit, Some("app-firefox.scope"));
+    }
+
+    #[test]
+    fn resolves_container_ids() {
+        let path = format!("/system.slice/docker-{CONTAINER}.scope");
+        let info = CgroupInfo::from_path(Some(&path));
+        assert_eq!(info.unit, Some(&path[14..]));
+        assert_eq!(info.container_id, Some(CONTAINER));
+
+        let path = format!(
+            "/kubepods.slice/kubepods-burstable.slice/kubepods-burstable-pod1234.slice/cri-containerd-{CONTAINER}.scope"
+        );
+        assert_eq!(
+            CgroupInfo::from_path(Some(&path)).container_id,
+            Some(CONTAINER)
+        );
+
+        let path = format!("/kubepods/burstable/pod1234/{CONTAINER}");
+        let info = CgroupInfo::from_path(Some(&path));
+        assert_eq!(info.unit, None);
+        assert_eq!(info.container_id, Some(CONTAINER));
+    }
+
+    #[test]
+    fn resolves_root_cgroup() {
+        let info = CgroupInfo::from_path(Some("/"));
+        assert_eq!(
+            info,
+            CgroupInfo {
+                cgroup: Some("/"),
+                ..Default::default()
+            }
+        );
+    }
+
+    fn table(procfs_root: PathBuf) -> Cgroup {
+        Cgroup::new(CgroupConfig {
+            procfs_root,
+            ..Default::default()
+        })
+    }
+
+    fn find(table: &Cgroup, pid: Value, select: Option<&[String]>) -> Result<ObjectMap, String> {
+        table.find_table_row(
+            Case::Sensitive,
+            &[Condition::Equals {
+                field: "pid",
+                value: pid,
+            }],
+            select,
+            None,
+        )
+    }
+
+    #[test]
+    fn finds_process_unit() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("42")).unwrap();
+        fs::write(
+            dir.path().join("42/cgroup"),
+            "0::/system.slice/nginx.service\n",
+        )
+        .unwrap();
+        let table = table(dir.path().to_path_buf());
+
+        let row = find(&table, Value::from(42), None).unwrap();
+        assert_eq!(row.get("pid"), Some(&Value::from(42)));
+        assert_eq!(row.get("unit"), Some(&Value::from("nginx.service")));
+        assert_eq!(row.get("slice"), Some(&Value::from("system.slice")));
+        assert_eq!(row.get("container_id"), Some(&Value::Null));
+
+        let row = find(&table, Value::from("42"), Some(&["unit".to_string()])).unwrap();
+        assert_eq!(
+            row,
+            ObjectMap::from([("unit".into(), Value::from("nginx.service"))])
+        );
+    }
+
+    #[test]
+    fn missing_process_yields_nulls() {
+        let dir = tempfile::tempdir().unwrap();
+        let table = table(dir.path().to_path_buf());
+
+        let row = find(&table, Value::from(42), None).unwrap();
+        assert_eq!(row.get("pid"), Some(&Value::from(42)));
+        assert_eq!(row.get("cgroup"), Some(&Value::Null));
+        assert_eq!(row.get("unit"), Some(&Value::Null));
+    }
+
+    #[test]
+    fn caches_lookups_until_expiry() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("42")).unwrap();
+        fs::write(
+            dir.path().join("42/cgroup"),
+            "0::/system.slice/nginx.service\n",
+        )
+        .unwrap();
+        let mut table = table(dir.path().to_path_buf());
+
+        find(&table, Value::from(42), None).unwrap();
+        // The PID is recycled by another unit, but the cached result is still fresh.
+        fs::write(
+            dir.path().join("42/cgroup"),
+            "0::/system.slice/sshd.service\n",
+        )
+        .unwrap();
+        let row = find(&table, Value::from(42), None).unwrap();
+        assert_eq!(row.get("unit"), Some(&Value::from("nginx.service")));
+
+        table.config.cache_ttl_secs = Duration::ZERO;
+        let row = find(&table, Value::from(42), None).unwrap();
+        assert_eq!(row.get("unit"), Some(&Value::from("sshd.service")));
+    }
+
+    #[test]
+    fn bounds_cache_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let table = Cgroup::new(CgroupConfig {
+            procfs_root: dir.path().to_path_buf(),
+            cache_size: NonZeroUsize::new(2).unwrap(),
+            ..Default::default()
+        });
+
+        for pid in 0..10 {
+            find(&table, Value::from(pid), None).unwrap();
+        }
+        assert_eq!(table.cache.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn rejects_invalid_conditions() {
+        let dir = tempfile::tempdir().unwrap();
+        let table = table(dir.path().to_path_buf());
+
+        assert!(find(&table, Value::from("not a pid"), None).is_err());
+        assert!(find(&table, Value::from(-1), None).is_err());
+        assert!(table
+            .find_table_row(
+                Case::Sensitive,
+                &[Condition::Equals {
+                    field: "uid",
+                    value: Value::from(0)
+                }],
+                None,
+                None,
+            )
+            .is_err());
+    }
+}
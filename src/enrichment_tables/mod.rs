@@ -5,6 +5,9 @@ pub use vector_lib::enrichment::{Condition, IndexHandle, Table};
 
 use crate::config::{EnrichmentTableConfig, GlobalOptions};
 
+#[cfg(feature = "enrichment-tables-cgroup")]
+pub mod cgroup;
+
 pub mod file;
 
 #[cfg(feature = "enrichment-tables-geoip")]
@@ -13,6 +16,9 @@ pub mod geoip;
 #[cfg(feature = "enrichment-tables-mmdb")]
 pub mod mmdb;
 
+#[cfg(feature = "enrichment-tables-passwd")]
+pub mod passwd;
+
 /// Configurable enrichment tables.
 #[configurable_component]
 #[derive(Clone, Debug)]
@@ -34,6 +40,14 @@ pub enum EnrichmentTables {
     /// [maxmind]: https://www.maxmind.com/
     #[cfg(feature = "enrichment-tables-mmdb")]
     Mmdb(mmdb::MmdbConfig),
+
+    /// Resolves process IDs to the cgroup, systemd unit and container that own them.
+    #[cfg(feature = "enrichment-tables-cgroup")]
+    Cgroup(cgroup::CgroupConfig),
+
+    /// Resolves user and group IDs to the users and groups they belong to.
+    #[cfg(feature = "enrichment-tables-passwd")]
+    Passwd(passwd::PasswdConfig),
 }
 
 // TODO: Use `enum_dispatch` here.
@@ -45,6 +59,10 @@ impl NamedComponent for EnrichmentTables {
             Self::Geoip(config) => config.get_component_name(),
             #[cfg(feature = "enrichment-tables-mmdb")]
             Self::Mmdb(config) => config.get_component_name(),
+            #[cfg(feature = "enrichment-tables-cgroup")]
+            Self::Cgroup(config) => config.get_component_name(),
+            #[cfg(feature = "enrichment-tables-passwd")]
+            Self::Passwd(config) => config.get_component_name(),
             #[allow(unreachable_patterns)]
             _ => unimplemented!(),
         }
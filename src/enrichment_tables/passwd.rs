@@ -0,0 +1,779 @@
+//! Handles enrichment tables for `type = passwd`.
+//!
+//! Resolves user and group IDs to the users and groups they belong to, from the `/etc/passwd`
+//! and `/etc/group` files or through the Name Service Switch (NSS) of the host.
+use std::{
+    collections::HashMap,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, Instant, SystemTime},
+};
+
+use lru::LruCache;
+use serde_with::serde_as;
+use vector_lib::configurable::configurable_component;
+use vector_lib::enrichment::{Case, Condition, IndexHandle, Table};
+use vrl::value::{ObjectMap, Value};
+
+use crate::config::EnrichmentTableConfig;
+
+/// The database that entries are looked up in.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Database {
+    /// Users, looked up by their `uid` or `name`.
+    ///
+    /// Records hold the `name`, `uid`, `gid`, `gecos`, `home` and `shell` of the user.
+    #[default]
+    Passwd,
+
+    /// Groups, looked up by their `gid` or `name`.
+    ///
+    /// Records hold the `name`, `gid` and `members` of the group.
+    Group,
+}
+
+impl Database {
+    const fn id_field(self) -> &'static str {
+        match self {
+            Self::Passwd => "uid",
+            Self::Group => "gid",
+        }
+    }
+
+    fn default_path(self) -> PathBuf {
+        match self {
+            Self::Passwd => PathBuf::from("/etc/passwd"),
+            Self::Group => PathBuf::from("/etc/group"),
+        }
+    }
+}
+
+/// Where entries are read from.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Source {
+    /// Entries are read from a file in the format of `/etc/passwd` or `/etc/group`.
+    ///
+    /// The file is checked for changes every `reload_interval_secs`, and read again if it changed
+    /// since it was last read.
+    #[default]
+    Files,
+
+    /// Entries are looked up through the Name Service Switch (NSS) of the host, which also covers
+    /// users and groups from network directories such as LDAP.
+    ///
+    /// Lookup results are cached, and this is only available on Unix systems.
+    Nss,
+}
+
+/// Configuration for the `passwd` enrichment table.
+#[serde_as]
+#[configurable_component(enrichment_table("passwd"))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PasswdConfig {
+    #[configurable(derived)]
+    #[serde(default)]
+    pub database: Database,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub source: Source,
+
+    /// The file to read entries from, when they are read from files.
+    ///
+    /// Defaults to `/etc/passwd` for users and `/etc/group` for groups.
+    #[configurable(metadata(docs::examples = "/host/etc/passwd"))]
+    pub path: Option<PathBuf>,
+
+    /// The interval, in seconds, at which the file is checked for changes, when entries are read
+    /// from files.
+    #[serde(default = "default_reload_interval_secs")]
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    #[configurable(metadata(docs::human_name = "Reload Interval"))]
+    pub reload_interval_secs: Duration,
+
+    /// The amount of time, in seconds, that a lookup result is cached, when entries are looked up
+    /// through NSS.
+    ///
+    /// Names are matched case-sensitively by NSS, whatever the case sensitivity of the lookup.
+    #[serde(default = "default_cache_ttl_secs")]
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    #[configurable(metadata(docs::human_name = "Cache TTL"))]
+    pub cache_ttl_secs: Duration,
+
+    /// The maximum number of lookup results kept in the cache, when entries are looked up through
+    /// NSS.
+    #[serde(default = "default_cache_size")]
+    pub cache_size: NonZeroUsize,
+}
+
+const fn default_reload_interval_secs() -> Duration {
+    Duration::from_secs(10)
+}
+
+const fn default_cache_ttl_secs() -> Duration {
+    Duration::from_secs(60)
+}
+
+fn default_cache_size() -> NonZeroUsize {
+    NonZeroUsize::new(10_000).expect("static non-zero number")
+}
+
+impl Default for PasswdConfig {
+    fn default() -> Self {
+        Self {
+            database: Database::default(),
+            source: Source::default(),
+            path: None,
+            reload_interval_secs: default_reload_interval_secs(),
+            cache_ttl_secs: default_cache_ttl_secs(),
+            cache_size: default_cache_size(),
+        }
+    }
+}
+
+impl_generate_config_from_default!(PasswdConfig);
+
+impl EnrichmentTableConfig for PasswdConfig {
+    async fn build(
+        &self,
+        _: &crate::config::GlobalOptions,
+    ) -> crate::Result<Box<dyn Table + Send + Sync>> {
+        if self.source == Source::Nss && !cfg!(unix) {
+            return Err("NSS lookups are only available on Unix systems".into());
+        }
+        let table = Passwd::new(self.clone())?;
+        table.spawn_reloader();
+        Ok(Box::new(table))
+    }
+}
+
+/// The entries of a file, indexed by their ID and name.
+#[derive(Debug, Default)]
+struct Entries {
+    rows: Vec<ObjectMap>,
+    by_id: HashMap<u32, usize>,
+    by_name: HashMap<String, usize>,
+}
+
+impl Entries {
+    /// Parses the contents of a file in the format of `/etc/passwd` or `/etc/group`.
+    ///
+    /// Malformed lines, comments and NIS compatibility entries are skipped. The first entry wins
+    /// when several share an ID or name, as with the C library.
+    fn parse(database: Database, contents: &str) -> Self {
+        let mut entries = Self::default();
+        for line in contents.lines() {
+            if line.starts_with(['#', '+', '-']) {
+                continue;
+            }
+            let Some((name, id, row)) = parse_line(database, line) else {
+                continue;
+            };
+
+            let index = entries.rows.len();
+            entries.rows.push(row);
+            entries.by_id.entry(id).or_insert(index);
+            entries.by_name.entry(name).or_insert(index);
+        }
+        entries
+    }
+
+    fn find(&self, case: Case, key: &Key) -> Option<&ObjectMap> {
+        let index = match (key, case) {
+            (Key::Id(id), _) => self.by_id.get(id).copied(),
+            (Key::Name(name), Case::Sensitive) => self.by_name.get(name).copied(),
+            (Key::Name(name), Case::Insensitive) => self
+                .by_name
+                .iter()
+                .filter(|(other, _)| other.eq_ignore_ascii_case(name))
+                .map(|(_, index)| *index)
+                .min(),
+        };
+        index.map(|index| &self.rows[index])
+    }
+}
+
+fn parse_line(database: Database, line: &str) -> Option<(String, u32, ObjectMap)> {
+    let fields = line.split(':').collect::<Vec<_>>();
+    match (database, fields.as_slice()) {
+        (Database::Passwd, [name, _, uid, gid, gecos, home, shell]) if !name.is_empty() => {
+            let (uid, gid) = (uid.parse().ok()?, gid.parse::<u32>().ok()?);
+            let row = passwd_row(name, uid, gid, gecos, home, shell);
+            Some((name.to_string(), uid, row))
+        }
+        (Database::Group, [name, _, gid, members]) if !name.is_empty() => {
+            let gid = gid.parse().ok()?;
+            let members = members.split(',').filter(|member| !member.is_empty());
+            Some((name.to_string(), gid, group_row(name, gid, members)))
+        }
+        _ => None,
+    }
+}
+
+fn passwd_row(name: &str, uid: u32, gid: u32, gecos: &str, home: &str, shell: &str) -> ObjectMap {
+    ObjectMap::from([
+        ("name".into(), Value::from(name)),
+        ("uid".into(), Value::from(uid)),
+        ("gid".into(), Value::from(gid)),
+        ("gecos".into(), Value::from(gecos)),
+        ("home".into(), Value::from(home)),
+        ("shell".into(), Value::from(shell)),
+    ])
+}
+
+fn group_row<'a>(name: &str, gid: u32, members: impl Iterator<Item = &'a str>) -> ObjectMap {
+    ObjectMap::from([
+        ("name".into(), Value::from(name)),
+        ("gid".into(), Value::from(gid)),
+        (
+            "members".into(),
+            Value::Array(members.map(Value::from).collect()),
+        ),
+    ])
+}
+
+/// The entries of a file, along with its modification time when it was read.
+#[derive(Debug)]
+struct LoadedFile {
+    entries: Arc<Entries>,
+    modified: Option<SystemTime>,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Key {
+    Id(u32),
+    Name(String),
+}
+
+#[derive(Clone)]
+struct CacheEntry {
+    inserted: Instant,
+    row: Option<ObjectMap>,
+}
+
+#[derive(Clone)]
+enum Backend {
+    /// The entries of the file are swapped when it is read again.
+    Files(Arc<RwLock<LoadedFile>>),
+    Nss(Arc<Mutex<LruCache<Key, CacheEntry>>>),
+}
+
+/// A struct that implements [vector_lib::enrichment::Table] to look users and groups up by their
+/// ID or name.
+#[derive(Clone)]
+pub struct Passwd {
+    config: PasswdConfig,
+    path: PathBuf,
+    backend: Backend,
+}
+
+impl Passwd {
+    /// Creates a new Passwd struct from the provided config, reading its file if entries are read
+    /// from files.
+    pub fn new(config: PasswdConfig) -> crate::Result<Self> {
+        let path = config
+            .path
+            .clone()
+            .unwrap_or_else(|| config.database.default_path());
+
+        let backend = match config.source {
+            Source::Files => {
+                let (entries, modified) = read_file(config.database, &path)
+                    .map_err(|error| format!("Unable to read {path:?}: {error}"))?;
+                Backend::Files(Arc::new(RwLock::new(LoadedFile {
+                    entries: Arc::new(entries),
+                    modified,
+                })))
+            }
+            Source::Nss => Backend::Nss(Arc::new(Mutex::new(LruCache::new(config.cache_size)))),
+        };
+
+        Ok(Self {
+            config,
+            path,
+            backend,
+        })
+    }
+
+    fn lookup(&self, case: Case, key: Key) -> Option<ObjectMap> {
+        match &self.backend {
+            Backend::Files(file) => {
+                let entries = Arc::clone(&file.read().expect("passwd file lock poisoned").entries);
+                entries.find(case, &key).cloned()
+            }
+            Backend::Nss(cache) => {
+                let now = Instant::now();
+                if let Some(entry) = cache.lock().expect("passwd cache lock poisoned").get(&key) {
+                    if now.duration_since(entry.inserted) < self.config.cache_ttl_secs {
+                        return entry.row.clone();
+                    }
+                }
+
+                // The lookup can go over the network, so it is made without holding the lock, at
+                // the risk of concurrent lookups of the same key. Missing entries are cached as
+                // well, as they are as expensive to look up.
+                let row = nss_lookup(self.config.database, &key);
+                cache.lock().expect("passwd cache lock poisoned").put(
+                    key,
+                    CacheEntry {
+                        inserted: now,
+                        row: row.clone(),
+                    },
+                );
+                row
+            }
+        }
+    }
+
+    /// Checks the file for changes every reload interval in the background, for as long as the
+    /// table is in use, when entries are read from files.
+    fn spawn_reloader(&self) {
+        let Backend::Files(file) = &self.backend else {
+            return;
+        };
+        let file = Arc::downgrade(file);
+        let database = self.config.database;
+        let path = self.path.clone();
+        let interval = self.config.reload_interval_secs;
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(interval);
+            // The first tick completes immediately, right after the file was read.
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let Some(file) = file.upgrade() else {
+                    break;
+                };
+                let path = path.clone();
+                // The file may be on a slow filesystem, so it isn't read on the runtime threads.
+                _ = tokio::task::spawn_blocking(move || reload_if_changed(database, &path, &file))
+                    .await;
+            }
+        });
+    }
+}
+
+/// Reads the file again if its modification time changed since it was last read.
+fn reload_if_changed(database: Database, path: &Path, file: &RwLock<LoadedFile>) {
+    if modified(path) == file.read().expect("passwd file lock poisoned").modified {
+        return;
+    }
+
+    // The previous entries are kept if the file can't be read, for example while it is being
+    // replaced.
+    match read_file(database, path) {
+        Ok((entries, modified)) => {
+            debug!(message = "Reloaded passwd enrichment table.", ?path);
+            *file.write().expect("passwd file lock poisoned") = LoadedFile {
+                entries: Arc::new(entries),
+                modified,
+            };
+        }
+        Err(error) => warn!(
+            message = "Unable to reload passwd enrichment table.",
+            ?path,
+            %error,
+            internal_log_rate_limit = true,
+        ),
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+fn read_file(database: Database, path: &Path) -> std::io::Result<(Entries, Option<SystemTime>)> {
+    // The modification time is taken first, so that changes made while reading are not missed.
+    let modified = modified(path);
+    let contents = std::fs::read_to_string(path)?;
+    Ok((Entries::parse(database, &contents), modified))
+}
+
+#[cfg(unix)]
+fn nss_lookup(database: Database, key: &Key) -> Option<ObjectMap> {
+    use nix::unistd::{Gid, Group, Uid, User};
+
+    // Lookup errors are treated like missing entries, so that enrichment never fails an
+    // otherwise valid event.
+    match database {
+        Database::Passwd => {
+            let user = match key {
+                Key::Id(uid) => User::from_uid(Uid::from_raw(*uid)),
+                Key::Name(name) => User::from_name(name),
+            };
+            user.ok().flatten().map(|user| {
+                passwd_row(
+                    &user.name,
+                    user.uid.as_raw(),
+                    user.gid.as_raw(),
+                    &user.gecos.to_string_lossy(),
+                    &user.dir.to_string_lossy(),
+                    &user.shell.to_string_lossy(),
+                )
+            })
+        }
+        Database::Group => {
+            let group = match key {
+                Key::Id(gid) => Group::from_gid(Gid::from_raw(*gid)),
+                Key::Name(name) => Group::from_name(name),
+            };
+            group.ok().flatten().map(|group| {
+                group_row(
+                    &group.name,
+                    group.gid.as_raw(),
+                    group.mem.iter().map(String::as_str),
+                )
+            })
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn nss_lookup(_database: Database, _key: &Key) -> Option<ObjectMap> {
+    None
+}
+
+impl Table for Passwd {
+    /// Search the enrichment table data with the given condition.
+    /// All conditions must match (AND).
+    ///
+    /// An entry that doesn't exist yields an empty record.
+    ///
+    /// # Errors
+    /// Errors if the condition is not a single ID or name equality.
+    fn find_table_row<'a>(
+        &self,
+        case: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&[String]>,
+        index: Option<IndexHandle>,
+    ) -> Result<ObjectMap, String> {
+        let mut rows = self.find_table_rows(case, condition, select, index)?;
+        Ok(rows.pop().unwrap_or_default())
+    }
+
+    /// Search the enrichment table data with the given condition.
+    /// All conditions must match (AND).
+    /// Can return multiple matched records
+    fn find_table_rows<'a>(
+        &self,
+        case: Case,
+        condition: &'a [Condition<'a>],
+        select: Option<&[String]>,
+        _: Option<IndexHandle>,
+    ) -> Result<Vec<ObjectMap>, String> {
+        let id_field = self.config.database.id_field();
+        let key = match condition.first() {
+            Some(_) if condition.len() > 1 => {
+                return Err("Only one condition is allowed".to_string())
+            }
+            Some(Condition::Equals { field, value }) if *field == id_field => {
+                match parse_id(value) {
+                    Some(id) => Key::Id(id),
+                    None => return Err(format!("Invalid {id_field}")),
+                }
+            }
+            Some(Condition::Equals { field, value }) if *field == "name" => {
+                Key::Name(value.to_string_lossy().into_owned())
+            }
+            Some(Condition::Equals { .. }) => {
+                return Err(format!(
+                    "Only the `{id_field}` and `name` fields can be searched"
+                ))
+            }
+            Some(_) => return Err("Only equality condition is allowed".to_string()),
+            None => {
+                return Err(format!(
+                    "A `{id_field}` or `name` condition must be specified"
+                ))
+            }
+        };
+
+        // Missing entries are not errors, as IDs commonly have no entry, for example those of
+        // users from containers.
+        Ok(self
+            .lookup(case, key)
+            .map(|mut row| {
+                if let Some(fields) = select {
+                    row.retain(|key, _| fields.iter().any(|field| field == key.as_str()));
+                }
+                row
+            })
+            .into_iter()
+            .collect())
+    }
+
+    /// Hints to the enrichment table what data is going to be searched to allow it to index the
+    /// data in advance.
+    ///
+    /// # Errors
+    /// Errors if the fields are not in the table.
+    fn add_index(&mut self, _: Case, fields: &[&str]) -> Result<IndexHandle, String> {
+        let id_field = self.config.database.id_field();
+        match fields {
+            [field] if *field == id_field || *field == "name" => Ok(IndexHandle(0)),
+            [] => Err(format!("A `{id_field}` or `name` field is required")),
+            _ => Err(format!(
+                "Only one of the `{id_field}` and `name` fields can be indexed"
+            )),
+        }
+    }
+
+    /// Returns a list of the field names that are in each index
+    fn index_fields(&self) -> Vec<(Case, Vec<String>)> {
+        Vec::new()
+    }
+
+    /// Checks the modification time of the file to see if its entries changed.
+    ///
+    /// Changes are also picked up by the table itself every reload interval.
+    fn needs_reload(&self) -> bool {
+        match &self.backend {
+            Backend::Files(file) => {
+                modified(&self.path) != file.read().expect("passwd file lock poisoned").modified
+            }
+            Backend::Nss(_) => false,
+        }
+    }
+}
+
+impl std::fmt::Debug for Passwd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.config.source {
+            Source::Files => write!(f, "Passwd table for {:?}", self.path),
+            Source::Nss => write!(
+                f,
+                "Passwd table for the {:?} NSS database",
+                self.config.database
+            ),
+        }
+    }
+}
+
+fn parse_id(value: &Value) -> Option<u32> {
+    match value {
+        Value::Integer(id) => u32::try_from(*id).ok(),
+        Value::Bytes(_) => value.to_string_lossy().trim().parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    const PASSWD: &str = "\
+# Comment
+root:x:0:0:root:/root:/bin/bash
+daemon:x:1:1:daemon:/usr/sbin:/usr/sbin/nologin
+malformed:x:2
++nis
+alice:x:1000:1000:Alice,,,:/home/alice:/bin/zsh
+shadow:x:1000:1000::/home/shadow:/bin/sh
+";
+
+    const GROUP: &str = "\
+root:x:0:
+adm:x:4:syslog,alice
+";
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<PasswdConfig>();
+    }
+
+    fn table(database: Database, contents: &str) -> (tempfile::TempDir, Passwd) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("db");
+        fs::write(&path, contents).unwrap();
+        let table = Passwd::new(PasswdConfig {
+            database,
+            path: Some(path),
+            ..Default::default()
+        })
+        .unwrap();
+        (dir, table)
+    }
+
+    fn find(table: &Passwd, field: &str, value: Value) -> Result<Vec<ObjectMap>, String> {
+        table.find_table_rows(
+            Case::Sensitive,
+            &[Condition::Equals { field, value }],
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn finds_users_by_uid_and_name() {
+        let (_dir, table) = table(Database::Passwd, PASSWD);
+
+        let rows = find(&table, "uid", Value::from(1000)).unwrap();
+        assert_eq!(
+            rows,
+            vec![passwd_row(
+                "alice",
+                1000,
+                1000,
+                "Alice,,,",
+                "/home/alice",
+                "/bin/zsh"
+            )]
+        );
+
+        let rows = find(&table, "uid", Value::from("0")).unwrap();
+        assert_eq!(rows[0].get("name"), Some(&Value::from("root")));
+
+        let rows = find(&table, "name", Value::from("daemon")).unwrap();
+        assert_eq!(rows[0].get("home"), Some(&Value::from("/usr/sbin")));
+
+        // The first entry wins when several share a uid.
+        let rows = find(&table, "name", Value::from("shadow")).unwrap();
+        assert_eq!(rows[0].get("uid"), Some(&Value::from(1000)));
+    }
+
+    #[test]
+    fn finds_groups_by_gid_and_name() {
+        let (_dir, table) = table(Database::Group, GROUP);
+
+        let rows = find(&table, "gid", Value::from(4)).unwrap();
+        assert_eq!(
+            rows,
+            vec![group_row("adm", 4, ["syslog", "alice"].into_iter())]
+        );
+
+        let rows = find(&table, "name", Value::from("root")).unwrap();
+        assert_eq!(rows[0].get("members"), Some(&Value::Array(Vec::new())));
+    }
+
+    #[test]
+    fn missing_entries_yield_no_rows() {
+        let (_dir, table) = table(Database::Passwd, PASSWD);
+
+        assert_eq!(find(&table, "uid", Value::from(4242)), Ok(Vec::new()));
+        assert_eq!(
+            find(&table, "name", Value::from("malformed")),
+            Ok(Vec::new())
+        );
+        assert_eq!(find(&table, "name", Value::from("nis")), Ok(Vec::new()));
+        assert_eq!(
+            table.find_table_row(
+                Case::Sensitive,
+                &[Condition::Equals {
+                    field: "uid",
+                    value: Value::from(4242)
+                }],
+                None,
+                None,
+            ),
+            Ok(ObjectMap::new())
+        );
+    }
+
+    #[test]
+    fn finds_names_case_insensitively() {
+        let (_dir, table) = table(Database::Passwd, PASSWD);
+
+        let condition = [Condition::Equals {
+            field: "name",
+            value: Value::from("ALICE"),
+        }];
+        let select = ["uid".to_string()];
+        let rows = table
+            .find_table_rows(Case::Insensitive, &condition, Some(&select), None)
+            .unwrap();
+        assert_eq!(
+            rows,
+            vec![ObjectMap::from([("uid".into(), Value::from(1000))])]
+        );
+    }
+
+    /// Replaces the contents of the file of a table, forgetting when it was last modified as the
+    /// modification time may not change at the resolution of the filesystem.
+    fn change_file(dir: &tempfile::TempDir, table: &Passwd) {
+        fs::write(
+            dir.path().join("db"),
+            "bob:x:1001:1001::/home/bob:/bin/sh\n",
+        )
+        .unwrap();
+        let Backend::Files(file) = &table.backend else {
+            unreachable!();
+        };
+        file.write().unwrap().modified = Some(SystemTime::UNIX_EPOCH);
+    }
+
+    #[test]
+    fn needs_reload_when_files_change() {
+        let (dir, table) = table(Database::Passwd, PASSWD);
+        assert!(!table.needs_reload());
+
+        change_file(&dir, &table);
+        assert!(table.needs_reload());
+
+        let Backend::Files(file) = &table.backend else {
+            unreachable!();
+        };
+        reload_if_changed(table.config.database, &table.path, file);
+        assert!(!table.needs_reload());
+        let rows = find(&table, "uid", Value::from(1001)).unwrap();
+        assert_eq!(rows[0].get("name"), Some(&Value::from("bob")));
+        assert_eq!(find(&table, "uid", Value::from(1000)), Ok(Vec::new()));
+    }
+
+    #[tokio::test]
+    async fn reloads_changed_files_periodically() {
+        let (dir, mut table) = table(Database::Passwd, PASSWD);
+        table.config.reload_interval_secs = Duration::from_millis(10);
+        table.spawn_reloader();
+
+        change_file(&dir, &table);
+        for _ in 0..100 {
+            if !find(&table, "uid", Value::from(1001)).unwrap().is_empty() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("the changed file was not read again");
+    }
+
+    #[test]
+    fn rejects_invalid_conditions() {
+        let (_dir, mut table) = table(Database::Group, GROUP);
+
+        assert!(find(&table, "gid", Value::from(-1)).is_err());
+        assert!(find(&table, "uid", Value::from(0)).is_err());
+        assert!(table.add_index(Case::Sensitive, &["gid"]).is_ok());
+        assert!(table.add_index(Case::Sensitive, &["uid"]).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn looks_up_entries_through_nss() {
+        let table = Passwd::new(PasswdConfig {
+            source: Source::Nss,
+            ..Default::default()
+        })
+        .unwrap();
+
+        let rows = find(&table, "uid", Value::from(0)).unwrap();
+        assert_eq!(rows[0].get("name"), Some(&Value::from("root")));
+        let rows = find(&table, "name", Value::from("root")).unwrap();
+        assert_eq!(rows[0].get("uid"), Some(&Value::from(0)));
+
+        let Backend::Nss(cache) = &table.backend else {
+            unreachable!();
+        };
+        assert_eq!(cache.lock().unwrap().len(), 2);
+    }
+}
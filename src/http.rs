@@ -10,7 +10,10 @@ use http::{
 use hyper::{
     body::{Body, HttpBody},
     client,
-    client::{Client, HttpConnector},
+    client::{
+        connect::{Connected, Connection},
+        Client, HttpConnector,
+    },
 };
 use hyper_openssl::HttpsConnector;
 use hyper_proxy::ProxyConnector;
@@ -18,15 +21,21 @@ use rand::Rng;
 use serde::Deserialize;
 use serde_with::serde_as;
 use snafu::{ResultExt, Snafu};
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
 use std::{
     error::Error,
-    fmt,
+    fmt, io,
     net::SocketAddr,
+    pin::Pin,
     sync::{Arc, Mutex},
     task::{Context, Poll},
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
-use tokio::time::Instant;
+use tokio::{
+    io::{AsyncRead, AsyncWrite, ReadBuf},
+    time::Instant,
+};
 use tower::{Layer, Service};
 use tower_http::{
     classify::{ServerErrorsAsFailures, SharedClassifier},
@@ -309,9 +318,9 @@ where
 }
 
 pub struct HttpClient<B = Body> {
-    client: Client<HttpProxyConnector, B>,
+    client: Client<HttpClientConnector, B>,
     user_agent: HeaderValue,
-    proxy_connector: HttpProxyConnector,
+    proxy_connector: Option<HttpProxyConnector>,
     auth_extension: Option<Arc<dyn AuthExtension<B>>>,
 }
 
@@ -349,17 +358,44 @@ where
     ) -> Result<HttpClient<B>, HttpError> {
         let proxy_connector = build_proxy_connector(tls_settings.into(), proxy_config)?;
         let auth_extension = build_auth_extension(auth_config, proxy_config, client_builder);
-        let client = client_builder.build(proxy_connector.clone());
+        let client = client_builder.build(HttpClientConnector::Proxy(proxy_connector.clone()));
+
+        Ok(HttpClient {
+            client,
+            user_agent: user_agent(),
+            proxy_connector: Some(proxy_connector),
+            auth_extension,
+        })
+    }
 
-        let app_name = crate::get_app_name();
-        let version = crate::get_version();
-        let user_agent = HeaderValue::from_str(&format!("{}/{}", app_name, version))
-            .expect("Invalid header value for user-agent!");
+    /// Creates a client sending all of its requests to the unix socket at `path`, whatever the
+    /// host of their URI.
+    ///
+    /// Neither the proxy settings nor TLS apply to the connections to the socket, though the proxy
+    /// settings still apply to the requests made to the token endpoint of an OAuth 2.0
+    /// authorization.
+    #[cfg(unix)]
+    pub fn new_with_unix_socket(
+        path: impl Into<PathBuf>,
+        proxy_config: &ProxyConfig,
+        auth_config: Option<AuthorizationConfig>,
+    ) -> Result<HttpClient<B>, HttpError> {
+        let path: Arc<Path> = path.into().into();
+        if proxy_config.enabled && (proxy_config.http.is_some() || proxy_config.https.is_some()) {
+            warn!(
+                message = "Proxy settings are ignored when connecting to a unix socket.",
+                path = ?path,
+            );
+        }
+
+        let mut client_builder = Client::builder();
+        let auth_extension = build_auth_extension(auth_config, proxy_config, &mut client_builder);
+        let client = client_builder.build(HttpClientConnector::Unix(path));
 
         Ok(HttpClient {
             client,
-            user_agent,
-            proxy_connector,
+            user_agent: user_agent(),
+            proxy_connector: None,
             auth_extension,
         })
     }
@@ -426,7 +462,11 @@ where
     }
 
     fn maybe_add_proxy_headers(&self, request: &mut Request<B>) {
-        if let Some(proxy_headers) = self.proxy_connector.http_headers(request.uri()) {
+        let proxy_headers = self
+            .proxy_connector
+            .as_ref()
+            .and_then(|proxy_connector| proxy_connector.http_headers(request.uri()));
+        if let Some(proxy_headers) = proxy_headers {
             for (k, v) in proxy_headers {
                 let request_headers = request.headers_mut();
                 if !request_headers.contains_key(k) {
@@ -437,6 +477,13 @@ where
     }
 }
 
+fn user_agent() -> HeaderValue {
+    let app_name = crate::get_app_name();
+    let version = crate::get_version();
+    HeaderValue::from_str(&format!("{}/{}", app_name, version))
+        .expect("Invalid header value for user-agent!")
+}
+
 fn build_auth_extension<B>(
     authorization_config: Option<AuthorizationConfig>,
     proxy_config: &ProxyConfig,
@@ -520,6 +567,129 @@ pub fn build_tls_connector(
     Ok(https)
 }
 
+/// Connects HTTP clients to the host of their requests, through the configured proxy if any, or
+/// to a unix socket.
+#[derive(Clone)]
+enum HttpClientConnector {
+    Proxy(HttpProxyConnector),
+    #[cfg(unix)]
+    Unix(Arc<Path>),
+}
+
+impl Service<Uri> for HttpClientConnector {
+    type Response = HttpClientStream;
+    type Error = Box<dyn Error + Send + Sync>;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self {
+            Self::Proxy(proxy) => proxy.poll_ready(cx).map_err(Into::into),
+            #[cfg(unix)]
+            Self::Unix(_) => Poll::Ready(Ok(())),
+        }
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        match self {
+            Self::Proxy(proxy) => {
+                let connecting = proxy.call(uri);
+                Box::pin(async move {
+                    let stream = connecting.await.map_err(Into::into)?;
+                    Ok(HttpClientStream::Proxy(stream))
+                })
+            }
+            #[cfg(unix)]
+            Self::Unix(path) => {
+                let path = Arc::clone(path);
+                Box::pin(async move {
+                    let stream = tokio::net::UnixStream::connect(&*path).await?;
+                    Ok(HttpClientStream::Unix(stream))
+                })
+            }
+        }
+    }
+}
+
+enum HttpClientStream {
+    Proxy(<HttpProxyConnector as Service<Uri>>::Response),
+    #[cfg(unix)]
+    Unix(tokio::net::UnixStream),
+}
+
+impl Connection for HttpClientStream {
+    fn connected(&self) -> Connected {
+        match self {
+            Self::Proxy(stream) => stream.connected(),
+            #[cfg(unix)]
+            Self::Unix(_) => Connected::new(),
+        }
+    }
+}
+
+impl AsyncRead for HttpClientStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            Self::Proxy(stream) => Pin::new(stream).poll_read(cx, buf),
+            #[cfg(unix)]
+            Self::Unix(stream) => Pin::new(stream).poll_read(cx, buf),
+        }
+    }
+}
+
+impl AsyncWrite for HttpClientStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.get_mut() {
+            Self::Proxy(stream) => Pin::new(stream).poll_write(cx, buf),
+            #[cfg(unix)]
+            Self::Unix(stream) => Pin::new(stream).poll_write(cx, buf),
+        }
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        match self.get_mut() {
+            Self::Proxy(stream) => Pin::new(stream).poll_write_vectored(cx, bufs),
+            #[cfg(unix)]
+            Self::Unix(stream) => Pin::new(stream).poll_write_vectored(cx, bufs),
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            Self::Proxy(stream) => stream.is_write_vectored(),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.is_write_vectored(),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            Self::Proxy(stream) => Pin::new(stream).poll_flush(cx),
+            #[cfg(unix)]
+            Self::Unix(stream) => Pin::new(stream).poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            Self::Proxy(stream) => Pin::new(stream).poll_shutdown(cx),
+            #[cfg(unix)]
+            Self::Unix(stream) => Pin::new(stream).poll_shutdown(cx),
+        }
+    }
+}
+
 fn default_request_headers<B>(request: &mut Request<B>, user_agent: &HeaderValue) {
     if !request.headers().contains_key("User-Agent") {
         request
@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use metrics::counter;
+use vector_lib::internal_event::{error_stage, error_type, InternalEvent};
+
+#[derive(Debug)]
+pub struct AcknowledgementTimedOut {
+    pub count: usize,
+    pub timeout: Duration,
+}
+
+impl InternalEvent for AcknowledgementTimedOut {
+    fn emit(self) {
+        error!(
+            message = "Sink did not acknowledge events in time, reporting them as errored to their sources.",
+            count = self.count,
+            timeout_secs = self.timeout.as_secs_f64(),
+            error_type = error_type::TIMED_OUT,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::TIMED_OUT,
+            "stage" => error_stage::SENDING,
+        )
+        .increment(1);
+        counter!("component_acknowledgement_timeouts_total").increment(self.count as u64);
+    }
+}
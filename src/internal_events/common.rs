@@ -147,3 +147,45 @@ impl<E: std::fmt::Display> InternalEvent for SinkRequestBuildError<E> {
         .increment(1);
     }
 }
+
+#[derive(Debug)]
+pub struct SinkSendError<E> {
+    pub error: E,
+}
+
+impl<E: std::fmt::Display> InternalEvent for SinkSendError<E> {
+    fn emit(self) {
+        error!(
+            message = "Failed to send request.",
+            error = %self.error,
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::SENDING,
+        )
+        .increment(1);
+    }
+}
+
+#[derive(Debug)]
+pub struct SinkEventsRerouted<'a> {
+    pub count: usize,
+    pub reason: &'a str,
+}
+
+impl InternalEvent for SinkEventsRerouted<'_> {
+    fn emit(self) {
+        debug!(
+            message = "Events rerouted to the dropped output.",
+            count = self.count,
+            reason = self.reason,
+            internal_log_rate_limit = true,
+        );
+        counter!("component_rerouted_events_total", "reason" => self.reason.to_owned())
+            .increment(self.count as u64);
+    }
+}
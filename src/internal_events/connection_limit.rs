@@ -0,0 +1,67 @@
+use metrics::{counter, gauge};
+use vector_lib::internal_event::InternalEvent;
+
+use super::SocketMode;
+
+#[derive(Debug)]
+pub struct SocketConnectionsOpen<'a> {
+    pub mode: SocketMode,
+    pub listener: &'a str,
+    pub count: usize,
+}
+
+impl InternalEvent for SocketConnectionsOpen<'_> {
+    fn emit(self) {
+        gauge!(
+            "open_connections",
+            "mode" => self.mode.as_str(),
+            "listener" => self.listener.to_owned(),
+        )
+        .set(self.count as f64);
+    }
+}
+
+#[derive(Debug)]
+pub struct SocketConnectionAccepted<'a> {
+    pub mode: SocketMode,
+    pub listener: &'a str,
+}
+
+impl InternalEvent for SocketConnectionAccepted<'_> {
+    fn emit(self) {
+        counter!(
+            "connections_accepted_total",
+            "mode" => self.mode.as_str(),
+            "listener" => self.listener.to_owned(),
+        )
+        .increment(1);
+    }
+}
+
+#[derive(Debug)]
+pub struct SocketConnectionRejected<'a> {
+    pub mode: SocketMode,
+    pub listener: &'a str,
+    pub peer: &'a str,
+    pub max_connections: u32,
+}
+
+impl InternalEvent for SocketConnectionRejected<'_> {
+    fn emit(self) {
+        let mode = self.mode.as_str();
+        warn!(
+            message = "Rejected connection, as the connection limit is reached.",
+            peer = %self.peer,
+            listener = %self.listener,
+            max_connections = %self.max_connections,
+            %mode,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "connections_rejected_total",
+            "mode" => mode,
+            "listener" => self.listener.to_owned(),
+        )
+        .increment(1);
+    }
+}
@@ -1,3 +1,4 @@
+use metrics::counter;
 use vector_lib::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIONAL};
 
 #[derive(Debug)]
@@ -13,3 +14,21 @@ impl InternalEvent for DedupeEventsDropped {
         });
     }
 }
+
+#[derive(Debug)]
+pub struct DedupeCacheEntriesRemoved {
+    pub expired: usize,
+    pub evicted: usize,
+}
+
+impl InternalEvent for DedupeCacheEntriesRemoved {
+    fn emit(self) {
+        trace!(
+            message = "Removed entries from the deduplication cache.",
+            expired = self.expired,
+            evicted = self.evicted,
+        );
+        counter!("dedupe_cache_expired_entries_total").increment(self.expired as u64);
+        counter!("dedupe_cache_evicted_entries_total").increment(self.evicted as u64);
+    }
+}
@@ -98,6 +98,7 @@ impl InternalEvent for ExecTimeoutError<'_> {
 pub struct ExecCommandExecuted<'a> {
     pub command: &'a str,
     pub exit_status: Option<i32>,
+    pub signal: Option<String>,
     pub exec_duration: Duration,
 }
 
@@ -108,6 +109,15 @@ impl ExecCommandExecuted<'_> {
             None => "unknown".to_string(),
         }
     }
+
+    /// The exit code of the command, or the name of the signal that terminated it.
+    fn exit_string(&self) -> String {
+        match (self.exit_status, &self.signal) {
+            (Some(exit_status), _) => exit_status.to_string(),
+            (None, Some(signal)) => signal.clone(),
+            (None, None) => "unknown".to_string(),
+        }
+    }
 }
 
 impl InternalEvent for ExecCommandExecuted<'_> {
@@ -117,6 +127,7 @@ impl InternalEvent for ExecCommandExecuted<'_> {
             message = "Executed command.",
             command = %self.command,
             exit_status = %exit_status,
+            signal = ?self.signal,
             elapsed_millis = %self.exec_duration.as_millis(),
             internal_log_rate_limit = true,
         );
@@ -126,6 +137,12 @@ impl InternalEvent for ExecCommandExecuted<'_> {
             "exit_status" => exit_status.clone(),
         )
         .increment(1);
+        counter!(
+            "exec_command_exits_total",
+            "command" => self.command.to_owned(),
+            "exit_status" => self.exit_string(),
+        )
+        .increment(1);
 
         histogram!(
             "command_execution_duration_seconds",
@@ -136,6 +153,73 @@ impl InternalEvent for ExecCommandExecuted<'_> {
     }
 }
 
+#[derive(Debug)]
+pub struct ExecCommandRespawning<'a> {
+    pub command: &'a str,
+    pub failures: u32,
+    pub delay: Duration,
+}
+
+impl InternalEvent for ExecCommandRespawning<'_> {
+    fn emit(self) {
+        debug!(
+            message = "Restarting streaming process.",
+            command = %self.command,
+            failures = self.failures,
+            delay_ms = %self.delay.as_millis(),
+        );
+        counter!(
+            "exec_command_respawns_total",
+            "command" => self.command.to_owned(),
+        )
+        .increment(1);
+    }
+}
+
+#[derive(Debug)]
+pub struct ExecRespawnsExhausted<'a> {
+    pub command: &'a str,
+    pub max_respawns: u32,
+}
+
+impl InternalEvent for ExecRespawnsExhausted<'_> {
+    fn emit(self) {
+        error!(
+            message = "Streaming process exited unsuccessfully too many times in a row. Stopping source.",
+            command = %self.command,
+            max_respawns = self.max_respawns,
+            error_type = error_type::COMMAND_FAILED,
+            error_code = "max_respawns",
+            stage = error_stage::RECEIVING,
+        );
+        counter!(
+            "component_errors_total",
+            "command" => self.command.to_owned(),
+            "error_type" => error_type::COMMAND_FAILED,
+            "error_code" => "max_respawns",
+            "stage" => error_stage::RECEIVING,
+        )
+        .increment(1);
+    }
+}
+
+#[derive(Debug)]
+pub struct ExecStderrLine<'a> {
+    pub command: &'a str,
+    pub line: &'a str,
+}
+
+impl InternalEvent for ExecStderrLine<'_> {
+    fn emit(self) {
+        info!(
+            message = "Command wrote to its standard error.",
+            command = %self.command,
+            line = %self.line,
+            internal_log_rate_limit = true,
+        );
+    }
+}
+
 pub enum ExecFailedToSignalChild {
     #[cfg(unix)]
     SignalError(nix::errno::Errno),
@@ -0,0 +1,191 @@
+use std::time::Duration;
+
+use metrics::counter;
+use vector_lib::internal_event::InternalEvent;
+use vector_lib::internal_event::{error_stage, error_type, ComponentEventsDropped, UNINTENTIONAL};
+
+use super::prelude::io_error_code;
+
+#[derive(Debug)]
+pub struct ExecSinkCommandError<'a> {
+    pub command: &'a str,
+    pub error: std::io::Error,
+}
+
+impl InternalEvent for ExecSinkCommandError<'_> {
+    fn emit(self) {
+        error!(
+            message = "Unable to run command.",
+            command = %self.command,
+            error = %self.error,
+            error_type = error_type::COMMAND_FAILED,
+            error_code = %io_error_code(&self.error),
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::COMMAND_FAILED,
+            "error_code" => io_error_code(&self.error),
+            "stage" => error_stage::SENDING,
+        )
+        .increment(1);
+    }
+}
+
+#[derive(Debug)]
+pub struct ExecSinkWriteError<'a> {
+    pub command: &'a str,
+    pub error: std::io::Error,
+    pub count: usize,
+}
+
+impl InternalEvent for ExecSinkWriteError<'_> {
+    fn emit(self) {
+        let reason = "Unable to write events to the standard input of the command.";
+        error!(
+            message = reason,
+            command = %self.command,
+            error = %self.error,
+            error_type = error_type::WRITER_FAILED,
+            error_code = %io_error_code(&self.error),
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::WRITER_FAILED,
+            "error_code" => io_error_code(&self.error),
+            "stage" => error_stage::SENDING,
+        )
+        .increment(1);
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+            count: self.count,
+            reason,
+        });
+    }
+}
+
+#[derive(Debug)]
+pub struct ExecSinkCommandFailed<'a> {
+    pub command: &'a str,
+    pub exit_status: Option<i32>,
+    pub count: usize,
+}
+
+impl InternalEvent for ExecSinkCommandFailed<'_> {
+    fn emit(self) {
+        let reason = "Command exited with a non-zero exit status.";
+        error!(
+            message = reason,
+            command = %self.command,
+            exit_status = ?self.exit_status,
+            error_type = error_type::COMMAND_FAILED,
+            error_code = "exit_status",
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::COMMAND_FAILED,
+            "error_code" => "exit_status",
+            "stage" => error_stage::SENDING,
+        )
+        .increment(1);
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+            count: self.count,
+            reason,
+        });
+    }
+}
+
+#[derive(Debug)]
+pub struct ExecSinkTimeoutError<'a> {
+    pub command: &'a str,
+    pub timeout: Duration,
+    pub count: usize,
+}
+
+impl InternalEvent for ExecSinkTimeoutError<'_> {
+    fn emit(self) {
+        let reason = "Command did not exit in time and was killed.";
+        error!(
+            message = reason,
+            command = %self.command,
+            timeout_secs = self.timeout.as_secs_f64(),
+            error_type = error_type::TIMED_OUT,
+            stage = error_stage::SENDING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::TIMED_OUT,
+            "stage" => error_stage::SENDING,
+        )
+        .increment(1);
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+            count: self.count,
+            reason,
+        });
+    }
+}
+
+#[derive(Debug)]
+pub struct ExecSinkCommandExited<'a> {
+    pub command: &'a str,
+    pub exit_status: Option<i32>,
+}
+
+impl InternalEvent for ExecSinkCommandExited<'_> {
+    fn emit(self) {
+        warn!(
+            message = "Command exited, restarting it.",
+            command = %self.command,
+            exit_status = ?self.exit_status,
+            internal_log_rate_limit = true,
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct ExecSinkRestartsExhausted<'a> {
+    pub command: &'a str,
+    pub max_restarts: u32,
+}
+
+impl InternalEvent for ExecSinkRestartsExhausted<'_> {
+    fn emit(self) {
+        error!(
+            message = "Command exited too many times in a row. Stopping sink.",
+            command = %self.command,
+            max_restarts = self.max_restarts,
+            error_type = error_type::COMMAND_FAILED,
+            error_code = "max_restarts",
+            stage = error_stage::SENDING,
+        );
+        counter!(
+            "component_errors_total",
+            "error_type" => error_type::COMMAND_FAILED,
+            "error_code" => "max_restarts",
+            "stage" => error_stage::SENDING,
+        )
+        .increment(1);
+    }
+}
+
+#[derive(Debug)]
+pub struct ExecSinkStderrLine<'a> {
+    pub command: &'a str,
+    pub line: &'a str,
+}
+
+impl InternalEvent for ExecSinkStderrLine<'_> {
+    fn emit(self) {
+        info!(
+            message = "Command wrote to its standard error.",
+            command = %self.command,
+            line = %self.line,
+            internal_log_rate_limit = true,
+        );
+    }
+}
@@ -102,6 +102,23 @@ impl<'a, P: std::fmt::Debug> InternalEvent for FileIoError<'a, P> {
     }
 }
 
+#[derive(Debug)]
+pub struct FileOwnershipError<'a> {
+    pub error: std::io::Error,
+    pub path: &'a std::path::Path,
+}
+
+impl InternalEvent for FileOwnershipError<'_> {
+    fn emit(self) {
+        warn!(
+            message = "Unable to change the ownership of the file. Keeping its current owner.",
+            path = ?self.path,
+            error = %self.error,
+            internal_log_rate_limit = true,
+        );
+    }
+}
+
 #[cfg(any(feature = "sources-file", feature = "sources-kubernetes_logs"))]
 mod source {
     use std::{io::Error, path::Path, time::Duration};
@@ -0,0 +1,18 @@
+use metrics::counter;
+use vector_lib::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct FlattenKeyCollision<'a> {
+    pub key: &'a str,
+}
+
+impl InternalEvent for FlattenKeyCollision<'_> {
+    fn emit(self) {
+        warn!(
+            message = "Flattened fields collided, keeping their values in an array.",
+            key = %self.key,
+            internal_log_rate_limit = true,
+        );
+        counter!("flatten_key_collisions_total").increment(1);
+    }
+}
@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use metrics::{counter, gauge};
+use vector_lib::internal_event::InternalEvent;
+
+use crate::{config::ComponentKey, topology::health::HealthStatus};
+
+#[derive(Debug)]
+pub struct SinkHealthChanged<'a> {
+    pub component_id: &'a ComponentKey,
+    pub status: HealthStatus,
+    pub error: Option<&'a str>,
+}
+
+impl InternalEvent for SinkHealthChanged<'_> {
+    fn emit(self) {
+        match self.status {
+            HealthStatus::Healthy => info!(
+                message = "Sink became healthy.",
+                component_id = %self.component_id.id(),
+            ),
+            HealthStatus::Degraded => warn!(
+                message = "Sink became degraded.",
+                component_id = %self.component_id.id(),
+                reason = self.error,
+            ),
+            HealthStatus::Unhealthy => warn!(
+                message = "Sink became unhealthy.",
+                component_id = %self.component_id.id(),
+                error = self.error,
+            ),
+        }
+        counter!(
+            "component_health_transitions_total",
+            "status" => self.status.as_str(),
+        )
+        .increment(1);
+    }
+}
+
+#[derive(Debug)]
+pub struct SinkHealthcheckCompleted {
+    pub status: HealthStatus,
+    pub duration: Duration,
+}
+
+impl InternalEvent for SinkHealthcheckCompleted {
+    fn emit(self) {
+        trace!(
+            message = "Healthcheck completed.",
+            status = self.status.as_str(),
+            duration = ?self.duration,
+        );
+        let status = match self.status {
+            HealthStatus::Unhealthy => 0.0,
+            HealthStatus::Degraded => 1.0,
+            HealthStatus::Healthy => 2.0,
+        };
+        gauge!("component_healthcheck_status").set(status);
+        gauge!("component_healthcheck_duration_seconds").set(self.duration.as_secs_f64());
+    }
+}
@@ -100,3 +100,32 @@ impl InternalEvent for LokiTimestampNonParsableEventsDropped {
         .increment(1);
     }
 }
+
+#[derive(Debug)]
+pub struct LokiStructuredMetadataUnsupported;
+
+impl InternalEvent for LokiStructuredMetadataUnsupported {
+    fn emit(self) {
+        warn!(
+            message = "Structured metadata is configured, but isn't supported by the Loki instance. Structured metadata won't be sent.",
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct LokiStructuredMetadataValueTruncated<'a> {
+    pub key: &'a str,
+    pub max_length: usize,
+}
+
+impl InternalEvent for LokiStructuredMetadataValueTruncated<'_> {
+    fn emit(self) {
+        warn!(
+            message = "Structured metadata value exceeds the maximum length and was truncated.",
+            key = %self.key,
+            max_length = %self.max_length,
+            internal_log_rate_limit = true,
+        );
+        counter!("loki_structured_metadata_truncated_total").increment(1);
+    }
+}
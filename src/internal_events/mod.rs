@@ -1,6 +1,7 @@
 #![allow(missing_docs)]
 pub mod prelude;
 
+mod acknowledgements;
 mod adaptive_concurrency;
 mod aggregate;
 #[cfg(any(feature = "sources-amqp", feature = "sinks-amqp"))]
@@ -30,6 +31,11 @@ mod batch;
 mod codecs;
 mod common;
 mod conditions;
+#[cfg(any(
+    feature = "sources-utils-net-tcp",
+    all(unix, feature = "sources-utils-net-unix")
+))]
+mod connection_limit;
 #[cfg(feature = "sources-datadog_agent")]
 mod datadog_agent;
 #[cfg(feature = "sinks-datadog_metrics")]
@@ -49,16 +55,21 @@ mod encoding_transcode;
 mod eventstoredb_metrics;
 #[cfg(feature = "sources-exec")]
 mod exec;
+#[cfg(feature = "sinks-exec")]
+mod exec_sink;
 #[cfg(any(feature = "sources-file_descriptor", feature = "sources-stdin"))]
 mod file_descriptor;
 #[cfg(feature = "transforms-filter")]
 mod filter;
+#[cfg(feature = "transforms-flatten")]
+mod flatten;
 #[cfg(feature = "sources-fluent")]
 mod fluent;
 #[cfg(feature = "sources-gcp_pubsub")]
 mod gcp_pubsub;
 #[cfg(any(feature = "sources-vector", feature = "sources-opentelemetry"))]
 mod grpc;
+mod healthcheck;
 mod heartbeat;
 #[cfg(feature = "sources-host_metrics")]
 mod host_metrics;
@@ -91,6 +102,8 @@ mod mongodb_metrics;
 mod mqtt;
 #[cfg(feature = "sources-nginx_metrics")]
 mod nginx_metrics;
+#[cfg(feature = "transforms-normalize_severity")]
+mod normalize_severity;
 mod open;
 mod parser;
 #[cfg(feature = "sources-postgresql_metrics")]
@@ -109,9 +122,12 @@ mod redis;
 #[cfg(feature = "transforms-impl-reduce")]
 mod reduce;
 mod remap;
+#[cfg(feature = "transforms-rollup")]
+mod rollup;
 mod sample;
 #[cfg(feature = "sinks-sematext")]
 mod sematext_metrics;
+mod sender;
 mod socket;
 #[cfg(any(feature = "sources-splunk_hec", feature = "sinks-splunk_hec"))]
 mod splunk_hec;
@@ -165,6 +181,11 @@ pub(crate) use self::aws_kinesis_firehose::*;
 #[cfg(any(feature = "sources-aws_s3", feature = "sources-aws_sqs",))]
 pub(crate) use self::aws_sqs::*;
 pub(crate) use self::codecs::*;
+#[cfg(any(
+    feature = "sources-utils-net-tcp",
+    all(unix, feature = "sources-utils-net-unix")
+))]
+pub(crate) use self::connection_limit::*;
 #[cfg(feature = "sources-datadog_agent")]
 pub(crate) use self::datadog_agent::*;
 #[cfg(feature = "sinks-datadog_metrics")]
@@ -183,6 +204,8 @@ pub(crate) use self::docker_logs::*;
 pub(crate) use self::eventstoredb_metrics::*;
 #[cfg(feature = "sources-exec")]
 pub(crate) use self::exec::*;
+#[cfg(feature = "sinks-exec")]
+pub(crate) use self::exec_sink::*;
 #[cfg(any(
     feature = "sources-file",
     feature = "sources-kubernetes_logs",
@@ -193,6 +216,8 @@ pub(crate) use self::file::*;
 pub(crate) use self::file_descriptor::*;
 #[cfg(feature = "transforms-filter")]
 pub(crate) use self::filter::*;
+#[cfg(feature = "transforms-flatten")]
+pub(crate) use self::flatten::*;
 #[cfg(feature = "sources-fluent")]
 pub(crate) use self::fluent::*;
 #[cfg(feature = "sources-gcp_pubsub")]
@@ -227,6 +252,8 @@ pub(crate) use self::metric_to_log::*;
 pub(crate) use self::mqtt::*;
 #[cfg(feature = "sources-nginx_metrics")]
 pub(crate) use self::nginx_metrics::*;
+#[cfg(feature = "transforms-normalize_severity")]
+pub(crate) use self::normalize_severity::*;
 #[allow(unused_imports)]
 pub(crate) use self::parser::*;
 #[cfg(feature = "sources-postgresql_metrics")]
@@ -245,6 +272,8 @@ pub(crate) use self::redis::*;
 pub(crate) use self::reduce::*;
 #[cfg(feature = "transforms-remap")]
 pub(crate) use self::remap::*;
+#[cfg(feature = "transforms-rollup")]
+pub(crate) use self::rollup::*;
 #[cfg(feature = "transforms-impl-sample")]
 pub(crate) use self::sample::*;
 #[cfg(feature = "sinks-sematext")]
@@ -264,6 +293,7 @@ pub(crate) use self::websocket::*;
 #[cfg(windows)]
 pub(crate) use self::windows::*;
 pub use self::{
-    adaptive_concurrency::*, batch::*, common::*, conditions::*, encoding_transcode::*,
-    heartbeat::*, http::*, open::*, process::*, socket::*, tcp::*, template::*, udp::*,
+    acknowledgements::*, adaptive_concurrency::*, batch::*, common::*, conditions::*,
+    encoding_transcode::*, healthcheck::*, heartbeat::*, http::*, open::*, process::*, sender::*,
+    socket::*, tcp::*, template::*, udp::*,
 };
@@ -0,0 +1,19 @@
+use metrics::counter;
+use vector_lib::internal_event::InternalEvent;
+use vector_lib::lookup::OwnedTargetPath;
+
+#[derive(Debug)]
+pub struct NormalizeSeverityUnknownValue<'a> {
+    pub field: &'a OwnedTargetPath,
+}
+
+impl InternalEvent for NormalizeSeverityUnknownValue<'_> {
+    fn emit(self) {
+        debug!(
+            message = "Field does not hold a known severity.",
+            field = %self.field,
+            internal_log_rate_limit = true,
+        );
+        counter!("normalize_severity_unknown_values_total").increment(1);
+    }
+}
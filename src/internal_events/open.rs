@@ -53,7 +53,10 @@ impl OpenGauge {
         }
     }
 
-    #[cfg(all(feature = "sources-utils-net-unix", unix))]
+    #[cfg(any(
+        feature = "sources-utils-net-tcp",
+        all(feature = "sources-utils-net-unix", unix)
+    ))]
     pub fn any_open(&self) -> bool {
         self.gauge.load(Ordering::Acquire) != 0
     }
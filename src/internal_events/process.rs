@@ -47,6 +47,26 @@ impl InternalEvent for VectorReloaded<'_> {
     }
 }
 
+#[derive(Debug)]
+pub struct VectorPanicked<'a> {
+    pub message: &'a str,
+    pub location: Option<String>,
+    pub backtrace: Option<String>,
+}
+
+impl InternalEvent for VectorPanicked<'_> {
+    fn emit(self) {
+        error!(
+            target: "vector",
+            message = "Vector has panicked.",
+            panic_message = self.message,
+            location = self.location.as_deref(),
+            backtrace = self.backtrace.as_deref(),
+        );
+        counter!("panics_total").increment(1);
+    }
+}
+
 #[derive(Debug)]
 pub struct VectorStopped;
 
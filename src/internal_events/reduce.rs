@@ -12,6 +12,22 @@ impl InternalEvent for ReduceStaleEventFlushed {
     }
 }
 
+#[derive(Debug)]
+pub struct ReduceGroupTruncated {
+    pub limit: &'static str,
+}
+
+impl InternalEvent for ReduceGroupTruncated {
+    fn emit(self) {
+        debug!(
+            message = "Flushing group that reached its limit.",
+            limit = self.limit,
+            internal_log_rate_limit = true,
+        );
+        counter!("reduce_groups_truncated_total", "limit" => self.limit).increment(1);
+    }
+}
+
 #[derive(Debug)]
 pub struct ReduceAddEventError {
     pub error: PathParseError,
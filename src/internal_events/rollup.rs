@@ -0,0 +1,38 @@
+use metrics::{counter, gauge};
+use vector_lib::internal_event::InternalEvent;
+
+#[derive(Debug)]
+pub struct RollupEventCollapsed;
+
+impl InternalEvent for RollupEventCollapsed {
+    fn emit(self) {
+        counter!("rollup_events_collapsed_total").increment(1);
+    }
+}
+
+#[derive(Debug)]
+pub struct RollupGroups {
+    pub count: usize,
+}
+
+impl InternalEvent for RollupGroups {
+    fn emit(self) {
+        gauge!("rollup_groups").set(self.count as f64);
+    }
+}
+
+#[derive(Debug)]
+pub struct RollupMaxGroupsReached {
+    pub max_groups: usize,
+}
+
+impl InternalEvent for RollupMaxGroupsReached {
+    fn emit(self) {
+        debug!(
+            message = "Maximum number of rollup groups reached, forwarding event as is.",
+            max_groups = self.max_groups,
+            internal_log_rate_limit = true
+        );
+        counter!("rollup_overflow_events_total").increment(1);
+    }
+}
@@ -0,0 +1,33 @@
+use std::time::Duration;
+
+use metrics::{gauge, histogram, Gauge, Histogram};
+
+registered_event! {
+    SinkSendInFlight => {
+        in_flight: Gauge = gauge!("sink_send_in_flight"),
+    }
+
+    fn emit(&self, in_flight: usize) {
+        self.in_flight.set(in_flight as f64);
+    }
+}
+
+registered_event! {
+    SinkSendDuration => {
+        duration: Histogram = histogram!("sink_send_duration_seconds"),
+    }
+
+    fn emit(&self, duration: Duration) {
+        self.duration.record(duration);
+    }
+}
+
+registered_event! {
+    SinkSendUtilization => {
+        utilization: Gauge = gauge!("sink_send_utilization"),
+    }
+
+    fn emit(&self, utilization: f64) {
+        self.utilization.set(utilization);
+    }
+}
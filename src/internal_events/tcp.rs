@@ -140,6 +140,26 @@ impl InternalEvent for TcpSendAckError {
     }
 }
 
+#[cfg(feature = "sources-utils-net-tcp")]
+#[derive(Debug)]
+pub struct TcpConnectionIdleTimeout {
+    pub peer_addr: SocketAddr,
+    pub timeout: std::time::Duration,
+}
+
+#[cfg(feature = "sources-utils-net-tcp")]
+impl InternalEvent for TcpConnectionIdleTimeout {
+    fn emit(self) {
+        debug!(
+            message = "Closing idle connection.",
+            peer_addr = %self.peer_addr,
+            timeout_secs = self.timeout.as_secs_f64(),
+            internal_log_rate_limit = true,
+        );
+        counter!("connection_idle_timeouts_total", "mode" => "tcp").increment(1);
+    }
+}
+
 #[derive(Debug)]
 pub struct TcpBytesReceived {
     pub byte_size: usize,
@@ -5,6 +5,8 @@ use vector_lib::internal_event::{ComponentEventsDropped, InternalEvent, INTENTIO
 pub(crate) struct ThrottleEventDiscarded {
     pub key: String,
     pub emit_events_discarded_per_key: bool,
+    /// The `key` tag of the `throttle_events_discarded_total` counter, if it is emitted.
+    pub discarded_key: Option<String>,
 }
 
 impl InternalEvent for ThrottleEventDiscarded {
@@ -24,6 +26,9 @@ impl InternalEvent for ThrottleEventDiscarded {
             // only specified when relevant.
             counter!("events_discarded_total", "key" => self.key).increment(1); // Deprecated.
         }
+        if let Some(key) = self.discarded_key {
+            counter!("throttle_events_discarded_total", "key" => key).increment(1);
+        }
 
         emit!(ComponentEventsDropped::<INTENTIONAL> {
             count: 1,
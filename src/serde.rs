@@ -16,6 +16,10 @@ pub const fn default_false() -> bool {
     false
 }
 
+pub const fn is_true(value: &bool) -> bool {
+    *value
+}
+
 /// The default max length of the input buffer.
 ///
 /// Any input exceeding this limit will be discarded.
@@ -100,7 +100,7 @@ impl GenerateConfig for AmqpSinkConfig {
 impl SinkConfig for AmqpSinkConfig {
     async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
         let sink = AmqpSink::new(self.clone()).await?;
-        let hc = healthcheck(Arc::clone(&sink.channel)).boxed();
+        let hc = healthcheck(Arc::clone(&sink.channel)).into_healthcheck();
         Ok((VectorSink::from_event_streamsink(sink), hc))
     }
 
@@ -1,4 +1,3 @@
-use futures::FutureExt;
 use http::{header::AUTHORIZATION, Request, Uri};
 use hyper::Body;
 use tower::ServiceBuilder;
@@ -18,7 +17,7 @@ use crate::{
             http::HttpStatusRetryLogic, BatchConfig, Compression, ServiceBuilderExt,
             SinkBatchSettings, TowerRequestConfig,
         },
-        BuildError, Healthcheck, HealthcheckError, VectorSink,
+        BuildError, Healthcheck, HealthcheckError, IntoHealthcheck, VectorSink,
     },
 };
 
@@ -130,7 +129,7 @@ impl SinkConfig for AppsignalConfig {
             self.push_api_key.inner().to_string(),
             client.clone(),
         )
-        .boxed();
+        .into_healthcheck();
         let sink = self.build_sink(client)?;
 
         Ok((sink, healthcheck))
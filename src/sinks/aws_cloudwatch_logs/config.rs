@@ -1,5 +1,4 @@
 use aws_sdk_cloudwatchlogs::Client as CloudwatchLogsClient;
-use futures::FutureExt;
 use serde::{de, Deserialize, Deserializer};
 use tower::ServiceBuilder;
 use vector_lib::codecs::JsonSerializerConfig;
@@ -22,7 +21,7 @@ use crate::{
         util::{
             http::RequestConfig, BatchConfig, Compression, ServiceBuilderExt, SinkBatchSettings,
         },
-        Healthcheck, VectorSink,
+        Healthcheck, IntoHealthcheck, VectorSink,
     },
     template::Template,
     tls::TlsConfig,
@@ -196,7 +195,7 @@ impl SinkConfig for CloudwatchLogsSinkConfig {
         let transformer = self.encoding.transformer();
         let serializer = self.encoding.build()?;
         let encoder = Encoder::<()>::new(serializer);
-        let healthcheck = healthcheck(self.clone(), client).boxed();
+        let healthcheck = healthcheck(self.clone(), client).into_healthcheck();
         let sink = CloudwatchSink {
             batcher_settings,
             request_builder: CloudwatchRequestBuilder {
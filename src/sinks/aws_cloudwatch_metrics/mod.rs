@@ -35,7 +35,7 @@ use crate::{
     tls::TlsConfig,
 };
 
-use super::util::service::TowerRequestConfigDefaults;
+use super::{util::service::TowerRequestConfigDefaults, IntoHealthcheck};
 
 #[derive(Clone, Copy, Debug, Default)]
 pub struct CloudWatchMetricsDefaultBatchSettings;
@@ -132,7 +132,7 @@ impl SinkConfig for CloudWatchMetricsSinkConfig {
         cx: SinkContext,
     ) -> crate::Result<(super::VectorSink, super::Healthcheck)> {
         let client = self.create_client(&cx.proxy).await?;
-        let healthcheck = self.clone().healthcheck(client.clone()).boxed();
+        let healthcheck = self.clone().healthcheck(client.clone()).into_healthcheck();
         let sink = CloudWatchMetricsSvc::new(self.clone(), client)?;
         Ok((sink, healthcheck))
     }
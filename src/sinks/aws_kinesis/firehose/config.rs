@@ -2,7 +2,6 @@ use aws_sdk_firehose::operation::{
     describe_delivery_stream::DescribeDeliveryStreamError, put_record_batch::PutRecordBatchError,
 };
 use aws_smithy_runtime_api::client::{orchestrator::HttpResponse, result::SdkError};
-use futures::FutureExt;
 use snafu::Snafu;
 use vector_lib::configurable::configurable_component;
 
@@ -12,7 +11,7 @@ use crate::{
     config::{AcknowledgementsConfig, GenerateConfig, Input, ProxyConfig, SinkConfig, SinkContext},
     sinks::{
         util::{retries::RetryLogic, BatchConfig, SinkBatchSettings},
-        Healthcheck, VectorSink,
+        Healthcheck, IntoHealthcheck, VectorSink,
     },
 };
 
@@ -118,7 +117,7 @@ impl KinesisFirehoseSinkConfig {
 impl SinkConfig for KinesisFirehoseSinkConfig {
     async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
         let client = self.create_client(&cx.proxy).await?;
-        let healthcheck = self.clone().healthcheck(client.clone()).boxed();
+        let healthcheck = self.clone().healthcheck(client.clone()).into_healthcheck();
 
         let batch_settings = self
             .batch
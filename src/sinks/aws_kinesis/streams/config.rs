@@ -1,7 +1,6 @@
 use aws_sdk_kinesis::operation::describe_stream::DescribeStreamError;
 use aws_sdk_kinesis::operation::put_records::PutRecordsError;
 use aws_smithy_runtime_api::client::{orchestrator::HttpResponse, result::SdkError};
-use futures::FutureExt;
 use snafu::Snafu;
 use vector_lib::configurable::{component::GenerateConfig, configurable_component};
 
@@ -11,7 +10,7 @@ use crate::{
     config::{AcknowledgementsConfig, Input, ProxyConfig, SinkConfig, SinkContext},
     sinks::{
         util::{retries::RetryLogic, BatchConfig, SinkBatchSettings},
-        Healthcheck, VectorSink,
+        Healthcheck, IntoHealthcheck, VectorSink,
     },
 };
 
@@ -115,7 +114,7 @@ impl KinesisStreamsSinkConfig {
 impl SinkConfig for KinesisStreamsSinkConfig {
     async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
         let client = self.create_client(&cx.proxy).await?;
-        let healthcheck = self.clone().healthcheck(client.clone()).boxed();
+        let healthcheck = self.clone().healthcheck(client.clone()).into_healthcheck();
 
         let batch_settings = self
             .batch
@@ -13,6 +13,7 @@ use super::{
 };
 use crate::aws::create_client;
 use crate::aws::ClientBuilder;
+use crate::sinks::IntoHealthcheck;
 
 /// Configuration for the `aws_sns` sink.
 #[configurable_component(sink(
@@ -70,7 +71,7 @@ impl SinkConfig for SnsSinkConfig {
 
         let publisher = SnsMessagePublisher::new(client.clone(), self.topic_arn.clone());
 
-        let healthcheck = Box::pin(healthcheck(client.clone(), self.topic_arn.clone()));
+        let healthcheck = healthcheck(client.clone(), self.topic_arn.clone()).into_healthcheck();
 
         let message_group_id = message_group_id(
             self.base_config.message_group_id.clone(),
@@ -11,7 +11,7 @@ use super::{
     client::SqsMessagePublisher, message_deduplication_id, message_group_id, BaseSSSinkConfig,
     SSRequestBuilder, SSSink,
 };
-use crate::{aws::create_client, common::sqs::SqsClientBuilder};
+use crate::{aws::create_client, common::sqs::SqsClientBuilder, sinks::IntoHealthcheck};
 
 /// Configuration for the `aws_sqs` sink.
 #[configurable_component(sink(
@@ -71,7 +71,7 @@ impl SinkConfig for SqsSinkConfig {
 
         let publisher = SqsMessagePublisher::new(client.clone(), self.queue_url.clone());
 
-        let healthcheck = Box::pin(healthcheck(client.clone(), self.queue_url.clone()));
+        let healthcheck = healthcheck(client.clone(), self.queue_url.clone()).into_healthcheck();
         let message_group_id = message_group_id(
             self.base_config.message_group_id.clone(),
             self.queue_url.ends_with(".fifo"),
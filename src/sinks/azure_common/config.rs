@@ -5,7 +5,6 @@ use azure_identity::{AutoRefreshingTokenCredential, DefaultAzureCredential};
 use azure_storage::{prelude::*, CloudLocation, ConnectionString};
 use azure_storage_blobs::{blob::operations::PutBlockBlobResponse, prelude::*};
 use bytes::Bytes;
-use futures::FutureExt;
 use http::StatusCode;
 use snafu::Snafu;
 use vector_lib::stream::DriverResponse;
@@ -16,7 +15,7 @@ use vector_lib::{
 
 use crate::{
     event::{EventFinalizers, EventStatus, Finalizable},
-    sinks::{util::retries::RetryLogic, Healthcheck},
+    sinks::{util::retries::RetryLogic, Healthcheck, IntoHealthcheck},
 };
 
 #[derive(Debug, Clone)]
@@ -120,7 +119,7 @@ pub fn build_healthcheck(
         resp
     };
 
-    Ok(healthcheck.boxed())
+    Ok(healthcheck.into_healthcheck())
 }
 
 pub fn build_client(
@@ -197,7 +197,7 @@ impl AzureMonitorLogsService {
     pub fn healthcheck(&self) -> Healthcheck {
         let mut client = self.client.clone();
         let request = self.build_request(Bytes::from("[]"));
-        Box::pin(async move {
+        async move {
             let request = request?;
             let res = client.call(request).in_current_span().await?;
 
@@ -220,7 +220,8 @@ impl AzureMonitorLogsService {
             }
 
             Ok(())
-        })
+        }
+        .into_healthcheck()
     }
 }
 
@@ -1,12 +1,11 @@
 use std::time::Duration;
 
-use futures::{future, FutureExt};
 use serde_with::serde_as;
 use vector_lib::configurable::configurable_component;
 
 use crate::{
     config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
-    sinks::{blackhole::sink::BlackholeSink, Healthcheck, VectorSink},
+    sinks::{blackhole::sink::BlackholeSink, healthy, Healthcheck, VectorSink},
 };
 
 const fn default_print_interval_secs() -> Duration {
@@ -53,7 +52,7 @@ pub struct BlackholeConfig {
 impl SinkConfig for BlackholeConfig {
     async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
         let sink = BlackholeSink::new(self.clone());
-        let healthcheck = future::ok(()).boxed();
+        let healthcheck = healthy();
 
         Ok((VectorSink::Stream(Box::new(sink)), healthcheck))
     }
@@ -174,7 +174,7 @@ impl SinkConfig for ClickhouseConfig {
             request_builder,
         );
 
-        let healthcheck = Box::pin(healthcheck(client, endpoint, auth));
+        let healthcheck = healthcheck(client, endpoint, auth).into_healthcheck();
 
         Ok((VectorSink::from_event_streamsink(sink), healthcheck))
     }
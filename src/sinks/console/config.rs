@@ -1,4 +1,3 @@
-use futures::{future, FutureExt};
 use tokio::io;
 use vector_lib::codecs::{
     encoding::{Framer, FramingConfig},
@@ -8,8 +7,11 @@ use vector_lib::configurable::configurable_component;
 
 use crate::{
     codecs::{Encoder, EncodingConfigWithFraming, SinkType},
-    config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
-    sinks::{console::sink::WriterSink, Healthcheck, VectorSink},
+    config::{
+        AcknowledgementsConfig, EncodableSink, GenerateConfig, Input, PayloadEncoder, SinkConfig,
+        SinkContext,
+    },
+    sinks::{console::sink::WriterSink, healthy, Healthcheck, VectorSink},
 };
 
 /// The [standard stream][standard_streams] to write to.
@@ -92,7 +94,7 @@ impl SinkConfig for ConsoleSinkConfig {
             }),
         };
 
-        Ok((sink, future::ok(()).boxed()))
+        Ok((sink, healthy()))
     }
 
     fn input(&self) -> Input {
@@ -102,6 +104,21 @@ impl SinkConfig for ConsoleSinkConfig {
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
         &self.acknowledgements
     }
+
+    fn as_encodable(&self) -> Option<&dyn EncodableSink> {
+        Some(self)
+    }
+}
+
+impl EncodableSink for ConsoleSinkConfig {
+    fn build_payload_encoder(&self) -> crate::Result<PayloadEncoder> {
+        let transformer = self.encoding.transformer();
+        let (framer, serializer) = self.encoding.build(SinkType::StreamBased)?;
+        Ok(Box::new((
+            transformer,
+            Encoder::<Framer>::new(framer, serializer),
+        )))
+    }
 }
 
 #[cfg(test)]
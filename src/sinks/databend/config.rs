@@ -1,7 +1,6 @@
 use std::collections::BTreeMap;
 
 use databend_client::APIClient as DatabendAPIClient;
-use futures::future::FutureExt;
 use tower::ServiceBuilder;
 use vector_lib::codecs::encoding::{Framer, FramingConfig};
 use vector_lib::configurable::{component::GenerateConfig, configurable_component};
@@ -15,7 +14,7 @@ use crate::{
             BatchConfig, Compression, RealtimeSizeBasedDefaultBatchSettings, ServiceBuilderExt,
             TowerRequestConfig, UriSerde,
         },
-        Healthcheck, VectorSink,
+        Healthcheck, IntoHealthcheck, VectorSink,
     },
     tls::TlsConfig,
     vector_version,
@@ -137,7 +136,7 @@ impl SinkConfig for DatabendConfig {
         }
         let endpoint = endpoint.to_string();
         let health_client = DatabendAPIClient::new(&endpoint, Some(ua.clone())).await?;
-        let healthcheck = select_one(health_client).boxed();
+        let healthcheck = select_one(health_client).into_healthcheck();
 
         let request_settings = self.request.into_settings();
         let batch_settings = self.batch.into_batcher_settings()?;
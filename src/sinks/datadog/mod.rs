@@ -1,4 +1,3 @@
-use futures_util::FutureExt;
 use http::{Request, StatusCode, Uri};
 use hyper::body::Body;
 use snafu::Snafu;
@@ -7,7 +6,7 @@ use vector_lib::{
     sensitive_string::SensitiveString, tls::TlsEnableableConfig,
 };
 
-use super::Healthcheck;
+use super::{Healthcheck, IntoHealthcheck};
 use crate::{
     common::datadog,
     http::{HttpClient, HttpError},
@@ -142,7 +141,7 @@ impl DatadogCommonConfig {
 
         let api_key: String = self.default_api_key.clone().into();
 
-        Ok(build_healthcheck_future(client, validate_endpoint, api_key).boxed())
+        Ok(build_healthcheck_future(client, validate_endpoint, api_key).into_healthcheck())
     }
 
     /// Gets the API endpoint with a given suffix path.
@@ -1,9 +1,10 @@
 use std::{
     collections::{BTreeMap, HashMap},
     convert::TryFrom,
+    sync::Arc,
 };
 
-use futures::{FutureExt, TryFutureExt};
+use futures::FutureExt;
 use vector_lib::configurable::configurable_component;
 
 use crate::{
@@ -25,7 +26,7 @@ use crate::{
             http::RequestConfig, service::HealthConfig, BatchConfig, Compression,
             RealtimeSizeBasedDefaultBatchSettings,
         },
-        Healthcheck, VectorSink,
+        Healthcheck, HealthcheckBuilder, HealthcheckStatus, VectorSink,
     },
     template::Template,
     tls::TlsConfig,
@@ -568,16 +569,21 @@ impl SinkConfig for ElasticsearchConfig {
 
         let stream = VectorSink::from_event_streamsink(sink);
 
-        let healthcheck = futures::future::select_ok(
-            commons
-                .into_iter()
-                .map(move |common| common.healthcheck(client.clone()).boxed()),
-        )
-        .map_ok(|((), _)| ())
-        .boxed();
+        let healthcheck = healthcheck(commons, client).boxed();
         Ok((stream, healthcheck))
     }
 
+    async fn healthcheck_builder(
+        &self,
+        cx: &SinkContext,
+    ) -> crate::Result<Option<HealthcheckBuilder>> {
+        let commons = ElasticsearchCommon::parse_many(self, cx.proxy()).await?;
+        let client = HttpClient::new(commons[0].tls_settings.clone(), cx.proxy())?;
+        Ok(Some(Arc::new(move || {
+            healthcheck(commons.clone(), client.clone()).boxed()
+        })))
+    }
+
     fn input(&self) -> Input {
         let requirements = Requirement::empty().optional_meaning("timestamp", Kind::timestamp());
 
@@ -589,6 +595,32 @@ impl SinkConfig for ElasticsearchConfig {
     }
 }
 
+/// Checks every endpoint, reporting the sink as degraded when only some of them are healthy.
+async fn healthcheck(
+    commons: Vec<ElasticsearchCommon>,
+    client: HttpClient,
+) -> crate::Result<HealthcheckStatus> {
+    let total = commons.len();
+    let mut errors = futures::future::join_all(
+        commons
+            .into_iter()
+            .map(|common| common.healthcheck(client.clone())),
+    )
+    .await
+    .into_iter()
+    .filter_map(Result::err)
+    .collect::<Vec<_>>();
+
+    match errors.len() {
+        0 => Ok(HealthcheckStatus::Healthy),
+        failed if failed < total => Ok(HealthcheckStatus::Degraded(format!(
+            "{failed} of {total} endpoints are unhealthy: {}",
+            errors[0]
+        ))),
+        _ => Err(errors.remove(0)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,246 @@
+use std::{collections::HashMap, path::PathBuf, time::Duration};
+
+use serde_with::serde_as;
+use snafu::Snafu;
+use vector_lib::codecs::{
+    encoding::{Framer, FramingConfig},
+    TextSerializerConfig,
+};
+
+use super::sink::{healthcheck, ExecSink};
+use crate::{
+    codecs::{EncodingConfigWithFraming, SinkType},
+    config::{EncodableSink, PayloadEncoder},
+    sinks::prelude::*,
+};
+
+/// Configuration for the `exec` sink.
+#[serde_as]
+#[configurable_component(sink(
+    "exec",
+    "Write observability events to the standard input of a process running on the host."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ExecSinkConfig {
+    /// The command to run, plus any arguments required.
+    #[configurable(metadata(docs::examples = "systemd-cat", docs::examples = "-t"))]
+    pub command: Vec<String>,
+
+    /// Custom environment variables to set or update when running the command.
+    /// If a variable name already exists in the environment, its value is replaced.
+    #[serde(default)]
+    #[configurable(metadata(docs::additional_props_description = "An environment variable."))]
+    pub environment: Option<HashMap<String, String>>,
+
+    /// Whether or not to clear the environment before setting custom environment variables.
+    #[serde(default = "crate::serde::default_false")]
+    pub clear_environment: bool,
+
+    /// The directory in which to run the command.
+    pub working_directory: Option<PathBuf>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub mode: Mode,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub streaming: StreamingConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub one_shot: OneShotConfig,
+
+    #[serde(flatten)]
+    pub encoding: EncodingConfigWithFraming,
+
+    #[configurable(derived)]
+    #[serde(
+        default,
+        deserialize_with = "crate::serde::bool_or_struct",
+        skip_serializing_if = "crate::serde::is_default"
+    )]
+    pub acknowledgements: AcknowledgementsConfig,
+}
+
+/// Mode of operation for running the command.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Mode {
+    /// The command is run for as long as the sink runs, and is restarted if it exits.
+    ///
+    /// Events are written to the standard input of the command as they arrive, and are
+    /// acknowledged once written.
+    #[default]
+    Streaming,
+
+    /// The command is run for each batch of events, whose standard input is closed once the batch
+    /// is written.
+    ///
+    /// Events are acknowledged once the command exits, successfully or not depending on its exit
+    /// status.
+    OneShot,
+}
+
+/// Configuration options for streaming commands.
+#[serde_as]
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct StreamingConfig {
+    /// The amount of time to wait before restarting the command when it exits.
+    ///
+    /// The delay doubles with each restart, up to `max_restart_backoff_secs`, until an event is
+    /// written to the restarted command.
+    #[serde(default = "default_restart_backoff")]
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Restart Backoff"))]
+    pub restart_backoff_secs: Duration,
+
+    /// The maximum amount of time to wait before restarting the command when it exits.
+    #[serde(default = "default_max_restart_backoff")]
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Maximum Restart Backoff"))]
+    pub max_restart_backoff_secs: Duration,
+
+    /// The maximum number of times the command is restarted in a row, without an event being
+    /// written to it, before the sink stops.
+    ///
+    /// The command is restarted indefinitely if not set.
+    #[configurable(metadata(docs::examples = 5))]
+    pub max_restarts: Option<u32>,
+}
+
+impl Default for StreamingConfig {
+    fn default() -> Self {
+        Self {
+            restart_backoff_secs: default_restart_backoff(),
+            max_restart_backoff_secs: default_max_restart_backoff(),
+            max_restarts: None,
+        }
+    }
+}
+
+const fn default_restart_backoff() -> Duration {
+    Duration::from_secs(1)
+}
+
+const fn default_max_restart_backoff() -> Duration {
+    Duration::from_secs(60)
+}
+
+/// Configuration options for one-shot commands.
+#[serde_as]
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct OneShotConfig {
+    #[configurable(derived)]
+    #[serde(default)]
+    pub batch: BatchConfig<ExecDefaultBatchSettings>,
+
+    /// The maximum amount of time the command is given to exit once its standard input is closed.
+    ///
+    /// The command is killed, and its batch of events is rejected, if it takes longer.
+    #[serde(default = "default_timeout")]
+    #[serde_as(as = "serde_with::DurationSeconds<u64>")]
+    #[configurable(metadata(docs::human_name = "Timeout"))]
+    pub timeout_secs: Duration,
+}
+
+impl Default for OneShotConfig {
+    fn default() -> Self {
+        Self {
+            batch: Default::default(),
+            timeout_secs: default_timeout(),
+        }
+    }
+}
+
+const fn default_timeout() -> Duration {
+    Duration::from_secs(60)
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExecDefaultBatchSettings;
+
+impl SinkBatchSettings for ExecDefaultBatchSettings {
+    const MAX_EVENTS: Option<usize> = Some(1000);
+    const MAX_BYTES: Option<usize> = Some(10_000_000);
+    const TIMEOUT_SECS: f64 = 1.0;
+}
+
+#[derive(Debug, PartialEq, Eq, Snafu)]
+pub enum ExecSinkConfigError {
+    #[snafu(display("A non-empty list for command must be provided"))]
+    CommandEmpty,
+}
+
+impl GenerateConfig for ExecSinkConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            command: vec![
+                "systemd-cat".to_owned(),
+                "-t".to_owned(),
+                "vector".to_owned(),
+            ],
+            environment: None,
+            clear_environment: false,
+            working_directory: None,
+            mode: Mode::Streaming,
+            streaming: Default::default(),
+            one_shot: Default::default(),
+            encoding: (None::<FramingConfig>, TextSerializerConfig::default()).into(),
+            acknowledgements: Default::default(),
+        })
+        .unwrap()
+    }
+}
+
+impl ExecSinkConfig {
+    pub(super) fn command_line(&self) -> String {
+        self.command.join(" ")
+    }
+
+    fn build_encoder(&self) -> crate::Result<(Transformer, Encoder<Framer>)> {
+        let transformer = self.encoding.transformer();
+        let (framer, serializer) = self.encoding.build(SinkType::StreamBased)?;
+        Ok((transformer, Encoder::<Framer>::new(framer, serializer)))
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "exec")]
+impl SinkConfig for ExecSinkConfig {
+    async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        if self.command.is_empty() {
+            return Err(Box::new(ExecSinkConfigError::CommandEmpty));
+        }
+
+        let (transformer, encoder) = self.build_encoder()?;
+        let sink = ExecSink::new(self.clone(), transformer, encoder)?;
+        let healthcheck = healthcheck(self.clone()).into_healthcheck();
+
+        Ok((VectorSink::from_event_streamsink(sink), healthcheck))
+    }
+
+    fn input(&self) -> Input {
+        Input::new(self.encoding.config().1.input_type())
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+
+    fn as_encodable(&self) -> Option<&dyn EncodableSink> {
+        Some(self)
+    }
+}
+
+impl EncodableSink for ExecSinkConfig {
+    fn build_payload_encoder(&self) -> crate::Result<PayloadEncoder> {
+        Ok(Box::new(self.build_encoder()?))
+    }
+}
@@ -0,0 +1,6 @@
+mod config;
+mod sink;
+#[cfg(all(test, unix))]
+mod tests;
+
+pub use config::ExecSinkConfig;
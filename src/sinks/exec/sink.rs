@@ -0,0 +1,406 @@
+use std::{io, path::Path, process::Stdio, time::Duration};
+
+use bytes::BytesMut;
+use futures::stream::BoxStream;
+use tokio::{
+    io::{AsyncWriteExt, BufReader},
+    process::{Child, ChildStdin, Command},
+    time::{sleep, timeout},
+};
+use tokio_util::codec::Encoder as _;
+use tracing::Instrument;
+use vector_lib::codecs::encoding::Framer;
+use vector_lib::{
+    internal_event::{ByteSize, BytesSent, EventsSent, InternalEventHandle as _, Output, Protocol},
+    EstimatedJsonEncodedSizeOf,
+};
+
+use super::config::{ExecSinkConfig, Mode};
+use crate::{
+    common::exec::read_stderr_lines,
+    internal_events::{
+        ExecSinkCommandError, ExecSinkCommandExited, ExecSinkCommandFailed,
+        ExecSinkRestartsExhausted, ExecSinkStderrLine, ExecSinkTimeoutError, ExecSinkWriteError,
+    },
+    sinks::prelude::*,
+};
+
+/// The amount of time a streaming command is given to exit once its standard input is closed when
+/// the sink stops, before it is killed.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The length at which the lines a command writes to its standard error are split.
+const MAX_STDERR_LINE_BYTES: usize = 1_000_000;
+
+pub struct ExecSink {
+    config: ExecSinkConfig,
+    command: String,
+    transformer: Transformer,
+    encoder: Encoder<Framer>,
+    batch_settings: BatcherSettings,
+}
+
+impl ExecSink {
+    pub fn new(
+        config: ExecSinkConfig,
+        transformer: Transformer,
+        encoder: Encoder<Framer>,
+    ) -> crate::Result<Self> {
+        let batch_settings = config.one_shot.batch.into_batcher_settings()?;
+        Ok(Self {
+            command: config.command_line(),
+            config,
+            transformer,
+            encoder,
+            batch_settings,
+        })
+    }
+
+    fn build_command(&self) -> Command {
+        let mut command = Command::new(&self.config.command[0]);
+        command.args(&self.config.command[1..]);
+        command.kill_on_drop(true);
+
+        if self.config.clear_environment {
+            command.env_clear();
+        }
+        if let Some(envs) = &self.config.environment {
+            command.envs(envs);
+        }
+        if let Some(current_dir) = &self.config.working_directory {
+            command.current_dir(current_dir);
+        }
+
+        command.stdin(Stdio::piped());
+        command.stdout(Stdio::null());
+        command.stderr(Stdio::piped());
+        command
+    }
+
+    /// Spawns the command, forwarding what it writes to its standard error to the logs of Vector.
+    fn spawn(&self) -> io::Result<(Child, ChildStdin)> {
+        let mut child = self.build_command().spawn()?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+
+        if let Some(stderr) = child.stderr.take() {
+            let command = self.command.clone();
+            tokio::spawn(
+                async move {
+                    read_stderr_lines(BufReader::new(stderr), MAX_STDERR_LINE_BYTES, |line| {
+                        emit!(ExecSinkStderrLine {
+                            command: &command,
+                            line,
+                        });
+                    })
+                    .await;
+                }
+                .in_current_span(),
+            );
+        }
+
+        Ok((child, stdin))
+    }
+
+    fn restart_delay(&self, restarts: u32) -> Duration {
+        let streaming = &self.config.streaming;
+        streaming
+            .restart_backoff_secs
+            .saturating_mul(2u32.saturating_pow(restarts))
+            .min(streaming.max_restart_backoff_secs)
+    }
+
+    /// Restarts the streaming command once its restart delay has elapsed, unless it was restarted
+    /// too many times in a row.
+    async fn restart(&self, restarts: &mut u32) -> Result<(Child, ChildStdin), ()> {
+        loop {
+            if let Some(max_restarts) = self.config.streaming.max_restarts {
+                if *restarts >= max_restarts {
+                    emit!(ExecSinkRestartsExhausted {
+                        command: &self.command,
+                        max_restarts,
+                    });
+                    return Err(());
+                }
+            }
+
+            sleep(self.restart_delay(*restarts)).await;
+            *restarts += 1;
+
+            match self.spawn() {
+                Ok(process) => return Ok(process),
+                Err(error) => emit!(ExecSinkCommandError {
+                    command: &self.command,
+                    error,
+                }),
+            }
+        }
+    }
+
+    async fn run_streaming(mut self: Box<Self>, mut input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let bytes_sent = register!(BytesSent::from(Protocol("exec".into())));
+        let events_sent = register!(EventsSent::from(Output(None)));
+
+        let mut restarts = 0;
+        let (mut child, mut stdin) = match self.spawn() {
+            Ok(process) => process,
+            Err(error) => {
+                emit!(ExecSinkCommandError {
+                    command: &self.command,
+                    error,
+                });
+                self.restart(&mut restarts).await?
+            }
+        };
+
+        loop {
+            tokio::select! {
+                status = child.wait() => {
+                    emit!(ExecSinkCommandExited {
+                        command: &self.command,
+                        exit_status: status.ok().and_then(|status| status.code()),
+                    });
+                    (child, stdin) = self.restart(&mut restarts).await?;
+                }
+                event = input.next() => {
+                    let Some(mut event) = event else {
+                        break;
+                    };
+
+                    let event_byte_size = event.estimated_json_encoded_size_of();
+                    self.transformer.transform(&mut event);
+
+                    let finalizers = event.take_finalizers();
+                    let mut bytes = BytesMut::new();
+                    if self.encoder.encode(event, &mut bytes).is_err() {
+                        // Error is handled by `Encoder`.
+                        finalizers.update_status(EventStatus::Errored);
+                        continue;
+                    }
+
+                    match stdin.write_all(&bytes).await {
+                        Ok(()) => {
+                            finalizers.update_status(EventStatus::Delivered);
+                            events_sent.emit(CountByteSize(1, event_byte_size));
+                            bytes_sent.emit(ByteSize(bytes.len()));
+                            restarts = 0;
+                        }
+                        Err(error) => {
+                            emit!(ExecSinkWriteError {
+                                command: &self.command,
+                                error,
+                                count: 1,
+                            });
+                            finalizers.update_status(EventStatus::Errored);
+
+                            // The command may have stopped reading without exiting.
+                            _ = child.start_kill();
+                            let status = child.wait().await;
+                            emit!(ExecSinkCommandExited {
+                                command: &self.command,
+                                exit_status: status.ok().and_then(|status| status.code()),
+                            });
+                            (child, stdin) = self.restart(&mut restarts).await?;
+                        }
+                    }
+                }
+            }
+        }
+
+        drop(stdin);
+        if timeout(SHUTDOWN_TIMEOUT, child.wait()).await.is_err() {
+            _ = child.kill().await;
+        }
+
+        Ok(())
+    }
+
+    async fn run_one_shot(mut self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let bytes_sent = register!(BytesSent::from(Protocol("exec".into())));
+        let events_sent = register!(EventsSent::from(Output(None)));
+
+        let mut batches = input.batched(self.batch_settings.as_byte_size_config());
+        while let Some(events) = batches.next().await {
+            let mut count = 0;
+            let mut byte_size = JsonSize::zero();
+            let mut finalizers = EventFinalizers::default();
+            let mut bytes = BytesMut::new();
+
+            for mut event in events {
+                let event_byte_size = event.estimated_json_encoded_size_of();
+                self.transformer.transform(&mut event);
+
+                let event_finalizers = event.take_finalizers();
+                if self.encoder.encode(event, &mut bytes).is_err() {
+                    // Error is handled by `Encoder`.
+                    event_finalizers.update_status(EventStatus::Errored);
+                    continue;
+                }
+
+                count += 1;
+                byte_size += event_byte_size;
+                finalizers.merge(event_finalizers);
+            }
+
+            if count == 0 {
+                continue;
+            }
+
+            let status = self.run_batch(&bytes, count).await;
+            if status == EventStatus::Delivered {
+                events_sent.emit(CountByteSize(count, byte_size));
+                bytes_sent.emit(ByteSize(bytes.len()));
+            }
+            finalizers.update_status(status);
+        }
+
+        Ok(())
+    }
+
+    /// Runs the one-shot command for a batch of encoded events, returning the status of the batch.
+    async fn run_batch(&self, bytes: &[u8], count: usize) -> EventStatus {
+        let (mut child, mut stdin) = match self.spawn() {
+            Ok(process) => process,
+            Err(error) => {
+                emit!(ExecSinkCommandError {
+                    command: &self.command,
+                    error,
+                });
+                return EventStatus::Errored;
+            }
+        };
+
+        let written = stdin.write_all(bytes).await;
+        drop(stdin);
+        match written {
+            // The command is free to exit without reading the whole batch, whose status then
+            // depends on the exit status of the command.
+            Err(error) if error.kind() != io::ErrorKind::BrokenPipe => {
+                emit!(ExecSinkWriteError {
+                    command: &self.command,
+                    error,
+                    count,
+                });
+                _ = child.kill().await;
+                return EventStatus::Errored;
+            }
+            _ => {}
+        }
+
+        let timeout_secs = self.config.one_shot.timeout_secs;
+        match timeout(timeout_secs, child.wait()).await {
+            Ok(Ok(status)) if status.success() => EventStatus::Delivered,
+            Ok(Ok(status)) => {
+                emit!(ExecSinkCommandFailed {
+                    command: &self.command,
+                    exit_status: status.code(),
+                    count,
+                });
+                EventStatus::Rejected
+            }
+            Ok(Err(error)) => {
+                emit!(ExecSinkCommandError {
+                    command: &self.command,
+                    error,
+                });
+                EventStatus::Errored
+            }
+            Err(_) => {
+                emit!(ExecSinkTimeoutError {
+                    command: &self.command,
+                    timeout: timeout_secs,
+                    count,
+                });
+                _ = child.kill().await;
+                EventStatus::Errored
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl StreamSink<Event> for ExecSink {
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        match self.config.mode {
+            Mode::Streaming => self.run_streaming(input).await,
+            Mode::OneShot => self.run_one_shot(input).await,
+        }
+    }
+}
+
+/// Checks that the command can be found and run, the same way it is looked up when spawned.
+pub async fn healthcheck(config: ExecSinkConfig) -> crate::Result<()> {
+    let program = &config.command[0];
+    let working_directory = config.working_directory.unwrap_or_default();
+
+    let found = if program.contains(std::path::is_separator) {
+        is_executable(&working_directory.join(program))
+    } else {
+        // The `PATH` of the command is used if it is set.
+        let paths = config
+            .environment
+            .as_ref()
+            .and_then(|envs| envs.get("PATH"))
+            .map(Into::into)
+            .or_else(|| {
+                (!config.clear_environment)
+                    .then(|| std::env::var_os("PATH"))
+                    .flatten()
+            });
+        paths.is_some_and(|paths| {
+            std::env::split_paths(&paths).any(|dir| {
+                is_executable(&dir.join(program))
+                    || is_executable(
+                        &dir.join(format!("{program}{}", std::env::consts::EXE_SUFFIX)),
+                    )
+            })
+        })
+    };
+
+    if found {
+        Ok(())
+    } else {
+        Err(format!("Command {program:?} was not found, or is not executable.").into())
+    }
+}
+
+fn is_executable(path: &Path) -> bool {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return false;
+    };
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        metadata.is_file() && metadata.permissions().mode() & 0o111 != 0
+    }
+    #[cfg(not(unix))]
+    {
+        metadata.is_file()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restart_delay_doubles_up_to_the_maximum() {
+        let config = toml::from_str::<ExecSinkConfig>(
+            r#"
+            command = ["cat"]
+            encoding.codec = "text"
+            streaming.restart_backoff_secs = 0.5
+            streaming.max_restart_backoff_secs = 3
+            "#,
+        )
+        .unwrap();
+        let sink = ExecSink::new(config, Default::default(), Default::default()).unwrap();
+
+        let delays = (0..5).map(|restarts| sink.restart_delay(restarts));
+        assert_eq!(
+            delays.map(|delay| delay.as_secs_f64()).collect::<Vec<_>>(),
+            [0.5, 1.0, 2.0, 3.0, 3.0]
+        );
+        assert_eq!(sink.restart_delay(u32::MAX), Duration::from_secs(3));
+    }
+}
@@ -0,0 +1,137 @@
+use futures::{stream, StreamExt};
+use vector_lib::event::{BatchNotifier, BatchStatus, Event, EventArray, LogEvent};
+
+use super::ExecSinkConfig;
+use crate::{
+    config::{SinkConfig, SinkContext},
+    test_util::{
+        components::{
+            assert_sink_error, run_and_assert_sink_compliance, COMPONENT_ERROR_TAGS, SINK_TAGS,
+        },
+        temp_file,
+    },
+};
+
+fn config(command: &[&str], extra: &str) -> ExecSinkConfig {
+    toml::from_str(&format!(
+        r#"
+        command = {command:?}
+        encoding.codec = "text"
+        {extra}
+        "#
+    ))
+    .unwrap()
+}
+
+fn events(lines: &[&str], batch: &BatchNotifier) -> Vec<Event> {
+    lines
+        .iter()
+        .map(|line| LogEvent::from(*line).with_batch_notifier(batch).into())
+        .collect()
+}
+
+#[test]
+fn generate_config() {
+    crate::test_util::test_generate_config::<ExecSinkConfig>();
+}
+
+#[tokio::test]
+async fn streams_events_to_the_command() {
+    let path = temp_file();
+    let script = format!("cat >> '{}'", path.display());
+    let config = config(&["sh", "-c", &script], "");
+    let (sink, healthcheck) = config.build(SinkContext::default()).await.unwrap();
+    healthcheck.await.unwrap();
+
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let events = events(&["foo", "bar"], &batch);
+    drop(batch);
+
+    run_and_assert_sink_compliance(sink, stream::iter(events), &SINK_TAGS).await;
+
+    assert_eq!(receiver.await, BatchStatus::Delivered);
+    assert_eq!(std::fs::read_to_string(path).unwrap(), "foo\nbar\n");
+}
+
+#[tokio::test]
+async fn keeps_reading_stderr_past_invalid_and_long_lines() {
+    // The command is killed for writing to a closed pipe if its stderr stops being read.
+    let path = temp_file();
+    let script = format!(
+        "set -e; printf 'invalid \\377\\n' >&2; sleep 0.1; \\
+         head -c 2000000 /dev/zero | tr '\\0' x >&2; cat >> '{}'",
+        path.display()
+    );
+    let config = config(&["sh", "-c", &script], "");
+    let (sink, _) = config.build(SinkContext::default()).await.unwrap();
+
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let events = events(&["foo", "bar"], &batch);
+    drop(batch);
+
+    run_and_assert_sink_compliance(sink, stream::iter(events), &SINK_TAGS).await;
+
+    assert_eq!(receiver.await, BatchStatus::Delivered);
+    assert_eq!(std::fs::read_to_string(path).unwrap(), "foo\nbar\n");
+}
+
+#[tokio::test]
+async fn stops_after_max_restarts() {
+    let config = config(&["sh", "-c", "exit 1"], "streaming.max_restarts = 0");
+    let (sink, _) = config.build(SinkContext::default()).await.unwrap();
+
+    assert_sink_error(&COMPONENT_ERROR_TAGS, async {
+        assert!(sink.run(stream::pending::<EventArray>()).await.is_err());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn runs_one_shot_commands_for_each_batch() {
+    let path = temp_file();
+    let script = format!("cat >> '{}'", path.display());
+    let config = config(&["sh", "-c", &script], r#"mode = "one_shot""#);
+    let (sink, _) = config.build(SinkContext::default()).await.unwrap();
+
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let events = events(&["foo", "bar"], &batch);
+    drop(batch);
+
+    run_and_assert_sink_compliance(sink, stream::iter(events), &SINK_TAGS).await;
+
+    assert_eq!(receiver.await, BatchStatus::Delivered);
+    assert_eq!(std::fs::read_to_string(path).unwrap(), "foo\nbar\n");
+}
+
+#[tokio::test]
+async fn rejects_batches_of_failed_one_shot_commands() {
+    let config = config(
+        &["sh", "-c", "cat > /dev/null; exit 3"],
+        r#"mode = "one_shot""#,
+    );
+    let (sink, _) = config.build(SinkContext::default()).await.unwrap();
+
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    let events = events(&["foo"], &batch);
+    drop(batch);
+
+    assert_sink_error(&COMPONENT_ERROR_TAGS, async {
+        sink.run(stream::iter(events).map(Into::into))
+            .await
+            .unwrap();
+    })
+    .await;
+
+    assert_eq!(receiver.await, BatchStatus::Rejected);
+}
+
+#[tokio::test]
+async fn healthcheck_looks_commands_up() {
+    let sink_config = config(&["vector-exec-sink-missing-command"], "");
+    let (_, healthcheck) = sink_config.build(SinkContext::default()).await.unwrap();
+    assert!(healthcheck.await.is_err());
+
+    let sink_config = config(&["sh"], "");
+    let (_, healthcheck) = sink_config.build(SinkContext::default()).await.unwrap();
+    healthcheck.await.unwrap();
+}
@@ -4,16 +4,9 @@ use std::time::{Duration, Instant};
 use async_compression::tokio::write::{GzipEncoder, ZstdEncoder};
 use async_trait::async_trait;
 use bytes::{Bytes, BytesMut};
-use futures::{
-    future,
-    stream::{BoxStream, StreamExt},
-    FutureExt,
-};
+use futures::stream::{BoxStream, StreamExt};
 use serde_with::serde_as;
-use tokio::{
-    fs::{self, File},
-    io::AsyncWriteExt,
-};
+use tokio::{fs::File, io::AsyncWriteExt};
 use tokio_util::codec::Encoder as _;
 use vector_lib::codecs::{
     encoding::{Framer, FramingConfig},
@@ -38,8 +31,11 @@ use crate::{
 };
 
 mod bytes_path;
+mod permissions;
 
 use bytes_path::BytesPath;
+use permissions::FilePermissions;
+pub use permissions::FilePermissionsConfig;
 
 /// Configuration for the `file` sink.
 #[serde_as]
@@ -89,6 +85,9 @@ pub struct FileSinkConfig {
     #[configurable(derived)]
     #[serde(default)]
     pub internal_metrics: FileInternalMetricsConfig,
+
+    #[serde(flatten)]
+    pub permissions: FilePermissionsConfig,
 }
 
 impl GenerateConfig for FileSinkConfig {
@@ -101,6 +100,7 @@ impl GenerateConfig for FileSinkConfig {
             acknowledgements: Default::default(),
             timezone: Default::default(),
             internal_metrics: Default::default(),
+            permissions: Default::default(),
         })
         .unwrap()
     }
@@ -189,7 +189,7 @@ impl SinkConfig for FileSinkConfig {
         let sink = FileSink::new(self, cx)?;
         Ok((
             super::VectorSink::from_event_streamsink(sink),
-            future::ok(()).boxed(),
+            super::healthy(),
         ))
     }
 
@@ -211,6 +211,7 @@ pub struct FileSink {
     compression: Compression,
     events_sent: Registered<EventsSent>,
     include_file_metric_tag: bool,
+    permissions: FilePermissions,
 }
 
 impl FileSink {
@@ -233,6 +234,7 @@ impl FileSink {
             compression: config.compression,
             events_sent: register!(EventsSent::from(Output(None))),
             include_file_metric_tag: config.internal_metrics.include_file_tag,
+            permissions: config.permissions.build()?,
         })
     }
 
@@ -345,7 +347,8 @@ impl FileSink {
             file
         } else {
             trace!(message = "Opening new file.", ?path);
-            let file = match open_file(BytesPath::new(path.clone())).await {
+            let file_path = BytesPath::new(path.clone());
+            let file = match self.permissions.open(file_path.as_ref()).await {
                 Ok(file) => file,
                 Err(error) => {
                     // We couldn't open the file for this event.
@@ -399,22 +402,6 @@ impl FileSink {
     }
 }
 
-async fn open_file(path: impl AsRef<std::path::Path>) -> std::io::Result<File> {
-    let parent = path.as_ref().parent();
-
-    if let Some(parent) = parent {
-        fs::create_dir_all(parent).await?;
-    }
-
-    fs::OpenOptions::new()
-        .read(false)
-        .write(true)
-        .create(true)
-        .append(true)
-        .open(path)
-        .await
-}
-
 async fn write_event_to_file(
     file: &mut OutFile,
     mut event: Event,
@@ -476,6 +463,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            permissions: Default::default(),
         };
 
         let (input, _events) = random_lines_with_stream(100, 64, None);
@@ -502,6 +490,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            permissions: Default::default(),
         };
 
         let (input, _) = random_lines_with_stream(100, 64, None);
@@ -528,6 +517,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            permissions: Default::default(),
         };
 
         let (input, _) = random_lines_with_stream(100, 64, None);
@@ -559,6 +549,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            permissions: Default::default(),
         };
 
         let (mut input, _events) = random_events_with_stream(32, 8, None);
@@ -641,6 +632,7 @@ mod tests {
             internal_metrics: FileInternalMetricsConfig {
                 include_file_tag: true,
             },
+            permissions: Default::default(),
         };
 
         let (mut input, _events) = random_lines_with_stream(10, 64, None);
@@ -683,6 +675,87 @@ mod tests {
         sink_handle.await.unwrap();
     }
 
+    #[cfg(unix)]
+    fn mode_of(path: impl AsRef<std::path::Path>) -> u32 {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::metadata(path).unwrap().permissions().mode() & 0o777
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn applies_modes_to_created_files_and_directories() {
+        let directory = temp_dir();
+        let template = format!("{}/{{{{level}}}}/nested/events.log", directory.display());
+
+        let config: FileSinkConfig = toml::from_str(&format!(
+            r#"
+            path = "{template}"
+            encoding.codec = "text"
+            file_mode = 0o604
+            directory_mode = 0o751
+            "#
+        ))
+        .unwrap();
+
+        let mut event = LogEvent::from("hello");
+        event.insert("level", "info");
+        run_assert_sink(config, std::iter::once(event.into())).await;
+
+        assert_eq!(mode_of(&directory), 0o751);
+        assert_eq!(mode_of(directory.join("info")), 0o751);
+        assert_eq!(mode_of(directory.join("info/nested")), 0o751);
+        assert_eq!(mode_of(directory.join("info/nested/events.log")), 0o604);
+        assert_eq!(
+            lines_from_file(directory.join("info/nested/events.log")),
+            ["hello"]
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn leaves_existing_files_alone_unless_enforced() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = temp_file();
+        std::fs::write(&path, "").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).unwrap();
+
+        for (enforce_permissions, mode) in [(false, 0o600), (true, 0o644)] {
+            let config: FileSinkConfig = toml::from_str(&format!(
+                r#"
+                path = "{}"
+                encoding.codec = "text"
+                file_mode = 0o644
+                enforce_permissions = {enforce_permissions}
+                "#,
+                path.display()
+            ))
+            .unwrap();
+
+            run_assert_log_sink(config, vec!["hello".to_owned()]).await;
+            assert_eq!(mode_of(&path), mode);
+        }
+        assert_eq!(lines_from_file(&path), ["hello", "hello"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn resolves_owners() {
+        let uid = nix::unistd::getuid().as_raw();
+        let permissions = FilePermissionsConfig {
+            owner: Some(uid.to_string()),
+            group: Some("0".to_owned()),
+            ..Default::default()
+        };
+        assert!(permissions.build().is_ok());
+
+        let permissions = FilePermissionsConfig {
+            owner: Some("vector-file-sink-unknown-user".to_owned()),
+            ..Default::default()
+        };
+        assert!(permissions.build().is_err());
+    }
+
     async fn run_assert_log_sink(config: FileSinkConfig, events: Vec<String>) {
         run_assert_sink(
             config,
@@ -0,0 +1,202 @@
+use std::{io, path::Path};
+
+use tokio::fs::{self, File};
+use vector_lib::configurable::configurable_component;
+
+#[cfg(unix)]
+use crate::internal_events::FileOwnershipError;
+
+/// Permissions and ownership of the files and directories created by the sink.
+#[configurable_component]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FilePermissionsConfig {
+    /// Unix file mode bits applied to the files created by the sink.
+    ///
+    /// Files are created with the permissions allowed by the umask of Vector if not set.
+    ///
+    /// Note: The file mode value can be specified in any numeric format supported by your configuration
+    /// language, but it is most intuitive to use an octal number.
+    #[configurable(metadata(docs::examples = 0o640))]
+    #[configurable(metadata(docs::examples = 0o600))]
+    pub file_mode: Option<u32>,
+
+    /// Unix file mode bits applied to the parent directories created by the sink.
+    ///
+    /// Directories are created with the permissions allowed by the umask of Vector if not set.
+    ///
+    /// Note: The file mode value can be specified in any numeric format supported by your configuration
+    /// language, but it is most intuitive to use an octal number.
+    #[configurable(metadata(docs::examples = 0o750))]
+    pub directory_mode: Option<u32>,
+
+    /// The user owning the files and directories created by the sink, as a name or a numeric ID.
+    ///
+    /// Changing the owner requires Vector to run with the necessary privileges. If it can't be
+    /// changed, a warning is logged and the file is still written to.
+    #[configurable(metadata(docs::examples = "vector", docs::examples = "1000"))]
+    pub owner: Option<String>,
+
+    /// The group owning the files and directories created by the sink, as a name or a numeric ID.
+    ///
+    /// Changing the group requires Vector to run with the necessary privileges. If it can't be
+    /// changed, a warning is logged and the file is still written to.
+    #[configurable(metadata(docs::examples = "adm", docs::examples = "4"))]
+    pub group: Option<String>,
+
+    /// Whether the permissions and ownership are also applied to existing files when they are
+    /// opened.
+    ///
+    /// Existing directories are always left as they are.
+    #[serde(default = "crate::serde::default_false")]
+    pub enforce_permissions: bool,
+}
+
+impl FilePermissionsConfig {
+    pub fn build(&self) -> crate::Result<FilePermissions> {
+        #[cfg(unix)]
+        {
+            Ok(FilePermissions {
+                file_mode: self.file_mode,
+                directory_mode: self.directory_mode,
+                owner: self.owner.as_deref().map(resolve_user).transpose()?,
+                group: self.group.as_deref().map(resolve_group).transpose()?,
+                enforce: self.enforce_permissions,
+            })
+        }
+
+        #[cfg(not(unix))]
+        {
+            if *self != Self::default() {
+                return Err("File permissions and ownership are only supported on Unix.".into());
+            }
+            Ok(FilePermissions::default())
+        }
+    }
+}
+
+#[cfg(unix)]
+fn resolve_user(user: &str) -> crate::Result<u32> {
+    if let Ok(uid) = user.parse() {
+        return Ok(uid);
+    }
+    match nix::unistd::User::from_name(user)? {
+        Some(user) => Ok(user.uid.as_raw()),
+        None => Err(format!("Unknown user {user:?}.").into()),
+    }
+}
+
+#[cfg(unix)]
+fn resolve_group(group: &str) -> crate::Result<u32> {
+    if let Ok(gid) = group.parse() {
+        return Ok(gid);
+    }
+    match nix::unistd::Group::from_name(group)? {
+        Some(group) => Ok(group.gid.as_raw()),
+        None => Err(format!("Unknown group {group:?}.").into()),
+    }
+}
+
+/// Permissions and ownership, resolved to the IDs of their owner and group.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(not(unix), allow(dead_code))]
+pub struct FilePermissions {
+    file_mode: Option<u32>,
+    directory_mode: Option<u32>,
+    owner: Option<u32>,
+    group: Option<u32>,
+    enforce: bool,
+}
+
+impl FilePermissions {
+    /// Opens a file for appending, creating it, and any missing parent directory, with these
+    /// permissions.
+    pub async fn open(&self, path: &Path) -> io::Result<File> {
+        if let Some(parent) = path.parent() {
+            self.create_dir_all(parent).await?;
+        }
+
+        let mut options = fs::OpenOptions::new();
+        options.read(false).write(true).append(true);
+
+        let mut new_options = options.clone();
+        new_options.create_new(true);
+        #[cfg(unix)]
+        {
+            if let Some(mode) = self.file_mode {
+                new_options.mode(mode);
+            }
+        }
+        match new_options.open(path).await {
+            Ok(file) => {
+                self.apply(path, self.file_mode).await?;
+                Ok(file)
+            }
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {
+                let file = options.create(true).open(path).await?;
+                if self.enforce {
+                    self.apply(path, self.file_mode).await?;
+                }
+                Ok(file)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Creates the missing directories of a path, from the outermost one, with these permissions.
+    async fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        let mut missing = Vec::new();
+        for dir in path.ancestors() {
+            if dir.as_os_str().is_empty() || fs::try_exists(dir).await? {
+                break;
+            }
+            missing.push(dir);
+        }
+
+        let mut builder = fs::DirBuilder::new();
+        #[cfg(unix)]
+        {
+            if let Some(mode) = self.directory_mode {
+                builder.mode(mode);
+            }
+        }
+        for dir in missing.into_iter().rev() {
+            match builder.create(dir).await {
+                Ok(()) => self.apply(dir, self.directory_mode).await?,
+                // Another sink, or another partition of this one, created it in the meantime.
+                Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {}
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    async fn apply(&self, path: &Path, mode: Option<u32>) -> io::Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Files and directories are created with their mode, so that they are never more
+        // accessible than requested, but it is masked by the umask, so it is set again.
+        if let Some(mode) = mode {
+            fs::set_permissions(path, std::fs::Permissions::from_mode(mode)).await?;
+        }
+
+        if self.owner.is_some() || self.group.is_some() {
+            let (owner, group) = (self.owner, self.group);
+            let owned_path = path.to_owned();
+            let result = tokio::task::spawn_blocking(move || {
+                std::os::unix::fs::chown(owned_path, owner, group)
+            })
+            .await
+            .map_err(io::Error::other)?;
+            if let Err(error) = result {
+                emit!(FileOwnershipError { error, path });
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    async fn apply(&self, _path: &Path, _mode: Option<u32>) -> io::Result<()> {
+        Ok(())
+    }
+}
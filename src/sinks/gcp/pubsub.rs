@@ -1,6 +1,6 @@
 use base64::prelude::{Engine as _, BASE64_STANDARD};
 use bytes::{Bytes, BytesMut};
-use futures::{FutureExt, SinkExt};
+use futures::SinkExt;
 use http::{Request, Uri};
 use hyper::Body;
 use indoc::indoc;
@@ -21,7 +21,7 @@ use crate::{
             http::{BatchedHttpSink, HttpEventEncoder, HttpSink},
             BatchConfig, BoxedRawValue, JsonArrayBuffer, SinkBatchSettings, TowerRequestConfig,
         },
-        Healthcheck, UriParseSnafu, VectorSink,
+        Healthcheck, IntoHealthcheck, UriParseSnafu, VectorSink,
     },
     tls::{TlsConfig, TlsSettings},
 };
@@ -127,7 +127,8 @@ impl SinkConfig for PubsubConfig {
         let tls_settings = TlsSettings::from_options(&self.tls)?;
         let client = HttpClient::new(tls_settings, cx.proxy())?;
 
-        let healthcheck = healthcheck(client.clone(), sink.uri("")?, sink.auth.clone()).boxed();
+        let healthcheck =
+            healthcheck(client.clone(), sink.uri("")?, sink.auth.clone()).into_healthcheck();
         sink.auth.spawn_regenerate_token();
 
         let sink = BatchedHttpSink::new(
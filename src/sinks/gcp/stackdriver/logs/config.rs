@@ -239,7 +239,7 @@ impl SinkConfig for StackdriverConfig {
 
         let sink = StackdriverLogsSink::new(service, batch_settings, request_builder);
 
-        let healthcheck = healthcheck(client, auth.clone(), uri).boxed();
+        let healthcheck = healthcheck(client, auth.clone(), uri).into_healthcheck();
 
         auth.spawn_regenerate_token();
 
@@ -96,7 +96,7 @@ impl SinkConfig for StackdriverConfig {
     async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
         let auth = self.auth.build(Scope::MonitoringWrite).await?;
 
-        let healthcheck = healthcheck().boxed();
+        let healthcheck = healthcheck().into_healthcheck();
         let started = chrono::Utc::now();
         let tls_settings = TlsSettings::from_options(&self.tls)?;
         let client = HttpClient::new(tls_settings, cx.proxy())?;
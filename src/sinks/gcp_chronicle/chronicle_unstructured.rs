@@ -48,7 +48,7 @@ use crate::{
             request_builder::EncodeResult,
             BatchConfig, Compression, RequestBuilder, SinkBatchSettings, TowerRequestConfig,
         },
-        Healthcheck,
+        Healthcheck, IntoHealthcheck,
     },
     template::{Template, TemplateParseError},
     tls::{TlsConfig, TlsSettings},
@@ -223,7 +223,7 @@ pub fn build_healthcheck(
         healthcheck_response(response, GcsHealthcheckError::NotFound.into())
     };
 
-    Ok(Box::pin(healthcheck))
+    Ok(healthcheck.into_healthcheck())
 }
 
 #[derive(Debug, Snafu)]
@@ -1,4 +1,3 @@
-use futures::FutureExt;
 use http::{StatusCode, Uri};
 use hyper::Body;
 use snafu::Snafu;
@@ -10,7 +9,7 @@ use crate::{
     sinks::{
         gcs_common::service::GcsResponse,
         util::retries::{RetryAction, RetryLogic},
-        Healthcheck, HealthcheckError,
+        Healthcheck, HealthcheckError, IntoHealthcheck,
     },
 };
 
@@ -123,7 +122,7 @@ pub fn build_healthcheck(
         healthcheck_response(response, not_found_error)
     };
 
-    Ok(healthcheck.boxed())
+    Ok(healthcheck.into_healthcheck())
 }
 
 pub fn healthcheck_response(
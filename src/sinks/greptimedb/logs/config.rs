@@ -165,11 +165,8 @@ impl SinkConfig for GreptimeDBLogsConfig {
             logs_sink_setting,
         );
 
-        let healthcheck = Box::pin(http_healthcheck(
-            client,
-            self.endpoint.clone(),
-            auth.clone(),
-        ));
+        let healthcheck =
+            http_healthcheck(client, self.endpoint.clone(), auth.clone()).into_healthcheck();
         Ok((VectorSink::from_event_streamsink(sink), healthcheck))
     }
 
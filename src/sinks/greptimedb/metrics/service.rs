@@ -135,5 +135,5 @@ pub(super) fn healthcheck(
     let config = config.into();
     let client = new_client_from_config(&config)?;
 
-    Ok(async move { client.health_check().await.map_err(|error| error.into()) }.boxed())
+    Ok(async move { client.health_check().await.map_err(|error| error.into()) }.into_healthcheck())
 }
@@ -1,7 +1,6 @@
 //! Configuration for the `honeycomb` sink.
 
 use bytes::Bytes;
-use futures::FutureExt;
 use http::{Request, StatusCode, Uri};
 use vector_lib::configurable::configurable_component;
 use vector_lib::sensitive_string::SensitiveString;
@@ -124,7 +123,7 @@ impl SinkConfig for HoneycombConfig {
 
         let sink = HoneycombSink::new(service, batch_settings, request_builder);
 
-        let healthcheck = healthcheck(uri, self.api_key.clone(), client).boxed();
+        let healthcheck = healthcheck(uri, self.api_key.clone(), client).into_healthcheck();
 
         Ok((VectorSink::from_event_streamsink(sink), healthcheck))
     }
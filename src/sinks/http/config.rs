@@ -1,5 +1,7 @@
 //! Configuration for the `http` sink.
 
+use std::{path::PathBuf, sync::Arc};
+
 use http::{header::AUTHORIZATION, HeaderName, HeaderValue, Method, Request, StatusCode};
 use hyper::Body;
 use indexmap::IndexMap;
@@ -10,6 +12,7 @@ use vector_lib::codecs::{
 
 use crate::{
     codecs::{EncodingConfigWithFraming, SinkType},
+    config::{EncodableSink, PayloadEncoder},
     http::{Auth, AuthorizationConfig, HttpClient, MaybeAuth},
     sinks::{
         prelude::*,
@@ -40,6 +43,16 @@ pub struct HttpSinkConfig {
     #[configurable(metadata(docs::examples = "https://10.22.212.22:9000/endpoint"))]
     pub uri: UriSerde,
 
+    /// The path of a unix socket to send requests to, instead of connecting to the host of `uri`.
+    ///
+    /// The `uri` still sets the path, query, and `Host` header of requests, and should use the
+    /// `http` scheme, as neither TLS nor the proxy settings apply to the connections to the socket.
+    /// Setting `tls` along with this option is rejected.
+    ///
+    /// This is only supported on Unix.
+    #[configurable(metadata(docs::examples = "/var/run/collector.sock"))]
+    pub unix_socket: Option<PathBuf>,
+
     /// The HTTP method to use when making the request.
     #[serde(default)]
     pub method: HttpMethod,
@@ -155,13 +168,28 @@ impl From<HttpMethod> for Method {
 
 impl HttpSinkConfig {
     fn build_http_client(&self, cx: &SinkContext) -> crate::Result<HttpClient> {
-        let tls = TlsSettings::from_options(&self.tls)?;
         let auth_strategy = self.authorization_config.clone();
-        Ok(HttpClient::new_with_auth_extension(
-            tls,
-            cx.proxy(),
-            auth_strategy,
-        )?)
+        match &self.unix_socket {
+            Some(_) if self.tls.is_some() => {
+                Err("The `tls` option cannot be used with the `unix_socket` option.".into())
+            }
+            #[cfg(unix)]
+            Some(path) => Ok(HttpClient::new_with_unix_socket(
+                path.clone(),
+                cx.proxy(),
+                auth_strategy,
+            )?),
+            #[cfg(not(unix))]
+            Some(_) => Err("The `unix_socket` option is only supported on Unix.".into()),
+            None => {
+                let tls = TlsSettings::from_options(&self.tls)?;
+                Ok(HttpClient::new_with_auth_extension(
+                    tls,
+                    cx.proxy(),
+                    auth_strategy,
+                )?)
+            }
+        }
     }
 
     pub(super) fn build_encoder(&self) -> crate::Result<Encoder<Framer>> {
@@ -253,9 +281,9 @@ impl SinkConfig for HttpSinkConfig {
 
         let healthcheck = match cx.healthcheck.uri {
             Some(healthcheck_uri) => {
-                healthcheck(healthcheck_uri, self.auth.clone(), client.clone()).boxed()
+                healthcheck(healthcheck_uri, self.auth.clone(), client.clone()).into_healthcheck()
             }
-            None => future::ok(()).boxed(),
+            None => healthy(),
         };
 
         let content_type = {
@@ -305,6 +333,20 @@ impl SinkConfig for HttpSinkConfig {
         Ok((VectorSink::from_event_streamsink(sink), healthcheck))
     }
 
+    async fn healthcheck_builder(
+        &self,
+        cx: &SinkContext,
+    ) -> crate::Result<Option<HealthcheckBuilder>> {
+        let Some(uri) = cx.healthcheck.uri.clone() else {
+            return Ok(Some(Arc::new(healthy)));
+        };
+        let client = self.build_http_client(cx)?;
+        let auth = self.auth.clone();
+        Ok(Some(Arc::new(move || {
+            healthcheck(uri.clone(), auth.clone(), client.clone()).into_healthcheck()
+        })))
+    }
+
     fn input(&self) -> Input {
         Input::new(self.encoding.config().1.input_type())
     }
@@ -312,6 +354,24 @@ impl SinkConfig for HttpSinkConfig {
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
         &self.acknowledgements
     }
+
+    fn as_encodable(&self) -> Option<&dyn EncodableSink> {
+        Some(self)
+    }
+}
+
+impl EncodableSink for HttpSinkConfig {
+    fn build_payload_encoder(&self) -> crate::Result<PayloadEncoder> {
+        let encoder = self.build_encoder()?;
+        let (payload_prefix, payload_suffix) =
+            validate_payload_wrapper(&self.payload_prefix, &self.payload_suffix, &encoder)?;
+        Ok(Box::new(HttpEncoder::new(
+            encoder,
+            self.encoding.transformer(),
+            payload_prefix,
+            payload_suffix,
+        )))
+    }
 }
 
 #[cfg(test)]
@@ -330,6 +390,7 @@ mod tests {
             let config = HttpSinkConfig {
                 uri: UriSerde::from_str("http://127.0.0.1:9000/endpoint")
                     .expect("should never fail to parse"),
+                unix_socket: None,
                 method: HttpMethod::Post,
                 encoding: EncodingConfigWithFraming::new(
                     None,
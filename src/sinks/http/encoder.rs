@@ -95,3 +95,14 @@ impl SinkEncoder<Vec<Event>> for HttpEncoder {
         write_all(writer, n_events, body.as_ref()).map(|()| (body.len(), byte_size))
     }
 }
+
+/// Encodes a single event as the body of a request holding only that event.
+impl SinkEncoder<Event> for HttpEncoder {
+    fn encode_input(
+        &self,
+        event: Event,
+        writer: &mut dyn io::Write,
+    ) -> io::Result<(usize, GroupedCountByteSize)> {
+        self.encode_input(vec![event], writer)
+    }
+}
@@ -49,6 +49,7 @@ fn generate_config() {
 fn default_cfg(encoding: EncodingConfigWithFraming) -> HttpSinkConfig {
     HttpSinkConfig {
         uri: Default::default(),
+        unix_socket: None,
         method: Default::default(),
         auth: Default::default(),
         headers: Default::default(),
@@ -583,3 +584,103 @@ async fn build_sink(extra_config: &str) -> (std::net::SocketAddr, crate::sinks::
     let (sink, _) = config.build(cx).await.unwrap();
     (in_addr, sink)
 }
+
+#[cfg(unix)]
+#[tokio::test]
+async fn sends_requests_over_unix_sockets() {
+    use std::convert::Infallible;
+
+    use hyper::{
+        service::{make_service_fn, service_fn},
+        Request, Server,
+    };
+    use tokio::{net::UnixListener, sync::mpsc};
+    use tokio_stream::wrappers::UnixListenerStream;
+
+    let path = crate::test_util::temp_file();
+    let listener = UnixListener::bind(&path).unwrap();
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let service = make_service_fn(move |_| {
+        let tx = tx.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                let tx = tx.clone();
+                async move {
+                    let (parts, body) = request.into_parts();
+                    let body = hyper::body::to_bytes(body).await.unwrap();
+                    tx.send((parts, body)).unwrap();
+                    Ok::<_, Infallible>(Response::new(Body::empty()))
+                }
+            }))
+        }
+    });
+    tokio::spawn(
+        Server::builder(hyper::server::accept::from_stream(UnixListenerStream::new(
+            listener,
+        )))
+        .serve(service),
+    );
+
+    let config = format!(
+        r#"
+        uri = "http://localhost/ingest"
+        unix_socket = "{}"
+        encoding.codec = "text"
+        request.headers.X-Test = "unix"
+        "#,
+        path.display()
+    );
+    let config: HttpSinkConfig = toml::from_str(&config).unwrap();
+
+    // The proxy would be unreachable, were it used.
+    let mut cx = SinkContext::default();
+    cx.proxy.http = Some("http://127.0.0.1:9".to_owned());
+    cx.healthcheck.uri = Some(
+        "http://localhost/health"
+            .parse::<http::Uri>()
+            .unwrap()
+            .into(),
+    );
+    let (sink, healthcheck) = config.build(cx).await.unwrap();
+
+    healthcheck.await.unwrap();
+    let (parts, _) = rx.recv().await.unwrap();
+    assert_eq!(parts.method, Method::HEAD);
+    assert_eq!(parts.uri.path(), "/health");
+
+    let (batch, mut receiver) = BatchNotifier::new_with_receiver();
+    let event = Event::from(LogEvent::from("hello").with_batch_notifier(&batch));
+    drop(batch);
+    components::run_and_assert_sink_compliance(
+        sink,
+        stream::once(async { event }),
+        &HTTP_SINK_TAGS,
+    )
+    .await;
+    assert_eq!(receiver.try_recv(), Ok(BatchStatus::Delivered));
+
+    let (parts, body) = rx.recv().await.unwrap();
+    assert_eq!(parts.method, Method::POST);
+    assert_eq!(parts.uri.path(), "/ingest");
+    assert_eq!(parts.headers["X-Test"], "unix");
+    assert_eq!(body, Bytes::from("hello\n"));
+}
+
+#[tokio::test]
+async fn rejects_tls_with_unix_sockets() {
+    let config: HttpSinkConfig = toml::from_str(
+        r#"
+        uri = "https://localhost/ingest"
+        unix_socket = "/var/run/collector.sock"
+        encoding.codec = "text"
+        tls.verify_certificate = false
+        "#,
+    )
+    .unwrap();
+
+    let error = config.build(SinkContext::default()).await.err().unwrap();
+    assert_eq!(
+        error.to_string(),
+        "The `tls` option cannot be used with the `unix_socket` option."
+    );
+}
@@ -5,7 +5,6 @@ use std::collections::HashMap;
 
 use bytes::{BufMut, BytesMut};
 use chrono::{DateTime, Utc};
-use futures::FutureExt;
 use http::{StatusCode, Uri};
 use snafu::{ResultExt, Snafu};
 use tower::Service;
@@ -13,7 +12,7 @@ use vector_lib::configurable::configurable_component;
 use vector_lib::event::{KeyString, MetricTags};
 use vector_lib::sensitive_string::SensitiveString;
 
-use crate::http::HttpClient;
+use crate::{http::HttpClient, sinks::IntoHealthcheck};
 
 pub(in crate::sinks) enum Field {
     /// string
@@ -224,7 +223,7 @@ fn healthcheck(
                 other => Err(super::HealthcheckError::UnexpectedStatus { status: other }.into()),
             })
     }
-    .boxed())
+    .into_healthcheck())
 }
 
 // https://docs.influxdata.com/influxdb/latest/reference/syntax/line-protocol/
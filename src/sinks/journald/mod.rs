@@ -1,14 +1,42 @@
-use crate::config::{AcknowledgementsConfig, Input, SinkConfig, SinkContext};
+use crate::common::severity::{Severity, SeverityNumbering};
+use crate::config::{
+    AcknowledgementsConfig, EncodableSink, Input, PayloadEncoder, SinkConfig, SinkContext,
+    SinkUpdates,
+};
+use crate::internal_events::{UnixSocketConnectionEstablished, UnixSocketOutgoingConnectionError};
 use crate::sinks::prelude::*;
+use crate::sinks::util::{
+    retries::is_retriable_io_error, EventSender, Maintenance, RetryConfig, SenderDriver,
+};
 use crate::sinks::{Healthcheck, VectorSink};
-use bytes::BufMut;
 use nix::errno::Errno;
-use std::path::PathBuf;
+use serde_with::serde_as;
+use snafu::Snafu;
+use std::collections::BTreeMap;
+use std::io;
+use std::os::unix::fs::FileTypeExt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio::net::UnixDatagram;
+use tokio::sync::watch;
+use tokio::time::{sleep, timeout};
+use vector_lib::codecs::journal_export;
 use vector_lib::configurable::configurable_component;
+use vector_lib::event::util::log::{FlattenOptions, KeyInterner};
+use vector_lib::internal_event::{
+    InternalEventHandle as _, KeyInternerLookups, KeyLookups, Registered,
+};
+use vector_lib::lookup::event_path;
+use vector_lib::schema::meaning;
+use vrl::{path, value::Kind};
 // reference: https://systemd.io/JOURNAL_NATIVE_PROTOCOL/
 
+/// How often the presence of the journald socket is checked.
+const SOCKET_PROBE_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Configuration for the `JournalD` sink.
+#[serde_as]
 #[configurable_component(sink("journald", "Deliver logs into JournalD via the native protocol."))]
 #[derive(Clone, Debug)]
 #[serde(deny_unknown_fields)]
@@ -23,9 +51,86 @@ pub struct JournaldSinkConfig {
 
     /// The Unix socket path.
     ///
-    /// This should be an absolute path.
+    /// This must be an absolute path.
     #[serde(default = "default_journald_socket_path")]
+    #[configurable(validation(path = "absolute"))]
     pub path: PathBuf,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub retry: RetryConfig,
+
+    /// The maximum amount of time to wait for the socket when it is missing, such as while
+    /// journald restarts, before sending an entry.
+    ///
+    /// The presence of the socket is checked in the background, so that entries are sent as soon
+    /// as it is back. By default, entries are sent without waiting for the socket.
+    #[serde_as(as = "Option<serde_with::DurationSecondsWithFrac<f64>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[configurable(metadata(docs::human_name = "Wait For Socket"))]
+    pub wait_for_socket_secs: Option<Duration>,
+
+    /// The priority of the entries for events without a `priority` field.
+    ///
+    /// Events with a field with the `severity` semantic meaning, such as the one the
+    /// `normalize_severity` transform writes, are given the priority of its severity instead.
+    ///
+    /// This can be changed on reload without restarting the sink.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_priority: Option<JournaldPriority>,
+
+    /// Fields added to every entry, unless the event has a field of the same name.
+    ///
+    /// This can be changed on reload without restarting the sink.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[configurable(metadata(
+        docs::additional_props_description = "A field added to every entry."
+    ))]
+    #[configurable(metadata(docs::examples = "example_extra_fields()"))]
+    pub extra_fields: BTreeMap<String, String>,
+
+    /// Whether or not to add the origin of events to their entries.
+    ///
+    /// When the `origin_metadata` global option is enabled, the ID and the type of the source
+    /// that ingested an event are added as the `VECTOR_SOURCE` and `VECTOR_SOURCE_TYPE` fields.
+    ///
+    /// This can be changed on reload without restarting the sink.
+    #[serde(default, skip_serializing_if = "crate::serde::is_default")]
+    pub include_origin: bool,
+}
+
+fn example_extra_fields() -> BTreeMap<String, String> {
+    BTreeMap::from([("SYSLOG_IDENTIFIER".to_owned(), "vector".to_owned())])
+}
+
+/// The priority of a journal entry, as a syslog severity.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum JournaldPriority {
+    /// System is unusable.
+    Emergency,
+
+    /// Action must be taken immediately.
+    Alert,
+
+    /// Critical conditions.
+    Critical,
+
+    /// Error conditions.
+    Error,
+
+    /// Warning conditions.
+    Warning,
+
+    /// Normal but significant conditions.
+    Notice,
+
+    /// Informational messages.
+    Info,
+
+    /// Debug-level messages.
+    Debug,
 }
 
 fn default_journald_socket_path() -> PathBuf {
@@ -35,22 +140,75 @@ fn default_journald_socket_path() -> PathBuf {
 #[async_trait::async_trait]
 #[typetag::serde(name = "journald")]
 impl SinkConfig for JournaldSinkConfig {
-    async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+    async fn build(&self, cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
         let socket = UnixDatagram::unbound()?;
         let target = self.path.clone();
 
-        let healthcheck = async move { Ok(()) }.boxed(); // TODO: implement healthcheck
-        let sink = JournalSink { socket, target }; // TODO: implement JournalSink
+        let healthcheck = healthcheck(target.clone()).into_healthcheck();
+        let (available_tx, available) = watch::channel(check_socket(&target).await.is_ok());
+        let (options_tx, options) = watch::channel(EntryOptions::from(self));
+        let sender = JournalSender {
+            socket,
+            target: target.clone(),
+            available,
+            wait_for_socket: self.wait_for_socket_secs,
+            options,
+            field_names: FieldNames::default(),
+        };
+        let mut sink = SenderDriver::new(sender)
+            .protocol("unix")
+            .dropped_output(cx.dropped_output)
+            .retry(self.retry)
+            .maintenance(socket_probe(target, available_tx));
+        if let Some(updates) = cx.updates {
+            sink = sink.maintenance(apply_updates(updates, options_tx));
+        }
         Ok((VectorSink::from_event_streamsink(sink), healthcheck))
     }
 
+    async fn healthcheck_builder(
+        &self,
+        _cx: &SinkContext,
+    ) -> crate::Result<Option<HealthcheckBuilder>> {
+        let target = self.path.clone();
+        Ok(Some(Arc::new(move || {
+            healthcheck(target.clone()).into_healthcheck()
+        })))
+    }
+
     fn input(&self) -> Input {
-        Input::log()
+        // Journal entries without a message or a priority are hard to make use of.
+        let requirement = Requirement::empty()
+            .recommended_meaning(meaning::MESSAGE, Kind::bytes())
+            .recommended_meaning(meaning::SEVERITY, Kind::bytes().or_integer());
+
+        Input::log().with_schema_requirement(requirement)
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
         &self.acknowledgements
     }
+
+    fn supports_dropped_output(&self) -> bool {
+        true
+    }
+
+    fn reloadable_fields(&self) -> &'static [&'static str] {
+        &["default_priority", "extra_fields", "include_origin"]
+    }
+
+    fn as_encodable(&self) -> Option<&dyn EncodableSink> {
+        Some(self)
+    }
+}
+
+impl EncodableSink for JournaldSinkConfig {
+    fn build_payload_encoder(&self) -> crate::Result<PayloadEncoder> {
+        Ok(Box::new(EntryEncoder {
+            options: EntryOptions::from(self),
+            field_names: FieldNames::default(),
+        }))
+    }
 }
 
 impl_generate_config_from_default!(JournaldSinkConfig);
@@ -60,113 +218,732 @@ impl Default for JournaldSinkConfig {
         Self {
             acknowledgements: Default::default(),
             path: default_journald_socket_path(),
+            retry: Default::default(),
+            wait_for_socket_secs: None,
+            default_priority: None,
+            extra_fields: BTreeMap::new(),
+            include_origin: false,
         }
     }
 }
 
-struct JournalSink {
-    socket: UnixDatagram,
-    target: PathBuf,
+/// The options of the entries sent by the sink, which are updated in place on reload.
+#[derive(Debug)]
+struct EntryOptions {
+    default_priority: Option<JournaldPriority>,
+    extra_fields: BTreeMap<String, String>,
+    include_origin: bool,
 }
 
-#[async_trait::async_trait]
-impl StreamSink<Event> for JournalSink {
-    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
-        self.run_inner(input).await
+impl From<&JournaldSinkConfig> for EntryOptions {
+    fn from(config: &JournaldSinkConfig) -> Self {
+        Self {
+            default_priority: config.default_priority,
+            extra_fields: config.extra_fields.clone(),
+            include_origin: config.include_origin,
+        }
     }
 }
 
-impl JournalSink {
-    async fn run_inner(&self, mut input: BoxStream<'_, Event>) -> Result<(), ()> {
-        let mut buffer = Vec::new();
-        while let Some(event) = input.next().await {
-            let event = event.into_log();
-            let fields = event.convert_to_fields();
+#[derive(Debug, Snafu)]
+enum JournaldHealthcheckError {
+    #[snafu(display("{} is not a socket", path.display()))]
+    NotASocket { path: PathBuf },
+}
+
+async fn healthcheck(path: PathBuf) -> crate::Result<()> {
+    check_socket(&path).await.map_err(Into::into)
+}
 
-            for (k, v) in fields {
-                write_field_name(&k, &mut buffer);
-                write_field_value(v, &mut buffer);
+async fn check_socket(path: &Path) -> io::Result<()> {
+    let metadata = tokio::fs::metadata(path).await?;
+    if metadata.file_type().is_socket() {
+        Ok(())
+    } else {
+        Err(io::Error::other(JournaldHealthcheckError::NotASocket {
+            path: path.to_owned(),
+        }))
+    }
+}
+
+/// Checks the presence of the journald socket in the background, so that entries waiting for it
+/// are sent as soon as it is back, even if no new entry comes in.
+fn socket_probe(path: PathBuf, available: watch::Sender<bool>) -> Maintenance {
+    let available = Arc::new(available);
+    Maintenance::new("journald_socket_probe", move || {
+        let path = path.clone();
+        let available = Arc::clone(&available);
+        async move {
+            loop {
+                match check_socket(&path).await {
+                    Ok(()) => {
+                        if !available.send_replace(true) {
+                            emit!(UnixSocketConnectionEstablished { path: &path });
+                        }
+                    }
+                    Err(error) => {
+                        if available.send_replace(false) {
+                            emit!(UnixSocketOutgoingConnectionError { error });
+                        }
+                    }
+                }
+                sleep(SOCKET_PROBE_INTERVAL).await;
             }
-            // TODO: handle errors
-            match self.socket.send_to(&buffer, &self.target).await {
-                Ok(_) => {}
-                Err(e) if e.raw_os_error() == Some(Errno::EMSGSIZE as i32) => self
-                    .send_via_memfd(&buffer)
-                    .await
-                    .expect("send memfd failed"),
-                Err(e) => Err(e).expect("send failed"),
-            };
-            buffer.clear();
         }
-        Ok(())
+    })
+}
+
+/// Applies the entry options of the configurations the sink is updated with.
+fn apply_updates(updates: SinkUpdates, options: watch::Sender<EntryOptions>) -> Maintenance {
+    let options = Arc::new(options);
+    Maintenance::new("journald_config_updates", move || {
+        let mut updates = updates.clone();
+        let options = Arc::clone(&options);
+        async move {
+            while let Some(config) = updates.next::<JournaldSinkConfig>().await {
+                debug!("Applying updated entry options.");
+                options.send_replace(EntryOptions::from(&config));
+            }
+            // The sink is no longer updated, so there is nothing left to do.
+            future::pending::<()>().await;
+        }
+    })
+}
+
+struct JournalSender {
+    socket: UnixDatagram,
+    target: PathBuf,
+    /// Whether the socket was present when last checked.
+    available: watch::Receiver<bool>,
+    wait_for_socket: Option<Duration>,
+    options: watch::Receiver<EntryOptions>,
+    field_names: FieldNames,
+}
+
+impl EventSender for JournalSender {
+    type Payload = Vec<u8>;
+    type Response = usize;
+    type Error = io::Error;
+
+    fn encode(&self, event: Event) -> io::Result<Vec<u8>> {
+        Ok(encode_entry(
+            event.into_log(),
+            &self.options.borrow(),
+            &self.field_names,
+        ))
     }
 
-    async fn send_via_memfd(&self, _data: &[u8]) -> Result<(), std::io::Error> {
-        todo!()
+    async fn send(&self, payload: &Vec<u8>) -> io::Result<usize> {
+        if let Some(wait) = self.wait_for_socket {
+            let mut available = self.available.clone();
+            // Sending fails as usual if the socket is still missing once done waiting.
+            _ = timeout(wait, available.wait_for(|available| *available)).await;
+        }
+
+        match self.socket.send_to(payload, &self.target).await {
+            Err(error) if error.raw_os_error() == Some(Errno::EMSGSIZE as i32) => {
+                send_via_memfd(&self.socket, &self.target, payload).await?;
+                Ok(payload.len())
+            }
+            result => result,
+        }
     }
-}
 
-/// Convert a field name to a valid field name in JournalD.
-/// This function magles the field name to a valid field name in JournalD according to the following rules:
-///
-/// - If the field name is empty, replace it with "EMPTY".
-/// - Transform all characters to ASII uppercase.
-/// - Replace all characters that are not allowed in field names with an underscore, which means that only 'A'-'Z', '0'-'9', and '_' are allowed.
-/// - If the field name starts with a digit or an underscore, prefix it with `ESC_`.
-/// - Only the first 64 characters of the field name are used.
-///
-/// # Reference
-///
-/// + [Upstream Validations](https://github.com/systemd/systemd/blob/cf8fd7148cd8fbdb79381202ce8686eed1de09d2/src/libsystemd/sd-journal/journal-file.c#L1703-L1739)
-fn write_field_name(name: &str, output: &mut Vec<u8>) {
-    let name = name.as_bytes();
-    if name.is_empty() {
-        output.extend_from_slice(b"EMPTY");
-        return;
+    fn is_retriable_error(&self, error: &io::Error) -> bool {
+        // The socket goes away while journald restarts.
+        is_retriable_io_error(error) || error.kind() == io::ErrorKind::NotFound
     }
 
-    let mut wrote = 0;
-    if !name[0].is_ascii_alphabetic() {
-        output.extend_from_slice(b"ESC_");
-        wrote += 4;
+    fn bytes_sent(&self, response: &usize) -> Option<usize> {
+        Some(*response)
     }
+}
+
+/// The journal field names the paths of event fields are mangled to, kept so that the same paths
+/// aren't mangled again for every entry.
+struct FieldNames {
+    interner: Mutex<KeyInterner>,
+    lookups: Registered<KeyInternerLookups>,
+}
 
-    for byte in name.iter().take(64) {
-        if byte.is_ascii_alphanumeric() {
-            output.push(byte.to_ascii_uppercase());
-        } else {
-            output.push(b'_');
+impl Default for FieldNames {
+    fn default() -> Self {
+        Self {
+            interner: Mutex::default(),
+            lookups: register!(KeyInternerLookups),
         }
-        wrote += 1;
+    }
+}
 
-        if wrote >= 64 {
-            break;
+impl FieldNames {
+    /// Writes the journal field names of the fields of an event, along with their values.
+    /// Returns whether the event has a priority field.
+    fn write_fields(&self, event: &LogEvent, buffer: &mut Vec<u8>) -> bool {
+        let mut interner = self.interner.lock().expect("poisoned lock");
+        let mut has_priority = false;
+        let mut fields = event.flattened_fields(FlattenOptions::default());
+        while let Some((path, value)) = fields.next_field() {
+            has_priority |= path.eq_ignore_ascii_case("priority");
+            let name = interner.get_or_insert_with(path, |path| {
+                let mut name = Vec::new();
+                // Fields starting with an underscore are reserved for trusted fields.
+                journal_export::write_field_name(path, false, &mut name);
+                String::from_utf8(name)
+                    .expect("mangled field names are ASCII")
+                    .into()
+            });
+            buffer.extend_from_slice(name.as_bytes());
+            write_field_value(value, buffer);
         }
+
+        let (hits, misses) = interner.take_stats();
+        self.lookups.emit(KeyLookups(hits, misses));
+        has_priority
     }
 }
 
-fn write_field_value(value: &Value, output: &mut Vec<u8>) {
-    fn write_bytes(bytes: impl AsRef<[u8]>, output: &mut Vec<u8>) {
-        let bytes = bytes.as_ref();
-        if !output.contains(&b'\n') {
-            output.push(b'=');
-        } else {
-            output.push(b'\n');
-            output.put_u64_le(bytes.len() as u64);
+/// Encodes an event as a journal entry, in the journal export format.
+fn encode_entry(event: LogEvent, options: &EntryOptions, field_names: &FieldNames) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    let has_priority = field_names.write_fields(&event, &mut buffer);
+    let priority = event
+        .get_by_meaning(meaning::SEVERITY)
+        .and_then(|value| Severity::from_value(value, SeverityNumbering::Syslog))
+        .map(Severity::code)
+        .or(options.default_priority.map(|priority| priority as u8));
+    if let Some(priority) = priority.filter(|_| !has_priority) {
+        journal_export::write_field_name("PRIORITY", false, &mut buffer);
+        journal_export::write_field_value(priority.to_string().as_bytes(), &mut buffer);
+    }
+    for (name, value) in &options.extra_fields {
+        if !event.contains(event_path!(name.as_str())) {
+            journal_export::write_field_name(name, false, &mut buffer);
+            journal_export::write_field_value(value.as_bytes(), &mut buffer);
+        }
+    }
+    if options.include_origin {
+        let metadata = event.metadata().value();
+        for (name, key) in [
+            ("VECTOR_SOURCE", "source_id"),
+            ("VECTOR_SOURCE_TYPE", "source_type"),
+        ] {
+            if let Some(value) = metadata.get(path!("vector", key)) {
+                journal_export::write_field_name(name, false, &mut buffer);
+                write_field_value(value, &mut buffer);
+            }
         }
-        output.extend_from_slice(bytes);
-        output.push(b'\n');
     }
+    buffer
+}
+
+/// Encodes events as the entries the sink sends, apart from the journald socket.
+struct EntryEncoder {
+    options: EntryOptions,
+    field_names: FieldNames,
+}
+
+impl encoding::Encoder<Event> for EntryEncoder {
+    fn encode_input(
+        &self,
+        event: Event,
+        writer: &mut dyn io::Write,
+    ) -> io::Result<(usize, GroupedCountByteSize)> {
+        let mut byte_size = telemetry().create_request_count_byte_size();
+        byte_size.add_event(&event, event.estimated_json_encoded_size_of());
+
+        let Event::Log(log) = event else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "journal entries can only be encoded from logs",
+            ));
+        };
+        let entry = encode_entry(log, &self.options, &self.field_names);
+        write_all(writer, 1, &entry)?;
+        Ok((entry.len(), byte_size))
+    }
+}
+
+/// Sends an entry too large for a datagram by passing a sealed memfd holding it, as
+/// `sd_journal_sendv` does.
+#[cfg(target_os = "linux")]
+async fn send_via_memfd(socket: &UnixDatagram, target: &Path, data: &[u8]) -> io::Result<()> {
+    use nix::{
+        fcntl::{fcntl, FcntlArg, SealFlag},
+        sys::{
+            memfd::{memfd_create, MemFdCreateFlag},
+            socket::{sendmsg, ControlMessage, MsgFlags, UnixAddr},
+        },
+    };
+    use std::{
+        io::Write,
+        os::fd::{AsRawFd, FromRawFd},
+    };
+    use tokio::io::Interest;
+
+    let fd = memfd_create(
+        c"journald",
+        MemFdCreateFlag::MFD_CLOEXEC | MemFdCreateFlag::MFD_ALLOW_SEALING,
+    )?;
+    // SAFETY: `memfd_create` returned a new file descriptor that nothing else owns.
+    let mut file = unsafe { std::fs::File::from_raw_fd(fd) };
+    file.write_all(data)?;
+    fcntl(
+        fd,
+        FcntlArg::F_ADD_SEALS(
+            SealFlag::F_SEAL_SHRINK
+                | SealFlag::F_SEAL_GROW
+                | SealFlag::F_SEAL_WRITE
+                | SealFlag::F_SEAL_SEAL,
+        ),
+    )?;
+
+    let address = UnixAddr::new(target)?;
+    socket
+        .async_io(Interest::WRITABLE, || {
+            sendmsg(
+                socket.as_raw_fd(),
+                &[],
+                &[ControlMessage::ScmRights(&[fd])],
+                MsgFlags::empty(),
+                Some(&address),
+            )
+            .map_err(io::Error::from)
+        })
+        .await?;
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+async fn send_via_memfd(_socket: &UnixDatagram, _target: &Path, _data: &[u8]) -> io::Result<()> {
+    Err(io::Error::from_raw_os_error(Errno::EMSGSIZE as i32))
+}
+
+fn write_field_value(value: &Value, output: &mut Vec<u8>) {
     match value {
-        Value::Bytes(bytes) => write_bytes(bytes, output),
-        Value::Regex(regex) => write_bytes(regex.as_bytes_slice(), output),
-        Value::Integer(integer) => write_bytes(integer.to_string(), output),
-        Value::Float(float) => write_bytes(float.to_string(), output),
-        Value::Boolean(boolean) => write_bytes(boolean.to_string(), output),
-        Value::Timestamp(timestamp) => write_bytes(timestamp.to_string(), output),
+        Value::Bytes(bytes) => journal_export::write_field_value(bytes, output),
+        Value::Regex(regex) => journal_export::write_field_value(regex.as_bytes_slice(), output),
+        Value::Integer(integer) => {
+            journal_export::write_field_value(integer.to_string().as_bytes(), output)
+        }
+        Value::Float(float) => {
+            journal_export::write_field_value(float.to_string().as_bytes(), output)
+        }
+        Value::Boolean(boolean) => {
+            journal_export::write_field_value(boolean.to_string().as_bytes(), output)
+        }
+        Value::Timestamp(timestamp) => {
+            journal_export::write_field_value(timestamp.to_string().as_bytes(), output)
+        }
         Value::Object(_) | Value::Array(_) => {
             unreachable!("Value should be flattened before calling this function")
         }
-        Value::Null => write_bytes("<NULL>", output),
+        Value::Null => journal_export::write_field_value(b"<NULL>", output),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+    use vector_lib::event::{BatchNotifier, BatchStatus, Metric, MetricKind, MetricValue};
+    use vector_lib::lookup::owned_value_path;
+    use vector_lib::schema::Definition;
+
+    use super::*;
+    use crate::{
+        config::Config,
+        sinks::util::{encoding::Encoder as _, DroppedOutput},
+        test_util::{
+            components::{run_and_assert_sink_compliance, SINK_TAGS},
+            mock::basic_source,
+            start_topology,
+        },
+    };
+
+    fn contains(datagram: &[u8], field: &[u8]) -> bool {
+        datagram.windows(field.len()).any(|window| window == field)
+    }
+
+    async fn receive(journal: &UnixDatagram) -> Vec<u8> {
+        let mut buffer = vec![0; 1024];
+        let length = journal.recv(&mut buffer).await.unwrap();
+        buffer.truncate(length);
+        buffer
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<JournaldSinkConfig>();
+    }
+
+    #[test]
+    fn rejects_relative_socket_path() {
+        let error = toml::from_str::<JournaldSinkConfig>(r#"path = "journal/socket""#).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("invalid value for `path`: must be an absolute path"));
+
+        let config =
+            toml::from_str::<JournaldSinkConfig>(r#"path = "/run/journal/socket""#).unwrap();
+        assert_eq!(config.path, PathBuf::from("/run/journal/socket"));
+    }
+
+    #[tokio::test]
+    async fn sends_entries() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("socket");
+        let journal = UnixDatagram::bind(&path).unwrap();
+
+        let config = JournaldSinkConfig {
+            path: path.clone(),
+            ..Default::default()
+        };
+        let (sink, healthcheck) = config.build(SinkContext::default()).await.unwrap();
+        healthcheck.await.unwrap();
+
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        let mut event = LogEvent::from("first line\nsecond line");
+        event.insert("priority", 6);
+        let event = Event::from(event.with_batch_notifier(&batch));
+        drop(batch);
+
+        run_and_assert_sink_compliance(sink, stream::once(async { event }), &SINK_TAGS).await;
+        assert_eq!(receiver.await, BatchStatus::Delivered);
+
+        let mut buffer = vec![0; 1024];
+        let length = journal.recv(&mut buffer).await.unwrap();
+        let datagram = &buffer[..length];
+        assert!(datagram
+            .windows(b"PRIORITY=6\n".len())
+            .any(|window| window == b"PRIORITY=6\n"));
+        assert!(datagram
+            .windows(b"MESSAGE\n\x16\x00\x00\x00\x00\x00\x00\x00first line\nsecond line\n".len())
+            .any(|window| {
+                window == b"MESSAGE\n\x16\x00\x00\x00\x00\x00\x00\x00first line\nsecond line\n"
+            }));
+    }
+
+    #[tokio::test]
+    async fn waits_for_missing_socket() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("socket");
+        let config = JournaldSinkConfig {
+            path: path.clone(),
+            wait_for_socket_secs: Some(Duration::from_secs(30)),
+            ..Default::default()
+        };
+        let (sink, _healthcheck) = config.build(SinkContext::default()).await.unwrap();
+
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        let event = LogEvent::from("hello").with_batch_notifier(&batch);
+        drop(batch);
+        let sink = tokio::spawn(sink.run_events([event.into()]));
+
+        // Journald comes back while the sink is waiting for it.
+        sleep(SOCKET_PROBE_INTERVAL / 2).await;
+        let journal = UnixDatagram::bind(&path).unwrap();
+
+        let mut buffer = vec![0; 1024];
+        let length = journal.recv(&mut buffer).await.unwrap();
+        assert!(buffer[..length]
+            .windows(b"MESSAGE=hello\n".len())
+            .any(|window| window == b"MESSAGE=hello\n"));
+        sink.await.unwrap().unwrap();
+        assert_eq!(receiver.await, BatchStatus::Delivered);
+    }
+
+    #[tokio::test]
+    async fn reroutes_undeliverable_entries() {
+        let directory = tempfile::tempdir().unwrap();
+        let config = JournaldSinkConfig {
+            path: directory.path().join("missing"),
+            ..Default::default()
+        };
+        let (dropped, mut rx) = DroppedOutput::new("journald".into(), "journald");
+        let cx = SinkContext {
+            dropped_output: Some(dropped),
+            ..SinkContext::default()
+        };
+        let (sink, _healthcheck) = config.build(cx).await.unwrap();
+
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        let event = LogEvent::from("hello").with_batch_notifier(&batch);
+        drop(batch);
+        sink.run_events([event.into()]).await.unwrap();
+
+        let log = rx
+            .recv()
+            .await
+            .unwrap()
+            .into_events()
+            .next()
+            .unwrap()
+            .into_log();
+        assert_eq!(log["message"], "hello".into());
+        assert_eq!(log["metadata.dropped.reason"], "sending_failed".into());
+        assert_eq!(log["metadata.dropped.component_kind"], "sink".into());
+
+        drop(log);
+        assert_eq!(receiver.await, BatchStatus::Delivered);
+    }
+
+    #[tokio::test]
+    async fn applies_entry_options() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("socket");
+        let journal = UnixDatagram::bind(&path).unwrap();
+
+        let config = JournaldSinkConfig {
+            path,
+            default_priority: Some(JournaldPriority::Warning),
+            extra_fields: BTreeMap::from([
+                ("SYSLOG_IDENTIFIER".to_owned(), "vector".to_owned()),
+                ("host".to_owned(), "default".to_owned()),
+            ]),
+            ..Default::default()
+        };
+        let (sink, _healthcheck) = config.build(SinkContext::default()).await.unwrap();
+
+        let mut event = LogEvent::from("hello");
+        event.insert("host", "example");
+        sink.run_events([event.into()]).await.unwrap();
+
+        let datagram = receive(&journal).await;
+        assert!(contains(&datagram, b"PRIORITY=4\n"));
+        assert!(contains(&datagram, b"SYSLOG_IDENTIFIER=vector\n"));
+        assert!(contains(&datagram, b"HOST=example\n"));
+        assert!(!contains(&datagram, b"HOST=default\n"));
+    }
+
+    #[test]
+    fn encodes_entries_without_socket() {
+        let config = JournaldSinkConfig {
+            default_priority: Some(JournaldPriority::Error),
+            ..Default::default()
+        };
+        let encoder = config.build_payload_encoder().unwrap();
+
+        let mut entry = Vec::new();
+        encoder
+            .encode_input(LogEvent::from("hello").into(), &mut entry)
+            .unwrap();
+        assert!(contains(&entry, b"MESSAGE=hello\n"));
+        assert!(contains(&entry, b"PRIORITY=3\n"));
+
+        let metric = Metric::new(
+            "counter",
+            MetricKind::Incremental,
+            MetricValue::Counter { value: 1.0 },
+        );
+        assert!(encoder.encode_input(metric.into(), &mut entry).is_err());
+    }
+
+    #[test]
+    fn encodes_nested_fields() {
+        let mut event = LogEvent::from("hello");
+        event.insert(event_path!("foo-bar"), "baz");
+        event.insert(event_path!("nested", "tags"), vec![1, 2]);
+
+        let options = EntryOptions::from(&JournaldSinkConfig::default());
+        let entry = encode_entry(event, &options, &FieldNames::default());
+        assert!(contains(&entry, b"FOO_BAR=baz\n"));
+        assert!(contains(&entry, b"NESTED_TAGS_0_=1\n"));
+        assert!(contains(&entry, b"NESTED_TAGS_1_=2\n"));
+    }
+
+    #[test]
+    fn encodes_priority_from_severity_meaning() {
+        let definition = Definition::empty_legacy_namespace().with_event_field(
+            &owned_value_path!("level"),
+            Kind::bytes(),
+            Some(meaning::SEVERITY),
+        );
+        let mut event = LogEvent::from("hello");
+        event.insert("level", "WARN");
+        event
+            .metadata_mut()
+            .set_schema_definition(&Arc::new(definition));
+
+        let options = EntryOptions::from(&JournaldSinkConfig {
+            default_priority: Some(JournaldPriority::Error),
+            ..Default::default()
+        });
+        let entry = encode_entry(event.clone(), &options, &FieldNames::default());
+        assert!(contains(&entry, b"PRIORITY=4\n"));
+
+        event.insert("level", "verbose");
+        let entry = encode_entry(event, &options, &FieldNames::default());
+        assert!(contains(&entry, b"PRIORITY=3\n"));
+    }
+
+    #[cfg(feature = "transforms-flatten")]
+    #[test]
+    fn flatten_transform_matches_field_names() {
+        use crate::transforms::{flatten::FlattenConfig, FunctionTransform, OutputBuffer};
+
+        let mut event = LogEvent::from("hello");
+        event.insert(event_path!("foo-bar"), "baz");
+        event.insert(event_path!("_trusted"), "no");
+        event.insert(event_path!("1st"), "first");
+        event.insert(event_path!("nested", "tags"), vec![1, 2]);
+        event.insert(event_path!("nested", "app.kubernetes.io/name"), "vector");
+
+        let options = EntryOptions::from(&JournaldSinkConfig::default());
+        let entry = encode_entry(event.clone(), &options, &FieldNames::default());
+        let mut names = entry
+            .split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let name = line.split(|byte| *byte == b'=').next().unwrap();
+                String::from_utf8(name.to_vec()).unwrap()
+            })
+            .collect::<Vec<_>>();
+        names.sort();
+
+        let config = toml::from_str::<FlattenConfig>(indoc::indoc! {r#"
+            arrays = "brackets"
+            sanitize.profile = "journald"
+        "#})
+        .unwrap();
+        let mut output = OutputBuffer::with_capacity(1);
+        crate::transforms::flatten::Flatten::new(&config).transform(&mut output, event.into());
+        let flattened = output.into_events().next().unwrap().into_log();
+        let keys = flattened
+            .as_map()
+            .unwrap()
+            .keys()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(keys, names);
+    }
+
+    #[test]
+    fn reuses_field_names() {
+        let mut event = LogEvent::from("hello");
+        event.insert(event_path!("nested", "tags"), vec![1, 2]);
+        let options = EntryOptions::from(&JournaldSinkConfig::default());
+        let field_names = FieldNames::default();
+
+        let first = encode_entry(event.clone(), &options, &field_names);
+        let second = encode_entry(event, &options, &field_names);
+        assert_eq!(first, second);
+
+        let mut interner = field_names.interner.lock().unwrap();
+        assert_eq!(interner.len(), 3);
+        assert_eq!(interner.take_stats(), (0, 0));
+    }
+
+    #[test]
+    fn encodes_origin() {
+        let mut event = LogEvent::from("hello");
+        let metadata = event.metadata_mut().value_mut();
+        metadata.insert(path!("vector", "source_id"), "in");
+        metadata.insert(path!("vector", "source_type"), "demo_logs");
+
+        let entry = |include_origin| {
+            let options = EntryOptions {
+                default_priority: None,
+                extra_fields: BTreeMap::new(),
+                include_origin,
+            };
+            encode_entry(event.clone(), &options, &FieldNames::default())
+        };
+        let with_origin = entry(true);
+        assert!(contains(&with_origin, b"VECTOR_SOURCE=in\n"));
+        assert!(contains(&with_origin, b"VECTOR_SOURCE_TYPE=demo_logs\n"));
+        assert!(!contains(&entry(false), b"VECTOR_SOURCE"));
+    }
+
+    #[tokio::test]
+    async fn reloads_entry_options_in_place() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("socket");
+        let journal = UnixDatagram::bind(&path).unwrap();
+
+        let (mut tx, source) = basic_source();
+        let config = |default_priority| {
+            let mut config = Config::builder();
+            config.add_source("in", source.clone());
+            config.add_sink(
+                "out",
+                &["in"],
+                JournaldSinkConfig {
+                    path: path.clone(),
+                    default_priority: Some(default_priority),
+                    ..Default::default()
+                },
+            );
+            config.build().unwrap()
+        };
+
+        let (mut topology, _) = start_topology(config(JournaldPriority::Info), false).await;
+        tx.send_event(LogEvent::from("before")).await.unwrap();
+        assert!(contains(&receive(&journal).await, b"PRIORITY=6\n"));
+
+        assert!(topology
+            .reload_config_and_respawn(config(JournaldPriority::Error), Default::default())
+            .await
+            .unwrap());
+        // The running sink applies its new options in the background.
+        sleep(Duration::from_millis(100)).await;
+        tx.send_event(LogEvent::from("after")).await.unwrap();
+        let datagram = receive(&journal).await;
+        assert!(contains(&datagram, b"MESSAGE=after\n"));
+        assert!(contains(&datagram, b"PRIORITY=3\n"));
+
+        topology.stop().await;
+    }
+
+    #[tokio::test]
+    async fn rebuilds_on_path_change() {
+        let directory = tempfile::tempdir().unwrap();
+        let old_path = directory.path().join("old");
+        let new_path = directory.path().join("new");
+        let old_journal = UnixDatagram::bind(&old_path).unwrap();
+        let new_journal = UnixDatagram::bind(&new_path).unwrap();
+
+        let (mut tx, source) = basic_source();
+        let config = |path: &Path, default_priority| {
+            let mut config = Config::builder();
+            config.add_source("in", source.clone());
+            config.add_sink(
+                "out",
+                &["in"],
+                JournaldSinkConfig {
+                    path: path.to_owned(),
+                    default_priority: Some(default_priority),
+                    ..Default::default()
+                },
+            );
+            config.build().unwrap()
+        };
+
+        let (mut topology, _) =
+            start_topology(config(&old_path, JournaldPriority::Info), false).await;
+        tx.send_event(LogEvent::from("before")).await.unwrap();
+        assert!(contains(&receive(&old_journal).await, b"MESSAGE=before\n"));
+
+        assert!(topology
+            .reload_config_and_respawn(
+                config(&new_path, JournaldPriority::Error),
+                Default::default()
+            )
+            .await
+            .unwrap());
+        tx.send_event(LogEvent::from("after")).await.unwrap();
+        let datagram = receive(&new_journal).await;
+        assert!(contains(&datagram, b"MESSAGE=after\n"));
+        assert!(contains(&datagram, b"PRIORITY=3\n"));
+
+        topology.stop().await;
+    }
+
+    #[tokio::test]
+    async fn healthcheck_requires_socket() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("file");
+        std::fs::write(&path, b"").unwrap();
+        assert!(healthcheck(path).await.is_err());
+        assert!(healthcheck(directory.path().join("missing")).await.is_err());
     }
 }
@@ -1,6 +1,5 @@
 use std::{collections::HashMap, time::Duration};
 
-use futures::FutureExt;
 use rdkafka::ClientConfig;
 use serde_with::serde_as;
 use vector_lib::codecs::JsonSerializerConfig;
@@ -258,7 +257,7 @@ impl GenerateConfig for KafkaSinkConfig {
 impl SinkConfig for KafkaSinkConfig {
     async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
         let sink = KafkaSink::new(self.clone())?;
-        let hc = healthcheck(self.clone()).boxed();
+        let hc = healthcheck(self.clone()).into_healthcheck();
         Ok((VectorSink::from_event_streamsink(sink), hc))
     }
 
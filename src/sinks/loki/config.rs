@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, num::NonZeroUsize};
 
 use vrl::value::Kind;
 
@@ -17,6 +17,12 @@ fn default_loki_path() -> String {
     "/loki/api/v1/push".to_string()
 }
 
+/// Loki rejects entries with more than 64 KiB of structured metadata by default, so longer values
+/// could never be accepted as they are.
+fn default_max_structured_metadata_value_length() -> NonZeroUsize {
+    NonZeroUsize::new(64 * 1024).expect("static")
+}
+
 /// Configuration for the `loki` sink.
 #[configurable_component(sink("loki", "Deliver log event data to the Loki aggregation system."))]
 #[derive(Clone, Debug)]
@@ -83,6 +89,35 @@ pub struct LokiConfig {
     #[serde(default = "crate::serde::default_false")]
     pub remove_structured_metadata_fields: bool,
 
+    /// Whether or not to send the remaining fields of the event as structured metadata.
+    ///
+    /// The remaining fields are those not used in labels or in `structured_metadata`, other than
+    /// the timestamp and the message, which is the log line when using the `text` codec. Nested
+    /// fields are flattened, their keys joined with `_`, and characters that Loki doesn't allow in
+    /// structured metadata names are replaced with `_`. When `structured_metadata` sets the same
+    /// key as a remaining field, `structured_metadata` takes precedence.
+    ///
+    /// Combined with `remove_structured_metadata_fields`, the remaining fields are deleted from the
+    /// event.
+    #[serde(default = "crate::serde::default_false")]
+    pub structured_metadata_remaining_fields: bool,
+
+    /// The maximum length, in bytes, of structured metadata values.
+    ///
+    /// Longer values are truncated. The default matches the maximum size of the structured metadata
+    /// of an entry that Loki accepts by default.
+    #[serde(default = "default_max_structured_metadata_value_length")]
+    #[configurable(metadata(docs::type_unit = "bytes"))]
+    pub max_structured_metadata_value_length: NonZeroUsize,
+
+    /// Whether or not the Loki instance accepts structured metadata.
+    ///
+    /// Structured metadata requires Loki 2.9.0 or newer, with `allow_structured_metadata` enabled,
+    /// as it is by default since Loki 3.0.0. If disabled, structured metadata isn't sent, and a
+    /// warning is logged when the sink starts if any is configured.
+    #[serde(default = "crate::serde::default_true")]
+    pub structured_metadata_supported: bool,
+
     /// Whether or not to remove the timestamp from the event payload.
     ///
     /// The timestamp is still sent as event metadata for Loki to use for indexing.
@@ -235,7 +270,7 @@ impl SinkConfig for LokiConfig {
 
         let sink = LokiSink::new(config.clone(), client.clone())?;
 
-        let healthcheck = healthcheck(config, client).boxed();
+        let healthcheck = healthcheck(config, client).into_healthcheck();
 
         Ok((VectorSink::from_event_streamsink(sink), healthcheck))
     }
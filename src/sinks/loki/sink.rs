@@ -1,9 +1,18 @@
-use std::{collections::HashMap, num::NonZeroUsize, sync::LazyLock};
+use std::{
+    collections::{HashMap, HashSet},
+    num::NonZeroUsize,
+    sync::LazyLock,
+};
 
 use bytes::{Bytes, BytesMut};
 use regex::Regex;
 use snafu::Snafu;
 use tokio_util::codec::Encoder as _;
+use vector_lib::event::{
+    util::log::{ArrayIndices, FlattenOptions, FlattenedFields},
+    ObjectMap,
+};
+use vector_lib::lookup::{OwnedTargetPath, PathPrefix};
 use vrl::path::parse_target_path;
 
 use super::{
@@ -16,6 +25,7 @@ use crate::{
     http::{get_http_scheme_from_uri, HttpClient},
     internal_events::{
         LokiEventUnlabeledError, LokiOutOfOrderEventDroppedError, LokiOutOfOrderEventRewritten,
+        LokiStructuredMetadataUnsupported, LokiStructuredMetadataValueTruncated,
         LokiTimestampNonParsableEventsDropped, SinkRequestBuildError,
     },
     sinks::prelude::*,
@@ -135,6 +145,8 @@ pub(super) struct EventEncoder {
     remove_label_fields: bool,
     structured_metadata: HashMap<Template, Template>,
     remove_structured_metadata_fields: bool,
+    structured_metadata_remaining_fields: bool,
+    max_structured_metadata_value_length: usize,
     remove_timestamp: bool,
 }
 
@@ -339,6 +351,85 @@ impl EventEncoder {
         Vec::from_iter(dynamic_structured_metadata)
     }
 
+    /// Returns the paths of the fields that aren't sent as remaining structured metadata: those
+    /// used in labels or in `structured_metadata`, the timestamp, and the message.
+    fn used_fields(&self, log: &LogEvent) -> Vec<OwnedTargetPath> {
+        let mut paths = self
+            .labels
+            .values()
+            .chain(self.structured_metadata.values())
+            .filter_map(Template::get_fields)
+            .flatten()
+            .filter_map(|field| parse_target_path(field.as_str()).ok())
+            .collect::<Vec<_>>();
+        paths.extend(log.timestamp_path().cloned());
+        paths.extend(log.message_path().cloned());
+        paths.retain(|path| path.prefix == PathPrefix::Event);
+        paths
+    }
+
+    fn build_remaining_structured_metadata(&self, event: &mut Event) -> Vec<(String, String)> {
+        let log = event.as_mut_log();
+        if !self.structured_metadata_remaining_fields || !log.value().is_object() {
+            return Vec::new();
+        }
+
+        // Set the used fields aside, so that only the remaining ones are flattened.
+        let paths = self.used_fields(log);
+        let mut used = LogEvent::from_map(ObjectMap::new(), log.metadata().clone());
+        for path in &paths {
+            if let Some(value) = log.remove(path) {
+                used.insert(path, value);
+            }
+        }
+
+        let options = FlattenOptions {
+            separator: '_',
+            array_indices: ArrayIndices::Segments,
+            max_depth: None,
+        };
+        let mut structured_metadata = Vec::new();
+        FlattenedFields::new(log.value(), options).for_each(|path, value| match value {
+            // Setting a used field aside leaves its emptied parents behind.
+            Value::Null => (),
+            Value::Object(map) if map.is_empty() => (),
+            Value::Array(array) if array.is_empty() => (),
+            value => structured_metadata.push((
+                sanitize_structured_metadata_key(path),
+                value.to_string_lossy().into_owned(),
+            )),
+        });
+
+        if self.remove_structured_metadata_fields {
+            *log = used;
+        } else {
+            for path in &paths {
+                if let Some(value) = used.remove(path) {
+                    log.insert(path, value);
+                }
+            }
+        }
+        structured_metadata
+    }
+
+    /// Truncates the structured metadata values longer than the maximum length.
+    fn truncate_structured_metadata(&self, structured_metadata: &mut [(String, String)]) {
+        let max_length = self.max_structured_metadata_value_length;
+        for (key, value) in structured_metadata {
+            if value.len() > max_length {
+                let mut length = max_length;
+                while !value.is_char_boundary(length) {
+                    length -= 1;
+                }
+                value.truncate(length);
+                emit!(LokiStructuredMetadataValueTruncated {
+                    key: key.as_str(),
+                    max_length,
+                });
+            }
+        }
+    }
+
     fn remove_structured_metadata_fields(&self, event: &mut Event) {
         if self.remove_structured_metadata_fields {
             for template in self.structured_metadata.values() {
@@ -359,7 +450,18 @@ impl EventEncoder {
         let json_byte_size = event.estimated_json_encoded_size_of();
         let mut labels: Vec<(String, String)> = self.build_labels(&event);
         self.remove_label_fields(&mut event);
-        let structured_metadata: Vec<(String, String)> = self.build_structured_metadata(&event);
+        let mut structured_metadata: Vec<(String, String)> = self.build_structured_metadata(&event);
+        let remaining = self.build_remaining_structured_metadata(&mut event);
+        if !remaining.is_empty() {
+            // `structured_metadata` takes precedence over the remaining fields.
+            let keys = structured_metadata
+                .iter()
+                .map(|(key, _)| key.clone())
+                .collect::<HashSet<_>>();
+            structured_metadata
+                .extend(remaining.into_iter().filter(|(key, _)| !keys.contains(key)));
+        }
+        self.truncate_structured_metadata(&mut structured_metadata);
         self.remove_structured_metadata_fields(&mut event);
 
         let timestamp = match event.as_log().get_timestamp() {
@@ -401,7 +503,7 @@ impl EventEncoder {
             event: LokiEvent {
                 timestamp,
                 event: bytes.freeze(),
-                structured_metadata: structured_metadata.clone(),
+                structured_metadata,
             },
             partition,
             finalizers,
@@ -495,9 +597,18 @@ pub struct LokiSink {
 
 impl LokiSink {
     #[allow(clippy::missing_const_for_fn)] // const cannot run destructor
-    pub fn new(config: LokiConfig, client: HttpClient) -> crate::Result<Self> {
+    pub fn new(mut config: LokiConfig, client: HttpClient) -> crate::Result<Self> {
         let compression = config.compression;
 
+        if !config.structured_metadata_supported
+            && (!config.structured_metadata.is_empty()
+                || config.structured_metadata_remaining_fields)
+        {
+            emit!(LokiStructuredMetadataUnsupported);
+            config.structured_metadata.clear();
+            config.structured_metadata_remaining_fields = false;
+        }
+
         // if Vector is configured to allow events with out of order timestamps, then we can
         // safely enable concurrency settings.
         //
@@ -546,6 +657,10 @@ impl LokiSink {
                 structured_metadata: config.structured_metadata,
                 remove_label_fields: config.remove_label_fields,
                 remove_structured_metadata_fields: config.remove_structured_metadata_fields,
+                structured_metadata_remaining_fields: config.structured_metadata_remaining_fields,
+                max_structured_metadata_value_length: config
+                    .max_structured_metadata_value_length
+                    .get(),
                 remove_timestamp: config.remove_timestamp,
             },
             batch_settings: config.batch.into_batcher_settings()?,
@@ -627,6 +742,17 @@ fn slugify_text(input: String) -> String {
     result.to_lowercase()
 }
 
+/// Sanitizes the key of a remaining field the same way as the keys of dynamic labels and
+/// structured metadata, also prefixing keys starting with a digit, which Loki doesn't allow.
+fn sanitize_structured_metadata_key(key: &str) -> String {
+    let key = slugify_text(key.to_owned());
+    if key.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{key}")
+    } else {
+        key
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{collections::HashMap, convert::TryFrom};
@@ -652,6 +778,8 @@ mod tests {
             structured_metadata: HashMap::default(),
             remove_label_fields: false,
             remove_structured_metadata_fields: false,
+            structured_metadata_remaining_fields: false,
+            max_structured_metadata_value_length: usize::MAX,
             remove_timestamp: false,
         };
         let mut event = Event::Log(LogEvent::from("hello world"));
@@ -697,6 +825,8 @@ mod tests {
             structured_metadata: HashMap::default(),
             remove_label_fields: false,
             remove_structured_metadata_fields: false,
+            structured_metadata_remaining_fields: false,
+            max_structured_metadata_value_length: usize::MAX,
             remove_timestamp: false,
         };
         let mut event = Event::Log(LogEvent::from("hello world"));
@@ -749,6 +879,8 @@ mod tests {
             structured_metadata: HashMap::default(),
             remove_label_fields: false,
             remove_structured_metadata_fields: false,
+            structured_metadata_remaining_fields: false,
+            max_structured_metadata_value_length: usize::MAX,
             remove_timestamp: false,
         };
 
@@ -801,6 +933,8 @@ mod tests {
             structured_metadata: HashMap::default(),
             remove_label_fields: false,
             remove_structured_metadata_fields: false,
+            structured_metadata_remaining_fields: false,
+            max_structured_metadata_value_length: usize::MAX,
             remove_timestamp: false,
         };
 
@@ -841,6 +975,8 @@ mod tests {
             structured_metadata: HashMap::default(),
             remove_label_fields: false,
             remove_structured_metadata_fields: false,
+            structured_metadata_remaining_fields: false,
+            max_structured_metadata_value_length: usize::MAX,
             remove_timestamp: false,
         };
 
@@ -864,6 +1000,8 @@ mod tests {
             structured_metadata: HashMap::default(),
             remove_label_fields: false,
             remove_structured_metadata_fields: false,
+            structured_metadata_remaining_fields: false,
+            max_structured_metadata_value_length: usize::MAX,
             remove_timestamp: true,
         };
         let mut event = Event::Log(LogEvent::from("hello world"));
@@ -896,6 +1034,8 @@ mod tests {
             structured_metadata: HashMap::default(),
             remove_label_fields: true,
             remove_structured_metadata_fields: false,
+            structured_metadata_remaining_fields: false,
+            max_structured_metadata_value_length: usize::MAX,
             remove_timestamp: false,
         };
         let mut event = Event::Log(LogEvent::from("hello world"));
@@ -934,6 +1074,8 @@ mod tests {
             structured_metadata,
             remove_label_fields: false,
             remove_structured_metadata_fields: false,
+            structured_metadata_remaining_fields: false,
+            max_structured_metadata_value_length: usize::MAX,
             remove_timestamp: false,
         };
 
@@ -989,6 +1131,8 @@ mod tests {
             structured_metadata: HashMap::default(),
             remove_label_fields: false,
             remove_structured_metadata_fields: false,
+            structured_metadata_remaining_fields: false,
+            max_structured_metadata_value_length: usize::MAX,
             remove_timestamp: false,
         };
         let base = chrono::Utc::now();
@@ -1,6 +1,6 @@
 use vector_lib::config::proxy::ProxyConfig;
 
-use super::{config::LokiConfig, healthcheck::healthcheck, sink::LokiSink};
+use super::{config::LokiConfig, event::LokiRecord, healthcheck::healthcheck, sink::LokiSink};
 use crate::{
     http::HttpClient,
     sinks::prelude::*,
@@ -200,3 +200,150 @@ async fn structured_metadata_as_json() {
 
     assert_eq!(body[2], expected_metadata);
 }
+
+async fn encode_with_structured_metadata(config: &str, event: LogEvent) -> LokiRecord {
+    let (config, cx) = load_sink::<LokiConfig>(config).unwrap();
+    let client = config.build_client(cx).unwrap();
+    let mut sink = LokiSink::new(config, client).unwrap();
+
+    let mut record = sink.encoder.encode_event(Event::Log(event)).unwrap();
+    record.event.structured_metadata.sort();
+    record
+}
+
+fn remaining_fields_event() -> LogEvent {
+    let mut event = LogEvent::from("hello world");
+    event.insert("app", "web");
+    event.insert("trace_id", "abc");
+    event.insert("http.status", 200);
+    event.insert("http.\"user-agent\"", "curl");
+    event.insert("tags", vec!["a", "b"]);
+    event.insert("\"2fa\"", true);
+    event
+}
+
+fn metadata(entries: &[(&str, &str)]) -> Vec<(String, String)> {
+    entries
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[tokio::test]
+async fn remaining_fields_as_structured_metadata() {
+    let record = encode_with_structured_metadata(
+        r#"
+        endpoint = "http://localhost:3100"
+        labels = {app = "{{ app }}"}
+        structured_metadata = {trace = "{{ trace_id }}", tags_1 = "static"}
+        structured_metadata_remaining_fields = true
+        encoding.codec = "text"
+        "#,
+        remaining_fields_event(),
+    )
+    .await;
+
+    assert_eq!(record.event.event, "hello world");
+    assert_eq!(
+        record.event.structured_metadata,
+        metadata(&[
+            ("_2fa", "true"),
+            ("http_status", "200"),
+            ("http_user_agent", "curl"),
+            ("tags_0", "a"),
+            ("tags_1", "static"),
+            ("trace", "abc"),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn remove_remaining_structured_metadata_fields() {
+    let record = encode_with_structured_metadata(
+        r#"
+        endpoint = "http://localhost:3100"
+        labels = {app = "{{ app }}"}
+        structured_metadata_remaining_fields = true
+        remove_structured_metadata_fields = true
+        encoding.codec = "json"
+        "#,
+        remaining_fields_event(),
+    )
+    .await;
+
+    let expected_line = serde_json::to_string(&serde_json::json!({
+        "app": "web",
+        "message": "hello world",
+    }))
+    .unwrap();
+    assert_eq!(record.event.event, expected_line);
+    assert_eq!(record.event.structured_metadata.len(), 6);
+}
+
+#[tokio::test]
+async fn truncate_structured_metadata_values() {
+    let mut event = LogEvent::from("hello world");
+    event.insert("foo", "ééé");
+    let record = encode_with_structured_metadata(
+        r#"
+        endpoint = "http://localhost:3100"
+        labels = {test = "structured_metadata"}
+        structured_metadata = {foo = "{{ foo }}", bar = "abcd"}
+        max_structured_metadata_value_length = 3
+        encoding.codec = "json"
+        "#,
+        event,
+    )
+    .await;
+
+    assert_eq!(
+        record.event.structured_metadata,
+        metadata(&[("bar", "abc"), ("foo", "é")])
+    );
+}
+
+#[tokio::test]
+async fn structured_metadata_values_are_capped_by_default() {
+    let short = "a".repeat(10_000);
+    let long = "b".repeat(100_000);
+    let mut event = LogEvent::from("hello world");
+    event.insert("foo", short.as_str());
+    event.insert("bar", long.as_str());
+    let record = encode_with_structured_metadata(
+        r#"
+        endpoint = "http://localhost:3100"
+        labels = {test = "structured_metadata"}
+        structured_metadata = {foo = "{{ foo }}", bar = "{{ bar }}"}
+        encoding.codec = "json"
+        "#,
+        event,
+    )
+    .await;
+
+    assert_eq!(
+        record.event.structured_metadata,
+        metadata(&[("bar", &long[..64 * 1024]), ("foo", short.as_str())])
+    );
+}
+
+#[tokio::test]
+async fn skip_unsupported_structured_metadata() {
+    let mut event = remaining_fields_event();
+    event.insert("foo", "bar");
+    let record = encode_with_structured_metadata(
+        r#"
+        endpoint = "http://localhost:3100"
+        labels = {test = "structured_metadata"}
+        structured_metadata.bar = "{{ foo }}"
+        structured_metadata_remaining_fields = true
+        remove_structured_metadata_fields = true
+        structured_metadata_supported = false
+        encoding.codec = "json"
+        "#,
+        event,
+    )
+    .await;
+
+    assert!(record.event.structured_metadata.is_empty());
+    assert!(String::from_utf8_lossy(&record.event.event).contains("\"foo\":\"bar\""));
+}
@@ -1,7 +1,7 @@
 use std::time::SystemTime;
 
 use bytes::Bytes;
-use futures::{FutureExt, SinkExt};
+use futures::SinkExt;
 use http::{Request, StatusCode, Uri};
 use serde_json::json;
 use vector_lib::configurable::configurable_component;
@@ -15,10 +15,13 @@ use crate::{
     event::Event,
     http::{Auth, HttpClient},
     schema,
-    sinks::util::{
-        http::{HttpEventEncoder, HttpSink, PartitionHttpSink},
-        BatchConfig, BoxedRawValue, JsonArrayBuffer, PartitionBuffer, PartitionInnerBuffer,
-        RealtimeSizeBasedDefaultBatchSettings, TowerRequestConfig, UriSerde,
+    sinks::{
+        util::{
+            http::{HttpEventEncoder, HttpSink, PartitionHttpSink},
+            BatchConfig, BoxedRawValue, JsonArrayBuffer, PartitionBuffer, PartitionInnerBuffer,
+            RealtimeSizeBasedDefaultBatchSettings, TowerRequestConfig, UriSerde,
+        },
+        IntoHealthcheck,
     },
     template::{Template, TemplateRenderingError},
 };
@@ -173,7 +176,7 @@ impl SinkConfig for MezmoConfig {
         )
         .sink_map_err(|error| error!(message = "Fatal mezmo sink error.", %error));
 
-        let healthcheck = healthcheck(self.clone(), client).boxed();
+        let healthcheck = healthcheck(self.clone(), client).into_healthcheck();
 
         #[allow(deprecated)]
         Ok((super::VectorSink::from_event_sink(sink), healthcheck))
@@ -1,5 +1,7 @@
 #![allow(missing_docs)]
-use futures::future::BoxFuture;
+use std::{future::Future, sync::Arc};
+
+use futures::{future::BoxFuture, FutureExt, TryFutureExt};
 use snafu::Snafu;
 
 pub mod prelude;
@@ -47,6 +49,8 @@ pub mod databend;
 pub mod datadog;
 #[cfg(feature = "sinks-elasticsearch")]
 pub mod elasticsearch;
+#[cfg(feature = "sinks-exec")]
+pub mod exec;
 #[cfg(feature = "sinks-file")]
 pub mod file;
 #[cfg(feature = "sinks-gcp")]
@@ -111,7 +115,43 @@ pub mod websocket;
 
 pub use vector_lib::{config::Input, sink::VectorSink};
 
-pub type Healthcheck = BoxFuture<'static, crate::Result<()>>;
+pub type Healthcheck = BoxFuture<'static, crate::Result<HealthcheckStatus>>;
+
+/// Builds a new healthcheck of a sink each time it is called, so that the healthcheck can be
+/// retried and re-run without building the sink again.
+pub type HealthcheckBuilder = Arc<dyn Fn() -> Healthcheck + Send + Sync>;
+
+/// How a sink passed its healthcheck.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HealthcheckStatus {
+    Healthy,
+
+    /// The sink is functional, but not in its nominal state, such as when it can only reach some
+    /// of its endpoints.
+    ///
+    /// Degraded sinks pass `require_healthy` unless `healthchecks.allow_degraded` is disabled.
+    Degraded(String),
+}
+
+/// Converts a future checking that a sink can be reached into a [`Healthcheck`], reporting the
+/// sink as healthy when it passes.
+pub trait IntoHealthcheck {
+    fn into_healthcheck(self) -> Healthcheck;
+}
+
+impl<F> IntoHealthcheck for F
+where
+    F: Future<Output = crate::Result<()>> + Send + 'static,
+{
+    fn into_healthcheck(self) -> Healthcheck {
+        self.map_ok(|()| HealthcheckStatus::Healthy).boxed()
+    }
+}
+
+/// A healthcheck that always passes, for sinks that have nothing to check.
+pub fn healthy() -> Healthcheck {
+    futures::future::ok(HealthcheckStatus::Healthy).boxed()
+}
 
 /// Common build errors
 #[derive(Debug, Snafu)]
@@ -12,7 +12,7 @@ use crate::{
     sinks::{
         mqtt::sink::{ConfigurationSnafu, MqttConnector, MqttError, MqttSink, TlsSnafu},
         prelude::*,
-        Healthcheck, VectorSink,
+        Healthcheck, IntoHealthcheck, VectorSink,
     },
     tls::{MaybeTlsSettings, TlsEnableableConfig},
 };
@@ -151,7 +151,7 @@ impl SinkConfig for MqttSinkConfig {
 
         Ok((
             VectorSink::from_event_streamsink(sink),
-            Box::pin(async move { connector.healthcheck().await }),
+            async move { connector.healthcheck().await }.into_healthcheck(),
         ))
     }
 
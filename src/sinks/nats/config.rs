@@ -113,7 +113,7 @@ impl GenerateConfig for NatsSinkConfig {
 impl SinkConfig for NatsSinkConfig {
     async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
         let sink = NatsSink::new(self.clone()).await?;
-        let healthcheck = healthcheck(self.clone()).boxed();
+        let healthcheck = healthcheck(self.clone()).into_healthcheck();
         Ok((VectorSink::from_event_streamsink(sink), healthcheck))
     }
 
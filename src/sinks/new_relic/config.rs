@@ -121,7 +121,7 @@ impl NewRelicConfig {
         client: HttpClient,
         credentials: Arc<NewRelicCredentials>,
     ) -> crate::Result<super::Healthcheck> {
-        Ok(healthcheck::healthcheck(client, credentials).boxed())
+        Ok(healthcheck::healthcheck(client, credentials).into_healthcheck())
     }
 }
 
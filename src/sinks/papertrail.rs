@@ -1,10 +1,12 @@
 use bytes::{BufMut, BytesMut};
 use syslog::{Facility, Formatter3164, LogFormat, Severity};
 use vector_lib::configurable::configurable_component;
+use vector_lib::schema::meaning;
 use vrl::value::Kind;
 
 use crate::{
     codecs::{Encoder, EncodingConfig, Transformer},
+    common::severity::{self, SeverityNumbering},
     config::{AcknowledgementsConfig, DataType, GenerateConfig, Input, SinkConfig, SinkContext},
     event::Event,
     internal_events::TemplateRenderingError,
@@ -111,7 +113,10 @@ impl SinkConfig for PapertrailConfig {
     }
 
     fn input(&self) -> Input {
-        let requirement = schema::Requirement::empty().optional_meaning("host", Kind::bytes());
+        let requirement = schema::Requirement::empty()
+            .optional_meaning("host", Kind::bytes())
+            .recommended_meaning("message", Kind::bytes())
+            .optional_meaning(meaning::SEVERITY, Kind::bytes().or_integer());
 
         Input::new(self.encoding.config().input_type() & DataType::Log)
             .with_schema_requirement(requirement)
@@ -156,6 +161,13 @@ impl tokio_util::codec::Encoder<Event> for PapertrailEncoder {
             .ok()
             .unwrap_or_else(|| String::from("vector"));
 
+        // Messages are sent with the informational severity, unless the event has one.
+        let severity = event
+            .as_log()
+            .get_by_meaning(meaning::SEVERITY)
+            .and_then(|value| severity::Severity::from_value(value, SeverityNumbering::Syslog))
+            .map_or(Severity::LOG_INFO, syslog_severity);
+
         let formatter = Formatter3164 {
             facility: Facility::LOG_USER,
             hostname: host,
@@ -171,7 +183,7 @@ impl tokio_util::codec::Encoder<Event> for PapertrailEncoder {
         let message = String::from_utf8_lossy(&bytes);
 
         formatter
-            .format(&mut buffer.writer(), Severity::LOG_INFO, message)
+            .format(&mut buffer.writer(), severity, message)
             .map_err(|error| Self::Error::SerializingError(format!("{}", error).into()))?;
 
         buffer.put_u8(b'\n');
@@ -180,16 +192,30 @@ impl tokio_util::codec::Encoder<Event> for PapertrailEncoder {
     }
 }
 
+const fn syslog_severity(severity: severity::Severity) -> Severity {
+    match severity {
+        severity::Severity::Emergency => Severity::LOG_EMERG,
+        severity::Severity::Alert => Severity::LOG_ALERT,
+        severity::Severity::Critical => Severity::LOG_CRIT,
+        severity::Severity::Error => Severity::LOG_ERR,
+        severity::Severity::Warning => Severity::LOG_WARNING,
+        severity::Severity::Notice => Severity::LOG_NOTICE,
+        severity::Severity::Info => Severity::LOG_INFO,
+        severity::Severity::Debug => Severity::LOG_DEBUG,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde::Deserialize;
-    use std::convert::TryFrom;
+    use std::{convert::TryFrom, sync::Arc};
 
     use bytes::BytesMut;
     use futures::{future::ready, stream};
     use tokio_util::codec::Encoder as _;
     use vector_lib::codecs::JsonSerializerConfig;
     use vector_lib::event::{Event, LogEvent};
+    use vector_lib::lookup::owned_value_path;
 
     use crate::test_util::{
         components::{run_and_assert_sink_compliance, SINK_TAGS},
@@ -244,4 +270,29 @@ mod tests {
         assert!(!value.contains_key("magic"));
         assert_eq!(value.get("process").unwrap().as_str(), Some("foo"));
     }
+
+    #[test]
+    fn encode_event_severity() {
+        let definition = schema::Definition::empty_legacy_namespace().with_event_field(
+            &owned_value_path!("level"),
+            Kind::bytes(),
+            Some(meaning::SEVERITY),
+        );
+        let mut evt = Event::Log(LogEvent::from("vector"));
+        evt.as_mut_log().insert("level", "err");
+        evt.metadata_mut()
+            .set_schema_definition(&Arc::new(definition));
+
+        let mut encoder = PapertrailEncoder {
+            pid: 0,
+            process: Template::try_from("vector").unwrap(),
+            transformer: Transformer::default(),
+            encoder: Encoder::<()>::new(JsonSerializerConfig::default().build().into()),
+        };
+
+        let mut bytes = BytesMut::new();
+        encoder.encode(evt, &mut bytes).unwrap();
+        // The user facility (1) and the error severity (3).
+        assert!(bytes.starts_with(b"<11>"));
+    }
 }
@@ -29,6 +29,7 @@ pub use crate::{
     event::{Event, LogEvent},
     internal_events::{SinkRequestBuildError, TemplateRenderingError},
     sinks::{
+        healthy,
         util::{
             builder::SinkBuilderExt,
             encoding::{self, write_all},
@@ -39,7 +40,7 @@ pub use crate::{
             BatchConfig, Compression, Concurrency, NoDefaultsBatchSettings, RequestBuilder,
             SinkBatchSettings, TowerRequestConfig,
         },
-        Healthcheck, HealthcheckError,
+        Healthcheck, HealthcheckBuilder, HealthcheckError, IntoHealthcheck,
     },
     template::{Template, TemplateParseError},
     tls::TlsConfig,
@@ -42,6 +42,7 @@ use crate::{
     http::{build_http_trace_layer, Auth},
     internal_events::PrometheusNormalizationError,
     sinks::{
+        healthy,
         util::{statistic::validate_quantiles, StreamSink},
         Healthcheck, VectorSink,
     },
@@ -201,7 +202,7 @@ impl SinkConfig for PrometheusExporterConfig {
         validate_quantiles(&self.quantiles)?;
 
         let sink = PrometheusExporter::new(self.clone());
-        let healthcheck = future::ok(()).boxed();
+        let healthcheck = healthy();
 
         Ok((VectorSink::from_event_streamsink(sink), healthcheck))
     }
@@ -178,7 +178,7 @@ impl SinkConfig for RemoteWriteConfig {
             self.compression,
             auth.clone(),
         )
-        .boxed();
+        .into_healthcheck();
 
         let service = RemoteWriteService {
             endpoint,
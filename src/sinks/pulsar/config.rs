@@ -5,7 +5,6 @@ use crate::{
         pulsar::sink::{healthcheck, PulsarSink},
     },
 };
-use futures_util::FutureExt;
 use pulsar::{
     authentication::oauth2::{OAuth2Authentication, OAuth2Params},
     compression,
@@ -358,7 +357,7 @@ impl SinkConfig for PulsarSinkConfig {
 
         let sink = PulsarSink::new(client, self.clone())?;
 
-        let hc = healthcheck(self.clone()).boxed();
+        let hc = healthcheck(self.clone()).into_healthcheck();
 
         Ok((VectorSink::from_event_streamsink(sink), hc))
     }
@@ -140,7 +140,7 @@ impl SinkConfig for RedisSinkConfig {
             return Err("`key` cannot be empty.".into());
         }
         let conn = self.build_client().await.context(RedisCreateFailedSnafu)?;
-        let healthcheck = RedisSinkConfig::healthcheck(conn.clone()).boxed();
+        let healthcheck = RedisSinkConfig::healthcheck(conn.clone()).into_healthcheck();
         let sink = RedisSink::new(self, conn)?;
         Ok((super::VectorSink::from_event_streamsink(sink), healthcheck))
     }
@@ -9,7 +9,6 @@ use aws_smithy_runtime_api::{
     client::{orchestrator::HttpResponse, result::SdkError},
     http::StatusCode,
 };
-use futures::FutureExt;
 use snafu::Snafu;
 use vector_lib::configurable::configurable_component;
 
@@ -19,7 +18,7 @@ use crate::{
     common::s3::S3ClientBuilder,
     config::ProxyConfig,
     http::status,
-    sinks::{util::retries::RetryLogic, Healthcheck},
+    sinks::{util::retries::RetryLogic, Healthcheck, IntoHealthcheck},
     tls::TlsConfig,
 };
 
@@ -355,7 +354,7 @@ pub fn build_healthcheck(bucket: String, client: S3Client) -> crate::Result<Heal
         }
     };
 
-    Ok(healthcheck.boxed())
+    Ok(healthcheck.into_healthcheck())
 }
 
 pub async fn create_service(
@@ -1,7 +1,7 @@
 use std::{collections::HashMap, future::ready, task::Poll};
 
 use bytes::{Bytes, BytesMut};
-use futures::{future::BoxFuture, stream, FutureExt, SinkExt};
+use futures::{future::BoxFuture, stream, SinkExt};
 use http::{StatusCode, Uri};
 use hyper::{Body, Request};
 use indoc::indoc;
@@ -26,7 +26,7 @@ use crate::{
             http::{HttpBatchService, HttpRetryLogic},
             BatchConfig, EncodedEvent, SinkBatchSettings, TowerRequestConfig,
         },
-        Healthcheck, HealthcheckError, VectorSink,
+        Healthcheck, HealthcheckError, IntoHealthcheck, VectorSink,
     },
     vector_version, Result,
 };
@@ -132,7 +132,7 @@ impl SinkConfig for SematextMetricsConfig {
             (None, Region::Eu) => EU_ENDPOINT.to_owned(),
         };
 
-        let healthcheck = healthcheck(endpoint.clone(), client.clone()).boxed();
+        let healthcheck = healthcheck(endpoint.clone(), client.clone()).into_healthcheck();
         let sink = SematextMetricsService::new(self.clone(), write_uri(&endpoint)?, client)?;
 
         Ok((sink, healthcheck))
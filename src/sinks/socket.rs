@@ -8,7 +8,10 @@ use vector_lib::configurable::configurable_component;
 use crate::sinks::util::unix::UnixSinkConfig;
 use crate::{
     codecs::{Encoder, EncodingConfig, EncodingConfigWithFraming, SinkType},
-    config::{AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
+    config::{
+        AcknowledgementsConfig, EncodableSink, GenerateConfig, Input, PayloadEncoder, SinkConfig,
+        SinkContext,
+    },
     sinks::util::{tcp::TcpSinkConfig, udp::UdpSinkConfig},
 };
 
@@ -155,6 +158,36 @@ impl SinkConfig for SocketSinkConfig {
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
         &self.acknowledgements
     }
+
+    fn as_encodable(&self) -> Option<&dyn EncodableSink> {
+        Some(self)
+    }
+}
+
+impl EncodableSink for SocketSinkConfig {
+    fn build_payload_encoder(&self) -> crate::Result<PayloadEncoder> {
+        Ok(match &self.mode {
+            Mode::Tcp(TcpMode { encoding, .. }) => {
+                let (framer, serializer) = encoding.build(SinkType::StreamBased)?;
+                Box::new((
+                    encoding.transformer(),
+                    Encoder::<Framer>::new(framer, serializer),
+                ))
+            }
+            Mode::Udp(UdpMode { encoding, .. }) => Box::new((
+                encoding.transformer(),
+                Encoder::<()>::new(encoding.build()?),
+            )),
+            #[cfg(unix)]
+            Mode::Unix(UnixMode { encoding, .. }) => {
+                let (framer, serializer) = encoding.build(SinkType::StreamBased)?;
+                Box::new((
+                    encoding.transformer(),
+                    Encoder::<Framer>::new(framer, serializer),
+                ))
+            }
+        })
+    }
 }
 
 #[cfg(test)]
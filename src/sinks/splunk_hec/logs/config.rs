@@ -197,7 +197,7 @@ impl SinkConfig for HecLogsSinkConfig {
             self.default_token.inner().to_owned(),
             client.clone(),
         )
-        .boxed();
+        .into_healthcheck();
         let sink = self.build_processor(client, cx)?;
 
         Ok((sink, healthcheck))
@@ -1,6 +1,5 @@
 use std::sync::Arc;
 
-use futures_util::FutureExt;
 use tower::ServiceBuilder;
 use vector_lib::configurable::configurable_component;
 use vector_lib::lookup::lookup_v2::OptionalValuePath;
@@ -21,7 +20,7 @@ use crate::{
         util::{
             http::HttpRetryLogic, BatchConfig, Compression, ServiceBuilderExt, TowerRequestConfig,
         },
-        Healthcheck,
+        Healthcheck, IntoHealthcheck,
     },
     template::Template,
     tls::TlsConfig,
@@ -151,7 +150,7 @@ impl SinkConfig for HecMetricsSinkConfig {
             self.default_token.inner().to_owned(),
             client.clone(),
         )
-        .boxed();
+        .into_healthcheck();
         let sink = self.build_processor(client, cx)?;
         Ok((sink, healthcheck))
     }
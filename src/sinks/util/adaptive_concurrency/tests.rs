@@ -14,11 +14,7 @@ use std::{
     task::Poll,
 };
 
-use futures::{
-    channel::oneshot,
-    future::{self, BoxFuture},
-    stream, FutureExt, SinkExt,
-};
+use futures::{channel::oneshot, future::BoxFuture, stream, SinkExt};
 use rand::{thread_rng, Rng};
 use rand_distr::Exp1;
 use rstest::*;
@@ -36,6 +32,7 @@ use crate::{
     event::{metric::MetricValue, Event},
     metrics,
     sinks::{
+        healthy,
         util::{
             retries::{JitterMode, RetryLogic},
             BatchSettings, Concurrency, EncodedEvent, EncodedLength, TowerRequestConfig, VecBuffer,
@@ -196,7 +193,7 @@ impl SinkConfig for TestConfig {
                 stream::iter(Some(Ok(EncodedEvent::new(event, 0, JsonSize::zero()))))
             })
             .sink_map_err(|error| panic!("Fatal test sink error: {}", error));
-        let healthcheck = future::ok(()).boxed();
+        let healthcheck = healthy();
 
         // Dig deep to get at the internal controller statistics
         let stats = Arc::clone(
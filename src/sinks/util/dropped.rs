@@ -0,0 +1,186 @@
+//! The `dropped` output of sinks.
+//!
+//! Sinks that support it can reroute the events they fail to deliver for good to a `dropped`
+//! output, instead of discarding them, when `reroute_dropped` is enabled. Like the `dropped`
+//! output of the `remap` transform, each rerouted event is annotated with the reason it failed
+//! and the component that rerouted it. The finalizers of a rerouted event travel with it, so it
+//! is acknowledged once the components consuming the `dropped` output deliver it.
+
+use std::{fmt, sync::Arc};
+
+use tokio::sync::mpsc;
+use vector_lib::{
+    config::{log_schema, LogNamespace},
+    finalization::{EventStatus, Finalizable},
+    internal_event::{ComponentEventsDropped, UNINTENTIONAL},
+    lookup::{metadata_path, PathPrefix},
+};
+use vrl::path;
+
+use crate::{
+    config::{ComponentKey, OutputId},
+    event::{Event, EventArray},
+    internal_events::SinkEventsRerouted,
+};
+
+/// The name of the output that sinks reroute dropped events to.
+pub const DROPPED: &str = "dropped";
+
+/// The number of rerouted events that can be waiting to be sent to the consumers of the output.
+const CHANNEL_SIZE: usize = 100;
+
+/// A handle to the `dropped` output of a sink.
+#[derive(Clone, Debug)]
+pub struct DroppedOutput {
+    output_id: Arc<OutputId>,
+    component_type: &'static str,
+    tx: mpsc::Sender<EventArray>,
+}
+
+impl DroppedOutput {
+    /// Creates the `dropped` output of a sink, along with the receiving end that feeds its
+    /// consumers.
+    pub fn new(
+        key: ComponentKey,
+        component_type: &'static str,
+    ) -> (Self, mpsc::Receiver<EventArray>) {
+        let (tx, rx) = mpsc::channel(CHANNEL_SIZE);
+        let output = Self {
+            output_id: Arc::new(OutputId::from((&key, DROPPED.to_owned()))),
+            component_type,
+            tx,
+        };
+        (output, rx)
+    }
+
+    /// Reroutes an event the sink failed to deliver.
+    ///
+    /// `reason` is a short identifier for the kind of failure, and `message` describes it.
+    pub async fn send(&self, mut event: Event, reason: &str, message: impl fmt::Display) {
+        self.annotate(&mut event, reason, &message.to_string());
+        event.set_upstream_id(Arc::clone(&self.output_id));
+
+        match self.tx.send(event.into()).await {
+            Ok(()) => emit!(SinkEventsRerouted { count: 1, reason }),
+            Err(mpsc::error::SendError(mut events)) => {
+                emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+                    count: 1,
+                    reason: "The dropped output is closed.",
+                });
+                events
+                    .take_finalizers()
+                    .update_status(EventStatus::Rejected);
+            }
+        }
+    }
+
+    fn dropped_data(&self, reason: &str, message: &str) -> serde_json::Value {
+        serde_json::json!({
+            "reason": reason,
+            "message": message,
+            "component_id": self.output_id.component,
+            "component_type": self.component_type,
+            "component_kind": "sink",
+        })
+    }
+
+    fn annotate(&self, event: &mut Event, reason: &str, message: &str) {
+        match event {
+            Event::Log(ref mut log) => match log.namespace() {
+                LogNamespace::Legacy => {
+                    if let Some(metadata_key) = log_schema().metadata_key() {
+                        log.insert(
+                            (PathPrefix::Event, metadata_key.concat(path!("dropped"))),
+                            self.dropped_data(reason, message),
+                        );
+                    }
+                }
+                LogNamespace::Vector => {
+                    log.insert(
+                        metadata_path!("vector", "dropped"),
+                        self.dropped_data(reason, message),
+                    );
+                }
+            },
+            Event::Metric(ref mut metric) => {
+                if let Some(metadata_key) = log_schema().metadata_key() {
+                    metric.replace_tag(format!("{}.dropped.reason", metadata_key), reason.into());
+                    metric.replace_tag(
+                        format!("{}.dropped.component_id", metadata_key),
+                        self.output_id.component.to_string(),
+                    );
+                    metric.replace_tag(
+                        format!("{}.dropped.component_type", metadata_key),
+                        self.component_type.into(),
+                    );
+                    metric.replace_tag(
+                        format!("{}.dropped.component_kind", metadata_key),
+                        "sink".into(),
+                    );
+                }
+            }
+            Event::Trace(ref mut trace) => {
+                trace.maybe_insert(log_schema().metadata_key_target_path(), || {
+                    self.dropped_data(reason, message).into()
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::event::{BatchNotifier, BatchStatus, LogEvent};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn annotates_rerouted_events() {
+        let (output, mut rx) = DroppedOutput::new("out".into(), "journald");
+        output
+            .send(
+                LogEvent::from("hello").into(),
+                "encoding_failed",
+                "bad field",
+            )
+            .await;
+
+        let events = rx.recv().await.unwrap();
+        let EventArray::Logs(logs) = events else {
+            panic!("expected logs");
+        };
+        let log = &logs[0];
+        assert_eq!(
+            log["metadata"],
+            serde_json::json!({
+                "dropped": {
+                    "reason": "encoding_failed",
+                    "message": "bad field",
+                    "component_id": "out",
+                    "component_type": "journald",
+                    "component_kind": "sink",
+                }
+            })
+            .try_into()
+            .unwrap()
+        );
+        assert_eq!(
+            log.metadata().upstream_id().map(ToString::to_string),
+            Some("out.dropped".to_owned())
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_events_when_closed() {
+        let (output, rx) = DroppedOutput::new("out".into(), "journald");
+        drop(rx);
+
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        let event = LogEvent::from("hello").with_batch_notifier(&batch);
+        drop(batch);
+        output
+            .send(event.into(), "sending_failed", "unreachable")
+            .await;
+        assert_eq!(receiver.await, BatchStatus::Rejected);
+    }
+}
@@ -92,6 +92,28 @@ impl Encoder<Event> for (Transformer, crate::codecs::Encoder<()>) {
     }
 }
 
+/// Encodes a single event framed, as stream-based sinks write each event they receive.
+impl Encoder<Event> for (Transformer, crate::codecs::Encoder<Framer>) {
+    fn encode_input(
+        &self,
+        mut event: Event,
+        writer: &mut dyn io::Write,
+    ) -> io::Result<(usize, GroupedCountByteSize)> {
+        let mut encoder = self.1.clone();
+        self.0.transform(&mut event);
+
+        let mut byte_size = telemetry().create_request_count_byte_size();
+        byte_size.add_event(&event, event.estimated_json_encoded_size_of());
+
+        let mut bytes = BytesMut::new();
+        encoder
+            .encode(event, &mut bytes)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        write_all(writer, 1, &bytes)?;
+        Ok((bytes.len(), byte_size))
+    }
+}
+
 /// Write the buffer to the writer. If the operation fails, emit an internal event which complies with the
 /// instrumentation spec- as this necessitates both an Error and EventsDropped event.
 ///
@@ -355,6 +377,30 @@ mod tests {
         assert_eq!(CountByteSize(1, input_json_size), json_size.size().unwrap());
     }
 
+    #[test]
+    fn test_encode_event_ndjson() {
+        let encoding = (
+            Transformer::default(),
+            crate::codecs::Encoder::<Framer>::new(
+                NewlineDelimitedEncoder::default().into(),
+                JsonSerializerConfig::default().build().into(),
+            ),
+        );
+
+        let mut writer = Vec::new();
+        let input = Event::Log(LogEvent::from(BTreeMap::from([(
+            KeyString::from("key"),
+            Value::from("value"),
+        )])));
+        let input_json_size = input.estimated_json_encoded_size_of();
+
+        let (written, json_size) = encoding.encode_input(input, &mut writer).unwrap();
+        assert_eq!(written, 16);
+
+        assert_eq!(String::from_utf8(writer).unwrap(), "{\"key\":\"value\"}\n");
+        assert_eq!(CountByteSize(1, input_json_size), json_size.size().unwrap());
+    }
+
     #[test]
     fn test_encode_event_text() {
         let encoding = (
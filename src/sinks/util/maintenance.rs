@@ -0,0 +1,109 @@
+//! Background tasks keeping up the connections of sinks.
+//!
+//! A sink that keeps a long-lived connection would otherwise only notice that its peer is gone,
+//! or back, when an event arrives. A [`Maintenance`] task runs alongside the sink instead, probing
+//! or reconnecting on its own schedule and sharing what it finds with the sink, usually through a
+//! `tokio::sync::watch` channel.
+//!
+//! The task is described when the sink is built, and only spawned once the sink runs, in the span
+//! of the sink so that the internal events it emits are tagged with the component. It is restarted
+//! if it ends or panics, and cancelled once the sink finishes, including when the sink is stopped
+//! on shutdown or reload.
+
+use std::{panic::AssertUnwindSafe, time::Duration};
+
+use futures::{future::BoxFuture, FutureExt};
+use tokio::{task::JoinHandle, time::sleep};
+use tracing::Instrument;
+
+/// How long to wait before restarting a maintenance task that ended.
+const RESTART_DELAY: Duration = Duration::from_secs(1);
+
+/// A background task keeping up the connection of a sink.
+pub struct Maintenance {
+    name: &'static str,
+    task: Box<dyn FnMut() -> BoxFuture<'static, ()> + Send>,
+}
+
+impl Maintenance {
+    /// Describes a maintenance task, each run of which is a future returned by `task`.
+    pub fn new<F, Fut>(name: &'static str, mut task: F) -> Self
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            name,
+            task: Box::new(move || task().boxed()),
+        }
+    }
+
+    /// Spawns the task in the current span, until the returned guard is dropped.
+    pub fn spawn(self) -> MaintenanceGuard {
+        let Self { name, mut task } = self;
+        let supervisor = async move {
+            loop {
+                match AssertUnwindSafe(task()).catch_unwind().await {
+                    Ok(()) => debug!(message = "Maintenance task ended, restarting.", task = name),
+                    Err(_) => error!(
+                        message = "Maintenance task panicked, restarting.",
+                        task = name,
+                        internal_log_rate_limit = true,
+                    ),
+                }
+                sleep(RESTART_DELAY).await;
+            }
+        };
+        MaintenanceGuard(tokio::spawn(supervisor.in_current_span()))
+    }
+}
+
+impl std::fmt::Debug for Maintenance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Maintenance")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Cancels a spawned maintenance task when dropped.
+#[derive(Debug)]
+pub struct MaintenanceGuard(JoinHandle<()>);
+
+impl Drop for MaintenanceGuard {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn restarts_until_cancelled() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&runs);
+        let maintenance = Maintenance::new("test", move || {
+            let runs = counter.fetch_add(1, Ordering::Relaxed);
+            async move {
+                if runs == 0 {
+                    panic!("first run fails");
+                }
+            }
+        });
+
+        let guard = maintenance.spawn();
+        sleep(RESTART_DELAY * 3 / 2).await;
+        assert_eq!(runs.load(Ordering::Relaxed), 2);
+
+        drop(guard);
+        sleep(RESTART_DELAY * 10).await;
+        assert_eq!(runs.load(Ordering::Relaxed), 2);
+    }
+}
@@ -6,14 +6,17 @@ pub mod batch;
 pub mod buffer;
 pub mod builder;
 pub mod compressor;
+pub mod dropped;
 pub mod encoding;
 pub mod http;
+pub mod maintenance;
 pub mod metadata;
 pub mod normalizer;
 pub mod partitioner;
 pub mod processed_event;
 pub mod request_builder;
 pub mod retries;
+pub mod sender;
 pub mod service;
 pub mod sink;
 pub mod snappy;
@@ -43,8 +46,12 @@ pub use buffer::{
 };
 pub use builder::SinkBuilderExt;
 pub use compressor::Compressor;
+pub use dropped::DroppedOutput;
+pub use maintenance::Maintenance;
 pub use normalizer::Normalizer;
 pub use request_builder::{IncrementalRequestBuilder, RequestBuilder};
+pub use retries::{BackoffConfig, RetryConfig};
+pub use sender::{EventSender, SenderDriver};
 pub use service::{
     Concurrency, ServiceBuilderExt, TowerBatchedSink, TowerPartitionSink, TowerRequestConfig,
     TowerRequestLayer, TowerRequestSettings,
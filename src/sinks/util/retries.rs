@@ -8,6 +8,7 @@ use std::{
 };
 
 use futures::FutureExt;
+use serde_with::serde_as;
 use tokio::time::{sleep, Sleep};
 use tower::{retry::Policy, timeout::error::Elapsed};
 use vector_lib::configurable::configurable_component;
@@ -236,6 +237,189 @@ impl RetryAction {
     }
 }
 
+/// Retry behavior of sinks that send events on their own, outside of a request-based service.
+///
+/// The delay between retries starts at `initial_backoff_secs`, and doubles after each retry up to
+/// `max_backoff_secs`.
+#[serde_as]
+#[configurable_component]
+#[derive(Clone, Copy, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct RetryConfig {
+    /// The maximum number of times a failed send is retried.
+    ///
+    /// Only errors that are considered transient, such as the destination being unreachable, are
+    /// retried. A value of `0` disables retries.
+    #[configurable(metadata(docs::type_unit = "retries"))]
+    pub attempts: usize,
+
+    /// The amount of time to wait before the first retry.
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Initial Backoff"))]
+    pub initial_backoff_secs: Duration,
+
+    /// The maximum amount of time to wait between retries.
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Max Backoff"))]
+    pub max_backoff_secs: Duration,
+
+    #[configurable(derived)]
+    pub jitter_mode: JitterMode,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            attempts: 0,
+            initial_backoff_secs: Duration::from_millis(500),
+            max_backoff_secs: Duration::from_secs(60),
+            jitter_mode: JitterMode::default(),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Returns the successive delays to wait between attempts.
+    pub fn backoff(&self) -> RetryBackoff {
+        RetryBackoff {
+            next: self.initial_backoff_secs,
+            max: self.max_backoff_secs,
+            jitter_mode: self.jitter_mode,
+        }
+    }
+
+    /// Runs `attempt` until it succeeds, fails with an error that `is_retriable` rejects, or the
+    /// retries are exhausted, returning the last result.
+    pub async fn retry<T, E, F, Fut>(
+        &self,
+        mut attempt: F,
+        is_retriable: impl Fn(&E) -> bool,
+    ) -> Result<T, E>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: std::fmt::Display,
+    {
+        let mut backoff = self.backoff();
+        let mut retries = 0;
+        loop {
+            match attempt().await {
+                Err(error) if retries < self.attempts && is_retriable(&error) => {
+                    retries += 1;
+                    let delay = backoff.next().expect("backoff never ends");
+                    warn!(
+                        message = "Retrying after error.",
+                        %error,
+                        retry = retries,
+                        delay_ms = %delay.as_millis(),
+                        internal_log_rate_limit = true,
+                    );
+                    sleep(delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Backoff between attempts to reconnect of sinks keeping a connection open.
+///
+/// The delay starts at `initial_backoff_secs`, and doubles after each failed attempt up to
+/// `max_backoff_secs`. Reconnecting is attempted for as long as the sink runs.
+#[serde_as]
+#[configurable_component]
+#[derive(Clone, Copy, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct BackoffConfig {
+    /// The amount of time to wait before the first attempt to reconnect.
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Initial Backoff"))]
+    pub initial_backoff_secs: Duration,
+
+    /// The maximum amount of time to wait between attempts to reconnect.
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Max Backoff"))]
+    pub max_backoff_secs: Duration,
+
+    /// The jitter mode to use for the backoff.
+    ///
+    /// Defaults to no jitter.
+    pub jitter_mode: JitterMode,
+}
+
+impl Default for BackoffConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff_secs: Duration::from_millis(500),
+            max_backoff_secs: Duration::from_secs(60),
+            jitter_mode: JitterMode::None,
+        }
+    }
+}
+
+impl BackoffConfig {
+    /// Returns the successive delays to wait between attempts.
+    pub const fn backoff(&self) -> RetryBackoff {
+        RetryBackoff {
+            next: self.initial_backoff_secs,
+            max: self.max_backoff_secs,
+            jitter_mode: self.jitter_mode,
+        }
+    }
+}
+
+/// Whether an I/O error is transient, and the operation that failed with it can be retried.
+pub fn is_retriable_io_error(error: &std::io::Error) -> bool {
+    use std::io::ErrorKind;
+
+    matches!(
+        error.kind(),
+        ErrorKind::ConnectionRefused
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::NotConnected
+            | ErrorKind::BrokenPipe
+            | ErrorKind::TimedOut
+            | ErrorKind::Interrupted
+            | ErrorKind::WouldBlock
+    ) || is_out_of_buffers(error)
+}
+
+#[cfg(unix)]
+fn is_out_of_buffers(error: &std::io::Error) -> bool {
+    error.raw_os_error() == Some(nix::errno::Errno::ENOBUFS as i32)
+}
+
+#[cfg(not(unix))]
+const fn is_out_of_buffers(_error: &std::io::Error) -> bool {
+    false
+}
+
+/// The delays between attempts of a [`RetryConfig`] or a [`BackoffConfig`], which go on forever.
+#[derive(Clone, Debug)]
+pub struct RetryBackoff {
+    next: Duration,
+    max: Duration,
+    jitter_mode: JitterMode,
+}
+
+impl Iterator for RetryBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let delay = cmp::min(self.next, self.max);
+        self.next = delay.saturating_mul(2);
+        Some(match self.jitter_mode {
+            JitterMode::None => delay,
+            JitterMode::Full if delay.is_zero() => delay,
+            JitterMode::Full => {
+                let jitter = (rand::random::<u64>() % (delay.as_millis() as u64).max(1)) + 1;
+                Duration::from_millis(jitter)
+            }
+        })
+    }
+}
+
 // `tokio-retry` crate
 // MIT License
 // Copyright (c) 2017 Sam Rijs
@@ -508,4 +692,100 @@ mod tests {
     }
 
     impl std::error::Error for Error {}
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_config_retries_transient_errors() {
+        let retry = RetryConfig {
+            attempts: 2,
+            jitter_mode: JitterMode::None,
+            ..Default::default()
+        };
+
+        let mut calls = 0;
+        let result = retry
+            .retry(
+                || {
+                    calls += 1;
+                    async move {
+                        if calls < 3 {
+                            Err("transient")
+                        } else {
+                            Ok(calls)
+                        }
+                    }
+                },
+                |_| true,
+            )
+            .await;
+        assert_eq!(result, Ok(3));
+
+        let mut calls = 0;
+        let result: Result<(), _> = retry
+            .retry(
+                || {
+                    calls += 1;
+                    async { Err("transient") }
+                },
+                |_| true,
+            )
+            .await;
+        assert_eq!(result, Err("transient"));
+        assert_eq!(calls, 3);
+    }
+
+    #[tokio::test]
+    async fn retry_config_does_not_retry_permanent_errors() {
+        let retry = RetryConfig {
+            attempts: 5,
+            ..Default::default()
+        };
+
+        let mut calls = 0;
+        let result: Result<(), _> = retry
+            .retry(
+                || {
+                    calls += 1;
+                    async { Err("permanent") }
+                },
+                |error| *error != "permanent",
+            )
+            .await;
+        assert_eq!(result, Err("permanent"));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn retry_backoff_doubles_up_to_max() {
+        let retry = RetryConfig {
+            initial_backoff_secs: Duration::from_secs(1),
+            max_backoff_secs: Duration::from_secs(5),
+            jitter_mode: JitterMode::None,
+            ..Default::default()
+        };
+        let delays = retry
+            .backoff()
+            .take(5)
+            .map(|d| d.as_secs())
+            .collect::<Vec<_>>();
+        assert_eq!(delays, vec![1, 2, 4, 5, 5]);
+
+        let retry = RetryConfig {
+            jitter_mode: JitterMode::Full,
+            ..retry
+        };
+        assert!(retry.backoff().take(5).all(|d| d <= Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn reconnect_backoff_defaults_to_no_jitter() {
+        let delays = BackoffConfig::default()
+            .backoff()
+            .take(9)
+            .map(|d| d.as_millis())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            delays,
+            vec![500, 1_000, 2_000, 4_000, 8_000, 16_000, 32_000, 60_000, 60_000]
+        );
+    }
 }
@@ -0,0 +1,675 @@
+//! A driver for stream sinks that encode and send each event on its own.
+//!
+//! Writing a `StreamSink` by hand means handling event finalization, the standard sink telemetry,
+//! concurrency and shutdown in every sink. Instead, sinks that are not built on HTTP can implement
+//! [`EventSender`], which only describes how to encode an event into a payload and how to send
+//! that payload, and run it with [`SenderDriver`]. This is the preferred way to write new
+//! non-HTTP sinks.
+//!
+//! When the sink has a [`DroppedOutput`], the events that fail to encode or to be sent are
+//! rerouted to it instead of being rejected. Sends failing with an error the sender considers
+//! retriable are retried according to the [`RetryConfig`] of the driver.
+//!
+//! A sender keeping a long-lived connection can have the driver run a [`Maintenance`] task for
+//! as long as the sink runs.
+//!
+//! Besides the standard sink telemetry, the driver reports how busy the sink is sending payloads:
+//! the `sink_send_utilization` gauge is the share of time during which at least one payload is
+//! being sent, as opposed to waiting for events, `sink_send_in_flight` the number of payloads
+//! being sent and `sink_send_duration_seconds` the time each payload took to send. A utilization
+//! close to one means the sink is the bottleneck.
+
+use std::{
+    convert::Infallible,
+    fmt,
+    future::Future,
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::{future::BoxFuture, stream::BoxStream, FutureExt, StreamExt};
+use tokio::time::{interval_at, Instant};
+use tower::{Service, ServiceBuilder};
+use vector_lib::{
+    config::telemetry,
+    finalization::{EventFinalizers, EventStatus, Finalizable},
+    internal_event::{
+        ComponentEventsDropped, InternalEventHandle as _, Registered, SharedString, UNINTENTIONAL,
+    },
+    request_metadata::{GroupedCountByteSize, MetaDescriptive, RequestMetadata},
+    sink::StreamSink,
+    stream::{Driver, DriverResponse},
+    ByteSizeOf, EstimatedJsonEncodedSizeOf,
+};
+
+use super::{DroppedOutput, Maintenance, RetryConfig};
+use crate::{
+    event::Event,
+    internal_events::{
+        SinkRequestBuildError, SinkSendDuration, SinkSendError, SinkSendInFlight,
+        SinkSendUtilization,
+    },
+    stats::Ewma,
+};
+
+/// How often the utilization of the sink is reported.
+const UTILIZATION_REPORT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A sink that encodes each event into a payload, and sends each payload on its own.
+pub trait EventSender: Send + Sync + 'static {
+    /// The encoded form of a single event.
+    type Payload: Send + Sync + 'static;
+
+    /// The result of successfully sending a payload.
+    type Response: Send + 'static;
+
+    /// The error returned when encoding or sending fails.
+    type Error: fmt::Debug + fmt::Display + Send + 'static;
+
+    /// Encodes an event into a payload.
+    ///
+    /// Events that fail to encode are rejected and reported as dropped, unless they are rerouted
+    /// to the dropped output.
+    fn encode(&self, event: Event) -> Result<Self::Payload, Self::Error>;
+
+    /// Sends a payload.
+    ///
+    /// Events whose payload failed to be sent are rejected, once the retries of retriable errors
+    /// are exhausted.
+    fn send(
+        &self,
+        payload: &Self::Payload,
+    ) -> impl Future<Output = Result<Self::Response, Self::Error>> + Send;
+
+    /// Whether sending a payload that failed with `error` can be retried.
+    ///
+    /// Only used when the driver is configured to retry. All errors are retriable by default.
+    fn is_retriable_error(&self, _error: &Self::Error) -> bool {
+        true
+    }
+
+    /// The number of bytes sent over the wire for a response, reported in the `BytesSent` event.
+    fn bytes_sent(&self, _response: &Self::Response) -> Option<usize> {
+        None
+    }
+}
+
+/// Runs an [`EventSender`] as a `StreamSink`.
+///
+/// The driver finalizes events in batches once their payloads have been sent, emits the
+/// `EventsSent`, `BytesSent` and error events, bounds the number of concurrent sends, and waits
+/// for in-flight sends to complete when the input stream ends.
+pub struct SenderDriver<S> {
+    sender: Arc<S>,
+    concurrency: NonZeroUsize,
+    protocol: Option<SharedString>,
+    dropped: Option<DroppedOutput>,
+    retry: RetryConfig,
+    maintenance: Vec<Maintenance>,
+}
+
+impl<S: EventSender> SenderDriver<S> {
+    /// Creates a driver sending one payload at a time.
+    pub fn new(sender: S) -> Self {
+        Self {
+            sender: Arc::new(sender),
+            concurrency: NonZeroUsize::MIN,
+            protocol: None,
+            dropped: None,
+            retry: RetryConfig::default(),
+            maintenance: Vec::new(),
+        }
+    }
+
+    /// Sets the maximum number of payloads sent concurrently.
+    ///
+    /// Payloads may be delivered out of order when this is greater than one.
+    #[must_use]
+    pub const fn concurrency(mut self, concurrency: NonZeroUsize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Sets the protocol tagging the `BytesSent` event.
+    #[must_use]
+    pub fn protocol(mut self, protocol: impl Into<SharedString>) -> Self {
+        self.protocol = Some(protocol.into());
+        self
+    }
+
+    /// Sets the output that events failing to encode or to be sent are rerouted to.
+    #[must_use]
+    pub fn dropped_output(mut self, dropped: Option<DroppedOutput>) -> Self {
+        self.dropped = dropped;
+        self
+    }
+
+    /// Sets how sends failing with a retriable error are retried.
+    ///
+    /// By default, failed sends are not retried.
+    #[must_use]
+    pub const fn retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Adds a task that runs in the background for as long as the sink runs.
+    #[must_use]
+    pub fn maintenance(mut self, maintenance: Maintenance) -> Self {
+        self.maintenance.push(maintenance);
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: EventSender> StreamSink<Event> for SenderDriver<S> {
+    async fn run(self: Box<Self>, input: BoxStream<'_, Event>) -> Result<(), ()> {
+        let Self {
+            sender,
+            concurrency,
+            protocol,
+            dropped,
+            retry,
+            maintenance,
+        } = *self;
+        let _maintenance = maintenance
+            .into_iter()
+            .map(Maintenance::spawn)
+            .collect::<Vec<_>>();
+
+        let encoder = Arc::clone(&sender);
+        let rerouted = dropped.clone();
+        let requests = input.filter_map(move |event| {
+            let encoder = Arc::clone(&encoder);
+            let dropped = rerouted.clone();
+            async move { encode(&*encoder, dropped.as_ref(), event).await }
+        });
+        let tracker = Arc::new(SendTracker::new());
+        let service = ServiceBuilder::new()
+            .concurrency_limit(concurrency.get())
+            .service(SenderService {
+                sender,
+                dropped,
+                retry,
+                tracker: Arc::clone(&tracker),
+            });
+
+        let driver = Driver::new(requests, service);
+        let driver = match protocol {
+            Some(protocol) => driver.protocol(protocol).run().boxed(),
+            None => driver.run().boxed(),
+        };
+
+        tokio::select! {
+            result = driver => result,
+            never = tracker.report_periodically() => match never {},
+        }
+    }
+}
+
+/// Tracks the payloads being sent, to report how busy the sink is.
+struct SendTracker {
+    state: Mutex<SendState>,
+    in_flight: Registered<SinkSendInFlight>,
+    duration: Registered<SinkSendDuration>,
+    utilization: Registered<SinkSendUtilization>,
+}
+
+struct SendState {
+    in_flight: usize,
+    /// When the current reporting period started.
+    period_start: Instant,
+    /// The time spent sending during the current reporting period, up to `busy_since`.
+    busy: Duration,
+    /// When the sink last went from sending nothing to sending a payload.
+    busy_since: Instant,
+    average: Ewma,
+}
+
+impl SendTracker {
+    fn new() -> Self {
+        let now = Instant::now();
+        Self {
+            state: Mutex::new(SendState {
+                in_flight: 0,
+                period_start: now,
+                busy: Duration::ZERO,
+                busy_since: now,
+                average: Ewma::new(0.9),
+            }),
+            in_flight: register!(SinkSendInFlight),
+            duration: register!(SinkSendDuration),
+            utilization: register!(SinkSendUtilization),
+        }
+    }
+
+    /// Records the start of a send, which ends when the returned guard is dropped.
+    fn start(self: &Arc<Self>) -> Sending {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        if state.in_flight == 0 {
+            state.busy_since = now;
+        }
+        state.in_flight += 1;
+        self.in_flight.emit(state.in_flight);
+
+        Sending {
+            tracker: Arc::clone(self),
+            start: now,
+        }
+    }
+
+    fn finish(&self, start: Instant) {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        state.in_flight -= 1;
+        if state.in_flight == 0 {
+            state.busy += now.duration_since(state.busy_since);
+        }
+        self.in_flight.emit(state.in_flight);
+        self.duration.emit(now.duration_since(start));
+    }
+
+    /// Reports the utilization every `UTILIZATION_REPORT_INTERVAL`, for as long as the sink runs.
+    async fn report_periodically(&self) -> Infallible {
+        let start = Instant::now() + UTILIZATION_REPORT_INTERVAL;
+        let mut reports = interval_at(start, UTILIZATION_REPORT_INTERVAL);
+        loop {
+            reports.tick().await;
+            self.report();
+        }
+    }
+
+    /// Reports the utilization since the previous report, and returns its moving average.
+    fn report(&self) -> f64 {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        if state.in_flight > 0 {
+            state.busy += now.duration_since(state.busy_since);
+            state.busy_since = now;
+        }
+
+        let period = now.duration_since(state.period_start);
+        let utilization = if period.is_zero() {
+            0.0
+        } else {
+            state.busy.as_secs_f64() / period.as_secs_f64()
+        };
+        let average = state.average.update(utilization);
+        self.utilization.emit(average);
+
+        state.period_start = now;
+        state.busy = Duration::ZERO;
+        average
+    }
+}
+
+/// A payload being sent.
+struct Sending {
+    tracker: Arc<SendTracker>,
+    start: Instant,
+}
+
+impl Drop for Sending {
+    fn drop(&mut self) {
+        self.tracker.finish(self.start);
+    }
+}
+
+async fn encode<S: EventSender>(
+    sender: &S,
+    dropped: Option<&DroppedOutput>,
+    mut event: Event,
+) -> Option<SenderRequest<S::Payload>> {
+    // When rerouting, the finalizers stay with a copy of the event until it is either sent or
+    // rerouted, so that the driver never rejects it.
+    let (finalizers, original) = match dropped {
+        Some(_) => {
+            let original = event.clone();
+            drop(event.take_finalizers());
+            (EventFinalizers::default(), Some(original))
+        }
+        None => (event.take_finalizers(), None),
+    };
+    let events_byte_size = event.size_of();
+    let mut events_sent = telemetry().create_request_count_byte_size();
+    events_sent.add_event(&event, event.estimated_json_encoded_size_of());
+
+    match sender.encode(event) {
+        Ok(payload) => Some(SenderRequest {
+            payload,
+            finalizers,
+            original,
+            metadata: RequestMetadata::new(1, events_byte_size, 0, 0, events_sent),
+        }),
+        Err(error) => {
+            let message = error.to_string();
+            emit!(SinkRequestBuildError { error });
+            match (dropped, original) {
+                (Some(dropped), Some(original)) => {
+                    dropped.send(original, "encoding_failed", message).await;
+                }
+                _ => {
+                    emit!(ComponentEventsDropped::<UNINTENTIONAL> {
+                        count: 1,
+                        reason: "Failed to encode event.",
+                    });
+                    finalizers.update_status(EventStatus::Rejected);
+                }
+            }
+            None
+        }
+    }
+}
+
+struct SenderRequest<P> {
+    payload: P,
+    finalizers: EventFinalizers,
+    /// The event the payload was encoded from, kept when rerouting to the dropped output.
+    original: Option<Event>,
+    metadata: RequestMetadata,
+}
+
+impl<P> Finalizable for SenderRequest<P> {
+    fn take_finalizers(&mut self) -> EventFinalizers {
+        std::mem::take(&mut self.finalizers)
+    }
+}
+
+impl<P> MetaDescriptive for SenderRequest<P> {
+    fn get_metadata(&self) -> &RequestMetadata {
+        &self.metadata
+    }
+
+    fn metadata_mut(&mut self) -> &mut RequestMetadata {
+        &mut self.metadata
+    }
+}
+
+struct SenderResponse {
+    events_sent: GroupedCountByteSize,
+    bytes_sent: Option<usize>,
+}
+
+impl DriverResponse for SenderResponse {
+    fn event_status(&self) -> EventStatus {
+        EventStatus::Delivered
+    }
+
+    fn events_sent(&self) -> &GroupedCountByteSize {
+        &self.events_sent
+    }
+
+    fn bytes_sent(&self) -> Option<usize> {
+        self.bytes_sent
+    }
+}
+
+struct SenderService<S> {
+    sender: Arc<S>,
+    dropped: Option<DroppedOutput>,
+    retry: RetryConfig,
+    tracker: Arc<SendTracker>,
+}
+
+impl<S: EventSender> Service<SenderRequest<S::Payload>> for SenderService<S> {
+    type Response = SenderResponse;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: SenderRequest<S::Payload>) -> Self::Future {
+        let sender = Arc::clone(&self.sender);
+        let dropped = self.dropped.clone();
+        let tracker = Arc::clone(&self.tracker);
+        let retry = self.retry;
+        let SenderRequest {
+            payload,
+            original,
+            metadata,
+            ..
+        } = request;
+        let events_sent = metadata.into_events_estimated_json_encoded_byte_size();
+        async move {
+            let sending = tracker.start();
+            let result = retry
+                .retry(
+                    || sender.send(&payload),
+                    |error| sender.is_retriable_error(error),
+                )
+                .await;
+            drop(sending);
+
+            match (result, dropped, original) {
+                (Ok(response), _, original) => {
+                    if let Some(mut original) = original {
+                        original
+                            .take_finalizers()
+                            .update_status(EventStatus::Delivered);
+                    }
+                    Ok(SenderResponse {
+                        events_sent,
+                        bytes_sent: sender.bytes_sent(&response),
+                    })
+                }
+                (Err(error), Some(dropped), Some(original)) => {
+                    let message = error.to_string();
+                    emit!(SinkSendError { error });
+                    dropped.send(original, "sending_failed", message).await;
+                    // Nothing was sent, and the event is now up to the dropped output.
+                    Ok(SenderResponse {
+                        events_sent: telemetry().create_request_count_byte_size(),
+                        bytes_sent: None,
+                    })
+                }
+                (Err(error), _, _) => Err(error),
+            }
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use futures::stream;
+    use vector_lib::{
+        event::{BatchNotifier, BatchStatus, BatchStatusReceiver, LogEvent},
+        sink::VectorSink,
+    };
+
+    use super::*;
+    use crate::{
+        sinks::util::retries::JitterMode,
+        test_util::components::{run_and_assert_sink_compliance, SINK_TAGS},
+    };
+
+    #[derive(Default)]
+    struct TestSender {
+        sent: Mutex<Vec<String>>,
+        /// The number of times sending "flaky" fails before it succeeds.
+        flaky_failures: Mutex<usize>,
+    }
+
+    impl EventSender for TestSender {
+        type Payload = String;
+        type Response = usize;
+        type Error = String;
+
+        fn encode(&self, event: Event) -> Result<String, String> {
+            let message = event.as_log().get_message().unwrap().to_string_lossy();
+            match message.as_ref() {
+                "unencodable" => Err("cannot encode".into()),
+                _ => Ok(message.into_owned()),
+            }
+        }
+
+        async fn send(&self, payload: &String) -> Result<usize, String> {
+            match payload.as_str() {
+                "unsendable" => return Err("cannot send".into()),
+                "flaky" => {
+                    let mut failures = self.flaky_failures.lock().unwrap();
+                    if *failures > 0 {
+                        *failures -= 1;
+                        return Err("temporarily unavailable".into());
+                    }
+                }
+                _ => {}
+            }
+            self.sent.lock().unwrap().push(payload.clone());
+            Ok(payload.len())
+        }
+
+        fn is_retriable_error(&self, error: &String) -> bool {
+            error != "cannot send"
+        }
+
+        fn bytes_sent(&self, response: &usize) -> Option<usize> {
+            Some(*response)
+        }
+    }
+
+    async fn run(messages: &[&str]) -> (Vec<String>, BatchStatus) {
+        let (sent, receiver) = run_with(messages, None, RetryConfig::default()).await;
+        (sent, receiver.await)
+    }
+
+    async fn run_with(
+        messages: &[&str],
+        dropped: Option<DroppedOutput>,
+        retry: RetryConfig,
+    ) -> (Vec<String>, BatchStatusReceiver) {
+        let sender = Arc::new(TestSender {
+            flaky_failures: Mutex::new(1),
+            ..Default::default()
+        });
+        let driver = SenderDriver {
+            sender: Arc::clone(&sender),
+            concurrency: NonZeroUsize::MIN,
+            protocol: Some("test".into()),
+            dropped,
+            retry,
+            maintenance: Vec::new(),
+        };
+
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        let events: Vec<Event> = messages
+            .iter()
+            .map(|message| LogEvent::from(*message).with_batch_notifier(&batch).into())
+            .collect();
+        drop(batch);
+
+        run_and_assert_sink_compliance(
+            VectorSink::from_event_streamsink(driver),
+            stream::iter(events),
+            &SINK_TAGS,
+        )
+        .await;
+
+        let sent = sender.sent.lock().unwrap().clone();
+        (sent, receiver)
+    }
+
+    #[tokio::test]
+    async fn sends_events_in_order() {
+        let (sent, status) = run(&["one", "two", "three"]).await;
+        assert_eq!(sent, vec!["one", "two", "three"]);
+        assert_eq!(status, BatchStatus::Delivered);
+    }
+
+    #[tokio::test]
+    async fn rejects_unencodable_events() {
+        let (sent, status) = run(&["one", "unencodable", "two"]).await;
+        assert_eq!(sent, vec!["one", "two"]);
+        assert_eq!(status, BatchStatus::Rejected);
+    }
+
+    #[tokio::test]
+    async fn rejects_unsent_events() {
+        let (sent, status) = run(&["one", "unsendable"]).await;
+        assert_eq!(sent, vec!["one"]);
+        assert_eq!(status, BatchStatus::Rejected);
+    }
+
+    #[tokio::test]
+    async fn reroutes_failed_events() {
+        let (dropped, mut rx) = DroppedOutput::new("out".into(), "test");
+        let (sent, receiver) = run_with(
+            &["one", "unencodable", "unsendable"],
+            Some(dropped),
+            RetryConfig::default(),
+        )
+        .await;
+        assert_eq!(sent, vec!["one"]);
+
+        let mut rerouted = Vec::new();
+        while let Ok(events) = rx.try_recv() {
+            for event in events.into_events() {
+                let log = event.into_log();
+                rerouted.push((
+                    log.get_message().unwrap().to_string_lossy().into_owned(),
+                    log["metadata.dropped.reason"]
+                        .to_string_lossy()
+                        .into_owned(),
+                ));
+            }
+        }
+        assert_eq!(
+            rerouted,
+            vec![
+                ("unencodable".to_owned(), "encoding_failed".to_owned()),
+                ("unsendable".to_owned(), "sending_failed".to_owned()),
+            ]
+        );
+
+        // The rerouted events were delivered by the consumer of the dropped output.
+        assert_eq!(receiver.await, BatchStatus::Delivered);
+    }
+
+    #[tokio::test]
+    async fn retries_retriable_errors() {
+        let retry = RetryConfig {
+            attempts: 3,
+            initial_backoff_secs: Duration::from_millis(1),
+            jitter_mode: JitterMode::None,
+            ..Default::default()
+        };
+        let (sent, receiver) = run_with(&["flaky", "unsendable", "two"], None, retry).await;
+        assert_eq!(sent, vec!["flaky", "two"]);
+        // Sending "unsendable" is not retried.
+        assert_eq!(receiver.await, BatchStatus::Rejected);
+
+        let (sent, receiver) = run_with(&["flaky"], None, RetryConfig::default()).await;
+        assert!(sent.is_empty());
+        assert_eq!(receiver.await, BatchStatus::Rejected);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reports_send_utilization() {
+        let tracker = Arc::new(SendTracker::new());
+
+        // Sending for one second out of four, with two overlapping sends counted once.
+        tokio::time::advance(Duration::from_secs(1)).await;
+        let first = tracker.start();
+        let second = tracker.start();
+        assert_eq!(tracker.state.lock().unwrap().in_flight, 2);
+        tokio::time::advance(Duration::from_millis(500)).await;
+        drop(first);
+        tokio::time::advance(Duration::from_millis(500)).await;
+        drop(second);
+        tokio::time::advance(Duration::from_secs(2)).await;
+        assert_eq!(tracker.report(), 0.25);
+
+        // A send still in flight counts towards the period it spans.
+        let _third = tracker.start();
+        tokio::time::advance(Duration::from_secs(4)).await;
+        assert!((tracker.report() - 0.925).abs() < 1e-9);
+        assert_eq!(tracker.state.lock().unwrap().in_flight, 1);
+    }
+}
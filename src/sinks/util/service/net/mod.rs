@@ -18,7 +18,7 @@ use crate::{
     internal_events::{
         SocketOutgoingConnectionError, TcpSocketConnectionEstablished, UdpSendIncompleteError,
     },
-    sinks::{util::retries::ExponentialBackoff, Healthcheck},
+    sinks::{util::retries::ExponentialBackoff, Healthcheck, IntoHealthcheck},
 };
 
 #[cfg(unix)]
@@ -270,7 +270,7 @@ impl NetworkConnector {
     /// Gets a `Healthcheck` based on the configured destination of this connector.
     pub fn healthcheck(&self) -> Healthcheck {
         let connector = self.clone();
-        Box::pin(async move { connector.connect().await.map(|_| ()).map_err(Into::into) })
+        async move { connector.connect().await.map(|_| ()).map_err(Into::into) }.into_healthcheck()
     }
 
     /// Gets a `Service` suitable for sending data to the configured destination of this connector.
@@ -3,7 +3,6 @@ use std::{
     net::SocketAddr,
     pin::Pin,
     task::{Context, Poll},
-    time::Duration,
 };
 
 use async_trait::async_trait;
@@ -31,11 +30,11 @@ use crate::{
     sink_ext::VecSinkExt,
     sinks::{
         util::{
-            retries::ExponentialBackoff,
+            retries::BackoffConfig,
             socket_bytes_sink::{BytesSink, ShutdownCheck},
             EncodedEvent, SinkBuildError, StreamSink,
         },
-        Healthcheck, VectorSink,
+        Healthcheck, IntoHealthcheck, VectorSink,
     },
     tcp::TcpKeepaliveConfig,
     tls::{MaybeTlsSettings, MaybeTlsStream, TlsEnableableConfig, TlsError},
@@ -62,6 +61,7 @@ pub struct TcpSinkConfig {
     /// The address _must_ include a port.
     #[configurable(metadata(docs::examples = "92.12.333.224:5000"))]
     #[configurable(metadata(docs::examples = "https://somehost:5000"))]
+    #[configurable(validation(not_empty))]
     address: String,
 
     #[configurable(derived)]
@@ -76,10 +76,14 @@ pub struct TcpSinkConfig {
     #[configurable(metadata(docs::type_unit = "bytes"))]
     #[configurable(metadata(docs::examples = 65536))]
     send_buffer_bytes: Option<usize>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    reconnect: BackoffConfig,
 }
 
 impl TcpSinkConfig {
-    pub const fn new(
+    pub fn new(
         address: String,
         keepalive: Option<TcpKeepaliveConfig>,
         tls: Option<TlsEnableableConfig>,
@@ -90,15 +94,17 @@ impl TcpSinkConfig {
             keepalive,
             tls,
             send_buffer_bytes,
+            reconnect: BackoffConfig::default(),
         }
     }
 
-    pub const fn from_address(address: String) -> Self {
+    pub fn from_address(address: String) -> Self {
         Self {
             address,
             keepalive: None,
             tls: None,
             send_buffer_bytes: None,
+            reconnect: BackoffConfig::default(),
         }
     }
 
@@ -115,12 +121,19 @@ impl TcpSinkConfig {
         let host = uri.host().ok_or(SinkBuildError::MissingHost)?.to_string();
         let port = uri.port_u16().ok_or(SinkBuildError::MissingPort)?;
         let tls = MaybeTlsSettings::from_config(&self.tls, false)?;
-        let connector = TcpConnector::new(host, port, self.keepalive, tls, self.send_buffer_bytes);
+        let connector = TcpConnector::new(
+            host,
+            port,
+            self.keepalive,
+            tls,
+            self.send_buffer_bytes,
+            self.reconnect,
+        );
         let sink = TcpSink::new(connector.clone(), transformer, encoder);
 
         Ok((
             VectorSink::from_event_streamsink(sink),
-            Box::pin(async move { connector.healthcheck().await }),
+            async move { connector.healthcheck().await }.into_healthcheck(),
         ))
     }
 }
@@ -132,6 +145,7 @@ struct TcpConnector {
     keepalive: Option<TcpKeepaliveConfig>,
     tls: MaybeTlsSettings,
     send_buffer_bytes: Option<usize>,
+    reconnect: BackoffConfig,
 }
 
 impl TcpConnector {
@@ -141,6 +155,7 @@ impl TcpConnector {
         keepalive: Option<TcpKeepaliveConfig>,
         tls: MaybeTlsSettings,
         send_buffer_bytes: Option<usize>,
+        reconnect: BackoffConfig,
     ) -> Self {
         Self {
             host,
@@ -148,19 +163,20 @@ impl TcpConnector {
             keepalive,
             tls,
             send_buffer_bytes,
+            reconnect,
         }
     }
 
     #[cfg(test)]
     fn from_host_port(host: String, port: u16) -> Self {
-        Self::new(host, port, None, None.into(), None)
-    }
-
-    const fn fresh_backoff() -> ExponentialBackoff {
-        // TODO: make configurable
-        ExponentialBackoff::from_millis(2)
-            .factor(250)
-            .max_delay(Duration::from_secs(60))
+        Self::new(
+            host,
+            port,
+            None,
+            None.into(),
+            None,
+            BackoffConfig::default(),
+        )
     }
 
     async fn connect(&self) -> Result<MaybeTlsStream<TcpStream>, TcpError> {
@@ -194,7 +210,7 @@ impl TcpConnector {
     }
 
     async fn connect_backoff(&self) -> MaybeTlsStream<TcpStream> {
-        let mut backoff = Self::fresh_backoff();
+        let mut backoff = self.reconnect.backoff();
         loop {
             match self.connect().await {
                 Ok(socket) => {
@@ -353,4 +369,12 @@ mod test {
         let bad = TcpConnector::from_host_port(addr.ip().to_string(), addr.port());
         assert!(bad.healthcheck().await.is_err());
     }
+
+    #[test]
+    fn rejects_empty_address() {
+        let error = toml::from_str::<TcpSinkConfig>(r#"address = """#).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("invalid value for `address`: must not be empty"));
+    }
 }
@@ -1,12 +1,11 @@
 use std::{
     net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
     pin::Pin,
-    time::Duration,
 };
 
 use async_trait::async_trait;
 use bytes::BytesMut;
-use futures::{stream::BoxStream, FutureExt, StreamExt};
+use futures::{stream::BoxStream, StreamExt};
 use snafu::{ResultExt, Snafu};
 use tokio::{net::UdpSocket, time::sleep};
 use tokio_util::codec::Encoder;
@@ -25,8 +24,11 @@ use crate::{
     },
     net,
     sinks::{
-        util::{retries::ExponentialBackoff, StreamSink},
-        Healthcheck, VectorSink,
+        util::{
+            retries::{is_retriable_io_error, BackoffConfig, RetryConfig},
+            StreamSink,
+        },
+        Healthcheck, IntoHealthcheck, VectorSink,
     },
 };
 
@@ -53,6 +55,7 @@ pub struct UdpSinkConfig {
     /// The address _must_ include a port.
     #[configurable(metadata(docs::examples = "92.12.333.224:5000"))]
     #[configurable(metadata(docs::examples = "https://somehost:5000"))]
+    #[configurable(validation(not_empty))]
     address: String,
 
     /// The size of the socket's send buffer.
@@ -61,13 +64,27 @@ pub struct UdpSinkConfig {
     #[configurable(metadata(docs::type_unit = "bytes"))]
     #[configurable(metadata(docs::examples = 65536))]
     send_buffer_bytes: Option<usize>,
+
+    /// How sending a datagram is retried.
+    ///
+    /// Sends are retried when they fail with a transient error, such as the destination refusing
+    /// the connection.
+    #[configurable(derived)]
+    #[serde(default)]
+    retry: RetryConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    reconnect: BackoffConfig,
 }
 
 impl UdpSinkConfig {
-    pub const fn from_address(address: String) -> Self {
+    pub fn from_address(address: String) -> Self {
         Self {
             address,
             send_buffer_bytes: None,
+            retry: RetryConfig::default(),
+            reconnect: BackoffConfig::default(),
         }
     }
 
@@ -75,7 +92,13 @@ impl UdpSinkConfig {
         let uri = self.address.parse::<http::Uri>()?;
         let host = uri.host().ok_or(SinkBuildError::MissingHost)?.to_string();
         let port = uri.port_u16().ok_or(SinkBuildError::MissingPort)?;
-        Ok(UdpConnector::new(host, port, self.send_buffer_bytes))
+        Ok(UdpConnector::new(
+            host,
+            port,
+            self.send_buffer_bytes,
+            self.retry,
+            self.reconnect,
+        ))
     }
 
     pub fn build(
@@ -91,7 +114,7 @@ impl UdpSinkConfig {
         let sink = UdpSink::new(connector.clone(), transformer, encoder);
         Ok((
             VectorSink::from_event_streamsink(sink),
-            async move { connector.healthcheck().await }.boxed(),
+            async move { connector.healthcheck().await }.into_healthcheck(),
         ))
     }
 }
@@ -101,24 +124,27 @@ struct UdpConnector {
     host: String,
     port: u16,
     send_buffer_bytes: Option<usize>,
+    retry: RetryConfig,
+    reconnect: BackoffConfig,
 }
 
 impl UdpConnector {
-    const fn new(host: String, port: u16, send_buffer_bytes: Option<usize>) -> Self {
+    const fn new(
+        host: String,
+        port: u16,
+        send_buffer_bytes: Option<usize>,
+        retry: RetryConfig,
+        reconnect: BackoffConfig,
+    ) -> Self {
         Self {
             host,
             port,
             send_buffer_bytes,
+            retry,
+            reconnect,
         }
     }
 
-    const fn fresh_backoff() -> ExponentialBackoff {
-        // TODO: make configurable
-        ExponentialBackoff::from_millis(2)
-            .factor(250)
-            .max_delay(Duration::from_secs(60))
-    }
-
     async fn connect(&self) -> Result<UdpSocket, UdpError> {
         let ip = dns::Resolver
             .lookup_ip(self.host.clone())
@@ -144,7 +170,7 @@ impl UdpConnector {
     }
 
     async fn connect_backoff(&self) -> UdpSocket {
-        let mut backoff = Self::fresh_backoff();
+        let mut backoff = self.reconnect.backoff();
         loop {
             match self.connect().await {
                 Ok(socket) => {
@@ -198,7 +224,7 @@ where
 
         let mut encoder = self.encoder.clone();
         while Pin::new(&mut input).peek().await.is_some() {
-            let mut socket = self.connector.connect_backoff().await;
+            let socket = self.connector.connect_backoff().await;
             while let Some(mut event) = input.next().await {
                 let byte_size = event.estimated_json_encoded_size_of();
 
@@ -212,7 +238,12 @@ where
                     continue;
                 }
 
-                match udp_send(&mut socket, &bytes).await {
+                let sent = self
+                    .connector
+                    .retry
+                    .retry(|| udp_send(&socket, &bytes), is_retriable_io_error)
+                    .await;
+                match sent {
                     Ok(()) => {
                         emit!(SocketEventsSent {
                             mode: SocketMode::Udp,
@@ -239,7 +270,7 @@ where
     }
 }
 
-async fn udp_send(socket: &mut UdpSocket, buf: &[u8]) -> tokio::io::Result<()> {
+async fn udp_send(socket: &UdpSocket, buf: &[u8]) -> tokio::io::Result<()> {
     let sent = socket.send(buf).await?;
     if sent != buf.len() {
         emit!(UdpSendIncompleteError {
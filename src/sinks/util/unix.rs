@@ -1,4 +1,4 @@
-use std::{path::PathBuf, pin::Pin, time::Duration};
+use std::{path::PathBuf, pin::Pin};
 
 use async_trait::async_trait;
 use bytes::{Bytes, BytesMut};
@@ -20,11 +20,11 @@ use crate::{
     sink_ext::VecSinkExt,
     sinks::{
         util::{
-            retries::ExponentialBackoff,
+            retries::BackoffConfig,
             socket_bytes_sink::{BytesSink, ShutdownCheck},
             EncodedEvent, StreamSink,
         },
-        Healthcheck, VectorSink,
+        Healthcheck, IntoHealthcheck, VectorSink,
     },
 };
 
@@ -46,11 +46,18 @@ pub struct UnixSinkConfig {
     /// This should be an absolute path.
     #[configurable(metadata(docs::examples = "/path/to/socket"))]
     pub path: PathBuf,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    reconnect: BackoffConfig,
 }
 
 impl UnixSinkConfig {
-    pub const fn new(path: PathBuf) -> Self {
-        Self { path }
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            reconnect: BackoffConfig::default(),
+        }
     }
 
     pub fn build(
@@ -62,11 +69,11 @@ impl UnixSinkConfig {
             + Sync
             + 'static,
     ) -> crate::Result<(VectorSink, Healthcheck)> {
-        let connector = UnixConnector::new(self.path.clone());
+        let connector = UnixConnector::new(self.path.clone(), self.reconnect);
         let sink = UnixSink::new(connector.clone(), transformer, encoder);
         Ok((
             VectorSink::from_event_streamsink(sink),
-            Box::pin(async move { connector.healthcheck().await }),
+            async move { connector.healthcheck().await }.into_healthcheck(),
         ))
     }
 }
@@ -74,18 +81,12 @@ impl UnixSinkConfig {
 #[derive(Debug, Clone)]
 struct UnixConnector {
     pub path: PathBuf,
+    reconnect: BackoffConfig,
 }
 
 impl UnixConnector {
-    const fn new(path: PathBuf) -> Self {
-        Self { path }
-    }
-
-    const fn fresh_backoff() -> ExponentialBackoff {
-        // TODO: make configurable
-        ExponentialBackoff::from_millis(2)
-            .factor(250)
-            .max_delay(Duration::from_secs(60))
+    const fn new(path: PathBuf, reconnect: BackoffConfig) -> Self {
+        Self { path, reconnect }
     }
 
     async fn connect(&self) -> Result<UnixStream, UnixError> {
@@ -97,7 +98,7 @@ impl UnixConnector {
     }
 
     async fn connect_backoff(&self) -> UnixStream {
-        let mut backoff = Self::fresh_backoff();
+        let mut backoff = self.reconnect.backoff();
         loop {
             match self.connect().await {
                 Ok(stream) => {
@@ -23,7 +23,7 @@ use crate::{
             retries::RetryLogic, BatchConfig, RealtimeEventBasedDefaultBatchSettings,
             ServiceBuilderExt, TowerRequestConfig,
         },
-        Healthcheck, VectorSink as VectorSinkType,
+        Healthcheck, IntoHealthcheck, VectorSink as VectorSinkType,
     },
     tls::{MaybeTlsSettings, TlsEnableableConfig},
 };
@@ -137,7 +137,7 @@ impl SinkConfig for VectorConfig {
 
         Ok((
             VectorSinkType::from_event_streamsink(sink),
-            Box::pin(healthcheck),
+            healthcheck.into_healthcheck(),
         ))
     }
 
@@ -16,7 +16,7 @@ use crate::{
             partitioner::KeyPartitioner, BatchConfig, BulkSizeBasedDefaultBatchSettings,
             Compression,
         },
-        Healthcheck,
+        Healthcheck, IntoHealthcheck,
     },
 };
 
@@ -103,7 +103,7 @@ impl SinkConfig for WebHdfsConfig {
         let op = self.build_operator()?;
 
         let check_op = op.clone();
-        let healthcheck = Box::pin(async move { Ok(check_op.check().await?) });
+        let healthcheck = async move { Ok(check_op.check().await?) }.into_healthcheck();
 
         let sink = self.build_processor(op)?;
         Ok((sink, healthcheck))
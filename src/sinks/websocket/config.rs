@@ -10,7 +10,7 @@ use crate::{
     http::Auth,
     sinks::{
         websocket::sink::{ConnectSnafu, WebSocketConnector, WebSocketError, WebSocketSink},
-        Healthcheck, VectorSink,
+        Healthcheck, IntoHealthcheck, VectorSink,
     },
     tls::{MaybeTlsSettings, TlsEnableableConfig},
 };
@@ -89,7 +89,7 @@ impl SinkConfig for WebSocketSinkConfig {
 
         Ok((
             VectorSink::from_event_streamsink(ws_sink),
-            Box::pin(async move { connector.healthcheck().await }),
+            async move { connector.healthcheck().await }.into_healthcheck(),
         ))
     }
 
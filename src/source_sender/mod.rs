@@ -1,7 +1,7 @@
 #![allow(missing_docs)]
 use std::{collections::HashMap, fmt, sync::Arc, time::Instant};
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use futures::{Stream, StreamExt};
 use metrics::{histogram, Histogram};
 use tracing::Span;
@@ -14,14 +14,15 @@ use vector_lib::finalization::{AddBatchNotifier, BatchNotifier};
 use vector_lib::internal_event::{ComponentEventsDropped, UNINTENTIONAL};
 use vector_lib::json_size::JsonSize;
 use vector_lib::{
-    config::{log_schema, SourceOutput},
-    event::{array, Event, EventArray, EventContainer, EventRef},
+    config::{log_schema, HostMetadata, SourceOutput},
+    event::{array, Event, EventArray, EventContainer, EventMetadata, EventRef},
     internal_event::{
-        self, CountByteSize, EventsSent, InternalEventHandle as _, Registered, DEFAULT_OUTPUT,
+        self, CountByteSize, EventsSent, InternalEventHandle as _, Registered,
+        SourceUnacknowledgedEvents, DEFAULT_OUTPUT,
     },
     ByteSizeOf, EstimatedJsonEncodedSizeOf,
 };
-use vrl::value::Value;
+use vrl::{path, value::Value};
 
 mod errors;
 
@@ -97,6 +98,9 @@ pub struct Builder {
     inner: Option<Inner>,
     named_inners: HashMap<String, Inner>,
     lag_time: Option<Histogram>,
+    host_metadata: Option<Arc<HostMetadata>>,
+    origin_source_type: Option<&'static str>,
+    unacknowledged_events: Registered<SourceUnacknowledgedEvents>,
 }
 
 impl Builder {
@@ -108,6 +112,27 @@ impl Builder {
             inner: self.inner,
             named_inners: self.named_inners,
             lag_time: self.lag_time,
+            host_metadata: self.host_metadata,
+            origin_source_type: self.origin_source_type,
+            unacknowledged_events: self.unacknowledged_events,
+        }
+    }
+
+    /// Enrich all events sent through the outputs added after this call with the given host
+    /// metadata.
+    pub fn with_host_metadata(self, host_metadata: Option<Arc<HostMetadata>>) -> Self {
+        Self {
+            host_metadata,
+            ..self
+        }
+    }
+
+    /// Attach the origin of all events sent through the outputs added after this call to their
+    /// metadata, using the given source type, if any.
+    pub fn with_origin_metadata(self, origin_source_type: Option<&'static str>) -> Self {
+        Self {
+            origin_source_type,
+            ..self
         }
     }
 
@@ -117,6 +142,10 @@ impl Builder {
         component_key: ComponentKey,
     ) -> LimitedReceiver<SourceSenderItem> {
         let lag_time = self.lag_time.clone();
+        let host_metadata = self.host_metadata.clone();
+        let origin = self
+            .origin_source_type
+            .map(|source_type| Arc::new(Origin::new(&component_key, source_type)));
         let log_definition = output.schema_definition.clone();
         let output_id = OutputId {
             component: component_key,
@@ -128,6 +157,8 @@ impl Builder {
                     self.buf_size,
                     DEFAULT_OUTPUT.to_owned(),
                     lag_time,
+                    host_metadata,
+                    origin,
                     log_definition,
                     output_id,
                 );
@@ -139,6 +170,8 @@ impl Builder {
                     self.buf_size,
                     name.clone(),
                     lag_time,
+                    host_metadata,
+                    origin,
                     log_definition,
                     output_id,
                 );
@@ -154,14 +187,26 @@ impl Builder {
         SourceSender {
             inner: self.inner,
             named_inners: self.named_inners,
+            unacknowledged_events: self.unacknowledged_events,
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct SourceSender {
     inner: Option<Inner>,
     named_inners: HashMap<String, Inner>,
+    unacknowledged_events: Registered<SourceUnacknowledgedEvents>,
+}
+
+impl fmt::Debug for SourceSender {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SourceSender")
+            .field("inner", &self.inner)
+            .field("named_inners", &self.named_inners)
+            // Registered handles are missing `impl Debug`
+            .finish_non_exhaustive()
+    }
 }
 
 impl SourceSender {
@@ -171,9 +216,21 @@ impl SourceSender {
             inner: None,
             named_inners: Default::default(),
             lag_time: Some(histogram!(LAG_TIME_NAME)),
+            host_metadata: None,
+            origin_source_type: None,
+            unacknowledged_events: register!(SourceUnacknowledgedEvents),
         }
     }
 
+    /// The handle reporting the events sent through here which are awaiting acknowledgement.
+    ///
+    /// It is registered along with this sender, so it is labelled with the source it was built
+    /// for. Batches of events sent through here are meant to be created with it, using
+    /// [`BatchNotifier::new_tracked_with_receiver`] and its variants.
+    pub fn unacknowledged_events(&self) -> &Registered<SourceUnacknowledgedEvents> {
+        &self.unacknowledged_events
+    }
+
     #[cfg(any(test, feature = "test-utils"))]
     pub fn new_test_sender_with_buffer(n: usize) -> (Self, LimitedReceiver<SourceSenderItem>) {
         let lag_time = Some(histogram!(LAG_TIME_NAME));
@@ -181,12 +238,20 @@ impl SourceSender {
             component: "test".to_string().into(),
             port: None,
         };
-        let (inner, rx) =
-            Inner::new_with_buffer(n, DEFAULT_OUTPUT.to_owned(), lag_time, None, output_id);
+        let (inner, rx) = Inner::new_with_buffer(
+            n,
+            DEFAULT_OUTPUT.to_owned(),
+            lag_time,
+            None,
+            None,
+            None,
+            output_id,
+        );
         (
             Self {
                 inner: Some(inner),
                 named_inners: Default::default(),
+                unacknowledged_events: register!(SourceUnacknowledgedEvents),
             },
             rx,
         )
@@ -254,7 +319,8 @@ impl SourceSender {
             component: "test".to_string().into(),
             port: Some(name.clone()),
         };
-        let (inner, recv) = Inner::new_with_buffer(100, name.clone(), None, None, output_id);
+        let (inner, recv) =
+            Inner::new_with_buffer(100, name.clone(), None, None, None, None, output_id);
         let recv = recv.into_stream().map(move |mut item| {
             item.events.iter_events_mut().for_each(|mut event| {
                 let metadata = event.metadata_mut();
@@ -367,12 +433,47 @@ impl Drop for UnsentEventCount {
     }
 }
 
+/// The origin of the events sent through a source, attached to their metadata under `vector`.
+#[derive(Debug)]
+struct Origin {
+    source_id: Value,
+    source_type: Value,
+}
+
+impl Origin {
+    fn new(component_key: &ComponentKey, source_type: &'static str) -> Self {
+        Self {
+            source_id: Value::from(component_key.id()),
+            source_type: Value::from(source_type),
+        }
+    }
+
+    /// Attaches the origin to the given event metadata.
+    ///
+    /// Sources using the `vector` log namespace already set their type and the time at which they
+    /// ingested the event, which are left as they are.
+    fn attach(&self, metadata: &mut EventMetadata, now: DateTime<Utc>) {
+        let value = metadata.value_mut();
+        value.insert(path!("vector", "source_id"), self.source_id.clone());
+        if value.get(path!("vector", "source_type")).is_none() {
+            value.insert(path!("vector", "source_type"), self.source_type.clone());
+        }
+        if value.get(path!("vector", "ingest_timestamp")).is_none() {
+            value.insert(path!("vector", "ingest_timestamp"), now);
+        }
+    }
+}
+
 #[derive(Clone)]
 struct Inner {
     inner: LimitedSender<SourceSenderItem>,
     output: String,
     lag_time: Option<Histogram>,
     events_sent: Registered<EventsSent>,
+    /// Host identifiers attached to all events sent through here, if enabled.
+    host_metadata: Option<Arc<HostMetadata>>,
+    /// The origin attached to the metadata of all events sent through here, if enabled.
+    origin: Option<Arc<Origin>>,
     /// The schema definition that will be attached to Log events sent through here
     log_definition: Option<Arc<Definition>>,
     /// The OutputId related to this source sender. This is set as the `upstream_id` in
@@ -395,6 +496,8 @@ impl Inner {
         n: usize,
         output: String,
         lag_time: Option<Histogram>,
+        host_metadata: Option<Arc<HostMetadata>>,
+        origin: Option<Arc<Origin>>,
         log_definition: Option<Arc<Definition>>,
         output_id: OutputId,
     ) -> (Self, LimitedReceiver<SourceSenderItem>) {
@@ -407,6 +510,8 @@ impl Inner {
                 events_sent: register!(EventsSent::from(internal_event::Output(Some(
                     output.into()
                 )))),
+                host_metadata,
+                origin,
                 log_definition,
                 output_id: Arc::new(output_id),
             },
@@ -416,7 +521,8 @@ impl Inner {
 
     async fn send(&mut self, mut events: EventArray) -> Result<(), ClosedError> {
         let send_reference = Instant::now();
-        let reference = Utc::now().timestamp_millis();
+        let now = Utc::now();
+        let reference = now.timestamp_millis();
         events
             .iter_events()
             .for_each(|event| self.emit_lag_time(event, reference));
@@ -429,6 +535,12 @@ impl Inner {
             event
                 .metadata_mut()
                 .set_upstream_id(Arc::clone(&self.output_id));
+            if let Some(origin) = &self.origin {
+                origin.attach(event.metadata_mut(), now);
+            }
+            if let Some(host_metadata) = &self.host_metadata {
+                host_metadata.enrich(event);
+            }
         });
 
         let byte_size = events.estimated_json_encoded_size_of();
@@ -537,8 +649,10 @@ mod tests {
     use chrono::{DateTime, Duration};
     use rand::{thread_rng, Rng};
     use tokio::time::timeout;
+    use vector_lib::config::{DataType, HostMetadataConfig};
     use vector_lib::event::{LogEvent, Metric, MetricKind, MetricValue, TraceEvent};
-    use vrl::event_path;
+    use vector_lib::lookup::lookup_v2::OptionalTargetPath;
+    use vrl::{event_path, metadata_path};
 
     use super::*;
     use crate::metrics::{self, Controller};
@@ -627,6 +741,106 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn attaches_host_metadata() {
+        let dir = tempfile::tempdir().unwrap();
+        let machine_id_path = dir.path().join("machine-id");
+        std::fs::write(&machine_id_path, "0123456789abcdef0123456789abcdef\n").unwrap();
+        let host_metadata = HostMetadataConfig {
+            enabled: true,
+            machine_id_path,
+            boot_id_key: OptionalTargetPath::none(),
+            ..Default::default()
+        }
+        .load()
+        .map(Arc::new);
+
+        let mut builder = SourceSender::builder()
+            .with_buffer(1)
+            .with_host_metadata(host_metadata);
+        let mut rx = builder.add_source_output(
+            SourceOutput::new_maybe_logs(DataType::Log, Definition::any()),
+            "test".to_string().into(),
+        );
+        let mut sender = builder.build();
+
+        sender
+            .send_event(Event::Log(LogEvent::from("message")))
+            .await
+            .expect("Send should not fail");
+
+        let item = rx.next().await.expect("Event should be received");
+        let event = item
+            .events
+            .into_events()
+            .next()
+            .expect("Event should be received");
+        assert_eq!(
+            event.as_log().get(metadata_path!("host", "machine_id")),
+            Some(&Value::from("0123456789abcdef0123456789abcdef"))
+        );
+        assert_eq!(event.as_log().get(metadata_path!("host", "boot_id")), None);
+    }
+
+    #[tokio::test]
+    async fn attaches_origin_metadata() {
+        let mut builder = SourceSender::builder()
+            .with_buffer(2)
+            .with_origin_metadata(Some("demo_logs"));
+        let mut rx = builder.add_source_output(
+            SourceOutput::new_maybe_logs(DataType::all_bits(), Definition::any()),
+            "in".to_string().into(),
+        );
+        let mut sender = builder.build();
+
+        let ingested = Utc::now() - Duration::seconds(10);
+        let mut log = LogEvent::from("message");
+        log.metadata_mut()
+            .value_mut()
+            .insert(path!("vector", "ingest_timestamp"), ingested);
+        let metric = Metric::new(
+            "name",
+            MetricKind::Absolute,
+            MetricValue::Gauge { value: 1.0 },
+        );
+        sender
+            .send_batch(vec![Event::Log(log), Event::Metric(metric)])
+            .await
+            .expect("Send should not fail");
+
+        // Logs and metrics are sent in separate arrays.
+        let mut events = Vec::new();
+        for _ in 0..2 {
+            let item = rx.next().await.expect("Events should be received");
+            events.extend(item.events.into_events());
+        }
+        for event in &events {
+            let metadata = event.metadata().value();
+            assert_eq!(
+                metadata.get(path!("vector", "source_id")),
+                Some(&Value::from("in"))
+            );
+            assert_eq!(
+                metadata.get(path!("vector", "source_type")),
+                Some(&Value::from("demo_logs"))
+            );
+        }
+        assert_eq!(
+            events[0]
+                .metadata()
+                .value()
+                .get(path!("vector", "ingest_timestamp")),
+            Some(&Value::from(ingested))
+        );
+        assert!(matches!(
+            events[1]
+                .metadata()
+                .value()
+                .get(path!("vector", "ingest_timestamp")),
+            Some(Value::Timestamp(timestamp)) if *timestamp > ingested
+        ));
+    }
+
     #[tokio::test]
     async fn emits_component_discarded_events_total_for_send_event() {
         metrics::init_test();
@@ -80,7 +80,9 @@ pub(super) async fn firehose(
                     let (batch, receiver) = context
                         .acknowledgements
                         .then(|| {
-                            let (batch, receiver) = BatchNotifier::new_with_receiver();
+                            let (batch, receiver) = BatchNotifier::new_tracked_with_receiver(
+                                context.out.unacknowledged_events(),
+                            );
                             (Some(batch), Some(receiver))
                         })
                         .unwrap_or((None, None));
@@ -525,7 +525,10 @@ impl IngestorProcess {
                 .expect("invalid timestamp")
         });
 
-        let (batch, receiver) = BatchNotifier::maybe_new_with_receiver(self.acknowledgements);
+        let (batch, receiver) = BatchNotifier::maybe_new_tracked_with_receiver(
+            self.acknowledgements,
+            self.out.unacknowledged_events(),
+        );
         let object_reader = super::s3_object_decoder(
             self.state.compression,
             &s3_event.s3.object.key,
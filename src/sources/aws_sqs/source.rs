@@ -136,8 +136,10 @@ impl SqsSource {
             let mut receipts_to_ack = Vec::with_capacity(messages.len());
             let mut events = Vec::with_capacity(messages.len());
 
-            let (batch, batch_receiver) =
-                BatchNotifier::maybe_new_with_receiver(finalizer.is_some());
+            let (batch, batch_receiver) = BatchNotifier::maybe_new_tracked_with_receiver(
+                finalizer.is_some(),
+                out.unacknowledged_events(),
+            );
             for message in messages {
                 if let Some(body) = message.body {
                     // a receipt handle should always exist
@@ -503,7 +503,11 @@ pub(crate) async fn handle_request(
 ) -> Result<Response, Rejection> {
     match events {
         Ok(mut events) => {
-            let receiver = BatchNotifier::maybe_apply_to(acknowledgements, &mut events);
+            let receiver = BatchNotifier::maybe_apply_tracked_to(
+                acknowledgements,
+                &mut events,
+                out.unacknowledged_events(),
+            );
             let count = events.len();
 
             if let Some(name) = output {
@@ -17,6 +17,7 @@ use tokio::{
 };
 use tokio_stream::wrappers::IntervalStream;
 use tokio_util::codec::FramedRead;
+use tracing::Instrument;
 use vector_lib::codecs::{
     decoding::{DeserializerConfig, FramingConfig},
     StreamDecodingError,
@@ -29,14 +30,17 @@ use vrl::value::Kind;
 
 use crate::{
     codecs::{Decoder, DecodingConfig},
+    common::exec::read_stderr_lines,
     config::{SourceConfig, SourceContext, SourceOutput},
-    event::Event,
+    event::{Event, LogEvent},
     internal_events::{
-        ExecChannelClosedError, ExecCommandExecuted, ExecEventsReceived, ExecFailedError,
-        ExecFailedToSignalChild, ExecFailedToSignalChildError, ExecTimeoutError, StreamClosedError,
+        ExecChannelClosedError, ExecCommandExecuted, ExecCommandRespawning, ExecEventsReceived,
+        ExecFailedError, ExecFailedToSignalChild, ExecFailedToSignalChildError,
+        ExecRespawnsExhausted, ExecStderrLine, ExecTimeoutError, StreamClosedError,
     },
     serde::default_decoding,
     shutdown::ShutdownSignal,
+    sinks::util::retries::JitterMode,
     SourceSender,
 };
 use vector_lib::config::{log_schema, LogNamespace};
@@ -78,9 +82,21 @@ pub struct ExecConfig {
     pub working_directory: Option<PathBuf>,
 
     /// Whether or not the output from stderr should be included when generating events.
+    ///
+    /// Ignored if `stderr` is set.
     #[serde(default = "default_include_stderr")]
     pub include_stderr: bool,
 
+    #[configurable(derived)]
+    pub stderr: Option<StderrMode>,
+
+    /// Whether or not an event is generated once the command exits.
+    ///
+    /// This event holds the exit code of the command, or the signal that terminated it, in the
+    /// `exit_code` and `signal` fields.
+    #[serde(default = "crate::serde::default_false")]
+    pub include_exit_event: bool,
+
     /// The maximum buffer size allowed before a log event is generated.
     #[serde(default = "default_maximum_buffer_size")]
     pub maximum_buffer_size_bytes: usize,
@@ -135,6 +151,76 @@ pub struct StreamingConfig {
     #[serde(default = "default_respawn_interval_secs")]
     #[configurable(metadata(docs::human_name = "Respawn Interval"))]
     respawn_interval_secs: u64,
+
+    /// The maximum amount of time, in seconds, before rerunning a streaming command that exited.
+    ///
+    /// If set, the amount of time before rerunning the command doubles each time it exits
+    /// unsuccessfully in a row, from `respawn_interval_secs` up to this value.
+    #[configurable(metadata(docs::human_name = "Maximum Respawn Interval"))]
+    #[configurable(metadata(docs::examples = 300))]
+    max_respawn_interval_secs: Option<u64>,
+
+    /// The maximum number of times a streaming command is rerun after exiting unsuccessfully in a
+    /// row, after which the source stops.
+    ///
+    /// The command is rerun indefinitely if not set.
+    #[configurable(metadata(docs::examples = 10))]
+    max_respawns: Option<u32>,
+
+    /// The jitter mode to use for the amount of time before rerunning a streaming command.
+    #[serde(default = "default_respawn_jitter_mode")]
+    respawn_jitter_mode: JitterMode,
+}
+
+impl Default for StreamingConfig {
+    fn default() -> Self {
+        Self {
+            respawn_on_exit: default_respawn_on_exit(),
+            respawn_interval_secs: default_respawn_interval_secs(),
+            max_respawn_interval_secs: None,
+            max_respawns: None,
+            respawn_jitter_mode: default_respawn_jitter_mode(),
+        }
+    }
+}
+
+impl StreamingConfig {
+    /// Returns the amount of time to wait before rerunning a command that exited unsuccessfully
+    /// `failures` times in a row.
+    fn respawn_delay(&self, failures: u32) -> Duration {
+        let interval = Duration::from_secs(self.respawn_interval_secs);
+        let delay = match self.max_respawn_interval_secs {
+            Some(max) => interval
+                .saturating_mul(2u32.saturating_pow(failures))
+                .min(Duration::from_secs(max).max(interval)),
+            None => interval,
+        };
+
+        match self.respawn_jitter_mode {
+            JitterMode::None => delay,
+            JitterMode::Full if delay.is_zero() => delay,
+            JitterMode::Full => {
+                let jitter = (rand::random::<u64>() % (delay.as_millis() as u64).max(1)) + 1;
+                Duration::from_millis(jitter)
+            }
+        }
+    }
+}
+
+/// How the output from stderr is handled.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum StderrMode {
+    /// The output from stderr is discarded.
+    Drop,
+
+    /// The output from stderr is decoded into events, like the output from stdout, with their
+    /// `stream` field set to `stderr`.
+    Events,
+
+    /// Each line of the output from stderr is logged by Vector.
+    Log,
 }
 
 #[derive(Debug, PartialEq, Eq, Snafu)]
@@ -158,6 +244,8 @@ impl Default for ExecConfig {
             clear_environment: default_clear_environment(),
             working_directory: None,
             include_stderr: default_include_stderr(),
+            stderr: None,
+            include_exit_event: false,
             maximum_buffer_size_bytes: default_maximum_buffer_size(),
             framing: None,
             decoding: default_decoding(),
@@ -183,6 +271,10 @@ const fn default_respawn_on_exit() -> bool {
     true
 }
 
+const fn default_respawn_jitter_mode() -> JitterMode {
+    JitterMode::None
+}
+
 const fn default_clear_environment() -> bool {
     false
 }
@@ -208,6 +300,12 @@ const STDERR: &str = "stderr";
 const STREAM_KEY: &str = "stream";
 const PID_KEY: &str = "pid";
 const COMMAND_KEY: &str = "command";
+const EXIT_CODE_KEY: &str = "exit_code";
+const SIGNAL_KEY: &str = "signal";
+
+/// The amount of time a command is given to exit once it closed its output, for its exit status
+/// to be known.
+const EXIT_STATUS_TIMEOUT: Duration = Duration::from_secs(1);
 
 impl_generate_config_from_default!(ExecConfig);
 
@@ -233,17 +331,11 @@ impl ExecConfig {
         }
     }
 
-    const fn respawn_on_exit_or_default(&self) -> bool {
-        match &self.streaming {
-            None => default_respawn_on_exit(),
-            Some(config) => config.respawn_on_exit,
-        }
-    }
-
-    const fn respawn_interval_secs_or_default(&self) -> u64 {
-        match &self.streaming {
-            None => default_respawn_interval_secs(),
-            Some(config) => config.respawn_interval_secs,
+    const fn stderr_mode(&self) -> StderrMode {
+        match self.stderr {
+            Some(mode) => mode,
+            None if self.include_stderr => StderrMode::Events,
+            None => StderrMode::Drop,
         }
     }
 }
@@ -277,14 +369,12 @@ impl SourceConfig for ExecConfig {
                 )))
             }
             Mode::Streaming => {
-                let respawn_on_exit = self.respawn_on_exit_or_default();
-                let respawn_interval_secs = self.respawn_interval_secs_or_default();
+                let streaming = self.streaming.clone().unwrap_or_default();
 
                 Ok(Box::pin(run_streaming(
                     self.clone(),
                     hostname,
-                    respawn_on_exit,
-                    respawn_interval_secs,
+                    streaming,
                     decoder,
                     cx.shutdown,
                     cx.out,
@@ -297,7 +387,7 @@ impl SourceConfig for ExecConfig {
     fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<SourceOutput> {
         let log_namespace = global_log_namespace.merge(Some(self.log_namespace.unwrap_or(false)));
 
-        let schema_definition = self
+        let mut schema_definition = self
             .decoding
             .schema_definition(log_namespace)
             .with_standard_vector_source_metadata()
@@ -334,6 +424,24 @@ impl SourceConfig for ExecConfig {
                 None,
             );
 
+        if self.include_exit_event {
+            schema_definition = schema_definition
+                .with_source_metadata(
+                    Self::NAME,
+                    Some(LegacyKey::InsertIfEmpty(owned_value_path!(EXIT_CODE_KEY))),
+                    &owned_value_path!(EXIT_CODE_KEY),
+                    Kind::integer().or_undefined(),
+                    None,
+                )
+                .with_source_metadata(
+                    Self::NAME,
+                    Some(LegacyKey::InsertIfEmpty(owned_value_path!(SIGNAL_KEY))),
+                    &owned_value_path!(SIGNAL_KEY),
+                    Kind::bytes().or_undefined(),
+                    None,
+                );
+        }
+
         vec![SourceOutput::new_maybe_logs(
             self.decoding.output_type(),
             schema_definition,
@@ -397,19 +505,18 @@ async fn run_scheduled(
     Ok(())
 }
 
-#[allow(clippy::too_many_arguments)]
 async fn run_streaming(
     config: ExecConfig,
     hostname: Option<String>,
-    respawn_on_exit: bool,
-    respawn_interval_secs: u64,
+    streaming: StreamingConfig,
     decoder: Decoder,
     mut shutdown: ShutdownSignal,
     out: SourceSender,
     log_namespace: LogNamespace,
 ) -> Result<(), ()> {
-    if respawn_on_exit {
-        let duration = Duration::from_secs(respawn_interval_secs);
+    if streaming.respawn_on_exit {
+        // The number of times in a row the command exited unsuccessfully.
+        let mut failures = 0;
 
         // Continue to loop while not shutdown
         loop {
@@ -424,16 +531,36 @@ async fn run_streaming(
             .await;
 
             // handle command finished
-            if let Err(command_error) = output {
-                emit!(ExecFailedError {
-                    command: config.command_line().as_str(),
-                    error: command_error,
-                });
+            match output {
+                Ok(Some(exit_status)) if exit_status.success() => failures = 0,
+                Ok(_) => failures += 1,
+                Err(command_error) => {
+                    emit!(ExecFailedError {
+                        command: config.command_line().as_str(),
+                        error: command_error,
+                    });
+                    failures += 1;
+                }
+            }
+
+            if let Some(max_respawns) = streaming.max_respawns {
+                if failures > max_respawns {
+                    emit!(ExecRespawnsExhausted {
+                        command: config.command_line().as_str(),
+                        max_respawns,
+                    });
+                    break;
+                }
             }
 
+            let delay = streaming.respawn_delay(failures.saturating_sub(1));
             tokio::select! {
                 _ = &mut shutdown => break, // will break early if a shutdown is started
-                _ = sleep(duration) => debug!("Restarting streaming process."),
+                _ = sleep(delay) => emit!(ExecCommandRespawning {
+                    command: config.command_line().as_str(),
+                    failures,
+                    delay,
+                }),
             }
         }
     } else {
@@ -479,7 +606,8 @@ async fn run_command(
     let (sender, mut receiver) = channel(1024);
 
     // Optionally include stderr
-    if config.include_stderr {
+    let stderr_mode = config.stderr_mode();
+    if stderr_mode != StderrMode::Drop {
         let stderr = child.stderr.take().ok_or_else(|| {
             Error::new(ErrorKind::Other, "Unable to take stderr of spawned process")
         })?;
@@ -487,7 +615,15 @@ async fn run_command(
         // Create stderr async reader
         let stderr_reader = BufReader::new(stderr);
 
-        spawn_reader_thread(stderr_reader, decoder.clone(), STDERR, sender.clone());
+        if stderr_mode == StderrMode::Events {
+            spawn_reader_thread(stderr_reader, decoder.clone(), STDERR, sender.clone());
+        } else {
+            spawn_stderr_logger(
+                stderr_reader,
+                config.command_line(),
+                config.maximum_buffer_size_bytes,
+            );
+        }
     }
 
     let stdout = child
@@ -537,38 +673,102 @@ async fn run_command(
         }
     }
 
+    let exit_status = match time::timeout(EXIT_STATUS_TIMEOUT, child.wait()).await {
+        Ok(Ok(exit_status)) => Some(exit_status),
+        Ok(Err(error)) => {
+            error!(message = "Unable to obtain exit status.", %error);
+            None
+        }
+        Err(_) => None,
+    };
     let elapsed = start.elapsed();
 
-    let result = match child.try_wait() {
-        Ok(Some(exit_status)) => {
-            handle_exit_status(&config, exit_status.code(), elapsed);
-            Ok(Some(exit_status))
-        }
-        Ok(None) => {
-            handle_exit_status(&config, None, elapsed);
-            Ok(None)
-        }
-        Err(error) => {
-            error!(message = "Unable to obtain exit status.", %error);
+    handle_exit_status(&config, exit_status, elapsed);
 
-            handle_exit_status(&config, None, elapsed);
-            Ok(None)
+    if config.include_exit_event {
+        let mut event = Event::from(exit_event(exit_status, log_namespace));
+        handle_event(&config, &hostname, &None, pid, &mut event, log_namespace);
+
+        emit!(ExecEventsReceived {
+            count: 1,
+            command: config.command_line().as_str(),
+            byte_size: event.estimated_json_encoded_size_of(),
+        });
+        if out.send_event(event).await.is_err() {
+            emit!(StreamClosedError { count: 1 });
         }
-    };
+    }
 
     debug!("Finished command run.");
 
-    result
+    Ok(exit_status)
 }
 
-fn handle_exit_status(config: &ExecConfig, exit_status: Option<i32>, exec_duration: Duration) {
+fn handle_exit_status(
+    config: &ExecConfig,
+    exit_status: Option<ExitStatus>,
+    exec_duration: Duration,
+) {
     emit!(ExecCommandExecuted {
         command: config.command_line().as_str(),
-        exit_status,
+        exit_status: exit_status.and_then(|exit_status| exit_status.code()),
+        signal: exit_status.and_then(terminating_signal),
         exec_duration,
     });
 }
 
+/// Builds the event generated once the command exits, holding its exit code or the signal that
+/// terminated it.
+fn exit_event(exit_status: Option<ExitStatus>, log_namespace: LogNamespace) -> LogEvent {
+    let exit_code = exit_status.and_then(|exit_status| exit_status.code());
+    let signal = exit_status.and_then(terminating_signal);
+
+    let message = match (exit_code, &signal) {
+        (Some(exit_code), _) => format!("Command exited with exit code {exit_code}."),
+        (None, Some(signal)) => format!("Command was terminated by signal {signal}."),
+        (None, None) => "Command exited with an unknown exit status.".to_owned(),
+    };
+    let mut log = log_namespace.new_log_from_data(message);
+
+    if let Some(exit_code) = exit_code {
+        log_namespace.insert_source_metadata(
+            ExecConfig::NAME,
+            &mut log,
+            Some(LegacyKey::InsertIfEmpty(path!(EXIT_CODE_KEY))),
+            path!(EXIT_CODE_KEY),
+            i64::from(exit_code),
+        );
+    }
+    if let Some(signal) = signal {
+        log_namespace.insert_source_metadata(
+            ExecConfig::NAME,
+            &mut log,
+            Some(LegacyKey::InsertIfEmpty(path!(SIGNAL_KEY))),
+            path!(SIGNAL_KEY),
+            signal,
+        );
+    }
+
+    log
+}
+
+#[cfg(unix)]
+fn terminating_signal(exit_status: ExitStatus) -> Option<String> {
+    use std::os::unix::process::ExitStatusExt;
+
+    exit_status
+        .signal()
+        .map(|signal| match nix::sys::signal::Signal::try_from(signal) {
+            Ok(signal) => signal.as_str().to_owned(),
+            Err(_) => signal.to_string(),
+        })
+}
+
+#[cfg(windows)]
+const fn terminating_signal(_exit_status: ExitStatus) -> Option<String> {
+    None
+}
+
 #[cfg(unix)]
 async fn shutdown_child(
     child: &mut tokio::process::Child,
@@ -655,7 +855,7 @@ fn build_command(config: &ExecConfig) -> Command {
     command.stdout(std::process::Stdio::piped());
 
     // Pipe stderr to the process if needed
-    if config.include_stderr {
+    if config.stderr_mode() != StderrMode::Drop {
         command.stderr(std::process::Stdio::piped());
     } else {
         command.stderr(std::process::Stdio::null());
@@ -756,3 +956,22 @@ fn spawn_reader_thread<R: 'static + AsyncRead + Unpin + std::marker::Send>(
         debug!("Finished capturing {} command output.", origin);
     }));
 }
+
+fn spawn_stderr_logger<R: 'static + AsyncRead + Unpin + std::marker::Send>(
+    reader: BufReader<R>,
+    command: String,
+    max_line_bytes: usize,
+) {
+    drop(tokio::spawn(
+        async move {
+            read_stderr_lines(reader, max_line_bytes, |line| {
+                emit!(ExecStderrLine {
+                    command: &command,
+                    line,
+                });
+            })
+            .await;
+        }
+        .in_current_span(),
+    ));
+}
@@ -171,15 +171,14 @@ fn test_build_command() {
     let config = ExecConfig {
         mode: Mode::Streaming,
         scheduled: None,
-        streaming: Some(StreamingConfig {
-            respawn_on_exit: default_respawn_on_exit(),
-            respawn_interval_secs: default_respawn_interval_secs(),
-        }),
+        streaming: Some(StreamingConfig::default()),
         command: vec!["./runner".to_owned(), "arg1".to_owned(), "arg2".to_owned()],
         environment: None,
         clear_environment: default_clear_environment(),
         working_directory: Some(PathBuf::from("/tmp")),
         include_stderr: default_include_stderr(),
+        stderr: None,
+        include_exit_event: false,
         maximum_buffer_size_bytes: default_maximum_buffer_size(),
         framing: None,
         decoding: default_decoding(),
@@ -205,15 +204,14 @@ fn test_build_command_custom_environment() {
     let config = ExecConfig {
         mode: Mode::Streaming,
         scheduled: None,
-        streaming: Some(StreamingConfig {
-            respawn_on_exit: default_respawn_on_exit(),
-            respawn_interval_secs: default_respawn_interval_secs(),
-        }),
+        streaming: Some(StreamingConfig::default()),
         command: vec!["./runner".to_owned(), "arg1".to_owned(), "arg2".to_owned()],
         environment: Some(HashMap::from([("FOO".to_owned(), "foo".to_owned())])),
         clear_environment: default_clear_environment(),
         working_directory: Some(PathBuf::from("/tmp")),
         include_stderr: default_include_stderr(),
+        stderr: None,
+        include_exit_event: false,
         maximum_buffer_size_bytes: default_maximum_buffer_size(),
         framing: None,
         decoding: default_decoding(),
@@ -235,15 +233,14 @@ fn test_build_command_clear_environment() {
     let config = ExecConfig {
         mode: Mode::Streaming,
         scheduled: None,
-        streaming: Some(StreamingConfig {
-            respawn_on_exit: default_respawn_on_exit(),
-            respawn_interval_secs: default_respawn_interval_secs(),
-        }),
+        streaming: Some(StreamingConfig::default()),
         command: vec!["./runner".to_owned(), "arg1".to_owned(), "arg2".to_owned()],
         environment: Some(HashMap::from([("FOO".to_owned(), "foo".to_owned())])),
         clear_environment: true,
         working_directory: Some(PathBuf::from("/tmp")),
         include_stderr: default_include_stderr(),
+        stderr: None,
+        include_exit_event: false,
         maximum_buffer_size_bytes: default_maximum_buffer_size(),
         framing: None,
         decoding: default_decoding(),
@@ -435,6 +432,215 @@ async fn test_graceful_shutdown() {
     }
 }
 
+#[tokio::test]
+#[cfg(unix)]
+async fn test_exit_event_exit_code() {
+    let mut config = standard_scheduled_test_config();
+    config.command = shell_command("echo out; exit 3");
+    config.include_exit_event = true;
+
+    let (exit_status, events) = run_test_command(&config).await;
+    assert_eq!(exit_status.unwrap().code(), Some(3));
+    assert_eq!(events.len(), 2);
+    assert_eq!(*events[0].as_log().get_message().unwrap(), "out".into());
+
+    let log = events[1].as_log();
+    assert_eq!(
+        *log.get_message().unwrap(),
+        "Command exited with exit code 3.".into()
+    );
+    assert_eq!(log[EXIT_CODE_KEY], 3_i64.into());
+    assert!(log.get(SIGNAL_KEY).is_none());
+    assert!(log.get(STREAM_KEY).is_none());
+    assert_eq!(log[COMMAND_KEY], config.command.into());
+}
+
+#[tokio::test]
+#[cfg(unix)]
+async fn test_exit_event_signal() {
+    let mut config = standard_scheduled_test_config();
+    config.command = shell_command("kill -KILL $$");
+    config.include_exit_event = true;
+
+    let (exit_status, events) = run_test_command(&config).await;
+    assert_eq!(exit_status.unwrap().code(), None);
+    assert_eq!(events.len(), 1);
+
+    let log = events[0].as_log();
+    assert_eq!(
+        *log.get_message().unwrap(),
+        "Command was terminated by signal SIGKILL.".into()
+    );
+    assert_eq!(log[SIGNAL_KEY], "SIGKILL".into());
+    assert!(log.get(EXIT_CODE_KEY).is_none());
+}
+
+#[tokio::test]
+#[cfg(unix)]
+async fn test_exit_event_vector_namespace() {
+    let mut config = standard_scheduled_test_config();
+    config.command = shell_command("exit 1");
+    config.include_exit_event = true;
+
+    let (tx, rx) = SourceSender::new_test();
+    run_command(
+        config,
+        None,
+        Default::default(),
+        ShutdownSignal::noop(),
+        tx,
+        LogNamespace::Vector,
+    )
+    .await
+    .unwrap();
+
+    let events = rx.collect::<Vec<_>>().await;
+    assert_eq!(events.len(), 1);
+    let log = events[0].as_log();
+    assert_eq!(log.value(), &value!("Command exited with exit code 1."));
+    assert_eq!(
+        log.metadata()
+            .value()
+            .get(path!(ExecConfig::NAME, EXIT_CODE_KEY)),
+        Some(&value!(1))
+    );
+}
+
+#[tokio::test]
+#[cfg(unix)]
+async fn test_stderr_modes() {
+    for mode in [StderrMode::Drop, StderrMode::Log] {
+        let mut config = standard_scheduled_test_config();
+        config.command = shell_command("echo err >&2; echo out");
+        config.stderr = Some(mode);
+
+        let (_, events) = run_test_command(&config).await;
+        assert_eq!(events.len(), 1, "{mode:?}");
+        assert_eq!(*events[0].as_log().get_message().unwrap(), "out".into());
+    }
+
+    let mut config = standard_scheduled_test_config();
+    config.command = shell_command("echo err >&2");
+    config.stderr = Some(StderrMode::Events);
+
+    let (_, events) = run_test_command(&config).await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(*events[0].as_log().get_message().unwrap(), "err".into());
+    assert_eq!(events[0].as_log()[STREAM_KEY], STDERR.into());
+}
+
+#[tokio::test]
+async fn test_read_stderr_lines() {
+    let input = b"first\ninvalid \xff utf-8\r\nlong line\nlast".as_slice();
+    let mut lines = Vec::new();
+    read_stderr_lines(input, 6, |line| lines.push(line.to_string())).await;
+
+    assert_eq!(
+        lines,
+        [
+            "first",
+            "invali",
+            "d \u{fffd} ut",
+            "f-8",
+            "long l",
+            "ine",
+            "last"
+        ]
+    );
+}
+
+#[tokio::test]
+#[cfg(unix)]
+async fn test_streaming_max_respawns() {
+    let mut config = standard_streaming_test_config();
+    config.command = shell_command("echo run; exit 1");
+    let streaming: StreamingConfig = toml::from_str(
+        r#"
+        respawn_interval_secs = 0
+        max_respawns = 2
+        "#,
+    )
+    .unwrap();
+
+    let (tx, rx) = SourceSender::new_test();
+    tokio::time::timeout(
+        time::Duration::from_secs(10),
+        run_streaming(
+            config,
+            None,
+            streaming,
+            Default::default(),
+            ShutdownSignal::noop(),
+            tx,
+            LogNamespace::Legacy,
+        ),
+    )
+    .await
+    .expect("source did not stop")
+    .unwrap();
+
+    // The command is run once, then rerun twice.
+    assert_eq!(rx.collect::<Vec<_>>().await.len(), 3);
+}
+
+#[test]
+fn test_respawn_delay() {
+    let streaming: StreamingConfig = toml::from_str(
+        r#"
+        respawn_interval_secs = 2
+        max_respawn_interval_secs = 10
+        "#,
+    )
+    .unwrap();
+    let delays = (0..5)
+        .map(|failures| streaming.respawn_delay(failures).as_secs())
+        .collect::<Vec<_>>();
+    assert_eq!(delays, [2, 4, 8, 10, 10]);
+    assert_eq!(streaming.respawn_delay(u32::MAX), Duration::from_secs(10));
+
+    // The delay is fixed unless a maximum is set.
+    let streaming: StreamingConfig = toml::from_str("respawn_interval_secs = 2").unwrap();
+    assert_eq!(streaming.respawn_delay(3), Duration::from_secs(2));
+
+    let streaming: StreamingConfig = toml::from_str(
+        r#"
+        respawn_interval_secs = 2
+        respawn_jitter_mode = "Full"
+        "#,
+    )
+    .unwrap();
+    for _ in 0..100 {
+        let delay = streaming.respawn_delay(0);
+        assert!(!delay.is_zero() && delay <= Duration::from_secs(2));
+    }
+}
+
+#[cfg(unix)]
+fn shell_command(script: &str) -> Vec<String> {
+    vec!["sh".to_owned(), "-c".to_owned(), script.to_owned()]
+}
+
+#[cfg(unix)]
+async fn run_test_command(config: &ExecConfig) -> (Option<ExitStatus>, Vec<Event>) {
+    let (tx, rx) = SourceSender::new_test();
+    let exit_status = tokio::time::timeout(
+        time::Duration::from_secs(5),
+        run_command(
+            config.clone(),
+            None,
+            Default::default(),
+            ShutdownSignal::noop(),
+            tx,
+            LogNamespace::Legacy,
+        ),
+    )
+    .await
+    .expect("command timed out")
+    .expect("command error");
+
+    (exit_status, rx.collect().await)
+}
+
 fn standard_scheduled_test_config() -> ExecConfig {
     Default::default()
 }
@@ -443,15 +649,14 @@ fn standard_streaming_test_config() -> ExecConfig {
     ExecConfig {
         mode: Mode::Streaming,
         scheduled: None,
-        streaming: Some(StreamingConfig {
-            respawn_on_exit: default_respawn_on_exit(),
-            respawn_interval_secs: default_respawn_interval_secs(),
-        }),
+        streaming: Some(StreamingConfig::default()),
         command: vec!["yes".to_owned()],
         environment: None,
         clear_environment: default_clear_environment(),
         working_directory: None,
         include_stderr: default_include_stderr(),
+        stderr: None,
+        include_exit_event: false,
         maximum_buffer_size_bytes: default_maximum_buffer_size(),
         framing: None,
         decoding: default_decoding(),
@@ -659,6 +659,7 @@ pub fn file_source(
         // Once file server ends this will run until it has finished processing remaining
         // logs in the queue.
         let span = Span::current();
+        let unacknowledged_events = out.unacknowledged_events().clone();
         let mut messages = messages.map(move |line| {
             let mut event = create_event(
                 line.text,
@@ -670,7 +671,8 @@ pub fn file_source(
             );
 
             if let Some(finalizer) = &finalizer {
-                let (batch, receiver) = BatchNotifier::new_with_receiver();
+                let (batch, receiver) =
+                    BatchNotifier::new_tracked_with_receiver(&unacknowledged_events);
                 event = event.with_batch_notifier(&batch);
                 let entry = FinalizerEntry {
                     file_id: line.file_id,
@@ -1772,6 +1774,46 @@ mod tests {
         }
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn file_reads_named_pipes() {
+        let dir = tempdir().unwrap();
+        let config = file::FileConfig {
+            include: vec![dir.path().join("*")],
+            ..test_default_file_config(&dir)
+        };
+
+        let path = dir.path().join("fifo");
+        nix::unistd::mkfifo(&path, nix::sys::stat::Mode::S_IRWXU).unwrap();
+
+        let received = run_file_source(&config, false, NoAcks, LogNamespace::Legacy, async {
+            for session in 0..3 {
+                // Opening the pipe for writing waits for the source to open it for reading.
+                let path = path.clone();
+                tokio::task::spawn_blocking(move || {
+                    let mut fifo = fs::OpenOptions::new().write(true).open(path).unwrap();
+                    writeln!(&mut fifo, "session {session} line 0").unwrap();
+                    write!(&mut fifo, "session {session} line 1").unwrap();
+                })
+                .await
+                .unwrap();
+
+                sleep_500_millis().await;
+            }
+
+            sleep_500_millis().await;
+        })
+        .await;
+
+        let lines = extract_messages_string(received);
+        assert_eq!(
+            lines,
+            (0..3)
+                .flat_map(|session| (0..2).map(move |line| format!("session {session} line {line}")))
+                .collect::<Vec<_>>()
+        );
+    }
+
     #[cfg(unix)] // this test uses unix-specific function `futimes` during test time
     #[tokio::test]
     async fn file_start_position_ignore_old_files() {
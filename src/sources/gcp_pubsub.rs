@@ -606,7 +606,10 @@ impl PubsubSource {
         }
         self.bytes_received.emit(ByteSize(response.size_of()));
 
-        let (batch, notifier) = BatchNotifier::maybe_new_with_receiver(self.acknowledgements);
+        let (batch, notifier) = BatchNotifier::maybe_new_tracked_with_receiver(
+            self.acknowledgements,
+            self.out.unacknowledged_events(),
+        );
         let (events, ids) = self.parse_messages(response.received_messages, batch).await;
 
         let count = events.len();
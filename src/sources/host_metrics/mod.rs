@@ -34,6 +34,10 @@ mod disk;
 mod filesystem;
 mod memory;
 mod network;
+#[cfg(target_os = "linux")]
+mod pressure;
+#[cfg(target_os = "linux")]
+mod systemd;
 
 /// Collector types.
 #[serde_as]
@@ -66,6 +70,18 @@ pub enum Collector {
 
     /// Metrics related to network utilization.
     Network,
+
+    /// Metrics related to Linux Pressure Stall Information (PSI).
+    ///
+    /// Only available on Linux 4.20 or later with PSI enabled. This collector is not enabled by
+    /// default.
+    Pressure,
+
+    /// Metrics related to systemd units.
+    ///
+    /// Only available on Linux systems running systemd. This collector is not enabled by default,
+    /// as it queries the system manager through `systemctl` on every scrape.
+    Systemd,
 }
 
 /// Filtering configuration.
@@ -125,6 +141,10 @@ pub struct HostMetricsConfig {
     #[configurable(derived)]
     #[serde(default)]
     pub network: network::NetworkConfig,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub systemd: SystemdConfig,
 }
 
 /// Options for the cgroups (controller groups) metrics collector.
@@ -162,6 +182,36 @@ pub struct CGroupsConfig {
     base_dir: Option<PathBuf>,
 }
 
+/// Options for the systemd units metrics collector.
+///
+/// This collector is only available on Linux systems running systemd.
+#[configurable_component]
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+#[serde(default)]
+pub struct SystemdConfig {
+    /// Lists of unit name patterns to include or exclude in gathering
+    /// unit metrics.
+    #[configurable(metadata(docs::examples = "example_units()"))]
+    #[derivative(Default(value = "default_all_devices()"))]
+    #[serde(default = "default_all_devices")]
+    units: FilterList,
+
+    /// The maximum number of units for which to report metrics.
+    ///
+    /// Units are sorted by name after filtering, and units beyond this limit are dropped with a
+    /// warning. This bounds the cardinality of the emitted metrics on hosts with many units.
+    #[derivative(Default(value = "default_max_units()"))]
+    #[serde(default = "default_max_units")]
+    #[configurable(metadata(docs::examples = 100))]
+    max_units: usize,
+
+    /// Path to the `systemctl` binary, for testing use only
+    #[serde(skip_serializing)]
+    #[configurable(metadata(docs::hidden))]
+    systemctl_path: Option<PathBuf>,
+}
+
 const fn default_scrape_interval() -> Duration {
     Duration::from_secs(15)
 }
@@ -170,7 +220,7 @@ pub fn default_namespace() -> Option<String> {
     Some(String::from("host"))
 }
 
-const fn example_collectors() -> [&'static str; 8] {
+const fn example_collectors() -> [&'static str; 10] {
     [
         "cgroups",
         "cpu",
@@ -180,6 +230,8 @@ const fn example_collectors() -> [&'static str; 8] {
         "host",
         "memory",
         "network",
+        "pressure",
+        "systemd",
     ]
 }
 
@@ -224,6 +276,17 @@ const fn default_levels() -> usize {
     100
 }
 
+const fn default_max_units() -> usize {
+    500
+}
+
+fn example_units() -> FilterList {
+    FilterList {
+        includes: Some(vec!["*.service".try_into().unwrap()]),
+        excludes: Some(vec!["user@*.service".try_into().unwrap()]),
+    }
+}
+
 fn example_cgroups() -> FilterList {
     FilterList {
         includes: Some(vec!["user.slice/*".try_into().unwrap()]),
@@ -261,6 +324,12 @@ impl SourceConfig for HostMetricsConfig {
             if self.cgroups.is_some() || self.has_collector(Collector::CGroups) {
                 return Err("CGroups collector is only available on Linux systems".into());
             }
+            if self.has_collector(Collector::Pressure) {
+                return Err("Pressure collector is only available on Linux systems".into());
+            }
+            if self.has_collector(Collector::Systemd) {
+                return Err("Systemd collector is only available on Linux systems".into());
+            }
         }
 
         let mut config = self.clone();
@@ -317,6 +386,10 @@ pub struct HostMetrics {
     config: HostMetricsConfig,
     #[cfg(target_os = "linux")]
     root_cgroup: Option<cgroups::CGroupRoot>,
+    #[cfg(target_os = "linux")]
+    pressure: pressure::PressureState,
+    #[cfg(target_os = "linux")]
+    systemd_availability: systemd::SystemdAvailability,
     events_received: Registered<EventsReceived>,
 }
 
@@ -336,6 +409,8 @@ impl HostMetrics {
         Self {
             config,
             root_cgroup,
+            pressure: pressure::PressureState::new(),
+            systemd_availability: Default::default(),
             events_received: register!(EventsReceived),
         }
     }
@@ -373,6 +448,14 @@ impl HostMetrics {
         if self.config.has_collector(Collector::Network) {
             self.network_metrics(&mut buffer).await;
         }
+        #[cfg(target_os = "linux")]
+        if self.config.has_collector(Collector::Pressure) {
+            self.pressure_metrics(&mut buffer).await;
+        }
+        #[cfg(target_os = "linux")]
+        if self.config.has_collector(Collector::Systemd) {
+            self.systemd_metrics(&mut buffer).await;
+        }
 
         let metrics = buffer.metrics;
         self.events_received.emit(CountByteSize(
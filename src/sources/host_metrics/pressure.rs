@@ -0,0 +1,281 @@
+use std::{
+    io,
+    path::PathBuf,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use vector_lib::metric_tags;
+
+use super::{HostMetrics, MetricsBuffer};
+use crate::internal_events::HostMetricsScrapeDetailError;
+
+/// The resources for which the kernel reports pressure stall information.
+const RESOURCES: [&str; 3] = ["cpu", "memory", "io"];
+
+/// State of the Pressure Stall Information (PSI) collector.
+///
+/// Kernels older than 4.20, or built or booted without PSI support, either lack the
+/// `/proc/pressure` files entirely or refuse to read them. In that case the collector is
+/// disabled for the lifetime of the source with a single warning.
+#[derive(Debug)]
+pub(super) struct PressureState {
+    root: PathBuf,
+    disabled: AtomicBool,
+}
+
+impl PressureState {
+    pub(super) fn new() -> Self {
+        let procfs_root = std::env::var_os("PROCFS_ROOT").unwrap_or_else(|| "/proc".into());
+        Self::with_root(PathBuf::from(procfs_root).join("pressure"))
+    }
+
+    fn with_root(root: PathBuf) -> Self {
+        Self {
+            root,
+            disabled: AtomicBool::new(false),
+        }
+    }
+}
+
+impl HostMetrics {
+    pub(super) async fn pressure_metrics(&self, output: &mut MetricsBuffer) {
+        let state = &self.pressure;
+        if state.disabled.load(Ordering::Relaxed) {
+            return;
+        }
+
+        output.name = "pressure";
+        let mut unsupported = 0;
+        for resource in RESOURCES {
+            let contents = match tokio::fs::read_to_string(state.root.join(resource)).await {
+                Ok(contents) => contents,
+                Err(error) if is_unsupported(&error) => {
+                    unsupported += 1;
+                    continue;
+                }
+                Err(error) => {
+                    emit!(HostMetricsScrapeDetailError {
+                        message: "Failed to load pressure stall info.",
+                        error,
+                    });
+                    continue;
+                }
+            };
+
+            for line in parse_pressure(&contents) {
+                let tags = metric_tags!(
+                    "resource" => resource,
+                    "type" => line.kind,
+                );
+                output.gauge("pressure_avg10", line.avg10, tags.clone());
+                output.gauge("pressure_avg60", line.avg60, tags.clone());
+                output.gauge("pressure_avg300", line.avg300, tags.clone());
+                output.counter("pressure_stall_microseconds_total", line.total as f64, tags);
+            }
+        }
+
+        if unsupported == RESOURCES.len() && !state.disabled.swap(true, Ordering::Relaxed) {
+            warn!(
+                message = "Pressure stall information is not available on this kernel, disabling the pressure collector.",
+                path = ?state.root,
+            );
+        }
+    }
+}
+
+/// Reading a pressure file fails with `EOPNOTSUPP` when the kernel was booted with `psi=0`.
+fn is_unsupported(error: &io::Error) -> bool {
+    error.kind() == io::ErrorKind::NotFound || error.raw_os_error() == Some(libc::EOPNOTSUPP)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct PressureLine<'a> {
+    kind: &'a str,
+    avg10: f64,
+    avg60: f64,
+    avg300: f64,
+    total: u64,
+}
+
+/// Parses the contents of a `/proc/pressure/<resource>` file, which contains one line per
+/// variant in the form `some avg10=0.00 avg60=0.00 avg300=0.00 total=0`.
+///
+/// The `full` line is missing for `cpu` on kernels older than 5.13, and lines that cannot be
+/// parsed are skipped.
+fn parse_pressure(contents: &str) -> Vec<PressureLine<'_>> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let kind = fields
+                .next()
+                .filter(|kind| matches!(*kind, "some" | "full"))?;
+            let mut pressure = PressureLine {
+                kind,
+                avg10: 0.0,
+                avg60: 0.0,
+                avg300: 0.0,
+                total: 0,
+            };
+            let mut seen = 0;
+            for field in fields {
+                match field.split_once('=')? {
+                    ("avg10", value) => pressure.avg10 = value.parse().ok()?,
+                    ("avg60", value) => pressure.avg60 = value.parse().ok()?,
+                    ("avg300", value) => pressure.avg300 = value.parse().ok()?,
+                    ("total", value) => pressure.total = value.parse().ok()?,
+                    _ => continue,
+                }
+                seen += 1;
+            }
+            (seen == 4).then_some(pressure)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use similar_asserts::assert_eq;
+    use tempfile::TempDir;
+
+    use super::{
+        super::{
+            tests::{all_counters, all_gauges, count_name, count_tag},
+            HostMetrics, HostMetricsConfig,
+        },
+        parse_pressure, MetricsBuffer, PressureLine, PressureState,
+    };
+
+    const CPU: &str = "\
+some avg10=1.53 avg60=0.87 avg300=0.29 total=86507262
+full avg10=0.00 avg60=0.00 avg300=0.00 total=0
+";
+
+    const MEMORY: &str = "\
+some avg10=0.00 avg60=0.12 avg300=0.05 total=1949756
+full avg10=0.00 avg60=0.07 avg300=0.02 total=1528243
+";
+
+    const IO: &str = "\
+some avg10=12.31 avg60=4.02 avg300=1.17 total=608200819
+full avg10=10.94 avg60=3.51 avg300=1.00 total=545340121
+";
+
+    #[test]
+    fn parses_pressure() {
+        assert_eq!(
+            parse_pressure(IO),
+            vec![
+                PressureLine {
+                    kind: "some",
+                    avg10: 12.31,
+                    avg60: 4.02,
+                    avg300: 1.17,
+                    total: 608200819,
+                },
+                PressureLine {
+                    kind: "full",
+                    avg10: 10.94,
+                    avg60: 3.51,
+                    avg300: 1.00,
+                    total: 545340121,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_pressure_without_full_line() {
+        let lines = parse_pressure("some avg10=0.22 avg60=0.17 avg300=0.11 total=12345678\n");
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].kind, "some");
+        assert_eq!(lines[0].total, 12345678);
+    }
+
+    #[test]
+    fn skips_malformed_pressure_lines() {
+        let lines = parse_pressure(
+            "some avg10=0.22 avg60=0.17 total=12345678\n\
+             full avg10=abc avg60=0.17 avg300=0.11 total=1\n\
+             other avg10=0.22 avg60=0.17 avg300=0.11 total=1\n",
+        );
+        assert!(lines.is_empty());
+    }
+
+    fn scraper(root: &TempDir) -> HostMetrics {
+        let mut host = HostMetrics::new(HostMetricsConfig::default());
+        host.pressure = PressureState::with_root(root.path().to_path_buf());
+        host
+    }
+
+    #[tokio::test]
+    async fn generates_pressure_metrics() {
+        let root = TempDir::new().unwrap();
+        fs::write(root.path().join("cpu"), CPU).unwrap();
+        fs::write(root.path().join("memory"), MEMORY).unwrap();
+        fs::write(root.path().join("io"), IO).unwrap();
+
+        let mut buffer = MetricsBuffer::new(None);
+        scraper(&root).pressure_metrics(&mut buffer).await;
+        let metrics = buffer.metrics;
+
+        assert_eq!(metrics.len(), 24);
+        assert!(all_gauges(&metrics[..3]));
+        assert!(all_counters(&metrics[3..4]));
+        assert_eq!(count_name(&metrics, "pressure_avg10"), 6);
+        assert_eq!(count_name(&metrics, "pressure_stall_microseconds_total"), 6);
+        assert_eq!(count_tag(&metrics, "resource"), 24);
+        assert_eq!(count_tag(&metrics, "type"), 24);
+        assert_eq!(count_tag(&metrics, "collector"), 24);
+
+        let io_full = metrics
+            .iter()
+            .find(|metric| {
+                let tags = metric.tags().unwrap();
+                metric.name() == "pressure_stall_microseconds_total"
+                    && tags.get("resource") == Some("io")
+                    && tags.get("type") == Some("full")
+            })
+            .unwrap();
+        assert_eq!(
+            io_full.value(),
+            &vector_lib::event::MetricValue::Counter { value: 545340121.0 }
+        );
+    }
+
+    #[tokio::test]
+    async fn disables_without_pressure_files() {
+        let root = TempDir::new().unwrap();
+        let host = scraper(&root);
+
+        let mut buffer = MetricsBuffer::new(None);
+        host.pressure_metrics(&mut buffer).await;
+        assert!(buffer.metrics.is_empty());
+        assert!(host
+            .pressure
+            .disabled
+            .load(std::sync::atomic::Ordering::Relaxed));
+
+        // Files appearing later are ignored once the collector has been disabled.
+        fs::write(root.path().join("cpu"), CPU).unwrap();
+        host.pressure_metrics(&mut buffer).await;
+        assert!(buffer.metrics.is_empty());
+    }
+
+    #[tokio::test]
+    async fn reports_available_resources() {
+        let root = TempDir::new().unwrap();
+        fs::write(root.path().join("memory"), MEMORY).unwrap();
+        let host = scraper(&root);
+
+        let mut buffer = MetricsBuffer::new(None);
+        host.pressure_metrics(&mut buffer).await;
+        assert_eq!(buffer.metrics.len(), 8);
+        assert!(!host
+            .pressure
+            .disabled
+            .load(std::sync::atomic::Ordering::Relaxed));
+    }
+}
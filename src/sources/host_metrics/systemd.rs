@@ -0,0 +1,477 @@
+use std::{
+    collections::HashMap,
+    io,
+    path::{Path, PathBuf},
+    process::ExitStatus,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use nix::time::{clock_gettime, ClockId};
+use snafu::{ResultExt, Snafu};
+use tokio::process::Command;
+use vector_lib::metric_tags;
+
+use super::{HostMetrics, MetricsBuffer, SystemdConfig};
+use crate::{event::MetricTags, internal_events::HostMetricsScrapeDetailError};
+
+const MICROSECONDS: f64 = 1.0 / 1_000_000.0;
+
+const DEFAULT_SYSTEMCTL: &str = "systemctl";
+
+/// Properties requested from `systemctl show` for each reported unit.
+const SHOW_PROPERTIES: &str = "Id,NRestarts,StateChangeTimestampMonotonic";
+
+#[derive(Debug, Snafu)]
+enum SystemdError {
+    #[snafu(display("Could not execute {:?}.", command))]
+    Spawning { command: PathBuf, source: io::Error },
+    #[snafu(display("Command {:?} failed with {}: {}", command, status, stderr))]
+    Failed {
+        command: PathBuf,
+        status: ExitStatus,
+        stderr: String,
+    },
+}
+
+type SystemdResult<T> = Result<T, SystemdError>;
+
+const AVAILABILITY_UNKNOWN: u8 = 0;
+const AVAILABILITY_AVAILABLE: u8 = 1;
+const AVAILABILITY_UNAVAILABLE: u8 = 2;
+
+/// Tracks whether the system manager can be queried at all.
+///
+/// If the very first query fails (no `systemctl` binary, or no system manager to talk to, as is
+/// the case inside most containers), the collector is disabled for the lifetime of the source
+/// with a single warning instead of reporting an error on every scrape. Failures after a
+/// successful query are treated as regular scrape errors.
+#[derive(Debug, Default)]
+pub(super) struct SystemdAvailability(AtomicU8);
+
+impl SystemdAvailability {
+    fn is_unavailable(&self) -> bool {
+        self.0.load(Ordering::Relaxed) == AVAILABILITY_UNAVAILABLE
+    }
+
+    fn mark_available(&self) {
+        self.0.store(AVAILABILITY_AVAILABLE, Ordering::Relaxed);
+    }
+
+    /// Marks the system manager as unavailable if it has never been reached before, returning
+    /// `true` if the collector should now be disabled.
+    fn mark_failed(&self) -> bool {
+        self.0
+            .compare_exchange(
+                AVAILABILITY_UNKNOWN,
+                AVAILABILITY_UNAVAILABLE,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            )
+            .is_ok()
+    }
+}
+
+impl HostMetrics {
+    pub(super) async fn systemd_metrics(&self, output: &mut MetricsBuffer) {
+        if self.systemd_availability.is_unavailable() {
+            return;
+        }
+
+        output.name = "systemd";
+        let config = &self.config.systemd;
+        let systemctl = config
+            .systemctl_path
+            .as_deref()
+            .unwrap_or_else(|| Path::new(DEFAULT_SYSTEMCTL));
+
+        let units = match list_units(systemctl).await {
+            Ok(units) => {
+                self.systemd_availability.mark_available();
+                units
+            }
+            Err(error) => {
+                if self.systemd_availability.mark_failed() {
+                    warn!(
+                        message = "Could not query the systemd system manager, disabling the systemd collector.",
+                        %error,
+                    );
+                } else {
+                    emit!(HostMetricsScrapeDetailError {
+                        message: "Failed to load systemd units.",
+                        error,
+                    });
+                }
+                return;
+            }
+        };
+
+        let mut units = units
+            .into_iter()
+            .filter(|unit| config.units.contains_str(Some(&unit.name)))
+            .collect::<Vec<_>>();
+        units.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+
+        let failed = units
+            .iter()
+            .filter(|unit| unit.active_state == "failed")
+            .count();
+        output.gauge("systemd_units_failed", failed as f64, MetricTags::default());
+
+        if units.len() > config.max_units {
+            warn!(
+                message = "Number of systemd units exceeds the configured maximum, dropping the remaining units.",
+                units = units.len(),
+                max_units = config.max_units,
+                internal_log_rate_limit = true,
+            );
+            units.truncate(config.max_units);
+        }
+        if units.is_empty() {
+            return;
+        }
+
+        let properties = match show_units(systemctl, &units).await {
+            Ok(properties) => properties,
+            Err(error) => {
+                emit!(HostMetricsScrapeDetailError {
+                    message: "Failed to load systemd unit properties.",
+                    error,
+                });
+                HashMap::new()
+            }
+        };
+        let now = clock_gettime(ClockId::CLOCK_MONOTONIC)
+            .ok()
+            .map(|now| now.tv_sec() as u64 * 1_000_000 + now.tv_nsec() as u64 / 1_000);
+
+        for unit in &units {
+            let tags = metric_tags!("unit" => unit.name.clone());
+            output.gauge(
+                "systemd_unit_active",
+                if unit.active_state == "active" {
+                    1.0
+                } else {
+                    0.0
+                },
+                tags.clone(),
+            );
+            output.gauge(
+                "systemd_unit_state",
+                1.0,
+                metric_tags!(
+                    "unit" => unit.name.clone(),
+                    "load_state" => unit.load_state.clone(),
+                    "active_state" => unit.active_state.clone(),
+                    "sub_state" => unit.sub_state.clone(),
+                ),
+            );
+
+            if let Some(properties) = properties.get(&unit.name) {
+                if let Some(restarts) = properties.restarts {
+                    output.counter("systemd_unit_restarts_total", restarts as f64, tags.clone());
+                }
+                if let (Some(now), Some(changed)) = (now, properties.state_change_usec) {
+                    output.gauge(
+                        "systemd_unit_state_change_age_seconds",
+                        now.saturating_sub(changed) as f64 * MICROSECONDS,
+                        tags,
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Unit {
+    name: String,
+    load_state: String,
+    active_state: String,
+    sub_state: String,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct UnitProperties {
+    restarts: Option<u64>,
+    state_change_usec: Option<u64>,
+}
+
+async fn run_systemctl(systemctl: &Path, args: &[&str]) -> SystemdResult<String> {
+    let output = Command::new(systemctl)
+        .args(args)
+        .env("SYSTEMD_COLORS", "0")
+        .kill_on_drop(true)
+        .output()
+        .await
+        .with_context(|_| SpawningSnafu {
+            command: systemctl.to_path_buf(),
+        })?;
+
+    if !output.status.success() {
+        return Err(SystemdError::Failed {
+            command: systemctl.to_path_buf(),
+            status: output.status,
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+async fn list_units(systemctl: &Path) -> SystemdResult<Vec<Unit>> {
+    let output = run_systemctl(
+        systemctl,
+        &[
+            "list-units",
+            "--all",
+            "--full",
+            "--plain",
+            "--no-legend",
+            "--no-pager",
+        ],
+    )
+    .await?;
+    Ok(parse_units(&output))
+}
+
+async fn show_units(
+    systemctl: &Path,
+    units: &[Unit],
+) -> SystemdResult<HashMap<String, UnitProperties>> {
+    let mut args = vec!["show", "--no-pager", "--property", SHOW_PROPERTIES, "--"];
+    args.extend(units.iter().map(|unit| unit.name.as_str()));
+    let output = run_systemctl(systemctl, &args).await?;
+    Ok(parse_properties(&output))
+}
+
+/// Parses the plain output of `systemctl list-units`, which contains one unit per line in the
+/// form `UNIT LOAD ACTIVE SUB DESCRIPTION...`.
+fn parse_units(output: &str) -> Vec<Unit> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            Some(Unit {
+                name: fields.next()?.to_string(),
+                load_state: fields.next()?.to_string(),
+                active_state: fields.next()?.to_string(),
+                sub_state: fields.next()?.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Parses the output of `systemctl show`, which contains one block of `KEY=VALUE` lines per unit,
+/// with blocks separated by empty lines.
+fn parse_properties(output: &str) -> HashMap<String, UnitProperties> {
+    let mut units = HashMap::new();
+    let mut id = None;
+    let mut properties = UnitProperties::default();
+
+    for line in output.lines().chain(std::iter::once("")) {
+        if line.is_empty() {
+            if let Some(id) = id.take() {
+                units.insert(id, std::mem::take(&mut properties));
+            }
+            continue;
+        }
+
+        match line.split_once('=') {
+            Some(("Id", value)) => id = Some(value.to_string()),
+            Some(("NRestarts", value)) => properties.restarts = value.parse().ok(),
+            // A zero timestamp means the unit never changed state since the manager started.
+            Some(("StateChangeTimestampMonotonic", value)) => {
+                properties.state_change_usec = value.parse().ok().filter(|&usec| usec > 0)
+            }
+            _ => {}
+        }
+    }
+
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::PathBuf;
+
+    use similar_asserts::assert_eq;
+    use tempfile::TempDir;
+
+    use super::{
+        super::{
+            tests::{all_counters, all_gauges, count_name, count_tag},
+            HostMetrics, HostMetricsConfig,
+        },
+        parse_properties, parse_units, MetricsBuffer, Unit, UnitProperties,
+    };
+
+    const LIST_UNITS: &str = "\
+dev-sda1.device loaded active plugged QEMU_HARDDISK 1
+cron.service loaded active running Regular background program processing daemon
+nginx.service loaded failed failed A high performance web server and a reverse proxy server
+ssh.service loaded active running OpenBSD Secure Shell server
+systemd-fsck-root.service loaded inactive dead File System Check on Root Device
+dbus.socket loaded active running D-Bus System Message Bus Socket
+";
+
+    const SHOW_UNITS: &str = "\
+Id=cron.service
+NRestarts=0
+StateChangeTimestampMonotonic=5402311
+
+Id=nginx.service
+NRestarts=3
+StateChangeTimestampMonotonic=91233012
+
+Id=ssh.service
+NRestarts=1
+StateChangeTimestampMonotonic=6012345
+";
+
+    #[test]
+    fn parses_list_units() {
+        let units = parse_units(LIST_UNITS);
+        assert_eq!(units.len(), 6);
+        assert_eq!(
+            units[2],
+            Unit {
+                name: "nginx.service".into(),
+                load_state: "loaded".into(),
+                active_state: "failed".into(),
+                sub_state: "failed".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn ignores_truncated_unit_lines() {
+        let units =
+            parse_units("cron.service loaded active\n\nssh.service loaded active running\n");
+        assert_eq!(units.len(), 1);
+        assert_eq!(units[0].name, "ssh.service");
+    }
+
+    #[test]
+    fn parses_show_units() {
+        let properties = parse_properties(
+            "Id=nginx.service\nNRestarts=3\nStateChangeTimestampMonotonic=91233012\n\nId=dbus.socket\nStateChangeTimestampMonotonic=0\n",
+        );
+        assert_eq!(properties.len(), 2);
+        assert_eq!(
+            properties["nginx.service"],
+            UnitProperties {
+                restarts: Some(3),
+                state_change_usec: Some(91233012),
+            }
+        );
+        assert_eq!(properties["dbus.socket"], UnitProperties::default());
+    }
+
+    #[tokio::test]
+    async fn generates_systemd_metrics() {
+        let setup = FakeSystemctl::new(LIST_UNITS, SHOW_UNITS);
+        let metrics = setup
+            .scrape(r#"systemd.units.includes = ["*.service"]"#)
+            .await;
+
+        assert_eq!(count_tag(&metrics, "collector"), metrics.len());
+        assert_eq!(count_name(&metrics, "systemd_units_failed"), 1);
+        assert_eq!(count_name(&metrics, "systemd_unit_active"), 4);
+        assert_eq!(count_name(&metrics, "systemd_unit_state"), 4);
+        assert_eq!(count_name(&metrics, "systemd_unit_restarts_total"), 3);
+        assert_eq!(
+            count_name(&metrics, "systemd_unit_state_change_age_seconds"),
+            3
+        );
+
+        let failed = metrics
+            .iter()
+            .find(|metric| metric.name() == "systemd_units_failed")
+            .unwrap();
+        assert_eq!(
+            failed.value(),
+            &vector_lib::event::MetricValue::Gauge { value: 1.0 }
+        );
+
+        let restarts = metrics
+            .iter()
+            .filter(|metric| metric.name() == "systemd_unit_restarts_total")
+            .cloned()
+            .collect::<Vec<_>>();
+        assert!(all_counters(&restarts));
+        let states = metrics
+            .iter()
+            .filter(|metric| metric.name() == "systemd_unit_state")
+            .cloned()
+            .collect::<Vec<_>>();
+        assert!(all_gauges(&states));
+        assert_eq!(count_tag(&states, "active_state"), 4);
+        assert_eq!(count_tag(&states, "sub_state"), 4);
+    }
+
+    #[tokio::test]
+    async fn limits_number_of_units() {
+        let setup = FakeSystemctl::new(LIST_UNITS, SHOW_UNITS);
+        let metrics = setup.scrape("systemd.max_units = 2").await;
+
+        assert_eq!(count_name(&metrics, "systemd_unit_active"), 2);
+        assert_eq!(count_name(&metrics, "systemd_unit_state"), 2);
+    }
+
+    #[tokio::test]
+    async fn disables_collector_without_systemctl() {
+        let dir = tempfile::tempdir().unwrap();
+        let config: HostMetricsConfig = toml::from_str(&format!(
+            r#"
+            collectors = ["systemd"]
+            systemd.systemctl_path = {:?}
+            "#,
+            dir.path().join("missing")
+        ))
+        .unwrap();
+        let host = HostMetrics::new(config);
+
+        let mut buffer = MetricsBuffer::new(None);
+        host.systemd_metrics(&mut buffer).await;
+        assert!(buffer.metrics.is_empty());
+        assert!(host.systemd_availability.is_unavailable());
+    }
+
+    /// A shell script standing in for `systemctl`, answering `list-units` and `show` with canned
+    /// output.
+    struct FakeSystemctl(TempDir);
+
+    impl FakeSystemctl {
+        fn new(list_units: &str, show: &str) -> Self {
+            let dir = tempfile::tempdir().unwrap();
+            fs::write(dir.path().join("list-units"), list_units).unwrap();
+            fs::write(dir.path().join("show"), show).unwrap();
+            let script = format!("#!/bin/sh\ncat {:?}/\"$1\"\n", dir.path().to_string_lossy());
+            let path = dir.path().join("systemctl");
+            fs::write(&path, script).unwrap();
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+            Self(dir)
+        }
+
+        fn path(&self) -> PathBuf {
+            self.0.path().join("systemctl")
+        }
+
+        async fn scrape(&self, extra: &str) -> Vec<vector_lib::event::Metric> {
+            let config: HostMetricsConfig = toml::from_str(&format!(
+                r#"
+                collectors = ["systemd"]
+                systemd.systemctl_path = {:?}
+                {extra}
+                "#,
+                self.path()
+            ))
+            .unwrap();
+            let mut buffer = MetricsBuffer::new(None);
+            HostMetrics::new(config).systemd_metrics(&mut buffer).await;
+            buffer.metrics
+        }
+    }
+}
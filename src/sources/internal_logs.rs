@@ -212,6 +212,7 @@ async fn run(
 mod tests {
     use futures::Stream;
     use tokio::time::{sleep, Duration};
+    use tracing::Instrument;
     use vector_lib::event::Value;
     use vector_lib::lookup::OwnedTargetPath;
     use vrl::value::kind::Collection;
@@ -276,8 +277,28 @@ mod tests {
             error!(message = "In a nested span.", %test_id);
         }
 
+        // Panics are reported along with the fields of the span they happen in.
+        trace::init_panic_hook();
+        let panic_message = format!("Deliberate panic {test_id}.");
+        let panicking = tokio::spawn({
+            let panic_message = panic_message.clone();
+            async move { panic!("{panic_message}") }.instrument(span.clone())
+        });
+        assert!(panicking.await.unwrap_err().is_panic());
+
         sleep(Duration::from_millis(1)).await;
         let mut events = collect_ready(rx).await;
+
+        let panic_message = Value::from(panic_message);
+        let panics = events
+            .iter()
+            .filter(|event| event.as_log().get("panic_message") == Some(&panic_message))
+            .collect::<Vec<_>>();
+        assert_eq!(panics.len(), 1);
+        let panic = panics[0].as_log();
+        assert_eq!(panic["message"], "Vector has panicked.".into());
+        assert_eq!(panic["vector.component_id"], "foo".into());
+        assert!(panic.get("location").is_some());
         let test_id = Value::from(test_id.to_string());
         events.retain(|event| event.as_log().get("test_id") == Some(&test_id));
 
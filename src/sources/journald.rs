@@ -542,7 +542,10 @@ struct Batch<'a> {
 
 impl<'a> Batch<'a> {
     fn new(source: &'a mut JournaldSource) -> Self {
-        let (batch, receiver) = BatchNotifier::maybe_new_with_receiver(source.acknowledgements);
+        let (batch, receiver) = BatchNotifier::maybe_new_tracked_with_receiver(
+            source.acknowledgements,
+            source.out.unacknowledged_events(),
+        );
         Self {
             events: Vec::new(),
             record_size: 0,
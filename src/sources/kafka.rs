@@ -961,7 +961,11 @@ async fn parse_message(
     log_namespace: LogNamespace,
 ) {
     if let Some((count, stream)) = parse_stream(&msg, decoder, keys, log_namespace) {
-        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        let (batch, receiver) = if acknowledgements {
+            BatchNotifier::new_tracked_with_receiver(out.unacknowledged_events())
+        } else {
+            BatchNotifier::new_with_receiver()
+        };
         let mut stream = stream.map(|event| {
             // All acknowledgements flow through the normal Finalizer stream so
             // that they can be handled in one place, but are only tied to the
@@ -79,7 +79,11 @@ impl Service {
         let byte_size = events.estimated_json_encoded_size_of();
         self.events_received.emit(CountByteSize(count, byte_size));
 
-        let receiver = BatchNotifier::maybe_apply_to(self.acknowledgements, &mut events);
+        let receiver = BatchNotifier::maybe_apply_tracked_to(
+            self.acknowledgements,
+            &mut events,
+            self.pipeline.unacknowledged_events(),
+        );
 
         self.pipeline
             .clone()
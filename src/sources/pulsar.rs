@@ -446,7 +446,8 @@ async fn finalize_event_stream(
 ) {
     match finalizer {
         Some(finalizer) => {
-            let (batch, receiver) = BatchNotifier::new_with_receiver();
+            let (batch, receiver) =
+                BatchNotifier::new_tracked_with_receiver(out.unacknowledged_events());
             let mut stream = stream.map(|event| event.with_batch_notifier(&batch));
 
             match out.send_event_stream(&mut stream).await {
@@ -12,7 +12,7 @@ use vrl::value::{kind::Collection, Kind};
 use crate::{
     codecs::DecodingConfig,
     config::{GenerateConfig, Resource, SourceConfig, SourceContext, SourceOutput},
-    sources::util::net::TcpSource,
+    sources::util::net::{validate_udp_listeners, TcpSource},
     tls::MaybeTlsSettings,
 };
 
@@ -149,6 +149,7 @@ impl SourceConfig for SocketConfig {
                 )
             }
             Mode::Udp(config) => {
+                validate_udp_listeners(config.address(), config.listeners)?;
                 let log_namespace = cx.log_namespace(config.log_namespace);
                 let decoding = config.decoding().clone();
                 let framing = config
@@ -320,6 +321,7 @@ mod test {
     use std::{
         collections::HashMap,
         net::{SocketAddr, UdpSocket},
+        num::NonZeroUsize,
         sync::{
             atomic::{AtomicBool, Ordering},
             Arc,
@@ -331,7 +333,7 @@ mod test {
     use futures::{stream, StreamExt};
     use rand::{rngs::SmallRng, seq::SliceRandom, SeedableRng};
     use serde_json::json;
-    use tokio::io::AsyncReadExt;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
     use tokio::net::TcpStream;
     use tokio::{
         task::JoinHandle,
@@ -357,7 +359,6 @@ mod test {
         std::os::unix::fs::PermissionsExt,
         std::path::PathBuf,
         tokio::{
-            io::AsyncWriteExt,
             net::{UnixDatagram, UnixStream},
             task::yield_now,
         },
@@ -367,10 +368,11 @@ mod test {
     use super::{tcp::TcpConfig, udp::UdpConfig, SocketConfig};
     use crate::{
         config::{log_schema, ComponentKey, GlobalOptions, SourceConfig, SourceContext},
-        event::{Event, LogEvent},
+        event::{Event, LogEvent, MetricValue},
+        metrics::Controller,
         shutdown::{ShutdownSignal, SourceShutdownCoordinator},
         sinks::util::tcp::TcpSinkConfig,
-        sources::util::net::SocketListenAddr,
+        sources::util::{connection_limit::ConnectionLimitMode, net::SocketListenAddr},
         test_util::{
             collect_n, collect_n_limited,
             components::{assert_source_compliance, SOCKET_PUSH_SOURCE_TAGS},
@@ -432,6 +434,42 @@ mod test {
         crate::test_util::test_generate_config::<SocketConfig>();
     }
 
+    /// The value of a connection metric of a listener.
+    fn listener_metric(name: &str, listener: &str) -> f64 {
+        Controller::get()
+            .expect("no controller")
+            .capture_metrics()
+            .into_iter()
+            .filter(|metric| {
+                metric.name() == name && metric.tag_value("listener").as_deref() == Some(listener)
+            })
+            .map(|metric| match metric.value() {
+                MetricValue::Counter { value } | MetricValue::Gauge { value } => *value,
+                value => panic!("Unexpected value of {name}: {value:?}"),
+            })
+            .sum()
+    }
+
+    /// Waits until a listener has accepted connections, and all of them are closed.
+    async fn wait_for_idle_listener(listener: &str) {
+        let listener = listener.to_owned();
+        crate::test_util::wait_for(move || {
+            std::future::ready(
+                listener_metric("connections_accepted_total", &listener) > 0.0
+                    && listener_metric("open_connections", &listener) == 0.0,
+            )
+        })
+        .await;
+    }
+
+    async fn assert_connection_closed(mut stream: impl tokio::io::AsyncRead + Unpin) {
+        let mut buffer = [0u8; 1];
+        let read = timeout(Duration::from_secs(5), stream.read(&mut buffer))
+            .await
+            .expect("connection should be closed");
+        assert!(!matches!(read, Ok(len) if len > 0), "{read:?}");
+    }
+
     //////// TCP TESTS ////////
     #[tokio::test]
     async fn tcp_it_includes_host() {
@@ -897,6 +935,129 @@ mod test {
         }
     }
 
+    #[tokio::test]
+    async fn tcp_closes_idle_connections() {
+        let (tx, mut rx) = SourceSender::new_test();
+        let addr = next_addr();
+
+        let mut source_config = TcpConfig::from_address(addr.into());
+        source_config.set_idle_timeout_secs(Some(1));
+        let source_task = SocketConfig::from(source_config)
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .unwrap();
+        drop(tokio::spawn(source_task));
+        wait_for_tcp(addr).await;
+
+        // Frames keep the connection open, but not incomplete ones.
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        for _ in 0..3 {
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            stream.write_all(b"beat\n").await.unwrap();
+            rx.next().await.expect("event should be received");
+        }
+        stream.write_all(b"incomplete").await.unwrap();
+
+        let start = Instant::now();
+        assert_connection_closed(stream).await;
+        assert_relative_eq!(start.elapsed().as_secs_f64(), 1.0, epsilon = 0.3);
+
+        // A connected, but silent, client is closed as well.
+        let start = Instant::now();
+        assert_connection_closed(TcpStream::connect(addr).await.unwrap()).await;
+        assert_relative_eq!(start.elapsed().as_secs_f64(), 1.0, epsilon = 0.3);
+    }
+
+    #[tokio::test]
+    async fn tcp_does_not_close_connections_blocked_on_the_output() {
+        let (tx, mut rx) = SourceSender::new_test_sender_with_buffer(1);
+        let addr = next_addr();
+
+        let mut source_config = TcpConfig::from_address(addr.into());
+        source_config.set_idle_timeout_secs(Some(1));
+        let source_task = SocketConfig::from(source_config)
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .unwrap();
+        drop(tokio::spawn(source_task));
+        wait_for_tcp(addr).await;
+
+        // The first event fills the output, so sending the second one is blocked for longer
+        // than the idle timeout.
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(b"first\n").await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        stream.write_all(b"second\n").await.unwrap();
+        tokio::time::sleep(Duration::from_secs(2)).await;
+        for _ in 0..2 {
+            rx.next().await.expect("event should be received");
+        }
+
+        // The connection is kept open once the output is unblocked.
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        stream.write_all(b"third\n").await.unwrap();
+        let item = timeout(Duration::from_secs(1), rx.next())
+            .await
+            .expect("connection should be open")
+            .expect("event should be received");
+        assert_eq!(
+            item.events.into_events().next().unwrap().as_log()["message"],
+            "third".into()
+        );
+    }
+
+    #[tokio::test]
+    async fn tcp_rejects_connections_over_the_limit() {
+        assert_source_compliance(&SOCKET_PUSH_SOURCE_TAGS, async {
+            let (tx, mut rx) = SourceSender::new_test();
+            let addr = next_addr();
+            let listener = addr.to_string();
+
+            let mut config = TcpConfig::from_address(addr.into());
+            config.connection_limit = Some(2);
+            config.connection_limit_mode = ConnectionLimitMode::Reject;
+            let server = SocketConfig::from(config)
+                .build(SourceContext::new_test(tx, None))
+                .await
+                .unwrap();
+            tokio::spawn(server);
+            wait_for_tcp(addr).await;
+            wait_for_idle_listener(&listener).await;
+
+            let mut open = Vec::new();
+            for line in ["first\n", "second\n"] {
+                let mut stream = TcpStream::connect(addr).await.unwrap();
+                stream.write_all(line.as_bytes()).await.unwrap();
+                rx.next().await.expect("event should be received");
+                open.push(stream);
+            }
+            assert_eq!(listener_metric("open_connections", &listener), 2.0);
+
+            for _ in 0..3 {
+                assert_connection_closed(TcpStream::connect(addr).await.unwrap()).await;
+            }
+            assert_eq!(
+                listener_metric("connections_rejected_total", &listener),
+                3.0
+            );
+            assert_eq!(listener_metric("open_connections", &listener), 2.0);
+            assert!(listener_metric("connections_accepted_total", &listener) >= 3.0);
+
+            drop(open);
+            wait_for_idle_listener(&listener).await;
+
+            // Connections are accepted again once the open ones are closed.
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            stream.write_all(b"third\n").await.unwrap();
+            let event = rx.next().await.expect("event should be received");
+            assert_eq!(
+                event.as_log()[log_schema().message_key().unwrap().to_string()],
+                "third".into()
+            );
+        })
+        .await;
+    }
+
     //////// UDP TESTS ////////
     fn send_lines_udp(addr: SocketAddr, lines: impl IntoIterator<Item = String>) -> SocketAddr {
         send_packets_udp(addr, lines.into_iter().map(|line| line.into()))
@@ -1026,6 +1187,50 @@ mod test {
         .await;
     }
 
+    #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+    #[tokio::test]
+    async fn udp_message_with_multiple_listeners() {
+        assert_source_compliance(&SOCKET_PUSH_SOURCE_TAGS, async {
+            let (tx, rx) = SourceSender::new_test();
+            let mut config = UdpConfig::from_address(next_addr().into());
+            config.set_listeners(NonZeroUsize::new(4));
+            let address = init_udp_with_config(tx, config).await;
+
+            // Each batch of lines is sent from its own port, for them to be spread between the
+            // listeners.
+            let lines = (0..40).map(|i| format!("line {i:02}")).collect::<Vec<_>>();
+            for batch in lines.chunks(10) {
+                send_lines_udp(address, batch.to_vec());
+            }
+
+            let message_key = log_schema().message_key().unwrap().to_string();
+            let mut messages = collect_n(rx, lines.len())
+                .await
+                .into_iter()
+                .map(|event| {
+                    event.as_log()[message_key.as_str()]
+                        .to_string_lossy()
+                        .into_owned()
+                })
+                .collect::<Vec<_>>();
+            messages.sort();
+            assert_eq!(messages, lines);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn udp_rejects_multiple_listeners_on_systemd_sockets() {
+        let mut config = UdpConfig::from_address(SocketListenAddr::SystemdFd(0));
+        config.set_listeners(NonZeroUsize::new(2));
+
+        let (tx, _) = SourceSender::new_test();
+        assert!(SocketConfig::from(config)
+            .build(SourceContext::new_test(tx, None))
+            .await
+            .is_err());
+    }
+
     #[tokio::test]
     async fn udp_message_preserves_newline() {
         assert_source_compliance(&SOCKET_PUSH_SOURCE_TAGS, async {
@@ -1791,4 +1996,37 @@ mod test {
         })
         .await;
     }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unix_stream_rejects_connections_over_the_limit() {
+        assert_source_compliance(&SOCKET_PUSH_SOURCE_TAGS, async {
+            let (tx, mut rx) = SourceSender::new_test();
+            let mut config =
+                UnixConfig::new(tempfile::tempdir().unwrap().into_path().join("unix_test"));
+            config.connection_limit = Some(1);
+            config.connection_limit_mode = ConnectionLimitMode::Reject;
+            let path = init_unix_with_config(tx, true, false, config).await;
+            let listener = path.to_string_lossy().into_owned();
+            wait_for_idle_listener(&listener).await;
+
+            let mut stream = UnixStream::connect(&path).await.unwrap();
+            stream.write_all(b"first\n").await.unwrap();
+            rx.next().await.expect("event should be received");
+            assert_eq!(listener_metric("open_connections", &listener), 1.0);
+
+            for _ in 0..2 {
+                assert_connection_closed(UnixStream::connect(&path).await.unwrap()).await;
+            }
+            assert_eq!(
+                listener_metric("connections_rejected_total", &listener),
+                2.0
+            );
+            assert_eq!(listener_metric("open_connections", &listener), 1.0);
+
+            drop(stream);
+            wait_for_idle_listener(&listener).await;
+        })
+        .await;
+    }
 }
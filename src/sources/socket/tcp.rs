@@ -13,7 +13,10 @@ use crate::{
     codecs::Decoder,
     event::Event,
     serde::default_decoding,
-    sources::util::net::{SocketListenAddr, TcpNullAcker, TcpSource},
+    sources::util::{
+        connection_limit::ConnectionLimitMode,
+        net::{SocketListenAddr, TcpNullAcker, TcpSource},
+    },
     tcp::TcpKeepaliveConfig,
     tls::TlsSourceConfig,
 };
@@ -74,10 +77,22 @@ pub struct TcpConfig {
     #[configurable(metadata(docs::type_unit = "seconds"))]
     max_connection_duration_secs: Option<u64>,
 
+    /// The time after which connections that didn't send a complete frame are closed.
+    ///
+    /// This closes the connections of senders that crashed, or that are no longer reachable,
+    /// without being noticed. Connections are kept open for as long as they stay open if not set.
+    #[configurable(metadata(docs::type_unit = "seconds"))]
+    idle_timeout_secs: Option<u64>,
+
     /// The maximum number of TCP connections that are allowed at any given time.
+    #[serde(alias = "max_connections")]
     #[configurable(metadata(docs::type_unit = "connections"))]
     pub connection_limit: Option<u32>,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    pub connection_limit_mode: ConnectionLimitMode,
+
     #[configurable(derived)]
     pub(super) framing: Option<FramingConfig>,
 
@@ -111,9 +126,11 @@ impl TcpConfig {
             tls: None,
             receive_buffer_bytes: None,
             max_connection_duration_secs: None,
+            idle_timeout_secs: None,
             framing: None,
             decoding: default_decoding(),
             connection_limit: None,
+            connection_limit_mode: ConnectionLimitMode::default(),
             log_namespace: None,
         }
     }
@@ -163,6 +180,11 @@ impl TcpConfig {
         self
     }
 
+    pub fn set_idle_timeout_secs(&mut self, val: Option<u64>) -> &mut Self {
+        self.idle_timeout_secs = val;
+        self
+    }
+
     pub fn set_shutdown_timeout_secs(&mut self, val: u64) -> &mut Self {
         self.shutdown_timeout_secs = Duration::from_secs(val);
         self
@@ -258,4 +280,12 @@ impl TcpSource for RawTcpSource {
     fn build_acker(&self, _: &[Self::Item]) -> Self::Acker {
         TcpNullAcker
     }
+
+    fn connection_limit_mode(&self) -> ConnectionLimitMode {
+        self.config.connection_limit_mode
+    }
+
+    fn idle_timeout(&self) -> Option<Duration> {
+        self.config.idle_timeout_secs.map(Duration::from_secs)
+    }
 }
@@ -1,15 +1,21 @@
+use std::num::NonZeroUsize;
+
 use super::default_host_key;
 use bytes::BytesMut;
 use chrono::Utc;
 use futures::StreamExt;
 use listenfd::ListenFd;
+use tokio::{net::UdpSocket, task::JoinSet};
 use tokio_util::codec::FramedRead;
+use tracing::Instrument;
 use vector_lib::codecs::{
     decoding::{DeserializerConfig, FramingConfig},
     StreamDecodingError,
 };
 use vector_lib::configurable::configurable_component;
-use vector_lib::internal_event::{ByteSize, BytesReceived, InternalEventHandle as _, Protocol};
+use vector_lib::internal_event::{
+    ByteSize, BytesReceived, InternalEventHandle as _, Protocol, Registered,
+};
 use vector_lib::lookup::{lookup_v2::OptionalValuePath, owned_value_path, path};
 use vector_lib::{
     config::{LegacyKey, LogNamespace},
@@ -27,7 +33,7 @@ use crate::{
     shutdown::ShutdownSignal,
     sources::{
         socket::SocketConfig,
-        util::net::{try_bind_udp_socket, SocketListenAddr},
+        util::net::{try_bind_udp_sockets, SocketListenAddr},
         Source,
     },
     SourceSender,
@@ -73,6 +79,15 @@ pub struct UdpConfig {
     #[configurable(metadata(docs::type_unit = "bytes"))]
     receive_buffer_bytes: Option<usize>,
 
+    /// The number of sockets listening on the address, each received from by its own task.
+    ///
+    /// If there is more than one, the sockets are bound with `SO_REUSEPORT` so that the kernel
+    /// spreads the datagrams received on the address between them. For small datagrams, this
+    /// allows receiving at higher rates than a single socket does. This is only supported on Unix
+    /// platforms that support `SO_REUSEPORT`, and not with sockets passed by systemd.
+    #[configurable(metadata(docs::examples = 4))]
+    pub(super) listeners: Option<NonZeroUsize>,
+
     #[configurable(derived)]
     pub(super) framing: Option<FramingConfig>,
 
@@ -122,6 +137,7 @@ impl UdpConfig {
             host_key: None,
             port_key: default_port_key(),
             receive_buffer_bytes: None,
+            listeners: None,
             framing: None,
             decoding: default_decoding(),
             log_namespace: None,
@@ -132,18 +148,23 @@ impl UdpConfig {
         self.log_namespace = val;
         self
     }
+
+    pub fn set_listeners(&mut self, val: Option<NonZeroUsize>) -> &mut Self {
+        self.listeners = val;
+        self
+    }
 }
 
 pub(super) fn udp(
     config: UdpConfig,
     decoder: Decoder,
-    mut shutdown: ShutdownSignal,
-    mut out: SourceSender,
+    shutdown: ShutdownSignal,
+    out: SourceSender,
     log_namespace: LogNamespace,
 ) -> Source {
     Box::pin(async move {
         let listenfd = ListenFd::from_env();
-        let socket = try_bind_udp_socket(config.address, listenfd)
+        let sockets = try_bind_udp_sockets(config.address, listenfd, config.listeners)
             .await
             .map_err(|error| {
                 emit!(SocketBindError {
@@ -152,138 +173,177 @@ pub(super) fn udp(
                 })
             })?;
 
-        if let Some(receive_buffer_bytes) = config.receive_buffer_bytes {
-            if let Err(error) = net::set_receive_buffer_size(&socket, receive_buffer_bytes) {
-                warn!(message = "Failed configuring receive buffer size on UDP socket.", %error);
-            }
+        let bytes_received = register!(BytesReceived::from(Protocol::UDP));
+
+        info!(
+            message = "Listening.",
+            address = %config.address,
+            listeners = sockets.len()
+        );
+        let mut receivers = JoinSet::new();
+        for socket in sockets {
+            receivers.spawn(
+                receive(
+                    socket,
+                    config.clone(),
+                    decoder.clone(),
+                    bytes_received.clone(),
+                    shutdown.clone(),
+                    out.clone(),
+                    log_namespace,
+                )
+                .in_current_span(),
+            );
         }
 
-        let mut max_length = config.max_length;
+        // The remaining listeners are stopped when the set is dropped, if one of them fails.
+        while let Some(result) = receivers.join_next().await {
+            if !matches!(result, Ok(Ok(()))) {
+                return Err(());
+            }
+        }
+        Ok(())
+    })
+}
 
-        if let Some(receive_buffer_bytes) = config.receive_buffer_bytes {
-            max_length = std::cmp::min(max_length, receive_buffer_bytes);
+/// Receives the datagrams of one of the sockets of the source.
+async fn receive(
+    socket: UdpSocket,
+    config: UdpConfig,
+    decoder: Decoder,
+    bytes_received: Registered<BytesReceived>,
+    mut shutdown: ShutdownSignal,
+    mut out: SourceSender,
+    log_namespace: LogNamespace,
+) -> Result<(), ()> {
+    if let Some(receive_buffer_bytes) = config.receive_buffer_bytes {
+        if let Err(error) = net::set_receive_buffer_size(&socket, receive_buffer_bytes) {
+            warn!(message = "Failed configuring receive buffer size on UDP socket.", %error);
         }
+    }
 
-        let bytes_received = register!(BytesReceived::from(Protocol::UDP));
+    let mut max_length = config.max_length;
 
-        info!(message = "Listening.", address = %config.address);
-        // We add 1 to the max_length in order to determine if the received data has been truncated.
-        let mut buf = BytesMut::with_capacity(max_length + 1);
-        loop {
-            buf.resize(max_length + 1, 0);
-            tokio::select! {
-                recv = socket.recv_from(&mut buf) => {
-                    let (byte_size, address) = match recv {
-                        Ok(res) => res,
-                        Err(error) => {
-                            #[cfg(windows)]
-                            if let Some(err) = error.raw_os_error() {
-                                if err == 10040 {
-                                    // 10040 is the Windows error that the Udp message has exceeded max_length
-                                    warn!(
-                                        message = "Discarding frame larger than max_length.",
-                                        max_length = max_length,
-                                        internal_log_rate_limit = true
-                                    );
-                                    continue;
-                                }
+    if let Some(receive_buffer_bytes) = config.receive_buffer_bytes {
+        max_length = std::cmp::min(max_length, receive_buffer_bytes);
+    }
+
+    // We add 1 to the max_length in order to determine if the received data has been truncated.
+    let mut buf = BytesMut::with_capacity(max_length + 1);
+    loop {
+        buf.resize(max_length + 1, 0);
+        tokio::select! {
+            recv = socket.recv_from(&mut buf) => {
+                let (byte_size, address) = match recv {
+                    Ok(res) => res,
+                    Err(error) => {
+                        #[cfg(windows)]
+                        if let Some(err) = error.raw_os_error() {
+                            if err == 10040 {
+                                // 10040 is the Windows error that the Udp message has exceeded max_length
+                                warn!(
+                                    message = "Discarding frame larger than max_length.",
+                                    max_length = max_length,
+                                    internal_log_rate_limit = true
+                                );
+                                continue;
                             }
+                        }
 
-                            return Err(emit!(SocketReceiveError {
+                        return Err(emit!(SocketReceiveError {
+                            mode: SocketMode::Udp,
+                            error
+                        }));
+                   }
+                };
+
+                bytes_received.emit(ByteSize(byte_size));
+                let payload = buf.split_to(byte_size);
+                let truncated = byte_size == max_length + 1;
+                let mut stream = FramedRead::new(payload.as_ref(), decoder.clone()).peekable();
+
+                while let Some(result) = stream.next().await {
+                    let last = Pin::new(&mut stream).peek().await.is_none();
+                    match result {
+                        Ok((mut events, _byte_size)) => {
+                            if last && truncated {
+                                // The last event in this payload was truncated, so we want to drop it.
+                                _ = events.pop();
+                                warn!(
+                                    message = "Discarding frame larger than max_length.",
+                                    max_length = max_length,
+                                    internal_log_rate_limit = true
+                                );
+                            }
+
+                            if events.is_empty() {
+                                continue;
+                            }
+
+                            let count = events.len();
+                            emit!(SocketEventsReceived {
                                 mode: SocketMode::Udp,
-                                error
-                            }));
-                       }
-                    };
-
-                    bytes_received.emit(ByteSize(byte_size));
-                    let payload = buf.split_to(byte_size);
-                    let truncated = byte_size == max_length + 1;
-                    let mut stream = FramedRead::new(payload.as_ref(), decoder.clone()).peekable();
-
-                    while let Some(result) = stream.next().await {
-                        let last = Pin::new(&mut stream).peek().await.is_none();
-                        match result {
-                            Ok((mut events, _byte_size)) => {
-                                if last && truncated {
-                                    // The last event in this payload was truncated, so we want to drop it.
-                                    _ = events.pop();
-                                    warn!(
-                                        message = "Discarding frame larger than max_length.",
-                                        max_length = max_length,
-                                        internal_log_rate_limit = true
+                                byte_size: events.estimated_json_encoded_size_of(),
+                                count,
+                            });
+
+                            let now = Utc::now();
+
+                            for event in &mut events {
+                                if let Event::Log(ref mut log) = event {
+                                    log_namespace.insert_standard_vector_source_metadata(
+                                        log,
+                                        SocketConfig::NAME,
+                                        now,
                                     );
-                                }
 
-                                if events.is_empty() {
-                                    continue;
-                                }
+                                    let legacy_host_key = config
+                                        .host_key
+                                        .clone()
+                                        .unwrap_or(default_host_key())
+                                        .path;
+
+                                    log_namespace.insert_source_metadata(
+                                        SocketConfig::NAME,
+                                        log,
+                                        legacy_host_key.as_ref().map(LegacyKey::InsertIfEmpty),
+                                        path!("host"),
+                                        address.ip().to_string()
+                                    );
 
-                                let count = events.len();
-                                emit!(SocketEventsReceived {
-                                    mode: SocketMode::Udp,
-                                    byte_size: events.estimated_json_encoded_size_of(),
-                                    count,
-                                });
-
-                                let now = Utc::now();
-
-                                for event in &mut events {
-                                    if let Event::Log(ref mut log) = event {
-                                        log_namespace.insert_standard_vector_source_metadata(
-                                            log,
-                                            SocketConfig::NAME,
-                                            now,
-                                        );
-
-                                        let legacy_host_key = config
-                                            .host_key
-                                            .clone()
-                                            .unwrap_or(default_host_key())
-                                            .path;
-
-                                        log_namespace.insert_source_metadata(
-                                            SocketConfig::NAME,
-                                            log,
-                                            legacy_host_key.as_ref().map(LegacyKey::InsertIfEmpty),
-                                            path!("host"),
-                                            address.ip().to_string()
-                                        );
-
-                                        let legacy_port_key = config.port_key.clone().path;
-
-                                        log_namespace.insert_source_metadata(
-                                            SocketConfig::NAME,
-                                            log,
-                                            legacy_port_key.as_ref().map(LegacyKey::InsertIfEmpty),
-                                            path!("port"),
-                                            address.port()
-                                        );
-                                    }
+                                    let legacy_port_key = config.port_key.clone().path;
+
+                                    log_namespace.insert_source_metadata(
+                                        SocketConfig::NAME,
+                                        log,
+                                        legacy_port_key.as_ref().map(LegacyKey::InsertIfEmpty),
+                                        path!("port"),
+                                        address.port()
+                                    );
                                 }
+                            }
 
-                                tokio::select!{
-                                    result = out.send_batch(events) => {
-                                        if result.is_err() {
-                                            emit!(StreamClosedError { count });
-                                            return Ok(())
-                                        }
+                            tokio::select!{
+                                result = out.send_batch(events) => {
+                                    if result.is_err() {
+                                        emit!(StreamClosedError { count });
+                                        return Ok(())
                                     }
-                                    _ = &mut shutdown => return Ok(()),
                                 }
+                                _ = &mut shutdown => return Ok(()),
                             }
-                            Err(error) => {
-                                // Error is logged by `crate::codecs::Decoder`, no
-                                // further handling is needed here.
-                                if !error.can_continue() {
-                                    break;
-                                }
+                        }
+                        Err(error) => {
+                            // Error is logged by `crate::codecs::Decoder`, no
+                            // further handling is needed here.
+                            if !error.can_continue() {
+                                break;
                             }
                         }
                     }
                 }
-                _ = &mut shutdown => return Ok(()),
             }
+            _ = &mut shutdown => return Ok(()),
         }
-    })
+    }
 }
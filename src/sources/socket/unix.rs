@@ -13,7 +13,10 @@ use crate::{
     event::Event,
     serde::default_decoding,
     sources::{
-        util::{build_unix_datagram_source, build_unix_stream_source},
+        util::{
+            build_unix_datagram_source, build_unix_stream_source,
+            connection_limit::ConnectionLimitMode,
+        },
         Source,
     },
     SourceSender,
@@ -53,6 +56,17 @@ pub struct UnixConfig {
     #[serde(default = "default_host_key")]
     pub host_key: OptionalValuePath,
 
+    /// The maximum number of connections that are allowed at any given time.
+    ///
+    /// This only applies to the `unix_stream` mode.
+    #[serde(alias = "max_connections")]
+    #[configurable(metadata(docs::type_unit = "connections"))]
+    pub connection_limit: Option<u32>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub connection_limit_mode: ConnectionLimitMode,
+
     #[configurable(derived)]
     #[serde(default)]
     pub framing: Option<FramingConfig>,
@@ -73,6 +87,8 @@ impl UnixConfig {
             path,
             socket_file_mode: None,
             host_key: default_host_key(),
+            connection_limit: None,
+            connection_limit_mode: ConnectionLimitMode::default(),
             framing: None,
             decoding: default_decoding(),
             log_namespace: None,
@@ -130,6 +146,7 @@ pub(super) fn unix_datagram(
             FramingConfig::CharacterDelimited(config) => config.character_delimited.max_length,
             FramingConfig::NewlineDelimited(config) => config.newline_delimited.max_length,
             FramingConfig::OctetCounting(config) => config.octet_counting.max_length,
+            FramingConfig::JournalExport(config) => config.journal_export.max_length,
             _ => None,
         })
         .unwrap_or_else(crate::serde::default_max_length);
@@ -157,6 +174,8 @@ pub(super) fn unix_stream(
     build_unix_stream_source(
         config.path,
         config.socket_file_mode,
+        config.connection_limit,
+        config.connection_limit_mode,
         decoder,
         move |events, received_from| {
             handle_events(events, &config.host_key, received_from, log_namespace)
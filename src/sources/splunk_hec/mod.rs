@@ -387,8 +387,10 @@ impl SplunkSource {
                             protocol,
                         });
 
-                        let (batch, receiver) =
-                            BatchNotifier::maybe_new_with_receiver(idx_ack.is_some());
+                        let (batch, receiver) = BatchNotifier::maybe_new_tracked_with_receiver(
+                            idx_ack.is_some(),
+                            out.unacknowledged_events(),
+                        );
                         let maybe_ack_id = match (idx_ack, receiver, channel.clone()) {
                             (Some(idx_ack), Some(receiver), Some(channel_id)) => {
                                 match idx_ack.get_ack_id_from_channel(channel_id, receiver).await {
@@ -477,8 +479,10 @@ impl SplunkSource {
                     });
 
                     async move {
-                        let (batch, receiver) =
-                            BatchNotifier::maybe_new_with_receiver(idx_ack.is_some());
+                        let (batch, receiver) = BatchNotifier::maybe_new_tracked_with_receiver(
+                            idx_ack.is_some(),
+                            out.unacknowledged_events(),
+                        );
                         let maybe_ack_id = match (idx_ack, receiver) {
                             (Some(idx_ack), Some(receiver)) => Some(
                                 idx_ack
@@ -42,6 +42,8 @@ pub fn statsd_unix(
     build_unix_stream_source(
         config.path,
         None,
+        None,
+        Default::default(),
         decoder,
         |_events, _host| {},
         shutdown,
@@ -1,6 +1,6 @@
 #[cfg(unix)]
 use std::path::PathBuf;
-use std::{net::SocketAddr, time::Duration};
+use std::{net::SocketAddr, num::NonZeroUsize, time::Duration};
 use vector_lib::ipallowlist::IpAllowlistConfig;
 
 use bytes::Bytes;
@@ -8,7 +8,9 @@ use chrono::Utc;
 use futures::StreamExt;
 use listenfd::ListenFd;
 use smallvec::SmallVec;
+use tokio::{net::UdpSocket, task::JoinSet};
 use tokio_util::udp::UdpFramed;
+use tracing::Instrument;
 use vector_lib::codecs::{
     decoding::{Deserializer, Framer},
     BytesDecoder, OctetCountingDecoder, SyslogDeserializerConfig,
@@ -30,7 +32,9 @@ use crate::{
     internal_events::{SocketBindError, SocketMode, SocketReceiveError},
     net,
     shutdown::ShutdownSignal,
-    sources::util::net::{try_bind_udp_socket, SocketListenAddr, TcpNullAcker, TcpSource},
+    sources::util::net::{
+        try_bind_udp_sockets, validate_udp_listeners, SocketListenAddr, TcpNullAcker, TcpSource,
+    },
     tcp::TcpKeepaliveConfig,
     tls::{MaybeTlsSettings, TlsSourceConfig},
     SourceSender,
@@ -107,6 +111,14 @@ pub enum Mode {
         /// This should not typically needed to be changed.
         #[configurable(metadata(docs::type_unit = "bytes"))]
         receive_buffer_bytes: Option<usize>,
+
+        /// The number of sockets listening on the address, each received from by its own task.
+        ///
+        /// If there is more than one, the sockets are bound with `SO_REUSEPORT` so that the kernel
+        /// spreads the messages received on the address between them. This is only supported on
+        /// Unix platforms that support `SO_REUSEPORT`, and not with sockets passed by systemd.
+        #[configurable(metadata(docs::examples = 4))]
+        listeners: Option<NonZeroUsize>,
     },
 
     /// Listen on UDS (Unix domain socket). This only supports Unix stream sockets.
@@ -215,15 +227,20 @@ impl SourceConfig for SyslogConfig {
             Mode::Udp {
                 address,
                 receive_buffer_bytes,
-            } => Ok(udp(
-                address,
-                self.max_length,
-                host_key,
-                receive_buffer_bytes,
-                cx.shutdown,
-                log_namespace,
-                cx.out,
-            )),
+                listeners,
+            } => {
+                validate_udp_listeners(address, listeners)?;
+                Ok(udp(
+                    address,
+                    self.max_length,
+                    host_key,
+                    receive_buffer_bytes,
+                    listeners,
+                    cx.shutdown,
+                    log_namespace,
+                    cx.out,
+                ))
+            }
             #[cfg(unix)]
             Mode::Unix {
                 path,
@@ -241,6 +258,8 @@ impl SourceConfig for SyslogConfig {
                 build_unix_stream_source(
                     path,
                     socket_file_mode,
+                    None,
+                    Default::default(),
                     decoder,
                     move |events, host| handle_events(events, &host_key, host, log_namespace),
                     cx.shutdown,
@@ -310,79 +329,115 @@ impl TcpSource for SyslogTcpSource {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn udp(
     addr: SocketListenAddr,
     _max_length: usize,
     host_key: Option<OwnedValuePath>,
     receive_buffer_bytes: Option<usize>,
+    listeners: Option<NonZeroUsize>,
     shutdown: ShutdownSignal,
     log_namespace: LogNamespace,
-    mut out: SourceSender,
+    out: SourceSender,
 ) -> super::Source {
     Box::pin(async move {
         let listenfd = ListenFd::from_env();
-        let socket = try_bind_udp_socket(addr, listenfd).await.map_err(|error| {
-            emit!(SocketBindError {
-                mode: SocketMode::Udp,
-                error: &error,
-            })
-        })?;
-
-        if let Some(receive_buffer_bytes) = receive_buffer_bytes {
-            if let Err(error) = net::set_receive_buffer_size(&socket, receive_buffer_bytes) {
-                warn!(message = "Failed configuring receive buffer size on UDP socket.", %error);
-            }
-        }
+        let sockets = try_bind_udp_sockets(addr, listenfd, listeners)
+            .await
+            .map_err(|error| {
+                emit!(SocketBindError {
+                    mode: SocketMode::Udp,
+                    error: &error,
+                })
+            })?;
 
         info!(
             message = "Listening.",
             addr = %addr,
-            r#type = "udp"
+            r#type = "udp",
+            listeners = sockets.len()
         );
 
-        let mut stream = UdpFramed::new(
-            socket,
-            Decoder::new(
-                Framer::Bytes(BytesDecoder::new()),
-                Deserializer::Syslog(
-                    SyslogDeserializerConfig::from_source(SyslogConfig::NAME).build(),
-                ),
-            ),
-        )
-        .take_until(shutdown)
-        .filter_map(|frame| {
-            let host_key = host_key.clone();
-            async move {
-                match frame {
-                    Ok(((mut events, _byte_size), received_from)) => {
-                        let received_from = received_from.ip().to_string().into();
-                        handle_events(&mut events, &host_key, Some(received_from), log_namespace);
-                        Some(events.remove(0))
-                    }
-                    Err(error) => {
-                        emit!(SocketReceiveError {
-                            mode: SocketMode::Udp,
-                            error: &error,
-                        });
-                        None
-                    }
-                }
-            }
-        })
-        .boxed();
+        let mut receivers = JoinSet::new();
+        for socket in sockets {
+            receivers.spawn(
+                receive_udp(
+                    socket,
+                    host_key.clone(),
+                    receive_buffer_bytes,
+                    shutdown.clone(),
+                    log_namespace,
+                    out.clone(),
+                )
+                .in_current_span(),
+            );
+        }
 
-        match out.send_event_stream(&mut stream).await {
-            Ok(()) => {
-                debug!("Finished sending.");
-                Ok(())
+        // The remaining listeners are stopped when the set is dropped, if one of them fails.
+        while let Some(result) = receivers.join_next().await {
+            if !matches!(result, Ok(Ok(()))) {
+                return Err(());
             }
-            Err(_) => {
-                let (count, _) = stream.size_hint();
-                emit!(StreamClosedError { count });
-                Err(())
+        }
+        Ok(())
+    })
+}
+
+/// Receives the messages of one of the sockets of the source.
+async fn receive_udp(
+    socket: UdpSocket,
+    host_key: Option<OwnedValuePath>,
+    receive_buffer_bytes: Option<usize>,
+    shutdown: ShutdownSignal,
+    log_namespace: LogNamespace,
+    mut out: SourceSender,
+) -> Result<(), ()> {
+    if let Some(receive_buffer_bytes) = receive_buffer_bytes {
+        if let Err(error) = net::set_receive_buffer_size(&socket, receive_buffer_bytes) {
+            warn!(message = "Failed configuring receive buffer size on UDP socket.", %error);
+        }
+    }
+
+    let mut stream = UdpFramed::new(
+        socket,
+        Decoder::new(
+            Framer::Bytes(BytesDecoder::new()),
+            Deserializer::Syslog(SyslogDeserializerConfig::from_source(SyslogConfig::NAME).build()),
+        ),
+    )
+    .take_until(shutdown)
+    .filter_map(|frame| {
+        let host_key = host_key.clone();
+        async move {
+            match frame {
+                Ok(((mut events, _byte_size), received_from)) => {
+                    let received_from = received_from.ip().to_string().into();
+                    handle_events(&mut events, &host_key, Some(received_from), log_namespace);
+                    Some(events.remove(0))
+                }
+                Err(error) => {
+                    emit!(SocketReceiveError {
+                        mode: SocketMode::Udp,
+                        error: &error,
+                    });
+                    None
+                }
             }
         }
     })
+    .boxed();
+
+    match out.send_event_stream(&mut stream).await {
+        Ok(()) => {
+            debug!("Finished sending.");
+            Ok(())
+        }
+        Err(_) => {
+            let (count, _) = stream.size_hint();
+            emit!(StreamClosedError { count });
+            Err(())
+        }
+    }
 }
 
 fn handle_events(
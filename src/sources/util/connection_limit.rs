@@ -0,0 +1,238 @@
+//! Limits on the number of connections open at once on the listeners of stream-based sources.
+use std::{fmt, sync::Arc};
+
+use tokio::sync::{Notify, OwnedSemaphorePermit, Semaphore};
+use vector_lib::configurable::configurable_component;
+
+use crate::internal_events::{
+    OpenGauge, OpenToken, SocketConnectionAccepted, SocketConnectionRejected,
+    SocketConnectionsOpen, SocketMode,
+};
+
+/// How connections opened beyond the connection limit are handled.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionLimitMode {
+    /// New connections are not accepted until an open connection is closed.
+    ///
+    /// They wait in the listen backlog of the socket in the meantime.
+    #[default]
+    Queue,
+
+    /// New connections are accepted, then closed right away.
+    Reject,
+}
+
+/// Tracks the connections open on a listener, and enforces its connection limit.
+#[derive(Clone)]
+pub struct ConnectionLimiter {
+    mode: SocketMode,
+    listener: Arc<str>,
+    max_connections: Option<u32>,
+    limit_mode: ConnectionLimitMode,
+    semaphore: Option<Arc<Semaphore>>,
+    gauge: OpenGauge,
+    closed: Arc<Notify>,
+}
+
+/// An accepted connection, counted as open until dropped.
+pub struct OpenConnection {
+    _permit: Option<OwnedSemaphorePermit>,
+    _token: OpenToken<Box<dyn Fn(usize) + Send + Sync>>,
+    // Dropped last, once the connection is no longer counted as open.
+    _closed: NotifyOnDrop,
+}
+
+/// Wakes up the tasks waiting for connections to close when dropped.
+struct NotifyOnDrop(Arc<Notify>);
+
+impl Drop for NotifyOnDrop {
+    fn drop(&mut self) {
+        self.0.notify_waiters();
+    }
+}
+
+impl ConnectionLimiter {
+    pub fn new(
+        mode: SocketMode,
+        listener: impl Into<Arc<str>>,
+        max_connections: Option<u32>,
+        limit_mode: ConnectionLimitMode,
+    ) -> Self {
+        Self {
+            mode,
+            listener: listener.into(),
+            max_connections,
+            limit_mode,
+            semaphore: max_connections.map(|max| Arc::new(Semaphore::new(max as usize))),
+            gauge: OpenGauge::new(),
+            closed: Arc::new(Notify::new()),
+        }
+    }
+
+    /// The limit to apply before accepting connections, when they are queued.
+    pub const fn queue_limit(&self) -> Option<u32> {
+        match self.limit_mode {
+            ConnectionLimitMode::Queue => self.max_connections,
+            ConnectionLimitMode::Reject => None,
+        }
+    }
+
+    /// Waits until a connection can be accepted, when connections are queued.
+    #[cfg(all(unix, feature = "sources-utils-net-unix"))]
+    pub async fn wait_for_slot(&self) -> Option<OwnedSemaphorePermit> {
+        match (&self.semaphore, self.limit_mode) {
+            (Some(semaphore), ConnectionLimitMode::Queue) => {
+                semaphore.clone().acquire_owned().await.ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Admits a connection accepted from `peer`, unless it goes over the connection limit.
+    ///
+    /// The `permit` is the one acquired before accepting the connection, if it was queued.
+    pub fn admit(
+        &self,
+        permit: Option<OwnedSemaphorePermit>,
+        peer: impl fmt::Display,
+    ) -> Option<OpenConnection> {
+        let permit = match (permit, &self.semaphore) {
+            (Some(permit), _) => Some(permit),
+            (None, Some(semaphore)) if self.limit_mode == ConnectionLimitMode::Reject => {
+                match semaphore.clone().try_acquire_owned() {
+                    Ok(permit) => Some(permit),
+                    Err(_) => {
+                        emit!(SocketConnectionRejected {
+                            mode: self.mode,
+                            listener: &self.listener,
+                            peer: &peer.to_string(),
+                            max_connections: self.max_connections.unwrap_or_default(),
+                        });
+                        return None;
+                    }
+                }
+            }
+            (None, _) => None,
+        };
+
+        emit!(SocketConnectionAccepted {
+            mode: self.mode,
+            listener: &self.listener,
+        });
+        let mode = self.mode;
+        let listener = Arc::clone(&self.listener);
+        let token = self.gauge.clone().open(Box::new(move |count| {
+            emit!(SocketConnectionsOpen {
+                mode,
+                listener: &listener,
+                count,
+            })
+        }) as Box<dyn Fn(usize) + Send + Sync>);
+
+        Some(OpenConnection {
+            _permit: permit,
+            _token: token,
+            _closed: NotifyOnDrop(Arc::clone(&self.closed)),
+        })
+    }
+
+    /// Whether any admitted connection is still open.
+    pub fn any_open(&self) -> bool {
+        self.gauge.any_open()
+    }
+
+    /// Waits until every admitted connection is closed.
+    pub async fn all_closed(&self) {
+        loop {
+            // Connections closing once this is created wake it up, even before it is polled.
+            let closed = self.closed.notified();
+            if !self.any_open() {
+                return;
+            }
+            closed.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio_test::{assert_pending, assert_ready, task};
+
+    use super::*;
+
+    #[test]
+    fn rejects_connections_over_the_limit() {
+        let limiter = ConnectionLimiter::new(
+            SocketMode::Tcp,
+            "test",
+            Some(2),
+            ConnectionLimitMode::Reject,
+        );
+        assert_eq!(limiter.queue_limit(), None);
+
+        let first = limiter.admit(None, "peer").expect("first connection");
+        let _second = limiter.admit(None, "peer").expect("second connection");
+        assert!(limiter.admit(None, "peer").is_none());
+
+        drop(first);
+        assert!(limiter.admit(None, "peer").is_some());
+    }
+
+    #[cfg(all(unix, feature = "sources-utils-net-unix"))]
+    #[tokio::test]
+    async fn admits_queued_connections() {
+        let limiter = ConnectionLimiter::new(
+            SocketMode::Unix,
+            "test",
+            Some(1),
+            ConnectionLimitMode::Queue,
+        );
+        assert_eq!(limiter.queue_limit(), Some(1));
+
+        // Queued connections wait for their permits before being accepted.
+        let permit = limiter.wait_for_slot().await;
+        assert!(permit.is_some());
+        let first = limiter.admit(permit, "peer").expect("first connection");
+
+        let mut second = task::spawn(limiter.wait_for_slot());
+        assert_pending!(second.poll());
+
+        drop(first);
+        assert!(second.is_woken());
+        let permit = assert_ready!(second.poll());
+        assert!(permit.is_some());
+        assert!(limiter.admit(permit, "peer").is_some());
+    }
+
+    #[tokio::test]
+    async fn waits_for_connections_to_close() {
+        let limiter =
+            ConnectionLimiter::new(SocketMode::Tcp, "test", None, ConnectionLimitMode::Queue);
+        let first = limiter.admit(None, "peer").expect("first connection");
+        let second = limiter.admit(None, "peer").expect("second connection");
+
+        let mut closed = task::spawn(limiter.all_closed());
+        assert_pending!(closed.poll());
+
+        drop(first);
+        assert!(closed.is_woken());
+        assert_pending!(closed.poll());
+
+        drop(second);
+        assert!(closed.is_woken());
+        assert_ready!(closed.poll());
+    }
+
+    #[test]
+    fn tracks_open_connections_without_a_limit() {
+        let limiter =
+            ConnectionLimiter::new(SocketMode::Unix, "test", None, ConnectionLimitMode::Reject);
+        let connection = limiter.admit(None, "peer").expect("connection");
+        assert!(limiter.any_open());
+
+        drop(connection);
+        assert!(!limiter.any_open());
+    }
+}
@@ -266,7 +266,11 @@ async fn handle_request(
 ) -> Result<impl warp::Reply, Rejection> {
     match events {
         Ok(mut events) => {
-            let receiver = BatchNotifier::maybe_apply_to(acknowledgements, &mut events);
+            let receiver = BatchNotifier::maybe_apply_tracked_to(
+                acknowledgements,
+                &mut events,
+                out.unacknowledged_events(),
+            );
 
             let count = events.len();
             out.send_batch(events)
@@ -1,6 +1,11 @@
 #![allow(missing_docs)]
 #[cfg(feature = "sources-http_server")]
 mod body_decoding;
+#[cfg(any(
+    feature = "sources-utils-net-tcp",
+    all(unix, feature = "sources-utils-net-unix")
+))]
+pub mod connection_limit;
 mod encoding_config;
 #[cfg(all(unix, feature = "sources-dnstap"))]
 pub mod framestream;
@@ -16,7 +16,7 @@ pub use self::tcp::{
     TcpSourceAcker, MAX_IN_FLIGHT_EVENTS_TARGET,
 };
 #[cfg(feature = "sources-utils-net-udp")]
-pub use self::udp::try_bind_udp_socket;
+pub use self::udp::{try_bind_udp_socket, try_bind_udp_sockets, validate_udp_listeners};
 
 #[derive(Clone, Debug, Eq, PartialEq, Snafu)]
 pub enum SocketListenAddrParseError {
@@ -12,7 +12,7 @@ use socket2::SockRef;
 use tokio::{
     io::AsyncWriteExt,
     net::{TcpListener, TcpStream},
-    time::sleep,
+    time::{sleep, Instant},
 };
 use tokio_util::codec::{Decoder, FramedRead};
 use tracing::Instrument;
@@ -32,12 +32,15 @@ use crate::{
     config::SourceContext,
     event::{BatchNotifier, BatchStatus, Event},
     internal_events::{
-        ConnectionOpen, DecoderFramingError, OpenGauge, SocketBindError, SocketEventsReceived,
-        SocketMode, SocketReceiveError, StreamClosedError, TcpBytesReceived, TcpSendAckError,
+        DecoderFramingError, SocketBindError, SocketEventsReceived, SocketMode, SocketReceiveError,
+        StreamClosedError, TcpBytesReceived, TcpConnectionIdleTimeout, TcpSendAckError,
         TcpSocketTlsConnectionError,
     },
     shutdown::ShutdownSignal,
-    sources::util::AfterReadExt,
+    sources::util::{
+        connection_limit::{ConnectionLimitMode, ConnectionLimiter},
+        AfterReadExt,
+    },
     tcp::TcpKeepaliveConfig,
     tls::{CertificateMetadata, MaybeTlsIncomingStream, MaybeTlsListener, MaybeTlsSettings},
     SourceSender,
@@ -108,6 +111,16 @@ where
 
     fn build_acker(&self, item: &[Self::Item]) -> Self::Acker;
 
+    /// How connections opened beyond `max_connections` are handled.
+    fn connection_limit_mode(&self) -> ConnectionLimitMode {
+        ConnectionLimitMode::Queue
+    }
+
+    /// The time after which connections that didn't send a complete frame are closed.
+    fn idle_timeout(&self) -> Option<Duration> {
+        None
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn run(
         self,
@@ -138,13 +151,11 @@ where
                     })
                 })?;
 
-            info!(
-                message = "Listening.",
-                addr = %listener
-                    .local_addr()
-                    .map(SocketListenAddr::SocketAddr)
-                    .unwrap_or(addr)
-            );
+            let listener_addr = listener
+                .local_addr()
+                .map(SocketListenAddr::SocketAddr)
+                .unwrap_or(addr);
+            info!(message = "Listening.", addr = %listener_addr);
 
             let tripwire = cx.shutdown.clone();
             let tripwire = async move {
@@ -153,21 +164,27 @@ where
             }
             .shared();
 
-            let connection_gauge = OpenGauge::new();
+            let connection_limiter = ConnectionLimiter::new(
+                SocketMode::Tcp,
+                listener_addr.to_string(),
+                max_connections,
+                self.connection_limit_mode(),
+            );
+            let open_connections = connection_limiter.clone();
             let shutdown_clone = cx.shutdown.clone();
 
             let request_limiter =
                 RequestLimiter::new(MAX_IN_FLIGHT_EVENTS_TARGET, crate::num_threads());
 
             listener
-                .accept_stream_limited(max_connections)
+                .accept_stream_limited(connection_limiter.queue_limit())
                 .take_until(shutdown_clone)
                 .for_each(move |(connection, tcp_connection_permit)| {
                     let shutdown_signal = cx.shutdown.clone();
                     let tripwire = tripwire.clone();
                     let source = self.clone();
                     let out = cx.out.clone();
-                    let connection_gauge = connection_gauge.clone();
+                    let connection_limiter = connection_limiter.clone();
                     let request_limiter = request_limiter.clone();
                     let tls_client_metadata_key = tls_client_metadata_key.clone();
 
@@ -184,6 +201,11 @@ where
                         };
 
                         let peer_addr = socket.peer_addr();
+                        let Some(open_connection) =
+                            connection_limiter.admit(tcp_connection_permit, peer_addr)
+                        else {
+                            return;
+                        };
                         let span = info_span!("connection", %peer_addr);
 
                         let tripwire = tripwire
@@ -198,9 +220,6 @@ where
                         span.clone().in_scope(|| {
                             debug!(message = "Accepted a new connection.", peer_addr = %peer_addr);
 
-                            let open_token =
-                                connection_gauge.open(|count| emit!(ConnectionOpen { count }));
-
                             let fut = handle_stream(
                                 shutdown_signal,
                                 socket,
@@ -219,17 +238,19 @@ where
                             );
 
                             tokio::spawn(
-                                fut.map(move |()| {
-                                    drop(open_token);
-                                    drop(tcp_connection_permit);
-                                })
-                                .instrument(span.or_current()),
+                                fut.map(move |()| drop(open_connection))
+                                    .instrument(span.or_current()),
                             );
                         });
                     }
                 })
-                .map(Ok)
-                .await
+                .await;
+
+            // New connections are no longer accepted, but the open ones are given until the
+            // shutdown timeout to finish sending their events.
+            open_connections.all_closed().await;
+
+            Ok(())
         }))
     }
 }
@@ -301,7 +322,13 @@ async fn handle_stream<T>(
 
     tokio::pin!(connection_close_timeout);
 
+    let idle_timeout = source.idle_timeout();
+    let idle_timer = sleep(idle_timeout.unwrap_or_default());
+    tokio::pin!(idle_timer);
+
     loop {
+        // Time spent waiting for a permit is spent not reading, so it doesn't count as idle.
+        let waiting_since = Instant::now();
         let mut permit = tokio::select! {
             _ = &mut tripwire => break,
             Some(_) = &mut connection_close_timeout  => {
@@ -321,12 +348,20 @@ async fn handle_stream<T>(
             }
             else => break,
         };
+        if idle_timeout.is_some() {
+            let deadline = idle_timer.deadline() + waiting_since.elapsed();
+            idle_timer.as_mut().reset(deadline);
+        }
 
         let timeout = tokio::time::sleep(Duration::from_millis(10));
         tokio::pin!(timeout);
 
         tokio::select! {
             _ = &mut tripwire => break,
+            _ = &mut idle_timer, if idle_timeout.is_some() => {
+                emit!(TcpConnectionIdleTimeout { peer_addr, timeout: idle_timeout.unwrap_or_default() });
+                break;
+            },
             _ = &mut shutdown_signal => {
                 if close_socket(reader.get_ref().get_ref().get_ref()) {
                     break;
@@ -342,7 +377,10 @@ async fn handle_stream<T>(
                     Some(Ok((frames, _byte_size))) => {
                         let _num_frames = frames.len();
                         let acker = source.build_acker(&frames);
-                        let (batch, receiver) = BatchNotifier::maybe_new_with_receiver(acknowledgements);
+                        let (batch, receiver) = BatchNotifier::maybe_new_tracked_with_receiver(
+                            acknowledgements,
+                            out.unacknowledged_events(),
+                        );
 
                         let mut events = frames.into_iter().flat_map(Into::into).collect::<Vec<Event>>();
                         let count = events.len();
@@ -398,6 +436,12 @@ async fn handle_stream<T>(
                                             }
                                         }
                                 };
+                                // The idle timer isn't polled while a batch is in flight. Restart it once the batch
+                                // is sent and acknowledged, so that time spent waiting on the output doesn't count as
+                                // idle.
+                                if let Some(idle_timeout) = idle_timeout {
+                                    idle_timer.as_mut().reset(Instant::now() + idle_timeout);
+                                }
                                 if let Some(ack_bytes) = acker.build_ack(ack){
                                     let stream = reader.get_mut().get_mut();
                                     if let Err(error) = stream.write_all(&ack_bytes).await {
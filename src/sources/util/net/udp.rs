@@ -1,10 +1,16 @@
-use std::io;
+use std::{io, net::SocketAddr, num::NonZeroUsize};
 
 use listenfd::ListenFd;
 use tokio::net::UdpSocket;
 
 use super::SocketListenAddr;
 
+/// Whether UDP sockets can share their address with `SO_REUSEPORT` on this platform.
+const REUSE_PORT_SUPPORTED: bool = cfg!(all(
+    unix,
+    not(any(target_os = "solaris", target_os = "illumos"))
+));
+
 /// Binds a UDP socket to the listen address.
 pub async fn try_bind_udp_socket(
     addr: SocketListenAddr,
@@ -21,3 +27,99 @@ pub async fn try_bind_udp_socket(
         },
     }
 }
+
+/// Checks that a listen address can be shared by the given number of UDP sockets.
+pub fn validate_udp_listeners(
+    addr: SocketListenAddr,
+    listeners: Option<NonZeroUsize>,
+) -> crate::Result<()> {
+    if listeners.map_or(1, NonZeroUsize::get) == 1 {
+        return Ok(());
+    }
+    if !REUSE_PORT_SUPPORTED {
+        return Err("Multiple UDP listeners require `SO_REUSEPORT`, which is not supported on this platform.".into());
+    }
+    if let SocketListenAddr::SystemdFd(_) = addr {
+        return Err("Multiple UDP listeners can't share a socket passed by systemd.".into());
+    }
+    Ok(())
+}
+
+/// Binds the given number of UDP sockets to the listen address.
+///
+/// If there is more than one, the sockets are bound with `SO_REUSEPORT` so that the kernel
+/// spreads the datagrams received on the address between them.
+pub async fn try_bind_udp_sockets(
+    addr: SocketListenAddr,
+    listenfd: ListenFd,
+    listeners: Option<NonZeroUsize>,
+) -> io::Result<Vec<UdpSocket>> {
+    let listeners = listeners.map_or(1, NonZeroUsize::get);
+    if listeners == 1 {
+        return Ok(vec![try_bind_udp_socket(addr, listenfd).await?]);
+    }
+
+    let SocketListenAddr::SocketAddr(mut addr) = addr else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Multiple UDP listeners can't share a socket passed by systemd.",
+        ));
+    };
+
+    let mut sockets = Vec::with_capacity(listeners);
+    for _ in 0..listeners {
+        let socket = bind_reuse_port(addr)?;
+        // The other sockets are bound to the port picked for the first one, if any was.
+        addr = socket.local_addr()?;
+        sockets.push(socket);
+    }
+    Ok(sockets)
+}
+
+#[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+fn bind_reuse_port(addr: SocketAddr) -> io::Result<UdpSocket> {
+    use socket2::{Domain, Protocol, Socket, Type};
+
+    let socket = Socket::new(Domain::for_address(addr), Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_reuse_port(true)?;
+    socket.set_nonblocking(true)?;
+    socket.bind(&addr.into())?;
+    UdpSocket::from_std(socket.into())
+}
+
+#[cfg(not(all(unix, not(any(target_os = "solaris", target_os = "illumos")))))]
+fn bind_reuse_port(_addr: SocketAddr) -> io::Result<UdpSocket> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "`SO_REUSEPORT` is not supported on this platform.",
+    ))
+}
+
+#[cfg(all(test, unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn binds_sockets_sharing_an_address() {
+        let addr = SocketListenAddr::SocketAddr("127.0.0.1:0".parse().unwrap());
+        let listeners = NonZeroUsize::new(3);
+        validate_udp_listeners(addr, listeners).unwrap();
+
+        let sockets = try_bind_udp_sockets(addr, ListenFd::empty(), listeners)
+            .await
+            .unwrap();
+        assert_eq!(sockets.len(), 3);
+        let addr = sockets[0].local_addr().unwrap();
+        assert_ne!(addr.port(), 0);
+        for socket in &sockets {
+            assert_eq!(socket.local_addr().unwrap(), addr);
+        }
+    }
+
+    #[test]
+    fn rejects_multiple_listeners_on_systemd_sockets() {
+        let addr = SocketListenAddr::SystemdFd(0);
+        assert!(validate_udp_listeners(addr, NonZeroUsize::new(1)).is_ok());
+        assert!(validate_udp_listeners(addr, NonZeroUsize::new(2)).is_err());
+    }
+}
@@ -1,11 +1,10 @@
-use std::{fs::remove_file, path::PathBuf, time::Duration};
+use std::{fs::remove_file, path::PathBuf};
 
 use bytes::Bytes;
 use futures::{FutureExt, StreamExt};
 use tokio::{
     io::AsyncWriteExt,
     net::{UnixListener, UnixStream},
-    time::sleep,
 };
 use tokio_stream::wrappers::UnixListenerStream;
 use tokio_util::codec::FramedRead;
@@ -20,11 +19,12 @@ use crate::{
     codecs::Decoder,
     event::Event,
     internal_events::{
-        ConnectionOpen, OpenGauge, SocketEventsReceived, SocketMode, StreamClosedError,
-        UnixSocketError, UnixSocketFileDeleteError,
+        SocketEventsReceived, SocketMode, StreamClosedError, UnixSocketError,
+        UnixSocketFileDeleteError,
     },
     shutdown::ShutdownSignal,
     sources::util::change_socket_permissions,
+    sources::util::connection_limit::{ConnectionLimitMode, ConnectionLimiter},
     sources::util::unix::UNNAMED_SOCKET_HOST,
     sources::Source,
     SourceSender,
@@ -34,9 +34,12 @@ use crate::{
 /// Passing in different functions for `decoder` and `handle_events` can allow
 /// for different source-specific logic (such as decoding syslog messages in the
 /// syslog source).
+#[allow(clippy::too_many_arguments)]
 pub fn build_unix_stream_source(
     listen_path: PathBuf,
     socket_file_mode: Option<u32>,
+    max_connections: Option<u32>,
+    connection_limit_mode: ConnectionLimitMode,
     decoder: Decoder,
     handle_events: impl Fn(&mut [Event], Option<Bytes>) + Clone + Send + Sync + 'static,
     shutdown: ShutdownSignal,
@@ -57,10 +60,19 @@ pub fn build_unix_stream_source(
 
         let bytes_received = register!(BytesReceived::from(Protocol::UNIX));
 
-        let connection_open = OpenGauge::new();
+        let connection_limiter = ConnectionLimiter::new(
+            SocketMode::Unix,
+            listen_path.to_string_lossy(),
+            max_connections,
+            connection_limit_mode,
+        );
         let stream = UnixListenerStream::new(listener).take_until(shutdown.clone());
         tokio::pin!(stream);
-        while let Some(socket) = stream.next().await {
+        loop {
+            let permit = connection_limiter.wait_for_slot().await;
+            let Some(socket) = stream.next().await else {
+                break;
+            };
             let socket = match socket {
                 Err(error) => {
                     error!(message = "Failed to accept socket.", %error);
@@ -90,6 +102,12 @@ pub fn build_unix_stream_source(
                 // host value.
                 .unwrap_or_else(|| UNNAMED_SOCKET_HOST.into());
 
+            let Some(open_connection) =
+                connection_limiter.admit(permit, String::from_utf8_lossy(&received_from))
+            else {
+                continue;
+            };
+
             let handle_events = handle_events.clone();
 
             let bytes_received = bytes_received.clone();
@@ -100,11 +118,10 @@ pub fn build_unix_stream_source(
                 .allow_read_until(shutdown.clone().map(|_| ()));
             let mut stream = FramedRead::new(stream, decoder.clone());
 
-            let connection_open = connection_open.clone();
             let mut out = out.clone();
             tokio::spawn(
                 async move {
-                    let _open_token = connection_open.open(|count| emit!(ConnectionOpen { count }));
+                    let _open_connection = open_connection;
 
                     while let Some(result) = stream.next().await {
                         match result {
@@ -147,9 +164,7 @@ pub fn build_unix_stream_source(
         }
 
         // Wait for open connections to finish
-        while connection_open.any_open() {
-            sleep(Duration::from_millis(10)).await;
-        }
+        connection_limiter.all_closed().await;
 
         // Delete socket file
         if let Err(error) = remove_file(&listen_path) {
@@ -71,7 +71,11 @@ impl proto::Service for Service {
         let events_received = register!(EventsReceived);
         events_received.emit(CountByteSize(count, byte_size));
 
-        let receiver = BatchNotifier::maybe_apply_to(self.acknowledgements, &mut events);
+        let receiver = BatchNotifier::maybe_apply_tracked_to(
+            self.acknowledgements,
+            &mut events,
+            self.pipeline.unacknowledged_events(),
+        );
 
         self.pipeline
             .clone()
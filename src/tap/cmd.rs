@@ -39,13 +39,17 @@ pub(crate) async fn cmd(opts: &super::Opts, signal_rx: SignalRx) -> exitcode::Ex
 pub async fn tap(opts: &super::Opts, mut signal_rx: SignalRx) -> exitcode::ExitCode {
     let subscription_url = opts.web_socket_url();
     let output_channel = OutputChannel::Stdout(EventFormatter::new(opts.meta, opts.format));
-    let tap_runner = TapRunner::new(
+    let mut tap_runner = TapRunner::new(
         &subscription_url,
         opts.inputs_of.clone(),
         opts.outputs_patterns().clone(),
         &output_channel,
         opts.format,
     );
+    if let Some(component) = &opts.component {
+        tap_runner =
+            tap_runner.with_encoding_sink(component.clone(), opts.max_payload_bytes as i64);
+    }
 
     loop {
         tokio::select! {
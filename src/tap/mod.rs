@@ -26,9 +26,20 @@ pub struct Opts {
     limit: u32,
 
     /// Encoding format for events printed to screen
+    ///
+    /// The `encoded` format prints the payloads that the sink given with `--component` encodes for
+    /// the events it receives, instead of the events themselves.
     #[arg(default_value = "json", short = 'f', long)]
     format: TapEncodingFormat,
 
+    /// Sink ID whose encoding to preview with the `encoded` format
+    #[arg(long, required_if_eq("format", "encoded"))]
+    component: Option<String>,
+
+    /// Maximum size of the encoded payloads printed, in bytes, beyond which they are truncated
+    #[arg(default_value = "4096", long)]
+    max_payload_bytes: u32,
+
     /// Components IDs to observe (comma-separated; accepts glob patterns)
     #[arg(value_delimiter(','))]
     component_id_patterns: Vec<String>,
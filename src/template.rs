@@ -11,6 +11,8 @@ use snafu::Snafu;
 use vector_lib::configurable::{configurable_component, ConfigurableString};
 use vector_lib::lookup::lookup_v2::parse_target_path;
 
+use vector_lib::config::{timestamp_settings, TimestampSettings};
+
 use crate::{
     config::log_schema,
     event::{EventRef, Metric, Value},
@@ -26,6 +28,8 @@ pub enum TemplateParseError {
     StrftimeError,
     #[snafu(display("Invalid field path in template {:?} (see https://vector.dev/docs/reference/configuration/template-syntax/)", path))]
     InvalidPathSyntax { path: String },
+    #[snafu(display("Invalid fallback value in template {:?} (see https://vector.dev/docs/reference/configuration/template-syntax/)", fallback))]
+    InvalidFallback { fallback: String },
 }
 
 /// Errors raised whilst rendering a Template.
@@ -108,7 +112,9 @@ impl TryFrom<Cow<'_, str>> for Template {
                     Part::Literal(lit) => lit.len(),
                     // We can't really put a useful number here, assume at least one byte will come
                     // from the input event.
-                    Part::Reference(_path) => 1,
+                    Part::Reference(reference) => {
+                        reference.fallback.as_ref().map_or(1, String::len)
+                    }
                     Part::Strftime(parsed) => parsed.reserve_size(),
                 })
                 .sum();
@@ -168,32 +174,18 @@ impl Template {
     fn render_event(&self, event: EventRef<'_>) -> Result<String, TemplateRenderingError> {
         let mut missing_keys = Vec::new();
         let mut out = String::with_capacity(self.reserve_size);
+        let timestamps = timestamp_settings();
         for part in &self.parts {
             match part {
                 Part::Literal(lit) => out.push_str(lit),
                 Part::Strftime(items) => {
                     out.push_str(&render_timestamp(items, event, self.tz_offset))
                 }
-                Part::Reference(key) => {
-                    out.push_str(
-                        &match event {
-                            EventRef::Log(log) => log
-                                .parse_path_and_get_value(key)
-                                .ok()
-                                .and_then(|v| v.map(Value::to_string_lossy)),
-                            EventRef::Metric(metric) => {
-                                render_metric_field(key, metric).map(Cow::Borrowed)
-                            }
-                            EventRef::Trace(trace) => trace
-                                .parse_path_and_get_value(key)
-                                .ok()
-                                .and_then(|v| v.map(Value::to_string_lossy)),
-                        }
-                        .unwrap_or_else(|| {
-                            missing_keys.push(key.to_owned());
-                            Cow::Borrowed("")
-                        }),
-                    );
+                Part::Reference(reference) => {
+                    out.push_str(&reference.render(event, timestamps).unwrap_or_else(|| {
+                        missing_keys.extend(reference.paths.iter().cloned());
+                        Cow::Borrowed("")
+                    }));
                 }
             }
         }
@@ -204,18 +196,20 @@ impl Template {
         }
     }
 
-    /// Returns the names of the fields that are rendered in this template.
+    /// Returns the names of the fields that are rendered in this template, fallback fields
+    /// included.
     pub fn get_fields(&self) -> Option<Vec<String>> {
         let parts: Vec<_> = self
             .parts
             .iter()
             .filter_map(|part| {
                 if let Part::Reference(r) = part {
-                    Some(r.to_owned())
+                    Some(r.paths.iter().cloned())
                 } else {
                     None
                 }
             })
+            .flatten()
             .collect();
         (!parts.is_empty()).then_some(parts)
     }
@@ -244,7 +238,52 @@ enum Part {
     /// A literal piece of text containing a time format string.
     Strftime(ParsedStrftime),
     /// A reference to the source event, to be copied from the relevant field or tag.
-    Reference(String),
+    Reference(Reference),
+}
+
+/// A reference to fields of the source event, such as `{{ service ?? app ?? "unknown" }}`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Reference {
+    /// The paths of the fields, tried in order.
+    paths: Vec<String>,
+    /// The text rendered when none of the fields are present.
+    fallback: Option<String>,
+}
+
+impl Reference {
+    /// Renders the first of the fields that is present, and not null unless it is the last
+    /// resort, or the fallback text if none is.
+    fn render<'a>(
+        &'a self,
+        event: EventRef<'a>,
+        timestamps: TimestampSettings,
+    ) -> Option<Cow<'a, str>> {
+        let last = self.paths.len() - 1;
+        self.paths
+            .iter()
+            .enumerate()
+            .find_map(|(index, path)| {
+                let skip_null = index < last || self.fallback.is_some();
+                match event {
+                    EventRef::Log(log) => log
+                        .parse_path_and_get_value(path)
+                        .ok()
+                        .flatten()
+                        .filter(|value| !(skip_null && value.is_null()))
+                        .map(|value| render_value(value, timestamps)),
+                    EventRef::Metric(metric) => {
+                        render_metric_field(path, metric).map(Cow::Borrowed)
+                    }
+                    EventRef::Trace(trace) => trace
+                        .parse_path_and_get_value(path)
+                        .ok()
+                        .flatten()
+                        .filter(|value| !(skip_null && value.is_null()))
+                        .map(|value| render_value(value, timestamps)),
+                }
+            })
+            .or_else(|| self.fallback.as_deref().map(Cow::Borrowed))
+    }
 }
 
 // Wrap the parsed time formatter in order to provide `impl Hash` and some convenience functions.
@@ -327,15 +366,7 @@ fn parse_template(src: &str) -> Result<Vec<Part>, TemplateParseError> {
             parts.push(parse_literal(&src[last_end..all.start()])?);
         }
 
-        let path = cap[1].trim().to_owned();
-
-        // This checks the syntax, but doesn't yet store it for use later
-        // see: https://github.com/vectordotdev/vector/issues/14864
-        if parse_target_path(&path).is_err() {
-            return Err(TemplateParseError::InvalidPathSyntax { path });
-        }
-
-        parts.push(Part::Reference(path));
+        parts.push(Part::Reference(parse_reference(&cap[1])?));
         last_end = all.end();
     }
     if src.len() > last_end {
@@ -345,6 +376,98 @@ fn parse_template(src: &str) -> Result<Vec<Part>, TemplateParseError> {
     Ok(parts)
 }
 
+/// Parses a reference to fields, made of field paths separated by `??`, the last of which can be
+/// replaced with a double-quoted fallback text.
+fn parse_reference(src: &str) -> Result<Reference, TemplateParseError> {
+    let mut paths = split_fallbacks(src)
+        .into_iter()
+        .map(str::trim)
+        .collect::<Vec<_>>();
+    // A single quoted string is a field path, as it always was, not a fallback.
+    let fallback = match paths.last() {
+        Some(last) if paths.len() > 1 && last.starts_with('"') => {
+            let fallback =
+                parse_fallback(last).ok_or_else(|| TemplateParseError::InvalidFallback {
+                    fallback: (*last).to_owned(),
+                })?;
+            paths.pop();
+            Some(fallback)
+        }
+        _ => None,
+    };
+
+    let chained = paths.len() > 1 || fallback.is_some();
+    let paths = paths
+        .into_iter()
+        .map(|path| {
+            // This checks the syntax, but doesn't yet store it for use later
+            // see: https://github.com/vectordotdev/vector/issues/14864
+            if (chained && path.is_empty()) || parse_target_path(path).is_err() {
+                return Err(TemplateParseError::InvalidPathSyntax {
+                    path: path.to_owned(),
+                });
+            }
+            Ok(path.to_owned())
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Reference { paths, fallback })
+}
+
+/// Splits a reference on the `??` separators outside of double quotes.
+fn split_fallbacks(src: &str) -> Vec<&str> {
+    let mut alternatives = Vec::new();
+    let mut start = 0;
+    let mut quoted = false;
+    let mut escaped = false;
+    let mut chars = src.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '?' if !quoted && chars.next_if(|(_, c)| *c == '?').is_some() => {
+                alternatives.push(&src[start..index]);
+                start = index + 2;
+            }
+            _ => {}
+        }
+    }
+    alternatives.push(&src[start..]);
+    alternatives
+}
+
+/// Parses a double-quoted fallback text, in which `\"` and `\\` stand for a quote and a backslash.
+fn parse_fallback(src: &str) -> Option<String> {
+    let inner = src.strip_prefix('"')?.strip_suffix('"')?;
+    let mut fallback = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                c @ ('"' | '\\') => fallback.push(c),
+                c => {
+                    fallback.push('\\');
+                    fallback.push(c);
+                }
+            },
+            '"' => return None,
+            c => fallback.push(c),
+        }
+    }
+    Some(fallback)
+}
+
+/// Renders the value of a field, with timestamps represented according to the given settings.
+fn render_value(value: &Value, timestamps: TimestampSettings) -> Cow<'_, str> {
+    match value {
+        Value::Timestamp(timestamp) => match timestamps.format(timestamp) {
+            Some(formatted) => Cow::Owned(formatted.to_string_lossy().into_owned()),
+            None => value.to_string_lossy(),
+        },
+        value => value.to_string_lossy(),
+    }
+}
+
 fn render_metric_field<'a>(key: &str, metric: &'a Metric) -> Option<&'a str> {
     match key {
         "name" => Some(metric.name()),
@@ -391,7 +514,7 @@ fn render_timestamp(
 mod tests {
     use chrono::{Offset, TimeZone, Utc};
     use chrono_tz::Tz;
-    use vector_lib::config::LogNamespace;
+    use vector_lib::config::{LogNamespace, TimestampFormat, TimestampPrecision};
     use vector_lib::lookup::{metadata_path, PathPrefix};
     use vector_lib::metric_tags;
 
@@ -514,6 +637,147 @@ mod tests {
         )
     }
 
+    #[test]
+    fn render_timestamp_fields() {
+        let timestamp = Value::Timestamp(
+            Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5)
+                .single()
+                .expect("invalid timestamp")
+                + chrono::Duration::microseconds(123_456),
+        );
+        let render = |format, precision| {
+            render_value(&timestamp, TimestampSettings { format, precision }).into_owned()
+        };
+
+        assert_eq!(render(None, None), "2024-01-02T03:04:05.123456Z");
+        assert_eq!(
+            render(None, Some(TimestampPrecision::Millis)),
+            "2024-01-02T03:04:05.123Z"
+        );
+        assert_eq!(
+            render(
+                Some(TimestampFormat::Unix),
+                Some(TimestampPrecision::Millis)
+            ),
+            "1704164645"
+        );
+        assert_eq!(
+            render_value(&Value::from(1), TimestampSettings::default()),
+            "1"
+        );
+    }
+
+    #[test]
+    fn parse_fallbacks() {
+        let reference = |src| match Template::try_from(src).unwrap().parts.as_slice() {
+            [Part::Reference(reference)] => reference.clone(),
+            parts => panic!("unexpected parts {parts:?}"),
+        };
+
+        assert_eq!(
+            reference("{{ service ?? .app.name ?? \"unknown\" }}"),
+            Reference {
+                paths: vec!["service".into(), ".app.name".into()],
+                fallback: Some("unknown".into()),
+            }
+        );
+        assert_eq!(
+            reference(r#"{{ foo ?? "say \"hi\" ?? C:\Temp\\" }}"#),
+            Reference {
+                paths: vec!["foo".into()],
+                fallback: Some(r#"say "hi" ?? C:\Temp\"#.into()),
+            }
+        );
+        // A lone quoted string is still a field path.
+        assert_eq!(
+            reference(r#"{{ "a??b" }}"#),
+            Reference {
+                paths: vec![r#""a??b""#.into()],
+                fallback: None,
+            }
+        );
+        assert_eq!(
+            reference(r#"{{ ."%F" ?? "" }}"#),
+            Reference {
+                paths: vec![r#"."%F""#.into()],
+                fallback: Some(String::new()),
+            }
+        );
+
+        assert_eq!(
+            Template::try_from(r#"{{ foo ?? "unterminated }}"#).unwrap_err(),
+            TemplateParseError::InvalidFallback {
+                fallback: r#""unterminated"#.into()
+            }
+        );
+        assert_eq!(
+            Template::try_from(r#"{{ foo ?? "a" "b" }}"#).unwrap_err(),
+            TemplateParseError::InvalidFallback {
+                fallback: r#""a" "b""#.into()
+            }
+        );
+        assert_eq!(
+            Template::try_from("{{ foo ?? }}").unwrap_err(),
+            TemplateParseError::InvalidPathSyntax {
+                path: String::new()
+            }
+        );
+        assert_eq!(
+            Template::try_from("{{ foo ?? bar baz }}").unwrap_err(),
+            TemplateParseError::InvalidPathSyntax {
+                path: "bar baz".into()
+            }
+        );
+    }
+
+    #[test]
+    fn render_log_fallbacks() {
+        let template =
+            Template::try_from("{{ .kubernetes.labels.app ?? service ?? \"unknown\" }}").unwrap();
+        let mut event = Event::Log(LogEvent::from("hello world"));
+        assert_eq!(Ok(Bytes::from("unknown")), template.render(&event));
+
+        event.as_mut_log().insert("service", Value::Null);
+        assert_eq!(Ok(Bytes::from("unknown")), template.render(&event));
+
+        event.as_mut_log().insert("service", "api");
+        assert_eq!(Ok(Bytes::from("api")), template.render(&event));
+
+        event
+            .as_mut_log()
+            .insert("kubernetes.labels.app", "frontend");
+        assert_eq!(Ok(Bytes::from("frontend")), template.render(&event));
+        assert_eq!(
+            template.get_fields(),
+            Some(vec![".kubernetes.labels.app".into(), "service".into()])
+        );
+    }
+
+    #[test]
+    fn render_log_fallbacks_missing_keys() {
+        let template = Template::try_from("{{ service ?? app }}-{{ foo }}").unwrap();
+        let mut event = Event::Log(LogEvent::from("hello world"));
+        event.as_mut_log().insert("app", Value::Null);
+        event.as_mut_log().insert("foo", "bar");
+
+        // The last field is rendered even if null, as a lone field is.
+        assert_eq!(
+            Ok(Bytes::from(format!(
+                "{}-bar",
+                Value::Null.to_string_lossy()
+            ))),
+            template.render(&event)
+        );
+
+        event.as_mut_log().remove("app");
+        assert_eq!(
+            Err(TemplateRenderingError::MissingKeys {
+                missing_keys: vec!["service".into(), "app".into()]
+            }),
+            template.render(&event)
+        );
+    }
+
     #[test]
     fn render_log_timestamp_strftime_style() {
         let ts = Utc
@@ -679,6 +943,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn render_metric_fallbacks() {
+        let template = Template::try_from("{{ tags.component ?? namespace ?? \"none\" }}").unwrap();
+        let metric = sample_metric();
+        assert_eq!(Ok(Bytes::from("none")), template.render(&metric));
+        assert_eq!(
+            Ok(Bytes::from("vector-test")),
+            template.render(&metric.with_namespace(Some("vector-test")))
+        );
+    }
+
     #[test]
     fn render_metric_with_namespace() {
         let template = Template::try_from("namespace={{namespace}} name={{name}}").unwrap();
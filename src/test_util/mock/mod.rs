@@ -3,14 +3,14 @@ use std::sync::{atomic::AtomicUsize, Arc};
 use futures_util::Stream;
 use stream_cancel::Trigger;
 use tokio::sync::oneshot::Sender;
-use vector_lib::event::EventArray;
+use vector_lib::{config::AcknowledgementsConfig, event::EventArray};
 
 use crate::{source_sender::SourceSenderItem, SourceSender};
 
 use self::{
     sinks::{
-        BackpressureSinkConfig, BasicSinkConfig, ErrorSinkConfig, OneshotSinkConfig,
-        PanicSinkConfig,
+        BackpressureSinkConfig, BasicSinkConfig, ErrorSinkConfig, ForgetfulSinkConfig,
+        OneshotSinkConfig, PanicSinkConfig,
     },
     sources::{
         BackpressureSourceConfig, BasicSourceConfig, ErrorSourceConfig, PanicSourceConfig,
@@ -101,6 +101,10 @@ pub fn error_sink() -> ErrorSinkConfig {
     ErrorSinkConfig::default()
 }
 
+pub const fn forgetful_sink(acknowledgements: AcknowledgementsConfig) -> ForgetfulSinkConfig {
+    ForgetfulSinkConfig { acknowledgements }
+}
+
 pub fn oneshot_sink(tx: Sender<EventArray>) -> OneshotSinkConfig {
     OneshotSinkConfig::new(tx)
 }
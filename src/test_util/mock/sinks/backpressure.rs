@@ -1,12 +1,12 @@
 use async_trait::async_trait;
 use futures::stream::BoxStream;
-use futures::{FutureExt, StreamExt};
+use futures::StreamExt;
 use vector_lib::configurable::configurable_component;
 
 use crate::config::{AcknowledgementsConfig, Input, SinkConfig, SinkContext};
 use crate::event::Event;
 use crate::sinks::util::StreamSink;
-use crate::sinks::{Healthcheck, VectorSink};
+use crate::sinks::{healthy, Healthcheck, VectorSink};
 
 #[derive(Debug)]
 struct BackpressureSink {
@@ -39,7 +39,7 @@ impl SinkConfig for BackpressureSinkConfig {
         let sink = BackpressureSink {
             num_to_consume: self.num_to_consume,
         };
-        let healthcheck = futures::future::ok(()).boxed();
+        let healthcheck = healthy();
         Ok((VectorSink::from_event_streamsink(sink), healthcheck))
     }
 
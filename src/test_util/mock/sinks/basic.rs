@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use futures_util::{stream::BoxStream, FutureExt, StreamExt};
+use futures_util::{stream::BoxStream, StreamExt};
 use snafu::Snafu;
 use tokio::sync::oneshot;
 use vector_lib::configurable::configurable_component;
@@ -12,7 +12,7 @@ use vector_lib::{
 
 use crate::{
     config::{SinkConfig, SinkContext},
-    sinks::Healthcheck,
+    sinks::{Healthcheck, IntoHealthcheck},
     SourceSender,
 };
 
@@ -87,7 +87,10 @@ impl SinkConfig for BasicSinkConfig {
 
         let healthcheck = async move { rx.await.unwrap() };
 
-        Ok((VectorSink::from_event_streamsink(sink), healthcheck.boxed()))
+        Ok((
+            VectorSink::from_event_streamsink(sink),
+            healthcheck.into_healthcheck(),
+        ))
     }
 
     fn input(&self) -> Input {
@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use futures_util::{stream::BoxStream, StreamExt};
+use vector_lib::configurable::configurable_component;
+use vector_lib::{
+    config::{AcknowledgementsConfig, Input},
+    event::EventArray,
+    finalization::Finalizable,
+    sink::{StreamSink, VectorSink},
+};
+
+use crate::{
+    config::{SinkConfig, SinkContext},
+    sinks::{healthy, Healthcheck},
+};
+
+/// Configuration for the `test_forgetful` sink.
+#[configurable_component(sink("test_forgetful", "Test (forgetful)."))]
+#[derive(Clone, Debug, Default)]
+pub struct ForgetfulSinkConfig {
+    #[configurable(derived)]
+    #[serde(default)]
+    pub acknowledgements: AcknowledgementsConfig,
+}
+
+impl_generate_config_from_default!(ForgetfulSinkConfig);
+
+#[async_trait]
+#[typetag::serde(name = "test_forgetful")]
+impl SinkConfig for ForgetfulSinkConfig {
+    fn input(&self) -> Input {
+        Input::all()
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &self.acknowledgements
+    }
+
+    async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let healthcheck = healthy();
+        Ok((VectorSink::Stream(Box::new(ForgetfulSink)), healthcheck))
+    }
+}
+
+/// A sink that never resolves the finalizers of the events it receives, as a stuck sink would.
+struct ForgetfulSink;
+
+#[async_trait]
+impl StreamSink<EventArray> for ForgetfulSink {
+    async fn run(self: Box<Self>, mut input: BoxStream<'_, EventArray>) -> Result<(), ()> {
+        while let Some(mut events) = input.next().await {
+            std::mem::forget(events.take_finalizers());
+        }
+        Ok(())
+    }
+}
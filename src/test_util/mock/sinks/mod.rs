@@ -7,8 +7,14 @@ pub use self::basic::BasicSinkConfig;
 mod error;
 pub use self::error::ErrorSinkConfig;
 
+mod forgetful;
+pub use self::forgetful::ForgetfulSinkConfig;
+
 mod oneshot;
 pub use self::oneshot::OneshotSinkConfig;
 
 mod panic;
 pub use self::panic::PanicSinkConfig;
+
+mod slow;
+pub use self::slow::SlowSinkConfig;
@@ -12,7 +12,7 @@ use vector_lib::{
 
 use crate::{
     config::{SinkConfig, SinkContext},
-    sinks::Healthcheck,
+    sinks::{healthy, Healthcheck},
 };
 
 /// Configurable for the `test_oneshot` sink.
@@ -51,7 +51,7 @@ impl SinkConfig for OneshotSinkConfig {
         };
         let sink = Box::new(OneshotSink { tx });
 
-        let healthcheck = Box::pin(async { Ok(()) });
+        let healthcheck = healthy();
 
         Ok((VectorSink::Stream(sink), healthcheck))
     }
@@ -4,7 +4,7 @@ use std::{
 };
 
 use async_trait::async_trait;
-use futures_util::{future::ok, FutureExt, Sink};
+use futures_util::Sink;
 use vector_lib::configurable::configurable_component;
 use vector_lib::{
     config::{AcknowledgementsConfig, Input},
@@ -14,7 +14,7 @@ use vector_lib::{
 
 use crate::{
     config::{SinkConfig, SinkContext},
-    sinks::Healthcheck,
+    sinks::{healthy, Healthcheck},
 };
 
 /// Configuration for the `test_panic` sink.
@@ -32,7 +32,7 @@ impl_generate_config_from_default!(PanicSinkConfig);
 impl SinkConfig for PanicSinkConfig {
     async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
         #[allow(deprecated)]
-        Ok((VectorSink::from_event_sink(PanicSink), ok(()).boxed()))
+        Ok((VectorSink::from_event_sink(PanicSink), healthy()))
     }
 
     fn input(&self) -> Input {
@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures_util::{stream::BoxStream, StreamExt};
+use vector_lib::configurable::configurable_component;
+use vector_lib::{
+    config::{AcknowledgementsConfig, Input},
+    event::EventArray,
+    finalization::{EventStatus, Finalizable},
+    sink::{StreamSink, VectorSink},
+};
+
+use crate::{
+    config::{SinkConfig, SinkContext},
+    sinks::{healthy, Healthcheck},
+};
+
+/// Configuration for the `test_slow` sink.
+#[configurable_component(sink("test_slow", "Test (slow)."))]
+#[derive(Clone, Debug, Default)]
+pub struct SlowSinkConfig {
+    /// The time taken to deliver each batch of events.
+    pub delay_secs: u64,
+}
+
+impl_generate_config_from_default!(SlowSinkConfig);
+
+#[async_trait]
+#[typetag::serde(name = "test_slow")]
+impl SinkConfig for SlowSinkConfig {
+    fn input(&self) -> Input {
+        Input::all()
+    }
+
+    fn acknowledgements(&self) -> &AcknowledgementsConfig {
+        &AcknowledgementsConfig::DEFAULT
+    }
+
+    async fn build(&self, _cx: SinkContext) -> crate::Result<(VectorSink, Healthcheck)> {
+        let sink = SlowSink {
+            delay: Duration::from_secs(self.delay_secs),
+        };
+        let healthcheck = healthy();
+        Ok((VectorSink::Stream(Box::new(sink)), healthcheck))
+    }
+}
+
+struct SlowSink {
+    delay: Duration,
+}
+
+#[async_trait]
+impl StreamSink<EventArray> for SlowSink {
+    async fn run(self: Box<Self>, mut input: BoxStream<'_, EventArray>) -> Result<(), ()> {
+        while let Some(mut events) = input.next().await {
+            tokio::time::sleep(self.delay).await;
+            events
+                .take_finalizers()
+                .update_status(EventStatus::Delivered);
+        }
+        Ok(())
+    }
+}
@@ -22,7 +22,8 @@ use tokio::sync::oneshot;
 
 use super::{
     events::capture_key_press,
-    state::{self, ConnectionStatus},
+    state::{self, ComponentRow, ConnectionStatus},
+    SortBy,
 };
 
 /// Format metrics, with thousands separation
@@ -119,10 +120,44 @@ fn format_metric_bytes(total: i64, throughput: i64, human_metrics: bool) -> Stri
     }
 }
 
+/// Format a byte size with a binary unit, showing zero as `0 B` rather than `--`
+fn format_bytes(n: i64) -> String {
+    match NumberPrefix::binary(n as f64) {
+        NumberPrefix::Standalone(n) => format!("{} B", n),
+        NumberPrefix::Prefixed(..) => n.human_format_bytes(),
+    }
+}
+
+/// Format event byte metrics, returning `--` for components that don't report them. Idle
+/// components that do are shown with a zero size instead.
+fn format_metric_event_bytes(total: Option<i64>, throughput: i64, human_metrics: bool) -> String {
+    match total {
+        None => "--".to_string(),
+        Some(v) => format!(
+            "{} ({}/s)",
+            if human_metrics {
+                format_bytes(v)
+            } else {
+                format!("{} B", v.to_formatted_string(&Locale::en))
+            },
+            format_bytes(throughput)
+        ),
+    }
+}
+
+/// Format the share of time a sink spends sending payloads as a percentage, followed by the number
+/// of payloads being sent, returning `--` for components that don't report it
+fn format_sending(utilization: Option<f64>, in_flight: i64) -> String {
+    utilization.map_or_else(
+        || "--".to_string(),
+        |u| format!("{:.0}% ({})", u * 100.0, in_flight),
+    )
+}
+
 const NUM_COLUMNS: usize = if is_allocation_tracking_enabled() {
-    10
+    14
 } else {
-    9
+    13
 };
 
 static HEADER: [&str; NUM_COLUMNS] = [
@@ -132,13 +167,82 @@ static HEADER: [&str; NUM_COLUMNS] = [
     "Type",
     "Events In",
     "Bytes In",
+    "Event Bytes In",
     "Events Out",
     "Bytes Out",
+    "Event Bytes Out",
     "Errors",
+    "Health",
+    "Sending",
     #[cfg(feature = "allocation-tracing")]
     "Memory Used",
 ];
 
+/// Widths of the columns, as a percentage of the table width when every column is shown
+#[cfg(feature = "allocation-tracing")]
+static WIDTHS: [u32; NUM_COLUMNS] = [
+    12, // ID
+    5,  // Output
+    4,  // Kind
+    6,  // Type
+    7,  // Events In
+    9,  // Bytes In
+    9,  // Event Bytes In
+    7,  // Events Out
+    9,  // Bytes Out
+    9,  // Event Bytes Out
+    5,  // Errors
+    6,  // Health
+    5,  // Sending
+    7,  // Memory Used
+];
+
+/// Widths of the columns, as a percentage of the table width when every column is shown
+#[cfg(not(feature = "allocation-tracing"))]
+static WIDTHS: [u32; NUM_COLUMNS] = [
+    12, // ID
+    6,  // Output
+    6,  // Kind
+    6,  // Type
+    9,  // Events In
+    9,  // Bytes In
+    9,  // Event Bytes In
+    9,  // Events Out
+    9,  // Bytes Out
+    9,  // Event Bytes Out
+    5,  // Errors
+    6,  // Health
+    5,  // Sending
+];
+
+/// Index of the "Events Out" column, where the events sent by each output are shown
+const EVENTS_OUT_COLUMN: usize = 7;
+
+/// Indices of the columns showing the bytes read and written on the wire, and the size of the
+/// events received and sent, which can be hidden together
+const BYTES_COLUMNS: [usize; 4] = [5, 6, 8, 9];
+
+/// Returns the cells of the columns that are shown
+fn shown_columns<T>(cells: impl IntoIterator<Item = T>, show_bytes: bool) -> Vec<T> {
+    cells
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| show_bytes || !BYTES_COLUMNS.contains(i))
+        .map(|(_, cell)| cell)
+        .collect()
+}
+
+/// Returns the width constraints of the columns that are shown, sharing the width of the hidden
+/// columns between the others
+fn column_widths(show_bytes: bool) -> Vec<Constraint> {
+    let widths = shown_columns(WIDTHS, show_bytes);
+    let total: u32 = widths.iter().sum();
+    widths
+        .into_iter()
+        .map(|width| Constraint::Ratio(width, total))
+        .collect()
+}
+
 struct Widgets<'a> {
     constraints: Vec<Constraint>,
     url_string: &'a str,
@@ -190,16 +294,25 @@ impl<'a> Widgets<'a> {
 
     /// Renders a components table, showing sources, transforms and sinks in tabular form, with
     /// statistics pulled from `ComponentsState`,
-    fn components_table(&self, f: &mut Frame, state: &state::State, area: Rect) {
+    fn components_table(
+        &self,
+        f: &mut Frame,
+        state: &state::State,
+        sort: SortBy,
+        show_bytes: bool,
+        area: Rect,
+    ) {
         // Header columns
-        let header = HEADER
-            .iter()
-            .map(|s| Cell::from(*s).style(Style::default().add_modifier(Modifier::BOLD)))
-            .collect::<Vec<_>>();
+        let header = shown_columns(
+            HEADER
+                .iter()
+                .map(|s| Cell::from(*s).style(Style::default().add_modifier(Modifier::BOLD))),
+            show_bytes,
+        );
 
         // Data columns
         let mut items = Vec::new();
-        for (_, r) in state.components.iter() {
+        for r in sorted_components(state, sort) {
             let mut data = vec![
                 r.key.id().to_string(),
                 (!r.has_displayable_outputs())
@@ -221,6 +334,11 @@ impl<'a> Widgets<'a> {
                     r.received_bytes_throughput_sec,
                     self.opts.human_metrics,
                 ),
+                format_metric_event_bytes(
+                    r.received_event_bytes_total,
+                    r.received_event_bytes_throughput_sec,
+                    self.opts.human_metrics,
+                ),
                 format_metric(
                     r.sent_events_total,
                     r.sent_events_throughput_sec,
@@ -231,17 +349,33 @@ impl<'a> Widgets<'a> {
                     r.sent_bytes_throughput_sec,
                     self.opts.human_metrics,
                 ),
+                format_metric_event_bytes(
+                    r.sent_event_bytes_total,
+                    r.sent_event_bytes_throughput_sec,
+                    self.opts.human_metrics,
+                ),
                 if self.opts.human_metrics {
                     r.errors.human_format()
                 } else {
                     r.errors.thousands_format()
                 },
-                #[cfg(feature = "allocation-tracing")]
-                r.allocated_bytes.human_format_bytes(),
             ];
 
             data.extend_from_slice(&formatted_metrics);
-            items.push(Row::new(data).style(Style::default()));
+            let mut data = data.into_iter().map(Cell::from).collect::<Vec<_>>();
+            // Sources and transforms don't have healthchecks
+            data.push(match r.health {
+                Some(health) => Cell::from(health.as_str()).style(health.style()),
+                None => Cell::from("--"),
+            });
+            // Only sinks sending each payload on their own report their utilization
+            data.push(Cell::from(format_sending(
+                r.send_utilization,
+                r.send_in_flight,
+            )));
+            #[cfg(feature = "allocation-tracing")]
+            data.push(Cell::from(r.allocated_bytes.human_format_bytes()));
+            items.push(Row::new(shown_columns(data, show_bytes)).style(Style::default()));
 
             // Add output rows
             if r.has_displayable_outputs() {
@@ -256,39 +390,13 @@ impl<'a> Widgets<'a> {
                         .map(Cell::from)
                         .collect::<Vec<_>>();
                     data[1] = Cell::from(id.as_str());
-                    data[5] = Cell::from(sent_events_metric);
-                    items.push(Row::new(data).style(Style::default()));
+                    data[EVENTS_OUT_COLUMN] = Cell::from(sent_events_metric);
+                    items.push(Row::new(shown_columns(data, show_bytes)).style(Style::default()));
                 }
             }
         }
 
-        let widths: &[Constraint] = if is_allocation_tracking_enabled() {
-            &[
-                Constraint::Percentage(13), // ID
-                Constraint::Percentage(8),  // Output
-                Constraint::Percentage(4),  // Kind
-                Constraint::Percentage(9),  // Type
-                Constraint::Percentage(10), // Events In
-                Constraint::Percentage(12), // Bytes In
-                Constraint::Percentage(10), // Events Out
-                Constraint::Percentage(12), // Bytes Out
-                Constraint::Percentage(8),  // Errors
-                Constraint::Percentage(14), // Allocated Bytes
-            ]
-        } else {
-            &[
-                Constraint::Percentage(13), // ID
-                Constraint::Percentage(12), // Output
-                Constraint::Percentage(9),  // Kind
-                Constraint::Percentage(6),  // Type
-                Constraint::Percentage(12), // Events In
-                Constraint::Percentage(14), // Bytes In
-                Constraint::Percentage(12), // Events Out
-                Constraint::Percentage(14), // Bytes Out
-                Constraint::Percentage(8),  // Errors
-            ]
-        };
-        let w = Table::new(items, widths)
+        let w = Table::new(items, column_widths(show_bytes))
             .header(Row::new(header).bottom_margin(1))
             .block(Block::default().borders(Borders::ALL).title("Components"))
             .column_spacing(2);
@@ -306,8 +414,19 @@ impl<'a> Widgets<'a> {
     }
 
     /// Renders a box showing instructions on how to exit from `vector top`.
-    fn quit_box(&self, f: &mut Frame, area: Rect) {
-        let text = vec![Line::from("To quit, press ESC or 'q'")];
+    fn quit_box(&self, f: &mut Frame, sort: SortBy, show_bytes: bool, area: Rect) {
+        let text = vec![Line::from(format!(
+            "To quit, press ESC or 'q' | {} | {}",
+            match sort {
+                SortBy::Id => "To sort by health, press 's'",
+                SortBy::Health => "To sort by ID, press 's'",
+            },
+            if show_bytes {
+                "To hide bytes, press 'b'"
+            } else {
+                "To show bytes, press 'b'"
+            },
+        ))];
 
         let block = Block::default()
             .borders(Borders::ALL)
@@ -321,7 +440,7 @@ impl<'a> Widgets<'a> {
     }
 
     /// Draw a single frame. Creates a layout and renders widgets into it.
-    fn draw(&self, f: &mut Frame, state: state::State) {
+    fn draw(&self, f: &mut Frame, state: &state::State, sort: SortBy, show_bytes: bool) {
         let size = f.area();
         let rects = Layout::default()
             .constraints(self.constraints.clone())
@@ -331,13 +450,24 @@ impl<'a> Widgets<'a> {
 
         // Require a minimum of 80 chars of line width to display the table
         if size.width >= 80 {
-            self.components_table(f, &state, rects[1]);
+            self.components_table(f, state, sort, show_bytes, rects[1]);
         } else {
             self.components_resize_window(f, rects[1]);
         }
 
-        self.quit_box(f, rects[2]);
+        self.quit_box(f, sort, show_bytes, rects[2]);
+    }
+}
+
+/// Returns the components in the order they are displayed in. Components are kept in ID order
+/// by the state, which is preserved among components of the same health.
+fn sorted_components(state: &state::State, sort: SortBy) -> Vec<&ComponentRow> {
+    let mut components = state.components.values().collect::<Vec<_>>();
+    if sort == SortBy::Health {
+        // Components without healthchecks sort last.
+        components.sort_by_key(|r| (r.health.is_none(), r.health));
     }
+    components
 }
 
 /// Determine if the terminal is a TTY
@@ -376,16 +506,35 @@ pub async fn init_dashboard<'a>(
     terminal.clear()?;
 
     let widgets = Widgets::new(title, url, opts);
+    let mut sort = opts.sort;
+    let mut show_bytes = !opts.hide_bytes;
+    let mut last_state = None;
 
     loop {
         tokio::select! {
             Some(state) = state_rx.recv() => {
-                terminal.draw(|f| widgets.draw(f, state))?;
+                terminal.draw(|f| widgets.draw(f, &state, sort, show_bytes))?;
+                last_state = Some(state);
             },
             k = key_press_rx.recv() => {
-                if let KeyCode::Esc | KeyCode::Char('q') = k.unwrap() {
-                    _ = key_press_kill_tx.send(());
-                    break
+                match k.unwrap() {
+                    KeyCode::Esc | KeyCode::Char('q') => {
+                        _ = key_press_kill_tx.send(());
+                        break
+                    }
+                    KeyCode::Char('s') => {
+                        sort = sort.toggle();
+                        if let Some(state) = &last_state {
+                            terminal.draw(|f| widgets.draw(f, state, sort, show_bytes))?;
+                        }
+                    }
+                    KeyCode::Char('b') => {
+                        show_bytes = !show_bytes;
+                        if let Some(state) = &last_state {
+                            terminal.draw(|f| widgets.draw(f, state, sort, show_bytes))?;
+                        }
+                    }
+                    _ => {}
                 }
             }
             _ = &mut shutdown_rx => {
@@ -501,4 +650,102 @@ mod tests {
         assert_eq!((N * (N * (N * N))).human_format_bytes(), "1.00 TiB");
         assert_eq!((N * (N * (N * (N * N)))).human_format_bytes(), "1.00 PiB");
     }
+
+    #[test]
+    /// Components that don't report event byte metrics should show a dash, and idle ones a zero
+    /// size
+    fn format_event_bytes_metric() {
+        assert_eq!(format_metric_event_bytes(None, 0, true), "--");
+        assert_eq!(format_metric_event_bytes(Some(0), 0, true), "0 B (0 B/s)");
+        assert_eq!(
+            format_metric_event_bytes(Some(2048), 512, true),
+            "2.00 KiB (512 B/s)"
+        );
+        assert_eq!(
+            format_metric_event_bytes(Some(2048), 1024, false),
+            "2,048 B (1.00 KiB/s)"
+        );
+    }
+
+    #[test]
+    /// Utilization should be shown as a percentage along with the payloads in flight, and a dash
+    /// for components that don't report it
+    fn format_send_utilization() {
+        assert_eq!(format_sending(None, 0), "--");
+        assert_eq!(format_sending(Some(0.0), 0), "0% (0)");
+        assert_eq!(format_sending(Some(0.426), 3), "43% (3)");
+        assert_eq!(format_sending(Some(1.0), 12), "100% (12)");
+    }
+
+    #[test]
+    /// Hiding bytes should drop both the wire and event byte columns, and widen the others
+    fn hide_bytes_columns() {
+        assert_eq!(shown_columns(HEADER, true), HEADER);
+        let header = shown_columns(HEADER, false);
+        assert_eq!(header.len(), NUM_COLUMNS - 4);
+        assert!(!header.iter().any(|h| h.contains("Bytes")));
+        assert!(header.contains(&"Events In"));
+        assert_eq!(HEADER[EVENTS_OUT_COLUMN], "Events Out");
+
+        assert_eq!(WIDTHS.iter().sum::<u32>(), 100);
+        let widths = column_widths(false);
+        assert_eq!(widths.len(), NUM_COLUMNS - 4);
+        assert_eq!(widths[0], Constraint::Ratio(12, 64));
+    }
+
+    fn component(id: &str, health: Option<state::ComponentHealth>) -> ComponentRow {
+        ComponentRow {
+            key: id.into(),
+            kind: if health.is_some() { "sink" } else { "source" }.to_string(),
+            component_type: "demo_logs".to_string(),
+            outputs: Default::default(),
+            received_bytes_total: 0,
+            received_bytes_throughput_sec: 0,
+            received_event_bytes_total: None,
+            received_event_bytes_throughput_sec: 0,
+            received_events_total: 0,
+            received_events_throughput_sec: 0,
+            sent_bytes_total: 0,
+            sent_bytes_throughput_sec: 0,
+            sent_event_bytes_total: None,
+            sent_event_bytes_throughput_sec: 0,
+            sent_events_total: 0,
+            sent_events_throughput_sec: 0,
+            #[cfg(feature = "allocation-tracing")]
+            allocated_bytes: 0,
+            errors: 0,
+            health,
+            send_in_flight: 0,
+            send_utilization: None,
+        }
+    }
+
+    #[test]
+    /// Failed sinks should sort first when sorting by health
+    fn sort_by_health() {
+        use state::ComponentHealth::*;
+
+        let state = state::State::new(
+            [
+                component("a", Some(Healthy)),
+                component("b", None),
+                component("c", Some(Failed)),
+                component("d", Some(Unknown)),
+                component("e", Some(Degraded)),
+                component("f", Some(Failed)),
+            ]
+            .into_iter()
+            .map(|r| (r.key.clone(), r))
+            .collect(),
+        );
+        let ids = |sort| {
+            sorted_components(&state, sort)
+                .into_iter()
+                .map(|r| r.key.id().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(ids(SortBy::Id), ["a", "b", "c", "d", "e", "f"]);
+        assert_eq!(ids(SortBy::Health), ["c", "f", "e", "d", "a", "b"]);
+    }
 }
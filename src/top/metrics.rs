@@ -6,11 +6,15 @@ use std::{
 use tokio::task::JoinHandle;
 use tokio_stream::StreamExt;
 use vector_lib::api_client::{
-    gql::{ComponentsQueryExt, ComponentsSubscriptionExt, MetricsSubscriptionExt},
+    gql::{
+        component_added_subscription::ComponentAddedSubscriptionComponentAddedOn,
+        component_health_statuses_subscription, components_query, ComponentsQueryExt,
+        ComponentsSubscriptionExt, MetricsSubscriptionExt,
+    },
     Client, SubscriptionClient,
 };
 
-use super::state::{self, OutputMetrics};
+use super::state::{self, ComponentHealth, OutputMetrics};
 use crate::{config::ComponentKey, top::state::SentEventsMetric};
 
 /// Components that have been added
@@ -23,6 +27,8 @@ async fn component_added(client: Arc<SubscriptionClient>, tx: state::EventTx) {
         if let Some(d) = res.data {
             let c = d.component_added;
             let key = ComponentKey::from(c.component_id);
+            let health = matches!(c.on, ComponentAddedSubscriptionComponentAddedOn::Sink)
+                .then_some(ComponentHealth::Unknown);
             _ = tx
                 .send(state::EventType::ComponentAdded(state::ComponentRow {
                     key,
@@ -31,15 +37,22 @@ async fn component_added(client: Arc<SubscriptionClient>, tx: state::EventTx) {
                     outputs: HashMap::new(),
                     received_bytes_total: 0,
                     received_bytes_throughput_sec: 0,
+                    received_event_bytes_total: None,
+                    received_event_bytes_throughput_sec: 0,
                     received_events_total: 0,
                     received_events_throughput_sec: 0,
                     sent_bytes_total: 0,
                     sent_bytes_throughput_sec: 0,
+                    sent_event_bytes_total: None,
+                    sent_event_bytes_throughput_sec: 0,
                     sent_events_total: 0,
                     sent_events_throughput_sec: 0,
                     #[cfg(feature = "allocation-tracing")]
                     allocated_bytes: 0,
                     errors: 0,
+                    health,
+                    send_in_flight: 0,
+                    send_utilization: None,
                 }))
                 .await;
         }
@@ -134,6 +147,60 @@ async fn received_bytes_throughputs(
     }
 }
 
+/// Estimated size of the events received per component, which unlike the bytes read from the
+/// wire is reported by every kind of component
+async fn received_event_bytes_totals(
+    client: Arc<SubscriptionClient>,
+    tx: state::EventTx,
+    interval: i64,
+) {
+    tokio::pin! {
+        let stream = client.component_received_event_bytes_totals_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_received_event_bytes_totals;
+            _ = tx
+                .send(state::EventType::ReceivedEventBytesTotals(
+                    c.into_iter()
+                        .map(|c| {
+                            (
+                                ComponentKey::from(c.component_id.as_str()),
+                                c.metric.received_event_bytes_total as i64,
+                            )
+                        })
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
+async fn received_event_bytes_throughputs(
+    client: Arc<SubscriptionClient>,
+    tx: state::EventTx,
+    interval: i64,
+) {
+    tokio::pin! {
+        let stream = client.component_received_event_bytes_throughputs_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_received_event_bytes_throughputs;
+            _ = tx
+                .send(state::EventType::ReceivedEventBytesThroughputs(
+                    interval,
+                    c.into_iter()
+                        .map(|c| (ComponentKey::from(c.component_id.as_str()), c.throughput))
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
 async fn received_events_totals(
     client: Arc<SubscriptionClient>,
     tx: state::EventTx,
@@ -234,6 +301,59 @@ async fn sent_bytes_throughputs(
     }
 }
 
+/// Estimated size of the events sent per component
+async fn sent_event_bytes_totals(
+    client: Arc<SubscriptionClient>,
+    tx: state::EventTx,
+    interval: i64,
+) {
+    tokio::pin! {
+        let stream = client.component_sent_event_bytes_totals_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_sent_event_bytes_totals;
+            _ = tx
+                .send(state::EventType::SentEventBytesTotals(
+                    c.into_iter()
+                        .map(|c| {
+                            (
+                                ComponentKey::from(c.component_id.as_str()),
+                                c.metric.sent_event_bytes_total as i64,
+                            )
+                        })
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
+async fn sent_event_bytes_throughputs(
+    client: Arc<SubscriptionClient>,
+    tx: state::EventTx,
+    interval: i64,
+) {
+    tokio::pin! {
+        let stream = client.component_sent_event_bytes_throughputs_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_sent_event_bytes_throughputs;
+            _ = tx
+                .send(state::EventType::SentEventBytesThroughputs(
+                    interval,
+                    c.into_iter()
+                        .map(|c| (ComponentKey::from(c.component_id.as_str()), c.throughput))
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
 async fn sent_events_totals(client: Arc<SubscriptionClient>, tx: state::EventTx, interval: i64) {
     tokio::pin! {
         let stream = client.component_sent_events_totals_subscription(interval);
@@ -309,6 +429,83 @@ async fn errors_totals(client: Arc<SubscriptionClient>, tx: state::EventTx, inte
     }
 }
 
+/// Payloads being sent per sink, for the sinks that report it
+async fn send_in_flights(client: Arc<SubscriptionClient>, tx: state::EventTx, interval: i64) {
+    tokio::pin! {
+        let stream = client.component_send_in_flights_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_send_in_flights;
+            _ = tx
+                .send(state::EventType::SendInFlights(
+                    c.into_iter()
+                        .map(|c| {
+                            (
+                                ComponentKey::from(c.component_id.as_str()),
+                                c.metric.send_in_flight as i64,
+                            )
+                        })
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
+/// Share of time each sink spends sending payloads, for the sinks that report it
+async fn send_utilizations(client: Arc<SubscriptionClient>, tx: state::EventTx, interval: i64) {
+    tokio::pin! {
+        let stream = client.component_send_utilizations_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_send_utilizations;
+            _ = tx
+                .send(state::EventType::SendUtilizations(
+                    c.into_iter()
+                        .map(|c| {
+                            (
+                                ComponentKey::from(c.component_id.as_str()),
+                                c.metric.send_utilization,
+                            )
+                        })
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
+/// Healthcheck status per sink
+async fn health_statuses(client: Arc<SubscriptionClient>, tx: state::EventTx, interval: i64) {
+    tokio::pin! {
+        let stream = client.component_health_statuses(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_health_statuses;
+            _ = tx
+                .send(state::EventType::HealthStatuses(
+                    c.into_iter()
+                        .map(|c| {
+                            (
+                                ComponentKey::from(c.component_id.as_str()),
+                                c.status
+                                    .as_ref()
+                                    .map_or(ComponentHealth::Unknown, Into::into),
+                            )
+                        })
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
 /// Subscribe to each metrics channel through a separate client. This is a temporary workaround
 /// until client multiplexing is fixed. In future, we should be able to use a single client
 pub fn subscribe(
@@ -331,6 +528,16 @@ pub fn subscribe(
             tx.clone(),
             interval,
         )),
+        tokio::spawn(received_event_bytes_totals(
+            Arc::clone(&client),
+            tx.clone(),
+            interval,
+        )),
+        tokio::spawn(received_event_bytes_throughputs(
+            Arc::clone(&client),
+            tx.clone(),
+            interval,
+        )),
         tokio::spawn(received_events_totals(
             Arc::clone(&client),
             tx.clone(),
@@ -347,6 +554,16 @@ pub fn subscribe(
             tx.clone(),
             interval,
         )),
+        tokio::spawn(sent_event_bytes_totals(
+            Arc::clone(&client),
+            tx.clone(),
+            interval,
+        )),
+        tokio::spawn(sent_event_bytes_throughputs(
+            Arc::clone(&client),
+            tx.clone(),
+            interval,
+        )),
         tokio::spawn(sent_events_totals(
             Arc::clone(&client),
             tx.clone(),
@@ -359,7 +576,10 @@ pub fn subscribe(
         )),
         #[cfg(feature = "allocation-tracing")]
         tokio::spawn(allocated_bytes(Arc::clone(&client), tx.clone(), interval)),
-        tokio::spawn(errors_totals(Arc::clone(&client), tx, interval)),
+        tokio::spawn(errors_totals(Arc::clone(&client), tx.clone(), interval)),
+        tokio::spawn(send_in_flights(Arc::clone(&client), tx.clone(), interval)),
+        tokio::spawn(send_utilizations(Arc::clone(&client), tx.clone(), interval)),
+        tokio::spawn(health_statuses(Arc::clone(&client), tx, interval)),
     ]
 }
 
@@ -381,6 +601,13 @@ pub async fn init_components(client: &Client) -> Result<state::State, ()> {
         .flat_map(|edge| {
             let d = edge.node;
             let key = ComponentKey::from(d.component_id);
+            let health = match d.on {
+                components_query::ComponentsQueryComponentsEdgesNodeOn::Sink(_) => Some(
+                    d.on.health_status()
+                        .map_or(ComponentHealth::Unknown, Into::into),
+                ),
+                _ => None,
+            };
             Some((
                 key.clone(),
                 state::ComponentRow {
@@ -395,15 +622,22 @@ pub async fn init_components(client: &Client) -> Result<state::State, ()> {
                         .collect(),
                     received_bytes_total: d.on.received_bytes_total(),
                     received_bytes_throughput_sec: 0,
+                    received_event_bytes_total: d.on.received_event_bytes_total(),
+                    received_event_bytes_throughput_sec: 0,
                     received_events_total: d.on.received_events_total(),
                     received_events_throughput_sec: 0,
                     sent_bytes_total: d.on.sent_bytes_total(),
                     sent_bytes_throughput_sec: 0,
+                    sent_event_bytes_total: d.on.sent_event_bytes_total(),
+                    sent_event_bytes_throughput_sec: 0,
                     sent_events_total: d.on.sent_events_total(),
                     sent_events_throughput_sec: 0,
                     #[cfg(feature = "allocation-tracing")]
                     allocated_bytes: 0,
                     errors: 0,
+                    health,
+                    send_in_flight: 0,
+                    send_utilization: None,
                 },
             ))
         })
@@ -411,3 +645,25 @@ pub async fn init_components(client: &Client) -> Result<state::State, ()> {
 
     Ok(state::State::new(rows))
 }
+
+impl From<&components_query::SinkHealthStatus> for ComponentHealth {
+    fn from(status: &components_query::SinkHealthStatus) -> Self {
+        match status {
+            components_query::SinkHealthStatus::HEALTHY => Self::Healthy,
+            components_query::SinkHealthStatus::DEGRADED => Self::Degraded,
+            components_query::SinkHealthStatus::UNHEALTHY => Self::Failed,
+            components_query::SinkHealthStatus::Other(_) => Self::Unknown,
+        }
+    }
+}
+
+impl From<&component_health_statuses_subscription::SinkHealthStatus> for ComponentHealth {
+    fn from(status: &component_health_statuses_subscription::SinkHealthStatus) -> Self {
+        match status {
+            component_health_statuses_subscription::SinkHealthStatus::HEALTHY => Self::Healthy,
+            component_health_statuses_subscription::SinkHealthStatus::DEGRADED => Self::Degraded,
+            component_health_statuses_subscription::SinkHealthStatus::UNHEALTHY => Self::Failed,
+            component_health_statuses_subscription::SinkHealthStatus::Other(_) => Self::Unknown,
+        }
+    }
+}
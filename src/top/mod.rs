@@ -34,6 +34,37 @@ pub struct Opts {
     /// By default, top will attempt to reconnect if the connection drops.
     #[arg(short, long)]
     no_reconnect: bool,
+
+    /// How components are sorted.
+    ///
+    /// The sort order can also be toggled from the dashboard, by pressing 's'.
+    #[arg(short, long, default_value = "id")]
+    sort: SortBy,
+
+    /// Whether to hide the columns showing the bytes received and sent on the wire, and the size
+    /// of the events received and sent.
+    ///
+    /// The columns can also be toggled from the dashboard, by pressing 'b'.
+    #[arg(long)]
+    hide_bytes: bool,
+}
+
+/// How components are sorted
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortBy {
+    /// By component ID
+    Id,
+    /// By sink health, failed sinks first, then by component ID
+    Health,
+}
+
+impl SortBy {
+    const fn toggle(self) -> Self {
+        match self {
+            Self::Id => Self::Health,
+            Self::Health => Self::Id,
+        }
+    }
 }
 
 impl Opts {
@@ -2,7 +2,7 @@ use std::collections::{BTreeMap, HashMap};
 
 use chrono::{DateTime, Local};
 use ratatui::{
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::Span,
 };
 use tokio::sync::mpsc;
@@ -25,17 +25,26 @@ pub enum EventType {
     ReceivedBytesTotals(Vec<IdentifiedMetric>),
     /// Interval + identified metric
     ReceivedBytesThroughputs(i64, Vec<IdentifiedMetric>),
+    ReceivedEventBytesTotals(Vec<IdentifiedMetric>),
+    /// Interval in ms + identified metric
+    ReceivedEventBytesThroughputs(i64, Vec<IdentifiedMetric>),
     ReceivedEventsTotals(Vec<IdentifiedMetric>),
     /// Interval in ms + identified metric
     ReceivedEventsThroughputs(i64, Vec<IdentifiedMetric>),
     SentBytesTotals(Vec<IdentifiedMetric>),
     /// Interval + identified metric
     SentBytesThroughputs(i64, Vec<IdentifiedMetric>),
+    SentEventBytesTotals(Vec<IdentifiedMetric>),
+    /// Interval in ms + identified metric
+    SentEventBytesThroughputs(i64, Vec<IdentifiedMetric>),
     // Identified overall metric + output-specific metrics
     SentEventsTotals(Vec<SentEventsMetric>),
     /// Interval in ms + identified overall metric + output-specific metrics
     SentEventsThroughputs(i64, Vec<SentEventsMetric>),
     ErrorsTotals(Vec<IdentifiedMetric>),
+    HealthStatuses(Vec<(ComponentKey, ComponentHealth)>),
+    SendInFlights(Vec<IdentifiedMetric>),
+    SendUtilizations(Vec<(ComponentKey, f64)>),
     #[cfg(feature = "allocation-tracing")]
     AllocatedBytes(Vec<IdentifiedMetric>),
     ComponentAdded(ComponentRow),
@@ -73,6 +82,37 @@ impl ConnectionStatus {
     }
 }
 
+/// The health of a sink, as reported by its latest healthcheck. Variants are ordered from the
+/// most to the least concerning, which is the order components are sorted in by health.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ComponentHealth {
+    Failed,
+    Degraded,
+    // The healthcheck hasn't run yet
+    Unknown,
+    Healthy,
+}
+
+impl ComponentHealth {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Failed => "failed",
+            Self::Degraded => "degraded",
+            Self::Unknown => "unknown",
+            Self::Healthy => "healthy",
+        }
+    }
+
+    pub fn style(self) -> Style {
+        match self {
+            Self::Failed => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            Self::Degraded => Style::default().fg(Color::Yellow),
+            Self::Unknown => Style::default().fg(Color::Gray),
+            Self::Healthy => Style::default().fg(Color::Green),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct State {
     pub connection_status: ConnectionStatus,
@@ -114,15 +154,26 @@ pub struct ComponentRow {
     pub outputs: HashMap<String, OutputMetrics>,
     pub received_bytes_total: i64,
     pub received_bytes_throughput_sec: i64,
+    /// Estimated size of the events received, or `None` if the component doesn't report it
+    pub received_event_bytes_total: Option<i64>,
+    pub received_event_bytes_throughput_sec: i64,
     pub received_events_total: i64,
     pub received_events_throughput_sec: i64,
     pub sent_bytes_total: i64,
     pub sent_bytes_throughput_sec: i64,
+    /// Estimated size of the events sent, or `None` if the component doesn't report it
+    pub sent_event_bytes_total: Option<i64>,
+    pub sent_event_bytes_throughput_sec: i64,
     pub sent_events_total: i64,
     pub sent_events_throughput_sec: i64,
     #[cfg(feature = "allocation-tracing")]
     pub allocated_bytes: i64,
     pub errors: i64,
+    /// Only sinks have healthchecks
+    pub health: Option<ComponentHealth>,
+    pub send_in_flight: i64,
+    /// Share of time spent sending payloads, or `None` if the sink doesn't report it
+    pub send_utilization: Option<f64>,
 }
 
 impl ComponentRow {
@@ -162,6 +213,21 @@ pub async fn updater(mut event_rx: EventRx) -> StateRx {
                         }
                     }
                 }
+                EventType::ReceivedEventBytesTotals(rows) => {
+                    for (key, v) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.received_event_bytes_total = Some(v);
+                        }
+                    }
+                }
+                EventType::ReceivedEventBytesThroughputs(interval, rows) => {
+                    for (key, v) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.received_event_bytes_throughput_sec =
+                                (v as f64 * (1000.0 / interval as f64)) as i64;
+                        }
+                    }
+                }
                 EventType::ReceivedEventsTotals(rows) => {
                     for (key, v) in rows {
                         if let Some(r) = state.components.get_mut(&key) {
@@ -192,6 +258,21 @@ pub async fn updater(mut event_rx: EventRx) -> StateRx {
                         }
                     }
                 }
+                EventType::SentEventBytesTotals(rows) => {
+                    for (key, v) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.sent_event_bytes_total = Some(v);
+                        }
+                    }
+                }
+                EventType::SentEventBytesThroughputs(interval, rows) => {
+                    for (key, v) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.sent_event_bytes_throughput_sec =
+                                (v as f64 * (1000.0 / interval as f64)) as i64;
+                        }
+                    }
+                }
                 EventType::SentEventsTotals(rows) => {
                     for m in rows {
                         if let Some(r) = state.components.get_mut(&m.key) {
@@ -227,6 +308,27 @@ pub async fn updater(mut event_rx: EventRx) -> StateRx {
                         }
                     }
                 }
+                EventType::HealthStatuses(rows) => {
+                    for (key, health) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.health = Some(health);
+                        }
+                    }
+                }
+                EventType::SendInFlights(rows) => {
+                    for (key, v) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.send_in_flight = v;
+                        }
+                    }
+                }
+                EventType::SendUtilizations(rows) => {
+                    for (key, v) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.send_utilization = Some(v);
+                        }
+                    }
+                }
                 #[cfg(feature = "allocation-tracing")]
                 EventType::AllocatedBytes(rows) => {
                     for (key, v) in rows {
@@ -0,0 +1,177 @@
+//! Watches the acknowledgement of the events a sink receives.
+//!
+//! The finalizers of the events given to a watched sink are replaced by a new batch. Once the sink
+//! finalizes that batch, its status is forwarded to the original finalizers. This allows bounding
+//! the time a sink may take to acknowledge events, and resolving the events of a sink that is
+//! killed during shutdown, instead of letting the sink hold back its sources.
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use futures::{future, FutureExt};
+use stream_cancel::Tripwire;
+use tokio::time::sleep;
+use tracing::Instrument;
+use vector_lib::{
+    finalization::{AddBatchNotifier, BatchNotifier, BatchStatus, EventStatus, Finalizable},
+    trigger::DisabledTrigger,
+};
+
+use crate::{
+    event::{EventArray, EventContainer},
+    internal_events::AcknowledgementTimedOut,
+    shutdown::tripwire_handler,
+};
+
+/// Watches the acknowledgement of the events given to a sink.
+#[derive(Clone)]
+pub(super) struct AcknowledgementWatcher {
+    timeout: Option<Duration>,
+    pending: Arc<AtomicUsize>,
+    abandoned: Tripwire,
+}
+
+impl AcknowledgementWatcher {
+    /// Creates a watcher reporting the events that are not acknowledged within `timeout` as
+    /// errored, along with the handle used to abandon the events that are still pending.
+    pub(super) fn new(timeout: Option<Duration>) -> (Self, Abandon) {
+        let (trigger, abandoned) = Tripwire::new();
+        let pending = Arc::new(AtomicUsize::new(0));
+        let abandon = Abandon {
+            pending: Arc::clone(&pending),
+            trigger: DisabledTrigger::new(trigger),
+        };
+        let watcher = Self {
+            timeout,
+            pending,
+            abandoned,
+        };
+        (watcher, abandon)
+    }
+
+    pub(super) fn watch(&self, mut events: EventArray) -> EventArray {
+        let finalizers = events.take_finalizers();
+        if finalizers.is_empty() {
+            return events;
+        }
+
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        events.add_batch_notifier(batch);
+
+        let count = events.len();
+        let timeout = self.timeout;
+        let pending = Arc::clone(&self.pending);
+        let abandoned = self.abandoned.clone().then(tripwire_handler);
+        pending.fetch_add(count, Ordering::Relaxed);
+
+        tokio::spawn(
+            async move {
+                let timed_out = match timeout {
+                    Some(timeout) => sleep(timeout).boxed(),
+                    None => future::pending().boxed(),
+                };
+                let status = tokio::select! {
+                    status = receiver => match status {
+                        BatchStatus::Delivered => EventStatus::Delivered,
+                        BatchStatus::Errored => EventStatus::Errored,
+                        BatchStatus::Rejected => EventStatus::Rejected,
+                    },
+                    () = timed_out => {
+                        emit!(AcknowledgementTimedOut {
+                            count,
+                            timeout: timeout.unwrap_or_default(),
+                        });
+                        EventStatus::Errored
+                    }
+                    () = abandoned => EventStatus::Errored,
+                };
+                finalizers.update_status(status);
+                pending.fetch_sub(count, Ordering::Relaxed);
+            }
+            .in_current_span(),
+        );
+
+        events
+    }
+}
+
+/// Abandons the events a sink has not acknowledged yet.
+///
+/// Dropping the handle leaves the pending events to the sink.
+pub(super) struct Abandon {
+    pending: Arc<AtomicUsize>,
+    trigger: DisabledTrigger,
+}
+
+impl Abandon {
+    /// The number of events whose acknowledgement is pending.
+    pub(super) fn pending(&self) -> usize {
+        self.pending.load(Ordering::Relaxed)
+    }
+
+    /// Reports the pending events as errored.
+    pub(super) fn abandon(self) {
+        self.trigger.into_inner().cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_lib::{event::LogEvent, finalization::BatchStatusReceiver};
+
+    use super::*;
+
+    fn batch() -> (EventArray, BatchStatusReceiver) {
+        let (batch, receiver) = BatchNotifier::new_with_receiver();
+        let events = vec![
+            LogEvent::from("one").with_batch_notifier(&batch),
+            LogEvent::from("two").with_batch_notifier(&batch),
+        ];
+        (EventArray::Logs(events), receiver)
+    }
+
+    #[tokio::test]
+    async fn forwards_sink_status() {
+        let (watcher, abandon) = AcknowledgementWatcher::new(Some(Duration::from_secs(60)));
+        let (events, receiver) = batch();
+        let mut events = watcher.watch(events);
+        assert_eq!(abandon.pending(), 2);
+
+        events
+            .take_finalizers()
+            .update_status(EventStatus::Rejected);
+        drop(events);
+        assert_eq!(receiver.await, BatchStatus::Rejected);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn errors_forgotten_events() {
+        let (watcher, _abandon) = AcknowledgementWatcher::new(Some(Duration::from_secs(60)));
+        let (events, receiver) = batch();
+        let mut events = watcher.watch(events);
+
+        // The sink holds on to the finalizers without ever resolving them.
+        let forgotten = events.take_finalizers();
+        assert_eq!(receiver.await, BatchStatus::Errored);
+
+        forgotten.update_status(EventStatus::Delivered);
+    }
+
+    #[tokio::test]
+    async fn errors_abandoned_events() {
+        let (watcher, abandon) = AcknowledgementWatcher::new(None);
+        let (events, receiver) = batch();
+        let mut events = watcher.watch(events);
+
+        let forgotten = events.take_finalizers();
+        abandon.abandon();
+        assert_eq!(receiver.await, BatchStatus::Errored);
+
+        forgotten.update_status(EventStatus::Delivered);
+    }
+}
@@ -3,16 +3,15 @@ use std::{
     future::ready,
     num::NonZeroUsize,
     sync::{Arc, LazyLock, Mutex},
-    time::Instant,
+    time::{Duration, Instant},
 };
 
-use futures::{stream::FuturesOrdered, FutureExt, StreamExt, TryStreamExt};
+use futures::{future, stream::FuturesOrdered, FutureExt, StreamExt, TryStreamExt};
 use futures_util::stream::FuturesUnordered;
 use stream_cancel::{StreamExt as StreamCancelExt, Trigger, Tripwire};
 use tokio::{
     select,
     sync::{mpsc::UnboundedSender, oneshot},
-    time::{timeout, Duration},
 };
 use tracing::Instrument;
 use vector_lib::config::LogNamespace;
@@ -33,20 +32,24 @@ use vector_lib::{
 };
 
 use super::{
+    acknowledgements::{Abandon, AcknowledgementWatcher},
     fanout::{self, Fanout},
+    health::{HealthcheckFailure, HealthcheckResult, Healthchecker},
     schema,
     task::{Task, TaskOutput, TaskResult},
     BuiltBuffer, ConfigDiff,
 };
 use crate::{
     config::{
-        ComponentKey, Config, DataType, EnrichmentTableConfig, Input, Inputs, OutputId,
-        ProxyConfig, SinkContext, SourceContext, TransformContext, TransformOuter, TransformOutput,
+        ComponentKey, Config, DataType, EnrichmentTableConfig, HealthcheckOptions, Input, Inputs,
+        OutputId, ProxyConfig, SinkContext, SinkUpdater, SourceContext, TransformContext,
+        TransformOuter, TransformOutput,
     },
     event::{EventArray, EventContainer},
     extra_context::ExtraContext,
     internal_events::EventsReceived,
     shutdown::SourceShutdownCoordinator,
+    sinks::util::dropped::{DroppedOutput, DROPPED},
     source_sender::{SourceSenderItem, CHUNK_SIZE},
     spawn_named,
     topology::task::TaskError,
@@ -81,8 +84,10 @@ struct Builder<'a> {
     tasks: HashMap<ComponentKey, Task>,
     buffers: HashMap<ComponentKey, BuiltBuffer>,
     inputs: HashMap<ComponentKey, (BufferSender<EventArray>, Inputs<OutputId>)>,
-    healthchecks: HashMap<ComponentKey, Task>,
+    healthchecks: HashMap<ComponentKey, SinkHealthcheck>,
     detach_triggers: HashMap<ComponentKey, Trigger>,
+    sink_shutdowns: HashMap<ComponentKey, SinkShutdown>,
+    sink_updaters: HashMap<ComponentKey, SinkUpdater>,
     extra_context: ExtraContext,
 }
 
@@ -104,6 +109,8 @@ impl<'a> Builder<'a> {
             inputs: HashMap::new(),
             healthchecks: HashMap::new(),
             detach_triggers: HashMap::new(),
+            sink_shutdowns: HashMap::new(),
+            sink_updaters: HashMap::new(),
             extra_context,
         }
     }
@@ -128,6 +135,8 @@ impl<'a> Builder<'a> {
                 healthchecks: self.healthchecks,
                 shutdown_coordinator: self.shutdown_coordinator,
                 detach_triggers: self.detach_triggers,
+                sink_shutdowns: self.sink_shutdowns,
+                sink_updaters: self.sink_updaters,
             })
         } else {
             Err(self.errors)
@@ -205,6 +214,7 @@ impl<'a> Builder<'a> {
 
     async fn build_sources(&mut self) -> HashMap<ComponentKey, Task> {
         let mut source_tasks = HashMap::new();
+        let host_metadata = self.config.global.host_metadata.load().map(Arc::new);
 
         for (key, source) in self
             .config
@@ -230,7 +240,10 @@ impl<'a> Builder<'a> {
                 key.id()
             );
 
-            let mut builder = SourceSender::builder().with_buffer(*SOURCE_SENDER_BUFFER_SIZE);
+            let mut builder = SourceSender::builder()
+                .with_buffer(*SOURCE_SENDER_BUFFER_SIZE)
+                .with_host_metadata(host_metadata.clone())
+                .with_origin_metadata(self.config.global.origin_metadata().then_some(typetag));
             let mut pumps = Vec::new();
             let mut controls = HashMap::new();
             let mut schema_definitions = HashMap::with_capacity(source_outputs.len());
@@ -516,9 +529,27 @@ impl<'a> Builder<'a> {
             let sink_inputs = &sink.inputs;
             let healthcheck = sink.healthcheck();
             let enable_healthcheck = healthcheck.enabled && self.config.healthchecks.enabled;
+            let require_healthy = healthcheck
+                .require_healthy
+                .unwrap_or(self.config.healthchecks.require_healthy);
 
             let typetag = sink.inner.get_component_name();
             let input_type = sink.inner.input().data_type();
+            let acknowledgement_timeout = sink
+                .inner
+                .acknowledgements()
+                .merge_default(&self.config.global.acknowledgements)
+                .timeout();
+            let shutdown_timeout = sink.graceful_shutdown_timeout();
+            let acknowledgements =
+                (acknowledgement_timeout.is_some() || shutdown_timeout.is_some()).then(|| {
+                    let (watcher, abandon) = AcknowledgementWatcher::new(acknowledgement_timeout);
+                    if let Some(timeout) = shutdown_timeout {
+                        self.sink_shutdowns
+                            .insert(key.clone(), SinkShutdown { timeout, abandon });
+                    }
+                    watcher
+                });
 
             let span = error_span!(
                 "sink",
@@ -565,16 +596,80 @@ impl<'a> Builder<'a> {
                 }
             };
 
-            let cx = SinkContext {
+            let mut cx = SinkContext {
                 healthcheck,
                 globals: self.config.global.clone(),
                 proxy: ProxyConfig::merge_with_env(&self.config.global.proxy, sink.proxy()),
                 schema: self.config.schema,
                 app_name: crate::get_app_name().to_string(),
                 app_name_slug: crate::get_slugified_app_name(),
+                dropped_output: None,
+                updates: None,
                 extra_context: self.extra_context.clone(),
             };
 
+            if !sink.inner.reloadable_fields().is_empty() {
+                let (updater, updates) = SinkUpdater::new(sink.inner.as_ref());
+                self.sink_updaters.insert(key.clone(), updater);
+                cx.updates = Some(updates);
+            }
+
+            let healthcheck_builder = if enable_healthcheck {
+                match sink.inner.healthcheck_builder(&cx).await {
+                    Err(error) => {
+                        self.errors.push(format!("Sink \"{}\": {}", key, error));
+                        continue;
+                    }
+                    Ok(None)
+                        if cx.healthcheck.retries > 0 || cx.healthcheck.interval_secs.is_some() =>
+                    {
+                        warn!(
+                            message = "Sink cannot re-run its healthcheck, ignoring `healthcheck.retries` and `healthcheck.interval_secs`."
+                        );
+                        None
+                    }
+                    Ok(builder) => builder,
+                }
+            } else {
+                None
+            };
+            let healthchecker = Arc::new(Healthchecker::new(
+                key.clone(),
+                healthcheck_builder,
+                cx.healthcheck.clone(),
+                HealthcheckOptions {
+                    require_healthy,
+                    ..self.config.healthchecks
+                },
+            ));
+            let startup_healthchecker = Arc::clone(&healthchecker);
+            let initial_delay = cx.healthcheck.initial_delay_secs;
+            let healthcheck_interval = healthchecker.interval().filter(|_| enable_healthcheck);
+
+            // Only the running sink gets the `dropped` output, so that the output is closed once
+            // the sink finishes.
+            let dropped_pump = sink.reroute_dropped().then(|| {
+                let (output, mut rx) = DroppedOutput::new(key.clone(), typetag);
+                let (mut fanout, control) = Fanout::new();
+                self.outputs.insert(
+                    OutputId {
+                        component: key.clone(),
+                        port: Some(DROPPED.to_owned()),
+                    },
+                    control,
+                );
+                cx.dropped_output = Some(output);
+
+                async move {
+                    while let Some(events) = rx.recv().await {
+                        if let Err(error) = fanout.send(events, None).await {
+                            debug!(message = "Dropped output pump finished with an error.", %error);
+                            return;
+                        }
+                    }
+                }
+            });
+
             let (sink, healthcheck) = match sink.inner.build(cx).await {
                 Err(error) => {
                     self.errors.push(format!("Sink \"{}\": {}", key, error));
@@ -603,7 +698,7 @@ impl<'a> Builder<'a> {
                 let mut rx = wrap(rx);
 
                 let events_received = register!(EventsReceived);
-                sink.run(
+                let run = sink.run(
                     rx.by_ref()
                         .filter(|events: &EventArray| ready(filter_events_type(events, input_type)))
                         .inspect(|events| {
@@ -612,62 +707,109 @@ impl<'a> Builder<'a> {
                                 events.estimated_json_encoded_size_of(),
                             ))
                         })
+                        .map(|events| match &acknowledgements {
+                            Some(watcher) => watcher.watch(events),
+                            None => events,
+                        })
                         .take_until_if(tripwire),
-                )
-                .await
-                .map(|_| {
-                    debug!("Sink finished normally.");
-                    TaskOutput::Sink(rx)
-                })
-                .map_err(|_| {
-                    debug!("Sink finished with an error.");
-                    TaskError::Opaque
-                })
+                );
+
+                // Periodic healthchecks run for as long as the sink does.
+                let run = async move {
+                    match healthcheck_interval {
+                        Some(interval) => select! {
+                            result = run => result,
+                            never = healthchecker.run_periodically(interval) => match never {},
+                        },
+                        None => run.await,
+                    }
+                };
+
+                // The events rerouted to the `dropped` output are forwarded until the sink is done
+                // rerouting them.
+                let result = match dropped_pump {
+                    Some(pump) => future::join(run, pump).await.0,
+                    None => run.await,
+                };
+
+                result
+                    .map(|_| {
+                        debug!("Sink finished normally.");
+                        TaskOutput::Sink(rx)
+                    })
+                    .map_err(|_| {
+                        debug!("Sink finished with an error.");
+                        TaskError::Opaque
+                    })
             };
 
             let task = Task::new(key.clone(), typetag, sink);
 
-            let component_key = key.clone();
+            // Like the sink itself, the healthcheck runs within the span of the component, so that
+            // its logs and metrics are tagged with it.
+            let healthcheck_span = error_span!(
+                "sink",
+                component_kind = "sink",
+                component_id = %key.id(),
+                component_type = typetag,
+            );
             let healthcheck_task = async move {
-                if enable_healthcheck {
-                    let duration = Duration::from_secs(10);
-                    timeout(duration, healthcheck)
-                        .map(|result| match result {
-                            Ok(Ok(_)) => {
-                                info!("Healthcheck passed.");
-                                Ok(TaskOutput::Healthcheck)
-                            }
-                            Ok(Err(error)) => {
-                                error!(
-                                    msg = "Healthcheck failed.",
-                                    %error,
-                                    component_kind = "sink",
-                                    component_type = typetag,
-                                    component_id = %component_key.id(),
-                                );
-                                Err(TaskError::wrapped(error))
-                            }
-                            Err(e) => {
-                                error!(
-                                    msg = "Healthcheck timed out.",
-                                    component_kind = "sink",
-                                    component_type = typetag,
-                                    component_id = %component_key.id(),
-                                );
-                                Err(TaskError::wrapped(Box::new(e)))
-                            }
-                        })
-                        .await
-                } else {
+                if !enable_healthcheck {
                     info!("Healthcheck disabled.");
-                    Ok(TaskOutput::Healthcheck)
+                    return Ok(TaskOutput::Healthcheck);
                 }
-            };
+
+                if !initial_delay.is_zero() {
+                    debug!(
+                        message = "Delaying healthcheck.",
+                        delay_secs = initial_delay.as_secs_f64(),
+                    );
+                    tokio::time::sleep(initial_delay).await;
+                }
+
+                match startup_healthchecker.check(healthcheck).await {
+                    HealthcheckResult::Healthy => {
+                        info!("Healthcheck passed.");
+                        Ok(TaskOutput::Healthcheck)
+                    }
+                    HealthcheckResult::Degraded(reason) => {
+                        warn!(
+                            message = "Healthcheck reported the sink as degraded.",
+                            %reason,
+                        );
+                        if startup_healthchecker.allow_degraded() {
+                            Ok(TaskOutput::DegradedHealthcheck(reason))
+                        } else {
+                            Err(TaskError::wrapped(
+                                format!("Sink is degraded: {reason}").into(),
+                            ))
+                        }
+                    }
+                    HealthcheckResult::Failed(HealthcheckFailure::Failed(error)) => {
+                        error!(
+                            message = "Healthcheck failed.",
+                            %error,
+                        );
+                        Err(TaskError::wrapped(error))
+                    }
+                    HealthcheckResult::Failed(failure @ HealthcheckFailure::TimedOut) => {
+                        error!(message = "Healthcheck timed out.");
+                        Err(TaskError::wrapped(Box::new(failure)))
+                    }
+                }
+            }
+            .instrument(healthcheck_span);
 
             let healthcheck_task = Task::new(key.clone(), typetag, healthcheck_task);
 
             self.inputs.insert(key.clone(), (tx, sink_inputs.clone()));
-            self.healthchecks.insert(key.clone(), healthcheck_task);
+            self.healthchecks.insert(
+                key.clone(),
+                SinkHealthcheck {
+                    task: healthcheck_task,
+                    required: require_healthy,
+                },
+            );
             self.tasks.insert(key.clone(), task);
             self.detach_triggers.insert(key.clone(), trigger);
         }
@@ -679,9 +821,24 @@ pub struct TopologyPieces {
     pub(crate) outputs: HashMap<ComponentKey, HashMap<Option<String>, fanout::ControlChannel>>,
     pub(super) tasks: HashMap<ComponentKey, Task>,
     pub(crate) source_tasks: HashMap<ComponentKey, Task>,
-    pub(super) healthchecks: HashMap<ComponentKey, Task>,
+    pub(super) healthchecks: HashMap<ComponentKey, SinkHealthcheck>,
     pub(crate) shutdown_coordinator: SourceShutdownCoordinator,
     pub(crate) detach_triggers: HashMap<ComponentKey, Trigger>,
+    pub(super) sink_shutdowns: HashMap<ComponentKey, SinkShutdown>,
+    pub(super) sink_updaters: HashMap<ComponentKey, SinkUpdater>,
+}
+
+/// The healthcheck of a sink, run before the sink is connected to the topology.
+pub(crate) struct SinkHealthcheck {
+    pub(crate) task: Task,
+    /// Whether the sink must pass its healthcheck for the topology to start, or reload.
+    pub(crate) required: bool,
+}
+
+/// How a sink with its own graceful shutdown timeout is killed when it exceeds it.
+pub(super) struct SinkShutdown {
+    pub(super) timeout: Duration,
+    pub(super) abandon: Abandon,
 }
 
 impl TopologyPieces {
@@ -0,0 +1,631 @@
+//! The health of sinks, as reported by their healthchecks.
+//!
+//! A sink's healthcheck runs when the sink starts and, when `healthcheck.interval_secs` is set,
+//! periodically for as long as the sink runs. The latest result of each sink is kept here so that
+//! it can be reported through the API. Each result is also reported by the
+//! `component_healthcheck_status` and `component_healthcheck_duration_seconds` internal metrics.
+//!
+//! Besides passing or failing, a healthcheck can report its sink as
+//! [`HealthcheckStatus::Degraded`]: the sink is functional, but not in its nominal state. Degraded
+//! sinks are considered passing unless `healthchecks.allow_degraded` is disabled.
+//!
+//! The healthchecks of the sinks that start together run concurrently, bounded by
+//! `healthchecks.deadline_secs` as a whole, and their outcomes are logged as a summary.
+
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    fmt,
+    sync::{LazyLock, RwLock},
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use futures::{future, stream, StreamExt};
+use tokio::time::{sleep, timeout, timeout_at, Instant};
+
+use super::task::{Task, TaskOutput, TaskResult};
+use crate::{
+    config::{ComponentKey, HealthcheckOptions, SinkHealthcheckOptions},
+    internal_events::{SinkHealthChanged, SinkHealthcheckCompleted},
+    sinks::{Healthcheck, HealthcheckBuilder, HealthcheckStatus},
+};
+
+/// The maximum number of startup healthchecks running at the same time.
+const CONCURRENCY: usize = 16;
+
+static HEALTH: LazyLock<RwLock<HashMap<ComponentKey, SinkHealth>>> =
+    LazyLock::new(Default::default);
+
+/// Whether a sink passed its latest healthcheck.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Unhealthy,
+}
+
+impl HealthStatus {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Healthy => "healthy",
+            Self::Degraded => "degraded",
+            Self::Unhealthy => "unhealthy",
+        }
+    }
+}
+
+/// The result of the latest healthcheck of a sink.
+#[derive(Clone, Debug)]
+pub struct SinkHealth {
+    pub status: HealthStatus,
+    /// The reason the sink is degraded, or the error it failed with.
+    pub last_error: Option<String>,
+    pub last_check: DateTime<Utc>,
+    /// Whether the sink is required to be healthy, in which case Vector is reported as unhealthy
+    /// while the sink is.
+    pub required: bool,
+    /// Whether being degraded counts as healthy for a sink that is required to be healthy.
+    pub allow_degraded: bool,
+}
+
+impl SinkHealth {
+    /// Whether the sink passed its latest healthcheck.
+    pub fn is_passing(&self) -> bool {
+        match self.status {
+            HealthStatus::Healthy => true,
+            HealthStatus::Degraded => self.allow_degraded,
+            HealthStatus::Unhealthy => false,
+        }
+    }
+}
+
+/// Returns the result of the latest healthcheck of a sink, if it ran.
+pub fn get(key: &ComponentKey) -> Option<SinkHealth> {
+    HEALTH.read().unwrap().get(key).cloned()
+}
+
+/// Returns the sinks that are required to be healthy but failed their latest healthcheck.
+pub fn unhealthy_required() -> Vec<ComponentKey> {
+    let mut keys = HEALTH
+        .read()
+        .unwrap()
+        .iter()
+        .filter(|(_, health)| health.required && !health.is_passing())
+        .map(|(key, _)| key.clone())
+        .collect::<Vec<_>>();
+    keys.sort();
+    keys
+}
+
+/// Forgets the health of a sink that was removed from the topology.
+pub(super) fn remove(key: &ComponentKey) {
+    HEALTH.write().unwrap().remove(key);
+}
+
+fn record(
+    key: &ComponentKey,
+    result: &HealthcheckResult,
+    options: HealthcheckOptions,
+    duration: Duration,
+) {
+    let (status, error) = match result {
+        HealthcheckResult::Healthy => (HealthStatus::Healthy, None),
+        HealthcheckResult::Degraded(reason) => (HealthStatus::Degraded, Some(reason.clone())),
+        HealthcheckResult::Failed(failure) => (HealthStatus::Unhealthy, Some(failure.to_string())),
+    };
+    let previous = HEALTH.write().unwrap().insert(
+        key.clone(),
+        SinkHealth {
+            status,
+            last_error: error.clone(),
+            last_check: Utc::now(),
+            required: options.require_healthy,
+            allow_degraded: options.allow_degraded,
+        },
+    );
+
+    emit!(SinkHealthcheckCompleted { status, duration });
+
+    // The first result is reported by the healthcheck itself, only changes are reported here.
+    if previous.is_some_and(|previous| previous.status != status) {
+        emit!(SinkHealthChanged {
+            component_id: key,
+            status,
+            error: error.as_deref(),
+        });
+    }
+}
+
+/// The result of a healthcheck.
+#[derive(Debug)]
+pub(super) enum HealthcheckResult {
+    Healthy,
+    /// The sink is functional, but not in its nominal state.
+    Degraded(String),
+    Failed(HealthcheckFailure),
+}
+
+/// Why a healthcheck did not pass.
+#[derive(Debug)]
+pub(super) enum HealthcheckFailure {
+    Failed(crate::Error),
+    TimedOut,
+}
+
+impl fmt::Display for HealthcheckFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Failed(error) => error.fmt(f),
+            Self::TimedOut => f.write_str("Healthcheck timed out."),
+        }
+    }
+}
+
+impl std::error::Error for HealthcheckFailure {}
+
+/// How a startup healthcheck ended.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Outcome {
+    Passed,
+    /// The sink is degraded, which its `healthcheck.allow_degraded` option accepts.
+    Degraded,
+    Failed,
+    /// The healthcheck had not completed by `healthchecks.deadline_secs`.
+    DeadlineExceeded,
+}
+
+impl Outcome {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Passed => "passed",
+            Self::Degraded => "degraded",
+            Self::Failed => "failed",
+            Self::DeadlineExceeded => "deadline exceeded",
+        }
+    }
+
+    /// Whether the sink can be started, which degraded sinks can.
+    pub(crate) const fn is_passing(self) -> bool {
+        matches!(self, Self::Passed | Self::Degraded)
+    }
+
+    /// How a completed healthcheck task ended, and why the sink is not healthy, if it is not.
+    pub(crate) fn of(result: TaskResult) -> (Self, Option<String>) {
+        match result {
+            Ok(TaskOutput::DegradedHealthcheck(reason)) => (Self::Degraded, Some(reason)),
+            Ok(_) => (Self::Passed, None),
+            Err(error) => (Self::Failed, Some(error.to_string())),
+        }
+    }
+}
+
+/// The outcome of a single startup healthcheck.
+#[derive(Debug)]
+pub(crate) struct HealthcheckReport {
+    pub(crate) key: ComponentKey,
+    pub(crate) outcome: Outcome,
+    pub(crate) duration: Duration,
+    /// Why the sink is degraded, or why its healthcheck failed.
+    pub(crate) error: Option<String>,
+}
+
+/// The outcomes of a set of startup healthchecks, sorted by component.
+#[derive(Debug)]
+pub(crate) struct HealthcheckSummary {
+    results: Vec<HealthcheckReport>,
+}
+
+impl HealthcheckSummary {
+    /// Whether all the healthchecks passed.
+    pub(super) fn passed(&self) -> bool {
+        self.results
+            .iter()
+            .all(|report| report.outcome.is_passing())
+    }
+
+    /// The components whose healthcheck did not pass.
+    pub(super) fn failed(&self) -> Vec<&ComponentKey> {
+        self.results
+            .iter()
+            .filter(|report| !report.outcome.is_passing())
+            .map(|report| &report.key)
+            .collect()
+    }
+
+    /// The outcome of each healthcheck.
+    pub(crate) fn reports(&self) -> &[HealthcheckReport] {
+        &self.results
+    }
+
+    /// Logs the outcome and duration of each healthcheck, one component per line.
+    pub(super) fn log(&self) {
+        let width = self
+            .results
+            .iter()
+            .map(|report| report.key.id().len())
+            .max()
+            .unwrap_or_default();
+        let summary = self
+            .results
+            .iter()
+            .map(|report| {
+                format!(
+                    "{:width$}  {:17}  {:.3?}",
+                    report.key.id(),
+                    report.outcome.as_str(),
+                    report.duration,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        info!(
+            message = "Healthchecks completed.",
+            passed = self.results.len() - self.failed().len(),
+            failed = self.failed().len(),
+            summary = %format_args!("\n{summary}"),
+        );
+    }
+}
+
+/// Runs the startup healthchecks of a set of sinks concurrently, up to `deadline` as a whole.
+pub(crate) async fn run_all(
+    healthchecks: Vec<(ComponentKey, Task)>,
+    deadline: Option<Duration>,
+) -> HealthcheckSummary {
+    let deadline = deadline.map(|deadline| Instant::now() + deadline);
+    let mut results = stream::iter(healthchecks)
+        .map(|(key, task)| async move {
+            let start = Instant::now();
+            let result = match deadline {
+                Some(deadline) => timeout_at(deadline, task).await.ok(),
+                None => Some(task.await),
+            };
+            let (outcome, error) = match result {
+                Some(result) => Outcome::of(result),
+                None => (Outcome::DeadlineExceeded, None),
+            };
+            HealthcheckReport {
+                key,
+                outcome,
+                duration: start.elapsed(),
+                error,
+            }
+        })
+        .buffer_unordered(CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await;
+    results.sort_by(|a, b| a.key.cmp(&b.key));
+    HealthcheckSummary { results }
+}
+
+/// Runs the healthchecks of a sink and records their results.
+///
+/// Healthchecks are only retried and re-run periodically for sinks that provide a
+/// [`HealthcheckBuilder`], as a healthcheck can only run once.
+pub(super) struct Healthchecker {
+    key: ComponentKey,
+    builder: Option<HealthcheckBuilder>,
+    sink_options: SinkHealthcheckOptions,
+    options: HealthcheckOptions,
+}
+
+impl Healthchecker {
+    pub(super) const fn new(
+        key: ComponentKey,
+        builder: Option<HealthcheckBuilder>,
+        sink_options: SinkHealthcheckOptions,
+        options: HealthcheckOptions,
+    ) -> Self {
+        Self {
+            key,
+            builder,
+            sink_options,
+            options,
+        }
+    }
+
+    /// The interval at which the healthcheck is re-run, if it is.
+    pub(super) fn interval(&self) -> Option<Duration> {
+        self.builder.as_ref().and(self.sink_options.interval_secs)
+    }
+
+    /// Whether a degraded sink passes its healthcheck.
+    pub(super) const fn allow_degraded(&self) -> bool {
+        self.options.allow_degraded
+    }
+
+    /// Runs a healthcheck, retrying it up to `healthcheck.retries` times.
+    ///
+    /// Degraded sinks are functional, so their healthcheck is not retried.
+    pub(super) async fn check(&self, mut healthcheck: Healthcheck) -> HealthcheckResult {
+        let options = &self.sink_options;
+        let start = Instant::now();
+        let mut attempt = 0;
+        let result = loop {
+            let failure = match timeout(options.timeout_secs, healthcheck).await {
+                Ok(Ok(HealthcheckStatus::Healthy)) => break HealthcheckResult::Healthy,
+                Ok(Ok(HealthcheckStatus::Degraded(reason))) => {
+                    break HealthcheckResult::Degraded(reason)
+                }
+                Ok(Err(error)) => HealthcheckFailure::Failed(error),
+                Err(_) => HealthcheckFailure::TimedOut,
+            };
+            let Some(build) = self.builder.as_ref().filter(|_| attempt < options.retries) else {
+                break HealthcheckResult::Failed(failure);
+            };
+
+            attempt += 1;
+            warn!(
+                message = "Healthcheck failed, retrying.",
+                error = %failure,
+                attempt,
+                retries = options.retries,
+            );
+            sleep(options.retry_backoff_secs).await;
+            healthcheck = build();
+        };
+
+        record(&self.key, &result, self.options, start.elapsed());
+        result
+    }
+
+    /// Re-runs the healthcheck every `interval`, for as long as the returned future is polled.
+    ///
+    /// This runs within the span of the sink, like the startup healthcheck does. The healthcheck
+    /// of a sink that cannot build it again is never re-run.
+    pub(super) async fn run_periodically(&self, interval: Duration) -> Infallible {
+        let Some(build) = &self.builder else {
+            return future::pending().await;
+        };
+        loop {
+            sleep(interval).await;
+            match self.check(build()).await {
+                HealthcheckResult::Healthy => (),
+                HealthcheckResult::Degraded(reason) => {
+                    debug!(message = "Periodic healthcheck reported a degraded sink.", %reason);
+                }
+                HealthcheckResult::Failed(error) => {
+                    debug!(message = "Periodic healthcheck failed.", %error);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use futures::FutureExt;
+
+    use super::*;
+    use crate::{sinks::healthy, topology::task::TaskError};
+
+    fn healthchecker(key: &ComponentKey, required: bool, allow_degraded: bool) -> Healthchecker {
+        Healthchecker::new(
+            key.clone(),
+            None,
+            SinkHealthcheckOptions::default(),
+            HealthcheckOptions {
+                require_healthy: required,
+                allow_degraded,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn degraded(reason: &str) -> Healthcheck {
+        future::ok(HealthcheckStatus::Degraded(reason.to_owned())).boxed()
+    }
+
+    #[tokio::test]
+    async fn records_latest_result() {
+        let key = ComponentKey::from("health_records_latest_result");
+        let checker = healthchecker(&key, true, true);
+
+        let failing = future::ready(Err("unreachable".into())).boxed();
+        assert!(matches!(
+            checker.check(failing).await,
+            HealthcheckResult::Failed(_)
+        ));
+        let health = get(&key).unwrap();
+        assert_eq!(health.status, HealthStatus::Unhealthy);
+        assert_eq!(health.last_error.as_deref(), Some("unreachable"));
+        assert!(unhealthy_required().contains(&key));
+
+        assert!(matches!(
+            checker.check(healthy()).await,
+            HealthcheckResult::Healthy
+        ));
+        let health = get(&key).unwrap();
+        assert_eq!(health.status, HealthStatus::Healthy);
+        assert_eq!(health.last_error, None);
+        assert!(!unhealthy_required().contains(&key));
+
+        remove(&key);
+        assert!(get(&key).is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn optional_sinks_are_not_required() {
+        let key = ComponentKey::from("health_optional_sinks_are_not_required");
+        let checker = healthchecker(&key, false, true);
+
+        assert!(matches!(
+            checker.check(future::pending().boxed()).await,
+            HealthcheckResult::Failed(HealthcheckFailure::TimedOut)
+        ));
+        assert_eq!(get(&key).unwrap().status, HealthStatus::Unhealthy);
+        assert!(!unhealthy_required().contains(&key));
+        remove(&key);
+    }
+
+    #[tokio::test]
+    async fn degraded_sinks_pass_when_allowed() {
+        let key = ComponentKey::from("health_degraded_sinks_pass_when_allowed");
+        let checker = healthchecker(&key, true, true);
+
+        assert!(matches!(
+            checker.check(degraded("one endpoint unreachable")).await,
+            HealthcheckResult::Degraded(reason) if reason == "one endpoint unreachable"
+        ));
+        let health = get(&key).unwrap();
+        assert_eq!(health.status, HealthStatus::Degraded);
+        assert_eq!(
+            health.last_error.as_deref(),
+            Some("one endpoint unreachable")
+        );
+        assert!(!unhealthy_required().contains(&key));
+        remove(&key);
+    }
+
+    #[tokio::test]
+    async fn degraded_sinks_fail_when_disallowed() {
+        let key = ComponentKey::from("health_degraded_sinks_fail_when_disallowed");
+        let checker = healthchecker(&key, true, false);
+
+        checker.check(degraded("one endpoint unreachable")).await;
+        assert_eq!(get(&key).unwrap().status, HealthStatus::Degraded);
+        assert!(unhealthy_required().contains(&key));
+        remove(&key);
+    }
+
+    /// A healthcheck builder whose healthchecks fail `failures` times before passing, and the
+    /// number of healthchecks it built.
+    fn flaky(failures: usize) -> (HealthcheckBuilder, Arc<AtomicUsize>) {
+        let built = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&built);
+        let builder: HealthcheckBuilder = Arc::new(move || {
+            if counter.fetch_add(1, Ordering::Relaxed) < failures {
+                future::ready(Err("unreachable".into())).boxed()
+            } else {
+                healthy()
+            }
+        });
+        (builder, built)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_with_the_healthcheck_builder() {
+        let key = ComponentKey::from("health_retries_with_the_healthcheck_builder");
+        let (builder, built) = flaky(2);
+        let checker = Healthchecker::new(
+            key.clone(),
+            Some(Arc::clone(&builder)),
+            SinkHealthcheckOptions {
+                retries: 2,
+                ..Default::default()
+            },
+            HealthcheckOptions::default(),
+        );
+
+        assert!(matches!(
+            checker.check(builder()).await,
+            HealthcheckResult::Healthy
+        ));
+        assert_eq!(built.load(Ordering::Relaxed), 3);
+        remove(&key);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn does_not_retry_without_a_healthcheck_builder() {
+        let key = ComponentKey::from("health_does_not_retry_without_a_healthcheck_builder");
+        let (builder, built) = flaky(1);
+        let checker = Healthchecker::new(
+            key.clone(),
+            None,
+            SinkHealthcheckOptions {
+                retries: 2,
+                interval_secs: Some(Duration::from_secs(1)),
+                ..Default::default()
+            },
+            HealthcheckOptions::default(),
+        );
+
+        assert!(matches!(
+            checker.check(builder()).await,
+            HealthcheckResult::Failed(_)
+        ));
+        assert_eq!(built.load(Ordering::Relaxed), 1);
+        assert_eq!(checker.interval(), None);
+        remove(&key);
+    }
+
+    fn slow_healthcheck(id: &str, delay: Duration, passes: bool) -> (ComponentKey, Task) {
+        let key = ComponentKey::from(id);
+        let task = Task::new(key.clone(), "test", async move {
+            sleep(delay).await;
+            if passes {
+                Ok(TaskOutput::Healthcheck)
+            } else {
+                Err(TaskError::Opaque)
+            }
+        });
+        (key, task)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn runs_healthchecks_concurrently() {
+        let healthchecks = (0..CONCURRENCY)
+            .map(|i| slow_healthcheck(&format!("sink{i:02}"), Duration::from_secs(5), true))
+            .collect();
+
+        let start = Instant::now();
+        let summary = run_all(healthchecks, None).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(5));
+        assert!(summary.passed());
+        assert_eq!(summary.results.len(), CONCURRENCY);
+        assert_eq!(summary.results[0].key, ComponentKey::from("sink00"));
+    }
+
+    #[tokio::test]
+    async fn reports_degraded_sinks_as_degraded() {
+        let key = ComponentKey::from("degraded");
+        let task = Task::new(key.clone(), "test", async {
+            Ok(TaskOutput::DegradedHealthcheck(
+                "one endpoint unreachable".to_owned(),
+            ))
+        });
+
+        let summary = run_all(vec![(key, task)], None).await;
+        assert!(summary.passed());
+        assert_eq!(summary.results[0].outcome, Outcome::Degraded);
+        assert_eq!(summary.results[0].outcome.as_str(), "degraded");
+        assert_eq!(
+            summary.results[0].error.as_deref(),
+            Some("one endpoint unreachable")
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reports_all_failures_by_the_deadline() {
+        let healthchecks = vec![
+            slow_healthcheck("slow", Duration::from_secs(60), true),
+            slow_healthcheck("failing", Duration::from_secs(1), false),
+            slow_healthcheck("passing", Duration::from_secs(2), true),
+            slow_healthcheck("also_failing", Duration::from_secs(3), false),
+        ];
+
+        let start = Instant::now();
+        let summary = run_all(healthchecks, Some(Duration::from_secs(10))).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(10));
+        assert!(!summary.passed());
+        assert_eq!(
+            summary.failed(),
+            [
+                &ComponentKey::from("also_failing"),
+                &ComponentKey::from("failing"),
+                &ComponentKey::from("slow"),
+            ]
+        );
+        assert_eq!(summary.results[3].outcome, Outcome::DeadlineExceeded);
+        assert_eq!(
+            summary.results[0].error.as_deref(),
+            Some("the task completed with an error")
+        );
+    }
+}
@@ -10,8 +10,10 @@
 pub(super) use vector_lib::fanout;
 pub mod schema;
 
+mod acknowledgements;
 pub mod builder;
 mod controller;
+pub mod health;
 mod ready_arrays;
 mod running;
 mod task;
@@ -32,7 +34,10 @@ pub use self::builder::TopologyPieces;
 pub use self::controller::{ReloadOutcome, SharedTopologyController, TopologyController};
 pub use self::running::{RunningTopology, ShutdownErrorReceiver};
 
-use self::task::{Task, TaskError, TaskResult};
+use self::{
+    builder::SinkHealthcheck,
+    task::{TaskError, TaskResult},
+};
 use crate::{
     config::{ComponentKey, Config, ConfigDiff},
     event::EventArray,
@@ -49,10 +54,15 @@ type BuiltBuffer = (
 pub(super) fn take_healthchecks(
     diff: &ConfigDiff,
     pieces: &mut TopologyPieces,
-) -> Vec<(ComponentKey, Task)> {
+) -> Vec<(ComponentKey, SinkHealthcheck)> {
     (&diff.sinks.to_change | &diff.sinks.to_add)
         .into_iter()
-        .filter_map(|id| pieces.healthchecks.remove(&id).map(move |task| (id, task)))
+        .filter_map(|id| {
+            pieces
+                .healthchecks
+                .remove(&id)
+                .map(move |healthcheck| (id, healthcheck))
+        })
         .collect()
 }
 
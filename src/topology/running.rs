@@ -8,14 +8,17 @@ use std::{
 
 use super::{
     builder,
-    builder::TopologyPieces,
+    builder::{SinkShutdown, TopologyPieces},
     fanout::{ControlChannel, ControlMessage},
-    handle_errors, retain, take_healthchecks,
+    handle_errors, health, retain, take_healthchecks,
     task::TaskOutput,
     BuiltBuffer, TaskHandle,
 };
 use crate::{
-    config::{ComponentKey, Config, ConfigDiff, HealthcheckOptions, Inputs, OutputId, Resource},
+    config::{
+        ComponentKey, Config, ConfigDiff, HealthcheckOptions, Inputs, OutputId, Resource,
+        SinkUpdater,
+    },
     event::EventArray,
     extra_context::ExtraContext,
     shutdown::SourceShutdownCoordinator,
@@ -25,7 +28,7 @@ use crate::{
 use futures::{future, Future, FutureExt};
 use tokio::{
     sync::{mpsc, watch},
-    time::{interval, sleep_until, Duration, Instant},
+    time::{interval, sleep_until, timeout_at, Duration, Instant},
 };
 use tracing::Instrument;
 use vector_lib::buffers::topology::channel::BufferSender;
@@ -44,6 +47,8 @@ pub struct RunningTopology {
     tasks: HashMap<ComponentKey, TaskHandle>,
     shutdown_coordinator: SourceShutdownCoordinator,
     detach_triggers: HashMap<ComponentKey, DisabledTrigger>,
+    sink_shutdowns: HashMap<ComponentKey, SinkShutdown>,
+    sink_updaters: HashMap<ComponentKey, SinkUpdater>,
     pub(crate) config: Config,
     pub(crate) abort_tx: mpsc::UnboundedSender<ShutdownError>,
     watch: (WatchTx, WatchRx),
@@ -60,6 +65,8 @@ impl RunningTopology {
             outputs_tap_metadata: HashMap::new(),
             shutdown_coordinator: SourceShutdownCoordinator::default(),
             detach_triggers: HashMap::new(),
+            sink_shutdowns: HashMap::new(),
+            sink_updaters: HashMap::new(),
             source_tasks: HashMap::new(),
             tasks: HashMap::new(),
             abort_tx,
@@ -116,20 +123,54 @@ impl RunningTopology {
         // pump in self.tasks, and the other for source in self.source_tasks.
         let mut check_handles = HashMap::<ComponentKey, Vec<_>>::new();
 
+        // If we reach this, we will forcefully shutdown the sources. If None, we will never force shutdown.
+        let deadline = self
+            .graceful_shutdown_duration
+            .map(|grace_period| Instant::now() + grace_period);
+
+        let mut sink_shutdowns = self.sink_shutdowns;
+
         // We need to give some time to the sources to gracefully shutdown, so
         // we will merge them with other tasks.
         for (key, task) in self.tasks.into_iter().chain(self.source_tasks.into_iter()) {
+            let abort_handle = task.abort_handle();
             let task = task.map(|_result| ()).shared();
 
+            // Sinks with their own timeout are killed once it is exceeded, even if the global
+            // deadline is later. A timeout later than the global deadline has no effect.
+            if let Some(SinkShutdown { timeout, abandon }) = sink_shutdowns.remove(&key) {
+                let sink_deadline = Instant::now() + timeout;
+                let sink_deadline = deadline.map_or(sink_deadline, |d| d.min(sink_deadline));
+                let upstream = self
+                    .config
+                    .inputs_for_node(&key)
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|input| self.outputs.get(input).cloned())
+                    .collect::<Vec<_>>();
+                let task = task.clone();
+                let key = key.clone();
+                tokio::spawn(async move {
+                    if timeout_at(sink_deadline, task).await.is_err() {
+                        error!(
+                            message = "Sink failed to shut down before its deadline. Killing it.",
+                            component_id = %key,
+                            abandoned_events = abandon.pending(),
+                        );
+                        // Detach the sink first, so that its inputs do not fail sending to it.
+                        for output in upstream {
+                            _ = output.send(ControlMessage::Remove(key.clone()));
+                        }
+                        abandon.abandon();
+                        abort_handle.abort();
+                    }
+                });
+            }
+
             wait_handles.push(task.clone());
             check_handles.entry(key).or_default().push(task);
         }
 
-        // If we reach this, we will forcefully shutdown the sources. If None, we will never force shutdown.
-        let deadline = self
-            .graceful_shutdown_duration
-            .map(|grace_period| Instant::now() + grace_period);
-
         let timeout = if let Some(deadline) = deadline {
             // If we reach the deadline, this future will print out which components
             // won't gracefully shutdown since we will start to forcefully shutdown
@@ -271,6 +312,7 @@ impl RunningTopology {
             {
                 self.connect_diff(&diff, &mut new_pieces).await;
                 self.spawn_diff(&diff, new_pieces);
+                self.update_sinks(&diff, &new_config);
                 self.config = new_config;
 
                 info!("New configuration loaded successfully.");
@@ -314,25 +356,54 @@ impl RunningTopology {
         options: HealthcheckOptions,
     ) -> bool {
         if options.enabled {
-            let healthchecks = take_healthchecks(diff, pieces)
+            let (required, optional): (Vec<_>, Vec<_>) = take_healthchecks(diff, pieces)
                 .into_iter()
-                .map(|(_, task)| task);
-            let healthchecks = future::try_join_all(healthchecks);
+                .partition(|(_, healthcheck)| healthcheck.required);
 
             info!("Running healthchecks.");
-            if options.require_healthy {
-                let success = healthchecks.await;
+            if !optional.is_empty() && (options.require_healthy || !required.is_empty()) {
+                let mut allowed = optional
+                    .iter()
+                    .map(|(key, _)| key.to_string())
+                    .collect::<Vec<_>>();
+                allowed.sort();
+                info!(
+                    message = "Sinks allowed to start unhealthy.",
+                    components = %allowed.join(", "),
+                );
+            }
 
-                if success.is_ok() {
-                    info!("All healthchecks passed.");
-                    true
-                } else {
-                    error!("Sinks unhealthy.");
-                    false
-                }
-            } else {
-                tokio::spawn(healthchecks);
+            let optional = optional
+                .into_iter()
+                .map(|(key, healthcheck)| (key, healthcheck.task))
+                .collect::<Vec<_>>();
+            if !optional.is_empty() {
+                tokio::spawn(
+                    async move { health::run_all(optional, options.deadline_secs).await.log() }
+                        .in_current_span(),
+                );
+            }
+
+            if required.is_empty() {
+                return true;
+            }
+            let required = required
+                .into_iter()
+                .map(|(key, healthcheck)| (key, healthcheck.task))
+                .collect();
+            let summary = health::run_all(required, options.deadline_secs).await;
+            summary.log();
+            if summary.passed() {
+                info!("All required healthchecks passed.");
                 true
+            } else {
+                let failed = summary
+                    .failed()
+                    .into_iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>();
+                error!(message = "Sinks unhealthy.", components = %failed.join(", "));
+                false
             }
         } else {
             true
@@ -466,6 +537,10 @@ impl RunningTopology {
         for key in &diff.sinks.to_remove {
             debug!(component = %key, "Removing sink.");
             self.remove_inputs(key, diff, new_config).await;
+            self.remove_outputs(key);
+            self.sink_shutdowns.remove(key);
+            self.sink_updaters.remove(key);
+            health::remove(key);
         }
 
         // After that, for any changed sinks, we temporarily detach their inputs (not remove) so
@@ -494,6 +569,7 @@ impl RunningTopology {
                 buffer_tx.insert(key.clone(), self.inputs.get(key).unwrap().clone());
             }
             self.remove_inputs(key, diff, new_config).await;
+            self.remove_outputs(key);
         }
 
         // Now that we've disconnected or temporarily detached the inputs to all changed/removed
@@ -601,6 +677,15 @@ impl RunningTopology {
             self.setup_outputs(key, new_pieces).await;
         }
 
+        // Sinks that reroute dropped events have a `dropped` output, which transforms and other
+        // sinks may consume as well.
+        for key in diff.sinks.changed_and_added() {
+            if new_pieces.outputs.contains_key(key) {
+                debug!(component = %key, "Configuring outputs for sink.");
+                self.setup_outputs(key, new_pieces).await;
+            }
+        }
+
         // Now that all possible outputs are configured, we can start wiring up inputs, starting
         // with transforms.
         for key in diff.transforms.changed_and_added() {
@@ -888,6 +973,27 @@ impl RunningTopology {
         if let Some(previous) = self.tasks.insert(key.clone(), spawned) {
             drop(previous); // detach and forget
         }
+
+        match new_pieces.sink_shutdowns.remove(key) {
+            Some(shutdown) => self.sink_shutdowns.insert(key.clone(), shutdown),
+            None => self.sink_shutdowns.remove(key),
+        };
+        match new_pieces.sink_updaters.remove(key) {
+            Some(updater) => self.sink_updaters.insert(key.clone(), updater),
+            None => self.sink_updaters.remove(key),
+        };
+    }
+
+    /// Sends their new configuration to the running sinks that are updated in place.
+    fn update_sinks(&self, diff: &ConfigDiff, new_config: &Config) {
+        for key in &diff.sinks.to_update {
+            debug!(message = "Updating sink in place.", key = %key);
+            let sink = new_config.sink(key).expect("updated sinks are configured");
+            self.sink_updaters
+                .get(key)
+                .expect("updated sinks have an updater")
+                .update(sink.inner.as_ref());
+        }
     }
 
     fn spawn_transform(&mut self, key: &ComponentKey, new_pieces: &mut builder::TopologyPieces) {
@@ -1078,5 +1184,14 @@ fn get_changed_outputs(diff: &ConfigDiff, output_ids: Inputs<OutputId>) -> Vec<O
         );
     }
 
+    for sink_key in &diff.sinks.to_change {
+        changed_outputs.extend(
+            output_ids
+                .iter()
+                .filter(|id| &id.component == sink_key)
+                .cloned(),
+        );
+    }
+
     changed_outputs
 }
@@ -1,7 +1,10 @@
 use std::collections::HashMap;
 
 use snafu::Snafu;
-use vector_lib::config::SourceOutput;
+use vector_lib::{
+    config::SourceOutput,
+    schema::{Requirement, ValidationError, ValidationErrors},
+};
 
 pub(super) use crate::schema::Definition;
 
@@ -90,6 +93,16 @@ pub fn possible_definitions(
 
             definitions.append(&mut transform_definition);
         }
+
+        // If the input is the `dropped` output of a sink, it carries the events the sink received.
+        if let Some(inputs) = config.sink_dropped_inputs(key) {
+            let mut sink_definitions = input.with_definitions(
+                possible_definitions(inputs, config, enrichment_tables.clone(), cache)?
+                    .into_iter()
+                    .map(|(_, definition)| definition),
+            );
+            definitions.append(&mut sink_definitions);
+        }
     }
 
     Ok(definitions)
@@ -193,6 +206,16 @@ pub(super) fn expanded_definitions(
             // Append whatever number of additional pipelines we created to the existing
             // pipeline definitions.
             definitions.append(&mut transform_definition);
+
+        // The `dropped` output of a sink carries the events the sink received, so each of the
+        // pipelines feeding into the sink continues through it.
+        } else if let Some(inputs) = config.sink_dropped_inputs(key) {
+            let mut sink_definitions = input.with_definitions(
+                expanded_definitions(enrichment_tables.clone(), inputs, config, &mut merged_cache)?
+                    .into_iter()
+                    .map(|(_, definition)| definition),
+            );
+            definitions.append(&mut sink_definitions);
         }
     }
 
@@ -283,6 +306,16 @@ pub(crate) fn input_definitions(
 
             definitions.append(&mut transform_definitions);
         }
+
+        // If the input is the `dropped` output of a sink, it carries the events the sink received.
+        if let Some(inputs) = config.sink_dropped_inputs(key) {
+            let mut sink_definitions = input.with_definitions(
+                input_definitions(inputs, config, enrichment_tables.clone(), cache)?
+                    .into_iter()
+                    .map(|(_, definition)| definition),
+            );
+            definitions.append(&mut sink_definitions);
+        }
     }
 
     Ok(definitions)
@@ -297,49 +330,193 @@ fn contains_never(transform_definitions: &[(OutputId, Definition)]) -> bool {
         .any(|(_, definition)| definition.event_kind().is_never())
 }
 
+/// A route from a source to one of the inputs of a sink, as the list of the outputs the events go
+/// through, along with the definition of the events it carries.
+type Pipeline = (Vec<OutputId>, Definition);
+
+/// Get the individual routes feeding into the given inputs.
+///
+/// Unlike [`expanded_definitions`], the definition of each route is computed from the definitions
+/// along that route alone, so that a route failing a sink requirement can be reported along with
+/// the components it goes through.
+fn pipeline_definitions(
+    enrichment_tables: &vector_lib::enrichment::TableRegistry,
+    inputs: &[OutputId],
+    config: &dyn ComponentContainer,
+    cache: &mut HashMap<OutputId, Vec<Pipeline>>,
+) -> Result<Vec<Pipeline>, Error> {
+    let mut pipelines = Vec::new();
+
+    for input in inputs {
+        if let Some(cached) = cache.get(input) {
+            pipelines.extend(cached.iter().cloned());
+            continue;
+        }
+
+        let key = &input.component;
+        let mut input_pipelines = Vec::new();
+
+        if let Some(outputs) = config.source_outputs(key) {
+            let definition = outputs
+                .into_iter()
+                .find(|output| output.port == input.port)
+                .unwrap_or_else(|| unreachable!("source output mis-configured"))
+                .schema_definition(config.schema_enabled());
+            input_pipelines.extend(definition.map(|definition| (vec![input.clone()], definition)));
+        } else if let Some(upstream) = config.transform_inputs(key) {
+            for (mut path, definition) in
+                pipeline_definitions(enrichment_tables, upstream, config, cache)?
+            {
+                let upstream_id = path.last().expect("routes are never empty").clone();
+                let output = config
+                    .transform_outputs(key, enrichment_tables.clone(), &[(upstream_id, definition)])
+                    .expect("already found inputs")
+                    .into_iter()
+                    .find(|output| output.port == input.port)
+                    .expect("transform output misconfigured");
+
+                path.push(input.clone());
+                for definition in output
+                    .schema_definitions(config.schema_enabled())
+                    .into_values()
+                {
+                    input_pipelines.push((path.clone(), definition));
+                }
+            }
+        } else if let Some(upstream) = config.sink_dropped_inputs(key) {
+            for (mut path, definition) in
+                pipeline_definitions(enrichment_tables, upstream, config, cache)?
+            {
+                path.push(input.clone());
+                input_pipelines.push((path, definition));
+            }
+        }
+
+        if input_pipelines
+            .iter()
+            .any(|(_, definition)| definition.event_kind().is_never())
+        {
+            return Err(Error::ContainsNever);
+        }
+
+        cache.insert(input.clone(), input_pipelines.clone());
+        pipelines.append(&mut input_pipelines);
+    }
+
+    Ok(pipelines)
+}
+
+/// Checks the definitions feeding into a sink, returning the errors found along with the route
+/// that caused them, formatted as `source -> transform -> sink`.
+fn check_sink_pipelines(
+    key: &ComponentKey,
+    inputs: &[OutputId],
+    config: &dyn ComponentContainer,
+    enrichment_tables: vector_lib::enrichment::TableRegistry,
+    check: impl Fn(&Definition) -> Result<(), ValidationErrors>,
+) -> Result<Vec<(ValidationError, String)>, Error> {
+    // Get all pipeline definitions feeding into this sink.
+    let mut cache = HashMap::default();
+    let definitions = expanded_definitions(enrichment_tables.clone(), inputs, config, &mut cache)?;
+    if definitions
+        .iter()
+        .all(|(_output, definition)| check(definition).is_ok())
+    {
+        return Ok(vec![]);
+    }
+
+    // Only compute the individual routes when something failed, to find the routes failing.
+    let mut failures = vec![];
+    let pipelines =
+        pipeline_definitions(&enrichment_tables, inputs, config, &mut HashMap::default())?;
+    for (path, definition) in pipelines {
+        if let Err(err) = check(&definition) {
+            let route = path
+                .iter()
+                .map(ToString::to_string)
+                .chain(std::iter::once(key.to_string()))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            failures.extend(err.errors().iter().cloned().map(|err| (err, route.clone())));
+        }
+    }
+    failures.dedup();
+
+    Ok(failures)
+}
+
 pub(super) fn validate_sink_expectations(
     key: &ComponentKey,
     sink: &SinkOuter<OutputId>,
     config: &topology::Config,
     enrichment_tables: vector_lib::enrichment::TableRegistry,
 ) -> Result<(), Vec<String>> {
-    let mut errors = vec![];
-
     // Get the schema against which we need to validate the schemas of the components feeding into
     // this sink.
     let input = sink.inner.input();
     let requirement = input.schema_requirement();
 
-    // Get all pipeline definitions feeding into this sink.
-    let mut cache = HashMap::default();
-    let definitions =
-        match expanded_definitions(enrichment_tables, &sink.inputs, config, &mut cache) {
-            Ok(definitions) => definitions,
-            Err(err) => {
-                errors.push(err.to_string());
-                return Err(errors);
-            }
-        };
-
     // Validate each individual definition against the sink requirement.
-    for (_output, definition) in definitions {
-        if let Err(err) = requirement.validate(&definition, config.schema.validation) {
-            errors.append(
-                &mut err
-                    .errors()
-                    .iter()
-                    .cloned()
-                    .map(|err| format!("schema error in component {}: {}", key, err))
-                    .collect(),
-            );
-        }
+    let failures =
+        check_sink_pipelines(key, &sink.inputs, config, enrichment_tables, |definition| {
+            requirement.validate(definition, config.schema.validation)
+        })
+        .map_err(|err| vec![err.to_string()])?;
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures
+            .into_iter()
+            .map(|(err, route)| format!("schema error in component {key}: {err} (via {route})"))
+            .collect())
     }
+}
 
-    if !errors.is_empty() {
-        return Err(errors);
+/// Returns a warning for each recommended semantic meaning of a sink that is missing from one of
+/// the routes feeding into it.
+pub fn sink_expectation_warnings(config: &topology::Config) -> Vec<String> {
+    config
+        .sinks()
+        .flat_map(|(key, sink)| {
+            let input = sink.inner.input();
+            sink_warnings(key, &sink.inputs, input.schema_requirement(), config)
+        })
+        .collect()
+}
+
+/// Returns a warning for each recommended semantic meaning of the given requirement that is missing
+/// from one of the routes feeding into the given inputs of a sink.
+fn sink_warnings(
+    key: &ComponentKey,
+    inputs: &[OutputId],
+    requirement: &Requirement,
+    config: &dyn ComponentContainer,
+) -> Vec<String> {
+    if requirement.is_empty() {
+        return vec![];
     }
 
-    Ok(())
+    // Definitions that can't be computed are reported as errors when building the topology.
+    let Ok(failures) = check_sink_pipelines(
+        key,
+        inputs,
+        config,
+        vector_lib::enrichment::TableRegistry::default(),
+        |definition| requirement.check_recommendations(definition),
+    ) else {
+        return vec![];
+    };
+
+    failures
+        .into_iter()
+        .map(|(err, route)| match err {
+            ValidationError::MeaningMissing { identifier } => format!(
+                "Sink \"{key}\" recommends the semantic meaning \"{identifier}\", which is missing via {route}"
+            ),
+            err => format!("Sink \"{key}\": {err} (via {route})"),
+        })
+        .collect()
 }
 
 pub trait ComponentContainer {
@@ -356,6 +533,12 @@ pub trait ComponentContainer {
         input_definitions: &[(OutputId, Definition)],
     ) -> Option<Vec<TransformOutput>>;
 
+    /// Gets the inputs of a sink that reroutes the events it fails to deliver to its `dropped`
+    /// output.
+    fn sink_dropped_inputs(&self, _key: &ComponentKey) -> Option<&[OutputId]> {
+        None
+    }
+
     /// Gets the transform output for the given port.
     ///
     /// Returns Err(()) if there is no transform with the given key
@@ -435,17 +618,23 @@ impl ComponentContainer for Config {
             )
         })
     }
+
+    fn sink_dropped_inputs(&self, key: &ComponentKey) -> Option<&[OutputId]> {
+        self.sink(key)
+            .filter(|sink| sink.reroute_dropped())
+            .map(|sink| &sink.inputs[..])
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::{collections::HashMap, sync::LazyLock};
 
     use indexmap::IndexMap;
     use similar_asserts::assert_eq;
-    use vector_lib::config::{DataType, SourceOutput, TransformOutput};
+    use vector_lib::config::{DataType, LogNamespace, SourceOutput, TransformOutput};
     use vector_lib::lookup::owned_value_path;
-    use vrl::value::Kind;
+    use vrl::value::{kind::Collection, Kind};
 
     use super::*;
 
@@ -827,4 +1016,159 @@ mod tests {
             assert_eq!(got, case.want, "{}", title);
         }
     }
+
+    #[test]
+    fn test_pipeline_definitions() {
+        // Sources `a` and `b` both feed into the transform `t`, which passes their events through.
+        struct TestCase {
+            sources: IndexMap<&'static str, Definition>,
+        }
+
+        impl ComponentContainer for TestCase {
+            fn schema_enabled(&self) -> bool {
+                true
+            }
+
+            fn source_outputs(&self, key: &ComponentKey) -> Option<Vec<SourceOutput>> {
+                self.sources.get(key.id()).map(|definition| {
+                    vec![SourceOutput::new_maybe_logs(
+                        DataType::all_bits(),
+                        definition.clone(),
+                    )]
+                })
+            }
+
+            fn transform_inputs(&self, key: &ComponentKey) -> Option<&[OutputId]> {
+                static INPUTS: LazyLock<Vec<OutputId>> =
+                    LazyLock::new(|| vec![OutputId::from("a"), OutputId::from("b")]);
+                (key.id() == "t").then(|| INPUTS.as_slice())
+            }
+
+            fn transform_outputs(
+                &self,
+                key: &ComponentKey,
+                _: vector_lib::enrichment::TableRegistry,
+                input_definitions: &[(OutputId, Definition)],
+            ) -> Option<Vec<TransformOutput>> {
+                (key.id() == "t").then(|| {
+                    vec![TransformOutput::new(
+                        DataType::all_bits(),
+                        input_definitions.iter().cloned().collect(),
+                    )]
+                })
+            }
+        }
+
+        let with_message = Definition::empty_legacy_namespace().with_event_field(
+            &owned_value_path!("message"),
+            Kind::bytes(),
+            Some("message"),
+        );
+        let without_message = Definition::empty_legacy_namespace();
+        let case = TestCase {
+            sources: IndexMap::from([("a", with_message.clone()), ("b", without_message.clone())]),
+        };
+
+        let got = pipeline_definitions(
+            &vector_lib::enrichment::TableRegistry::default(),
+            &[OutputId::from("t"), OutputId::from("a")],
+            &case,
+            &mut HashMap::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            got,
+            vec![
+                (vec!["a".into(), "t".into()], with_message.clone()),
+                (vec!["b".into(), "t".into()], without_message),
+                (vec!["a".into()], with_message),
+            ]
+        );
+    }
+
+    /// Sources feeding directly into a sink, each with its own definition.
+    struct Sources(IndexMap<&'static str, Definition>);
+
+    impl ComponentContainer for Sources {
+        fn schema_enabled(&self) -> bool {
+            true
+        }
+
+        fn source_outputs(&self, key: &ComponentKey) -> Option<Vec<SourceOutput>> {
+            self.0.get(key.id()).map(|definition| {
+                vec![SourceOutput::new_maybe_logs(
+                    DataType::all_bits(),
+                    definition.clone(),
+                )]
+            })
+        }
+
+        fn transform_inputs(&self, _key: &ComponentKey) -> Option<&[OutputId]> {
+            None
+        }
+
+        fn transform_outputs(
+            &self,
+            _key: &ComponentKey,
+            _: vector_lib::enrichment::TableRegistry,
+            _input_definitions: &[(OutputId, Definition)],
+        ) -> Option<Vec<TransformOutput>> {
+            None
+        }
+    }
+
+    fn sink_warnings_for(namespace: LogNamespace) -> Vec<String> {
+        let empty =
+            Definition::new_with_default_metadata(Kind::object(Collection::empty()), [namespace]);
+        let with_message = empty.clone().with_event_field(
+            &owned_value_path!("message"),
+            Kind::bytes(),
+            Some("message"),
+        );
+        let sources = Sources(IndexMap::from([("a", with_message), ("b", empty)]));
+        let requirement = Requirement::empty().recommended_meaning("message", Kind::bytes());
+
+        sink_warnings(
+            &ComponentKey::from("out"),
+            &[OutputId::from("a"), OutputId::from("b")],
+            &requirement,
+            &sources,
+        )
+    }
+
+    #[test]
+    fn test_sink_warnings_legacy_namespace() {
+        // Events of the legacy namespace don't carry semantic meanings, so none is recommended.
+        assert_eq!(
+            sink_warnings_for(LogNamespace::Legacy),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_sink_warnings_vector_namespace() {
+        assert_eq!(
+            sink_warnings_for(LogNamespace::Vector),
+            vec![
+                "Sink \"out\" recommends the semantic meaning \"message\", which is missing via b -> out"
+                    .to_owned()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sink_warnings_without_recommendations() {
+        let sources = Sources(IndexMap::from([(
+            "a",
+            Definition::new_with_default_metadata(Kind::any_object(), [LogNamespace::Vector]),
+        )]));
+
+        assert!(sink_warnings(
+            &ComponentKey::from("out"),
+            &[OutputId::from("a")],
+            &Requirement::empty(),
+            &sources,
+        )
+        .is_empty());
+    }
 }
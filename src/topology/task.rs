@@ -21,6 +21,8 @@ pub(crate) enum TaskOutput {
     /// Buffer of sink
     Sink(Utilization<BufferReceiverStream<EventArray>>),
     Healthcheck,
+    /// The healthcheck passed, but reported the sink as degraded.
+    DegradedHealthcheck(String),
 }
 
 #[derive(Debug, Snafu)]
@@ -98,7 +98,8 @@ async fn buffer_drop_fan_out() {
         backpressure_sink(events_to_sink / 2),
     );
     sink_outer.buffer = BufferConfig::Single(BufferType::Memory {
-        max_events: MEMORY_BUFFER_DEFAULT_MAX_EVENTS,
+        max_events: Some(MEMORY_BUFFER_DEFAULT_MAX_EVENTS),
+        max_size_bytes: None,
         when_full: WhenFull::DropNewest,
     });
     config.add_sink_outer("out2", sink_outer);
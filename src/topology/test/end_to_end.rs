@@ -13,6 +13,8 @@ use tokio::{
 use super::{RunningTopology, TopologyPieces};
 use crate::{
     config::{self, ConfigDiff, Format},
+    event::MetricValue,
+    metrics::Controller,
     test_util, Error,
 };
 
@@ -154,3 +156,77 @@ async fn http_to_http_delivered() {
 async fn http_to_http_failed() {
     http_to_http(StatusCode::FORBIDDEN, StatusCode::BAD_REQUEST).await;
 }
+
+fn unacknowledged_events(component_id: &str) -> Option<f64> {
+    Controller::get()
+        .unwrap()
+        .capture_metrics()
+        .into_iter()
+        .find(|metric| {
+            metric.name() == "source_unacknowledged_events"
+                && metric.tag_value("component_id").as_deref() == Some(component_id)
+        })
+        .map(|metric| match metric.value() {
+            MetricValue::Gauge { value } => *value,
+            value => panic!("expected a gauge, got {value:?}"),
+        })
+}
+
+#[tokio::test]
+async fn http_to_http_reports_unacknowledged_events() {
+    test_util::trace_init();
+
+    let address1 = test_util::next_addr();
+    let address2 = test_util::next_addr();
+    let config = config::load_from_str(
+        &format!(
+            r#"
+[sources.unacknowledged_in]
+type = "http"
+address = "{address1}"
+acknowledgements.enabled = true
+
+[sinks.out]
+type = "http"
+inputs = ["unacknowledged_in"]
+encoding.codec = "json"
+uri = "http://{address2}/"
+"#
+        ),
+        Format::Toml,
+    )
+    .unwrap();
+    let diff = ConfigDiff::initial(&config);
+    let pieces =
+        TopologyPieces::build_or_log_errors(&config, &diff, HashMap::new(), Default::default())
+            .await
+            .unwrap();
+    let (_topology, _) = RunningTopology::start_validated(config, diff, pieces)
+        .await
+        .unwrap();
+
+    test_util::wait_for_tcp(address1).await;
+
+    // The sink is slow to acknowledge events, as the test server only responds once unpaused.
+    let mutex = Arc::new(Mutex::new(()));
+    let pause = mutex.lock().await;
+    let mut rx_server = http_server(address2, Arc::clone(&mutex), StatusCode::OK).await;
+
+    let (_rx_client, sender) = http_client(address1, "test");
+    timeout(Duration::from_secs(4), rx_server.recv())
+        .await
+        .expect("Timed out waiting to receive event from HTTP sink")
+        .expect("Error receiving event from HTTP sink");
+
+    // The event is awaiting acknowledgement while the sink waits for its response.
+    assert_eq!(unacknowledged_events("unacknowledged_in"), Some(1.0));
+
+    drop(pause);
+    let result = timeout(Duration::from_secs(1), sender)
+        .await
+        .expect("Timed out waiting to receive result from HTTP source")
+        .expect("Error receiving result from tokio task");
+    assert_eq!(result.status(), StatusCode::OK);
+
+    assert_eq!(unacknowledged_events("unacknowledged_in"), Some(0.0));
+}
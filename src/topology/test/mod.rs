@@ -8,13 +8,13 @@ use std::{
 };
 
 use crate::{
-    config::{Config, ConfigDiff, SinkOuter},
-    event::{into_event_stream, Event, EventArray, EventContainer, LogEvent},
+    config::{Config, ConfigDiff, SinkHealthcheckOptions, SinkOuter},
+    event::{into_event_stream, metric::MetricValue, Event, EventArray, EventContainer, LogEvent},
     test_util::{
         mock::{
             basic_sink, basic_sink_failing_healthcheck, basic_sink_with_data, basic_source,
             basic_source_with_data, basic_source_with_event_counter, basic_transform,
-            error_definition_transform,
+            error_definition_transform, forgetful_sink,
         },
         start_topology, trace_init,
     },
@@ -29,6 +29,8 @@ use tokio::{
 use vector_lib::buffers::{BufferConfig, BufferType, WhenFull};
 use vector_lib::config::ComponentKey;
 use vector_lib::config::OutputId;
+use vector_lib::finalization::{BatchNotifier, BatchStatus};
+use vector_lib::metrics::Controller;
 
 mod backpressure;
 mod compliance;
@@ -812,6 +814,129 @@ async fn topology_optional_healthcheck_does_not_fail_reload() {
         .unwrap());
 }
 
+fn config_with_sink_failing_healthcheck(global: bool, sink: Option<bool>) -> Config {
+    trace_init();
+
+    let mut config = Config::builder();
+    config.add_source("in1", basic_source().1);
+    let healthcheck = SinkHealthcheckOptions {
+        require_healthy: sink,
+        ..Default::default()
+    };
+    config.add_sink_outer(
+        "out1",
+        SinkOuter::new(vec!["in1".to_owned()], basic_sink_failing_healthcheck(10).1)
+            .with_healthcheck(healthcheck),
+    );
+    let mut config = config.build().unwrap();
+    config.healthchecks.require_healthy = global;
+    config
+}
+
+async fn starts(config: Config) -> bool {
+    RunningTopology::start_init_validated(config, Default::default())
+        .await
+        .is_some()
+}
+
+#[tokio::test]
+async fn topology_delays_first_healthcheck() {
+    trace_init();
+
+    let mut config = Config::builder();
+    config.add_source("in1", basic_source().1);
+    let healthcheck = SinkHealthcheckOptions {
+        initial_delay_secs: Duration::from_millis(200),
+        ..Default::default()
+    };
+    config.add_sink_outer(
+        "out1",
+        SinkOuter::new(vec!["in1".to_owned()], basic_sink(10).1).with_healthcheck(healthcheck),
+    );
+    let mut config = config.build().unwrap();
+    // Waits for the healthchecks to complete.
+    config.healthchecks.require_healthy = true;
+
+    let start = std::time::Instant::now();
+    assert!(starts(config).await);
+    assert!(start.elapsed() >= Duration::from_millis(200));
+}
+
+#[tokio::test]
+async fn topology_reports_healthcheck_metrics() {
+    trace_init();
+
+    let mut config = Config::builder();
+    config.add_source("in1", basic_source().1);
+    config.add_sink("healthy", &["in1"], basic_sink(10).1);
+    config.add_sink("unhealthy", &["in1"], basic_sink_failing_healthcheck(10).1);
+    let mut config = config.build().unwrap();
+    // Waits for the healthchecks to complete.
+    config.healthchecks.require_healthy = true;
+    assert!(!starts(config).await);
+
+    let metrics = Controller::get().unwrap().capture_metrics();
+    let gauge = |name: &str, component_id: &str| {
+        metrics
+            .iter()
+            .find(|metric| {
+                metric.name() == name
+                    && metric.tag_value("component_id").as_deref() == Some(component_id)
+            })
+            .map(|metric| match metric.value() {
+                MetricValue::Gauge { value } => *value,
+                value => panic!("expected a gauge, got {value:?}"),
+            })
+    };
+    assert_eq!(gauge("component_healthcheck_status", "healthy"), Some(2.0));
+    assert_eq!(
+        gauge("component_healthcheck_status", "unhealthy"),
+        Some(0.0)
+    );
+    assert!(gauge("component_healthcheck_duration_seconds", "healthy").is_some());
+    assert!(gauge("component_healthcheck_duration_seconds", "unhealthy").is_some());
+}
+
+#[tokio::test]
+async fn topology_sink_required_healthcheck_overrides_global() {
+    assert!(!starts(config_with_sink_failing_healthcheck(false, Some(true))).await);
+}
+
+#[tokio::test]
+async fn topology_sink_optional_healthcheck_overrides_global() {
+    assert!(starts(config_with_sink_failing_healthcheck(true, Some(false))).await);
+}
+
+#[tokio::test]
+async fn topology_sink_required_healthcheck_matches_global() {
+    assert!(!starts(config_with_sink_failing_healthcheck(true, Some(true))).await);
+}
+
+#[tokio::test]
+async fn topology_sink_optional_healthcheck_matches_global() {
+    assert!(starts(config_with_sink_failing_healthcheck(false, Some(false))).await);
+}
+
+#[tokio::test]
+async fn topology_sink_required_healthcheck_fails_reload() {
+    let (mut topology, _) = start_topology(basic_config(), false).await;
+    let config = config_with_sink_failing_healthcheck(false, Some(true));
+    assert!(!topology
+        .reload_config_and_respawn(config, Default::default())
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn topology_sink_optional_healthcheck_does_not_fail_reload() {
+    let (mut topology, _) = start_topology(basic_config(), false).await;
+    let config = config_with_sink_failing_healthcheck(true, Some(false));
+    assert!(topology
+        .reload_config_and_respawn(config, Default::default())
+        .await
+        .unwrap());
+}
+
 #[tokio::test]
 async fn topology_healthcheck_not_run_on_unchanged_reload() {
     let config = basic_config();
@@ -873,6 +998,10 @@ async fn topology_disk_buffer_flushes_on_idle() {
     );
     sink1_outer.buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: std::num::NonZeroU64::new(268435488).unwrap(),
+        max_age_secs: None,
+        expired_status: Default::default(),
+        durability: Default::default(),
+        encryption: None,
         when_full: WhenFull::DropNewest,
     });
     config.add_sink_outer("out1", sink1_outer);
@@ -971,3 +1100,66 @@ async fn source_metadata_reaches_sink() {
         ComponentKey::from("in2")
     );
 }
+
+#[tokio::test]
+async fn topology_acknowledgement_timeout() {
+    trace_init();
+
+    let (mut in1, source1) = basic_source();
+    let acknowledgements = toml::from_str("timeout_secs = 0.5").unwrap();
+
+    let mut config = Config::builder();
+    config.add_source("in1", source1);
+    config.add_sink("out1", &["in1"], forgetful_sink(acknowledgements));
+
+    let (topology, _) = start_topology(config.build().unwrap(), false).await;
+
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    in1.send_event(LogEvent::from("test").with_batch_notifier(&batch))
+        .await
+        .unwrap();
+    drop(batch);
+
+    let status = tokio::time::timeout(Duration::from_secs(5), receiver)
+        .await
+        .expect("Acknowledgement timeout did not resolve the batch");
+    assert_eq!(status, BatchStatus::Errored);
+
+    topology.stop().await;
+}
+
+#[tokio::test]
+async fn topology_sink_graceful_shutdown_timeout() {
+    trace_init();
+
+    let (mut in1, source1) = basic_source();
+    let sink: SinkOuter<String> = toml::from_str(
+        r#"
+        inputs = ["in1"]
+        type = "test_slow"
+        delay_secs = 3600
+        graceful_shutdown_timeout_secs = 0.5
+        "#,
+    )
+    .unwrap();
+
+    let mut config = Config::builder();
+    config.add_source("in1", source1);
+    config.add_sink_outer("out1", sink);
+
+    let (topology, _) = start_topology(config.build().unwrap(), false).await;
+
+    let (batch, receiver) = BatchNotifier::new_with_receiver();
+    in1.send_event(LogEvent::from("test").with_batch_notifier(&batch))
+        .await
+        .unwrap();
+    drop(batch);
+
+    // Let the sink pick up the event before shutting down.
+    sleep(Duration::from_millis(100)).await;
+
+    tokio::time::timeout(Duration::from_secs(5), topology.stop())
+        .await
+        .expect("Slow sink was not killed at its deadline");
+    assert_eq!(receiver.await, BatchStatus::Errored);
+}
@@ -187,6 +187,10 @@ async fn topology_disk_buffer_conflict() {
     let sink_key = ComponentKey::from("out");
     old_config.sinks[&sink_key].buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: NonZeroU64::new(268435488).unwrap(),
+        max_age_secs: None,
+        expired_status: Default::default(),
+        durability: Default::default(),
+        encryption: None,
         when_full: WhenFull::Block,
     });
 
@@ -194,6 +198,10 @@ async fn topology_disk_buffer_conflict() {
     new_config.sinks[&sink_key].inner = prom_exporter_sink(address_1, 1).into();
     new_config.sinks[&sink_key].buffer = BufferConfig::Single(BufferType::DiskV2 {
         max_size: NonZeroU64::new(268435488).unwrap(),
+        max_age_secs: None,
+        expired_status: Default::default(),
+        durability: Default::default(),
+        encryption: None,
         when_full: WhenFull::Block,
     });
 
@@ -237,6 +245,31 @@ async fn topology_reload_with_new_components() {
     .await;
 }
 
+#[tokio::test]
+async fn topology_reload_enabled_sink() {
+    test_util::trace_init();
+
+    // Disabled components are left out of the topology, so enabling one on reload starts it just
+    // like adding it would.
+    let address_0 = next_addr();
+    let address_1 = next_addr();
+
+    let config = |enabled| {
+        let mut config = Config::builder();
+        config.add_source("in1", internal_metrics_source());
+        config.add_sink("out1", &["in1"], prom_exporter_sink(address_0, 1));
+        config.add_sink("out2", &["in1"], prom_exporter_sink(address_1, 1));
+        config
+            .sinks
+            .get_mut(&ComponentKey::from("out2"))
+            .unwrap()
+            .enabled = enabled;
+        config.build().unwrap()
+    };
+
+    reload_sink_test(config(false), config(true), address_0, address_1).await;
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn topology_readd_input() {
     // TODO: Write a test source that emits only metrics, and a test sink that can bind a TCP listener, so we can
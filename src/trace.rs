@@ -1,11 +1,13 @@
 #![allow(missing_docs)]
 use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    cell::Cell,
     collections::HashMap,
     marker::PhantomData,
     str::FromStr,
     sync::{
         atomic::{AtomicBool, Ordering},
-        Mutex, MutexGuard, OnceLock,
+        Mutex, MutexGuard, Once, OnceLock,
     },
 };
 
@@ -29,7 +31,7 @@ pub use tracing_tower::{InstrumentableService, InstrumentedService};
 use vector_lib::lookup::event_path;
 use vrl::value::Value;
 
-use crate::event::LogEvent;
+use crate::{event::LogEvent, internal_events::VectorPanicked};
 
 /// BUFFER contains all of the internal log events generated by Vector between the initialization of `tracing` and early
 /// buffering being stopped, which occurs once the topology reports as having successfully started.
@@ -52,6 +54,16 @@ static SUBSCRIBERS: Mutex<Option<Vec<oneshot::Sender<Vec<LogEvent>>>>> =
 /// has been initialized.
 static SENDER: OnceLock<Sender<LogEvent>> = OnceLock::new();
 
+/// PANIC_HOOK makes sure the panic hook is only installed once, so that it doesn't wrap itself.
+static PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+    /// IN_INTERNAL_LOGGING is set while the current thread is reporting a panic or processing an internal log event, so
+    /// that a panic raised by the logging path itself isn't reported through it again, which could deadlock on the locks
+    /// it holds.
+    static IN_INTERNAL_LOGGING: Cell<bool> = const { Cell::new(false) };
+}
+
 fn metrics_layer_enabled() -> bool {
     !matches!(std::env::var("DISABLE_INTERNAL_METRICS_TRACING_INTEGRATION"), Ok(x) if x == "true")
 }
@@ -116,6 +128,51 @@ pub fn init(color: bool, json: bool, levels: &str, internal_log_rate_limit: u64)
     }
 }
 
+/// Installs a panic hook which, in addition to printing panics to stderr as the default hook does, reports them as
+/// internal log events, along with the fields of the component span they happened in, if any, and their backtrace, if
+/// enabled with `RUST_BACKTRACE`.
+pub fn init_panic_hook() {
+    PANIC_HOOK.call_once(|| {
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            default_hook(info);
+
+            let Some(_guard) = InternalLoggingGuard::enter() else {
+                return;
+            };
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("Box<dyn Any>");
+            let backtrace = Backtrace::capture();
+            emit!(VectorPanicked {
+                message,
+                location: info.location().map(ToString::to_string),
+                backtrace: (backtrace.status() == BacktraceStatus::Captured)
+                    .then(|| backtrace.to_string()),
+            });
+        }));
+    });
+}
+
+/// Marks the current thread as being in the internal logging path until dropped.
+struct InternalLoggingGuard;
+
+impl InternalLoggingGuard {
+    /// Returns `None` if the current thread is already in the internal logging path.
+    fn enter() -> Option<Self> {
+        (!IN_INTERNAL_LOGGING.with(|in_logging| in_logging.replace(true))).then_some(Self)
+    }
+}
+
+impl Drop for InternalLoggingGuard {
+    fn drop(&mut self) {
+        IN_INTERNAL_LOGGING.with(|in_logging| in_logging.set(false));
+    }
+}
+
 #[cfg(test)]
 pub fn reset_early_buffer() -> Option<Vec<LogEvent>> {
     get_early_buffer().replace(Vec::new())
@@ -302,6 +359,8 @@ where
     S: Subscriber + 'static + for<'lookup> LookupSpan<'lookup>,
 {
     fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        // The guard is already held if this event reports a panic.
+        let _guard = InternalLoggingGuard::enter();
         if should_process_tracing_event() {
             let mut log = LogEvent::from(event);
             // Add span fields if available
@@ -47,6 +47,7 @@ impl TransformConfig for DedupeConfig {
     async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
         Ok(Transform::event_task(Dedupe::new(
             self.cache.num_events,
+            self.cache.ttl_secs,
             fill_default_fields_match(self.fields.as_ref()),
         )))
     }
@@ -100,6 +101,7 @@ mod tests {
         DedupeConfig {
             cache: CacheConfig {
                 num_events: std::num::NonZeroUsize::new(num_events).expect("non-zero num_events"),
+                ttl_secs: None,
             },
             fields: Some(FieldMatchConfig::MatchFields(fields)),
         }
@@ -116,6 +118,7 @@ mod tests {
         DedupeConfig {
             cache: CacheConfig {
                 num_events: std::num::NonZeroUsize::new(num_events).expect("non-zero num_events"),
+                ttl_secs: None,
             },
             fields: Some(FieldMatchConfig::IgnoreFields(fields)),
         }
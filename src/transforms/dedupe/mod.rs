@@ -6,24 +6,37 @@ pub mod transform;
 
 #[cfg(feature = "transforms-impl-dedupe")]
 pub mod common {
-    use std::num::NonZeroUsize;
+    use std::{num::NonZeroUsize, time::Duration};
 
+    use serde_with::serde_as;
     use vector_lib::{configurable::configurable_component, lookup::lookup_v2::ConfigTargetPath};
 
     use crate::config::log_schema;
 
     /// Caching configuration for deduplication.
+    #[serde_as]
     #[configurable_component]
     #[derive(Clone, Debug)]
     #[serde(deny_unknown_fields)]
     pub struct CacheConfig {
         /// Number of events to cache and use for comparing incoming events to previously seen events.
         pub num_events: NonZeroUsize,
+
+        /// How long an event is cached for, from when it is first seen.
+        ///
+        /// Once expired, an identical event is no longer considered a duplicate, and is cached
+        /// again. Events are cached until evicted by newer ones if not set.
+        #[serde_as(as = "Option<serde_with::DurationSecondsWithFrac<f64>>")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[configurable(metadata(docs::examples = 3600))]
+        #[configurable(metadata(docs::human_name = "Time To Live"))]
+        pub ttl_secs: Option<Duration>,
     }
 
     pub fn default_cache_config() -> CacheConfig {
         CacheConfig {
             num_events: NonZeroUsize::new(5000).expect("static non-zero number"),
+            ttl_secs: None,
         }
     }
 
@@ -1,4 +1,9 @@
-use std::{future::ready, num::NonZeroUsize, pin::Pin};
+use std::{
+    future::ready,
+    num::NonZeroUsize,
+    pin::Pin,
+    time::{Duration, Instant},
+};
 
 use bytes::Bytes;
 use futures::{Stream, StreamExt};
@@ -8,7 +13,7 @@ use vrl::path::OwnedTargetPath;
 
 use crate::{
     event::{Event, Value},
-    internal_events::DedupeEventsDropped,
+    internal_events::{DedupeCacheEntriesRemoved, DedupeEventsDropped},
     transforms::TaskTransform,
 };
 
@@ -17,7 +22,9 @@ use super::common::FieldMatchConfig;
 #[derive(Clone)]
 pub struct Dedupe {
     fields: FieldMatchConfig,
-    cache: LruCache<CacheEntry, bool>,
+    /// The cached entries, along with when they were first seen.
+    cache: LruCache<CacheEntry, Instant>,
+    ttl: Option<Duration>,
 }
 
 type TypeId = u8;
@@ -67,24 +74,72 @@ const fn type_id_for_value(val: &Value) -> TypeId {
 }
 
 impl Dedupe {
-    pub fn new(num_entries: NonZeroUsize, fields: FieldMatchConfig) -> Self {
+    pub fn new(num_entries: NonZeroUsize, ttl: Option<Duration>, fields: FieldMatchConfig) -> Self {
         Self {
             fields,
             cache: LruCache::new(num_entries),
+            ttl,
         }
     }
 
     pub fn transform_one(&mut self, event: Event) -> Option<Event> {
+        self.transform_one_at(event, Instant::now())
+    }
+
+    fn transform_one_at(&mut self, event: Event, now: Instant) -> Option<Event> {
         let cache_entry = build_cache_entry(&event, &self.fields);
-        if self.cache.put(cache_entry, true).is_some() {
-            emit!(DedupeEventsDropped { count: 1 });
-            None
-        } else {
-            Some(event)
+        let mut removed = RemovedEntries::default();
+        match self.cache.get(&cache_entry).copied() {
+            Some(seen) if !self.is_expired(seen, now) => {
+                emit!(DedupeEventsDropped { count: 1 });
+                return None;
+            }
+            Some(_) => {
+                removed.expired += 1;
+                self.cache.put(cache_entry, now);
+            }
+            None => {
+                // Expired entries are removed lazily, from the least recently used end of the
+                // cache, stopping at the first entry that isn't: as entries are promoted when
+                // seen again, it may not be the oldest one.
+                while let Some((_, seen)) = self.cache.peek_lru() {
+                    if !self.is_expired(*seen, now) {
+                        break;
+                    }
+                    self.cache.pop_lru();
+                    removed.expired += 1;
+                }
+                if let Some((_, seen)) = self.cache.push(cache_entry, now) {
+                    if self.is_expired(seen, now) {
+                        removed.expired += 1;
+                    } else {
+                        removed.evicted += 1;
+                    }
+                }
+            }
+        }
+
+        if removed.expired > 0 || removed.evicted > 0 {
+            emit!(DedupeCacheEntriesRemoved {
+                expired: removed.expired,
+                evicted: removed.evicted,
+            });
         }
+        Some(event)
+    }
+
+    fn is_expired(&self, seen: Instant, now: Instant) -> bool {
+        self.ttl
+            .is_some_and(|ttl| now.saturating_duration_since(seen) >= ttl)
     }
 }
 
+#[derive(Default)]
+struct RemovedEntries {
+    expired: usize,
+    evicted: usize,
+}
+
 /// Takes in an Event and returns a CacheEntry to place into the LRU cache
 /// containing all relevant information for the fields that need matching
 /// against according to the specified FieldMatchConfig.
@@ -137,3 +192,84 @@ impl TaskTransform<Event> for Dedupe {
         Box::pin(task.filter_map(move |v| ready(inner.transform_one(v))))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::LogEvent;
+
+    fn dedupe(num_entries: usize, ttl: Option<Duration>) -> Dedupe {
+        Dedupe::new(
+            NonZeroUsize::new(num_entries).unwrap(),
+            ttl,
+            FieldMatchConfig::MatchFields(vec!["message".into()]),
+        )
+    }
+
+    fn passes(dedupe: &mut Dedupe, message: &str, now: Instant) -> bool {
+        dedupe
+            .transform_one_at(LogEvent::from(message).into(), now)
+            .is_some()
+    }
+
+    #[test]
+    fn expires_entries_after_ttl() {
+        let mut dedupe = dedupe(5, Some(Duration::from_secs(10)));
+        let start = Instant::now();
+
+        assert!(passes(&mut dedupe, "a", start));
+        assert!(!passes(
+            &mut dedupe,
+            "a",
+            start + Duration::from_millis(9_999)
+        ));
+        // Entries expire once the TTL has elapsed since they were first seen, whether or not
+        // duplicates were seen since.
+        assert!(passes(&mut dedupe, "a", start + Duration::from_secs(10)));
+        assert!(!passes(&mut dedupe, "a", start + Duration::from_secs(19)));
+        assert!(passes(&mut dedupe, "a", start + Duration::from_secs(20)));
+    }
+
+    #[test]
+    fn keeps_entries_without_ttl() {
+        let mut dedupe = dedupe(5, None);
+        let start = Instant::now();
+
+        assert!(passes(&mut dedupe, "a", start));
+        assert!(!passes(
+            &mut dedupe,
+            "a",
+            start + Duration::from_secs(86_400)
+        ));
+    }
+
+    #[test]
+    fn evicts_entries_beyond_count() {
+        let mut dedupe = dedupe(2, Some(Duration::from_secs(10)));
+        let start = Instant::now();
+
+        assert!(passes(&mut dedupe, "a", start));
+        assert!(passes(&mut dedupe, "b", start));
+        assert!(passes(&mut dedupe, "c", start));
+        // `a` was evicted before expiring.
+        assert!(passes(&mut dedupe, "a", start + Duration::from_secs(1)));
+        assert!(!passes(&mut dedupe, "c", start + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn removes_expired_entries_lazily() {
+        let mut dedupe = dedupe(3, Some(Duration::from_secs(10)));
+        let start = Instant::now();
+
+        assert!(passes(&mut dedupe, "a", start));
+        assert!(passes(&mut dedupe, "b", start + Duration::from_secs(5)));
+        assert!(passes(&mut dedupe, "c", start + Duration::from_secs(10)));
+        // `a` expired, and is removed when `c` is cached.
+        assert_eq!(dedupe.cache.len(), 2);
+
+        assert!(passes(&mut dedupe, "d", start + Duration::from_secs(15)));
+        assert_eq!(dedupe.cache.len(), 2);
+        assert!(!passes(&mut dedupe, "c", start + Duration::from_secs(15)));
+        assert!(!passes(&mut dedupe, "d", start + Duration::from_secs(15)));
+    }
+}
@@ -102,6 +102,7 @@ mod test {
     use tokio_stream::wrappers::ReceiverStream;
     use vector_lib::config::ComponentKey;
     use vector_lib::event::{Metric, MetricKind, MetricValue};
+    use vrl::path;
 
     use super::*;
     use crate::config::schema::Definition;
@@ -149,4 +150,38 @@ mod test {
         })
         .await;
     }
+
+    #[tokio::test]
+    async fn filter_on_metadata() {
+        assert_transform_compliance(async {
+            let condition = AnyCondition::String(r#"%vector.source_id != "journald_in""#.into());
+            let transform_config = FilterConfig::from(condition);
+
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) =
+                create_topology(ReceiverStream::new(rx), transform_config).await;
+
+            let mut journald_log = LogEvent::from("from journald");
+            journald_log
+                .metadata_mut()
+                .value_mut()
+                .insert(path!("vector", "source_id"), "journald_in");
+            tx.send(journald_log.into()).await.unwrap();
+
+            let mut file_log = LogEvent::from("from file");
+            file_log
+                .metadata_mut()
+                .value_mut()
+                .insert(path!("vector", "source_id"), "file_in");
+            tx.send(file_log.into()).await.unwrap();
+
+            let event = out.recv().await.unwrap();
+            assert_eq!(event.as_log()["message"], "from file".into());
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
 }
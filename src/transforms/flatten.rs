@@ -0,0 +1,411 @@
+use std::{collections::BTreeMap, num::NonZeroUsize};
+
+use vector_lib::codecs::journal_export;
+use vector_lib::config::LogNamespace;
+use vector_lib::configurable::configurable_component;
+use vector_lib::event::util::log::{ArrayIndices, FlattenOptions, FlattenedFields};
+use vrl::value::{KeyString, Kind, ObjectMap};
+
+use crate::{
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{Event, Value},
+    internal_events::FlattenKeyCollision,
+    schema,
+    transforms::{FunctionTransform, OutputBuffer, Transform},
+};
+
+/// Configuration for the `flatten` transform.
+#[configurable_component(transform(
+    "flatten",
+    "Flatten the nested fields of log events, and sanitize their keys."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct FlattenConfig {
+    /// The separator joining the keys of nested fields.
+    #[serde(default = "default_separator")]
+    separator: char,
+
+    /// The maximum number of levels of nesting that are flattened.
+    ///
+    /// Objects and arrays nested deeper are kept as they are, under their flattened key. All
+    /// levels are flattened if not set.
+    #[configurable(metadata(docs::examples = 2))]
+    max_depth: Option<NonZeroUsize>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    arrays: ArrayFlattening,
+
+    /// How the flattened keys are sanitized.
+    ///
+    /// Keys are left as they are if not set.
+    #[configurable(derived)]
+    sanitize: Option<KeySanitization>,
+}
+
+const fn default_separator() -> char {
+    '.'
+}
+
+/// How arrays are flattened.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ArrayFlattening {
+    /// Arrays are kept as they are.
+    #[default]
+    Keep,
+
+    /// The elements of arrays are flattened, with their index in brackets, as in `tags[0]`.
+    ///
+    /// This is how the `journald` sink flattens arrays.
+    Brackets,
+
+    /// The elements of arrays are flattened, with their index joined with the separator, as in
+    /// `tags.0`.
+    Segments,
+}
+
+impl From<ArrayFlattening> for ArrayIndices {
+    fn from(arrays: ArrayFlattening) -> Self {
+        match arrays {
+            ArrayFlattening::Keep => ArrayIndices::Skip,
+            ArrayFlattening::Brackets => ArrayIndices::Brackets,
+            ArrayFlattening::Segments => ArrayIndices::Segments,
+        }
+    }
+}
+
+/// How keys are sanitized.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(tag = "profile", rename_all = "snake_case", deny_unknown_fields)]
+#[configurable(metadata(docs::enum_tag_description = "The sanitization profile."))]
+pub enum KeySanitization {
+    /// Sanitizes keys the same way as the `journald` sink sanitizes the names of the fields it
+    /// writes.
+    ///
+    /// Keys are uppercased, characters other than ASCII letters and digits are replaced with
+    /// underscores, keys not starting with a letter are prefixed with `ESC_`, and only their first
+    /// 64 characters are kept.
+    Journald,
+
+    /// Sanitizes keys with custom rules.
+    Custom(CustomSanitization),
+}
+
+/// Custom key sanitization rules.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct CustomSanitization {
+    #[configurable(derived)]
+    #[serde(default)]
+    case: KeyCase,
+
+    /// The characters allowed in keys, in addition to ASCII letters and digits.
+    ///
+    /// Other characters are replaced with the replacement character.
+    #[serde(default = "default_allowed_characters")]
+    allowed_characters: String,
+
+    /// The character replacing characters that are not allowed in keys.
+    #[serde(default = "default_replacement")]
+    replacement: char,
+}
+
+fn default_allowed_characters() -> String {
+    "_".to_owned()
+}
+
+const fn default_replacement() -> char {
+    '_'
+}
+
+/// How the case of keys is changed.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyCase {
+    /// The case of keys is preserved.
+    #[default]
+    Preserve,
+
+    /// Keys are lowercased.
+    Lowercase,
+
+    /// Keys are uppercased.
+    Uppercase,
+}
+
+impl KeySanitization {
+    /// Sanitizes a flattened key.
+    pub fn sanitize(&self, key: &str) -> KeyString {
+        match self {
+            Self::Journald => {
+                let mut name = Vec::with_capacity(key.len());
+                // Keys starting with an underscore are reserved for trusted fields.
+                journal_export::write_field_name(key, false, &mut name);
+                String::from_utf8(name)
+                    .expect("mangled field names are ASCII")
+                    .into()
+            }
+            Self::Custom(custom) => key
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || custom.allowed_characters.contains(c) {
+                        match custom.case {
+                            KeyCase::Preserve => c,
+                            KeyCase::Lowercase => c.to_ascii_lowercase(),
+                            KeyCase::Uppercase => c.to_ascii_uppercase(),
+                        }
+                    } else {
+                        custom.replacement
+                    }
+                })
+                .collect::<String>()
+                .into(),
+        }
+    }
+}
+
+impl GenerateConfig for FlattenConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(r#"separator = "_""#).unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "flatten")]
+impl TransformConfig for FlattenConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Ok(Transform::function(Flatten::new(self)))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _enrichment_tables: vector_lib::enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        // The fields of the events are renamed, so only the fact that they are objects is known.
+        let definitions = input_definitions
+            .iter()
+            .map(|(output, definition)| {
+                let mut definition = definition.clone();
+                let event_kind = definition.event_kind_mut();
+                *event_kind = if event_kind.is_object() {
+                    Kind::any_object()
+                } else {
+                    Kind::any()
+                };
+                (output.clone(), definition)
+            })
+            .collect();
+
+        vec![TransformOutput::new(DataType::Log, definitions)]
+    }
+
+    fn enable_concurrency(&self) -> bool {
+        true
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Flatten {
+    options: FlattenOptions,
+    sanitize: Option<KeySanitization>,
+}
+
+impl Flatten {
+    pub fn new(config: &FlattenConfig) -> Self {
+        Self {
+            options: FlattenOptions {
+                separator: config.separator,
+                array_indices: config.arrays.into(),
+                max_depth: config.max_depth.map(NonZeroUsize::get),
+            },
+            sanitize: config.sanitize.clone(),
+        }
+    }
+
+    /// Flattens the fields of an object, returning `None` if the value is not an object.
+    ///
+    /// The values of fields whose flattened keys collide are kept, in order, in an array, just as
+    /// the `journald` sink keeps them all, as repeated fields.
+    fn flatten(&self, value: &Value) -> Option<ObjectMap> {
+        if !value.is_object() {
+            return None;
+        }
+
+        let mut fields = BTreeMap::<KeyString, Vec<Value>>::new();
+        FlattenedFields::new(value, self.options).for_each(|path, value| {
+            let key = match &self.sanitize {
+                Some(sanitize) => sanitize.sanitize(path),
+                None => path.into(),
+            };
+            let values = fields.entry(key).or_default();
+            values.push(value.clone());
+        });
+
+        Some(
+            fields
+                .into_iter()
+                .map(|(key, mut values)| {
+                    let value = if values.len() == 1 {
+                        values.pop().expect("one value")
+                    } else {
+                        emit!(FlattenKeyCollision { key: &key });
+                        Value::Array(values)
+                    };
+                    (key, value)
+                })
+                .collect(),
+        )
+    }
+}
+
+impl FunctionTransform for Flatten {
+    fn transform(&mut self, output: &mut OutputBuffer, mut event: Event) {
+        let log = event.as_mut_log();
+        if let Some(fields) = self.flatten(log.value()) {
+            *log.value_mut() = Value::Object(fields);
+        }
+        output.push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+
+    use super::*;
+    use crate::{
+        event::LogEvent, test_util::components::assert_transform_compliance,
+        transforms::test::create_topology,
+    };
+
+    fn flatten(config: &str, value: serde_json::Value) -> Value {
+        let config = toml::from_str::<FlattenConfig>(config).unwrap();
+        let event = Event::from(LogEvent::from(Value::from(value)));
+        let mut output = OutputBuffer::with_capacity(1);
+        Flatten::new(&config).transform(&mut output, event);
+        output
+            .into_events()
+            .next()
+            .unwrap()
+            .into_log()
+            .value()
+            .clone()
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<FlattenConfig>();
+    }
+
+    #[test]
+    fn flattens_nested_objects() {
+        let value = json!({
+            "kubernetes": {"pod_labels": {"app": "vector"}, "namespace": "default"},
+            "tags": ["a", "b"],
+            "message": "hello",
+        });
+
+        assert_eq!(
+            flatten("", value.clone()),
+            Value::from(json!({
+                "kubernetes.namespace": "default",
+                "kubernetes.pod_labels.app": "vector",
+                "message": "hello",
+                "tags": ["a", "b"],
+            }))
+        );
+        assert_eq!(
+            flatten(
+                r#"
+                separator = "_"
+                max_depth = 2
+                arrays = "segments"
+                "#,
+                value
+            ),
+            Value::from(json!({
+                "kubernetes_namespace": "default",
+                "kubernetes_pod_labels": {"app": "vector"},
+                "message": "hello",
+                "tags_0": "a",
+                "tags_1": "b",
+            }))
+        );
+    }
+
+    #[test]
+    fn sanitizes_keys() {
+        let value = json!({"Pod-Labels": {"app.kubernetes.io/name": "vector"}});
+
+        assert_eq!(
+            flatten(
+                r#"
+                separator = "_"
+                sanitize.profile = "custom"
+                sanitize.case = "lowercase"
+                sanitize.allowed_characters = "_."
+                "#,
+                value.clone()
+            ),
+            Value::from(json!({"pod_labels_app.kubernetes.io_name": "vector"}))
+        );
+        assert_eq!(
+            flatten(r#"sanitize.profile = "journald""#, value),
+            Value::from(json!({"POD_LABELS_APP_KUBERNETES_IO_NAME": "vector"}))
+        );
+    }
+
+    #[test]
+    fn keeps_colliding_values() {
+        assert_eq!(
+            flatten(
+                r#"sanitize.profile = "journald""#,
+                json!({"foo-bar": 1, "foo": {"bar": 2}, "foo_bar": 3}),
+            ),
+            Value::from(json!({"FOO_BAR": [2, 1, 3]}))
+        );
+    }
+
+    #[test]
+    fn keeps_non_object_roots() {
+        assert_eq!(flatten("", json!("hello")), Value::from("hello"));
+    }
+
+    #[tokio::test]
+    async fn emits_flattened_events() {
+        assert_transform_compliance(async {
+            let config = toml::from_str::<FlattenConfig>(r#"separator = "_""#).unwrap();
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            let mut log = LogEvent::from("hello");
+            log.insert("nested.field", 1);
+            tx.send(log.into()).await.unwrap();
+
+            let event = out.recv().await.unwrap();
+            assert_eq!(event.as_log().get("nested_field"), Some(&Value::from(1)));
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
+}
@@ -13,14 +13,20 @@ pub mod aggregate;
 pub mod aws_ec2_metadata;
 #[cfg(feature = "transforms-filter")]
 pub mod filter;
+#[cfg(feature = "transforms-flatten")]
+pub mod flatten;
 #[cfg(feature = "transforms-log_to_metric")]
 pub mod log_to_metric;
 #[cfg(feature = "transforms-lua")]
 pub mod lua;
 #[cfg(feature = "transforms-metric_to_log")]
 pub mod metric_to_log;
+#[cfg(feature = "transforms-normalize_severity")]
+pub mod normalize_severity;
 #[cfg(feature = "transforms-remap")]
 pub mod remap;
+#[cfg(feature = "transforms-rollup")]
+pub mod rollup;
 #[cfg(feature = "transforms-route")]
 pub mod route;
 #[cfg(feature = "transforms-tag_cardinality_limit")]
@@ -0,0 +1,388 @@
+use vector_lib::config::LogNamespace;
+use vector_lib::configurable::configurable_component;
+use vector_lib::lookup::lookup_v2::ConfigTargetPath;
+use vector_lib::lookup::{OwnedTargetPath, PathPrefix};
+use vector_lib::schema::meaning;
+use vrl::value::Kind;
+
+use crate::{
+    common::severity::{Severity, SeverityNumbering},
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{Event, LogEvent},
+    internal_events::NormalizeSeverityUnknownValue,
+    schema,
+    transforms::{FunctionTransform, OutputBuffer, Transform},
+};
+
+/// Configuration for the `normalize_severity` transform.
+#[configurable_component(transform(
+    "normalize_severity",
+    "Normalize the severity of log events into a syslog severity."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct NormalizeSeverityConfig {
+    /// The fields the severity is read from, in order of preference.
+    ///
+    /// The severity is read from the first of these fields holding a known severity.
+    #[serde(default = "default_fields")]
+    fields: Vec<SeverityField>,
+
+    /// The field the syslog keyword of the severity, such as `err`, is written to.
+    ///
+    /// The field is given the `severity` semantic meaning, which the `journald` and `papertrail`
+    /// sinks use to set the priority of the messages they write.
+    #[serde(default = "default_target")]
+    #[configurable(metadata(docs::examples = "severity"))]
+    target: ConfigTargetPath,
+
+    /// The field the syslog code of the severity, from 0 for `emerg` to 7 for `debug`, is written
+    /// to.
+    #[serde(default = "default_code_target")]
+    #[configurable(metadata(docs::examples = "severity_code"))]
+    code_target: ConfigTargetPath,
+
+    /// The severity of events none of whose fields hold a known severity.
+    ///
+    /// Such events are left untouched if not set.
+    #[configurable(derived)]
+    default: Option<Severity>,
+
+    /// Whether the fields holding a known severity are removed once it is normalized.
+    ///
+    /// Fields holding an unknown severity are kept.
+    #[serde(default = "crate::serde::default_false")]
+    remove_fields: bool,
+}
+
+/// A field the severity is read from.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct SeverityField {
+    /// The path of the field.
+    #[configurable(metadata(docs::examples = "level"))]
+    path: ConfigTargetPath,
+
+    /// How the numbers held by the field are mapped to severities.
+    ///
+    /// Keywords, such as `warn` or `ERROR`, are recognized whatever the numbering.
+    #[configurable(derived)]
+    #[serde(default)]
+    numbering: SeverityNumbering,
+}
+
+fn default_fields() -> Vec<SeverityField> {
+    [
+        ("severity", SeverityNumbering::Syslog),
+        ("level", SeverityNumbering::Syslog),
+        ("severity_text", SeverityNumbering::Syslog),
+        ("severity_number", SeverityNumbering::Opentelemetry),
+        ("priority", SeverityNumbering::Syslog),
+    ]
+    .into_iter()
+    .map(|(path, numbering)| SeverityField {
+        path: path.into(),
+        numbering,
+    })
+    .collect()
+}
+
+fn default_target() -> ConfigTargetPath {
+    "severity".into()
+}
+
+fn default_code_target() -> ConfigTargetPath {
+    "severity_code".into()
+}
+
+impl GenerateConfig for NormalizeSeverityConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(r#"default = "info""#).unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "normalize_severity")]
+impl TransformConfig for NormalizeSeverityConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Ok(Transform::function(NormalizeSeverity::new(self)))
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _enrichment_tables: vector_lib::enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        let definitions = input_definitions
+            .iter()
+            .map(|(output, definition)| (output.clone(), self.output_definition(definition)))
+            .collect();
+
+        vec![TransformOutput::new(DataType::Log, definitions)]
+    }
+
+    fn enable_concurrency(&self) -> bool {
+        true
+    }
+}
+
+impl NormalizeSeverityConfig {
+    fn output_definition(&self, definition: &schema::Definition) -> schema::Definition {
+        let target = &self.target.0;
+        let code_target = &self.code_target.0;
+        // Fields can't be added to events that are not objects.
+        if definition.event_kind().as_object().is_none()
+            && (target.prefix == PathPrefix::Event || code_target.prefix == PathPrefix::Event)
+        {
+            return definition.clone();
+        }
+
+        let mut definition = definition.clone();
+        if self.remove_fields {
+            for field in &self.fields {
+                let path = &field.path.0;
+                if path != target && path != code_target {
+                    let kind = field_kind(&definition, path).or_undefined();
+                    definition = definition.with_field(path, kind, None);
+                }
+            }
+        }
+
+        // Without a default, events without a known severity are left untouched.
+        let (keyword, code) = if self.default.is_some() {
+            (Kind::bytes(), Kind::integer())
+        } else {
+            let keyword = field_kind(&definition, target).union(Kind::bytes());
+            let code = field_kind(&definition, code_target).union(Kind::integer());
+            (keyword, code)
+        };
+        definition
+            .with_field(target, keyword, Some(meaning::SEVERITY))
+            .with_field(code_target, code, None)
+    }
+}
+
+fn field_kind(definition: &schema::Definition, path: &OwnedTargetPath) -> Kind {
+    match path.prefix {
+        PathPrefix::Event => definition.event_kind().at_path(&path.path),
+        PathPrefix::Metadata => definition.metadata_kind().at_path(&path.path),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct NormalizeSeverity {
+    fields: Vec<SeverityField>,
+    target: OwnedTargetPath,
+    code_target: OwnedTargetPath,
+    default: Option<Severity>,
+    remove_fields: bool,
+}
+
+impl NormalizeSeverity {
+    pub fn new(config: &NormalizeSeverityConfig) -> Self {
+        Self {
+            fields: config.fields.clone(),
+            target: config.target.0.clone(),
+            code_target: config.code_target.0.clone(),
+            default: config.default,
+            remove_fields: config.remove_fields,
+        }
+    }
+
+    /// Returns the severity held by the first field holding a known one, along with the first
+    /// field found to hold an unknown one before it, if any.
+    fn find_severity(&self, log: &LogEvent) -> (Option<Severity>, Option<&OwnedTargetPath>) {
+        let mut unknown = None;
+        for field in &self.fields {
+            if let Some(value) = log.get(&field.path.0) {
+                match Severity::from_value(value, field.numbering) {
+                    Some(severity) => return (Some(severity), unknown),
+                    None => {
+                        unknown.get_or_insert(&field.path.0);
+                    }
+                }
+            }
+        }
+        (None, unknown)
+    }
+
+    fn normalize(&self, log: &mut LogEvent) {
+        let severity = match self.find_severity(log) {
+            (Some(severity), _) => {
+                if self.remove_fields {
+                    for field in &self.fields {
+                        let path = &field.path.0;
+                        let known = log.get(path).is_some_and(|value| {
+                            Severity::from_value(value, field.numbering).is_some()
+                        });
+                        if known && *path != self.target && *path != self.code_target {
+                            log.remove(path);
+                        }
+                    }
+                }
+                severity
+            }
+            (None, unknown) => {
+                if let Some(field) = unknown {
+                    emit!(NormalizeSeverityUnknownValue { field });
+                }
+                match self.default {
+                    Some(severity) => severity,
+                    None => return,
+                }
+            }
+        };
+
+        log.insert(&self.target, severity.keyword());
+        log.insert(&self.code_target, i64::from(severity.code()));
+    }
+}
+
+impl FunctionTransform for NormalizeSeverity {
+    fn transform(&mut self, output: &mut OutputBuffer, mut event: Event) {
+        self.normalize(event.as_mut_log());
+        output.push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use vector_lib::lookup::owned_value_path;
+
+    use super::*;
+    use crate::{
+        event::Value, test_util::components::assert_transform_compliance,
+        transforms::test::create_topology,
+    };
+
+    fn normalize(config: &str, value: serde_json::Value) -> Value {
+        let config = toml::from_str::<NormalizeSeverityConfig>(config).unwrap();
+        let mut log = LogEvent::from(Value::from(value));
+        NormalizeSeverity::new(&config).normalize(&mut log);
+        log.value().clone()
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<NormalizeSeverityConfig>();
+    }
+
+    #[test]
+    fn normalizes_known_severities() {
+        assert_eq!(
+            normalize("", json!({"level": "WARN", "message": "hello"})),
+            Value::from(json!({
+                "level": "WARN",
+                "message": "hello",
+                "severity": "warning",
+                "severity_code": 4,
+            }))
+        );
+        assert_eq!(
+            normalize("", json!({"severity_number": 17})),
+            Value::from(json!({
+                "severity": "err",
+                "severity_code": 3,
+                "severity_number": 17,
+            }))
+        );
+    }
+
+    #[test]
+    fn reads_fields_in_order() {
+        let config = r#"
+            fields = [
+                { path = "pri", numbering = "syslog_priority" },
+                { path = "level" },
+            ]
+            target = "sev"
+            code_target = "sev_code"
+            remove_fields = true
+        "#;
+
+        assert_eq!(
+            normalize(
+                config,
+                json!({"pri": "verbose", "level": 2, "message": "hello"})
+            ),
+            Value::from(json!({
+                "message": "hello",
+                "pri": "verbose",
+                "sev": "crit",
+                "sev_code": 2,
+            }))
+        );
+        assert_eq!(
+            normalize(config, json!({"pri": 13, "level": 2})),
+            Value::from(json!({"sev": "notice", "sev_code": 5}))
+        );
+    }
+
+    #[test]
+    fn passes_unknown_severities_through() {
+        let value = json!({"level": "verbose"});
+
+        assert_eq!(normalize("", value.clone()), Value::from(value.clone()));
+        assert_eq!(
+            normalize(r#"default = "info""#, value),
+            Value::from(json!({
+                "level": "verbose",
+                "severity": "info",
+                "severity_code": 6,
+            }))
+        );
+    }
+
+    #[test]
+    fn attaches_severity_meaning() {
+        let config = toml::from_str::<NormalizeSeverityConfig>(r#"default = "info""#).unwrap();
+        let definition = schema::Definition::empty_legacy_namespace();
+
+        let definition = config.output_definition(&definition);
+        assert_eq!(
+            definition.meaning_path(meaning::SEVERITY),
+            Some(&OwnedTargetPath::event(owned_value_path!("severity")))
+        );
+        assert_eq!(
+            definition
+                .event_kind()
+                .at_path(&owned_value_path!("severity_code")),
+            Kind::integer()
+        );
+    }
+
+    #[tokio::test]
+    async fn emits_normalized_events() {
+        assert_transform_compliance(async {
+            let config = toml::from_str::<NormalizeSeverityConfig>("").unwrap();
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            let mut log = LogEvent::from("hello");
+            log.insert("level", "error");
+            tx.send(log.into()).await.unwrap();
+
+            let event = out.recv().await.unwrap();
+            let log = event.as_log();
+            assert_eq!(log.get("severity_code"), Some(&Value::from(3)));
+            assert_eq!(log.get("severity"), Some(&Value::from("err")));
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
+}
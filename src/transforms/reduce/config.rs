@@ -8,6 +8,7 @@ use vrl::path::{parse_target_path, PathPrefix};
 use vrl::prelude::{Collection, KeyString, Kind};
 
 use vector_lib::configurable::configurable_component;
+use vector_lib::lookup::lookup_v2::ConfigTargetPath;
 
 use crate::conditions::AnyCondition;
 use crate::config::{
@@ -51,8 +52,25 @@ pub struct ReduceConfig {
     pub flush_period_ms: Duration,
 
     /// The maximum number of events to group together.
+    ///
+    /// A group reaching this number of events is flushed right away.
     pub max_events: Option<NonZeroUsize>,
 
+    /// The maximum size of the events to group together, in bytes.
+    ///
+    /// The size of events is estimated as it is for buffers. A group that the next event would make
+    /// exceed this size is flushed before it, and a group reaching it is flushed right away, so
+    /// that groups whose end is never found don't grow without bound.
+    #[configurable(metadata(docs::type_unit = "bytes"))]
+    pub max_bytes_per_group: Option<NonZeroUsize>,
+
+    /// The field set to `true` in the events of groups flushed because they reached
+    /// `max_events` or `max_bytes_per_group`, rather than because they ended or expired.
+    ///
+    /// Events are not tagged if not set.
+    #[configurable(metadata(docs::examples = "reduce_truncated"))]
+    pub truncated_field: Option<ConfigTargetPath>,
+
     /// An ordered list of fields by which to group events.
     ///
     /// Each group with matching values for the specified keys is reduced independently, allowing
@@ -218,6 +236,14 @@ impl TransformConfig for ReduceConfig {
             schema_definition = schema_definition.with_field(&key, new_kind, None);
         }
 
+        if let Some(truncated_field) = &self.truncated_field {
+            schema_definition = schema_definition.with_field(
+                &truncated_field.0,
+                Kind::boolean().or_undefined(),
+                None,
+            );
+        }
+
         // the same schema definition is used for all inputs
         let mut output_definitions = HashMap::new();
         for (output, _input) in input_definitions {
@@ -3,7 +3,7 @@ use std::collections::{hash_map, HashMap};
 use std::pin::Pin;
 use std::time::{Duration, Instant};
 
-use crate::internal_events::ReduceAddEventError;
+use crate::internal_events::{ReduceAddEventError, ReduceGroupTruncated};
 use crate::transforms::reduce::merge_strategy::{
     get_value_merger, MergeStrategy, ReduceValueMerger,
 };
@@ -16,12 +16,14 @@ use crate::{
 use futures::Stream;
 use indexmap::IndexMap;
 use vector_lib::stream::expiration_map::{map_with_expiration, Emitter};
+use vector_lib::ByteSizeOf;
 use vrl::path::{parse_target_path, OwnedTargetPath};
 use vrl::prelude::KeyString;
 
 #[derive(Debug)]
 struct ReduceState {
     events: usize,
+    bytes: usize,
     fields: HashMap<OwnedTargetPath, Box<dyn ReduceValueMerger>>,
     stale_since: Instant,
     creation: Instant,
@@ -46,6 +48,7 @@ impl ReduceState {
     fn new() -> Self {
         Self {
             events: 0,
+            bytes: 0,
             stale_since: Instant::now(),
             creation: Instant::now(),
             fields: HashMap::new(),
@@ -54,6 +57,7 @@ impl ReduceState {
     }
 
     fn add_event(&mut self, e: LogEvent, strategies: &IndexMap<OwnedTargetPath, MergeStrategy>) {
+        self.bytes += e.size_of();
         self.metadata.merge(e.metadata().clone());
 
         for (path, strategy) in strategies {
@@ -128,10 +132,27 @@ impl ReduceState {
             }
         }
         self.events = 0;
+        self.bytes = 0;
         event
     }
 }
 
+/// A limit of the groups of events, reaching which has them flushed before their end.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum GroupLimit {
+    Events,
+    Bytes,
+}
+
+impl GroupLimit {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Events => "max_events",
+            Self::Bytes => "max_bytes_per_group",
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Reduce {
     expire_after: Duration,
@@ -143,6 +164,8 @@ pub struct Reduce {
     ends_when: Option<Condition>,
     starts_when: Option<Condition>,
     max_events: Option<usize>,
+    max_bytes_per_group: Option<usize>,
+    truncated_field: Option<OwnedTargetPath>,
 }
 
 fn validate_merge_strategies(strategies: IndexMap<KeyString, MergeStrategy>) -> crate::Result<()> {
@@ -211,6 +234,8 @@ impl Reduce {
             ends_when,
             starts_when,
             max_events,
+            max_bytes_per_group: config.max_bytes_per_group.map(Into::into),
+            truncated_field: config.truncated_field.as_ref().map(|field| field.0.clone()),
         })
     }
 
@@ -255,6 +280,20 @@ impl Reduce {
         };
     }
 
+    /// Flushes a group, tagging it if it was truncated for reaching one of the limits.
+    fn flush_group(&self, state: ReduceState, truncated_by: Option<GroupLimit>) -> Event {
+        let mut event = state.flush();
+        if let Some(limit) = truncated_by {
+            emit!(ReduceGroupTruncated {
+                limit: limit.as_str()
+            });
+            if let Some(field) = &self.truncated_field {
+                event.insert(field, true);
+            }
+        }
+        event.into()
+    }
+
     pub(crate) fn transform_one(&mut self, emitter: &mut Emitter<Event>, event: Event) {
         let (starts_here, event) = match &self.starts_when {
             Some(condition) => condition.check(event),
@@ -269,35 +308,63 @@ impl Reduce {
         let event = event.into_log();
         let discriminant = Discriminant::from_log_event(&event, &self.group_by);
 
+        let mut reaches_max_bytes = false;
+        if let Some(max_bytes) = self.max_bytes_per_group {
+            let bytes = event.size_of();
+            let mut current = self
+                .reduce_merge_states
+                .get(&discriminant)
+                .filter(|_| !starts_here)
+                .map_or(0, |state| state.bytes);
+            // The group is flushed before this event if it would exceed the maximum size with it.
+            if current > 0 && current + bytes > max_bytes {
+                let state = self
+                    .reduce_merge_states
+                    .remove(&discriminant)
+                    .expect("group exists");
+                emitter.emit(self.flush_group(state, Some(GroupLimit::Bytes)));
+                current = 0;
+            }
+            reaches_max_bytes = current + bytes >= max_bytes;
+        }
+
+        let mut truncated_by = None;
         if let Some(max_events) = self.max_events {
             if max_events == 1 {
-                ends_here = true;
+                truncated_by = Some(GroupLimit::Events);
             } else if let Some(entry) = self.reduce_merge_states.get(&discriminant) {
                 // The current event will finish this set
                 if entry.events + 1 == max_events {
-                    ends_here = true;
+                    truncated_by = Some(GroupLimit::Events);
                 }
             }
         }
+        if reaches_max_bytes {
+            truncated_by = truncated_by.or(Some(GroupLimit::Bytes));
+        }
+        // Groups ending with this event are not truncated.
+        let truncated_by = truncated_by.filter(|_| !ends_here);
+        ends_here |= truncated_by.is_some();
 
         if starts_here {
             if let Some(state) = self.reduce_merge_states.remove(&discriminant) {
                 emitter.emit(state.flush().into());
             }
 
-            self.push_or_new_reduce_state(event, discriminant)
+            if truncated_by == Some(GroupLimit::Bytes) {
+                let mut state = ReduceState::new();
+                state.add_event(event, &self.merge_strategies);
+                emitter.emit(self.flush_group(state, truncated_by));
+            } else {
+                self.push_or_new_reduce_state(event, discriminant)
+            }
         } else if ends_here {
-            emitter.emit(match self.reduce_merge_states.remove(&discriminant) {
-                Some(mut state) => {
-                    state.add_event(event, &self.merge_strategies);
-                    state.flush().into()
-                }
-                None => {
-                    let mut state = ReduceState::new();
-                    state.add_event(event, &self.merge_strategies);
-                    state.flush().into()
-                }
-            });
+            let mut state = self
+                .reduce_merge_states
+                .remove(&discriminant)
+                .unwrap_or_else(ReduceState::new);
+            state.add_event(event, &self.merge_strategies);
+            emitter.emit(self.flush_group(state, truncated_by));
         } else {
             self.push_or_new_reduce_state(event, discriminant)
         }
@@ -742,6 +809,140 @@ max_events = 3
         .await
     }
 
+    fn grouped_events(count: usize) -> Vec<Event> {
+        (1..=count)
+            .map(|i| {
+                let mut event = LogEvent::from(format!("test {i}"));
+                event.insert("id", "1");
+                event.into()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn max_events_truncated() {
+        let reduce_config = toml::from_str::<ReduceConfig>(
+            r#"
+group_by = [ "id" ]
+merge_strategies.id = "retain"
+merge_strategies.message = "array"
+max_events = 2
+truncated_field = "reduce_truncated"
+
+[ends_when]
+  type = "vrl"
+  source = "exists(.test_end)"
+            "#,
+        )
+        .unwrap();
+
+        assert_transform_compliance(async move {
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), reduce_config).await;
+
+            let mut events = grouped_events(4);
+            events[3].as_mut_log().insert("test_end", true);
+            for event in events {
+                tx.send(event).await.unwrap();
+            }
+
+            let output_1 = out.recv().await.unwrap().into_log();
+            assert_eq!(output_1["message"], vec!["test 1", "test 2"].into());
+            assert_eq!(output_1["reduce_truncated"], true.into());
+
+            // The group ends with its last event, so it isn't truncated.
+            let output_2 = out.recv().await.unwrap().into_log();
+            assert_eq!(output_2["message"], vec!["test 3", "test 4"].into());
+            assert!(!output_2.contains("reduce_truncated"));
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn max_bytes_per_group() {
+        let events = grouped_events(6);
+        let event_bytes = events[0].as_log().size_of();
+        // Room for two and a half events.
+        let reduce_config = toml::from_str::<ReduceConfig>(&format!(
+            r#"
+group_by = [ "id" ]
+merge_strategies.id = "retain"
+merge_strategies.message = "array"
+max_bytes_per_group = {}
+truncated_field = "reduce_truncated"
+            "#,
+            event_bytes * 5 / 2
+        ))
+        .unwrap();
+
+        assert_transform_compliance(async move {
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), reduce_config).await;
+
+            for event in events.into_iter().take(5) {
+                tx.send(event).await.unwrap();
+            }
+
+            // Groups are flushed before the event that would make them exceed the limit.
+            for messages in [vec!["test 1", "test 2"], vec!["test 3", "test 4"]] {
+                let output = out.recv().await.unwrap().into_log();
+                assert_eq!(output["message"], messages.into());
+                assert_eq!(output["reduce_truncated"], true.into());
+            }
+
+            drop(tx);
+            topology.stop().await;
+
+            // The last group is flushed when the input ends.
+            let output = out.recv().await.unwrap().into_log();
+            assert_eq!(output["message"], vec!["test 5"].into());
+            assert!(!output.contains("reduce_truncated"));
+            assert_eq!(out.recv().await, None);
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn max_bytes_per_group_single_event() {
+        let events = grouped_events(2);
+        let event_bytes = events[0].as_log().size_of();
+        let reduce_config = toml::from_str::<ReduceConfig>(&format!(
+            r#"
+group_by = [ "id" ]
+merge_strategies.message = "array"
+max_bytes_per_group = {}
+truncated_field = "reduce_truncated"
+            "#,
+            event_bytes / 2
+        ))
+        .unwrap();
+
+        assert_transform_compliance(async move {
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), reduce_config).await;
+
+            for event in events {
+                tx.send(event).await.unwrap();
+            }
+
+            // Events reaching the limit on their own are flushed right away.
+            for message in ["test 1", "test 2"] {
+                let output = out.recv().await.unwrap().into_log();
+                assert_eq!(output["message"], vec![message].into());
+                assert_eq!(output["reduce_truncated"], true.into());
+            }
+
+            drop(tx);
+            topology.stop().await;
+            assert_eq!(out.recv().await, None);
+        })
+        .await
+    }
+
     #[tokio::test]
     async fn arrays() {
         let reduce_config = toml::from_str::<ReduceConfig>(
@@ -863,6 +863,40 @@ mod tests {
         assert_eq!(get_field_string(&result, "copy"), "buz");
     }
 
+    #[test]
+    fn check_remap_reads_origin_metadata() {
+        // The origin is attached to the event metadata by the source sender, when enabled.
+        let event = {
+            let mut event = LogEvent::from("augment me");
+            event.insert(metadata_path!("vector", "source_id"), "in");
+            event.insert(metadata_path!("vector", "source_type"), "demo_logs");
+            event.insert(
+                metadata_path!("vector", "ingest_timestamp"),
+                chrono::Utc::now(),
+            );
+            Event::from(event)
+        };
+
+        let conf = RemapConfig {
+            source: Some(
+                r#"  .source_id = %vector.source_id
+  .source_type = %vector.source_type
+  .ingested = is_timestamp(%vector.ingest_timestamp)
+"#
+                .to_string(),
+            ),
+            file: None,
+            drop_on_error: true,
+            drop_on_abort: false,
+            ..Default::default()
+        };
+        let mut tform = remap(conf).unwrap();
+        let result = transform_one(&mut tform, event).unwrap();
+        assert_eq!(get_field_string(&result, "source_id"), "in");
+        assert_eq!(get_field_string(&result, "source_type"), "demo_logs");
+        assert_eq!(result.as_log()["ingested"], true.into());
+    }
+
     #[test]
     fn check_remap_emits_multiple() {
         let event = {
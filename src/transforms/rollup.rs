@@ -0,0 +1,498 @@
+use std::{collections::BTreeMap, num::NonZeroUsize, pin::Pin, time::Duration};
+
+use async_stream::stream;
+use chrono::{DateTime, Utc};
+use futures::{Stream, StreamExt};
+use indexmap::IndexMap;
+use serde_with::serde_as;
+use vector_lib::config::LogNamespace;
+use vector_lib::configurable::configurable_component;
+use vector_lib::lookup::lookup_v2::ConfigTargetPath;
+use vector_lib::lookup::{OwnedTargetPath, PathPrefix};
+use vrl::value::Kind;
+
+use crate::{
+    conditions::{AnyCondition, Condition},
+    config::{
+        DataType, GenerateConfig, Input, OutputId, TransformConfig, TransformContext,
+        TransformOutput,
+    },
+    event::{Event, LogEvent, ObjectMap, Value},
+    internal_events::{
+        RollupEventCollapsed, RollupGroups, RollupMaxGroupsReached, TemplateRenderingError,
+    },
+    schema,
+    template::Template,
+    transforms::{TaskTransform, Transform},
+};
+
+/// Configuration for the `rollup` transform.
+#[serde_as]
+#[configurable_component(transform(
+    "rollup",
+    "Collapse repetitive logs into periodic rollup events counting them."
+))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct RollupConfig {
+    /// A logical condition selecting the events that are rolled up.
+    ///
+    /// Events not matching the condition are forwarded as they are.
+    condition: AnyCondition,
+
+    /// The values events are grouped by, each group being rolled up into its own rollup event.
+    ///
+    /// Events are grouped by the rendered values of all of these templates. All selected events
+    /// are rolled up into a single group if not set.
+    #[serde(default)]
+    #[configurable(metadata(
+        docs::examples = "{{ host }}",
+        docs::examples = "{{ status }}",
+        docs::examples = "{{ path }}"
+    ))]
+    group_by: Vec<Template>,
+
+    /// The interval between flushes, in seconds.
+    ///
+    /// Every interval, a rollup event is emitted for each group events were rolled up into.
+    #[serde(default = "default_interval_secs")]
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<f64>")]
+    #[configurable(metadata(docs::human_name = "Flush Interval"))]
+    interval_secs: Duration,
+
+    /// The maximum number of groups events are rolled up into during an interval.
+    ///
+    /// Once this many groups exist, selected events that would start another group are forwarded
+    /// as they are.
+    #[serde(default = "default_max_groups")]
+    #[configurable(metadata(docs::examples = 1000))]
+    max_groups: NonZeroUsize,
+
+    /// The field rollup events hold their rollup details in.
+    ///
+    /// A rollup event is the first event of its group, with this field set to an object holding
+    /// the `count` of events rolled up, and the `first_timestamp` and `last_timestamp` of these
+    /// events. The timestamps are those of the events, or the times at which they were rolled up
+    /// if they have none.
+    #[serde(default = "default_field")]
+    #[configurable(metadata(docs::examples = "rollup"))]
+    field: ConfigTargetPath,
+}
+
+const fn default_interval_secs() -> Duration {
+    Duration::from_secs(60)
+}
+
+fn default_max_groups() -> NonZeroUsize {
+    NonZeroUsize::new(1000).expect("static")
+}
+
+fn default_field() -> ConfigTargetPath {
+    "rollup".into()
+}
+
+impl GenerateConfig for RollupConfig {
+    fn generate_config() -> toml::Value {
+        toml::from_str(
+            r#"condition = ".status == 200"
+            group_by = ["{{ path }}"]"#,
+        )
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+#[typetag::serde(name = "rollup")]
+impl TransformConfig for RollupConfig {
+    async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
+        Rollup::new(self, context).map(Transform::event_task)
+    }
+
+    fn input(&self) -> Input {
+        Input::log()
+    }
+
+    fn outputs(
+        &self,
+        _: vector_lib::enrichment::TableRegistry,
+        input_definitions: &[(OutputId, schema::Definition)],
+        _: LogNamespace,
+    ) -> Vec<TransformOutput> {
+        let definitions = input_definitions
+            .iter()
+            .map(|(output, definition)| (output.clone(), self.output_definition(definition)))
+            .collect();
+
+        vec![TransformOutput::new(DataType::Log, definitions)]
+    }
+}
+
+impl RollupConfig {
+    fn output_definition(&self, definition: &schema::Definition) -> schema::Definition {
+        let field = &self.field.0;
+        // Fields can't be added to events that are not objects.
+        if field.prefix == PathPrefix::Event && definition.event_kind().as_object().is_none() {
+            return definition.clone();
+        }
+
+        // Only rollup events have the field.
+        let kind = match field.prefix {
+            PathPrefix::Event => definition.event_kind().at_path(&field.path),
+            PathPrefix::Metadata => definition.metadata_kind().at_path(&field.path),
+        };
+        let rollup = Kind::object(BTreeMap::from([
+            ("count".into(), Kind::integer()),
+            ("first_timestamp".into(), Kind::timestamp()),
+            ("last_timestamp".into(), Kind::timestamp()),
+        ]));
+        definition
+            .clone()
+            .with_field(field, kind.union(rollup), None)
+    }
+}
+
+/// The events rolled up into a group during the current interval.
+struct RollupGroup {
+    /// The first event of the group, which becomes its rollup event.
+    event: LogEvent,
+    count: u64,
+    first_timestamp: DateTime<Utc>,
+    last_timestamp: DateTime<Utc>,
+}
+
+pub struct Rollup {
+    condition: Condition,
+    group_by: Vec<Template>,
+    interval: Duration,
+    max_groups: usize,
+    field: OwnedTargetPath,
+    groups: IndexMap<Vec<Option<String>>, RollupGroup>,
+}
+
+impl Rollup {
+    pub fn new(config: &RollupConfig, context: &TransformContext) -> crate::Result<Self> {
+        if config.interval_secs.is_zero() {
+            return Err("`interval_secs` must be non-zero".into());
+        }
+
+        Ok(Self {
+            condition: config.condition.build(&context.enrichment_tables)?,
+            group_by: config.group_by.clone(),
+            interval: config.interval_secs,
+            max_groups: config.max_groups.get(),
+            field: config.field.0.clone(),
+            groups: IndexMap::new(),
+        })
+    }
+
+    /// Returns the values of the templates events are grouped by, a value failing to render being
+    /// left out.
+    fn group_key(&self, log: &LogEvent) -> Vec<Option<String>> {
+        self.group_by
+            .iter()
+            .map(|template| {
+                template
+                    .render_string(log)
+                    .map_err(|error| {
+                        emit!(TemplateRenderingError {
+                            error,
+                            field: Some("group_by"),
+                            drop_event: false,
+                        })
+                    })
+                    .ok()
+            })
+            .collect()
+    }
+
+    /// Rolls the event up if it is selected, returning it otherwise, or if it would start a group
+    /// beyond the maximum number of groups.
+    fn record(&mut self, event: Event, now: DateTime<Utc>) -> Option<Event> {
+        let (selected, event) = self.condition.check(event);
+        if !selected {
+            return Some(event);
+        }
+
+        let log = event.into_log();
+        let key = self.group_key(&log);
+        let timestamp = log
+            .get_timestamp()
+            .and_then(Value::as_timestamp)
+            .copied()
+            .unwrap_or(now);
+        match self.groups.get_mut(&key) {
+            Some(group) => {
+                group.count += 1;
+                group.first_timestamp = group.first_timestamp.min(timestamp);
+                group.last_timestamp = group.last_timestamp.max(timestamp);
+                // The rolled up events are only acknowledged once their rollup event is.
+                let (_, metadata) = log.into_parts();
+                group.event.metadata_mut().merge(metadata);
+            }
+            None if self.groups.len() >= self.max_groups => {
+                emit!(RollupMaxGroupsReached {
+                    max_groups: self.max_groups
+                });
+                return Some(log.into());
+            }
+            None => {
+                self.groups.insert(
+                    key,
+                    RollupGroup {
+                        event: log,
+                        count: 1,
+                        first_timestamp: timestamp,
+                        last_timestamp: timestamp,
+                    },
+                );
+                emit!(RollupGroups {
+                    count: self.groups.len()
+                });
+            }
+        }
+        emit!(RollupEventCollapsed);
+        None
+    }
+
+    /// Ends the current interval, returning the rollup event of each group.
+    fn flush(&mut self) -> Vec<Event> {
+        let events = self
+            .groups
+            .drain(..)
+            .map(|(_, group)| {
+                let mut event = group.event;
+                let mut rollup = ObjectMap::new();
+                rollup.insert(
+                    "count".into(),
+                    i64::try_from(group.count).unwrap_or(i64::MAX).into(),
+                );
+                rollup.insert("first_timestamp".into(), group.first_timestamp.into());
+                rollup.insert("last_timestamp".into(), group.last_timestamp.into());
+                event.insert(&self.field, Value::Object(rollup));
+                event.into()
+            })
+            .collect();
+        emit!(RollupGroups { count: 0 });
+        events
+    }
+}
+
+impl TaskTransform<Event> for Rollup {
+    fn transform(
+        mut self: Box<Self>,
+        mut input_rx: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    ) -> Pin<Box<dyn Stream<Item = Event> + Send>>
+    where
+        Self: 'static,
+    {
+        let mut flush_stream =
+            tokio::time::interval_at(tokio::time::Instant::now() + self.interval, self.interval);
+
+        Box::pin(stream! {
+            loop {
+                let done = tokio::select! {
+                    _ = flush_stream.tick() => {
+                        for event in self.flush() {
+                            yield event;
+                        }
+                        false
+                    },
+                    maybe_event = input_rx.next() => {
+                        match maybe_event {
+                            None => {
+                                for event in self.flush() {
+                                    yield event;
+                                }
+                                true
+                            }
+                            Some(event) => {
+                                if let Some(event) = self.record(event, Utc::now()) {
+                                    yield event;
+                                }
+                                false
+                            }
+                        }
+                    }
+                };
+                if done { break }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::task::Poll;
+
+    use chrono::TimeZone;
+    use futures::SinkExt;
+    use serde_json::json;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::ReceiverStream;
+    use vector_lib::lookup::owned_value_path;
+
+    use super::*;
+    use crate::{
+        config::log_schema, test_util::components::assert_transform_compliance,
+        transforms::test::create_topology,
+    };
+
+    fn rollup(config: &str) -> Rollup {
+        let config = toml::from_str::<RollupConfig>(config).unwrap();
+        Rollup::new(&config, &TransformContext::default()).unwrap()
+    }
+
+    fn log(value: serde_json::Value) -> Event {
+        LogEvent::from(Value::from(value)).into()
+    }
+
+    fn timestamp(seconds: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, seconds)
+            .single()
+            .unwrap()
+    }
+
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<RollupConfig>();
+    }
+
+    #[test]
+    fn rolls_up_selected_events() {
+        let mut rollup = rollup(
+            r#"
+            condition = ".status == 200"
+            group_by = ["{{ path }}"]
+            "#,
+        );
+        let now = timestamp(0);
+
+        assert_eq!(
+            rollup.record(log(json!({"status": 500, "path": "/"})), now),
+            Some(log(json!({"status": 500, "path": "/"})))
+        );
+        for seconds in [3, 1, 2] {
+            let mut event = log(json!({"status": 200, "path": "/health", "n": seconds}));
+            event.as_mut_log().insert(
+                log_schema().timestamp_key_target_path().unwrap(),
+                timestamp(seconds),
+            );
+            assert_eq!(rollup.record(event, now), None);
+        }
+        assert_eq!(
+            rollup.record(log(json!({"status": 200, "path": "/"})), now),
+            None
+        );
+
+        let events = rollup.flush();
+        assert_eq!(events.len(), 2);
+        let health = events[0].as_log();
+        assert_eq!(health["n"], 3.into());
+        assert_eq!(health["rollup.count"], 3.into());
+        assert_eq!(health["rollup.first_timestamp"], timestamp(1).into());
+        assert_eq!(health["rollup.last_timestamp"], timestamp(3).into());
+        let root = events[1].as_log();
+        assert_eq!(root["path"], "/".into());
+        assert_eq!(root["rollup.count"], 1.into());
+        assert_eq!(root["rollup.first_timestamp"], now.into());
+
+        assert!(rollup.flush().is_empty());
+    }
+
+    #[test]
+    fn passes_events_through_beyond_max_groups() {
+        let mut rollup = rollup(
+            r#"
+            condition = "true"
+            group_by = ["{{ path }}"]
+            max_groups = 1
+            field = "summary"
+            "#,
+        );
+        let now = timestamp(0);
+
+        assert_eq!(rollup.record(log(json!({"path": "/a"})), now), None);
+        assert_eq!(
+            rollup.record(log(json!({"path": "/b"})), now),
+            Some(log(json!({"path": "/b"})))
+        );
+        assert_eq!(rollup.record(log(json!({"path": "/a"})), now), None);
+
+        let events = rollup.flush();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].as_log()["summary.count"], 2.into());
+
+        // The groups are reset on flush.
+        assert_eq!(rollup.record(log(json!({"path": "/b"})), now), None);
+    }
+
+    #[test]
+    fn adds_rollup_field_to_schema() {
+        let config = toml::from_str::<RollupConfig>(r#"condition = "true""#).unwrap();
+        let definition = config.output_definition(&schema::Definition::empty_legacy_namespace());
+
+        assert_eq!(
+            definition
+                .event_kind()
+                .at_path(&owned_value_path!("rollup", "count")),
+            Kind::integer().or_undefined()
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn flushes_every_interval() {
+        let rollup = rollup(
+            r#"
+            condition = "true"
+            interval_secs = 10
+            "#,
+        );
+
+        let (mut tx, rx) = futures::channel::mpsc::channel(10);
+        let mut out_stream = Box::new(rollup).transform(Box::pin(rx));
+
+        tx.send(log(json!({"message": "ok"}))).await.unwrap();
+        tx.send(log(json!({"message": "ok"}))).await.unwrap();
+        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+
+        tokio::time::advance(Duration::from_secs(10)).await;
+        let event = out_stream.next().await.unwrap();
+        assert_eq!(event.as_log()["rollup.count"], 2.into());
+        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+
+        // The groups left are flushed on shutdown.
+        tx.send(log(json!({"message": "ok"}))).await.unwrap();
+        tx.disconnect();
+        let event = out_stream.next().await.unwrap();
+        assert_eq!(event.as_log()["rollup.count"], 1.into());
+        assert_eq!(out_stream.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn emits_rollup_events() {
+        assert_transform_compliance(async {
+            let config = toml::from_str::<RollupConfig>(
+                r#"
+                condition = '.message == "ok"'
+                interval_secs = 3600
+                "#,
+            )
+            .unwrap();
+            let (tx, rx) = mpsc::channel(1);
+            let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
+
+            tx.send(LogEvent::from("ok").into()).await.unwrap();
+            tx.send(LogEvent::from("error").into()).await.unwrap();
+
+            let event = out.recv().await.unwrap();
+            assert_eq!(event.as_log()["message"], "error".into());
+
+            drop(tx);
+            topology.stop().await;
+            let event = out.recv().await.unwrap();
+            assert_eq!(event.as_log()["rollup.count"], 1.into());
+            assert_eq!(out.recv().await, None);
+        })
+        .await;
+    }
+}
@@ -180,10 +180,12 @@ mod test {
 
     use indoc::indoc;
     use vector_lib::transform::TransformOutputsBuf;
+    use vrl::path;
 
     use super::*;
     use crate::{
         config::{build_unit_tests, ConfigBuilder},
+        event::LogEvent,
         test_util::components::{init_test, COMPONENT_MULTIPLE_OUTPUTS_TESTS},
     };
 
@@ -385,6 +387,60 @@ mod test {
         }
     }
 
+    #[test]
+    fn route_on_metadata() {
+        let output_names = vec!["journald", "other", UNMATCHED_ROUTE];
+        let journald_event = {
+            let mut event = Event::from(LogEvent::from("hello world"));
+            event
+                .metadata_mut()
+                .value_mut()
+                .insert(path!("vector", "source_id"), "journald_in");
+            event
+        };
+        let file_event = {
+            let mut event = Event::from(LogEvent::from("hello world"));
+            event
+                .metadata_mut()
+                .value_mut()
+                .insert(path!("vector", "source_id"), "file_in");
+            event
+        };
+        let config = toml::from_str::<RouteConfig>(
+            r#"
+            route.journald.type = "vrl"
+            route.journald.source = '%vector.source_id == "journald_in"'
+
+            route.other.type = "vrl"
+            route.other.source = '%vector.source_id != "journald_in"'
+        "#,
+        )
+        .unwrap();
+
+        let mut transform = Route::new(&config, &Default::default()).unwrap();
+        let mut outputs = TransformOutputsBuf::new_with_capacity(
+            output_names
+                .iter()
+                .map(|output_name| {
+                    TransformOutput::new(DataType::all_bits(), HashMap::new())
+                        .with_port(output_name.to_owned())
+                })
+                .collect(),
+            1,
+        );
+
+        transform.transform(journald_event.clone(), &mut outputs);
+        transform.transform(file_event.clone(), &mut outputs);
+        for (output_name, expected) in [
+            ("journald", vec![journald_event]),
+            ("other", vec![file_event]),
+            (UNMATCHED_ROUTE, vec![]),
+        ] {
+            let events: Vec<_> = outputs.drain_named(output_name).collect();
+            assert_eq!(events, expected, "{output_name}");
+        }
+    }
+
     #[tokio::test]
     async fn route_metrics_with_output_tag() {
         init_test();
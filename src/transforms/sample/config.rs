@@ -14,7 +14,7 @@ use crate::{
     transforms::Transform,
 };
 
-use super::transform::Sample;
+use super::transform::{MissingKey, Sample};
 
 /// Configuration for the `sample` transform.
 #[configurable_component(transform(
@@ -42,9 +42,28 @@ pub struct SampleConfig {
     ///
     /// This can be useful to, for example, ensure that all logs for a given transaction are
     /// sampled together, but that overall `1/N` transactions are sampled.
-    #[configurable(metadata(docs::examples = "message"))]
+    ///
+    /// Must be set in `hash` mode.
+    #[configurable(metadata(docs::examples = "message", docs::examples = "trace_id"))]
     pub key_field: Option<String>,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    pub mode: SampleMode,
+
+    /// The seed the value of `key_field` is hashed with, in `hash` mode.
+    ///
+    /// Vector instances sampling with the same `rate` and `hash_seed` keep the same buckets of
+    /// events, so that events of the same transaction are kept together across instances. The
+    /// default seed hashes values the same way as in `rate` mode.
+    #[serde(default)]
+    #[configurable(metadata(docs::examples = 42))]
+    pub hash_seed: u64,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    pub missing_key: MissingKeyBehavior,
+
     /// The event key in which the sample rate is stored. If set to an empty string, the sample rate will not be added to the event.
     #[configurable(metadata(docs::examples = "sample_rate"))]
     #[serde(default = "default_sample_rate_key")]
@@ -64,11 +83,62 @@ pub struct SampleConfig {
     pub exclude: Option<AnyCondition>,
 }
 
+/// How events are sampled.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SampleMode {
+    /// Events are sampled by hashing the value of `key_field` if they have it, and independently
+    /// otherwise, one out of every `rate` events being forwarded.
+    #[default]
+    Rate,
+
+    /// Events are sampled by hashing the value of `key_field` with `hash_seed`, the events whose
+    /// hash is a multiple of `rate` being forwarded.
+    ///
+    /// The hash is the [SeaHash][seahash] of the value, rendered as a string, and is stable: the
+    /// same events are kept across restarts of Vector, instances of Vector using the same seed, and
+    /// upgrades of Vector. Events without `key_field` are sampled according to `missing_key`.
+    ///
+    /// [seahash]: https://docs.rs/seahash
+    Hash,
+}
+
+/// How events without `key_field` are sampled in `hash` mode.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MissingKeyBehavior {
+    /// The events are sampled independently, one out of every `rate` events being forwarded, as in
+    /// `rate` mode.
+    #[default]
+    Sample,
+
+    /// The events are all forwarded, with a sample rate of 1.
+    Keep,
+
+    /// The events are all dropped.
+    Drop,
+}
+
+impl From<MissingKeyBehavior> for MissingKey {
+    fn from(behavior: MissingKeyBehavior) -> Self {
+        match behavior {
+            MissingKeyBehavior::Sample => MissingKey::Sample,
+            MissingKeyBehavior::Keep => MissingKey::Keep,
+            MissingKeyBehavior::Drop => MissingKey::Drop,
+        }
+    }
+}
+
 impl GenerateConfig for SampleConfig {
     fn generate_config() -> toml::Value {
         toml::Value::try_from(Self {
             rate: 10,
             key_field: None,
+            mode: SampleMode::default(),
+            hash_seed: 0,
+            missing_key: MissingKeyBehavior::default(),
             group_by: None,
             exclude: None::<AnyCondition>,
             sample_rate_key: default_sample_rate_key(),
@@ -81,7 +151,7 @@ impl GenerateConfig for SampleConfig {
 #[typetag::serde(name = "sample")]
 impl TransformConfig for SampleConfig {
     async fn build(&self, context: &TransformContext) -> crate::Result<Transform> {
-        Ok(Transform::function(Sample::new(
+        let sample = Sample::new(
             Self::NAME.to_string(),
             self.rate,
             self.key_field.clone(),
@@ -91,7 +161,17 @@ impl TransformConfig for SampleConfig {
                 .map(|condition| condition.build(&context.enrichment_tables))
                 .transpose()?,
             default_sample_rate_key(),
-        )))
+        );
+        let sample = match self.mode {
+            SampleMode::Rate => sample,
+            SampleMode::Hash => {
+                if self.key_field.is_none() {
+                    return Err("`key_field` must be set in `hash` mode".into());
+                }
+                sample.with_hash_sampling(self.hash_seed, self.missing_key.into())
+            }
+        };
+        Ok(Transform::function(sample))
     }
 
     fn input(&self) -> Input {
@@ -1,15 +1,15 @@
 use crate::template::Template;
 use crate::test_util::components::assert_transform_compliance;
-use crate::transforms::sample::config::SampleConfig;
+use crate::transforms::sample::config::{MissingKeyBehavior, SampleConfig, SampleMode};
 use crate::transforms::test::create_topology;
 use crate::transforms::{FunctionTransform, OutputBuffer};
 use crate::{
     conditions::{Condition, ConditionalConfig, VrlConfig},
-    config::log_schema,
+    config::{log_schema, TransformConfig},
     event::{Event, LogEvent, TraceEvent},
     test_util::random_lines,
     transforms::sample::config::default_sample_rate_key,
-    transforms::sample::transform::Sample,
+    transforms::sample::transform::{hash_key, MissingKey, Sample},
     transforms::test::transform_one,
 };
 use approx::assert_relative_eq;
@@ -24,6 +24,9 @@ async fn emits_internal_events() {
         let config = SampleConfig {
             rate: 1,
             key_field: None,
+            mode: SampleMode::Rate,
+            hash_seed: 0,
+            missing_key: MissingKeyBehavior::Sample,
             group_by: None,
             exclude: None,
             sample_rate_key: default_sample_rate_key(),
@@ -132,6 +135,95 @@ fn hash_consistently_samples_the_same_events() {
     assert_eq!(first_run, second_run);
 }
 
+#[test]
+fn hash_is_stable() {
+    // Changing these values would change which events are sampled in `hash` mode.
+    let trace_id = b"4bf92f3577b34da6a3ce929d0e0e4736";
+    assert_eq!(hash_key(trace_id, 0), 15518995762568248654);
+    assert_eq!(hash_key(trace_id, 42), 9077438258671111491);
+    assert_eq!(hash_key(b"hello", 0), seahash::hash(b"hello"));
+}
+
+#[test]
+fn hash_mode_samples_keys_together() {
+    for (seed, expected) in [(0, [4, 7, 11, 18]), (42, [4, 7, 9, 15])] {
+        let mut sampler = Sample::new(
+            "sample".to_string(),
+            4,
+            Some("trace_id".to_string()),
+            None,
+            None,
+            default_sample_rate_key(),
+        )
+        .with_hash_sampling(seed, MissingKey::Sample);
+
+        let mut passed = Vec::new();
+        for _ in 0..3 {
+            for i in 0..20 {
+                let mut log = LogEvent::from("hello");
+                log.insert("trace_id", format!("trace-{i}"));
+                if transform_one(&mut sampler, log.into()).is_some() {
+                    passed.push(i);
+                }
+            }
+        }
+        assert_eq!(passed, expected.repeat(3), "seed {seed}");
+    }
+}
+
+#[test]
+fn hash_mode_handles_missing_keys() {
+    for (missing_key, expected) in [
+        (MissingKey::Sample, 5),
+        (MissingKey::Keep, 10),
+        (MissingKey::Drop, 0),
+    ] {
+        let mut sampler = Sample::new(
+            "sample".to_string(),
+            2,
+            Some("trace_id".to_string()),
+            None,
+            None,
+            default_sample_rate_key(),
+        )
+        .with_hash_sampling(42, missing_key);
+
+        let passed = (0..10)
+            .filter_map(|_| transform_one(&mut sampler, LogEvent::from("hello").into()))
+            .count();
+        assert_eq!(passed, expected, "{missing_key:?}");
+    }
+}
+
+#[test]
+fn hash_mode_sets_sample_rate_of_kept_events() {
+    let mut sampler = Sample::new(
+        "sample".to_string(),
+        2,
+        Some("trace_id".to_string()),
+        None,
+        None,
+        default_sample_rate_key(),
+    )
+    .with_hash_sampling(42, MissingKey::Keep);
+
+    let kept = transform_one(&mut sampler, LogEvent::from("hello").into()).unwrap();
+    assert_eq!(kept.as_log()["sample_rate"], "1".into());
+}
+
+#[tokio::test]
+async fn hash_mode_requires_key_field() {
+    let config = toml::from_str::<SampleConfig>(
+        r#"
+        rate = 10
+        mode = "hash"
+        "#,
+    )
+    .unwrap();
+
+    assert!(config.build(&Default::default()).await.is_err());
+}
+
 #[test]
 fn always_passes_events_matching_pass_list() {
     for key_field in &[None, log_schema().message_key().map(ToString::to_string)] {
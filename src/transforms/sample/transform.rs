@@ -21,6 +21,39 @@ pub struct Sample {
     exclude: Option<Condition>,
     sample_rate_key: OptionalValuePath,
     counter: HashMap<Option<String>, u64>,
+    hash_seed: u64,
+    missing_key: MissingKey,
+}
+
+/// How events without the key field are sampled.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MissingKey {
+    /// The events are sampled independently, by counting them.
+    Sample,
+
+    /// The events are all forwarded, with a sample rate of 1.
+    Keep,
+
+    /// The events are all dropped.
+    Drop,
+}
+
+/// The keys SeaHash hashes with by default, combined with the seed, so that the default seed
+/// hashes keys the same way as `seahash::hash`.
+const DEFAULT_SEAHASH_KEYS: [u64; 4] = [
+    0x16f1_1fe8_9b0d_677c,
+    0xb480_a793_d8e6_c86c,
+    0x6fe2_e5aa_f078_ebc9,
+    0x14f9_94a4_c525_9381,
+];
+
+/// Hashes the value of the key field of an event with a seed.
+///
+/// The events sampled by hashing their key depend on this hash, so it must not change across
+/// versions.
+pub fn hash_key(key: &[u8], seed: u64) -> u64 {
+    let [k1, k2, k3, k4] = DEFAULT_SEAHASH_KEYS;
+    seahash::hash_seeded(key, k1 ^ seed, k2 ^ seed, k3 ^ seed, k4 ^ seed)
 }
 
 impl Sample {
@@ -43,6 +76,37 @@ impl Sample {
             exclude,
             sample_rate_key,
             counter: HashMap::new(),
+            hash_seed: 0,
+            missing_key: MissingKey::Sample,
+        }
+    }
+
+    /// Samples events by hashing their key with the given seed, and events without the key as
+    /// given.
+    pub fn with_hash_sampling(mut self, seed: u64, missing_key: MissingKey) -> Self {
+        self.hash_seed = seed;
+        self.missing_key = missing_key;
+        self
+    }
+
+    /// Writes the number of events a forwarded event stands for to `sample_rate_key`, if set.
+    fn set_sample_rate(&self, event: &mut Event, rate: u64) {
+        if let Some(path) = &self.sample_rate_key.path {
+            match event {
+                Event::Log(event) => {
+                    event.namespace().insert_source_metadata(
+                        self.name.as_str(),
+                        event,
+                        Some(LegacyKey::Overwrite(path)),
+                        path,
+                        rate.to_string(),
+                    );
+                }
+                Event::Trace(event) => {
+                    event.insert(&OwnedTargetPath::event(path.clone()), rate.to_string());
+                }
+                Event::Metric(_) => panic!("component can never receive metric events"),
+            };
         }
     }
 }
@@ -106,10 +170,19 @@ impl FunctionTransform for Sample {
 
         let counter_value: u64 = *self.counter.entry(group_by_key.clone()).or_default();
 
-        let num = if let Some(value) = value {
-            seahash::hash(value.as_bytes())
-        } else {
-            counter_value
+        let num = match (value, self.missing_key) {
+            (Some(value), _) => hash_key(value.as_bytes(), self.hash_seed),
+            (None, MissingKey::Sample) => counter_value,
+            (None, MissingKey::Keep) => {
+                // Every kept event stands for itself only.
+                self.set_sample_rate(&mut event, 1);
+                output.push(event);
+                return;
+            }
+            (None, MissingKey::Drop) => {
+                emit!(SampleEventDiscarded);
+                return;
+            }
         };
 
         // reset counter for particular key, or default key if group_by option isn't provided
@@ -117,23 +190,7 @@ impl FunctionTransform for Sample {
         self.counter.insert(group_by_key.clone(), increment);
 
         if num % self.rate == 0 {
-            if let Some(path) = &self.sample_rate_key.path {
-                match event {
-                    Event::Log(ref mut event) => {
-                        event.namespace().insert_source_metadata(
-                            self.name.as_str(),
-                            event,
-                            Some(LegacyKey::Overwrite(path)),
-                            path,
-                            self.rate.to_string(),
-                        );
-                    }
-                    Event::Trace(ref mut event) => {
-                        event.insert(&OwnedTargetPath::event(path.clone()), self.rate.to_string());
-                    }
-                    Event::Metric(_) => panic!("component can never receive metric events"),
-                };
-            }
+            self.set_sample_rate(&mut event, self.rate);
             output.push(event);
         } else {
             emit!(SampleEventDiscarded);
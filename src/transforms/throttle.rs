@@ -1,17 +1,25 @@
-use std::{num::NonZeroU32, pin::Pin, time::Duration};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    num::{NonZeroU32, NonZeroUsize},
+    pin::Pin,
+    time::Duration,
+};
 
 use async_stream::stream;
+use chrono::{DateTime, Utc};
 use futures::{Stream, StreamExt};
 use governor::{clock, Quota, RateLimiter};
 use serde_with::serde_as;
 use snafu::Snafu;
 use vector_lib::config::{clone_input_definitions, LogNamespace};
 use vector_lib::configurable::configurable_component;
+use vector_lib::lookup::event_path;
+use vrl::value::Kind;
 
 use crate::{
     conditions::{AnyCondition, Condition},
     config::{DataType, Input, OutputId, TransformConfig, TransformContext, TransformOutput},
-    event::Event,
+    event::{Event, LogEvent},
     internal_events::{TemplateRenderingError, ThrottleEventDiscarded},
     schema,
     template::Template,
@@ -34,8 +42,22 @@ pub struct ThrottleInternalMetricsConfig {
     /// Only set this to true if you know that the number of unique keys is bounded.
     #[serde(default)]
     pub emit_events_discarded_per_key: bool,
+
+    /// The maximum number of keys the `throttle_events_discarded_total` internal metric is emitted
+    /// with.
+    ///
+    /// If set, the counter is incremented for each discarded event, with the `key` tag set to the
+    /// key value associated with the discarded event. Once this many keys have been seen, events
+    /// discarded for any other key are counted with the `key` tag set to `_other`, which keeps the
+    /// cardinality of the tag bounded. If not set, the counter is not emitted.
+    #[configurable(metadata(docs::examples = 100))]
+    pub max_discarded_keys: Option<NonZeroUsize>,
 }
 
+/// The value of the `key` tag of the `throttle_events_discarded_total` internal metric for the
+/// keys seen after the maximum number of keys has been reached.
+const OTHER_DISCARDED_KEY: &str = "_other";
+
 /// Configuration for the `throttle` transform.
 #[serde_as]
 #[configurable_component(transform("throttle", "Rate limit logs passing through a topology."))]
@@ -65,6 +87,16 @@ pub struct ThrottleConfig {
     #[configurable(derived)]
     #[serde(default)]
     internal_metrics: ThrottleInternalMetricsConfig,
+
+    /// Whether or not to emit a summary event for each key events were discarded for, at the end of
+    /// each window.
+    ///
+    /// Summary events are emitted along with the events that are not discarded, and hold a
+    /// `message` field set to `throttled`, the `key` the events were discarded for, if any, the
+    /// number of `dropped` events, and the `window_start` and `window_end` timestamps. The summaries
+    /// of the current window are also emitted when the transform stops.
+    #[serde(default)]
+    emit_summaries: bool,
 }
 
 impl_generate_config_from_default!(ThrottleConfig);
@@ -87,13 +119,28 @@ impl TransformConfig for ThrottleConfig {
         _: LogNamespace,
     ) -> Vec<TransformOutput> {
         // The event is not modified, so the definition is passed through as-is
-        vec![TransformOutput::new(
-            DataType::Log,
-            clone_input_definitions(input_definitions),
-        )]
+        let mut definitions = clone_input_definitions(input_definitions);
+        if self.emit_summaries {
+            for definition in definitions.values_mut() {
+                let event_kind = definition.event_kind_mut();
+                *event_kind = event_kind.union(summary_kind());
+            }
+        }
+        vec![TransformOutput::new(DataType::Log, definitions)]
     }
 }
 
+/// The kind of the summary events.
+fn summary_kind() -> Kind {
+    Kind::object(BTreeMap::from([
+        ("message".into(), Kind::bytes()),
+        ("key".into(), Kind::bytes().or_undefined()),
+        ("dropped".into(), Kind::integer()),
+        ("window_start".into(), Kind::timestamp()),
+        ("window_end".into(), Kind::timestamp()),
+    ]))
+}
+
 #[derive(Clone)]
 pub struct Throttle<C: clock::Clock<Instant = I>, I: clock::Reference> {
     quota: Quota,
@@ -102,6 +149,7 @@ pub struct Throttle<C: clock::Clock<Instant = I>, I: clock::Reference> {
     exclude: Option<Condition>,
     clock: C,
     internal_metrics: ThrottleInternalMetricsConfig,
+    emit_summaries: bool,
 }
 
 impl<C, I> Throttle<C, I>
@@ -140,10 +188,83 @@ where
             key_field: config.key_field.clone(),
             exclude,
             internal_metrics: config.internal_metrics.clone(),
+            emit_summaries: config.emit_summaries,
         })
     }
 }
 
+/// The number of events discarded for each key during the current window.
+struct DiscardedSummaries {
+    window_start: DateTime<Utc>,
+    discarded: HashMap<Option<String>, u64>,
+}
+
+impl DiscardedSummaries {
+    fn new() -> Self {
+        Self {
+            window_start: Utc::now(),
+            discarded: HashMap::new(),
+        }
+    }
+
+    fn record(&mut self, key: &Option<String>) {
+        *self.discarded.entry(key.clone()).or_default() += 1;
+    }
+
+    /// Ends the current window, returning a summary event for each key events were discarded for.
+    fn flush(&mut self) -> Vec<Event> {
+        let window_start = self.window_start;
+        let window_end = Utc::now();
+        self.window_start = window_end;
+
+        self.discarded
+            .drain()
+            .map(|(key, dropped)| {
+                let mut log = LogEvent::default();
+                log.insert(event_path!("message"), "throttled");
+                if let Some(key) = key {
+                    log.insert(event_path!("key"), key);
+                }
+                log.insert(
+                    event_path!("dropped"),
+                    i64::try_from(dropped).unwrap_or(i64::MAX),
+                );
+                log.insert(event_path!("window_start"), window_start);
+                log.insert(event_path!("window_end"), window_end);
+                log.into()
+            })
+            .collect()
+    }
+}
+
+/// The keys the `throttle_events_discarded_total` internal metric has been emitted with.
+struct DiscardedKeys {
+    max_keys: usize,
+    keys: HashSet<String>,
+}
+
+impl DiscardedKeys {
+    fn new(max_keys: NonZeroUsize) -> Self {
+        Self {
+            max_keys: max_keys.get(),
+            keys: HashSet::new(),
+        }
+    }
+
+    /// Returns the value of the `key` tag for the given key, which is the key itself unless the
+    /// maximum number of keys has been reached.
+    fn tag(&mut self, key: &str) -> String {
+        if self.keys.contains(key) {
+            key.to_string()
+        } else if self.keys.len() < self.max_keys {
+            self.keys.insert(key.to_string());
+            key.to_string()
+        } else {
+            OTHER_DISCARDED_KEY.to_string()
+        }
+    }
+}
+
 impl<C, I> TaskTransform<Event> for Throttle<C, I>
 where
     C: clock::Clock<Instant = I> + Send + 'static + Clone,
@@ -158,6 +279,16 @@ where
     {
         let mut flush_keys = tokio::time::interval(self.flush_keys_interval * 2);
 
+        let mut flush_summaries = tokio::time::interval_at(
+            tokio::time::Instant::now() + self.flush_keys_interval,
+            self.flush_keys_interval,
+        );
+        let mut summaries = DiscardedSummaries::new();
+        let mut discarded_keys = self
+            .internal_metrics
+            .max_discarded_keys
+            .map(DiscardedKeys::new);
+
         let limiter = RateLimiter::dashmap_with_clock(self.quota, self.clock.clone());
 
         Box::pin(stream! {
@@ -167,7 +298,14 @@ where
 
                 maybe_event = input_rx.next() => {
                     match maybe_event {
-                        None => true,
+                        None => {
+                            if self.emit_summaries {
+                                for summary in summaries.flush() {
+                                    yield summary;
+                                }
+                            }
+                            true
+                        }
                         Some(event) => {
                             let (throttle, event) = match self.exclude.as_ref() {
                                 Some(condition) => {
@@ -194,9 +332,15 @@ where
                                         Some(event)
                                     }
                                     _ => {
+                                        if self.emit_summaries {
+                                            summaries.record(&key);
+                                        }
+                                        let key = key.unwrap_or_else(|| "None".to_string());
+                                        let discarded_key = discarded_keys.as_mut().map(|keys| keys.tag(&key));
                                         emit!(ThrottleEventDiscarded{
-                                            key: key.unwrap_or_else(|| "None".to_string()),
-                                            emit_events_discarded_per_key: self.internal_metrics.emit_events_discarded_per_key
+                                            key,
+                                            emit_events_discarded_per_key: self.internal_metrics.emit_events_discarded_per_key,
+                                            discarded_key,
                                         });
                                         None
                                     }
@@ -215,6 +359,12 @@ where
                     limiter.retain_recent();
                     false
                 }
+                _ = flush_summaries.tick(), if self.emit_summaries => {
+                    for summary in summaries.flush() {
+                        yield summary;
+                    }
+                    false
+                }
             };
             if done { break }
           }
@@ -436,6 +586,105 @@ key_field = "{{ bucket }}"
         assert_eq!(Poll::Ready(None), futures::poll!(out_stream.next()));
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn throttle_summaries() {
+        let clock = clock::FakeRelativeClock::default();
+        let config = toml::from_str::<ThrottleConfig>(
+            r#"
+threshold = 1
+window_secs = 5
+key_field = "{{ bucket }}"
+emit_summaries = true
+"#,
+        )
+        .unwrap();
+
+        let throttle = Throttle::new(&config, &TransformContext::default(), clock.clone())
+            .map(Transform::event_task)
+            .unwrap();
+
+        let throttle = throttle.into_task();
+
+        let (mut tx, rx) = futures::channel::mpsc::channel(10);
+        let mut out_stream = throttle.transform_events(Box::pin(rx));
+
+        // tokio interval is always immediately ready, so we poll once to make sure
+        // we trip it/set the interval in the future
+        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+
+        for _ in 0..3 {
+            let mut log = LogEvent::default();
+            log.insert("bucket", "a");
+            tx.send(log.into()).await.unwrap();
+        }
+        let event = out_stream.next().await.unwrap();
+        assert_eq!(event.as_log().get("bucket"), Some(&"a".into()));
+
+        // The other two events are discarded, and summarized at the end of the window
+        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+        tokio::time::advance(Duration::from_secs(5)).await;
+
+        let summary = out_stream.next().await.unwrap();
+        let summary = summary.as_log();
+        assert_eq!(summary.get("message"), Some(&"throttled".into()));
+        assert_eq!(summary.get("key"), Some(&"a".into()));
+        assert_eq!(summary.get("dropped"), Some(&2.into()));
+        assert!(summary.get("window_start").unwrap().is_timestamp());
+        assert!(summary.get("window_end").unwrap().is_timestamp());
+
+        // Nothing is summarized for windows without discarded events
+        tokio::time::advance(Duration::from_secs(5)).await;
+        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+
+        tx.disconnect();
+        assert_eq!(Poll::Ready(None), futures::poll!(out_stream.next()));
+    }
+
+    #[tokio::test]
+    async fn throttle_summaries_on_shutdown() {
+        let clock = clock::FakeRelativeClock::default();
+        let config = toml::from_str::<ThrottleConfig>(
+            r"
+threshold = 1
+window_secs = 60
+emit_summaries = true
+",
+        )
+        .unwrap();
+
+        let throttle = Throttle::new(&config, &TransformContext::default(), clock.clone())
+            .map(Transform::event_task)
+            .unwrap();
+
+        let throttle = throttle.into_task();
+
+        let (mut tx, rx) = futures::channel::mpsc::channel(10);
+        let mut out_stream = throttle.transform_events(Box::pin(rx));
+
+        tx.send(LogEvent::default().into()).await.unwrap();
+        tx.send(LogEvent::default().into()).await.unwrap();
+        assert!(out_stream.next().await.is_some());
+        assert_eq!(Poll::Pending, futures::poll!(out_stream.next()));
+
+        // The summary of the current window is emitted when the input ends
+        tx.disconnect();
+        let summary = out_stream.next().await.unwrap();
+        let summary = summary.as_log();
+        assert_eq!(summary.get("dropped"), Some(&1.into()));
+        assert_eq!(summary.get("key"), None);
+        assert_eq!(out_stream.next().await, None);
+    }
+
+    #[test]
+    fn caps_discarded_keys() {
+        let mut keys = DiscardedKeys::new(NonZeroUsize::new(2).unwrap());
+
+        assert_eq!(keys.tag("a"), "a");
+        assert_eq!(keys.tag("b"), "b");
+        assert_eq!(keys.tag("c"), OTHER_DISCARDED_KEY);
+        assert_eq!(keys.tag("a"), "a");
+    }
+
     #[tokio::test]
     async fn emits_internal_events() {
         assert_transform_compliance(async move {
@@ -445,6 +694,7 @@ key_field = "{{ bucket }}"
                 key_field: None,
                 exclude: None,
                 internal_metrics: Default::default(),
+                emit_summaries: false,
             };
             let (tx, rx) = mpsc::channel(1);
             let (topology, mut out) = create_topology(ReceiverStream::new(rx), config).await;
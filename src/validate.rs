@@ -1,6 +1,6 @@
 #![allow(missing_docs)]
 
-use std::{collections::HashMap, fmt, fs::remove_dir_all, path::PathBuf};
+use std::{collections::HashMap, fmt, fs::remove_dir_all, path::PathBuf, time::Duration};
 
 use clap::Parser;
 use colored::*;
@@ -9,7 +9,11 @@ use exitcode::ExitCode;
 use crate::{
     config::{self, Config, ConfigDiff},
     extra_context::ExtraContext,
-    topology::{self, builder::TopologyPieces},
+    topology::{
+        self,
+        builder::TopologyPieces,
+        health::{self, Outcome},
+    },
 };
 
 const TEMPORARY_DIRECTORY: &str = "validate_tmp";
@@ -25,6 +29,18 @@ pub struct Opts {
     #[arg(long)]
     pub skip_healthchecks: bool,
 
+    /// Probes the connectivity of sinks by running their health checks concurrently, and reports
+    /// the outcome of each one.
+    ///
+    /// Every sink is required to pass, unless its `healthcheck.require_healthy` option is
+    /// disabled, in which case its failure is only a warning.
+    #[arg(long, conflicts_with_all = ["no_environment", "skip_healthchecks"])]
+    pub probe: bool,
+
+    /// The time to wait for all probes to complete, in seconds. Probes still running by then fail.
+    #[arg(long, default_value = "10", requires = "probe")]
+    pub probe_timeout_secs: u64,
+
     /// Fail validation on warnings that are probably a mistake in the configuration
     /// or are recommended to be fixed.
     #[arg(short, long)]
@@ -107,6 +123,11 @@ pub async fn validate(opts: &Opts, color: bool) -> ExitCode {
         None => return exitcode::CONFIG,
     };
 
+    if opts.probe {
+        // Probing treats sinks as if Vector was started with `--require-healthy`.
+        config.healthchecks.set_require_healthy(true);
+    }
+
     if !opts.no_environment {
         if let Some(tmp_directory) = create_tmp_directory(&mut config, &mut fmt) {
             validated &= validate_environment(opts, &config, &mut fmt).await;
@@ -176,7 +197,11 @@ async fn validate_environment(opts: &Opts, config: &Config, fmt: &mut Formatter)
     } else {
         return false;
     };
-    opts.skip_healthchecks || validate_healthchecks(opts, config, &diff, &mut pieces, fmt).await
+    if opts.probe {
+        probe_sinks(opts, config, &diff, &mut pieces, fmt).await
+    } else {
+        opts.skip_healthchecks || validate_healthchecks(opts, config, &diff, &mut pieces, fmt).await
+    }
 }
 
 async fn validate_components(
@@ -222,8 +247,8 @@ async fn validate_healthchecks(
         };
 
         trace!("Healthcheck for {id} starting.");
-        match tokio::spawn(healthcheck).await {
-            Ok(Ok(_)) => {
+        match tokio::spawn(healthcheck.task).await.map(Outcome::of) {
+            Ok((Outcome::Passed, _)) => {
                 if config
                     .sink(&id)
                     .expect("Sink not present")
@@ -236,7 +261,19 @@ async fn validate_healthchecks(
                     validated &= !opts.deny_warnings;
                 }
             }
-            Ok(Err(e)) => failed(format!("Health check for \"{}\" failed: {}", id, e)),
+            Ok((Outcome::Degraded, reason)) => {
+                fmt.warning(format!(
+                    "Health check for \"{}\" reported the sink as degraded: {}",
+                    id,
+                    reason.unwrap_or_default()
+                ));
+                validated &= !opts.deny_warnings;
+            }
+            Ok((_, error)) => failed(format!(
+                "Health check for \"{}\" failed: {}",
+                id,
+                error.unwrap_or_default()
+            )),
             Err(error) if error.is_cancelled() => {
                 failed(format!("Health check for \"{}\" was cancelled", id))
             }
@@ -248,6 +285,71 @@ async fn validate_healthchecks(
     validated
 }
 
+async fn probe_sinks(
+    opts: &Opts,
+    config: &Config,
+    diff: &ConfigDiff,
+    pieces: &mut TopologyPieces,
+    fmt: &mut Formatter,
+) -> bool {
+    if !config.healthchecks.enabled {
+        fmt.warning("Health checks are disabled, so sinks cannot be probed");
+        return !opts.deny_warnings;
+    }
+
+    let mut required = HashMap::new();
+    let mut probes = Vec::new();
+    for (id, healthcheck) in topology::take_healthchecks(diff, pieces) {
+        if config
+            .sink(&id)
+            .expect("Sink not present")
+            .healthcheck()
+            .enabled
+        {
+            required.insert(id.clone(), healthcheck.required);
+            probes.push((id, healthcheck.task));
+        } else {
+            fmt.warning(format!("Health check disabled for \"{}\"", id));
+        }
+    }
+
+    let summary = health::run_all(probes, Some(Duration::from_secs(opts.probe_timeout_secs))).await;
+    let width = summary
+        .reports()
+        .iter()
+        .map(|report| report.key.id().len())
+        .max()
+        .unwrap_or_default();
+
+    fmt.title("Sink probes");
+    let mut validated = true;
+    for report in summary.reports() {
+        let line = format!(
+            "{:width$}  {:17}  {:.3?}",
+            report.key.id(),
+            report.outcome.as_str(),
+            report.duration,
+        );
+        let line = match &report.error {
+            Some(error) => format!("{line}  {error}"),
+            None => line,
+        };
+
+        if report.outcome == Outcome::Passed {
+            fmt.success(line);
+        } else if report.outcome.is_passing() || !required[&report.key] {
+            validated &= !opts.deny_warnings;
+            fmt.warning(line);
+        } else {
+            validated = false;
+            fmt.error(line);
+        }
+    }
+    fmt.space();
+
+    validated
+}
+
 /// For data directory that we write to:
 /// 1. Create a tmp directory in it.
 /// 2. Change config to point to that tmp directory.
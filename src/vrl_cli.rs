@@ -0,0 +1,490 @@
+#![allow(missing_docs)]
+//! The `vector vrl` subcommand.
+//!
+//! Programs run against input files are handled here, so that inputs can be read from the
+//! formats Vector decodes. The REPL, and programs run without input files, are handled by the
+//! VRL CLI itself.
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use bytes::{Bytes, BytesMut};
+use clap::{ArgMatches, Args, Command, FromArgMatches as _};
+use tokio_util::codec::Decoder as _;
+use vector_lib::codecs::decoding::{
+    format::Deserializer as _, JournalExportDecoder, JournalExportDeserializer,
+};
+use vector_lib::config::LogNamespace;
+use vrl::compiler::{
+    runtime::Runtime, state::RuntimeState, CompilationResult, Function, Program, TargetValue,
+    TimeZone, VrlRuntime,
+};
+use vrl::diagnostic::Formatter;
+use vrl::value::{Secrets, Value};
+
+/// The options of `vector vrl`: those of the VRL CLI, along with the formats of input files.
+#[derive(Debug)]
+pub struct Opts {
+    /// The options of the VRL CLI, which handles the REPL and programs run without input files.
+    vrl: vrl::cli::Opts,
+    /// The values of the VRL CLI options, used to run programs against input files.
+    shared: SharedOpts,
+    formats: FormatOpts,
+}
+
+impl clap::FromArgMatches for Opts {
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, clap::Error> {
+        Ok(Self {
+            vrl: vrl::cli::Opts::from_arg_matches(matches)?,
+            shared: SharedOpts::from_arg_matches(matches),
+            formats: FormatOpts::from_arg_matches(matches)?,
+        })
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> Result<(), clap::Error> {
+        *self = Self::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+impl Args for Opts {
+    fn augment_args(cmd: Command) -> Command {
+        FormatOpts::augment_args(vrl::cli::Opts::augment_args(cmd))
+    }
+
+    fn augment_args_for_update(cmd: Command) -> Command {
+        FormatOpts::augment_args_for_update(vrl::cli::Opts::augment_args_for_update(cmd))
+    }
+}
+
+/// The options of the VRL CLI, read back from the arguments it defines.
+#[derive(Debug)]
+struct SharedOpts {
+    program: Option<String>,
+    input_file: Option<PathBuf>,
+    program_file: Option<PathBuf>,
+    print_object: bool,
+    timezone: Option<String>,
+    runtime: VrlRuntime,
+    print_warnings: bool,
+}
+
+impl SharedOpts {
+    fn from_arg_matches(matches: &ArgMatches) -> Self {
+        Self {
+            program: matches.get_one::<String>("PROGRAM").cloned(),
+            input_file: matches.get_one::<PathBuf>("input_file").cloned(),
+            program_file: matches.get_one::<PathBuf>("program_file").cloned(),
+            print_object: matches.get_flag("print_object"),
+            timezone: matches.get_one::<String>("timezone").cloned(),
+            runtime: matches
+                .get_one::<VrlRuntime>("runtime")
+                .copied()
+                .unwrap_or_default(),
+            print_warnings: matches.get_flag("print_warnings"),
+        }
+    }
+
+    fn timezone(&self) -> Result<TimeZone, String> {
+        match &self.timezone {
+            Some(timezone) => {
+                TimeZone::parse(timezone).ok_or_else(|| format!("Invalid timezone {timezone:?}."))
+            }
+            None => Ok(TimeZone::default()),
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+#[command(rename_all = "kebab-case")]
+struct FormatOpts {
+    /// The format of the input file.
+    #[arg(long, default_value = "ndjson")]
+    input_format: InputFormat,
+
+    /// The format of the outputs printed for each event of the input file.
+    #[arg(long, default_value = "text")]
+    output_format: OutputFormat,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum InputFormat {
+    /// One JSON object per line.
+    Ndjson,
+    /// A single JSON object, or an array of them.
+    Json,
+    /// Entries in the journal export format, such as written by `journalctl -o export`.
+    JournalExport,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    /// VRL values, as printed by the VRL CLI.
+    Text,
+    /// One JSON value per line.
+    Ndjson,
+}
+
+#[allow(clippy::print_stderr)]
+pub fn cmd(opts: &Opts, functions: Vec<Box<dyn Function>>) -> exitcode::ExitCode {
+    let Opts {
+        vrl: vrl_opts,
+        shared: opts,
+        formats,
+    } = opts;
+    let source = match &opts.program_file {
+        Some(path) => match fs::read_to_string(path) {
+            Ok(source) => Some(source),
+            Err(error) => {
+                eprintln!("Unable to read program file {path:?}: {error}");
+                return exitcode::NOINPUT;
+            }
+        },
+        None => opts.program.clone(),
+    };
+
+    let (Some(input_file), Some(source)) = (&opts.input_file, source) else {
+        if formats.input_format != InputFormat::Ndjson
+            || formats.output_format != OutputFormat::Text
+        {
+            eprintln!(
+                "`--input-format` and `--output-format` require an input file and a program."
+            );
+            return exitcode::USAGE;
+        }
+        return vrl::cli::cmd::cmd(vrl_opts, functions);
+    };
+
+    let timezone = match opts.timezone() {
+        Ok(timezone) => timezone,
+        Err(error) => {
+            eprintln!("{error}");
+            return exitcode::USAGE;
+        }
+    };
+
+    let events = match read_events(input_file, formats.input_format) {
+        Ok(events) => events,
+        Err(error) => {
+            eprintln!("{error}");
+            return exitcode::DATAERR;
+        }
+    };
+
+    let program = match vrl::compiler::compile(&source, &functions) {
+        Ok(CompilationResult {
+            program, warnings, ..
+        }) => {
+            if opts.print_warnings && !warnings.is_empty() {
+                eprintln!("{}", Formatter::new(&source, warnings));
+            }
+            program
+        }
+        Err(diagnostics) => {
+            eprintln!("{}", Formatter::new(&source, diagnostics));
+            return exitcode::DATAERR;
+        }
+    };
+
+    let failures = run(
+        &program,
+        events,
+        &RunOptions {
+            runtime: opts.runtime,
+            timezone,
+            print_object: opts.print_object,
+            output_format: formats.output_format,
+        },
+        &mut io::stdout().lock(),
+        &mut io::stderr().lock(),
+    );
+    match failures {
+        Ok(0) => exitcode::OK,
+        Ok(_) => exitcode::DATAERR,
+        Err(error) => {
+            eprintln!("Unable to write output: {error}");
+            exitcode::IOERR
+        }
+    }
+}
+
+/// An input event, along with where it was found in the input file.
+#[derive(Debug, PartialEq)]
+struct InputEvent {
+    location: String,
+    value: Value,
+}
+
+fn read_events(path: &Path, format: InputFormat) -> Result<Vec<InputEvent>, String> {
+    let contents =
+        fs::read(path).map_err(|error| format!("Unable to read input file {path:?}: {error}"))?;
+    parse_events(contents.into(), format)
+}
+
+fn parse_events(contents: Bytes, format: InputFormat) -> Result<Vec<InputEvent>, String> {
+    match format {
+        InputFormat::Ndjson => String::from_utf8_lossy(&contents)
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                let location = format!("line {}", index + 1);
+                serde_json::from_str::<serde_json::Value>(line)
+                    .map(|value| InputEvent {
+                        value: value.into(),
+                        location: location.clone(),
+                    })
+                    .map_err(|error| format!("Invalid JSON on {location}: {error}"))
+            })
+            .collect(),
+        InputFormat::Json => {
+            let values = match serde_json::from_slice(&contents)
+                .map_err(|error| format!("Invalid JSON: {error}"))?
+            {
+                serde_json::Value::Array(values) => values,
+                value => vec![value],
+            };
+            Ok(values
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| InputEvent {
+                    location: format!("entry {}", index + 1),
+                    value: value.into(),
+                })
+                .collect())
+        }
+        InputFormat::JournalExport => parse_journal_export(contents),
+    }
+}
+
+fn parse_journal_export(contents: Bytes) -> Result<Vec<InputEvent>, String> {
+    let mut decoder = JournalExportDecoder::default();
+    let mut buffer = BytesMut::from(&contents[..]);
+    let mut events = Vec::new();
+    let mut entry = 0;
+
+    loop {
+        entry += 1;
+        let frame = match decoder.decode_eof(&mut buffer) {
+            Ok(Some(frame)) => frame,
+            Ok(None) => break,
+            Err(error) => return Err(format!("Invalid journal export entry {entry}: {error}")),
+        };
+
+        let parsed = JournalExportDeserializer
+            .parse(frame, LogNamespace::Legacy)
+            .map_err(|error| format!("Invalid journal export entry {entry}: {error}"))?;
+        events.extend(parsed.into_iter().map(|event| InputEvent {
+            location: format!("entry {entry}"),
+            value: event.into_log().into_parts().0,
+        }));
+    }
+
+    Ok(events)
+}
+
+struct RunOptions {
+    runtime: VrlRuntime,
+    timezone: TimeZone,
+    print_object: bool,
+    output_format: OutputFormat,
+}
+
+/// Runs the program on each event, printing its output, and returns the number of events for
+/// which it failed.
+fn run(
+    program: &Program,
+    events: Vec<InputEvent>,
+    options: &RunOptions,
+    output: &mut impl Write,
+    errors: &mut impl Write,
+) -> io::Result<usize> {
+    let mut failures = 0;
+    for InputEvent { location, value } in events {
+        let mut target = TargetValue {
+            value,
+            metadata: Value::Object(BTreeMap::new()),
+            secrets: Secrets::default(),
+        };
+
+        let result = match options.runtime {
+            VrlRuntime::Ast => Runtime::new(RuntimeState::default()).resolve(
+                &mut target,
+                program,
+                &options.timezone,
+            ),
+        };
+
+        match result {
+            Ok(result) => {
+                let value = if options.print_object {
+                    target.value
+                } else {
+                    result
+                };
+                match options.output_format {
+                    OutputFormat::Text => writeln!(output, "{value}")?,
+                    OutputFormat::Ndjson => {
+                        serde_json::to_writer(&mut *output, &value)?;
+                        writeln!(output)?;
+                    }
+                }
+            }
+            Err(error) => {
+                failures += 1;
+                writeln!(errors, "Error on input {location}: {error}")?;
+            }
+        }
+    }
+    Ok(failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use vrl::value;
+
+    use super::*;
+
+    fn compile(source: &str) -> Program {
+        vrl::compiler::compile(source, &vrl::stdlib::all())
+            .unwrap()
+            .program
+    }
+
+    fn run_program(
+        source: &str,
+        events: Vec<InputEvent>,
+        print_object: bool,
+        output_format: OutputFormat,
+    ) -> (String, String, usize) {
+        let (mut output, mut errors) = (Vec::new(), Vec::new());
+        let options = RunOptions {
+            runtime: VrlRuntime::Ast,
+            timezone: TimeZone::default(),
+            print_object,
+            output_format,
+        };
+        let failures = run(&compile(source), events, &options, &mut output, &mut errors).unwrap();
+        (
+            String::from_utf8(output).unwrap(),
+            String::from_utf8(errors).unwrap(),
+            failures,
+        )
+    }
+
+    #[test]
+    fn parses_ndjson_input() {
+        let events =
+            parse_events("{\"a\": 1}\n\n{\"a\": 2}\n".into(), InputFormat::Ndjson).unwrap();
+        assert_eq!(
+            events,
+            vec![
+                InputEvent {
+                    location: "line 1".into(),
+                    value: value!({"a": 1}),
+                },
+                InputEvent {
+                    location: "line 3".into(),
+                    value: value!({"a": 2}),
+                },
+            ]
+        );
+
+        let error = parse_events("{\"a\": 1}\n{\"a\"\n".into(), InputFormat::Ndjson).unwrap_err();
+        assert!(error.starts_with("Invalid JSON on line 2: "), "{error}");
+    }
+
+    #[test]
+    fn parses_json_input() {
+        let events = parse_events("[{\"a\": 1}, {\"a\": 2}]".into(), InputFormat::Json).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].location, "entry 2");
+        assert_eq!(events[1].value, value!({"a": 2}));
+
+        let events = parse_events("{\"a\": 1}".into(), InputFormat::Json).unwrap();
+        assert_eq!(events[0].value, value!({"a": 1}));
+
+        assert!(parse_events("[{\"a\": 1},".into(), InputFormat::Json).is_err());
+    }
+
+    #[test]
+    fn parses_journal_export_input() {
+        let contents = "MESSAGE=first\nPRIORITY=6\n\nMESSAGE=second\n_PID=42\n\n";
+        let events = parse_events(contents.into(), InputFormat::JournalExport).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].location, "entry 1");
+        assert_eq!(
+            events[0].value,
+            value!({"message": "first", "PRIORITY": "6"})
+        );
+        assert_eq!(events[1].location, "entry 2");
+        assert_eq!(events[1].value, value!({"message": "second", "_PID": "42"}));
+
+        // A binary field whose value is shorter than its length.
+        let mut contents = b"MESSAGE=first\n\nMESSAGE\n".to_vec();
+        contents.extend(10u64.to_le_bytes());
+        contents.extend(b"short\n\n");
+        let error = parse_events(contents.into(), InputFormat::JournalExport).unwrap_err();
+        assert!(error.contains("entry 2"), "{error}");
+    }
+
+    #[test]
+    fn prints_results_and_objects() {
+        let events = parse_events("{\"a\": 1}\n{\"a\": 2}\n".into(), InputFormat::Ndjson).unwrap();
+        let (output, errors, failures) =
+            run_program(".b = .a * 10; .b", events, false, OutputFormat::Text);
+        assert_eq!(
+            (output.as_str(), errors.as_str(), failures),
+            ("10\n20\n", "", 0)
+        );
+
+        let events = parse_events("{\"a\": 1}\n".into(), InputFormat::Ndjson).unwrap();
+        let (output, _, _) = run_program(".b = .a * 10", events, true, OutputFormat::Ndjson);
+        assert_eq!(output, "{\"a\":1,\"b\":10}\n");
+    }
+
+    #[test]
+    fn reports_failing_inputs() {
+        let events =
+            parse_events("{\"a\": 1}\n{\"a\": \"x\"}\n".into(), InputFormat::Ndjson).unwrap();
+        let (output, errors, failures) =
+            run_program(".a = int!(.a)", events, true, OutputFormat::Ndjson);
+        assert_eq!(output, "{\"a\":1}\n");
+        assert!(errors.starts_with("Error on input line 2: "), "{errors}");
+        assert_eq!(failures, 1);
+    }
+
+    #[test]
+    fn reads_the_options_of_the_vrl_cli() {
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            opts: Opts,
+        }
+
+        let Cli { opts } = Cli::try_parse_from([
+            "vrl",
+            "-i",
+            "events.json",
+            "-o",
+            "--input-format",
+            "json",
+            ".a = 1",
+        ])
+        .unwrap();
+        let shared = &opts.shared;
+        assert_eq!(shared.program.as_deref(), Some(".a = 1"));
+        assert_eq!(shared.input_file, Some("events.json".into()));
+        assert_eq!(shared.program_file, None);
+        assert!(shared.print_object);
+        assert_eq!(shared.timezone, None);
+        assert!(matches!(shared.runtime, VrlRuntime::Ast));
+        assert!(!shared.print_warnings);
+        assert_eq!(opts.formats.input_format, InputFormat::Json);
+        assert_eq!(opts.formats.output_format, OutputFormat::Text);
+    }
+}
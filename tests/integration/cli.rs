@@ -133,7 +133,39 @@ fn validate_ignore_healthcheck() {
     );
 }
 
+#[test]
+fn validate_probe_failing_healthcheck() {
+    assert_eq!(
+        validate_with_args(
+            FAILING_HEALTHCHECK,
+            &["--probe", "--probe-timeout-secs", "1"]
+        ),
+        exitcode::CONFIG
+    );
+}
+
+#[test]
+fn validate_probe_optional_healthcheck() {
+    assert_eq!(
+        validate_with_args(
+            &format!(
+                r#"
+        {}
+    healthcheck.require_healthy = false
+        "#,
+                FAILING_HEALTHCHECK
+            ),
+            &["--probe", "--probe-timeout-secs", "1"]
+        ),
+        exitcode::OK
+    );
+}
+
 fn validate(config: &str) -> i32 {
+    validate_with_args(config, &[])
+}
+
+fn validate_with_args(config: &str, args: &[&str]) -> i32 {
     let dir = create_directory();
 
     // Config with some components that write to file system.
@@ -141,7 +173,10 @@ fn validate(config: &str) -> i32 {
 
     // Run vector
     let mut cmd = Command::cargo_bin("vector").unwrap();
-    cmd.arg("validate").arg(config).env("VECTOR_DATA_DIR", dir);
+    cmd.arg("validate")
+        .args(args)
+        .arg(config)
+        .env("VECTOR_DATA_DIR", dir);
 
     let output = cmd.output().unwrap();
     println!(
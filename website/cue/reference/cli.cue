@@ -349,11 +349,21 @@ cli: {
 					type:        "enum"
 					default:     "json"
 					enum: {
-						yaml:   "Output events as YAML"
-						json:   "Output events as JSON"
-						logfmt: "Output events as logfmt"
+						yaml:    "Output events as YAML"
+						json:    "Output events as JSON"
+						logfmt:  "Output events as logfmt"
+						encoded: "Output the payloads that the sink given with `--component` encodes for the events it receives, escaped, or as hexadecimal if they are binary"
 					}
 				}
+				"component": {
+					description: "Sink whose encoding to preview with the `encoded` format"
+					type:        "string"
+				}
+				"max-payload-bytes": {
+					description: "Maximum size of the encoded payloads printed, in bytes, beyond which they are truncated"
+					type:        "integer"
+					default:     4096
+				}
 				"inputs-of": {
 					description: "Components (transforms, sinks) to observe for their inputs (comma-separated; accepts glob patterns)"
 					type:        "list"
@@ -397,6 +407,9 @@ cli: {
 					_short:      "n"
 					description: "Whether to reconnect if the underlying Vector API connection drops. By default, top will attempt to reconnect if the connection drops."
 				}
+				"hide-bytes": {
+					description: "Hide the columns showing the bytes received and sent on the wire, and the size of the events received and sent. They can also be toggled from the dashboard, by pressing 'b'."
+				}
 			}
 
 			options: {
@@ -435,9 +448,21 @@ cli: {
 					_short:      "d"
 					description: "Fail validation on warnings"
 				}
+				"probe": {
+					description: """
+						Probes the connectivity of every sink concurrently, and prints the
+						outcome of each probe. Sinks with `healthcheck.require_healthy`
+						disabled only raise warnings when failing their probe.
+						"""
+				}
 			}
 
 			options: {
+				"probe-timeout-secs": {
+					description: "How long to wait for all probes to complete, in seconds"
+					type:        "integer"
+					default:     10
+				}
 				"config-yaml": {
 					description: """
 						Any number of Vector config files to validate.
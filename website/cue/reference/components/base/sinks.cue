@@ -12,6 +12,130 @@ base: components: sinks: configuration: {
 			"""
 		required: false
 		type: object: options: {
+			durability: {
+				description:   "When the events in a disk buffer are synchronized to disk, and acknowledged."
+				relevant_when: "type = \"disk\""
+				required:      false
+				type: object: options: {
+					interval_ms: {
+						description:   "The interval between synchronizations."
+						relevant_when: "mode = \"interval\""
+						required:      false
+						type: uint: {
+							default: 500
+							unit:    "milliseconds"
+						}
+					}
+					max_bytes: {
+						description: """
+							The amount of data written, past which the buffer is synchronized before the interval
+							elapses.
+							"""
+						relevant_when: "mode = \"interval\""
+						required:      false
+						type: uint: unit: "bytes"
+					}
+					mode: {
+						description: "When events are synchronized to disk, and acknowledged."
+						required:    false
+						type: string: {
+							default: "none"
+							enum: {
+								always: """
+									Events are synchronized to disk before being acknowledged, every time they are written.
+
+									This is the most durable, and slowest, mode.
+									"""
+								interval: """
+									Events are synchronized to disk periodically, and acknowledged once synchronized.
+
+									Synchronizations are batched, so events are acknowledged up to about `interval_ms` after
+									being written.
+									"""
+								none: """
+									Events are acknowledged once written to the buffer, which only puts them in the page cache
+									of the operating system.
+
+									The buffer is still synchronized to disk every 500 milliseconds while events are written to
+									it, but acknowledged events written since the last synchronization are lost if the host
+									crashes or loses power.
+									"""
+							}
+						}
+					}
+				}
+			}
+			encryption: {
+				description: """
+					Encrypts the events in the buffer.
+
+					Events are encrypted with XChaCha20-Poly1305 as they are written to the buffer. Events
+					already in the buffer when encryption is enabled are read as they are, and the buffer
+					only refuses to open without its key while it holds encrypted events. Likewise, it
+					refuses to open with a key other than the one it was encrypted with while it holds
+					events, and switches to the new key once it is empty.
+
+					Encryption adds 40 bytes to each record, and the cost of encrypting and decrypting it.
+					"""
+				relevant_when: "type = \"disk\""
+				required:      false
+				type: object: options: {
+					key: {
+						description: """
+							The key itself.
+
+							This is meant to be fetched from a secrets backend, such as `SECRET[backend.buffer_key]`.
+							"""
+						required: false
+						type: string: examples: ["SECRET[backend.buffer_key]"]
+					}
+					key_env: {
+						description: "The name of an environment variable containing the key."
+						required:    false
+						type: string: examples: ["VECTOR_BUFFER_KEY"]
+					}
+					key_file: {
+						description: "The path of a file containing the key."
+						required:    false
+						type: string: examples: ["/etc/vector/buffer.key"]
+					}
+				}
+			}
+			expired_status: {
+				description: """
+					The status expired events are finalized with.
+
+					As events are acknowledged to their sources once stored in the buffer, this only changes
+					how expired events are reported.
+					"""
+				relevant_when: "type = \"disk\""
+				required:      false
+				type: string: {
+					default: "delivered"
+					enum: {
+						delivered: "Expired events are finalized as delivered, and counted as discarded intentionally."
+						rejected: """
+							Expired events are finalized as rejected, counted as discarded unintentionally, and logged
+							as an error.
+							"""
+					}
+				}
+			}
+			max_age_secs: {
+				description: """
+					The maximum age of the events in the buffer.
+
+					Events older than this, based on the time they were written to the buffer, are discarded
+					instead of being sent to the sink, such as after the sink was unavailable for a long time.
+					Events are acknowledged once stored in a disk buffer, so discarding them does not affect
+					their sources.
+
+					Only the events written while this option is set can expire.
+					"""
+				relevant_when: "type = \"disk\""
+				required:      false
+				type: uint: unit: "seconds"
+			}
 			max_events: {
 				description:   "The maximum number of events allowed in the buffer."
 				relevant_when: "type = \"memory\""
@@ -40,7 +164,7 @@ base: components: sinks: configuration: {
 														This is less performant, but more durable. Data that has been synchronized to disk will not
 														be lost if Vector is restarted forcefully or crashes.
 
-														Data is synchronized to disk every 500ms.
+														By default, data is synchronized to disk every 500ms. See `durability` to change this.
 														"""
 						memory: """
 														Events are buffered in memory.
@@ -249,11 +249,31 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		buffer_oldest_event_age_seconds: {
+			description:       "The age of the oldest event currently in the buffer, or zero if the buffer is empty."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		buffer_discarded_events_total: {
-			description:       "The number of events dropped by this non-blocking buffer."
+			description:       "The number of events dropped by this buffer."
 			type:              "counter"
 			default_namespace: "vector"
-			tags:              _component_tags
+			tags: _component_tags & {
+				intentional: {
+					description: "True if the events were discarded intentionally, or false if due to an error."
+					required:    true
+				}
+				reason: {
+					description: "Why the events were discarded."
+					required:    true
+					enum: {
+						"corrupted_events": "The events were lost to a corrupted disk buffer."
+						"drop_newest":      "The buffer was full."
+						"expired":          "The events were older than the `max_age_secs` of the disk buffer."
+					}
+				}
+			}
 		}
 		buffer_received_event_bytes_total: {
 			description:       "The number of bytes received by this buffer."
@@ -305,6 +325,18 @@ components: sources: internal_metrics: {
 				stage:      _stage
 			}
 		}
+		component_healthcheck_duration_seconds: {
+			description:       "The time taken by the latest healthcheck of this sink, including retries."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		component_healthcheck_status: {
+			description:       "The outcome of the latest healthcheck of this sink: 0 when it failed, 1 when the sink is degraded, and 2 when it is healthy."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		component_received_bytes_total: {
 			description:       string | *"The number of raw bytes accepted by this component from source origins."
 			type:              "counter"
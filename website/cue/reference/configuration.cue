@@ -468,6 +468,33 @@ configuration: {
 							default: false
 						}
 					}
+
+					allow_degraded: {
+						common: false
+						description: """
+							Whether sinks reporting as degraded, functional but not in their nominal
+							state, pass their health check. When false, degraded sinks are treated as
+							unhealthy.
+							"""
+						required: false
+						type: bool: {
+							default: true
+						}
+					}
+
+					deadline_secs: {
+						common: false
+						description: """
+							The maximum amount of time to wait for the health checks of all sinks,
+							which run concurrently, on startup and reload. Health checks that have
+							not completed by then are treated as failed.
+							"""
+						required: false
+						type: float: {
+							examples: [60.0]
+							unit: "seconds"
+						}
+					}
 				}
 			}
 		}